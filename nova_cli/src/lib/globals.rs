@@ -291,6 +291,16 @@ pub fn initialize_global_object_with_internals(agent: &mut Agent, global: Object
     );
     create_obj_func(agent, nova_obj, "gc", run_gc, 0, gc);
 
+    let abstract_module_source = agent.current_realm(gc).abstract_module_source(agent);
+    let property_key = PropertyKey::from_static_str(agent, "AbstractModuleSource", gc);
+    unwrap_try(nova_obj.try_define_own_property(
+        agent,
+        property_key,
+        PropertyDescriptor::new_data_descriptor(abstract_module_source),
+        None,
+        gc,
+    ));
+
     /// `start` function
     fn start<'gc>(
         agent: &mut Agent,
@@ -324,6 +334,7 @@ pub fn initialize_global_object_with_internals(agent: &mut Agent, global: Object
                     print_internals: false,
                     // Always allow children to block.
                     no_block: false,
+                    ..Default::default()
                 },
                 child_hooks,
             );
@@ -184,6 +184,13 @@ impl HostHooks for CliHostHooks {
         finish_loading_imported_module(agent, referrer, module_request, payload, result, gc);
     }
 
+    fn get_source_name(&self, host_defined: Option<HostDefined>) -> Option<String> {
+        // Scripts and modules loaded from files have their path as
+        // [[HostDefined]].
+        let path = host_defined?.downcast::<PathBuf>().ok()?;
+        Some(path.display().to_string())
+    }
+
     fn get_host_data(&self) -> &dyn std::any::Any {
         self
     }
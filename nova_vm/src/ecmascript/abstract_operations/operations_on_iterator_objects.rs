@@ -73,6 +73,40 @@ pub(crate) fn get_iterator_direct<'gc>(
     Ok(Some(iterator_record))
 }
 
+/// ### [7.4.2 GetIteratorDirect ( obj )](https://tc39.es/ecma262/#sec-getiteratordirect)
+///
+/// Variant of GetIteratorDirect that keeps the iterator object even if its
+/// next method isn't callable. This is needed by lazy operations which only
+/// throw once the next method is actually called.
+pub(crate) fn get_maybe_invalid_iterator_direct<'gc>(
+    agent: &mut Agent,
+    obj: Object,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, MaybeInvalidIteratorRecord<'gc>> {
+    let obj = obj.bind(gc.nogc());
+
+    let scoped_obj = obj.scope(agent, gc.nogc());
+    // 1. Let nextMethod be ? Get(obj, "next").
+    let next_method = get(
+        agent,
+        obj.unbind(),
+        BUILTIN_STRING_MEMORY.next.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    // SAFETY: not shared.
+    let iterator = unsafe { scoped_obj.take(agent) }.bind(gc);
+    let next_method = is_callable(next_method, gc);
+
+    // 2. Let iteratorRecord be the Iterator Record { [[Iterator]]: obj, [[NextMethod]]: nextMethod, [[Done]]: false }.
+    // 3. Return iteratorRecord.
+    Ok(MaybeInvalidIteratorRecord {
+        iterator,
+        next_method,
+    })
+}
+
 pub(crate) struct MaybeInvalidIteratorRecord<'a> {
     pub(crate) iterator: Object<'a>,
     pub(crate) next_method: Option<Function<'a>>,
@@ -172,84 +206,111 @@ pub(crate) fn get_iterator_from_method<'a>(
 /// The abstract operation GetIterator takes arguments obj (an ECMAScript
 /// language value) and kind (sync or async) and returns either a normal
 /// completion containing an Iterator Record or a throw completion.
+///
+/// This function performs the sync version of the operation. Async iteration
+/// wraps sync iterators using CreateAsyncFromSyncIterator, which is performed
+/// by `VmIteratorRecord` in the interpreter and by Array.fromAsync.
 pub(crate) fn get_iterator<'a>(
     agent: &mut Agent,
     obj: Value,
-    is_async: bool,
     mut gc: GcScope<'a, '_>,
 ) -> JsResult<'a, MaybeInvalidIteratorRecord<'a>> {
     let obj = obj.bind(gc.nogc());
     let scoped_obj = obj.scope(agent, gc.nogc());
-    // 1. If kind is async, then
-    let method = if is_async {
-        // a. Let method be ? GetMethod(obj, @@asyncIterator).
-        let method = get_method(
-            agent,
-            obj.unbind(),
-            PropertyKey::Symbol(WellKnownSymbols::AsyncIterator.into()),
-            gc.reborrow(),
-        )
-        .unbind()?
-        .bind(gc.nogc());
+    // 2. Else,
+    // a. Let method be ? GetMethod(obj, @@iterator).
+    let method = get_method(
+        agent,
+        obj.unbind(),
+        PropertyKey::Symbol(WellKnownSymbols::Iterator.into()),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
 
-        // b. If method is undefined, then
-        if method.is_none() {
-            // i. Let syncMethod be ? GetMethod(obj, @@iterator).
-            let Some(sync_method) = get_method(
-                agent,
-                scoped_obj.get(agent),
-                PropertyKey::Symbol(WellKnownSymbols::Iterator.into()),
-                gc.reborrow(),
-            )
-            .unbind()?
-            .bind(gc.nogc()) else {
-                // ii. If syncMethod is undefined, throw a TypeError exception.
-                return Err(agent.throw_exception_with_static_message(
-                    ExceptionType::TypeError,
-                    "No iterator on object",
-                    gc.into_nogc(),
-                ));
-            };
+    // 3. If method is undefined, throw a TypeError exception.
+    let Some(method) = method else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Iterator method cannot be undefined",
+            gc.into_nogc(),
+        ));
+    };
 
-            // iii. Let syncIteratorRecord be ? GetIteratorFromMethod(obj, syncMethod).
-            let _sync_iterator_record = get_iterator_from_method(
-                agent,
-                scoped_obj.get(agent),
-                sync_method.unbind(),
-                gc.reborrow(),
-            )
-            .unbind()?
-            .bind(gc.nogc());
+    // 4. Return ? GetIteratorFromMethod(obj, method).
+    get_iterator_from_method(agent, scoped_obj.get(agent), method.unbind(), gc)
+}
 
-            // iv. Return CreateAsyncFromSyncIterator(syncIteratorRecord).
-            todo!("Implement create_async_from_sync_iterator(sync_iterator_record)")
-        } else {
-            method
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PrimitiveHandling {
+    IterateStringPrimitives,
+    RejectPrimitives,
+}
+
+/// ### [7.4.5 GetIteratorFlattenable ( obj, primitiveHandling )](https://tc39.es/ecma262/#sec-getiteratorflattenable)
+///
+/// The abstract operation GetIteratorFlattenable takes arguments obj (an
+/// ECMAScript language value) and primitiveHandling (iterate-string-primitives
+/// or reject-primitives) and returns either a normal completion containing an
+/// Iterator Record or a throw completion.
+///
+/// Note: Different from the spec, the returned record's next method is None
+/// if the iterator object's next method isn't callable.
+pub(crate) fn get_iterator_flattenable<'a>(
+    agent: &mut Agent,
+    obj: Value,
+    primitive_handling: PrimitiveHandling,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, MaybeInvalidIteratorRecord<'a>> {
+    let obj = obj.bind(gc.nogc());
+    // 1. If obj is not an Object, then
+    if !obj.is_object() {
+        // a. If primitiveHandling is reject-primitives, throw a TypeError exception.
+        // b. Assert: primitiveHandling is iterate-string-primitives.
+        // c. If obj is not a String, throw a TypeError exception.
+        if primitive_handling == PrimitiveHandling::RejectPrimitives || !obj.is_string() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Value is not an object",
+                gc.into_nogc(),
+            ));
         }
+    }
+    let scoped_obj = obj.scope(agent, gc.nogc());
+    // 2. Let method be ? GetMethod(obj, %Symbol.iterator%).
+    let method = get_method(
+        agent,
+        obj.unbind(),
+        PropertyKey::Symbol(WellKnownSymbols::Iterator.into()),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // SAFETY: not shared.
+    let obj = unsafe { scoped_obj.take(agent) }.bind(gc.nogc());
+    let iterator = if let Some(method) = method {
+        // 4. Else,
+        // a. Let iterator be ? Call(method, obj).
+        call_function(agent, method.unbind(), obj.unbind(), None, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc())
     } else {
-        // 2. Else,
-        // a. Let method be ? GetMethod(obj, @@iterator).
-        get_method(
-            agent,
-            obj.unbind(),
-            PropertyKey::Symbol(WellKnownSymbols::Iterator.into()),
-            gc.reborrow(),
-        )
-        .unbind()?
-        .bind(gc.nogc())
+        // 3. If method is undefined, then
+        // a. Let iterator be obj.
+        obj
     };
 
-    // 3. If method is undefined, throw a TypeError exception.
-    let Some(method) = method else {
+    // 5. If iterator is not an Object, throw a TypeError exception.
+    let Ok(iterator) = Object::try_from(iterator) else {
         return Err(agent.throw_exception_with_static_message(
             ExceptionType::TypeError,
-            "Iterator method cannot be undefined",
+            "Iterator is not an object",
             gc.into_nogc(),
         ));
     };
 
-    // 4. Return ? GetIteratorFromMethod(obj, method).
-    get_iterator_from_method(agent, scoped_obj.get(agent), method.unbind(), gc)
+    // 6. Return ? GetIteratorDirect(iterator).
+    get_maybe_invalid_iterator_direct(agent, iterator.unbind(), gc)
 }
 
 /// ### [7.4.6 IteratorNext ( iteratorRecord [ , value ] )](https://tc39.es/ecma262/#sec-iteratornext)
@@ -1981,6 +1981,7 @@ pub(crate) fn get_function_realm<'a, 'gc>(
     match obj {
         Object::BuiltinFunction(f) => Ok(f.realm(agent)),
         Object::ECMAScriptFunction(f) => Ok(f.realm(agent)),
+        Object::BuiltinProxyRevokerFunction(f) => Ok(f.realm(agent)),
         Object::BoundFunction(f) => {
             // 2. If obj is a bound function exotic object, then
             // a. Let boundTargetFunction be obj.[[BoundTargetFunction]].
@@ -2022,6 +2023,7 @@ pub(crate) fn try_get_function_realm<'a, 'gc>(
     match obj {
         Object::BuiltinFunction(f) => Some(f.realm(agent)),
         Object::ECMAScriptFunction(f) => Some(f.realm(agent)),
+        Object::BuiltinProxyRevokerFunction(f) => Some(f.realm(agent)),
         Object::BoundFunction(f) => {
             // 2. If obj is a bound function exotic object, then
             // a. Let boundTargetFunction be obj.[[BoundTargetFunction]].
@@ -2817,7 +2819,7 @@ pub(crate) fn group_by_property<'gc>(
     let MaybeInvalidIteratorRecord {
         iterator,
         next_method: Some(next_method),
-    } = get_iterator(agent, items.unbind(), false, gc.reborrow())
+    } = get_iterator(agent, items.unbind(), gc.reborrow())
         .unbind()?
         .bind(gc.nogc())
     else {
@@ -2951,7 +2953,7 @@ pub(crate) fn group_by_collection<'gc>(
     let MaybeInvalidIteratorRecord {
         iterator,
         next_method: Some(next_method),
-    } = get_iterator(agent, items.unbind(), false, gc.reborrow())
+    } = get_iterator(agent, items.unbind(), gc.reborrow())
         .unbind()?
         .bind(gc.nogc())
     else {
@@ -3114,3 +3116,34 @@ pub(crate) fn setter_that_ignores_prototype_properties<'a>(
     }
     // 6. Return unused.
 }
+
+/// ### [14.5.2.1 GetOptionsObject ( options )](https://tc39.es/proposal-temporal/#sec-getoptionsobject)
+///
+/// The abstract operation GetOptionsObject takes argument options (an ECMAScript language value)
+/// and returns either a normal completion containing an Object or a throw completion. It returns
+/// an Object suitable for use with GetOption, either options itself or a default empty Object. It
+/// throws a TypeError if options is not undefined and not an Object. It performs the following
+/// steps when called:
+pub(crate) fn get_options_object<'gc>(
+    agent: &mut Agent,
+    options: Value,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, Option<Object<'gc>>> {
+    let options = options.bind(gc);
+    // 1. If options is undefined, then
+    if options.is_undefined() {
+        // a. Return OrdinaryObjectCreate(null).
+        Ok(None)
+    } else if let Ok(options) = Object::try_from(options) {
+        // 2. If options is an Object, then
+        // a. Return options.
+        Ok(Some(options))
+    } else {
+        // 3. Throw a TypeError exception.
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "options provided to GetOptionsObject is not an object",
+            gc,
+        ))
+    }
+}
@@ -483,7 +483,6 @@ impl PartialOrd<IntegerOrInfinity> for i64 {
 trivially_bindable!(IntegerOrInfinity);
 
 /// ### [7.1.5 ToIntegerOrInfinity ( argument )](https://tc39.es/ecma262/#sec-tointegerorinfinity)
-#[cfg(feature = "date")]
 pub(crate) fn to_integer_or_infinity_f64(number: f64) -> f64 {
     // `ToIntegerOrInfinity ( argument )`
     if number.is_nan() || number == 0.0 {
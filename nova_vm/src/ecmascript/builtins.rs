@@ -42,6 +42,7 @@ mod control_abstraction_objects;
 mod data_view;
 #[cfg(feature = "date")]
 mod date;
+mod disposable_stack;
 mod ecmascript_function;
 mod embedder_object;
 mod error;
@@ -49,6 +50,7 @@ mod finalization_registry;
 mod fundamental_objects;
 mod global_object;
 mod indexed_collections;
+mod iterator_helper;
 mod keyed_collections;
 mod managing_memory;
 mod map;
@@ -58,6 +60,8 @@ mod ordinary;
 mod primitive_objects;
 mod promise;
 mod proxy;
+#[cfg(feature = "json")]
+mod raw_json;
 mod reflection;
 #[cfg(feature = "regexp")]
 mod regexp;
@@ -90,6 +94,7 @@ pub use control_abstraction_objects::*;
 pub use data_view::*;
 #[cfg(feature = "date")]
 pub use date::*;
+pub use disposable_stack::*;
 pub use ecmascript_function::*;
 pub use embedder_object::*;
 pub use error::*;
@@ -97,6 +102,7 @@ pub use finalization_registry::*;
 pub(crate) use fundamental_objects::*;
 pub(crate) use global_object::*;
 pub use indexed_collections::*;
+pub use iterator_helper::*;
 pub use keyed_collections::*;
 pub(crate) use managing_memory::*;
 pub use map::*;
@@ -106,6 +112,8 @@ pub use ordinary::*;
 pub use primitive_objects::*;
 pub use promise::*;
 pub use proxy::*;
+#[cfg(feature = "json")]
+pub use raw_json::*;
 pub(crate) use reflection::*;
 #[cfg(feature = "regexp")]
 pub use regexp::*;
@@ -67,8 +67,6 @@
 //! extension any longer, ECMAScript 2017 dropped the requirement for a throwing
 //! "caller" accessor.
 
-use std::collections::TryReserveError;
-
 use ahash::AHashMap;
 
 use crate::{
@@ -78,7 +76,7 @@ use crate::{
     },
     engine::{Bindable, HeapRootData, NoGcScope, bindable_handle},
     heap::{
-        CompactionLists, DirectArenaAccess, ElementDescriptor, HeapMarkAndSweep,
+        AllocationError, CompactionLists, DirectArenaAccess, ElementDescriptor, HeapMarkAndSweep,
         HeapSweepWeakReference, WellKnownSymbols, WorkQueues,
     },
 };
@@ -105,7 +103,7 @@ pub(crate) fn create_unmapped_arguments_object<'a, 'b>(
     agent: &mut Agent,
     arguments_list: &ScopedArgumentsList<'b>,
     gc: NoGcScope<'a, 'b>,
-) -> Result<UnmappedArguments<'a>, TryReserveError> {
+) -> Result<UnmappedArguments<'a>, AllocationError> {
     // 1. Let len be the number of elements in argumentsList.
     let len = arguments_list.len(agent);
     // SAFETY: GC is not allowed in this scope, and no other scoped values are
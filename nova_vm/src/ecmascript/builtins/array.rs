@@ -11,21 +11,22 @@ pub(crate) use abstract_operations::*;
 pub(crate) use data::*;
 
 use core::ops::RangeInclusive;
-use std::collections::{TryReserveError, hash_map::Entry};
+use std::collections::hash_map::Entry;
 
 use crate::{
     ecmascript::{
         Agent, BUILTIN_STRING_MEMORY, Caches, Function, InternalMethods, InternalSlots, JsResult,
         Object, OrdinaryObject, PropertyDescriptor, PropertyKey, ProtoIntrinsics, TryError,
         TryGetResult, TryHasResult, TryResult, Value, call_function, create_array_from_list,
-        js_result_into_try, object_handle, ordinary_define_own_property, same_value, unwrap_try,
+        js_result_into_try, object_handle, ordinary_define_own_property, same_value,
+        try_result_into_js, unwrap_try,
     },
     engine::{Bindable, GcScope, NoGcScope},
     heap::{
-        ArenaAccessSoA, ArenaAccessSoAMut, BaseIndex, CompactionLists, CreateHeapData,
-        ElementArrays, ElementDescriptor, ElementStorageMut, ElementStorageRef, ElementsVector,
-        Heap, HeapIndexHandle, HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues,
-        arena_vec_access,
+        AllocationError, Arena, ArenaAccessSoA, ArenaAccessSoAMut, BaseIndex, CompactionLists,
+        CreateHeapData, ElementArrays, ElementDescriptor, ElementStorageMut, ElementStorageRef,
+        ElementsVector, Heap, HeapIndexHandle, HeapMarkAndSweep, HeapSweepWeakReference,
+        SparseElements, WorkQueues, arena_vec_access,
     },
 };
 
@@ -41,9 +42,10 @@ use super::{
 ///
 /// ## Support status
 ///
-/// `Array` in Nova does not support sparse storage. This means that setting the
-/// `length` property will grow the `Array`'s backing storage to the requested
-/// size or larger.
+/// `Array` in Nova switches to sparse storage when its `length` far exceeds
+/// the number of elements present in it, and back to dense storage when it
+/// fills up again. Operations that work on the element slice directly treat
+/// sparse Arrays as neither dense, simple, nor trivial.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct Array<'a>(BaseIndex<'a, ArrayHeapData<'static>>);
@@ -81,7 +83,7 @@ impl<'a> Array<'a> {
         agent: &mut Agent,
         capacity: u32,
         gc: NoGcScope<'a, '_>,
-    ) -> Result<Self, TryReserveError> {
+    ) -> Result<Self, AllocationError> {
         let elements = agent
             .heap
             .elements
@@ -100,7 +102,7 @@ impl<'a> Array<'a> {
     /// > Note: this should only be used in places where the next index is
     /// > populated using code such as `! CreateDataPropertyOrThrow(A, "0", S)`,
     /// > ie. when the operation is known to be infallible.
-    pub fn push(self, agent: &mut Agent, value: Value<'a>) -> Result<(), TryReserveError> {
+    pub fn push(self, agent: &mut Agent, value: Value<'a>) -> Result<(), AllocationError> {
         agent
             .heap
             .arrays
@@ -111,7 +113,7 @@ impl<'a> Array<'a> {
     }
 
     /// Reserve space for `additional` Values in the Array.
-    pub fn reserve(self, agent: &mut Agent, additional: u32) -> Result<(), TryReserveError> {
+    pub fn reserve(self, agent: &mut Agent, additional: u32) -> Result<(), AllocationError> {
         let Heap {
             arrays, elements, ..
         } = &mut agent.heap;
@@ -132,7 +134,7 @@ impl<'a> Array<'a> {
 
     pub(crate) fn get_elements_mut(
         self,
-        agent: &mut impl AsMut<SoAVec<ArrayHeapData<'static>>>,
+        agent: &mut impl AsMut<Arena<SoAVec<ArrayHeapData<'static>>>>,
     ) -> &mut ElementsVector<'static> {
         agent
             .as_mut()
@@ -200,6 +202,50 @@ impl<'a> Array<'a> {
         self.get_elements(agent).is_trivial(agent)
     }
 
+    /// An array is sparse if it only stores the elements present in it.
+    ///
+    /// A sparse array has no element slice: [`Array::as_slice`] and
+    /// [`Array::get_storage`] must not be called on it.
+    pub(crate) fn is_sparse(self, agent: &impl ArrayHeapAccess<'a>) -> bool {
+        self.get_elements(agent).is_sparse()
+    }
+
+    /// Get the sparse storage of the Array if HasProperty cannot observe the
+    /// holes in it: that is, if the Array is sparse and no object in its
+    /// prototype chain has indexed properties.
+    ///
+    /// Operations that skip holes can then iterate over the elements present
+    /// in the Array instead of over its whole length.
+    pub(crate) fn get_sparse_with_unobservable_holes(
+        self,
+        agent: &Agent,
+    ) -> Option<&SparseElements> {
+        let elements = self.get_elements(agent);
+        if !elements.is_sparse() {
+            return None;
+        }
+        let mut prototype = self.internal_prototype(agent);
+        while let Some(object) = prototype {
+            let has_indexed_properties = match object {
+                // Note: the indexed properties of an Array are its elements.
+                Object::Array(array) => !array.is_empty(agent),
+                Object::Object(object) => object
+                    .get_property_storage(agent)
+                    .keys
+                    .iter()
+                    .any(|key| key.is_array_index()),
+                // Exotic objects, eg. String objects and Proxies, may have
+                // indexed properties that are not in their property storage.
+                _ => true,
+            };
+            if has_indexed_properties {
+                return None;
+            }
+            prototype = object.internal_prototype(agent);
+        }
+        Some(elements.get_sparse(agent))
+    }
+
     /// Returns the `value` as an Array if it is one `method` is
     /// `%Array.prototype.values%`.
     pub(crate) fn is_iterable_array(
@@ -271,6 +317,31 @@ impl<'a> Array<'a> {
         agent.heap.create(data)
     }
 
+    /// Get the value of the element at `index` if it is present and not an
+    /// accessor property.
+    pub(crate) fn get_data_value(
+        self,
+        arena: &impl ArrayHeapAccess<'a>,
+        index: u32,
+    ) -> Option<Value<'a>> {
+        let elements = self.get_elements(arena);
+        if elements.is_sparse() {
+            elements.get_sparse(arena).get(index)?.0
+        } else {
+            elements
+                .get_storage(arena)
+                .values
+                .get(index as usize)
+                .copied()
+                .flatten()
+        }
+    }
+
+    /// Get the element slice of a dense array.
+    ///
+    /// ## Panics
+    ///
+    /// In debug builds, if the array is sparse.
     #[inline]
     pub(crate) fn as_slice(self, arena: &impl ArrayHeapAccess<'a>) -> &[Option<Value<'a>>] {
         let elvec = self.get_elements(arena);
@@ -374,6 +445,16 @@ impl<'a> InternalMethods<'a> for Array<'a> {
                 // Out of bounds
                 return TryResult::Continue(None);
             }
+            if elements.is_sparse() {
+                let Some((value, descriptor)) = elements.get_sparse(agent).get(index) else {
+                    // Hole
+                    return TryResult::Continue(None);
+                };
+                return TryResult::Continue(Some(ElementDescriptor::to_property_descriptor(
+                    descriptor.as_ref(),
+                    value.bind(gc),
+                )));
+            }
             let ElementStorageRef {
                 values,
                 descriptors,
@@ -430,6 +511,14 @@ impl<'a> InternalMethods<'a> for Array<'a> {
             // b. Assert: IsDataDescriptor(lengthDesc) is true.
             // c. Assert: lengthDesc.[[Configurable]] is false.
             // d. Let length be lengthDesc.[[Value]].
+            if self.is_sparse(agent)
+                && let Err(err) = agent.check_heap_limit(gc)
+            {
+                // Sparse storage grows one element at a time without going
+                // through the backing store size checks; check the heap size
+                // limit here instead.
+                return err.into();
+            }
             let elements = self.get_elements(agent);
             let length = elements.len();
             let length_writable = elements.len_writable;
@@ -450,7 +539,7 @@ impl<'a> InternalMethods<'a> for Array<'a> {
                     elements: elems,
                     object_index: backing_object,
                 } = self.get_mut(arrays);
-                if elems.reserve(elements, index + 1).is_err() {
+                if elems.reserve_holey(elements, index + 1).is_err() {
                     return TryError::GcError.into();
                 }
                 let mut value = property_descriptor.value;
@@ -475,6 +564,7 @@ impl<'a> InternalMethods<'a> for Array<'a> {
                 // i. Set lengthDesc.[[Value]] to index + 1𝔽.
                 // This should've already been handled by the push.
                 debug_assert_eq!(elems.len(), index + 1);
+                elems.maybe_make_dense(elements);
                 if let Some(shape) = backing_object.map(|o| o.object_shape(agent))
                     && shape.is_intrinsic(agent)
                 {
@@ -530,13 +620,16 @@ impl<'a> InternalMethods<'a> for Array<'a> {
         if property_key == PropertyKey::from(BUILTIN_STRING_MEMORY.length) {
             array_set_length(agent, self, property_descriptor.unbind(), gc)
         } else {
-            Ok(unwrap_try(self.try_define_own_property(
+            // Note: defining an element never calls into JavaScript, but it
+            // can throw on allocation failure.
+            try_result_into_js(self.try_define_own_property(
                 agent,
                 property_key.unbind(),
                 property_descriptor.unbind(),
                 None,
                 gc.into_nogc(),
-            )))
+            ))
+            .map(Option::unwrap)
         }
     }
 
@@ -553,8 +646,13 @@ impl<'a> InternalMethods<'a> for Array<'a> {
         } else if let Some(index) = property_key.into_u32() {
             // Within possible Array bounds: the data is found in the Array
             // elements storage.
-            let values = array.as_slice(agent);
-            if index < values.len() as u32 {
+            let elements = array.get_elements(agent);
+            if elements.is_sparse() && elements.get_sparse(agent).values.contains_key(&index) {
+                return TryHasResult::Custom(index, array.into()).into();
+            }
+            // Sparse storage has no value slice: absent elements are holes.
+            if !elements.is_sparse() && index < elements.len() {
+                let values = array.as_slice(agent);
                 // Within the Array slice: first check values as checking
                 // descriptors requires a hash calculation.
                 if values[index as usize].is_some() {
@@ -598,8 +696,13 @@ impl<'a> InternalMethods<'a> for Array<'a> {
         } else if let Some(index) = property_key.into_u32() {
             // Within possible Array bounds: the data is found in the Array
             // elements storage.
-            let values = self.as_slice(agent);
-            if index < values.len() as u32 {
+            let elements = self.get_elements(agent);
+            if elements.is_sparse() && elements.get_sparse(agent).values.contains_key(&index) {
+                return Ok(true);
+            }
+            // Sparse storage has no value slice: absent elements are holes.
+            if !elements.is_sparse() && index < elements.len() {
+                let values = self.as_slice(agent);
                 // Within the Array slice: first check values as checking
                 // descriptors requires a hash calculation.
                 if values[index as usize].is_some() {
@@ -660,8 +763,22 @@ impl<'a> InternalMethods<'a> for Array<'a> {
         if property_key == PropertyKey::from(BUILTIN_STRING_MEMORY.length) {
             return TryGetResult::Value(array.len(agent).into()).into();
         } else if let Some(index) = property_key.into_u32() {
-            let values = array.as_slice(agent);
-            if index < values.len() as u32 {
+            let elements = array.get_elements(agent);
+            if elements.is_sparse()
+                && let Some((value, descriptor)) = elements.get_sparse(agent).get(index)
+            {
+                return if let Some(value) = value {
+                    TryGetResult::Value(value.bind(gc)).into()
+                } else if let Some(getter) = descriptor.and_then(|d| d.getter_function(gc)) {
+                    TryGetResult::Get(getter).into()
+                } else {
+                    // Accessor with no getter.
+                    TryGetResult::Value(Value::Undefined).into()
+                };
+            }
+            // Sparse storage has no value slice: absent elements are holes.
+            if !elements.is_sparse() && index < elements.len() {
+                let values = array.as_slice(agent);
                 // Index has been checked to be between 0 <= idx < len;
                 // indexing should never fail.
                 let element = values[index as usize];
@@ -711,8 +828,22 @@ impl<'a> InternalMethods<'a> for Array<'a> {
         if property_key == PropertyKey::from(BUILTIN_STRING_MEMORY.length) {
             return Ok(array.len(agent).into());
         } else if let Some(index) = property_key.into_u32() {
-            let values = array.as_slice(agent);
-            if index < values.len() as u32 {
+            let elements = array.get_elements(agent);
+            if elements.is_sparse()
+                && let Some((value, descriptor)) = elements.get_sparse(agent).get(index)
+            {
+                return if let Some(value) = value {
+                    Ok(value)
+                } else if let Some(getter) = descriptor.and_then(|d| d.getter_function(nogc)) {
+                    call_function(agent, getter.unbind(), receiver.unbind(), None, gc)
+                } else {
+                    // Accessor with no getter.
+                    Ok(Value::Undefined)
+                };
+            }
+            // Sparse storage has no value slice: absent elements are holes.
+            if !elements.is_sparse() && index < elements.len() {
+                let values = array.as_slice(agent);
                 // Index has been checked to be between 0 <= idx < len;
                 // indexing should never fail.
                 let element = values[index as usize];
@@ -774,6 +905,19 @@ impl<'a> InternalMethods<'a> for Array<'a> {
             if index >= elements.len() {
                 return TryResult::Continue(true);
             }
+            if elements.is_sparse() {
+                let sparse = elements.get_sparse_mut(&mut agent.heap.elements);
+                if sparse
+                    .descriptors
+                    .get(&index)
+                    .is_some_and(|descriptor| !descriptor.is_configurable())
+                {
+                    // Unconfigurable property.
+                    return TryResult::Continue(false);
+                }
+                sparse.remove(index);
+                return TryResult::Continue(true);
+            }
             let ElementStorageMut {
                 values,
                 descriptors,
@@ -813,10 +957,20 @@ impl<'a> InternalMethods<'a> for Array<'a> {
         } else {
             Default::default()
         };
+        let elements = self.get_elements(agent);
+        if elements.is_sparse() {
+            let sparse = elements.get_sparse(agent);
+            let mut keys = Vec::with_capacity(sparse.values.len() + 1 + backing_keys.len());
+            // Sparse elements are stored in ascending index order.
+            keys.extend(sparse.values.keys().map(|&index| PropertyKey::from(index)));
+            keys.push(BUILTIN_STRING_MEMORY.length.to_property_key());
+            keys.extend(backing_keys);
+            return TryResult::Continue(keys);
+        }
         let ElementStorageRef {
             values,
             descriptors,
-        } = self.get_elements(agent).get_storage(agent);
+        } = elements.get_storage(agent);
         let mut keys = Vec::with_capacity(values.len() + 1 + backing_keys.len());
 
         for (index, value) in values.iter().enumerate() {
@@ -881,6 +1035,15 @@ fn invalidate_array_index_caches(agent: &mut Agent, array: Array, index: u32, gc
     }
 }
 
+/// Helper to switch a sparse Array back to dense storage after a hole in it
+/// has been filled.
+fn maybe_make_array_dense(agent: &mut Agent, array: Array) {
+    let Heap {
+        arrays, elements, ..
+    } = &mut agent.heap;
+    array.get_elements_mut(arrays).maybe_make_dense(elements);
+}
+
 fn ordinary_define_own_property_for_array(
     agent: &mut Agent,
     array: Array,
@@ -891,18 +1054,25 @@ fn ordinary_define_own_property_for_array(
 ) -> bool {
     let descriptor_value = descriptor.value;
 
-    let ElementStorageRef {
-        values,
-        descriptors,
-    } = agent.heap.elements.get_element_storage(&elements);
-    let current_value = values[index as usize];
-    let current_descriptor = {
-        let descriptor = descriptors.and_then(|descriptors| descriptors.get(&index).copied());
-        if current_value.is_some() && descriptor.is_none() {
-            Some(ElementDescriptor::WritableEnumerableConfigurableData)
-        } else {
-            descriptor
-        }
+    let (current_value, stored_descriptor) = if elements.is_sparse() {
+        elements
+            .get_sparse(agent)
+            .get(index)
+            .unwrap_or((None, None))
+    } else {
+        let ElementStorageRef {
+            values,
+            descriptors,
+        } = agent.heap.elements.get_element_storage(&elements);
+        (
+            values[index as usize],
+            descriptors.and_then(|descriptors| descriptors.get(&index).copied()),
+        )
+    };
+    let current_descriptor = if current_value.is_some() && stored_descriptor.is_none() {
+        Some(ElementDescriptor::WritableEnumerableConfigurableData)
+    } else {
+        stored_descriptor
     };
 
     // 2. If current is undefined, then
@@ -923,6 +1093,7 @@ fn ordinary_define_own_property_for_array(
             let elem_descriptor = ElementDescriptor::from_accessor_descriptor(descriptor);
             insert_element_descriptor(agent, &elements, index, None, elem_descriptor);
             invalidate_array_index_caches(agent, array, index, gc);
+            maybe_make_array_dense(agent, array);
         }
         // d. Else,
         else {
@@ -938,6 +1109,7 @@ fn ordinary_define_own_property_for_array(
                 ElementDescriptor::from_data_descriptor(descriptor),
             );
             invalidate_array_index_caches(agent, array, index, gc);
+            maybe_make_array_dense(agent, array);
         }
 
         // e. Return true.
@@ -1094,6 +1266,10 @@ fn mutate_data_descriptor(
 ) {
     if let Some(descriptor) = elem_descriptor {
         insert_element_descriptor(agent, elements, index, descriptor_value, descriptor);
+    } else if elements.is_sparse() {
+        elements
+            .get_sparse_mut(agent)
+            .set(index, descriptor_value, None);
     } else {
         let ElementStorageMut {
             values,
@@ -1119,6 +1295,8 @@ fn mutate_element_descriptor(
 ) {
     if let Some(descriptor) = elem_descriptor {
         insert_element_descriptor(agent, elements, index, descriptor_value, descriptor);
+    } else if elements.is_sparse() {
+        elements.get_sparse_mut(agent).descriptors.remove(&index);
     } else if let ElementStorageMut {
         descriptors: Entry::Occupied(mut descriptors),
         ..
@@ -1141,6 +1319,13 @@ fn insert_data_descriptor(
 ) {
     if let Some(descriptor) = elem_descriptor {
         insert_element_descriptor(agent, elements, index, descriptor_value, descriptor);
+    } else if elements.is_sparse() {
+        agent.heap.alloc_counter += core::mem::size_of::<(u32, Option<Value>)>();
+        elements.get_sparse_mut(agent).set(
+            index,
+            Some(descriptor_value.unwrap_or(Value::Undefined)),
+            None,
+        );
     } else {
         agent.heap.alloc_counter += core::mem::size_of::<Option<Value>>();
         agent.heap.elements.get_values_mut(elements)[index as usize] =
@@ -1155,6 +1340,15 @@ fn insert_element_descriptor(
     descriptor_value: Option<Value>,
     descriptor: ElementDescriptor,
 ) {
+    if elements.is_sparse() {
+        let sparse = elements.get_sparse_mut(agent);
+        let inserted = !sparse.descriptors.contains_key(&index);
+        sparse.set(index, descriptor_value, Some(descriptor));
+        if inserted {
+            agent.heap.alloc_counter += core::mem::size_of::<(u32, ElementDescriptor)>();
+        }
+        return;
+    }
     let ElementStorageMut {
         values,
         descriptors,
@@ -1180,14 +1374,14 @@ fn insert_element_descriptor(
 /// A partial view to the Agent's Heap that allows accessing array heap data.
 pub(crate) struct ArrayHeap<'a> {
     elements: &'a mut ElementArrays,
-    arrays: &'a mut SoAVec<ArrayHeapData<'static>>,
+    arrays: &'a mut Arena<SoAVec<ArrayHeapData<'static>>>,
 }
 
 impl ArrayHeap<'_> {
     #[inline(always)]
     pub(crate) fn new<'a>(
         elements: &'a mut ElementArrays,
-        arrays: &'a mut SoAVec<ArrayHeapData<'static>>,
+        arrays: &'a mut Arena<SoAVec<ArrayHeapData<'static>>>,
     ) -> ArrayHeap<'a> {
         ArrayHeap { elements, arrays }
     }
@@ -1207,9 +1401,9 @@ impl AsRef<ElementArrays> for ArrayHeap<'_> {
     }
 }
 
-impl AsMut<SoAVec<ArrayHeapData<'static>>> for ArrayHeap<'_> {
+impl AsMut<Arena<SoAVec<ArrayHeapData<'static>>>> for ArrayHeap<'_> {
     #[inline(always)]
-    fn as_mut(&mut self) -> &mut SoAVec<ArrayHeapData<'static>> {
+    fn as_mut(&mut self) -> &mut Arena<SoAVec<ArrayHeapData<'static>>> {
         self.arrays
     }
 }
@@ -1225,7 +1419,7 @@ impl AsMut<ElementArrays> for ArrayHeap<'_> {
 pub(crate) trait ArrayHeapAccess<'a>:
     AsRef<SoAVec<ArrayHeapData<'static>>>
     + AsRef<ElementArrays>
-    + AsMut<SoAVec<ArrayHeapData<'static>>>
+    + AsMut<Arena<SoAVec<ArrayHeapData<'static>>>>
     + AsMut<ElementArrays>
 {
 }
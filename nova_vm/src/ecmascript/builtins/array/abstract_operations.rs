@@ -2,7 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use std::collections::{TryReserveError, hash_map::Entry};
+use std::collections::hash_map::Entry;
 
 use crate::{
     ecmascript::{
@@ -12,7 +12,7 @@ use crate::{
         to_uint32_number,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable},
-    heap::{CreateHeapData, ElementStorageMut, Heap, WellKnownSymbols},
+    heap::{AllocationError, CreateHeapData, ElementStorageMut, Heap, WellKnownSymbols},
 };
 
 /// ### [10.4.2.2 ArrayCreate ( length \[ , proto \] )](https://tc39.es/ecma262/#sec-arraycreate)
@@ -300,7 +300,7 @@ pub(crate) fn array_set_length_handling(
     desc_configurable: Option<bool>,
     desc_enumerable: Option<bool>,
     desc_writable: Option<bool>,
-) -> Result<bool, TryReserveError> {
+) -> Result<bool, AllocationError> {
     // 6. Set newLenDesc.[[Value]] to newLen.
     // 7. Let oldLenDesc be OrdinaryGetOwnProperty(A, "length").
     let Heap {
@@ -325,7 +325,7 @@ pub(crate) fn array_set_length_handling(
         ) {
             return Ok(false);
         }
-        elems.reserve(elements, new_len)?;
+        elems.reserve_holey(elements, new_len)?;
         elems.len = new_len;
         elems.len_writable = desc_writable.unwrap_or(old_len_writable);
         return Ok(true);
@@ -361,6 +361,26 @@ pub(crate) fn array_set_length_handling(
     ) {
         return Ok(false);
     }
+    if elems.is_sparse() {
+        // 17. For each own property key P of A such that P is an array index
+        //     and ! ToUint32(P) ≥ newLen, in descending numeric index order, do
+        // Note: sparse storage only visits the present elements.
+        let succeeded = match elems.get_sparse_mut(elements).truncate(new_len) {
+            Ok(()) => {
+                elems.len = new_len;
+                true
+            }
+            Err(i) => {
+                // i. Set newLenDesc.[[Value]] to ! ToUint32(P) + 1𝔽.
+                elems.len = i + 1;
+                false
+            }
+        };
+        // 18. If newWritable is false, then
+        elems.len_writable &= new_writable;
+        elems.maybe_make_dense(elements);
+        return Ok(succeeded);
+    }
     let ElementStorageMut {
         values,
         descriptors,
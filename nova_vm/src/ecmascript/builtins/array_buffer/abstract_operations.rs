@@ -4,17 +4,17 @@
 
 use ecmascript_atomics::Ordering;
 
-use super::{AnyArrayBuffer, ArrayBuffer, InternalBuffer};
+use super::{AnyArrayBuffer, ArrayBuffer, ArrayBufferHeapData, InternalBuffer};
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::SharedDataBlock;
 use crate::{
     ecmascript::{
         Agent, BUILTIN_STRING_MEMORY, ExceptionType, Function, JsResult, Numeric, Object,
         ProtoIntrinsics, Value, Viewable, create_byte_data_block, get,
-        ordinary_create_from_constructor, to_index,
+        ordinary_create_from_constructor, to_index, try_result_into_js, try_to_index,
     },
-    engine::{Bindable, GcScope, NoGcScope},
-    heap::{ArenaAccess, ArenaAccessMut},
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{ArenaAccess, ArenaAccessMut, CreateHeapData},
 };
 
 // TODO: Implement the contents of the `DetachKey` struct?
@@ -86,6 +86,132 @@ pub(crate) fn allocate_array_buffer<'a>(
     Ok(obj)
 }
 
+/// Resizability of the ArrayBuffer produced by [`array_buffer_copy_and_detach`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum PreserveResizability {
+    /// The new ArrayBuffer is resizable if the original ArrayBuffer is.
+    PreserveResizability,
+    /// The new ArrayBuffer is always fixed-length.
+    FixedLength,
+}
+
+/// ### [25.1.3.2 ArrayBufferCopyAndDetach ( arrayBuffer, newLength, preserveResizability )](https://tc39.es/ecma262/#sec-arraybuffercopyanddetach)
+///
+/// The abstract operation ArrayBufferCopyAndDetach takes arguments
+/// *arrayBuffer* (an ECMAScript language value), *newLength* (an ECMAScript
+/// language value), and *preserveResizability* (PRESERVE-RESIZABILITY or
+/// FIXED-LENGTH) and returns either a normal completion containing an
+/// ArrayBuffer or a throw completion.
+///
+/// > NOTE: Nova implements this operation as a move of the original Data
+/// > Block into the new ArrayBuffer, followed by a reallocation if the byte
+/// > length changes.
+pub(crate) fn array_buffer_copy_and_detach<'a>(
+    agent: &mut Agent,
+    array_buffer: Value,
+    new_length: Value,
+    preserve_resizability: PreserveResizability,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, ArrayBuffer<'a>> {
+    let new_length = new_length.bind(gc.nogc());
+    // 1. Perform ? RequireInternalSlot(arrayBuffer, [[ArrayBufferData]]).
+    // 2. If IsSharedArrayBuffer(arrayBuffer) is true, throw a TypeError
+    //    exception.
+    let Value::ArrayBuffer(mut array_buffer) = array_buffer.bind(gc.nogc()) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Expected this to be ArrayBuffer",
+            gc.into_nogc(),
+        ));
+    };
+    // 3. If newLength is undefined, then
+    let new_byte_length = if new_length.is_undefined() {
+        // a. Let newByteLength be arrayBuffer.[[ArrayBufferByteLength]].
+        array_buffer.byte_length(agent)
+    } else if let Some(new_byte_length) =
+        try_result_into_js(try_to_index(agent, new_length, gc.nogc())).unbind()?
+    {
+        new_byte_length as usize
+    } else {
+        // 4. Else,
+        // a. Let newByteLength be ? ToIndex(newLength).
+        let scoped_array_buffer = array_buffer.scope(agent, gc.nogc());
+        let new_byte_length = to_index(agent, new_length.unbind(), gc.reborrow()).unbind()?;
+        // SAFETY: not shared.
+        array_buffer = unsafe { scoped_array_buffer.take(agent) }.bind(gc.nogc());
+        new_byte_length as usize
+    };
+    let array_buffer = array_buffer.unbind();
+    let gc = gc.into_nogc();
+    let array_buffer = array_buffer.bind(gc);
+    // 5. If IsDetachedBuffer(arrayBuffer) is true, throw a TypeError
+    //    exception.
+    if array_buffer.is_detached(agent) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Cannot transfer a detached ArrayBuffer",
+            gc,
+        ));
+    }
+    // 6. If preserveResizability is PRESERVE-RESIZABILITY and
+    //    IsFixedLengthArrayBuffer(arrayBuffer) is false, then
+    let new_max_byte_length = if preserve_resizability == PreserveResizability::PreserveResizability
+        && array_buffer.is_resizable(agent)
+    {
+        // a. Let newMaxByteLength be arrayBuffer.[[ArrayBufferMaxByteLength]].
+        Some(array_buffer.max_byte_length(agent))
+    } else {
+        // 7. Else,
+        // a. Let newMaxByteLength be EMPTY.
+        None
+    };
+    // 8. If arrayBuffer.[[ArrayBufferDetachKey]] is not undefined, throw a
+    //    TypeError exception.
+    if array_buffer.get_detach_key(agent).is_some() {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Cannot transfer an ArrayBuffer with a detach key",
+            gc,
+        ));
+    }
+    // 9. Let newBuffer be ? AllocateArrayBuffer(%ArrayBuffer%, newByteLength,
+    //    newMaxByteLength).
+    if new_max_byte_length.is_some_and(|max| new_byte_length > max) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Byte length is over maximumm byte length",
+            gc,
+        ));
+    }
+    // 10. Let copyLength be min(newByteLength,
+    //     arrayBuffer.[[ArrayBufferByteLength]]).
+    // 11. Let fromBlock be arrayBuffer.[[ArrayBufferData]].
+    // 12. Let toBlock be newBuffer.[[ArrayBufferData]].
+    // 13. Perform CopyDataBlockBytes(toBlock, 0, fromBlock, 0, copyLength).
+    // 14. NOTE: Neither creation of the new Data Block nor copying from the
+    //     old Data Block are observable. Implementations may implement this
+    //     method as a zero-copy move or a realloc.
+    // 15. Perform ! DetachArrayBuffer(arrayBuffer).
+    let Some(block) = array_buffer
+        .get_mut(agent)
+        .buffer
+        .take_resized_data_block(new_byte_length)
+    else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Failed to allocate ArrayBuffer",
+            gc,
+        ));
+    };
+    let buffer = if let Some(new_max_byte_length) = new_max_byte_length {
+        InternalBuffer::resizable(block, new_max_byte_length)
+    } else {
+        InternalBuffer::fixed_length(block)
+    };
+    // 16. Return newBuffer.
+    Ok(agent.heap.create(ArrayBufferHeapData::new(buffer)).bind(gc))
+}
+
 /// ### [25.1.3.3 IsDetachedBuffer ( arrayBuffer )](https://tc39.es/ecma262/#sec-isdetachedbuffer)
 ///
 /// The abstract operation IsDetachedBuffer takes argument *arrayBuffer* (an
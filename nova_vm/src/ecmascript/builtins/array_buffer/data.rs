@@ -154,6 +154,20 @@ impl InternalBuffer {
         self.data_block = DataBlock::DETACHED_DATA_BLOCK;
     }
 
+    /// Moves the DataBlock out of the buffer, resized to the given byte
+    /// length, and leaves the buffer detached.
+    ///
+    /// Returns None if resizing the DataBlock failed, in which case the
+    /// buffer is left unchanged.
+    pub(crate) fn take_resized_data_block(&mut self, new_byte_length: usize) -> Option<DataBlock> {
+        if !self.data_block.try_realloc(new_byte_length) {
+            return None;
+        }
+        let data_block = core::mem::replace(&mut self.data_block, DataBlock::DETACHED_DATA_BLOCK);
+        self.capacity = 0;
+        Some(data_block)
+    }
+
     const fn detached() -> Self {
         Self {
             data_block: DataBlock::DETACHED_DATA_BLOCK,
@@ -197,6 +211,13 @@ impl Default for ArrayBufferHeapData<'_> {
 unsafe impl Send for ArrayBufferHeapData<'_> {}
 
 impl<'a> ArrayBufferHeapData<'a> {
+    pub(crate) fn new(buffer: InternalBuffer) -> Self {
+        Self {
+            object_index: None,
+            buffer,
+        }
+    }
+
     pub(crate) fn new_fixed_length(db: DataBlock) -> Self {
         Self {
             object_index: None,
@@ -7,9 +7,9 @@ use std::{borrow::Cow, ptr::NonNull};
 
 use crate::{
     ecmascript::{
-        Agent, BUILTIN_STRING_MEMORY, BuiltinFunctionHeapData, ExceptionType, ExecutionContext,
-        Function, FunctionInternalProperties, InternalSlots, JsResult, Object, OrdinaryObject,
-        PropertyKey, Realm, ScopedValuesIterator, String, Value, function_handle,
+        Agent, BUILTIN_STRING_MEMORY, BuiltinFunctionHeapData, EmbedderObject, ExceptionType,
+        ExecutionContext, Function, FunctionInternalProperties, InternalSlots, JsResult, Object,
+        OrdinaryObject, PropertyKey, Realm, ScopedValuesIterator, String, Value, function_handle,
     },
     engine::{Bindable, GcScope, HeapRootCollection, NoGcScope, bindable_handle},
     heap::{
@@ -476,6 +476,9 @@ pub struct BuiltinFunctionArgs<'a> {
     pub prototype: Option<Object<'a>>,
     /// An optional prefix for the builtin function's name.
     pub prefix: Option<&'static str>,
+    /// Embedder-defined data attached to the builtin function. The data can be
+    /// accessed at call time using [`BuiltinFunction::host_data`].
+    pub host_data: Option<EmbedderObject<'a>>,
 }
 
 impl<'a> BuiltinFunctionArgs<'a> {
@@ -549,6 +552,18 @@ impl<'f> BuiltinFunction<'f> {
     pub fn realm(self, agent: &Agent) -> Realm<'f> {
         self.get(agent).realm
     }
+
+    /// Returns the embedder-defined data attached to the builtin function.
+    ///
+    /// A builtin function's behaviour can access its own data using the
+    /// [active function object](Agent::active_function_object).
+    pub fn host_data(self, agent: &Agent) -> Option<EmbedderObject<'f>> {
+        agent
+            .heap
+            .builtin_function_host_data
+            .get(&self.unbind())
+            .copied()
+    }
 }
 function_handle!(BuiltinFunction);
 
@@ -682,7 +697,7 @@ fn builtin_call_or_construct<'gc>(
     this_argument: Option<Value>,
     arguments_list: ArgumentsList,
     new_target: Option<Function>,
-    gc: GcScope<'gc, '_>,
+    mut gc: GcScope<'gc, '_>,
 ) -> JsResult<'gc, Value<'gc>> {
     agent.check_call_depth(gc.nogc()).unbind()?;
 
@@ -720,14 +735,14 @@ fn builtin_call_or_construct<'gc>(
                 Err(agent.throw_exception_with_static_message(
                     ExceptionType::TypeError,
                     "Not a constructor",
-                    gc.into_nogc(),
+                    gc.nogc(),
                 ))
             } else {
                 func(
                     agent,
                     this_argument.unwrap_or(Value::Undefined).unbind(),
                     arguments_list.unbind(),
-                    gc,
+                    gc.reborrow(),
                 )
             }
         }
@@ -736,9 +751,19 @@ fn builtin_call_or_construct<'gc>(
             this_argument.unwrap_or(Value::Undefined).unbind(),
             arguments_list.unbind(),
             new_target.map(|target| target.unbind().into()),
-            gc,
+            gc.reborrow(),
         ),
-    };
+    }
+    .unbind();
+    let gc = gc.into_nogc();
+    let result = result.bind(gc);
+    if let Err(err) = result
+        && let Value::Error(error) = err.value()
+    {
+        // Note: errors thrown by the builtin capture their stack trace while
+        // the builtin is still on the execution context stack.
+        error.complete_stack_trace(agent, 0, None, gc);
+    }
     // 11. NOTE: If F is defined in this document, “the specification of F” is the behaviour specified for it via
     // algorithm steps or other means.
     // 12. Remove calleeContext from the execution context stack and restore callerContext as the running
@@ -747,6 +772,12 @@ fn builtin_call_or_construct<'gc>(
     // When calleeContext is removed from the execution context stack it must not be destroyed if it has been
     // suspended and retained by an accessible Generator for later resumption.
     let _callee_context = agent.pop_execution_context();
+    // Note: builtins such as the Promise constructor turn abrupt completions
+    // into promise rejections; an interrupt must still propagate.
+    if result.is_ok() && agent.is_interrupted() {
+        let requests = agent.safe_point_requests.load();
+        return Err(agent.throw_interrupt(requests, gc));
+    }
     // 13. Return ? result.
     result
 }
@@ -842,19 +873,24 @@ pub fn create_builtin_function<'a>(
         None
     };
 
+    let func = agent.heap.create(BuiltinFunctionHeapData {
+        behaviour,
+        initial_name: Some(initial_name),
+        // 10. Perform SetFunctionLength(func, length).
+        length: args.length as u8,
+        // 8. Set func.[[Realm]] to realm.
+        realm,
+        object_index,
+    });
+    if let Some(host_data) = args.host_data {
+        agent
+            .heap
+            .builtin_function_host_data
+            .insert(func.unbind(), host_data.unbind());
+    }
+
     // 13. Return func.
-    agent
-        .heap
-        .create(BuiltinFunctionHeapData {
-            behaviour,
-            initial_name: Some(initial_name),
-            // 10. Perform SetFunctionLength(func, length).
-            length: args.length as u8,
-            // 8. Set func.[[Realm]] to realm.
-            realm,
-            object_index,
-        })
-        .bind(gc)
+    func.bind(gc)
 }
 
 impl<'a> CreateHeapData<BuiltinFunctionHeapData<'a>, BuiltinFunction<'a>> for Heap {
@@ -2,18 +2,22 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+mod async_disposable_stack_objects;
 mod async_function_objects;
 mod async_generator_function_objects;
 mod async_generator_objects;
+mod disposable_stack_objects;
 mod generator_function_objects;
 mod generator_objects;
 mod generator_prototype;
 mod iteration;
 mod promise_objects;
 
+pub(crate) use async_disposable_stack_objects::*;
 pub(crate) use async_function_objects::*;
 pub(crate) use async_generator_function_objects::*;
 pub use async_generator_objects::*;
+pub(crate) use disposable_stack_objects::*;
 pub(crate) use generator_function_objects::*;
 pub use generator_objects::*;
 pub(crate) use generator_prototype::*;
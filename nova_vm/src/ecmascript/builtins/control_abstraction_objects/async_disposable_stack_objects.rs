@@ -0,0 +1,9 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod async_disposable_stack_constructor;
+mod async_disposable_stack_prototype;
+
+pub(crate) use async_disposable_stack_constructor::*;
+pub(crate) use async_disposable_stack_prototype::*;
@@ -0,0 +1,74 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, ExceptionType, Function, JsResult, Object, ProtoIntrinsics,
+        Realm, String, Value, builders::BuiltinFunctionBuilder, ordinary_create_from_constructor,
+    },
+    engine::{Bindable, GcScope},
+    heap::IntrinsicConstructorIndexes,
+};
+
+pub(crate) struct AsyncDisposableStackConstructor;
+impl Builtin for AsyncDisposableStackConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.AsyncDisposableStack;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for AsyncDisposableStackConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::AsyncDisposableStack;
+}
+
+impl AsyncDisposableStackConstructor {
+    /// ### [AsyncDisposableStack ( )](https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        _arguments: ArgumentsList,
+        new_target: Option<Object>,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let new_target = new_target.bind(gc.nogc());
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "calling a builtin AsyncDisposableStack constructor without new is forbidden",
+                gc.into_nogc(),
+            ));
+        };
+        let new_target = Function::try_from(new_target).unwrap();
+        // 2. Let asyncDisposableStack be ?
+        //    OrdinaryCreateFromConstructor(NewTarget,
+        //    "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]],
+        //    [[DisposeCapability]] »).
+        // 3. Set asyncDisposableStack.[[AsyncDisposableState]] to pending.
+        // 4. Set asyncDisposableStack.[[DisposeCapability]] to
+        //    NewDisposeCapability().
+        let async_disposable_stack = ordinary_create_from_constructor(
+            agent,
+            new_target.unbind(),
+            ProtoIntrinsics::AsyncDisposableStack,
+            gc,
+        )?;
+        // 5. Return asyncDisposableStack.
+        Ok(async_disposable_stack.into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let async_disposable_stack_prototype = intrinsics.async_disposable_stack_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<AsyncDisposableStackConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(1)
+        .with_prototype_property(async_disposable_stack_prototype.into())
+        .build();
+    }
+}
@@ -0,0 +1,353 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
+        BuiltinIntrinsic, DisposableStack, DisposableStackHeapData, DisposeHint, DisposeMethodKind,
+        ExceptionType, JsError, JsResult, Promise, PropertyKey, Realm, String, Value,
+        add_disposable_resource, add_disposable_resource_with_method, async_dispose_resources,
+        builders::OrdinaryObjectBuilder, is_callable,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{CreateHeapData, IntrinsicFunctionIndexes, WellKnownSymbols},
+};
+
+pub(crate) struct AsyncDisposableStackPrototype;
+
+struct AsyncDisposableStackPrototypeAdopt;
+impl Builtin for AsyncDisposableStackPrototypeAdopt {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.adopt;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AsyncDisposableStackPrototype::adopt);
+}
+struct AsyncDisposableStackPrototypeDefer;
+impl Builtin for AsyncDisposableStackPrototypeDefer {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.defer;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AsyncDisposableStackPrototype::defer);
+}
+struct AsyncDisposableStackPrototypeDisposeAsync;
+impl Builtin for AsyncDisposableStackPrototypeDisposeAsync {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.disposeAsync;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AsyncDisposableStackPrototype::dispose_async);
+}
+impl BuiltinIntrinsic for AsyncDisposableStackPrototypeDisposeAsync {
+    const INDEX: IntrinsicFunctionIndexes =
+        IntrinsicFunctionIndexes::AsyncDisposableStackPrototypeDisposeAsync;
+}
+struct AsyncDisposableStackPrototypeGetDisposed;
+impl Builtin for AsyncDisposableStackPrototypeGetDisposed {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_disposed;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.disposed.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AsyncDisposableStackPrototype::get_disposed);
+}
+impl BuiltinGetter for AsyncDisposableStackPrototypeGetDisposed {}
+struct AsyncDisposableStackPrototypeMove;
+impl Builtin for AsyncDisposableStackPrototypeMove {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.r#move;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AsyncDisposableStackPrototype::r#move);
+}
+struct AsyncDisposableStackPrototypeUse;
+impl Builtin for AsyncDisposableStackPrototypeUse {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.r#use;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(AsyncDisposableStackPrototype::r#use);
+}
+
+impl AsyncDisposableStackPrototype {
+    /// ### [AsyncDisposableStack.prototype.adopt ( value, onDisposeAsync )](https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.adopt)
+    fn adopt<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let value = arguments.get(0).bind(gc);
+        let on_dispose_async = arguments.get(1).bind(gc);
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack,
+        //    [[AsyncDisposableState]]).
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed,
+        //    throw a ReferenceError exception.
+        let async_disposable_stack = require_pending_async_disposable_stack(agent, this_value, gc)?;
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError
+        //    exception.
+        let Some(on_dispose_async) = is_callable(on_dispose_async, gc) else {
+            return Err(throw_not_callable_error(agent, gc));
+        };
+        // 5. Let closure be a new Abstract Closure with no parameters that
+        //    captures value and onDisposeAsync and performs the following
+        //    steps when called:
+        // a. Return ? Call(onDisposeAsync, undefined, « value »).
+        // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        // 7. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]],
+        //    undefined, async-dispose, F).
+        add_disposable_resource_with_method(
+            agent,
+            async_disposable_stack,
+            value,
+            DisposeHint::AsyncDispose,
+            on_dispose_async,
+            DisposeMethodKind::Adopt,
+        );
+        // 8. Return value.
+        Ok(value)
+    }
+
+    /// ### [AsyncDisposableStack.prototype.defer ( onDisposeAsync )](https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.defer)
+    fn defer<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let on_dispose_async = arguments.get(0).bind(gc);
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack,
+        //    [[AsyncDisposableState]]).
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed,
+        //    throw a ReferenceError exception.
+        let async_disposable_stack = require_pending_async_disposable_stack(agent, this_value, gc)?;
+        // 4. If IsCallable(onDisposeAsync) is false, throw a TypeError
+        //    exception.
+        let Some(on_dispose_async) = is_callable(on_dispose_async, gc) else {
+            return Err(throw_not_callable_error(agent, gc));
+        };
+        // 5. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]],
+        //    undefined, async-dispose, onDisposeAsync).
+        add_disposable_resource_with_method(
+            agent,
+            async_disposable_stack,
+            Value::Undefined,
+            DisposeHint::AsyncDispose,
+            on_dispose_async,
+            DisposeMethodKind::Method,
+        );
+        // 6. Return undefined.
+        Ok(Value::Undefined)
+    }
+
+    /// ### [AsyncDisposableStack.prototype.disposeAsync ( )](https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.disposeAsync)
+    fn dispose_async<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+        // 3. If asyncDisposableStack does not have an
+        //    [[AsyncDisposableState]] internal slot, then
+        let async_disposable_stack = match this_value {
+            Value::DisposableStack(stack) if stack.is_async(agent) => stack,
+            _ => {
+                // a. Perform ! Call(promiseCapability.[[Reject]], undefined,
+                //    « a newly created TypeError object »).
+                // b. Return promiseCapability.[[Promise]].
+                let gc = gc.into_nogc();
+                let error = agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Receiver is not an AsyncDisposableStack",
+                    gc,
+                );
+                return Ok(Promise::new_rejected(agent, error.value(), gc).into());
+            }
+        };
+        // 4. If asyncDisposableStack.[[AsyncDisposableState]] is disposed,
+        //    then
+        if async_disposable_stack.is_disposed(agent) {
+            // a. Perform ! Call(promiseCapability.[[Resolve]], undefined,
+            //    « undefined »).
+            // b. Return promiseCapability.[[Promise]].
+            return Ok(Promise::new_resolved(agent, Value::Undefined).into());
+        }
+        // 5. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+        async_disposable_stack.set_disposed(agent);
+        // 6. Let result be Completion(DisposeResources(asyncDisposableStack.[[DisposeCapability]],
+        //    NormalCompletion(undefined))).
+        // 7. IfAbruptRejectPromise(result, promiseCapability).
+        // 8. Perform ! Call(promiseCapability.[[Resolve]], undefined,
+        //    « result »).
+        // 9. Return promiseCapability.[[Promise]].
+        Ok(async_dispose_resources(agent, async_disposable_stack.unbind(), None, gc).into())
+    }
+
+    /// ### [get AsyncDisposableStack.prototype.disposed](https://tc39.es/proposal-explicit-resource-management/#sec-get-asyncdisposablestack.prototype.disposed)
+    fn get_disposed<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack,
+        //    [[AsyncDisposableState]]).
+        let async_disposable_stack = require_async_disposable_stack(agent, this_value, gc)?;
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed,
+        //    return true.
+        // 4. Otherwise, return false.
+        Ok(async_disposable_stack.is_disposed(agent).into())
+    }
+
+    /// ### [AsyncDisposableStack.prototype.move ( )](https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.move)
+    fn r#move<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack,
+        //    [[AsyncDisposableState]]).
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed,
+        //    throw a ReferenceError exception.
+        let async_disposable_stack = require_pending_async_disposable_stack(agent, this_value, gc)?;
+        // 4. Let newAsyncDisposableStack be ?
+        //    OrdinaryCreateFromConstructor(%AsyncDisposableStack%,
+        //    "%AsyncDisposableStack.prototype%", « [[AsyncDisposableState]],
+        //    [[DisposeCapability]] »).
+        // 5. Set newAsyncDisposableStack.[[AsyncDisposableState]] to pending.
+        let new_async_disposable_stack = agent
+            .heap
+            .create(DisposableStackHeapData::new(true))
+            .bind(gc);
+        // 6. Set newAsyncDisposableStack.[[DisposeCapability]] to
+        //    asyncDisposableStack.[[DisposeCapability]].
+        // 7. Set asyncDisposableStack.[[DisposeCapability]] to
+        //    NewDisposeCapability().
+        async_disposable_stack.move_resources_to(agent, new_async_disposable_stack);
+        // 8. Set asyncDisposableStack.[[AsyncDisposableState]] to disposed.
+        async_disposable_stack.set_disposed(agent);
+        // 9. Return newAsyncDisposableStack.
+        Ok(new_async_disposable_stack.into())
+    }
+
+    /// ### [AsyncDisposableStack.prototype.use ( value )](https://tc39.es/proposal-explicit-resource-management/#sec-asyncdisposablestack.prototype.use)
+    fn r#use<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let value = arguments.get(0).bind(gc.nogc());
+        // 1. Let asyncDisposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(asyncDisposableStack,
+        //    [[AsyncDisposableState]]).
+        // 3. If asyncDisposableStack.[[AsyncDisposableState]] is disposed,
+        //    throw a ReferenceError exception.
+        let async_disposable_stack =
+            require_pending_async_disposable_stack(agent, this_value, gc.nogc())
+                .unbind()?
+                .bind(gc.nogc());
+        let scoped_value = value.scope(agent, gc.nogc());
+        // 4. Perform ? AddDisposableResource(asyncDisposableStack.[[DisposeCapability]],
+        //    value, async-dispose).
+        add_disposable_resource(
+            agent,
+            async_disposable_stack.unbind(),
+            value.unbind(),
+            DisposeHint::AsyncDispose,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 5. Return value.
+        // SAFETY: scoped_value is not shared.
+        Ok(unsafe { scoped_value.take(agent) }.bind(gc.into_nogc()))
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.async_disposable_stack_prototype();
+        let async_disposable_stack_constructor = intrinsics.async_disposable_stack();
+        let async_disposable_stack_prototype_dispose_async =
+            intrinsics.async_disposable_stack_prototype_dispose_async();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(9)
+            .with_prototype(object_prototype)
+            .with_builtin_function_property::<AsyncDisposableStackPrototypeAdopt>()
+            .with_constructor_property(async_disposable_stack_constructor)
+            .with_builtin_function_property::<AsyncDisposableStackPrototypeDefer>()
+            .with_builtin_intrinsic_function_property::<AsyncDisposableStackPrototypeDisposeAsync>()
+            .with_builtin_function_getter_property::<AsyncDisposableStackPrototypeGetDisposed>()
+            .with_builtin_function_property::<AsyncDisposableStackPrototypeMove>()
+            .with_builtin_function_property::<AsyncDisposableStackPrototypeUse>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::AsyncDispose.into())
+                    .with_value(async_disposable_stack_prototype_dispose_async.into())
+                    .with_enumerable(AsyncDisposableStackPrototypeDisposeAsync::ENUMERABLE)
+                    .with_configurable(AsyncDisposableStackPrototypeDisposeAsync::CONFIGURABLE)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.AsyncDisposableStack.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+#[inline(always)]
+fn require_async_disposable_stack<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, DisposableStack<'a>> {
+    match value {
+        Value::DisposableStack(async_disposable_stack)
+            if async_disposable_stack.is_async(agent) =>
+        {
+            Ok(async_disposable_stack.bind(gc))
+        }
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Receiver is not an AsyncDisposableStack",
+            gc,
+        )),
+    }
+}
+
+/// Perform RequireInternalSlot(asyncDisposableStack,
+/// \[\[AsyncDisposableState]]) and throw a ReferenceError if the
+/// AsyncDisposableStack has already been disposed.
+#[inline(always)]
+fn require_pending_async_disposable_stack<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, DisposableStack<'a>> {
+    let async_disposable_stack = require_async_disposable_stack(agent, value, gc)?;
+    if async_disposable_stack.is_disposed(agent) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::ReferenceError,
+            "AsyncDisposableStack has already been disposed",
+            gc,
+        ));
+    }
+    Ok(async_disposable_stack)
+}
+
+fn throw_not_callable_error<'a>(agent: &mut Agent, gc: NoGcScope<'a, '_>) -> JsError<'a> {
+    agent.throw_exception_with_static_message(
+        ExceptionType::TypeError,
+        "onDisposeAsync is not callable",
+        gc,
+    )
+}
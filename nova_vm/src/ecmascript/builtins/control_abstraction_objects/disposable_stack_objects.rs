@@ -0,0 +1,11 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod disposable_stack_abstract_operations;
+mod disposable_stack_constructor;
+mod disposable_stack_prototype;
+
+pub(crate) use disposable_stack_abstract_operations::*;
+pub(crate) use disposable_stack_constructor::*;
+pub(crate) use disposable_stack_prototype::*;
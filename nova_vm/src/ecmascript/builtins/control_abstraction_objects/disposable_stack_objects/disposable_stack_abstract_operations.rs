@@ -0,0 +1,501 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, AsyncDisposeState, DisposableResource, DisposableStack, DisposeHint,
+        DisposeMethodKind, ExceptionType, Function, JsError, JsResult, Object, Promise,
+        PromiseCapability, PromiseReactionHandler, PromiseReactionType, Value, call_function,
+        create_suppressed_error, get_method, inner_promise_then,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{ArenaAccess, ArenaAccessMut, WellKnownSymbols},
+};
+
+/// ### [AddDisposableResource ( disposeCapability, V, hint \[ , method \] )](https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource)
+///
+/// The abstract operation AddDisposableResource takes arguments
+/// disposeCapability (a DisposeCapability Record), V (an ECMAScript language
+/// value), and hint (either sync-dispose or async-dispose) and returns either
+/// a normal completion containing unused or a throw completion.
+///
+/// > Note: The `method` parameter is handled by
+/// > [`add_disposable_resource_with_method`].
+pub(crate) fn add_disposable_resource<'gc>(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    v: Value,
+    hint: DisposeHint,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
+    let dispose_capability = dispose_capability.bind(gc.nogc());
+    let v = v.bind(gc.nogc());
+    // 1. If method is not present, then
+    // a. If V is either null or undefined and hint is sync-dispose, return
+    //    unused.
+    if (v.is_null() || v.is_undefined()) && hint == DisposeHint::SyncDispose {
+        return Ok(());
+    }
+    // b. NOTE: When V is either null or undefined and hint is async-dispose,
+    //    we record that the resource was evaluated to ensure we will still
+    //    perform an Await when resources are later disposed.
+    // c. Let resource be ? CreateDisposableResource(V, hint).
+    let dispose_capability = dispose_capability.scope(agent, gc.nogc());
+    let resource = create_disposable_resource(agent, v.unbind(), hint, gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    // 3. Append resource to disposeCapability.[[DisposableResourceStack]].
+    dispose_capability.get(agent).push_resource(agent, resource);
+    // 4. Return unused.
+    Ok(())
+}
+
+/// ### [AddDisposableResource ( disposeCapability, V, hint \[ , method \] )](https://tc39.es/proposal-explicit-resource-management/#sec-adddisposableresource)
+///
+/// AddDisposableResource with the `method` parameter present. The `kind`
+/// parameter describes the builtin function closure that the specification
+/// would create to wrap `method`.
+pub(crate) fn add_disposable_resource_with_method(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    v: Value,
+    hint: DisposeHint,
+    method: Function,
+    kind: DisposeMethodKind,
+) {
+    // 2. Else,
+    // a. Assert: V is undefined.
+    debug_assert!(v.is_undefined() || kind == DisposeMethodKind::Adopt);
+    // b. Let resource be ? CreateDisposableResource(undefined, hint, method).
+    // NOTE: CreateDisposableResource only checks that method is callable,
+    // which our callers have already done.
+    let resource = DisposableResource {
+        value: v,
+        hint,
+        method: Some(method),
+        kind,
+    };
+    // 3. Append resource to disposeCapability.[[DisposableResourceStack]].
+    dispose_capability.push_resource(agent, resource);
+    // 4. Return unused.
+}
+
+/// ### [CreateDisposableResource ( V, hint \[ , method \] )](https://tc39.es/proposal-explicit-resource-management/#sec-createdisposableresource)
+///
+/// The abstract operation CreateDisposableResource takes arguments V (an
+/// ECMAScript language value) and hint (either sync-dispose or async-dispose)
+/// and returns either a normal completion containing a DisposableResource
+/// Record or a throw completion.
+fn create_disposable_resource<'gc>(
+    agent: &mut Agent,
+    v: Value,
+    hint: DisposeHint,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, DisposableResource<'gc>> {
+    let v = v.bind(gc.nogc());
+    // 1. If method is not present, then
+    // a. If V is either null or undefined, then
+    if v.is_null() || v.is_undefined() {
+        // i. Set V to undefined.
+        // ii. Set method to undefined.
+        return Ok(DisposableResource {
+            value: Value::Undefined,
+            hint,
+            method: None,
+            kind: DisposeMethodKind::Method,
+        });
+    }
+    // b. Else,
+    // i. If V is not an Object, throw a TypeError exception.
+    let Ok(object) = Object::try_from(v) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Resource is not an object",
+            gc.into_nogc(),
+        ));
+    };
+    // ii. Set method to ? GetDisposeMethod(V, hint).
+    let scoped_object = object.scope(agent, gc.nogc());
+    let method = get_dispose_method(agent, object.unbind(), hint, gc.reborrow()).unbind()?;
+    let gc = gc.into_nogc();
+    let method = method.bind(gc);
+    // iii. If method is undefined, throw a TypeError exception.
+    let Some((method, kind)) = method else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            if hint == DisposeHint::SyncDispose {
+                "Resource does not have a [Symbol.dispose] method"
+            } else {
+                "Resource does not have a [Symbol.asyncDispose] or [Symbol.dispose] method"
+            },
+            gc,
+        ));
+    };
+    // 3. Return the DisposableResource Record { [[ResourceValue]]: V,
+    //    [[Hint]]: hint, [[DisposeMethod]]: method }.
+    Ok(DisposableResource {
+        // SAFETY: scoped_object is not shared.
+        value: unsafe { scoped_object.take(agent) }.bind(gc).into(),
+        hint,
+        method: Some(method),
+        kind,
+    })
+}
+
+/// ### [GetDisposeMethod ( V, hint )](https://tc39.es/proposal-explicit-resource-management/#sec-getdisposemethod)
+///
+/// The abstract operation GetDisposeMethod takes arguments V (an Object) and
+/// hint (either sync-dispose or async-dispose) and returns either a normal
+/// completion containing either a function object or undefined, or a throw
+/// completion.
+fn get_dispose_method<'gc>(
+    agent: &mut Agent,
+    v: Object,
+    hint: DisposeHint,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<(Function<'gc>, DisposeMethodKind)>> {
+    let v = v.bind(gc.nogc());
+    // 1. If hint is async-dispose, then
+    if hint == DisposeHint::AsyncDispose {
+        let scoped_v = v.scope(agent, gc.nogc());
+        // a. Let method be ? GetMethod(V, @@asyncDispose).
+        let method = get_method(
+            agent,
+            v.unbind().into(),
+            WellKnownSymbols::AsyncDispose.into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        if let Some(method) = method {
+            return Ok(Some((
+                method.unbind().bind(gc.into_nogc()),
+                DisposeMethodKind::Method,
+            )));
+        }
+        // b. If method is undefined, then
+        // i. Set method to ? GetMethod(V, @@dispose).
+        let method = get_method(
+            agent,
+            scoped_v.get(agent).into(),
+            WellKnownSymbols::Dispose.into(),
+            gc,
+        )?;
+        // ii. If method is not undefined, then
+        // 1. Let closure be a new Abstract Closure with no parameters that
+        //    captures method and performs the following steps when called:
+        // 2. Return CreateBuiltinFunction(closure, 0, "", « »).
+        // NOTE: We do not create the closure but instead remember the
+        // resource kind.
+        return Ok(method.map(|method| (method, DisposeMethodKind::SyncFallback)));
+    }
+    // 2. Else,
+    // a. Let method be ? GetMethod(V, @@dispose).
+    let method = get_method(
+        agent,
+        v.unbind().into(),
+        WellKnownSymbols::Dispose.into(),
+        gc,
+    )?;
+    // 3. Return method.
+    Ok(method.map(|method| (method, DisposeMethodKind::Method)))
+}
+
+/// ### [Dispose ( V, hint, method )](https://tc39.es/proposal-explicit-resource-management/#sec-dispose)
+///
+/// Calls the resource's \[\[DisposeMethod]]. Awaiting the result of
+/// async-dispose resources is left to the caller.
+fn call_dispose_method<'gc>(
+    agent: &mut Agent,
+    resource: DisposableResource,
+    method: Function,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    match resource.kind {
+        // 2. Else, let result be ? Call(method, V).
+        DisposeMethodKind::Method | DisposeMethodKind::SyncFallback => {
+            call_function(agent, method, resource.value, None, gc)
+        }
+        // a. Return ? Call(onDispose, undefined, « value »).
+        DisposeMethodKind::Adopt => call_function(
+            agent,
+            method,
+            Value::Undefined,
+            Some(ArgumentsList::from_mut_value(&mut resource.value.unbind())),
+            gc,
+        ),
+    }
+}
+
+/// Merges a throw completion from a dispose method into the completion of a
+/// DisposeResources operation.
+///
+/// ### [DisposeResources ( disposeCapability, completion )](https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources)
+fn merge_dispose_error<'a>(
+    agent: &mut Agent,
+    completion: Option<Value>,
+    result: Value,
+    gc: NoGcScope<'a, '_>,
+) -> Value<'a> {
+    // iii. If result is a throw completion, then
+    if let Some(suppressed) = completion {
+        // 1. If completion is a throw completion, then
+        // a. Set result to result.[[Value]].
+        // b. Let suppressed be completion.[[Value]].
+        // c. Let error be a newly created SuppressedError object.
+        // d. Perform CreateNonEnumerableDataPropertyOrThrow(error, "error",
+        //    result).
+        // e. Perform CreateNonEnumerableDataPropertyOrThrow(error,
+        //    "suppressed", suppressed).
+        // f. Set completion to ThrowCompletion(error).
+        create_suppressed_error(agent, result, suppressed, gc).into()
+    } else {
+        // 2. Else,
+        // a. Set completion to result.
+        result.bind(gc)
+    }
+}
+
+/// ### [DisposeResources ( disposeCapability, completion )](https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources)
+///
+/// The abstract operation DisposeResources takes arguments disposeCapability
+/// (a DisposeCapability Record) and completion (either a normal completion
+/// containing either an ECMAScript language value or empty, or an abrupt
+/// completion) and returns either a normal completion containing either an
+/// ECMAScript language value or empty, or an abrupt completion.
+///
+/// This is the synchronous version of the abstract operation, used when the
+/// disposeCapability only contains sync-dispose resources. The completion is
+/// given as an optional thrown error.
+pub(crate) fn dispose_resources<'gc>(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    completion: Option<JsError>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
+    let dispose_capability = dispose_capability.scope(agent, gc.nogc());
+    let mut completion = completion.map(|error| error.scope(agent, gc.nogc()));
+    // 3. For each element resource of
+    //    disposeCapability.[[DisposableResourceStack]], in reverse list
+    //    order, do
+    while let Some(resource) = dispose_capability.get(agent).pop_resource(agent) {
+        let resource = resource.bind(gc.nogc());
+        // a. Let value be resource.[[ResourceValue]].
+        // b. Let hint be resource.[[Hint]].
+        // c. Let method be resource.[[DisposeMethod]].
+        debug_assert_eq!(resource.hint, DisposeHint::SyncDispose);
+        // e. If method is not undefined, then
+        let Some(method) = resource.method else {
+            continue;
+        };
+        // i. Let result be Completion(Call(method, value)).
+        let result = call_dispose_method(agent, resource.unbind(), method.unbind(), gc.reborrow())
+            .unbind()
+            .bind(gc.nogc());
+        // iii. If result is a throw completion, then
+        if let Err(result) = result {
+            let previous = completion.as_ref().map(|error| error.get(agent).value());
+            let error = merge_dispose_error(agent, previous, result.value(), gc.nogc());
+            completion = Some(JsError::new(error).scope(agent, gc.nogc()));
+        }
+    }
+    // 6. Set disposeCapability.[[DisposableResourceStack]] to a new empty
+    //    List.
+    // 7. Return ? completion.
+    match completion {
+        Some(error) => Err(error.get(agent).bind(gc.into_nogc())),
+        None => Ok(()),
+    }
+}
+
+/// ### [DisposeResources ( disposeCapability, completion )](https://tc39.es/proposal-explicit-resource-management/#sec-disposeresources)
+///
+/// The asynchronous version of the abstract operation: the completion is
+/// given as an optional thrown error, and the returned promise is settled
+/// with the final completion once all resources have been disposed. Await
+/// steps of the operation are performed by waiting on promise reactions.
+pub(crate) fn async_dispose_resources<'gc>(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    completion: Option<JsError>,
+    gc: GcScope<'gc, '_>,
+) -> Promise<'gc> {
+    let dispose_capability = dispose_capability.bind(gc.nogc());
+    let promise = PromiseCapability::new(agent, gc.nogc()).promise();
+    // 1. Let needsAwait be false.
+    // 2. Let hasAwaited be false.
+    dispose_capability.get_mut(agent).async_dispose = Some(AsyncDisposeState {
+        promise: promise.unbind(),
+        error: completion.map(|error| error.value().unbind()),
+        needs_await: false,
+        has_awaited: false,
+    });
+    let scoped_promise = promise.scope(agent, gc.nogc());
+    continue_async_dispose_resources(agent, dispose_capability.unbind(), gc);
+    // SAFETY: scoped_promise is not shared.
+    unsafe { scoped_promise.take(agent) }
+}
+
+/// Resume an asynchronous DisposeResources operation after an Await.
+pub(crate) fn resume_async_dispose_resources(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    reaction_type: PromiseReactionType,
+    value: Value,
+    gc: GcScope,
+) {
+    let dispose_capability = dispose_capability.bind(gc.nogc());
+    let value = value.bind(gc.nogc());
+    if reaction_type == PromiseReactionType::Reject {
+        // iii. If result is a throw completion, then
+        let previous = dispose_capability
+            .get(agent)
+            .async_dispose
+            .as_ref()
+            .unwrap()
+            .error;
+        let error = merge_dispose_error(agent, previous, value, gc.nogc());
+        dispose_capability
+            .get_mut(agent)
+            .async_dispose
+            .as_mut()
+            .unwrap()
+            .error = Some(error.unbind());
+    }
+    continue_async_dispose_resources(agent, dispose_capability.unbind(), gc);
+}
+
+/// Perform an Await step of an asynchronous DisposeResources operation.
+fn await_dispose_result(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    promise: Promise,
+    gc: NoGcScope,
+) {
+    inner_promise_then(
+        agent,
+        promise,
+        PromiseReactionHandler::DisposeResources(dispose_capability),
+        PromiseReactionHandler::DisposeResources(dispose_capability),
+        None,
+        gc,
+    );
+}
+
+fn continue_async_dispose_resources(
+    agent: &mut Agent,
+    dispose_capability: DisposableStack,
+    mut gc: GcScope,
+) {
+    let dispose_capability = dispose_capability.scope(agent, gc.nogc());
+    // 3. For each element resource of
+    //    disposeCapability.[[DisposableResourceStack]], in reverse list
+    //    order, do
+    loop {
+        let capability = dispose_capability.get(agent).bind(gc.nogc());
+        let resource = capability.pop_resource(agent);
+        let state = capability.get_mut(agent).async_dispose.as_mut().unwrap();
+        let Some(resource) = resource else {
+            // 4. If needsAwait is true and hasAwaited is false, then
+            if state.needs_await && !state.has_awaited {
+                state.needs_await = false;
+                // a. Perform ! Await(undefined).
+                let promise = Promise::new_resolved(agent, Value::Undefined);
+                await_dispose_result(agent, capability, promise, gc.nogc());
+                return;
+            }
+            // 5. NOTE: After disposeCapability has been disposed, it will
+            //    never be used again. The contents of
+            //    disposeCapability.[[DisposableResourceStack]] can be
+            //    discarded in implementations, such as by garbage
+            //    collection, at this point.
+            // 6. Set disposeCapability.[[DisposableResourceStack]] to a new
+            //    empty List.
+            let state = capability.get_mut(agent).async_dispose.take().unwrap();
+            let promise_capability = PromiseCapability::from_promise(state.promise.unbind(), true);
+            // 7. Return ? completion.
+            if let Some(error) = state.error {
+                promise_capability.reject(agent, error.unbind(), gc.nogc());
+            } else {
+                promise_capability.resolve(agent, Value::Undefined, gc.reborrow());
+            }
+            return;
+        };
+        // b. If hint is sync-dispose and needsAwait is true and hasAwaited is
+        //    false, then
+        if resource.hint == DisposeHint::SyncDispose && state.needs_await && !state.has_awaited {
+            // ii. Set needsAwait to false.
+            state.needs_await = false;
+            // We come back to this resource after the Await.
+            capability.push_resource(agent, resource);
+            // i. Perform ! Await(undefined).
+            let promise = Promise::new_resolved(agent, Value::Undefined);
+            await_dispose_result(agent, capability, promise, gc.nogc());
+            return;
+        }
+        // c. If method is not undefined, then
+        let Some(method) = resource.method else {
+            // d. Else,
+            // i. Assert: hint is async-dispose.
+            debug_assert_eq!(resource.hint, DisposeHint::AsyncDispose);
+            // ii. Set needsAwait to true.
+            // iii. NOTE: This can only indicate a case where either null or
+            //      undefined was the initialized value of an await using
+            //      declaration.
+            state.needs_await = true;
+            continue;
+        };
+        let DisposableResource { hint, kind, .. } = resource;
+        // i. Let result be Completion(Call(method, value)).
+        let result = call_dispose_method(agent, resource.unbind(), method.unbind(), gc.reborrow())
+            .unbind()
+            .bind(gc.nogc());
+        let result = match (hint, kind, result) {
+            // ii. If result is a normal completion and hint is async-dispose,
+            //     then
+            (DisposeHint::AsyncDispose, DisposeMethodKind::SyncFallback, result) => {
+                // GetDisposeMethod closure:
+                // c. IfAbruptRejectPromise(result, promiseCapability).
+                // d. Perform ! Call(promiseCapability.[[Resolve]], undefined,
+                //    « undefined »).
+                let promise = match result {
+                    Ok(_) => Promise::new_resolved(agent, Value::Undefined),
+                    Err(err) => Promise::new_rejected(agent, err.value(), gc.nogc()),
+                };
+                Ok(promise)
+            }
+            (DisposeHint::AsyncDispose, _, Ok(result)) => {
+                // Await(value) step 2: Let promise be ? PromiseResolve(%Promise%,
+                // value).
+                Promise::resolve(agent, result.unbind(), gc.reborrow())
+                    .unbind()
+                    .bind(gc.nogc())
+            }
+            (_, _, Ok(_)) => continue,
+            (_, _, Err(err)) => Err(err),
+        };
+        let capability = dispose_capability.get(agent).bind(gc.nogc());
+        match result {
+            Ok(promise) => {
+                // 1. Set result to Completion(Await(result.[[Value]])).
+                // 2. Set hasAwaited to true.
+                let state = capability.get_mut(agent).async_dispose.as_mut().unwrap();
+                state.has_awaited = true;
+                await_dispose_result(agent, capability, promise, gc.nogc());
+                return;
+            }
+            Err(err) => {
+                // iii. If result is a throw completion, then
+                let previous = capability.get(agent).async_dispose.as_ref().unwrap().error;
+                let error = merge_dispose_error(agent, previous, err.value(), gc.nogc());
+                capability
+                    .get_mut(agent)
+                    .async_dispose
+                    .as_mut()
+                    .unwrap()
+                    .error = Some(error.unbind());
+            }
+        }
+    }
+}
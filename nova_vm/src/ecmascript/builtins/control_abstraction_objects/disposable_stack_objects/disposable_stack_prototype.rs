@@ -0,0 +1,323 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
+        BuiltinIntrinsic, DisposableStack, DisposableStackHeapData, DisposeHint, DisposeMethodKind,
+        ExceptionType, JsError, JsResult, PropertyKey, Realm, String, Value,
+        add_disposable_resource, add_disposable_resource_with_method,
+        builders::OrdinaryObjectBuilder, dispose_resources, is_callable,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{CreateHeapData, IntrinsicFunctionIndexes, WellKnownSymbols},
+};
+
+pub(crate) struct DisposableStackPrototype;
+
+struct DisposableStackPrototypeAdopt;
+impl Builtin for DisposableStackPrototypeAdopt {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.adopt;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::adopt);
+}
+struct DisposableStackPrototypeDefer;
+impl Builtin for DisposableStackPrototypeDefer {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.defer;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::defer);
+}
+struct DisposableStackPrototypeDispose;
+impl Builtin for DisposableStackPrototypeDispose {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.dispose;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::dispose);
+}
+impl BuiltinIntrinsic for DisposableStackPrototypeDispose {
+    const INDEX: IntrinsicFunctionIndexes =
+        IntrinsicFunctionIndexes::DisposableStackPrototypeDispose;
+}
+struct DisposableStackPrototypeGetDisposed;
+impl Builtin for DisposableStackPrototypeGetDisposed {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_disposed;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.disposed.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::get_disposed);
+}
+impl BuiltinGetter for DisposableStackPrototypeGetDisposed {}
+struct DisposableStackPrototypeMove;
+impl Builtin for DisposableStackPrototypeMove {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.r#move;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::r#move);
+}
+struct DisposableStackPrototypeUse;
+impl Builtin for DisposableStackPrototypeUse {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.r#use;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(DisposableStackPrototype::r#use);
+}
+
+impl DisposableStackPrototype {
+    /// ### [DisposableStack.prototype.adopt ( value, onDispose )](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.adopt)
+    fn adopt<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let value = arguments.get(0).bind(gc);
+        let on_dispose = arguments.get(1).bind(gc);
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a
+        //    ReferenceError exception.
+        let disposable_stack = require_pending_disposable_stack(agent, this_value, gc)?;
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let Some(on_dispose) = is_callable(on_dispose, gc) else {
+            return Err(throw_not_callable_error(agent, gc));
+        };
+        // 5. Let closure be a new Abstract Closure with no parameters that
+        //    captures value and onDispose and performs the following steps
+        //    when called:
+        // a. Return ? Call(onDispose, undefined, « value »).
+        // 6. Let F be CreateBuiltinFunction(closure, 0, "", « »).
+        // 7. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]],
+        //    undefined, sync-dispose, F).
+        add_disposable_resource_with_method(
+            agent,
+            disposable_stack,
+            value,
+            DisposeHint::SyncDispose,
+            on_dispose,
+            DisposeMethodKind::Adopt,
+        );
+        // 8. Return value.
+        Ok(value)
+    }
+
+    /// ### [DisposableStack.prototype.defer ( onDispose )](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.defer)
+    fn defer<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let on_dispose = arguments.get(0).bind(gc);
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a
+        //    ReferenceError exception.
+        let disposable_stack = require_pending_disposable_stack(agent, this_value, gc)?;
+        // 4. If IsCallable(onDispose) is false, throw a TypeError exception.
+        let Some(on_dispose) = is_callable(on_dispose, gc) else {
+            return Err(throw_not_callable_error(agent, gc));
+        };
+        // 5. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]],
+        //    undefined, sync-dispose, onDispose).
+        add_disposable_resource_with_method(
+            agent,
+            disposable_stack,
+            Value::Undefined,
+            DisposeHint::SyncDispose,
+            on_dispose,
+            DisposeMethodKind::Method,
+        );
+        // 6. Return undefined.
+        Ok(Value::Undefined)
+    }
+
+    /// ### [DisposableStack.prototype.dispose ( )](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.dispose)
+    fn dispose<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let disposable_stack = require_disposable_stack(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. If disposableStack.[[DisposableState]] is disposed, return
+        //    undefined.
+        if disposable_stack.is_disposed(agent) {
+            return Ok(Value::Undefined);
+        }
+        // 4. Set disposableStack.[[DisposableState]] to disposed.
+        disposable_stack.set_disposed(agent);
+        // 5. Return ? DisposeResources(disposableStack.[[DisposeCapability]],
+        //    NormalCompletion(undefined)).
+        dispose_resources(agent, disposable_stack.unbind(), None, gc)?;
+        Ok(Value::Undefined)
+    }
+
+    /// ### [get DisposableStack.prototype.disposed](https://tc39.es/proposal-explicit-resource-management/#sec-get-disposablestack.prototype.disposed)
+    fn get_disposed<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        let disposable_stack = require_disposable_stack(agent, this_value, gc)?;
+        // 3. If disposableStack.[[DisposableState]] is disposed, return true.
+        // 4. Otherwise, return false.
+        Ok(disposable_stack.is_disposed(agent).into())
+    }
+
+    /// ### [DisposableStack.prototype.move ( )](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.move)
+    fn r#move<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a
+        //    ReferenceError exception.
+        let disposable_stack = require_pending_disposable_stack(agent, this_value, gc)?;
+        // 4. Let newDisposableStack be ?
+        //    OrdinaryCreateFromConstructor(%DisposableStack%,
+        //    "%DisposableStack.prototype%", « [[DisposableState]],
+        //    [[DisposeCapability]] »).
+        // 5. Set newDisposableStack.[[DisposableState]] to pending.
+        let new_disposable_stack = agent
+            .heap
+            .create(DisposableStackHeapData::new(false))
+            .bind(gc);
+        // 6. Set newDisposableStack.[[DisposeCapability]] to
+        //    disposableStack.[[DisposeCapability]].
+        // 7. Set disposableStack.[[DisposeCapability]] to
+        //    NewDisposeCapability().
+        disposable_stack.move_resources_to(agent, new_disposable_stack);
+        // 8. Set disposableStack.[[DisposableState]] to disposed.
+        disposable_stack.set_disposed(agent);
+        // 9. Return newDisposableStack.
+        Ok(new_disposable_stack.into())
+    }
+
+    /// ### [DisposableStack.prototype.use ( value )](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack.prototype.use)
+    fn r#use<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let value = arguments.get(0).bind(gc.nogc());
+        // 1. Let disposableStack be the this value.
+        // 2. Perform ? RequireInternalSlot(disposableStack, [[DisposableState]]).
+        // 3. If disposableStack.[[DisposableState]] is disposed, throw a
+        //    ReferenceError exception.
+        let disposable_stack = require_pending_disposable_stack(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        let scoped_value = value.scope(agent, gc.nogc());
+        // 4. Perform ? AddDisposableResource(disposableStack.[[DisposeCapability]],
+        //    value, sync-dispose).
+        add_disposable_resource(
+            agent,
+            disposable_stack.unbind(),
+            value.unbind(),
+            DisposeHint::SyncDispose,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 5. Return value.
+        // SAFETY: scoped_value is not shared.
+        Ok(unsafe { scoped_value.take(agent) }.bind(gc.into_nogc()))
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.disposable_stack_prototype();
+        let disposable_stack_constructor = intrinsics.disposable_stack();
+        let disposable_stack_prototype_dispose = intrinsics.disposable_stack_prototype_dispose();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(9)
+            .with_prototype(object_prototype)
+            .with_builtin_function_property::<DisposableStackPrototypeAdopt>()
+            .with_constructor_property(disposable_stack_constructor)
+            .with_builtin_function_property::<DisposableStackPrototypeDefer>()
+            .with_builtin_intrinsic_function_property::<DisposableStackPrototypeDispose>()
+            .with_builtin_function_getter_property::<DisposableStackPrototypeGetDisposed>()
+            .with_builtin_function_property::<DisposableStackPrototypeMove>()
+            .with_builtin_function_property::<DisposableStackPrototypeUse>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::Dispose.into())
+                    .with_value(disposable_stack_prototype_dispose.into())
+                    .with_enumerable(DisposableStackPrototypeDispose::ENUMERABLE)
+                    .with_configurable(DisposableStackPrototypeDispose::CONFIGURABLE)
+                    .build()
+            })
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.DisposableStack.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+#[inline(always)]
+fn require_disposable_stack<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, DisposableStack<'a>> {
+    match value {
+        Value::DisposableStack(disposable_stack) if !disposable_stack.is_async(agent) => {
+            Ok(disposable_stack.bind(gc))
+        }
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Receiver is not a DisposableStack",
+            gc,
+        )),
+    }
+}
+
+/// Perform RequireInternalSlot(disposableStack, \[\[DisposableState]]) and
+/// throw a ReferenceError if the DisposableStack has already been disposed.
+#[inline(always)]
+fn require_pending_disposable_stack<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, DisposableStack<'a>> {
+    let disposable_stack = require_disposable_stack(agent, value, gc)?;
+    if disposable_stack.is_disposed(agent) {
+        return Err(throw_disposed_error(agent, gc));
+    }
+    Ok(disposable_stack)
+}
+
+fn throw_disposed_error<'a>(agent: &mut Agent, gc: NoGcScope<'a, '_>) -> JsError<'a> {
+    agent.throw_exception_with_static_message(
+        ExceptionType::ReferenceError,
+        "DisposableStack has already been disposed",
+        gc,
+    )
+}
+
+fn throw_not_callable_error<'a>(agent: &mut Agent, gc: NoGcScope<'a, '_>) -> JsError<'a> {
+    agent.throw_exception_with_static_message(
+        ExceptionType::TypeError,
+        "onDispose is not callable",
+        gc,
+    )
+}
@@ -5,9 +5,13 @@
 mod async_from_sync_iterator_objects;
 mod async_iterator_prototype;
 mod iterator_constructor;
+mod iterator_helper_prototype;
 mod iterator_prototype;
+mod wrap_for_valid_iterator_prototype;
 
 pub(crate) use async_from_sync_iterator_objects::*;
 pub(crate) use async_iterator_prototype::*;
 pub(crate) use iterator_constructor::*;
+pub(crate) use iterator_helper_prototype::*;
 pub(crate) use iterator_prototype::*;
+pub(crate) use wrap_for_valid_iterator_prototype::*;
@@ -454,7 +454,7 @@ pub(crate) fn async_from_sync_iterator_continuation<'a>(
         value_wrapper,
         on_fulfilled,
         on_rejected,
-        Some(promise_capability),
+        Some(promise_capability.into()),
         gc.nogc(),
     );
     // 15. Return promiseCapability.[[Promise]].
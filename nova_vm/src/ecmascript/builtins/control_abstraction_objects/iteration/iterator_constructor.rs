@@ -5,11 +5,14 @@
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
-        BuiltinIntrinsicConstructor, ExceptionType, Function, JsResult, Object, ProtoIntrinsics,
-        Realm, String, Value, builders::BuiltinFunctionBuilder, ordinary_create_from_constructor,
+        BuiltinIntrinsicConstructor, ConcatIterable, ExceptionType, Function,
+        IteratorHelperHeapData, IteratorHelperKind, JsResult, Object, PrimitiveHandling,
+        PropertyKey, ProtoIntrinsics, Realm, String, Value, builders::BuiltinFunctionBuilder,
+        get_iterator_flattenable, get_method, ordinary_create_from_constructor,
+        ordinary_has_instance,
     },
-    engine::{Bindable, GcScope},
-    heap::IntrinsicConstructorIndexes,
+    engine::{Bindable, GcScope, Scopable},
+    heap::{CreateHeapData, IntrinsicConstructorIndexes, WellKnownSymbols},
 };
 
 pub(crate) struct IteratorConstructor;
@@ -22,6 +25,20 @@ impl BuiltinIntrinsicConstructor for IteratorConstructor {
     const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::Iterator;
 }
 
+struct IteratorConcat;
+impl Builtin for IteratorConcat {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.concat;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorConstructor::concat);
+}
+
+struct IteratorFrom;
+impl Builtin for IteratorFrom {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.from;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorConstructor::from);
+}
+
 impl IteratorConstructor {
     fn constructor<'gc>(
         agent: &mut Agent,
@@ -56,15 +73,132 @@ impl IteratorConstructor {
         .map(Into::into)
     }
 
+    /// ### [Iterator.concat ( ...items )](https://tc39.es/proposal-iterator-sequencing/#sec-iterator.concat)
+    fn concat<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        items: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let items = items.bind(gc.nogc());
+        // 1. Let iterables be a new empty List.
+        let mut iterables: Vec<ConcatIterable> = Vec::with_capacity(items.len());
+        // 2. For each element item of items, do
+        let items = items
+            .iter()
+            .map(|item| item.scope(agent, gc.nogc()))
+            .collect::<Vec<_>>();
+        for item in items {
+            // a. If item is not an Object, throw a TypeError exception.
+            let Ok(item) = Object::try_from(item.get(agent)) else {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Iterator.concat argument is not an object",
+                    gc.into_nogc(),
+                ));
+            };
+            // b. Let method be ? GetMethod(item, %Symbol.iterator%).
+            let method = get_method(
+                agent,
+                item.into(),
+                PropertyKey::Symbol(WellKnownSymbols::Iterator.into()),
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            // c. If method is undefined, throw a TypeError exception.
+            let Some(method) = method else {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::TypeError,
+                    "Iterator.concat argument is not iterable",
+                    gc.into_nogc(),
+                ));
+            };
+            // d. Append the Record { [[OpenMethod]]: method, [[Iterable]]: item } to iterables.
+            iterables.push(ConcatIterable {
+                open_method: method.unbind(),
+                iterable: item.unbind(),
+            });
+        }
+        // 3. Let closure be a new Abstract Closure with no parameters that
+        //    captures iterables and performs the following steps when called:
+        // 4. Let gen be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterators]] »).
+        // 5. Set gen.[[UnderlyingIterators]] to a new empty List.
+        // 6. Return gen.
+        Ok(agent
+            .heap
+            .create(IteratorHelperHeapData::new(
+                None,
+                None,
+                IteratorHelperKind::Concat {
+                    iterables,
+                    index: 0,
+                    inner: None,
+                },
+            ))
+            .into())
+    }
+
+    /// ### [27.1.3.2.1 Iterator.from ( O )](https://tc39.es/ecma262/#sec-iterator.from)
+    fn from<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let o = arguments.get(0).bind(gc.nogc());
+        // 1. Let iteratorRecord be ? GetIteratorFlattenable(O, iterate-string-primitives).
+        let iterator_record = get_iterator_flattenable(
+            agent,
+            o.unbind(),
+            PrimitiveHandling::IterateStringPrimitives,
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        let iterator = iterator_record.iterator.scope(agent, gc.nogc());
+        let next_method = iterator_record
+            .next_method
+            .map(|f| f.scope(agent, gc.nogc()));
+        // 2. Let hasInstance be ? OrdinaryHasInstance(%Iterator%, iteratorRecord.[[Iterator]]).
+        let has_instance = ordinary_has_instance(
+            agent,
+            agent.current_realm_record().intrinsics().iterator(),
+            iterator_record.iterator.unbind(),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        // 3. If hasInstance is true, then
+        if has_instance {
+            // a. Return iteratorRecord.[[Iterator]].
+            return Ok(iterator.get(agent).bind(gc).into());
+        }
+        // 4. Let wrapper be OrdinaryObjectCreate(%WrapForValidIteratorPrototype%, « [[Iterated]] »).
+        // 5. Set wrapper.[[Iterated]] to iteratorRecord.
+        // 6. Return wrapper.
+        Ok(agent
+            .heap
+            .create(IteratorHelperHeapData::new(
+                Some(iterator.get(agent)),
+                next_method.map(|f| f.get(agent)),
+                IteratorHelperKind::WrapForValidIterator,
+            ))
+            .bind(gc)
+            .into())
+    }
+
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
         let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
         let iterator_prototype = intrinsics.iterator_prototype();
         let function_prototype = intrinsics.function_prototype();
 
         BuiltinFunctionBuilder::new_intrinsic_constructor::<IteratorConstructor>(agent, realm)
-            .with_property_capacity(1)
+            .with_property_capacity(3)
             .with_prototype(function_prototype)
             .with_prototype_property(iterator_prototype.into())
+            .with_builtin_function_property::<IteratorConcat>()
+            .with_builtin_function_property::<IteratorFrom>()
             .build();
     }
 }
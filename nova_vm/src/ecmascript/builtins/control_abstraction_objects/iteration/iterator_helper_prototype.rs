@@ -0,0 +1,684 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, ExceptionType,
+        IteratorHelper, IteratorHelperKind, IteratorHelperState, IteratorRecord, JsResult, Object,
+        PrimitiveHandling, Realm, String, Value, builders::OrdinaryObjectBuilder, call_function,
+        create_iter_result_object, get_iterator_direct, get_iterator_flattenable,
+        if_abrupt_close_iterator, iterator_close_with_error, iterator_close_with_value,
+        iterator_step, iterator_step_value, throw_not_callable, to_boolean,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{ArenaAccessMut, WellKnownSymbols},
+};
+
+pub(crate) struct IteratorHelperPrototype;
+
+struct IteratorHelperPrototypeNext;
+impl Builtin for IteratorHelperPrototypeNext {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.next;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorHelperPrototype::next);
+}
+
+struct IteratorHelperPrototypeReturn;
+impl Builtin for IteratorHelperPrototypeReturn {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.r#return;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorHelperPrototype::r#return);
+}
+
+impl IteratorHelperPrototype {
+    /// ### [27.1.2.1.1 %IteratorHelperPrototype%.next ( )](https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.next)
+    fn next<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        // 1. Return ? GeneratorResume(this value, undefined, "Iterator Helper").
+        // 1. Let state be ? GeneratorValidate(generator, generatorBrand).
+        let generator = generator_validate(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 2. If state is completed, return CreateIteratorResultObject(undefined, true).
+        if generator.state(agent) == IteratorHelperState::Completed {
+            return create_iter_result_object(agent, Value::Undefined, true, gc.into_nogc())
+                .map(|o| o.into());
+        }
+        // 3. Assert: state is either suspended-start or suspended-yield.
+        // 7. Set generator.[[GeneratorState]] to executing.
+        generator.set_state(agent, IteratorHelperState::Executing);
+        let scoped_generator = generator.scope(agent, gc.nogc());
+        // 9. Resume the suspended evaluation of genContext.
+        let result = resume(agent, generator.unbind(), gc.reborrow()).unbind();
+        let gc = gc.into_nogc();
+        let result = result.bind(gc);
+        let generator = scoped_generator.get(agent).bind(gc);
+        match result {
+            Ok(Some(value)) => {
+                // GeneratorYield: Set generator.[[GeneratorState]] to suspended-yield.
+                generator.set_state(agent, IteratorHelperState::SuspendedYield);
+                create_iter_result_object(agent, value, false, gc).map(|o| o.into())
+            }
+            Ok(None) => {
+                // The closure returned: the generator is now completed.
+                generator.set_state(agent, IteratorHelperState::Completed);
+                create_iter_result_object(agent, Value::Undefined, true, gc).map(|o| o.into())
+            }
+            Err(err) => {
+                generator.set_state(agent, IteratorHelperState::Completed);
+                Err(err)
+            }
+        }
+    }
+
+    /// ### [27.1.2.1.2 %IteratorHelperPrototype%.return ( )](https://tc39.es/ecma262/#sec-%iteratorhelperprototype%.return)
+    fn r#return<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[UnderlyingIterators]]).
+        // 3. Assert: O has a [[GeneratorState]] slot.
+        // 6. Return ? GeneratorResumeAbrupt(O, C, "Iterator Helper").
+        // GeneratorResumeAbrupt 1. Let state be ? GeneratorValidate(generator, generatorBrand).
+        let o = generator_validate(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        match o.state(agent) {
+            // 4. If O.[[GeneratorState]] is suspended-start, then
+            IteratorHelperState::SuspendedStart => {
+                // a. Set O.[[GeneratorState]] to completed.
+                o.set_state(agent, IteratorHelperState::Completed);
+                // b. NOTE: Once a generator enters the completed state it never
+                //    leaves it and its associated execution context is never
+                //    resumed. Any execution state associated with O can be
+                //    discarded at this point.
+                // c. Perform ? IteratorCloseAll(O.[[UnderlyingIterators]], NormalCompletion(unused)).
+                if let Some(iterator) = o.iterator(agent) {
+                    iterator_close_with_value(
+                        agent,
+                        iterator.unbind(),
+                        Value::Undefined,
+                        gc.reborrow(),
+                    )
+                    .unbind()?;
+                }
+                // d. Return CreateIteratorResultObject(undefined, true).
+                create_iter_result_object(agent, Value::Undefined, true, gc.into_nogc())
+                    .map(|o| o.into())
+            }
+            // GeneratorResumeAbrupt 2. If state is completed, then
+            IteratorHelperState::Completed => {
+                // b. Return CreateIteratorResultObject(undefined, true).
+                create_iter_result_object(agent, Value::Undefined, true, gc.into_nogc())
+                    .map(|o| o.into())
+            }
+            IteratorHelperState::SuspendedYield => {
+                // 5. Let C be ReturnCompletion(undefined).
+                // GeneratorResumeAbrupt 8. Set generator.[[GeneratorState]] to executing.
+                o.set_state(agent, IteratorHelperState::Executing);
+                let scoped_o = o.scope(agent, gc.nogc());
+                // 10. Resume the suspended evaluation of genContext using
+                //     abruptCompletion as the result of the operation that
+                //     suspended it.
+                let result = resume_return(agent, o.unbind(), gc.reborrow()).unbind();
+                let gc = gc.into_nogc();
+                let result = result.bind(gc);
+                scoped_o
+                    .get(agent)
+                    .set_state(agent, IteratorHelperState::Completed);
+                result?;
+                create_iter_result_object(agent, Value::Undefined, true, gc).map(|o| o.into())
+            }
+            IteratorHelperState::Executing => unreachable!(),
+        }
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let this = intrinsics.iterator_helper_prototype();
+        let iterator_prototype = intrinsics.iterator_prototype();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(3)
+            .with_prototype(iterator_prototype)
+            .with_builtin_function_property::<IteratorHelperPrototypeNext>()
+            .with_builtin_function_property::<IteratorHelperPrototypeReturn>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Iterator_Helper.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
+
+/// ### [27.5.3.2 GeneratorValidate ( generator, generatorBrand )](https://tc39.es/ecma262/#sec-generatorvalidate)
+///
+/// GeneratorValidate with the "Iterator Helper" brand.
+fn generator_validate<'a>(
+    agent: &mut Agent,
+    generator: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, IteratorHelper<'a>> {
+    // 1. Perform ? RequireInternalSlot(generator, [[GeneratorState]]).
+    // 2. Perform ? RequireInternalSlot(generator, [[GeneratorBrand]]).
+    // 3. If generator.[[GeneratorBrand]] is not generatorBrand, throw a TypeError exception.
+    let Value::IteratorHelper(generator) = generator else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Not an Iterator Helper object",
+            gc,
+        ));
+    };
+    if generator.is_wrap_for_valid_iterator(agent) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Not an Iterator Helper object",
+            gc,
+        ));
+    }
+    // 5. Let state be generator.[[GeneratorState]].
+    // 6. If state is executing, throw a TypeError exception.
+    if generator.state(agent) == IteratorHelperState::Executing {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "The generator is currently running",
+            gc,
+        ));
+    }
+    // 7. Return state.
+    Ok(generator.bind(gc))
+}
+
+/// Convert an Iterator Helper counter into a Number value.
+fn counter_value(counter: u64) -> Value<'static> {
+    // Note: counter can never exceed 2^53 - 1 in practice.
+    Value::try_from(counter as i64).unwrap()
+}
+
+/// Get the underlying Iterator Record of an Iterator Helper, throwing a
+/// TypeError if its next method is not callable.
+fn underlying_iterator<'a>(
+    agent: &mut Agent,
+    generator: IteratorHelper,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, IteratorRecord<'a>> {
+    match generator.iterator_record(agent) {
+        Some(iterated) => Ok(iterated.bind(gc)),
+        None => Err(throw_not_callable(agent, gc)),
+    }
+}
+
+/// Resume the abstract closure of an Iterator Helper from its start or from
+/// its last Yield point. Returns the next value to yield, or None if the
+/// closure returned.
+fn resume<'a>(
+    agent: &mut Agent,
+    generator: IteratorHelper,
+    gc: GcScope<'a, '_>,
+) -> JsResult<'a, Option<Value<'a>>> {
+    match generator.kind(agent) {
+        IteratorHelperKind::Map { .. } => resume_map(agent, generator, gc),
+        IteratorHelperKind::Filter { .. } => resume_filter(agent, generator, gc),
+        IteratorHelperKind::Take { .. } => resume_take(agent, generator, gc),
+        IteratorHelperKind::Drop { .. } => resume_drop(agent, generator, gc),
+        IteratorHelperKind::FlatMap { .. } => resume_flat_map(agent, generator, gc),
+        IteratorHelperKind::Concat { .. } => resume_concat(agent, generator, gc),
+        IteratorHelperKind::WrapForValidIterator => unreachable!(),
+    }
+}
+
+/// ### [27.1.4.8 Iterator.prototype.map ( mapper )](https://tc39.es/ecma262/#sec-iterator.prototype.map)
+///
+/// Steps 6.b.i through 6.b.vii of the closure.
+fn resume_map<'a>(
+    agent: &mut Agent,
+    generator: IteratorHelper,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, Option<Value<'a>>> {
+    let generator = generator.bind(gc.nogc());
+    let scoped_generator = generator.scope(agent, gc.nogc());
+    let iterated = underlying_iterator(agent, generator, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    // i. Let value be ? IteratorStepValue(iterated).
+    let value = iterator_step_value(agent, iterated.unbind(), gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    // ii. If value is done, return ReturnCompletion(undefined).
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    let generator = scoped_generator.get(agent).bind(gc.nogc());
+    let IteratorHelperKind::Map { mapper, counter } = *generator.kind(agent) else {
+        unreachable!()
+    };
+    // iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+    let mapped = call_function(
+        agent,
+        mapper.unbind(),
+        Value::Undefined,
+        Some(ArgumentsList::from_mut_slice(&mut [
+            value.unbind(),
+            counter_value(counter),
+        ])),
+        gc.reborrow(),
+    )
+    .unbind()
+    .bind(gc.nogc());
+    let generator = scoped_generator.get(agent).bind(gc.nogc());
+    // iv. IfAbruptCloseIterator(mapped, iterated).
+    let iterated = generator.iterator_record(agent).unwrap();
+    let mapped = if_abrupt_close_iterator!(agent, mapped, iterated, gc);
+    // vii. Set counter to counter + 1.
+    if let IteratorHelperKind::Map { counter, .. } = &mut generator.get_mut(agent).kind {
+        *counter += 1;
+    }
+    // v. Let completion be Completion(Yield(mapped)).
+    Ok(Some(mapped.unbind()))
+}
+
+/// ### [27.1.4.4 Iterator.prototype.filter ( predicate )](https://tc39.es/ecma262/#sec-iterator.prototype.filter)
+///
+/// Step 6.b of the closure.
+fn resume_filter<'a>(
+    agent: &mut Agent,
+    generator: IteratorHelper,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, Option<Value<'a>>> {
+    let generator = generator.bind(gc.nogc());
+    let scoped_generator = generator.scope(agent, gc.nogc());
+    // b. Repeat,
+    loop {
+        let generator = scoped_generator.get(agent).bind(gc.nogc());
+        let iterated = underlying_iterator(agent, generator, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // i. Let value be ? IteratorStepValue(iterated).
+        let value = iterator_step_value(agent, iterated.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // ii. If value is done, return ReturnCompletion(undefined).
+        let Some(value) = value else {
+            return Ok(None);
+        };
+        let scoped_value = value.scope(agent, gc.nogc());
+        let generator = scoped_generator.get(agent).bind(gc.nogc());
+        let IteratorHelperKind::Filter { predicate, counter } = *generator.kind(agent) else {
+            unreachable!()
+        };
+        // iii. Let selected be Completion(Call(predicate, undefined, « value, 𝔽(counter) »)).
+        let selected = call_function(
+            agent,
+            predicate.unbind(),
+            Value::Undefined,
+            Some(ArgumentsList::from_mut_slice(&mut [
+                value.unbind(),
+                counter_value(counter),
+            ])),
+            gc.reborrow(),
+        )
+        .unbind()
+        .bind(gc.nogc());
+        let generator = scoped_generator.get(agent).bind(gc.nogc());
+        // iv. IfAbruptCloseIterator(selected, iterated).
+        let iterated = generator.iterator_record(agent).unwrap();
+        let selected = if_abrupt_close_iterator!(agent, selected, iterated, gc);
+        // vi. Set counter to counter + 1.
+        if let IteratorHelperKind::Filter { counter, .. } = &mut generator.get_mut(agent).kind {
+            *counter += 1;
+        }
+        // v. If ToBoolean(selected) is true, then
+        if to_boolean(agent, selected) {
+            // 1. Let completion be Completion(Yield(value)).
+            // SAFETY: scoped_value is not shared.
+            return Ok(Some(
+                unsafe { scoped_value.take(agent) }.bind(gc.into_nogc()),
+            ));
+        }
+    }
+}
+
+/// ### [27.1.4.11 Iterator.prototype.take ( limit )](https://tc39.es/ecma262/#sec-iterator.prototype.take)
+///
+/// Step 10.b of the closure.
+fn resume_take<'a>(
+    agent: &mut Agent,
+    generator: IteratorHelper,
+    gc: GcScope<'a, '_>,
+) -> JsResult<'a, Option<Value<'a>>> {
+    let generator = generator.bind(gc.nogc());
+    let IteratorHelperKind::Take { remaining } = *generator.kind(agent) else {
+        unreachable!()
+    };
+    // i. If remaining = 0, then
+    if remaining == 0.0 {
+        // 1. Return ? IteratorClose(iterated, ReturnCompletion(undefined)).
+        let iterator = generator.iterator(agent).unwrap();
+        iterator_close_with_value(agent, iterator.unbind(), Value::Undefined, gc)?;
+        return Ok(None);
+    }
+    let iterated = underlying_iterator(agent, generator, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    // ii. If remaining ≠ +∞, then
+    if remaining != f64::INFINITY {
+        // 1. Set remaining to remaining - 1.
+        if let IteratorHelperKind::Take { remaining } = &mut generator.get_mut(agent).kind {
+            *remaining -= 1.0;
+        }
+    }
+    // iii. Let value be ? IteratorStepValue(iterated).
+    // iv. If value is done, return ReturnCompletion(undefined).
+    // v. Let completion be Completion(Yield(value)).
+    iterator_step_value(agent, iterated.unbind(), gc)
+}
+
+/// ### [27.1.4.2 Iterator.prototype.drop ( limit )](https://tc39.es/ecma262/#sec-iterator.prototype.drop)
+///
+/// Steps 10.b and 10.c of the closure.
+fn resume_drop<'a>(
+    agent: &mut Agent,
+    generator: IteratorHelper,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, Option<Value<'a>>> {
+    let generator = generator.bind(gc.nogc());
+    let scoped_generator = generator.scope(agent, gc.nogc());
+    // b. Repeat, while remaining > 0,
+    loop {
+        let generator = scoped_generator.get(agent).bind(gc.nogc());
+        let IteratorHelperKind::Drop { remaining } = *generator.kind(agent) else {
+            unreachable!()
+        };
+        if remaining <= 0.0 {
+            break;
+        }
+        let iterated = underlying_iterator(agent, generator, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // i. If remaining ≠ +∞, then
+        if remaining != f64::INFINITY {
+            // 1. Set remaining to remaining - 1.
+            if let IteratorHelperKind::Drop { remaining } = &mut generator.get_mut(agent).kind {
+                *remaining -= 1.0;
+            }
+        }
+        // ii. Let next be ? IteratorStep(iterated).
+        let next = iterator_step(agent, iterated.unbind(), gc.reborrow()).unbind()?;
+        // iii. If next is done, return ReturnCompletion(undefined).
+        if next.is_none() {
+            return Ok(None);
+        }
+    }
+    let generator = scoped_generator.get(agent).bind(gc.nogc());
+    let iterated = underlying_iterator(agent, generator, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    // c. Repeat,
+    // i. Let value be ? IteratorStepValue(iterated).
+    // ii. If value is done, return ReturnCompletion(undefined).
+    // iii. Let completion be Completion(Yield(value)).
+    iterator_step_value(agent, iterated.unbind(), gc)
+}
+
+/// ### [27.1.4.6 Iterator.prototype.flatMap ( mapper )](https://tc39.es/ecma262/#sec-iterator.prototype.flatmap)
+///
+/// Step 6.b of the closure.
+fn resume_flat_map<'a>(
+    agent: &mut Agent,
+    generator: IteratorHelper,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, Option<Value<'a>>> {
+    let generator = generator.bind(gc.nogc());
+    let scoped_generator = generator.scope(agent, gc.nogc());
+    // b. Repeat,
+    loop {
+        let generator = scoped_generator.get(agent).bind(gc.nogc());
+        let IteratorHelperKind::FlatMap { inner, .. } = *generator.kind(agent) else {
+            unreachable!()
+        };
+        // viii. Repeat, while innerAlive is true,
+        if let Some(inner) = inner {
+            // 1. Let innerValue be Completion(IteratorStepValue(innerIterator)).
+            let inner_value = iterator_step_value(agent, inner.unbind(), gc.reborrow())
+                .unbind()
+                .bind(gc.nogc());
+            let generator = scoped_generator.get(agent).bind(gc.nogc());
+            // 2. IfAbruptCloseIterator(innerValue, iterated).
+            let iterated = generator.iterator_record(agent).unwrap();
+            let inner_value = if_abrupt_close_iterator!(agent, inner_value, iterated, gc);
+            match inner_value {
+                // 3. If innerValue is done, then
+                None => {
+                    // a. Set innerAlive to false.
+                    if let IteratorHelperKind::FlatMap { inner, .. } =
+                        &mut generator.get_mut(agent).kind
+                    {
+                        *inner = None;
+                    }
+                    continue;
+                }
+                // 4. Else,
+                // a. Let completion be Completion(Yield(innerValue)).
+                Some(value) => return Ok(Some(value.unbind())),
+            }
+        }
+        let iterated = underlying_iterator(agent, generator, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // i. Let value be ? IteratorStepValue(iterated).
+        let value = iterator_step_value(agent, iterated.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // ii. If value is done, return ReturnCompletion(undefined).
+        let Some(value) = value else {
+            return Ok(None);
+        };
+        let generator = scoped_generator.get(agent).bind(gc.nogc());
+        let IteratorHelperKind::FlatMap {
+            mapper, counter, ..
+        } = *generator.kind(agent)
+        else {
+            unreachable!()
+        };
+        // iii. Let mapped be Completion(Call(mapper, undefined, « value, 𝔽(counter) »)).
+        let mapped = call_function(
+            agent,
+            mapper.unbind(),
+            Value::Undefined,
+            Some(ArgumentsList::from_mut_slice(&mut [
+                value.unbind(),
+                counter_value(counter),
+            ])),
+            gc.reborrow(),
+        )
+        .unbind()
+        .bind(gc.nogc());
+        let generator = scoped_generator.get(agent).bind(gc.nogc());
+        // iv. IfAbruptCloseIterator(mapped, iterated).
+        let iterated = generator.iterator_record(agent).unwrap();
+        let mapped = if_abrupt_close_iterator!(agent, mapped, iterated, gc);
+        // v. Let innerIterator be Completion(GetIteratorFlattenable(mapped, reject-primitives)).
+        let inner_iterator = get_iterator_flattenable(
+            agent,
+            mapped.unbind(),
+            PrimitiveHandling::RejectPrimitives,
+            gc.reborrow(),
+        )
+        .unbind()
+        .bind(gc.nogc());
+        let generator = scoped_generator.get(agent).bind(gc.nogc());
+        // vi. IfAbruptCloseIterator(innerIterator, iterated).
+        let iterated = generator.iterator_record(agent).unwrap();
+        let inner_iterator = if_abrupt_close_iterator!(agent, inner_iterator, iterated, gc);
+        // Note: IteratorStepValue(innerIterator) would immediately throw a
+        // TypeError if the next method is not callable; we throw it here.
+        let Some(inner_iterator) = inner_iterator.into_iterator_record() else {
+            let error = throw_not_callable(agent, gc.nogc());
+            return Err(iterator_close_with_error(
+                agent,
+                iterated.iterator.unbind(),
+                error.unbind(),
+                gc,
+            ));
+        };
+        // vii. Let innerAlive be true.
+        // ix. Set counter to counter + 1.
+        if let IteratorHelperKind::FlatMap { counter, inner, .. } =
+            &mut generator.get_mut(agent).kind
+        {
+            *inner = Some(inner_iterator.unbind());
+            *counter += 1;
+        }
+    }
+}
+
+/// ### [Iterator.concat ( ...items )](https://tc39.es/proposal-iterator-sequencing/#sec-iterator.concat)
+///
+/// Step 3.a of the closure.
+fn resume_concat<'a>(
+    agent: &mut Agent,
+    generator: IteratorHelper,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, Option<Value<'a>>> {
+    let generator = generator.bind(gc.nogc());
+    let scoped_generator = generator.scope(agent, gc.nogc());
+    // a. For each Record iterable of iterables, do
+    loop {
+        let generator = scoped_generator.get(agent).bind(gc.nogc());
+        let IteratorHelperKind::Concat {
+            iterables,
+            index,
+            inner,
+        } = generator.kind(agent)
+        else {
+            unreachable!()
+        };
+        let (inner, iterable) = (*inner, iterables.get(*index).copied());
+        // v. Repeat, while innerAlive is true,
+        if let Some(inner) = inner {
+            // 1. Let innerValue be ? IteratorStepValue(iteratorRecord).
+            let inner_value = iterator_step_value(agent, inner.unbind(), gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            match inner_value {
+                // 2. If innerValue is done, then
+                None => {
+                    // a. Set innerAlive to false.
+                    let generator = scoped_generator.get(agent).bind(gc.nogc());
+                    if let IteratorHelperKind::Concat { inner, .. } =
+                        &mut generator.get_mut(agent).kind
+                    {
+                        *inner = None;
+                    }
+                    continue;
+                }
+                // 3. Else,
+                // a. Let completion be Completion(Yield(innerValue)).
+                Some(value) => return Ok(Some(value.unbind().bind(gc.into_nogc()))),
+            }
+        }
+        // b. Return ReturnCompletion(undefined).
+        let Some(iterable) = iterable else {
+            return Ok(None);
+        };
+        if let IteratorHelperKind::Concat { index, .. } = &mut generator.get_mut(agent).kind {
+            *index += 1;
+        }
+        // i. Let iter be ? Call(iterable.[[OpenMethod]], iterable.[[Iterable]]).
+        let iter = call_function(
+            agent,
+            iterable.open_method.unbind(),
+            iterable.iterable.unbind().into(),
+            None,
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        // ii. If iter is not an Object, throw a TypeError exception.
+        let Ok(iter) = Object::try_from(iter) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Iterator is not an object",
+                gc.into_nogc(),
+            ));
+        };
+        // iii. Let iteratorRecord be ? GetIteratorDirect(iter).
+        let iterator_record = get_iterator_direct(agent, iter.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        // Note: IteratorStepValue(iteratorRecord) would immediately throw a
+        // TypeError if the next method is not callable; we throw it here.
+        let Some(iterator_record) = iterator_record else {
+            return Err(throw_not_callable(agent, gc.into_nogc()));
+        };
+        // iv. Let innerAlive be true.
+        let generator = scoped_generator.get(agent).bind(gc.nogc());
+        if let IteratorHelperKind::Concat { inner, .. } = &mut generator.get_mut(agent).kind {
+            *inner = Some(iterator_record.unbind());
+        }
+    }
+}
+
+/// Resume the abstract closure of an Iterator Helper from its last Yield
+/// point with a return completion.
+fn resume_return<'a>(
+    agent: &mut Agent,
+    generator: IteratorHelper,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    let generator = generator.bind(gc.nogc());
+    match *generator.kind(agent) {
+        IteratorHelperKind::Map { .. }
+        | IteratorHelperKind::Filter { .. }
+        | IteratorHelperKind::Take { .. }
+        | IteratorHelperKind::Drop { .. } => {
+            // IfAbruptCloseIterator(completion, iterated).
+            let iterator = generator.iterator(agent).unwrap();
+            iterator_close_with_value(agent, iterator.unbind(), Value::Undefined, gc)?;
+        }
+        IteratorHelperKind::FlatMap { inner, .. } => {
+            let scoped_generator = generator.scope(agent, gc.nogc());
+            if let Some(inner) = inner {
+                // b. If completion is an abrupt completion, then
+                // i. Let backupCompletion be Completion(IteratorClose(innerIterator, completion)).
+                let backup_completion = iterator_close_with_value(
+                    agent,
+                    inner.iterator.unbind(),
+                    Value::Undefined,
+                    gc.reborrow(),
+                )
+                .unbind()
+                .bind(gc.nogc());
+                // ii. IfAbruptCloseIterator(backupCompletion, iterated).
+                let iterated = scoped_generator.get(agent).iterator_record(agent).unwrap();
+                if_abrupt_close_iterator!(agent, backup_completion, iterated, gc);
+            }
+            // iii. Return ? IteratorClose(iterated, completion).
+            let iterator = scoped_generator.get(agent).iterator(agent).unwrap();
+            iterator_close_with_value(agent, iterator.unbind(), Value::Undefined, gc)?;
+        }
+        IteratorHelperKind::Concat { inner, .. } => {
+            if let Some(inner) = inner {
+                // b. If completion is an abrupt completion, then
+                // i. Return ? IteratorClose(iteratorRecord, completion).
+                iterator_close_with_value(agent, inner.iterator.unbind(), Value::Undefined, gc)?;
+            }
+        }
+        IteratorHelperKind::WrapForValidIterator => unreachable!(),
+    }
+    Ok(())
+}
@@ -5,14 +5,16 @@
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, Array, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
-        BuiltinSetter, ExceptionType, IteratorRecord, JsResult, Object, PropertyKey, Realm, String,
+        BuiltinSetter, ExceptionType, Function, IteratorHelperHeapData, IteratorHelperKind,
+        IteratorRecord, JsResult, MaybeInvalidIteratorRecord, Object, PropertyKey, Realm, String,
         Value, builders::OrdinaryObjectBuilder, call, get_iterator_direct,
-        if_abrupt_close_iterator, is_callable, iterator_close_with_error,
-        iterator_close_with_value, iterator_step_value, setter_that_ignores_prototype_properties,
-        throw_not_callable, to_boolean,
+        get_maybe_invalid_iterator_direct, if_abrupt_close_iterator, is_callable,
+        iterator_close_with_error, iterator_close_with_value, iterator_step_value,
+        setter_that_ignores_prototype_properties, throw_not_callable, to_boolean,
+        to_integer_or_infinity_f64, to_number,
     },
     engine::{Bindable, GcScope, Scopable, ScopableCollection},
-    heap::WellKnownSymbols,
+    heap::{CreateHeapData, WellKnownSymbols},
 };
 
 pub(crate) struct IteratorPrototype;
@@ -33,6 +35,22 @@ impl Builtin for IteratorPrototypeEvery {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::every);
 }
 
+struct IteratorPrototypeDrop;
+impl Builtin for IteratorPrototypeDrop {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.drop;
+    const KEY: Option<PropertyKey<'static>> = None;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::drop);
+}
+
+struct IteratorPrototypeFilter;
+impl Builtin for IteratorPrototypeFilter {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.filter;
+    const KEY: Option<PropertyKey<'static>> = None;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::filter);
+}
+
 struct IteratorPrototypeFind;
 impl Builtin for IteratorPrototypeFind {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.find;
@@ -41,6 +59,14 @@ impl Builtin for IteratorPrototypeFind {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::find);
 }
 
+struct IteratorPrototypeFlatMap;
+impl Builtin for IteratorPrototypeFlatMap {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.flatMap;
+    const KEY: Option<PropertyKey<'static>> = None;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::flat_map);
+}
+
 struct IteratorPrototypeForEach;
 impl Builtin for IteratorPrototypeForEach {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.forEach;
@@ -49,6 +75,14 @@ impl Builtin for IteratorPrototypeForEach {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::for_each);
 }
 
+struct IteratorPrototypeMap;
+impl Builtin for IteratorPrototypeMap {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.map;
+    const KEY: Option<PropertyKey<'static>> = None;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::map);
+}
+
 struct IteratorPrototypeSome;
 impl Builtin for IteratorPrototypeSome {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.some;
@@ -65,6 +99,14 @@ impl Builtin for IteratorPrototypeReduce {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::reduce);
 }
 
+struct IteratorPrototypeTake;
+impl Builtin for IteratorPrototypeTake {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.take;
+    const KEY: Option<PropertyKey<'static>> = None;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(IteratorPrototype::take);
+}
+
 struct IteratorPrototypeToArray;
 impl Builtin for IteratorPrototypeToArray {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.toArray;
@@ -100,6 +142,34 @@ impl IteratorPrototype {
         Ok(this_value.unbind())
     }
 
+    /// ### [27.1.4.2 Iterator.prototype.drop ( limit )](https://tc39.es/ecma262/#sec-iterator.prototype.drop)
+    fn drop<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let O be the this value.
+        // ...
+        // 9. Set iterated to ? GetIteratorDirect(O).
+        let (iterated, integer_limit) =
+            get_iterated_with_limit(agent, this_value, arguments.get(0), gc)?;
+        // 10. Let closure be a new Abstract Closure with no parameters that
+        //     captures iterated and integerLimit and performs the following
+        //     steps when called:
+        // a. Let remaining be integerLimit.
+        // 11. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterators]] »).
+        // 12. Set result.[[UnderlyingIterators]] to « iterated ».
+        // 13. Return result.
+        Ok(create_iterator_helper(
+            agent,
+            iterated,
+            IteratorHelperKind::Drop {
+                remaining: integer_limit,
+            },
+        ))
+    }
+
     /// ### [27.1.4.3 Iterator.prototype.every ( predicate )](https://tc39.es/ecma262/#sec-iterator.prototype.every)
     fn every<'gc>(
         agent: &mut Agent,
@@ -205,6 +275,40 @@ impl IteratorPrototype {
         }
     }
 
+    /// ### [27.1.4.4 Iterator.prototype.filter ( predicate )](https://tc39.es/ecma262/#sec-iterator.prototype.filter)
+    fn filter<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let O be the this value.
+        // ...
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let (iterated, predicate) = get_iterated_with_callback(
+            agent,
+            this_value,
+            arguments.get(0),
+            "'predicate' is not callable",
+            gc,
+        )?;
+        // 6. Let closure be a new Abstract Closure with no parameters that
+        //    captures iterated and predicate and performs the following steps
+        //    when called:
+        // a. Let counter be 0.
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterators]] »).
+        // 8. Set result.[[UnderlyingIterators]] to « iterated ».
+        // 9. Return result.
+        Ok(create_iterator_helper(
+            agent,
+            iterated,
+            IteratorHelperKind::Filter {
+                predicate,
+                counter: 0,
+            },
+        ))
+    }
+
     /// ### [27.1.4.5 Iterator.prototype.find ( predicate )](https://tc39.es/ecma262/#sec-iterator.prototype.find)
     fn find<'gc>(
         agent: &mut Agent,
@@ -319,6 +423,41 @@ impl IteratorPrototype {
         }
     }
 
+    /// ### [27.1.4.6 Iterator.prototype.flatMap ( mapper )](https://tc39.es/ecma262/#sec-iterator.prototype.flatmap)
+    fn flat_map<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let O be the this value.
+        // ...
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let (iterated, mapper) = get_iterated_with_callback(
+            agent,
+            this_value,
+            arguments.get(0),
+            "'mapper' is not callable",
+            gc,
+        )?;
+        // 6. Let closure be a new Abstract Closure with no parameters that
+        //    captures iterated and mapper and performs the following steps
+        //    when called:
+        // a. Let counter be 0.
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterators]] »).
+        // 8. Set result.[[UnderlyingIterators]] to « iterated ».
+        // 9. Return result.
+        Ok(create_iterator_helper(
+            agent,
+            iterated,
+            IteratorHelperKind::FlatMap {
+                mapper,
+                counter: 0,
+                inner: None,
+            },
+        ))
+    }
+
     /// ### [27.1.4.7 Iterator.prototype.forEach ( procedure )](https://tc39.es/ecma262/#sec-iterator.prototype.foreach)
     fn for_each<'gc>(
         agent: &mut Agent,
@@ -418,6 +557,37 @@ impl IteratorPrototype {
         }
     }
 
+    /// ### [27.1.4.8 Iterator.prototype.map ( mapper )](https://tc39.es/ecma262/#sec-iterator.prototype.map)
+    fn map<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let O be the this value.
+        // ...
+        // 5. Set iterated to ? GetIteratorDirect(O).
+        let (iterated, mapper) = get_iterated_with_callback(
+            agent,
+            this_value,
+            arguments.get(0),
+            "'mapper' is not callable",
+            gc,
+        )?;
+        // 6. Let closure be a new Abstract Closure with no parameters that
+        //    captures iterated and mapper and performs the following steps
+        //    when called:
+        // a. Let counter be 0.
+        // 7. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterators]] »).
+        // 8. Set result.[[UnderlyingIterators]] to « iterated ».
+        // 9. Return result.
+        Ok(create_iterator_helper(
+            agent,
+            iterated,
+            IteratorHelperKind::Map { mapper, counter: 0 },
+        ))
+    }
+
     /// ### [27.1.4.9 Iterator.prototype.reduce ( reducer \[ , initialValue \] )](https://tc39.es/ecma262/#sec-iterator.prototype.reduce)
     fn reduce<'gc>(
         agent: &mut Agent,
@@ -657,6 +827,34 @@ impl IteratorPrototype {
         }
     }
 
+    /// ### [27.1.4.11 Iterator.prototype.take ( limit )](https://tc39.es/ecma262/#sec-iterator.prototype.take)
+    fn take<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let O be the this value.
+        // ...
+        // 9. Set iterated to ? GetIteratorDirect(O).
+        let (iterated, integer_limit) =
+            get_iterated_with_limit(agent, this_value, arguments.get(0), gc)?;
+        // 10. Let closure be a new Abstract Closure with no parameters that
+        //     captures iterated and integerLimit and performs the following
+        //     steps when called:
+        // a. Let remaining be integerLimit.
+        // 11. Let result be CreateIteratorFromClosure(closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterators]] »).
+        // 12. Set result.[[UnderlyingIterators]] to « iterated ».
+        // 13. Return result.
+        Ok(create_iterator_helper(
+            agent,
+            iterated,
+            IteratorHelperKind::Take {
+                remaining: integer_limit,
+            },
+        ))
+    }
+
     /// 27.1.4.12 Iterator.prototype.toArray ( )
     fn to_array<'gc>(
         agent: &mut Agent,
@@ -763,16 +961,166 @@ impl IteratorPrototype {
         let this = intrinsics.iterator_prototype();
 
         OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
-            .with_property_capacity(8)
+            .with_property_capacity(13)
             .with_prototype(object_prototype)
             .with_builtin_function_property::<IteratorPrototypeIterator>()
+            .with_builtin_function_property::<IteratorPrototypeDrop>()
             .with_builtin_function_property::<IteratorPrototypeEvery>()
+            .with_builtin_function_property::<IteratorPrototypeFilter>()
             .with_builtin_function_property::<IteratorPrototypeFind>()
+            .with_builtin_function_property::<IteratorPrototypeFlatMap>()
             .with_builtin_function_property::<IteratorPrototypeForEach>()
+            .with_builtin_function_property::<IteratorPrototypeMap>()
             .with_builtin_function_property::<IteratorPrototypeSome>()
             .with_builtin_function_property::<IteratorPrototypeReduce>()
+            .with_builtin_function_property::<IteratorPrototypeTake>()
             .with_builtin_function_property::<IteratorPrototypeToArray>()
             .with_builtin_function_getter_setter_property::<IteratorPrototypeToStringTag>()
             .build();
     }
 }
+
+/// Steps 1 to 5 of Iterator.prototype.filter, flatMap and map: validate that
+/// the this value is an object and the callback is callable, and get the
+/// iterator's next method.
+fn get_iterated_with_callback<'gc>(
+    agent: &mut Agent,
+    this_value: Value,
+    callback: Value,
+    message: &'static str,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, (MaybeInvalidIteratorRecord<'gc>, Function<'gc>)> {
+    let nogc = gc.nogc();
+    let this_value = this_value.bind(nogc);
+    let callback = callback.bind(nogc);
+
+    // 1. Let O be the this value.
+    // 2. If O is not an Object, throw a TypeError exception.
+    let Ok(o) = Object::try_from(this_value) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "'this' is not an object",
+            gc.into_nogc(),
+        ));
+    };
+
+    // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+    // 4. If IsCallable(callback) is false, then
+    let Some(callback) = is_callable(callback, nogc).unbind().bind(nogc) else {
+        // a. Let error be ThrowCompletion(a newly created TypeError object).
+        let error =
+            agent.throw_exception_with_static_message(ExceptionType::TypeError, message, nogc);
+        // b. Return ? IteratorClose(iterated, error).
+        return Err(iterator_close_with_error(
+            agent,
+            o.unbind(),
+            error.unbind(),
+            gc,
+        ));
+    };
+    let callback = callback.scope(agent, nogc);
+
+    // 5. Set iterated to ? GetIteratorDirect(O).
+    let iterated = get_maybe_invalid_iterator_direct(agent, o.unbind(), gc.reborrow()).unbind()?;
+    let gc = gc.into_nogc();
+    // SAFETY: not shared.
+    let callback = unsafe { callback.take(agent) }.bind(gc);
+    Ok((iterated.bind(gc), callback))
+}
+
+/// Steps 1 to 9 of Iterator.prototype.drop and take: validate that the this
+/// value is an object and the limit is a non-negative number, and get the
+/// iterator's next method.
+fn get_iterated_with_limit<'gc>(
+    agent: &mut Agent,
+    this_value: Value,
+    limit: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, (MaybeInvalidIteratorRecord<'gc>, f64)> {
+    let nogc = gc.nogc();
+    let this_value = this_value.bind(nogc);
+    let limit = limit.bind(nogc);
+
+    // 1. Let O be the this value.
+    // 2. If O is not an Object, throw a TypeError exception.
+    let Ok(o) = Object::try_from(this_value) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "'this' is not an object",
+            gc.into_nogc(),
+        ));
+    };
+    let scoped_o = o.scope(agent, nogc);
+
+    // 3. Let iterated be the Iterator Record { [[Iterator]]: O, [[NextMethod]]: undefined, [[Done]]: false }.
+    // 4. Let numLimit be Completion(ToNumber(limit)).
+    let num_limit = to_number(agent, limit.unbind(), gc.reborrow())
+        .unbind()
+        .bind(gc.nogc());
+    // 5. IfAbruptCloseIterator(numLimit, iterated).
+    let iterated = IteratorRecord {
+        iterator: scoped_o.get(agent),
+        // Note: the next method is not used by IteratorClose.
+        next_method: agent.current_realm_record().intrinsics().iterator().into(),
+    };
+    let num_limit = if_abrupt_close_iterator!(agent, num_limit, iterated, gc);
+    let num_limit = num_limit.into_f64(agent);
+    // 6. If numLimit is NaN, then
+    if num_limit.is_nan() {
+        // a. Let error be ThrowCompletion(a newly created RangeError object).
+        let error = agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "'limit' must be a number",
+            gc.nogc(),
+        );
+        // b. Return ? IteratorClose(iterated, error).
+        return Err(iterator_close_with_error(
+            agent,
+            scoped_o.get(agent),
+            error.unbind(),
+            gc,
+        ));
+    }
+    // 7. Let integerLimit be ! ToIntegerOrInfinity(numLimit).
+    let integer_limit = to_integer_or_infinity_f64(num_limit);
+    // 8. If integerLimit < 0, then
+    if integer_limit < 0.0 {
+        // a. Let error be ThrowCompletion(a newly created RangeError object).
+        let error = agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "'limit' must be non-negative",
+            gc.nogc(),
+        );
+        // b. Return ? IteratorClose(iterated, error).
+        return Err(iterator_close_with_error(
+            agent,
+            scoped_o.get(agent),
+            error.unbind(),
+            gc,
+        ));
+    }
+
+    // 9. Set iterated to ? GetIteratorDirect(O).
+    let iterated = get_maybe_invalid_iterator_direct(agent, scoped_o.get(agent), gc)?;
+    Ok((iterated, integer_limit))
+}
+
+/// ### [27.1.2.1 CreateIteratorFromClosure ( closure, "Iterator Helper", %IteratorHelperPrototype%, « [[UnderlyingIterators]] » )](https://tc39.es/ecma262/#sec-createiteratorfromclosure)
+fn create_iterator_helper<'gc>(
+    agent: &mut Agent,
+    iterated: MaybeInvalidIteratorRecord<'gc>,
+    kind: IteratorHelperKind<'gc>,
+) -> Value<'gc> {
+    let MaybeInvalidIteratorRecord {
+        iterator,
+        next_method,
+    } = iterated;
+    agent
+        .heap
+        .create(IteratorHelperHeapData::new(
+            Some(iterator),
+            next_method,
+            kind,
+        ))
+        .into()
+}
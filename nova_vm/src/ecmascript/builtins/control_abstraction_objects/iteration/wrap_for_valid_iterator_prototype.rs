@@ -0,0 +1,128 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, ExceptionType,
+        IteratorHelper, JsResult, Realm, String, Value, builders::OrdinaryObjectBuilder,
+        call_function, create_iter_result_object, get_method, throw_not_callable,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+};
+
+pub(crate) struct WrapForValidIteratorPrototype;
+
+struct WrapForValidIteratorPrototypeNext;
+impl Builtin for WrapForValidIteratorPrototypeNext {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.next;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(WrapForValidIteratorPrototype::next);
+}
+
+struct WrapForValidIteratorPrototypeReturn;
+impl Builtin for WrapForValidIteratorPrototypeReturn {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.r#return;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(WrapForValidIteratorPrototype::r#return);
+}
+
+impl WrapForValidIteratorPrototype {
+    /// ### [27.1.3.2.1.1.1 %WrapForValidIteratorPrototype%.next ( )](https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%.next)
+    fn next<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[Iterated]]).
+        let o = require_iterated(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Let iteratorRecord be O.[[Iterated]].
+        let iterator = o.iterator(agent).unwrap();
+        // 4. Return ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+        let Some(next_method) = o.next_method(agent) else {
+            return Err(throw_not_callable(agent, gc.into_nogc()));
+        };
+        call_function(
+            agent,
+            next_method.unbind(),
+            iterator.unbind().into(),
+            None,
+            gc,
+        )
+    }
+
+    /// ### [27.1.3.2.1.1.2 %WrapForValidIteratorPrototype%.return ( )](https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%.return)
+    fn r#return<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let this_value = this_value.bind(gc.nogc());
+        // 1. Let O be this value.
+        // 2. Perform ? RequireInternalSlot(O, [[Iterated]]).
+        let o = require_iterated(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Let iterator be O.[[Iterated]].[[Iterator]].
+        // 4. Assert: iterator is an Object.
+        let iterator = o.iterator(agent).unwrap();
+        let scoped_iterator = iterator.scope(agent, gc.nogc());
+        // 5. Let returnMethod be ? GetMethod(iterator, "return").
+        let return_method = get_method(
+            agent,
+            iterator.unbind().into(),
+            BUILTIN_STRING_MEMORY.r#return.into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        // 6. If returnMethod is undefined, then
+        let Some(return_method) = return_method else {
+            // a. Return CreateIteratorResultObject(undefined, true).
+            return create_iter_result_object(agent, Value::Undefined, true, gc.into_nogc())
+                .map(|o| o.into());
+        };
+        // 7. Return ? Call(returnMethod, iterator).
+        call_function(
+            agent,
+            return_method.unbind(),
+            scoped_iterator.get(agent).into(),
+            None,
+            gc,
+        )
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let this = intrinsics.wrap_for_valid_iterator_prototype();
+        let iterator_prototype = intrinsics.iterator_prototype();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(2)
+            .with_prototype(iterator_prototype)
+            .with_builtin_function_property::<WrapForValidIteratorPrototypeNext>()
+            .with_builtin_function_property::<WrapForValidIteratorPrototypeReturn>()
+            .build();
+    }
+}
+
+fn require_iterated<'a>(
+    agent: &mut Agent,
+    o: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, IteratorHelper<'a>> {
+    match o {
+        Value::IteratorHelper(o) if o.is_wrap_for_valid_iterator(agent) => Ok(o.bind(gc)),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Not a wrapped iterator object",
+            gc,
+        )),
+    }
+}
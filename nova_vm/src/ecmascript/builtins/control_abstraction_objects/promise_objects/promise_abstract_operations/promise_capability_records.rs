@@ -6,11 +6,13 @@
 
 use crate::{
     ecmascript::{
-        Agent, BUILTIN_STRING_MEMORY, ExceptionType, Function, JsResult, Object, Promise,
-        PromiseHeapData, PromiseRejectionTrackerOperation, PromiseState, TryError, TryGetResult,
-        TryResult, Value, get, try_get,
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, BuiltinPromiseFinallyFunction, ExceptionType,
+        Function, JsResult, Object, Promise, PromiseHeapData, PromiseRejectionTrackerOperation,
+        PromiseResolvingFunctionHeapData, PromiseResolvingFunctionType, PromiseState, TryError,
+        TryGetResult, TryResult, Value, call_function, construct, get, is_callable, is_constructor,
+        try_get,
     },
-    engine::{Bindable, GcScope, NoGcScope, Scopable, bindable_handle},
+    engine::{Bindable, GcScope, NoGcScope, Scopable, Scoped, bindable_handle},
     heap::{
         ArenaAccess, ArenaAccessMut, CompactionLists, CreateHeapData, HeapMarkAndSweep, WorkQueues,
     },
@@ -22,11 +24,11 @@ use super::promise_jobs::new_promise_resolve_thenable_job;
 /// of resolving or rejecting that promise.
 ///
 /// NOTE: In the spec, promise capability records contain an object that is
-/// usable as a promise, together with its resolve and reject functions. In our
-/// current implementation, we only ever support built-in promises, and not
-/// other promise-like objects (e.g. we don't support Promise subclasses), and
-/// for that we don't need to store resolve and reject functions, we can create
-/// them only when needed.
+/// usable as a promise, together with its resolve and reject functions. This
+/// type only supports built-in promises, and for that we don't need to store
+/// resolve and reject functions, we can create them only when needed. Promise
+/// capabilities of arbitrary constructors are represented by
+/// [`PromiseCapabilityRecord`].
 ///
 /// The `must_be_unresolved` boolean is used to map the `AlreadyResolved` state
 /// of a pair of resolve/reject functions with the promise state. If
@@ -44,8 +46,8 @@ impl<'a> PromiseCapability<'a> {
     ///
     /// Create a new PromiseCapability
     ///
-    /// NOTE: Our implementation doesn't take C as a parameter, since we don't
-    /// yet support promise subclassing.
+    /// NOTE: This is NewPromiseCapability(%Promise%). Use
+    /// [`PromiseCapabilityRecord::new`] for other constructors.
     pub fn new(agent: &mut Agent, gc: NoGcScope<'a, '_>) -> Self {
         Self::from_promise(agent.heap.create(PromiseHeapData::default()), true).bind(gc)
     }
@@ -384,6 +386,313 @@ impl HeapMarkAndSweep for PromiseCapability<'static> {
     }
 }
 
+/// ### [27.2.1.1 PromiseCapability Records](https://tc39.es/ecma262/#sec-promisecapability-records)
+///
+/// A PromiseCapability Record created by NewPromiseCapability for an arbitrary
+/// constructor.
+///
+/// Capabilities of built-in promises, including instances of Promise
+/// subclasses that pass the built-in resolving functions through to their
+/// executor, use the [`PromiseCapability`] fast path. Anything else resolves
+/// and rejects its promise by calling the functions that the constructor
+/// passed to the executor.
+#[derive(Debug, Clone)]
+pub(crate) enum PromiseCapabilityRecord<'a> {
+    Intrinsic(PromiseCapability<'a>),
+    Object {
+        /// \[\[Promise\]\]
+        promise: Object<'a>,
+        /// \[\[Resolve\]\]
+        resolve: Function<'a>,
+        /// \[\[Reject\]\]
+        reject: Function<'a>,
+    },
+}
+bindable_handle!(PromiseCapabilityRecord);
+
+impl<'a> PromiseCapabilityRecord<'a> {
+    /// ### [27.2.1.5 NewPromiseCapability ( C )](https://tc39.es/ecma262/#sec-newpromisecapability)
+    pub(crate) fn new(agent: &mut Agent, c: Value, mut gc: GcScope<'a, '_>) -> JsResult<'a, Self> {
+        let c = c.bind(gc.nogc());
+        // 1. If IsConstructor(C) is false, throw a TypeError exception.
+        let Some(c) = is_constructor(agent, c) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Promise capability constructor is not a constructor",
+                gc.into_nogc(),
+            ));
+        };
+        if c == agent.current_realm_record().intrinsics().promise().into() {
+            // NOTE: Constructing %Promise% is not observable, so we can skip
+            // creating the executor and resolving functions.
+            return Ok(Self::Intrinsic(PromiseCapability::new(
+                agent,
+                gc.into_nogc(),
+            )));
+        }
+        // 2. NOTE: C is assumed to be a constructor function that supports
+        //    the parameter conventions of the Promise constructor.
+        // 3. Let resolvingFunctions be the Record { [[Resolve]]: undefined, [[Reject]]: undefined }.
+        // 4. Let executorClosure be a new Abstract Closure with parameters
+        //    (resolve, reject) that captures resolvingFunctions...
+        // 5. Let executor be CreateBuiltinFunction(executorClosure, 2, "", « »).
+        let executor =
+            BuiltinPromiseFinallyFunction::create_get_capabilities_executor(agent).bind(gc.nogc());
+        let scoped_executor = executor.scope(agent, gc.nogc());
+        // 6. Let promise be ? Construct(C, « executor »).
+        let promise = construct(
+            agent,
+            c.unbind(),
+            Some(ArgumentsList::from_mut_value(&mut executor.unbind().into())),
+            None,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let promise = promise.bind(gc);
+        // SAFETY: not shared.
+        let executor = unsafe { scoped_executor.take(agent) }.bind(gc);
+        let (resolve, reject) = executor.get_capabilities(agent);
+        // 7. If IsCallable(resolvingFunctions.[[Resolve]]) is false, throw a TypeError exception.
+        // 8. If IsCallable(resolvingFunctions.[[Reject]]) is false, throw a TypeError exception.
+        let (Some(resolve), Some(reject)) = (is_callable(resolve, gc), is_callable(reject, gc))
+        else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Promise resolve or reject function is not callable",
+                gc,
+            ));
+        };
+        // NOTE: If C handed the built-in resolving functions of a built-in
+        // promise through to the executor unchanged, then calling them is
+        // equivalent to using the intrinsic promise capability directly.
+        if let (
+            Object::Promise(promise),
+            Function::BuiltinPromiseResolvingFunction(resolve),
+            Function::BuiltinPromiseResolvingFunction(reject),
+        ) = (promise, resolve, reject)
+        {
+            let resolve_data = resolve.get(agent);
+            let reject_data = reject.get(agent);
+            if matches!(
+                resolve_data.resolve_type,
+                PromiseResolvingFunctionType::Resolve
+            ) && matches!(
+                reject_data.resolve_type,
+                PromiseResolvingFunctionType::Reject
+            ) && resolve_data.promise_capability == reject_data.promise_capability
+                && resolve_data.promise_capability.promise == promise
+            {
+                return Ok(Self::Intrinsic(
+                    resolve_data.promise_capability.clone().bind(gc),
+                ));
+            }
+        }
+        // 9. Set resolvingFunctions.[[Resolve]] to resolve.
+        // 10. Set resolvingFunctions.[[Reject]] to reject.
+        // 11. Return the PromiseCapability Record { [[Promise]]: promise, [[Resolve]]: resolvingFunctions.[[Resolve]], [[Reject]]: resolvingFunctions.[[Reject]] }.
+        Ok(Self::Object {
+            promise,
+            resolve,
+            reject,
+        })
+    }
+
+    /// Get the \[\[Promise\]\] of the capability.
+    pub(crate) fn promise(&self) -> Object<'a> {
+        match self {
+            Self::Intrinsic(capability) => capability.promise().into(),
+            Self::Object { promise, .. } => *promise,
+        }
+    }
+
+    /// Get the \[\[Resolve\]\] function of the capability.
+    ///
+    /// NOTE: Intrinsic capabilities create a new resolve function object on
+    /// each call.
+    pub(crate) fn resolve_function(&self, agent: &mut Agent) -> Function<'a> {
+        match self {
+            Self::Intrinsic(capability) => agent
+                .heap
+                .create(PromiseResolvingFunctionHeapData {
+                    object_index: None,
+                    promise_capability: capability.clone(),
+                    resolve_type: PromiseResolvingFunctionType::Resolve,
+                })
+                .into(),
+            Self::Object { resolve, .. } => *resolve,
+        }
+    }
+
+    /// Get the \[\[Reject\]\] function of the capability.
+    ///
+    /// NOTE: Intrinsic capabilities create a new reject function object on
+    /// each call.
+    pub(crate) fn reject_function(&self, agent: &mut Agent) -> Function<'a> {
+        match self {
+            Self::Intrinsic(capability) => agent
+                .heap
+                .create(PromiseResolvingFunctionHeapData {
+                    object_index: None,
+                    promise_capability: capability.clone(),
+                    resolve_type: PromiseResolvingFunctionType::Reject,
+                })
+                .into(),
+            Self::Object { reject, .. } => *reject,
+        }
+    }
+
+    /// Perform ? Call(promiseCapability.\[\[Resolve\]\], undefined, « value »).
+    pub(crate) fn resolve<'gc>(
+        self,
+        agent: &mut Agent,
+        value: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, ()> {
+        match self {
+            Self::Intrinsic(capability) => {
+                capability.unbind().resolve(agent, value, gc);
+                Ok(())
+            }
+            Self::Object { resolve, .. } => call_function(
+                agent,
+                resolve.unbind(),
+                Value::Undefined,
+                Some(ArgumentsList::from_mut_value(&mut value.unbind())),
+                gc,
+            )
+            .map(|_| ()),
+        }
+    }
+
+    /// Perform ? Call(promiseCapability.\[\[Reject\]\], undefined, « reason »).
+    pub(crate) fn reject<'gc>(
+        self,
+        agent: &mut Agent,
+        reason: Value,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, ()> {
+        match self {
+            Self::Intrinsic(capability) => {
+                capability.reject(agent, reason, gc.into_nogc());
+                Ok(())
+            }
+            Self::Object { reject, .. } => call_function(
+                agent,
+                reject.unbind(),
+                Value::Undefined,
+                Some(ArgumentsList::from_mut_value(&mut reason.unbind())),
+                gc,
+            )
+            .map(|_| ()),
+        }
+    }
+
+    pub(crate) fn scope<'scope>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'_, 'scope>,
+    ) -> ScopedPromiseCapabilityRecord<'scope> {
+        match self {
+            Self::Intrinsic(PromiseCapability {
+                promise,
+                must_be_unresolved,
+            }) => ScopedPromiseCapabilityRecord::Intrinsic {
+                promise: promise.scope(agent, gc),
+                must_be_unresolved,
+            },
+            Self::Object {
+                promise,
+                resolve,
+                reject,
+            } => ScopedPromiseCapabilityRecord::Object {
+                promise: promise.scope(agent, gc),
+                resolve: resolve.scope(agent, gc),
+                reject: reject.scope(agent, gc),
+            },
+        }
+    }
+}
+
+impl<'a> From<PromiseCapability<'a>> for PromiseCapabilityRecord<'a> {
+    fn from(value: PromiseCapability<'a>) -> Self {
+        Self::Intrinsic(value)
+    }
+}
+
+impl HeapMarkAndSweep for PromiseCapabilityRecord<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match self {
+            Self::Intrinsic(capability) => capability.mark_values(queues),
+            Self::Object {
+                promise,
+                resolve,
+                reject,
+            } => {
+                promise.mark_values(queues);
+                resolve.mark_values(queues);
+                reject.mark_values(queues);
+            }
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match self {
+            Self::Intrinsic(capability) => capability.sweep_values(compactions),
+            Self::Object {
+                promise,
+                resolve,
+                reject,
+            } => {
+                promise.sweep_values(compactions);
+                resolve.sweep_values(compactions);
+                reject.sweep_values(compactions);
+            }
+        }
+    }
+}
+
+#[derive(Debug)]
+pub(crate) enum ScopedPromiseCapabilityRecord<'a> {
+    Intrinsic {
+        promise: Scoped<'a, Promise<'static>>,
+        must_be_unresolved: bool,
+    },
+    Object {
+        promise: Scoped<'a, Object<'static>>,
+        resolve: Scoped<'a, Function<'static>>,
+        reject: Scoped<'a, Function<'static>>,
+    },
+}
+
+impl<'b> ScopedPromiseCapabilityRecord<'b> {
+    /// Return the promise capability as unscoped.
+    pub(crate) fn get<'a>(
+        &self,
+        agent: &Agent,
+        gc: NoGcScope<'a, 'b>,
+    ) -> PromiseCapabilityRecord<'a> {
+        match self {
+            Self::Intrinsic {
+                promise,
+                must_be_unresolved,
+            } => PromiseCapabilityRecord::Intrinsic(PromiseCapability {
+                promise: promise.get(agent).bind(gc),
+                must_be_unresolved: *must_be_unresolved,
+            }),
+            Self::Object {
+                promise,
+                resolve,
+                reject,
+            } => PromiseCapabilityRecord::Object {
+                promise: promise.get(agent).bind(gc),
+                resolve: resolve.get(agent).bind(gc),
+                reject: reject.get(agent).bind(gc),
+            },
+        }
+    }
+}
+
 /// ### [27.2.1.1.1 IfAbruptRejectPromise ( value, capability )](https://tc39.es/ecma262/#sec-ifabruptrejectpromise)
 ///
 /// IfAbruptRejectPromise is a shorthand for a sequence of algorithm steps that
@@ -4,8 +4,10 @@
 
 use crate::{
     ecmascript::{
-        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Function, FunctionInternalProperties, JsError,
-        JsResult, OrdinaryObject, Promise, String, Value, call_function, function_handle, invoke,
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, ExceptionType, Function,
+        FunctionInternalProperties, JsError, JsResult, OrdinaryObject, PromiseGroup,
+        PromiseReactionType, String, Value, call_function, function_handle, invoke,
+        promise_resolve,
     },
     engine::{Bindable, GcScope, Scopable, bindable_handle},
     heap::{
@@ -32,6 +34,24 @@ enum PromiseFinallyFunctionType<'a> {
     ThrowReason {
         reason: JsError<'a>,
     },
+    /// GetCapabilitiesExecutor closure of NewPromiseCapability.
+    GetCapabilitiesExecutor {
+        resolve: Value<'a>,
+        reject: Value<'a>,
+    },
+    /// Promise.all Resolve Element Functions, Promise.allSettled Resolve and
+    /// Reject Element Functions, and Promise.any Reject Element Functions.
+    GroupElement {
+        promise_group: PromiseGroup<'a>,
+        /// \[\[Index\]\]
+        index: u32,
+        reaction_type: PromiseReactionType,
+        /// \[\[AlreadyCalled\]\]
+        already_called: bool,
+        /// The other element function of a Promise.allSettled pair, which
+        /// shares \[\[AlreadyCalled\]\] with this one.
+        sibling: Option<BuiltinPromiseFinallyFunction<'a>>,
+    },
 }
 
 /// ### [27.2.1.3.1 Promise Finally Functions](https://tc39.es/ecma262/#sec-promise.prototype.finally)
@@ -47,7 +67,10 @@ pub(crate) struct PromiseFinallyFunctionHeapData<'a> {
 }
 bindable_handle!(PromiseFinallyFunctionHeapData);
 
-/// Special functions created as part of `Promise.prototype.finally`.
+/// Special functions created as part of `Promise.prototype.finally`, the
+/// executor function passed to promise constructors by NewPromiseCapability,
+/// and the element functions of `Promise.all`, `Promise.allSettled` and
+/// `Promise.any`.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct BuiltinPromiseFinallyFunction<'a>(
@@ -72,6 +95,81 @@ impl<'f> BuiltinPromiseFinallyFunction<'f> {
         });
         (then_finally_closure, catch_finally_closure)
     }
+
+    /// Create the GetCapabilitiesExecutor function of
+    /// [NewPromiseCapability](https://tc39.es/ecma262/#sec-newpromisecapability).
+    pub(crate) fn create_get_capabilities_executor(agent: &mut Agent) -> Self {
+        agent.heap.create(PromiseFinallyFunctionHeapData {
+            backing_object: None,
+            resolve_type: PromiseFinallyFunctionType::GetCapabilitiesExecutor {
+                resolve: Value::Undefined,
+                reject: Value::Undefined,
+            },
+        })
+    }
+
+    /// Create the onFulfilled or onRejected element function for index
+    /// `index` of a `Promise.all` or `Promise.any` group.
+    pub(crate) fn create_group_element_function(
+        agent: &mut Agent,
+        promise_group: PromiseGroup<'f>,
+        index: u32,
+        reaction_type: PromiseReactionType,
+    ) -> Self {
+        agent.heap.create(PromiseFinallyFunctionHeapData {
+            backing_object: None,
+            resolve_type: PromiseFinallyFunctionType::GroupElement {
+                promise_group,
+                index,
+                reaction_type,
+                already_called: false,
+                sibling: None,
+            },
+        })
+    }
+
+    /// Create the onFulfilled and onRejected element functions for index
+    /// `index` of a `Promise.allSettled` group. The two functions share their
+    /// \[\[AlreadyCalled\]\] state.
+    pub(crate) fn create_all_settled_element_functions(
+        agent: &mut Agent,
+        promise_group: PromiseGroup<'f>,
+        index: u32,
+    ) -> (Self, Self) {
+        let on_fulfilled = Self::create_group_element_function(
+            agent,
+            promise_group,
+            index,
+            PromiseReactionType::Fulfill,
+        );
+        let on_rejected = agent.heap.create(PromiseFinallyFunctionHeapData {
+            backing_object: None,
+            resolve_type: PromiseFinallyFunctionType::GroupElement {
+                promise_group,
+                index,
+                reaction_type: PromiseReactionType::Reject,
+                already_called: false,
+                sibling: Some(on_fulfilled),
+            },
+        });
+        if let PromiseFinallyFunctionType::GroupElement { sibling, .. } =
+            &mut on_fulfilled.get_mut(agent).resolve_type
+        {
+            *sibling = Some(on_rejected.unbind());
+        }
+        (on_fulfilled, on_rejected)
+    }
+
+    /// Get the resolve and reject functions captured by a
+    /// GetCapabilitiesExecutor function.
+    pub(crate) fn get_capabilities(self, agent: &Agent) -> (Value<'f>, Value<'f>) {
+        match self.get(agent).resolve_type {
+            PromiseFinallyFunctionType::GetCapabilitiesExecutor { resolve, reject } => {
+                (resolve, reject)
+            }
+            _ => unreachable!(),
+        }
+    }
 }
 
 impl<'a> FunctionInternalProperties<'a> for BuiltinPromiseFinallyFunction<'a> {
@@ -82,7 +180,9 @@ impl<'a> FunctionInternalProperties<'a> for BuiltinPromiseFinallyFunction<'a> {
     fn get_length(self, agent: &Agent) -> u8 {
         match self.get(agent).resolve_type {
             PromiseFinallyFunctionType::ResolveFinally { .. }
-            | PromiseFinallyFunctionType::RejectFinally { .. } => 1,
+            | PromiseFinallyFunctionType::RejectFinally { .. }
+            | PromiseFinallyFunctionType::GroupElement { .. } => 1,
+            PromiseFinallyFunctionType::GetCapabilitiesExecutor { .. } => 2,
             PromiseFinallyFunctionType::ReturnValue { .. }
             | PromiseFinallyFunctionType::ThrowReason { .. } => 0,
         }
@@ -130,9 +230,9 @@ impl<'a> FunctionInternalProperties<'a> for BuiltinPromiseFinallyFunction<'a> {
                 .unbind()?
                 .bind(gc.nogc());
                 // SAFETY: not shared.
-                let _c = unsafe { c.take(agent) }.bind(gc.nogc());
+                let c = unsafe { c.take(agent) }.bind(gc.nogc());
                 // ii. Let p be ? PromiseResolve(C, result).
-                let p = Promise::resolve(agent, result.unbind(), gc.reborrow())
+                let p = promise_resolve(agent, c.unbind().into(), result.unbind(), gc.reborrow())
                     .unbind()?
                     .bind(gc.nogc());
                 // SAFETY: not shared.
@@ -172,9 +272,9 @@ impl<'a> FunctionInternalProperties<'a> for BuiltinPromiseFinallyFunction<'a> {
                 .unbind()?
                 .bind(gc.nogc());
                 // SAFETY: not shared.
-                let _c = unsafe { c.take(agent) }.bind(gc.nogc());
+                let c = unsafe { c.take(agent) }.bind(gc.nogc());
                 // ii. Let p be ? PromiseResolve(C, result).
-                let p = Promise::resolve(agent, result.unbind(), gc.reborrow())
+                let p = promise_resolve(agent, c.unbind().into(), result.unbind(), gc.reborrow())
                     .unbind()?
                     .bind(gc.nogc());
                 let reason = unsafe { reason.take(agent) }.bind(gc.nogc());
@@ -206,6 +306,63 @@ impl<'a> FunctionInternalProperties<'a> for BuiltinPromiseFinallyFunction<'a> {
                 // 1. Return ThrowCompletion(reason).
                 Err(reason.unbind().bind(gc.into_nogc()))
             }
+            PromiseFinallyFunctionType::GetCapabilitiesExecutor { resolve, reject } => {
+                // a. If resolvingFunctions.[[Resolve]] is not undefined, throw a TypeError exception.
+                // b. If resolvingFunctions.[[Reject]] is not undefined, throw a TypeError exception.
+                if !resolve.is_undefined() || !reject.is_undefined() {
+                    return Err(agent.throw_exception_with_static_message(
+                        ExceptionType::TypeError,
+                        "Promise executor has already been invoked",
+                        gc.into_nogc(),
+                    ));
+                }
+                let resolve = arguments_list.get(0).bind(gc.nogc());
+                let reject = arguments_list.get(1).bind(gc.nogc());
+                // c. Set resolvingFunctions.[[Resolve]] to resolve.
+                // d. Set resolvingFunctions.[[Reject]] to reject.
+                f.get_mut(agent).resolve_type =
+                    PromiseFinallyFunctionType::GetCapabilitiesExecutor {
+                        resolve: resolve.unbind(),
+                        reject: reject.unbind(),
+                    };
+                // e. Return undefined.
+                Ok(Value::Undefined)
+            }
+            PromiseFinallyFunctionType::GroupElement {
+                promise_group,
+                index,
+                reaction_type,
+                already_called,
+                sibling,
+            } => {
+                // 1. Let F be the active function object.
+                // 2. If F.[[AlreadyCalled]] is true, return undefined.
+                if already_called {
+                    return Ok(Value::Undefined);
+                }
+                // 3. Set F.[[AlreadyCalled]] to true.
+                for f in core::iter::once(f).chain(sibling) {
+                    if let PromiseFinallyFunctionType::GroupElement { already_called, .. } =
+                        &mut f.get_mut(agent).resolve_type
+                    {
+                        *already_called = true;
+                    }
+                }
+                // 4. Let index be F.[[Index]].
+                // 8. Set values[index] to x.
+                // 9. Set remainingElementsCount.[[Value]] to
+                //    remainingElementsCount.[[Value]] - 1.
+                // 10. If remainingElementsCount.[[Value]] = 0, settle
+                //     promiseCapability.
+                promise_group.unbind().settle(
+                    agent,
+                    reaction_type,
+                    index,
+                    arguments_list.get(0).unbind(),
+                    gc,
+                )?;
+                Ok(Value::Undefined)
+            }
         }
     }
 }
@@ -275,6 +432,18 @@ impl HeapMarkAndSweep for PromiseFinallyFunctionType<'static> {
             }
             PromiseFinallyFunctionType::ReturnValue { value } => value.mark_values(queues),
             PromiseFinallyFunctionType::ThrowReason { reason } => reason.mark_values(queues),
+            PromiseFinallyFunctionType::GetCapabilitiesExecutor { resolve, reject } => {
+                resolve.mark_values(queues);
+                reject.mark_values(queues);
+            }
+            PromiseFinallyFunctionType::GroupElement {
+                promise_group,
+                sibling,
+                ..
+            } => {
+                promise_group.mark_values(queues);
+                sibling.mark_values(queues);
+            }
         }
     }
 
@@ -287,6 +456,18 @@ impl HeapMarkAndSweep for PromiseFinallyFunctionType<'static> {
             }
             PromiseFinallyFunctionType::ReturnValue { value } => value.sweep_values(compactions),
             PromiseFinallyFunctionType::ThrowReason { reason } => reason.sweep_values(compactions),
+            PromiseFinallyFunctionType::GetCapabilitiesExecutor { resolve, reject } => {
+                resolve.sweep_values(compactions);
+                reject.sweep_values(compactions);
+            }
+            PromiseFinallyFunctionType::GroupElement {
+                promise_group,
+                sibling,
+                ..
+            } => {
+                promise_group.sweep_values(compactions);
+                sibling.sweep_values(compactions);
+            }
         }
     }
 }
@@ -4,8 +4,8 @@
 
 use crate::{
     ecmascript::{
-        Agent, Array, BUILTIN_STRING_MEMORY, ErrorHeapData, ExceptionType, OrdinaryObject, Promise,
-        PromiseCapability, PromiseReactionType, PropertyDescriptor, Value,
+        Agent, Array, BUILTIN_STRING_MEMORY, ErrorHeapData, ExceptionType, JsResult,
+        OrdinaryObject, PromiseCapabilityRecord, PromiseReactionType, PropertyDescriptor, Value,
         try_define_property_or_throw, unwrap_try,
     },
     engine::{Bindable, GcScope, NoGcScope, bindable_handle},
@@ -22,12 +22,12 @@ pub(crate) enum PromiseGroupType {
     Any,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub(crate) struct PromiseGroupRecord<'a> {
     pub(crate) promise_group_type: PromiseGroupType,
     pub(crate) remaining_elements_count: u32,
     pub(crate) result_array: Array<'a>,
-    pub(crate) promise: Promise<'a>,
+    pub(crate) capability: PromiseCapabilityRecord<'a>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
@@ -36,18 +36,6 @@ pub(crate) struct PromiseGroup<'a>(BaseIndex<'a, PromiseGroupRecord<'static>>);
 index_handle!(PromiseGroup);
 arena_vec_access!(PromiseGroup, 'a, PromiseGroupRecord, promise_group_records);
 
-impl<'a> PromiseGroupRecord<'a> {
-    fn take_result_and_promise(&mut self) -> (Array<'a>, Option<Promise<'a>>) {
-        self.remaining_elements_count = self.remaining_elements_count.saturating_sub(1);
-
-        if self.remaining_elements_count > 0 {
-            (self.result_array, None)
-        } else {
-            (self.result_array, Some(self.promise))
-        }
-    }
-}
-
 impl<'a> PromiseGroup<'a> {
     pub(crate) fn settle(
         self,
@@ -55,8 +43,8 @@ impl<'a> PromiseGroup<'a> {
         reaction_type: PromiseReactionType,
         index: u32,
         value: Value<'a>,
-        mut gc: GcScope<'a, '_>,
-    ) {
+        gc: GcScope<'a, '_>,
+    ) -> JsResult<'a, ()> {
         let group = self.bind(gc.nogc());
         let value = value.bind(gc.nogc());
         let record = group.get(agent);
@@ -64,120 +52,130 @@ impl<'a> PromiseGroup<'a> {
         match record.promise_group_type {
             PromiseGroupType::All => match reaction_type {
                 PromiseReactionType::Fulfill => {
-                    group
-                        .unbind()
-                        .fulfill(agent, index, value.unbind(), gc.reborrow());
+                    group.unbind().set_element(agent, index, value.unbind(), gc)
                 }
                 PromiseReactionType::Reject => {
-                    group
-                        .unbind()
-                        .immediately_reject(agent, value.unbind(), gc.nogc());
+                    group.unbind().immediately_reject(agent, value.unbind(), gc)
                 }
             },
             PromiseGroupType::AllSettled => {
                 let obj = PromiseGroup::to_all_settled_obj(agent, reaction_type, value, gc.nogc());
-                group
-                    .unbind()
-                    .fulfill(agent, index, obj.unbind(), gc.reborrow());
+                group.unbind().set_element(agent, index, obj.unbind(), gc)
             }
             PromiseGroupType::Any => match reaction_type {
                 PromiseReactionType::Fulfill => {
                     group
                         .unbind()
-                        .immediately_resolve(agent, value.unbind(), gc.reborrow());
+                        .immediately_resolve(agent, value.unbind(), gc)
                 }
                 PromiseReactionType::Reject => {
-                    group
-                        .unbind()
-                        .reject(agent, index, value.unbind(), gc.into_nogc());
+                    group.unbind().set_element(agent, index, value.unbind(), gc)
                 }
             },
         }
     }
 
-    fn fulfill(self, agent: &mut Agent, index: u32, value: Value<'a>, mut gc: GcScope<'a, '_>) {
+    /// Set values\[index\] (or errors\[index\] for `Promise.any`) to the
+    /// given value and count the element as settled.
+    fn set_element(
+        self,
+        agent: &mut Agent,
+        index: u32,
+        value: Value<'a>,
+        gc: GcScope<'a, '_>,
+    ) -> JsResult<'a, ()> {
         let group = self.bind(gc.nogc());
         let value = value.bind(gc.nogc());
 
-        let promise_group_record = group.get_mut(agent);
-        let (result_array, promise_to_resolve) = promise_group_record.take_result_and_promise();
-
+        let result_array = group.get(agent).result_array;
         let elements = result_array.as_mut_slice(agent);
         elements[index as usize] = Some(value.unbind());
 
-        if let Some(promise_to_resolve) = promise_to_resolve {
-            group.pop_empty_records(agent);
-
-            let capability = PromiseCapability::from_promise(promise_to_resolve, true);
-            capability
-                .unbind()
-                .resolve(agent, result_array.unbind().into(), gc.reborrow());
-        }
+        group.unbind().decrement_remaining_elements(agent, gc)
     }
 
-    fn reject(self, agent: &mut Agent, index: u32, error: Value<'a>, gc: NoGcScope<'a, '_>) {
-        let promise_group = self.bind(gc);
-        let error = error.bind(gc);
-
-        let promise_group_record = promise_group.get_mut(agent);
-        let (result_array, promise_to_resolve) = promise_group_record.take_result_and_promise();
-
-        let elements = result_array.as_mut_slice(agent);
-        elements[index as usize] = Some(error.unbind());
-
-        if let Some(promise_to_resolve) = promise_to_resolve {
-            promise_group.pop_empty_records(agent);
+    /// Set remainingElementsCount.\[\[Value\]\] to
+    /// remainingElementsCount.\[\[Value\]\] - 1. If it reaches 0, resolve the
+    /// group's capability with the values array, or for `Promise.any` reject
+    /// it with an AggregateError holding the errors array.
+    pub(crate) fn decrement_remaining_elements(
+        self,
+        agent: &mut Agent,
+        gc: GcScope<'a, '_>,
+    ) -> JsResult<'a, ()> {
+        let group = self.bind(gc.nogc());
+        let record = group.get_mut(agent);
+        record.remaining_elements_count = record.remaining_elements_count.saturating_sub(1);
+        if record.remaining_elements_count > 0 {
+            return Ok(());
+        }
+        let result_array = record.result_array;
+        let capability = record.capability.clone();
 
-            let aggregate_error = agent
-                .heap
-                .create(ErrorHeapData::new(
-                    ExceptionType::AggregateError,
-                    None,
-                    None,
-                ))
-                .bind(gc);
+        if !matches!(record.promise_group_type, PromiseGroupType::Any) {
+            // Let valuesArray be CreateArrayFromList(values).
+            // Return ? Call(promiseCapability.[[Resolve]], undefined, « valuesArray »).
+            return capability
+                .unbind()
+                .resolve(agent, result_array.unbind().into(), gc);
+        }
 
-            let capability = PromiseCapability::from_promise(promise_to_resolve, true);
-            unwrap_try(try_define_property_or_throw(
-                agent,
-                aggregate_error,
-                BUILTIN_STRING_MEMORY.errors.into(),
-                PropertyDescriptor {
-                    value: Some(result_array.unbind().into()),
-                    writable: Some(true),
-                    get: None,
-                    set: None,
-                    enumerable: Some(true),
-                    configurable: Some(true),
-                },
+        // Let error be a newly created AggregateError object.
+        let aggregate_error = agent
+            .heap
+            .create(ErrorHeapData::new(
+                ExceptionType::AggregateError,
                 None,
-                gc,
-            ));
-
-            capability.reject(agent, aggregate_error.into(), gc);
-        }
+                None,
+            ))
+            .bind(gc.nogc());
+        aggregate_error.capture_stack_trace(agent, 0, gc.nogc());
+
+        // Perform ! DefinePropertyOrThrow(error, "errors", PropertyDescriptor {
+        //   [[Configurable]]: true, [[Enumerable]]: false, [[Writable]]: true,
+        //   [[Value]]: CreateArrayFromList(errors) }).
+        unwrap_try(try_define_property_or_throw(
+            agent,
+            aggregate_error,
+            BUILTIN_STRING_MEMORY.errors.into(),
+            PropertyDescriptor {
+                value: Some(result_array.unbind().into()),
+                writable: Some(true),
+                get: None,
+                set: None,
+                enumerable: Some(false),
+                configurable: Some(true),
+            },
+            None,
+            gc.nogc(),
+        ));
+
+        // Return ? Call(promiseCapability.[[Reject]], undefined, « error »).
+        capability
+            .unbind()
+            .reject(agent, aggregate_error.unbind().into(), gc)
     }
 
-    fn immediately_resolve(self, agent: &mut Agent, value: Value<'a>, gc: GcScope<'a, '_>) {
+    fn immediately_resolve(
+        self,
+        agent: &mut Agent,
+        value: Value<'a>,
+        gc: GcScope<'a, '_>,
+    ) -> JsResult<'a, ()> {
         let value = value.bind(gc.nogc());
-        let promise_group = self.bind(gc.nogc());
-        let data = promise_group.get_mut(agent);
-
-        let capability = PromiseCapability::from_promise(data.promise, true);
-
-        promise_group.pop_empty_records(agent);
-        capability.unbind().resolve(agent, value.unbind(), gc);
+        let capability = self.get(agent).capability.clone();
+        capability.unbind().resolve(agent, value.unbind(), gc)
     }
 
-    fn immediately_reject(self, agent: &mut Agent, value: Value<'a>, gc: NoGcScope<'a, '_>) {
-        let value = value.bind(gc);
-        let promise_group = self.bind(gc);
-        let data = promise_group.get_mut(agent);
-
-        let capability = PromiseCapability::from_promise(data.promise, true);
-
-        promise_group.pop_empty_records(agent);
-        capability.reject(agent, value.unbind(), gc);
+    fn immediately_reject(
+        self,
+        agent: &mut Agent,
+        value: Value<'a>,
+        gc: GcScope<'a, '_>,
+    ) -> JsResult<'a, ()> {
+        let value = value.bind(gc.nogc());
+        let capability = self.get(agent).capability.clone();
+        capability.unbind().reject(agent, value.unbind(), gc)
     }
 
     fn to_all_settled_obj(
@@ -221,16 +219,6 @@ impl<'a> PromiseGroup<'a> {
 
         obj.unbind().into()
     }
-
-    fn pop_empty_records(self, agent: &mut Agent) {
-        while let Some(last) = agent.heap.promise_group_records.last() {
-            if last.remaining_elements_count == 0 {
-                agent.heap.promise_group_records.pop();
-            } else {
-                break;
-            }
-        }
-    }
 }
 
 #[doc(hidden)]
@@ -253,10 +241,10 @@ impl HeapMarkAndSweep for PromiseGroupRecord<'static> {
             promise_group_type: _,
             remaining_elements_count: _,
             result_array,
-            promise,
+            capability,
         } = self;
         result_array.mark_values(queues);
-        promise.mark_values(queues);
+        capability.mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
@@ -264,10 +252,10 @@ impl HeapMarkAndSweep for PromiseGroupRecord<'static> {
             promise_group_type: _,
             remaining_elements_count: _,
             result_array,
-            promise,
+            capability,
         } = self;
         result_array.sweep_values(compactions);
-        promise.sweep_values(compactions);
+        capability.sweep_values(compactions);
     }
 }
 
@@ -11,7 +11,7 @@ use crate::{
         PromiseResolvingFunctionHeapData, PromiseResolvingFunctionType, Value,
         async_module_execution_fulfilled, async_module_execution_rejected, call_function,
         create_iter_result_object, get_function_realm, import_get_module_namespace,
-        iterator_close_with_error, link_and_evaluate,
+        iterator_close_with_error, link_and_evaluate, resume_async_dispose_resources,
     },
     engine::{Bindable, GcScope, Global, NoGcScope, Scopable},
     heap::{ArenaAccess, CreateHeapData},
@@ -238,11 +238,15 @@ impl PromiseReactionJob {
                 // b. Return undefined.
                 return Ok(());
             }
-            PromiseReactionHandler::DynamicImport { promise, module } => {
+            PromiseReactionHandler::DynamicImport {
+                promise,
+                module,
+                phase,
+            } => {
                 assert!(reaction_data.capability.is_none());
                 match reaction_data.reaction_type {
                     PromiseReactionType::Fulfill => {
-                        link_and_evaluate(agent, promise.unbind(), module.unbind(), gc);
+                        link_and_evaluate(agent, promise.unbind(), module.unbind(), phase, gc);
                         return Ok(());
                     }
                     PromiseReactionType::Reject => {
@@ -250,12 +254,16 @@ impl PromiseReactionJob {
                         // b. Return unused.
                         (
                             Err(JsError::new(argument)),
-                            PromiseCapability::from_promise(promise, true),
+                            PromiseCapability::from_promise(promise, true).into(),
                         )
                     }
                 }
             }
-            PromiseReactionHandler::DynamicImportEvaluate { promise, module } => {
+            PromiseReactionHandler::DynamicImportEvaluate {
+                promise,
+                module,
+                phase,
+            } => {
                 assert!(reaction_data.capability.is_none());
                 match reaction_data.reaction_type {
                     PromiseReactionType::Fulfill => {
@@ -263,13 +271,14 @@ impl PromiseReactionJob {
                             agent,
                             promise.unbind(),
                             module.unbind(),
+                            phase,
                             gc.into_nogc(),
                         );
                         return Ok(());
                     }
                     PromiseReactionType::Reject => (
                         Err(JsError::new(argument)),
-                        PromiseCapability::from_promise(promise, true),
+                        PromiseCapability::from_promise(promise, true).into(),
                     ),
                 }
             }
@@ -278,11 +287,33 @@ impl PromiseReactionJob {
                 index,
             } => {
                 let reaction_type = reaction_data.reaction_type;
-                promise_group.unbind().settle(
+                return promise_group.unbind().settle(
                     agent,
                     reaction_type,
                     index,
                     argument.unbind(),
+                    gc,
+                );
+            }
+            PromiseReactionHandler::DisposeResources(disposable_stack) => {
+                assert!(reaction_data.capability.is_none());
+                let reaction_type = reaction_data.reaction_type;
+                resume_async_dispose_resources(
+                    agent,
+                    disposable_stack.unbind(),
+                    reaction_type,
+                    argument.unbind(),
+                    gc.reborrow(),
+                );
+                return Ok(());
+            }
+            PromiseReactionHandler::ArrayFromAsync(array_from_async) => {
+                assert!(reaction_data.capability.is_none());
+                let reaction_type = reaction_data.reaction_type;
+                array_from_async.unbind().resume(
+                    agent,
+                    reaction_type,
+                    argument.unbind(),
                     gc.reborrow(),
                 );
                 return Ok(());
@@ -297,7 +328,9 @@ impl PromiseReactionJob {
             // h. If handlerResult is an abrupt completion, then
             Err(err) => {
                 // i. Return ? Call(promiseCapability.[[Reject]], undefined, « handlerResult.[[Value]] »).
-                promise_capability.reject(agent, err.value(), gc.nogc())
+                promise_capability
+                    .unbind()
+                    .reject(agent, err.value().unbind(), gc)
             }
             // i. Else,
             Ok(value) => {
@@ -306,8 +339,7 @@ impl PromiseReactionJob {
                     .unbind()
                     .resolve(agent, value.unbind(), gc)
             }
-        };
-        Ok(())
+        }
     }
 }
 
@@ -349,7 +381,9 @@ pub(crate) fn new_promise_reaction_job(
         | PromiseReactionHandler::AsyncModule(_)
         | PromiseReactionHandler::DynamicImport { .. }
         | PromiseReactionHandler::DynamicImportEvaluate { .. }
-        | PromiseReactionHandler::PromiseGroup { .. } => None,
+        | PromiseReactionHandler::PromiseGroup { .. }
+        | PromiseReactionHandler::DisposeResources(_)
+        | PromiseReactionHandler::ArrayFromAsync(_) => None,
     };
 
     // 4. Return the Record { [[Job]]: job, [[Realm]]: handlerRealm }.
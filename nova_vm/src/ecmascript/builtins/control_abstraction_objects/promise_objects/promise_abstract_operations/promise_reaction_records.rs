@@ -4,8 +4,8 @@
 
 use crate::{
     ecmascript::{
-        AbstractModule, AsyncGenerator, AwaitReaction, Function, Object, Promise,
-        PromiseCapability, PromiseGroup, SourceTextModule,
+        AbstractModule, ArrayFromAsync, AsyncGenerator, AwaitReaction, DisposableStack, Function,
+        ImportPhase, Object, Promise, PromiseCapabilityRecord, PromiseGroup, SourceTextModule,
     },
     engine::{Bindable, bindable_handle},
     heap::{
@@ -49,15 +49,21 @@ pub(crate) enum PromiseReactionHandler<'a> {
     DynamicImport {
         promise: Promise<'a>,
         module: AbstractModule<'a>,
+        phase: ImportPhase,
     },
     DynamicImportEvaluate {
         promise: Promise<'a>,
         module: AbstractModule<'a>,
+        phase: ImportPhase,
     },
     PromiseGroup {
         index: u32,
         promise_group: PromiseGroup<'a>,
     },
+    /// Continuation of an asynchronous DisposeResources operation.
+    DisposeResources(DisposableStack<'a>),
+    /// Continuation of an Array.fromAsync operation.
+    ArrayFromAsync(ArrayFromAsync<'a>),
     Empty,
 }
 bindable_handle!(PromiseReactionHandler);
@@ -71,8 +77,16 @@ impl HeapMarkAndSweep for PromiseReactionHandler<'static> {
             Self::AsyncFromSyncIterator { done: _ } => {}
             Self::AsyncFromSyncIteratorClose(object) => object.mark_values(queues),
             Self::AsyncModule(module) => module.mark_values(queues),
-            Self::DynamicImport { promise, module }
-            | Self::DynamicImportEvaluate { promise, module } => {
+            Self::DynamicImport {
+                promise,
+                module,
+                phase: _,
+            }
+            | Self::DynamicImportEvaluate {
+                promise,
+                module,
+                phase: _,
+            } => {
                 promise.mark_values(queues);
                 module.mark_values(queues);
             }
@@ -80,6 +94,8 @@ impl HeapMarkAndSweep for PromiseReactionHandler<'static> {
                 index: _,
                 promise_group,
             } => promise_group.mark_values(queues),
+            Self::DisposeResources(disposable_stack) => disposable_stack.mark_values(queues),
+            Self::ArrayFromAsync(array_from_async) => array_from_async.mark_values(queues),
             Self::Empty => {}
         }
     }
@@ -94,8 +110,16 @@ impl HeapMarkAndSweep for PromiseReactionHandler<'static> {
             Self::AsyncFromSyncIterator { done: _ } => {}
             Self::AsyncFromSyncIteratorClose(object) => object.sweep_values(compactions),
             Self::AsyncModule(module) => module.sweep_values(compactions),
-            Self::DynamicImport { promise, module }
-            | Self::DynamicImportEvaluate { promise, module } => {
+            Self::DynamicImport {
+                promise,
+                module,
+                phase: _,
+            }
+            | Self::DynamicImportEvaluate {
+                promise,
+                module,
+                phase: _,
+            } => {
                 promise.sweep_values(compactions);
                 module.sweep_values(compactions);
             }
@@ -103,6 +127,8 @@ impl HeapMarkAndSweep for PromiseReactionHandler<'static> {
                 index: _,
                 promise_group,
             } => promise_group.sweep_values(compactions),
+            Self::DisposeResources(disposable_stack) => disposable_stack.sweep_values(compactions),
+            Self::ArrayFromAsync(array_from_async) => array_from_async.sweep_values(compactions),
             Self::Empty => {}
         }
     }
@@ -116,7 +142,7 @@ pub(crate) struct PromiseReactionRecord<'a> {
     ///
     /// The capabilities of the promise for which this record provides a
     /// reaction handler.
-    pub(crate) capability: Option<PromiseCapability<'a>>,
+    pub(crate) capability: Option<PromiseCapabilityRecord<'a>>,
     /// \[\[Type\]\]
     pub(crate) reaction_type: PromiseReactionType,
     /// \[\[Handler\]\]
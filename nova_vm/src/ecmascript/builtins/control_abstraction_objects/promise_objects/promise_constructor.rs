@@ -7,15 +7,16 @@ use crate::heap::ArenaAccessSoA;
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
-        BuiltinIntrinsicConstructor, ExceptionType, Function, IteratorRecord, JsError, JsResult,
-        Object, OrdinaryObject, Promise, PromiseCapability, PromiseGroupRecord, PromiseGroupType,
-        PromiseHeapData, PromiseReactionHandler, PromiseResolvingFunctionHeapData,
-        PromiseResolvingFunctionType, PromiseState, PropertyKey, ProtoIntrinsics, Realm, String,
-        Value, array_create, builders::BuiltinFunctionBuilder, call, call_function, get,
-        get_iterator, inner_promise_then, is_callable, is_constructor, iterator_close_with_error,
-        iterator_step_value, ordinary_create_from_constructor,
+        BuiltinIntrinsicConstructor, BuiltinPromiseFinallyFunction, ExceptionType, Function,
+        IteratorRecord, JsError, JsResult, Object, OrdinaryObject, Promise, PromiseCapability,
+        PromiseCapabilityRecord, PromiseGroupRecord, PromiseGroupType, PromiseReactionType,
+        PromiseResolvingFunctionHeapData, PromiseResolvingFunctionType, PropertyKey,
+        ProtoIntrinsics, Realm, ScopedPromiseCapabilityRecord, String, Value, array_create,
+        builders::BuiltinFunctionBuilder, call, call_function, get, get_iterator, invoke,
+        is_callable, iterator_close_with_error, iterator_step_value,
+        ordinary_create_from_constructor,
     },
-    engine::{Bindable, GcScope, NoGcScope, Scopable, Scoped, bindable_handle},
+    engine::{Bindable, GcScope, Scopable, Scoped, bindable_handle},
     heap::{
         ArenaAccessMut, CreateHeapData, IntrinsicConstructorIndexes, ObjectEntry, WellKnownSymbols,
     },
@@ -92,14 +93,14 @@ impl BuiltinGetter for PromiseGetSpecies {}
 struct PromiseGroupSetup<'a> {
     iterator_record: IteratorRecord<'a>,
     constructor: Function<'a>,
-    promise_capability: PromiseCapability<'a>,
+    promise_capability: PromiseCapabilityRecord<'a>,
     promise_resolve: Function<'a>,
 }
 bindable_handle!(PromiseGroupSetup);
 
 enum PromiseGroupSetupResult<'a> {
     Success(PromiseGroupSetup<'a>),
-    AbruptReject(Promise<'a>),
+    AbruptReject(Object<'a>),
 }
 bindable_handle!(PromiseGroupSetupResult);
 
@@ -124,13 +125,6 @@ impl PromiseConstructor {
         };
         let new_target = new_target.unbind().bind(gc.nogc());
 
-        if new_target != agent.current_realm_record().intrinsics().promise().into() {
-            return Err(throw_promise_subclassing_not_supported(
-                agent,
-                gc.into_nogc(),
-            ));
-        }
-
         // 2. If IsCallable(executor) is false, throw a TypeError exception.
         // TODO: Callable proxies
         let Ok(executor) = Function::try_from(executor) else {
@@ -185,6 +179,10 @@ impl PromiseConstructor {
             ])),
             gc.reborrow(),
         ) {
+            if agent.is_interrupted() {
+                // Note: interrupts cannot be turned into promise rejections.
+                return Err(err.unbind().bind(gc.into_nogc()));
+            }
             // a. Perform ? Call(resolvingFunctions.[[Reject]], undefined, « completion.[[Value]] »).
             let promise_capability =
                 PromiseCapability::from_promise(scoped_promise.get(agent), true);
@@ -231,7 +229,7 @@ impl PromiseConstructor {
         };
 
         let iterator = iterator_record.iterator.scope(agent, gc.nogc());
-        let promise = promise_capability.promise.scope(agent, gc.nogc());
+        let scoped_capability = promise_capability.clone().scope(agent, gc.nogc());
 
         // 7. Let result be Completion(PerformPromiseAll(iteratorRecord, C, promiseCapability, promiseResolve)).
         let mut iterator_done = false;
@@ -254,7 +252,7 @@ impl PromiseConstructor {
             result.unbind(),
             iterator_done,
             iterator.get(agent),
-            promise.get(agent),
+            &scoped_capability,
             gc.reborrow(),
         )
         .unbind()?
@@ -299,7 +297,7 @@ impl PromiseConstructor {
         };
 
         let iterator = iterator_record.iterator.scope(agent, gc.nogc());
-        let promise = promise_capability.promise.scope(agent, gc.nogc());
+        let scoped_capability = promise_capability.clone().scope(agent, gc.nogc());
 
         // 7. Let result be Completion(PerformPromiseAll(iteratorRecord, C, promiseCapability, promiseResolve)).
         let mut iterator_done = false;
@@ -322,7 +320,7 @@ impl PromiseConstructor {
             result.unbind(),
             iterator_done,
             iterator.get(agent),
-            promise.get(agent),
+            &scoped_capability,
             gc.reborrow(),
         )
         .unbind()?
@@ -367,7 +365,7 @@ impl PromiseConstructor {
         };
 
         let iterator = iterator_record.iterator.scope(agent, gc.nogc());
-        let promise = promise_capability.promise.scope(agent, gc.nogc());
+        let scoped_capability = promise_capability.clone().scope(agent, gc.nogc());
 
         // 7. Let result be Completion(PerformPromiseAll(iteratorRecord, C, promiseCapability, promiseResolve)).
         let mut iterator_done = false;
@@ -390,7 +388,7 @@ impl PromiseConstructor {
             result.unbind(),
             iterator_done,
             iterator.get(agent),
-            promise.get(agent),
+            &scoped_capability,
             gc.reborrow(),
         )
         .unbind()?
@@ -440,7 +438,7 @@ impl PromiseConstructor {
         };
 
         let iterator = iterator_record.iterator.scope(agent, gc.nogc());
-        let promise = promise_capability.promise.scope(agent, gc.nogc());
+        let scoped_capability = promise_capability.clone().scope(agent, gc.nogc());
 
         // 7. Let result be Completion(PerformPromiseAll(iteratorRecord, C, promiseCapability, promiseResolve)).
         let mut iterator_done = false;
@@ -462,7 +460,7 @@ impl PromiseConstructor {
             result.unbind(),
             iterator_done,
             iterator.get(agent),
-            promise.get(agent),
+            &scoped_capability,
             gc.reborrow(),
         )
         .unbind()?
@@ -480,21 +478,23 @@ impl PromiseConstructor {
         agent: &mut Agent,
         this_value: Value,
         arguments: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        let gc = gc.into_nogc();
-        let r = arguments.get(0).bind(gc);
-        if this_value != agent.current_realm_record().intrinsics().promise().into() {
-            return Err(throw_promise_subclassing_not_supported(agent, gc));
-        }
-
+        let r = arguments.get(0).scope(agent, gc.nogc());
         // 1. Let C be the this value.
         // 2. Let promiseCapability be ? NewPromiseCapability(C).
+        let promise_capability = PromiseCapabilityRecord::new(agent, this_value, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        let promise = promise_capability.promise().scope(agent, gc.nogc());
         // 3. Perform ? Call(promiseCapability.[[Reject]], undefined, « r »).
+        promise_capability
+            .unbind()
+            .reject(agent, r.get(agent), gc.reborrow())
+            .unbind()?;
         // 4. Return promiseCapability.[[Promise]].
-        // NOTE: Since we don't support promise subclassing, this is equivalent
-        // to creating an already-rejected promise.
-        Ok(Promise::new_rejected(agent, r, gc).into())
+        // SAFETY: not shared.
+        Ok(unsafe { promise.take(agent) }.bind(gc.into_nogc()).into())
     }
 
     /// ### [27.2.4.7 Promise.resolve ( x )](https://tc39.es/ecma262/#sec-promise.resolve)
@@ -508,15 +508,17 @@ impl PromiseConstructor {
         arguments: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        if this_value != agent.current_realm_record().intrinsics().promise().into() {
-            return Err(throw_promise_subclassing_not_supported(
-                agent,
+        // 1. Let C be the this value.
+        // 2. If C is not an Object, throw a TypeError exception.
+        let Ok(c) = Object::try_from(this_value) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Expected the this value to be an object.",
                 gc.into_nogc(),
             ));
-        }
-
+        };
         // 3. Return ? PromiseResolve(C, x).
-        Promise::resolve(agent, arguments.get(0), gc).map(Value::from)
+        promise_resolve(agent, c, arguments.get(0), gc).map(Value::from)
     }
 
     /// ### [27.2.4.8 Promise.try ( callback, ...args )](https://tc39.es/ecma262/#sec-promise.try)
@@ -528,56 +530,70 @@ impl PromiseConstructor {
         agent: &mut Agent,
         this_value: Value,
         arguments: ArgumentsList,
-        mut gc: GcScope<'gc, '_>,
+        gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        let callback_fn = arguments.get(0).bind(gc.nogc());
-        let args = arguments.slice_from(1).bind(gc.nogc());
         // 1. Let C be the this value.
         // 2. If C is not an Object, throw a TypeError exception.
-        if is_constructor(agent, this_value).is_none() {
+        let Ok(c) = Object::try_from(this_value) else {
             return Err(agent.throw_exception_with_static_message(
                 ExceptionType::TypeError,
-                "Expected the this value to be a constructor.",
+                "Expected the this value to be an object.",
                 gc.into_nogc(),
             ));
-        }
-        if this_value != agent.current_realm_record().intrinsics().promise().into() {
-            return Err(throw_promise_subclassing_not_supported(
-                agent,
-                gc.into_nogc(),
-            ));
-        }
-
-        // 3. Let promiseCapability be ? NewPromiseCapability(C).
-        // 4. Let status be Completion(Call(callbackfn, undefined, args)).
-        let status = call(
+        };
+        let c = c.unbind();
+        arguments.unbind().with_scoped(
             agent,
-            callback_fn.unbind(),
-            Value::Undefined,
-            Some(args.unbind()),
-            gc.reborrow(),
-        );
-        let promise = match status {
-            // 5. If status is an abrupt completion, then
-            Err(err) => {
-                // a. Perform ? Call(promiseCapability.[[Reject]], undefined, « status.[[Value]] »).
+            |agent, arguments, mut gc| {
+                let c = c.bind(gc.nogc());
+                // 3. Let promiseCapability be ? NewPromiseCapability(C).
+                let promise_capability =
+                    PromiseCapabilityRecord::new(agent, c.unbind().into(), gc.reborrow())
+                        .unbind()?
+                        .bind(gc.nogc());
+                let promise_capability = promise_capability.scope(agent, gc.nogc());
+                // 4. Let status be Completion(Call(callbackfn, undefined, args)).
+                let callback_fn = arguments.get(agent, 0, gc.nogc());
+                let mut args = (1..arguments.len(agent) as u32)
+                    .map(|i| arguments.get(agent, i, gc.nogc()).unbind())
+                    .collect::<Vec<_>>();
+                let status = call(
+                    agent,
+                    callback_fn.unbind(),
+                    Value::Undefined,
+                    Some(ArgumentsList::from_mut_slice(&mut args)),
+                    gc.reborrow(),
+                )
+                .unbind()
+                .bind(gc.nogc());
+                let capability = promise_capability.get(agent, gc.nogc());
+                match status {
+                    // 5. If status is an abrupt completion, then
+                    Err(err) => {
+                        // a. Perform ? Call(promiseCapability.[[Reject]], undefined, « status.[[Value]] »).
+                        capability
+                            .unbind()
+                            .reject(agent, err.value().unbind(), gc.reborrow())
+                            .unbind()?;
+                    }
+                    // 6. Else,
+                    Ok(result) => {
+                        // a. Perform ? Call(promiseCapability.[[Resolve]], undefined, « status.[[Value]] »).
+                        capability
+                            .unbind()
+                            .resolve(agent, result.unbind(), gc.reborrow())
+                            .unbind()?;
+                    }
+                }
                 // 7. Return promiseCapability.[[Promise]].
-                agent.heap.create(PromiseHeapData {
-                    object_index: None,
-                    promise_state: PromiseState::Rejected {
-                        promise_result: err.value(),
-                        is_handled: false,
-                    },
-                })
-            }
-            // 6. Else,
-            Ok(result) => {
-                // a. Perform ? Call(promiseCapability.[[Resolve]], undefined, « status.[[Value]] »).
-                Promise::resolve(agent, result.unbind(), gc)?
-            }
-        };
-        // 7. Return promiseCapability.[[Promise]].
-        Ok(promise.unbind().into())
+                Ok(promise_capability
+                    .get(agent, gc.nogc())
+                    .promise()
+                    .unbind()
+                    .into())
+            },
+            gc,
+        )
     }
 
     /// ### [27.2.4.9 Promise.withResolvers ( )](https://tc39.es/ecma262/#sec-promise.withResolvers)
@@ -585,34 +601,17 @@ impl PromiseConstructor {
         agent: &mut Agent,
         this_value: Value,
         _arguments: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        let gc = gc.into_nogc();
-        // Step 2 will throw if `this_value` is not a constructor.
-        if is_constructor(agent, this_value).is_none() {
-            return Err(agent.throw_exception_with_static_message(
-                ExceptionType::TypeError,
-                "Expected the this value to be a constructor.",
-                gc,
-            ));
-        }
-        if this_value != agent.current_realm_record().intrinsics().promise().into() {
-            return Err(throw_promise_subclassing_not_supported(agent, gc));
-        }
-
         // 1. Let C be the this value.
         // 2. Let promiseCapability be ? NewPromiseCapability(C).
-        let promise_capability = PromiseCapability::new(agent, gc);
-        let resolve_function = agent.heap.create(PromiseResolvingFunctionHeapData {
-            object_index: None,
-            promise_capability: promise_capability.clone(),
-            resolve_type: PromiseResolvingFunctionType::Resolve,
-        });
-        let reject_function = agent.heap.create(PromiseResolvingFunctionHeapData {
-            object_index: None,
-            promise_capability: promise_capability.clone(),
-            resolve_type: PromiseResolvingFunctionType::Reject,
-        });
+        let promise_capability =
+            PromiseCapabilityRecord::new(agent, this_value, gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let promise_capability = promise_capability.bind(gc);
+        let promise = promise_capability.promise();
+        let resolve_function = promise_capability.resolve_function(agent);
+        let reject_function = promise_capability.reject_function(agent);
 
         // 3. Let obj be OrdinaryObjectCreate(%Object.prototype%).
         // 4. Perform ! CreateDataPropertyOrThrow(obj, "promise", promiseCapability.[[Promise]]).
@@ -628,10 +627,7 @@ impl PromiseConstructor {
                     .into(),
             ),
             &[
-                ObjectEntry::new_data_entry(
-                    BUILTIN_STRING_MEMORY.promise.into(),
-                    promise_capability.promise().into(),
-                ),
+                ObjectEntry::new_data_entry(BUILTIN_STRING_MEMORY.promise.into(), promise.into()),
                 ObjectEntry::new_data_entry(
                     BUILTIN_STRING_MEMORY.resolve.into(),
                     resolve_function.into(),
@@ -712,6 +708,59 @@ fn get_promise_resolve<'gc>(
     Ok(promise_resolve.unbind())
 }
 
+/// ### [27.2.4.7.1 PromiseResolve ( C, x )](https://tc39.es/ecma262/#sec-promise-resolve)
+///
+/// The abstract operation PromiseResolve takes arguments C (an Object) and x
+/// (an ECMAScript language value) and returns either a normal completion
+/// containing an ECMAScript language value or a throw completion. It returns a
+/// new promise resolved with x.
+pub(crate) fn promise_resolve<'gc>(
+    agent: &mut Agent,
+    c: Object,
+    x: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Object<'gc>> {
+    let c = c.bind(gc.nogc());
+    let x = x.bind(gc.nogc());
+    if c == agent.current_realm_record().intrinsics().promise().into() {
+        return Promise::resolve(agent, x.unbind(), gc).map(Object::from);
+    }
+    let c = c.scope(agent, gc.nogc());
+    let x = x.scope(agent, gc.nogc());
+    // 1. If IsPromise(x) is true, then
+    if let Value::Promise(promise) = x.get(agent) {
+        // a. Let xConstructor be ? Get(x, "constructor").
+        let x_constructor = get(
+            agent,
+            promise,
+            BUILTIN_STRING_MEMORY.constructor.into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        // b. If SameValue(xConstructor, C) is true, return x.
+        if x_constructor == c.get(agent).into() {
+            // SAFETY: not shared.
+            let x = unsafe { x.take(agent) }.bind(gc.into_nogc());
+            return Ok(Object::try_from(x).unwrap());
+        }
+    }
+    // 2. Let promiseCapability be ? NewPromiseCapability(C).
+    let promise_capability =
+        PromiseCapabilityRecord::new(agent, c.get(agent).into(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+    let promise = promise_capability.promise().scope(agent, gc.nogc());
+    // 3. Perform ? Call(promiseCapability.[[Resolve]], undefined, « x »).
+    promise_capability
+        .unbind()
+        .resolve(agent, x.get(agent), gc.reborrow())
+        .unbind()?;
+    // 4. Return promiseCapability.[[Promise]].
+    // SAFETY: not shared.
+    Ok(unsafe { promise.take(agent) }.bind(gc.into_nogc()))
+}
+
 fn promise_group_setup<'gc>(
     agent: &mut Agent,
     this_value: Value,
@@ -723,24 +772,17 @@ fn promise_group_setup<'gc>(
     let iterable = arguments.get(0).scope(agent, gc.nogc());
 
     // 1. Let C be the this value.
-    if this_value != agent.current_realm_record().intrinsics().promise().into() {
-        return Err(throw_promise_subclassing_not_supported(
-            agent,
-            gc.into_nogc(),
-        ));
-    }
-
+    let c = this_value.scope(agent, gc.nogc());
     // 2. Let promiseCapability be ? NewPromiseCapability(C).
-    let Some(constructor) = is_constructor(agent, this_value) else {
-        return Err(agent.throw_exception_with_static_message(
-            ExceptionType::TypeError,
-            "Expected the this value to be a constructor.",
-            gc.into_nogc(),
-        ));
-    };
-    let constructor = constructor.scope(agent, gc.nogc());
-    let promise_capability = PromiseCapability::new(agent, gc.nogc());
-    let promise = promise_capability.promise().scope(agent, gc.nogc());
+    let promise_capability =
+        PromiseCapabilityRecord::new(agent, this_value.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+    // NOTE: NewPromiseCapability checked that C is a constructor.
+    let constructor = Function::try_from(c.get(agent))
+        .unwrap()
+        .scope(agent, gc.nogc());
+    let promise_capability = promise_capability.scope(agent, gc.nogc());
 
     // 3. Let promiseResolve be Completion(GetPromiseResolve(C)).
     let promise_resolve = get_promise_resolve(agent, constructor.get(agent), gc.reborrow())
@@ -748,37 +790,23 @@ fn promise_group_setup<'gc>(
         .bind(gc.nogc());
 
     // 4. IfAbruptRejectPromise(promiseResolve, promiseCapability).
-    let promise_capability = PromiseCapability {
-        promise: promise.get(agent).bind(gc.nogc()),
-        must_be_unresolved: true,
-    };
-
     let promise_resolve = match promise_resolve.unbind().bind(gc.nogc()) {
         Err(err) => {
-            promise_capability.reject(agent, err.value().unbind(), gc.nogc());
-            let promise = promise_capability.promise.unbind().bind(gc.into_nogc());
-            return Ok(PromiseGroupSetupResult::AbruptReject(promise));
+            return if_abrupt_reject_group_setup(agent, err.unbind(), &promise_capability, gc);
         }
         Ok(value) => value.unbind().bind(gc.nogc()),
     };
     let promise_resolve = promise_resolve.scope(agent, gc.nogc());
 
     // 5. Let iteratorRecord be Completion(GetIterator(iterable, sync)).
-    let iterator_record = get_iterator(agent, iterable.get(agent), false, gc.reborrow())
+    let iterator_record = get_iterator(agent, iterable.get(agent), gc.reborrow())
         .unbind()
         .bind(gc.nogc());
 
     // 6. IfAbruptRejectPromise(iteratorRecord, promiseCapability).
-    let promise_capability = PromiseCapability {
-        promise: promise.get(agent).bind(gc.nogc()),
-        must_be_unresolved: true,
-    };
-
     let iterator_record = match iterator_record.unbind().bind(gc.nogc()) {
         Err(err) => {
-            promise_capability.reject(agent, err.value().unbind(), gc.nogc());
-            let promise = promise_capability.promise.unbind().bind(gc.into_nogc());
-            return Ok(PromiseGroupSetupResult::AbruptReject(promise));
+            return if_abrupt_reject_group_setup(agent, err.unbind(), &promise_capability, gc);
         }
         Ok(value) => value.unbind().bind(gc.nogc()),
     };
@@ -795,22 +823,43 @@ fn promise_group_setup<'gc>(
     Ok(PromiseGroupSetupResult::Success(PromiseGroupSetup {
         iterator_record: iterator_record.unbind(),
         constructor: constructor.get(agent),
-        promise_capability: promise_capability.unbind(),
+        promise_capability: promise_capability.get(agent, gc.nogc()).unbind(),
         promise_resolve: promise_resolve.get(agent),
     }))
 }
 
+/// IfAbruptRejectPromise(value, promiseCapability) for the abrupt completions
+/// of [`promise_group_setup`].
+fn if_abrupt_reject_group_setup<'gc>(
+    agent: &mut Agent,
+    err: JsError,
+    promise_capability: &ScopedPromiseCapabilityRecord,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, PromiseGroupSetupResult<'gc>> {
+    let err = err.bind(gc.nogc());
+    // a. Perform ? Call(capability.[[Reject]], undefined, « value.[[Value]] »).
+    promise_capability
+        .get(agent, gc.nogc())
+        .unbind()
+        .reject(agent, err.value().unbind(), gc.reborrow())
+        .unbind()?;
+    // b. Return capability.[[Promise]].
+    let promise = promise_capability.get(agent, gc.nogc()).promise();
+    Ok(PromiseGroupSetupResult::AbruptReject(
+        promise.unbind().bind(gc.into_nogc()),
+    ))
+}
+
 fn handle_promise_group_result<'gc>(
     agent: &mut Agent,
-    result: JsResult<'gc, Promise<'gc>>,
+    result: JsResult<'gc, Object<'gc>>,
     iterator_done: bool,
     iterator: Object,
-    promise: Promise<'gc>,
+    promise_capability: &ScopedPromiseCapabilityRecord,
     mut gc: GcScope<'gc, '_>,
 ) -> JsResult<'gc, Value<'gc>> {
     let result = result.bind(gc.nogc());
     let iterator = iterator.bind(gc.nogc());
-    let promise = promise.bind(gc.nogc()).scope(agent, gc.nogc());
 
     // 8. If result is an abrupt completion, then
     let result = match result {
@@ -828,14 +877,14 @@ fn handle_promise_group_result<'gc>(
             }
 
             // b. IfAbruptRejectPromise(result, promiseCapability).
-            let promise_capability = PromiseCapability {
-                promise: promise.get(agent),
-                must_be_unresolved: true,
-            };
             // a. Perform ? Call(capability.[[Reject]], undefined, « value.[[Value]] »).
-            promise_capability.reject(agent, result.value().unbind(), gc.nogc());
+            promise_capability
+                .get(agent, gc.nogc())
+                .unbind()
+                .reject(agent, result.value().unbind(), gc.reborrow())
+                .unbind()?;
             // b. Return capability.[[Promise]].
-            promise_capability.promise()
+            promise_capability.get(agent, gc.nogc()).promise()
         }
         Ok(result) => result,
     };
@@ -852,18 +901,28 @@ fn perform_promise_group<'gc>(
     iterator: Scoped<Object>,
     next_method: Function,
     constructor: Function,
-    result_capability: PromiseCapability,
+    result_capability: PromiseCapabilityRecord,
     promise_resolve: Function,
     iterator_done: &mut bool,
     promise_group_type: PromiseGroupType,
     mut gc: GcScope<'gc, '_>,
-) -> JsResult<'gc, Promise<'gc>> {
+) -> JsResult<'gc, Object<'gc>> {
     let result_capability = result_capability.bind(gc.nogc());
     let constructor = constructor.scope(agent, gc.nogc());
     let promise_resolve = promise_resolve.scope(agent, gc.nogc());
 
     let next_method = next_method.scope(agent, gc.nogc());
 
+    // NOTE: Promise.all passes resultCapability.[[Reject]] and Promise.any
+    // passes resultCapability.[[Resolve]] to every "then" call, so we only
+    // need to get it once.
+    let capability_function = match promise_group_type {
+        PromiseGroupType::All => Some(result_capability.reject_function(agent)),
+        PromiseGroupType::AllSettled => None,
+        PromiseGroupType::Any => Some(result_capability.resolve_function(agent)),
+    }
+    .map(|f| f.scope(agent, gc.nogc()));
+
     // 1. Let values be a new empty List.
     let capacity = match iterator.get(agent) {
         Object::Array(array) => array.len(agent),
@@ -880,14 +939,14 @@ fn perform_promise_group<'gc>(
     let result_array = result_array.scope(agent, gc.nogc());
 
     // 2. Let remainingElementsCount be the Record { [[Value]]: 1 }.
-    let promise = result_capability.promise.scope(agent, gc.nogc());
+    let promise = result_capability.promise().scope(agent, gc.nogc());
     let promise_group_reference = agent
         .heap
         .create(PromiseGroupRecord {
             promise_group_type,
             remaining_elements_count: 1,
             result_array: result_array.get(agent),
-            promise: promise.get(agent),
+            capability: result_capability.unbind(),
         })
         .scope(agent, gc.nogc());
 
@@ -904,33 +963,29 @@ fn perform_promise_group<'gc>(
 
         // a. Let next be ? IteratorStepValue(iteratorRecord).
         let next = iterator_step_value(agent, iterator_record.unbind(), gc.reborrow())
-            .unbind()?
+            .unbind()
             .bind(gc.nogc());
+        let next = match next {
+            Ok(next) => next,
+            Err(err) => {
+                // NOTE: IteratorStepValue sets iteratorRecord.[[Done]] to
+                // true if it returns an abrupt completion.
+                *iterator_done = true;
+                return Err(err.unbind().bind(gc.into_nogc()));
+            }
+        };
 
         // b. If next is done, then
         let Some(next) = next else {
             *iterator_done = true;
-            let promise_group = promise_group_reference.get(agent).bind(gc.nogc());
-            let data = promise_group.get_mut(agent);
-
             // i. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] - 1.
-            data.remaining_elements_count -= 1;
-
             // ii. If remainingElementsCount.[[Value]] = 0, then
-            if data.remaining_elements_count == 0 {
-                // 1. Let valuesArray be CreateArrayFromList(values).
-                let values_array = result_array.get(agent).bind(gc.nogc());
-                // 2. Perform ? Call(resultCapability.[[Resolve]], undefined, « valuesArray »).
-                let result_capability = PromiseCapability {
-                    promise: promise.get(agent).bind(gc.nogc()),
-                    must_be_unresolved: true,
-                };
-                result_capability.unbind().resolve(
-                    agent,
-                    values_array.unbind().into(),
-                    gc.reborrow(),
-                );
-            }
+            // 1. Let valuesArray be CreateArrayFromList(values).
+            // 2. Perform ? Call(resultCapability.[[Resolve]], undefined, « valuesArray »).
+            promise_group_reference
+                .get(agent)
+                .decrement_remaining_elements(agent, gc.reborrow())
+                .unbind()?;
 
             // iii. Return resultCapability.[[Promise]].
             return Ok(promise.get(agent));
@@ -943,9 +998,10 @@ fn perform_promise_group<'gc>(
         }
         // SAFETY: reserve did not fail.
         unsafe { temp_array.set_len(agent, index + 1) };
+        temp_array.as_mut_slice(agent)[index as usize] = Some(Value::Undefined);
 
         // d. Let nextPromise be ? Call(promiseResolve, constructor, « next »).
-        let call_result = call_function(
+        let next_promise = call_function(
             agent,
             promise_resolve.get(agent),
             constructor.get(agent).into(),
@@ -955,39 +1011,62 @@ fn perform_promise_group<'gc>(
         .unbind()?
         .bind(gc.nogc());
 
-        // Note: as we don't yet support Promise subclassing, if we see a
-        // non-Promise return we wrap it inside a resolved Promise to get
-        // then-chaining.
-        let next_promise = match call_result {
-            Value::Promise(next_promise) => next_promise,
-            _ => Promise::new_resolved(agent, call_result),
-        };
-
         // e. Let steps be the algorithm steps defined in Promise.all Resolve Element Functions.
         // f. Let length be the number of non-optional parameters of the function definition in Promise.all Resolve Element Functions.
         // g. Let onFulfilled be CreateBuiltinFunction(steps, length, "", « [[AlreadyCalled]], [[Index]], [[Values]], [[Capability]], [[RemainingElements]] »).
         // h. Set onFulfilled.[[AlreadyCalled]] to false.
         // i. Set onFulfilled.[[Index]] to index.
         // j. Set onFulfilled.[[Values]] to values.
+        // k. Set onFulfilled.[[Capability]] to resultCapability.
+        // l. Set onFulfilled.[[RemainingElements]] to remainingElementsCount.
         let promise_group = promise_group_reference.get(agent).bind(gc.nogc());
-        promise_group.get_mut(agent).remaining_elements_count += 1;
-        let reaction = PromiseReactionHandler::PromiseGroup {
-            index,
-            promise_group,
+        let (on_fulfilled, on_rejected): (Function, Function) = match promise_group_type {
+            PromiseGroupType::All => (
+                BuiltinPromiseFinallyFunction::create_group_element_function(
+                    agent,
+                    promise_group,
+                    index,
+                    PromiseReactionType::Fulfill,
+                )
+                .into(),
+                capability_function.as_ref().unwrap().get(agent),
+            ),
+            PromiseGroupType::AllSettled => {
+                let (on_fulfilled, on_rejected) =
+                    BuiltinPromiseFinallyFunction::create_all_settled_element_functions(
+                        agent,
+                        promise_group,
+                        index,
+                    );
+                (on_fulfilled.into(), on_rejected.into())
+            }
+            PromiseGroupType::Any => (
+                capability_function.as_ref().unwrap().get(agent),
+                BuiltinPromiseFinallyFunction::create_group_element_function(
+                    agent,
+                    promise_group,
+                    index,
+                    PromiseReactionType::Reject,
+                )
+                .into(),
+            ),
         };
 
-        // k. Set onFulfilled.[[Capability]] to resultCapability.
-        // l. Set onFulfilled.[[RemainingElements]] to remainingElementsCount.
         // m. Set remainingElementsCount.[[Value]] to remainingElementsCount.[[Value]] + 1.
+        promise_group.get_mut(agent).remaining_elements_count += 1;
+
         // n. Perform ? Invoke(nextPromise, "then", « onFulfilled, resultCapability.[[Reject]] »).
-        inner_promise_then(
+        invoke(
             agent,
             next_promise.unbind(),
-            reaction.unbind(),
-            reaction.unbind(),
-            None,
-            gc.nogc(),
-        );
+            BUILTIN_STRING_MEMORY.then.to_property_key(),
+            Some(ArgumentsList::from_mut_slice(&mut [
+                on_fulfilled.unbind().into(),
+                on_rejected.unbind().into(),
+            ])),
+            gc.reborrow(),
+        )
+        .unbind()?;
 
         // o. Set index to index + 1.
         index += 1;
@@ -1001,17 +1080,26 @@ fn perform_promise_race<'gc>(
     iterator: Scoped<Object>,
     next_method: Function,
     constructor: Function,
-    result_capability: PromiseCapability,
+    result_capability: PromiseCapabilityRecord,
     promise_resolve: Function,
     iterator_done: &mut bool,
     mut gc: GcScope<'gc, '_>,
-) -> JsResult<'gc, Promise<'gc>> {
+) -> JsResult<'gc, Object<'gc>> {
     let result_capability = result_capability.bind(gc.nogc());
     let constructor = constructor.scope(agent, gc.nogc());
     let promise_resolve = promise_resolve.scope(agent, gc.nogc());
     let next_method = next_method.scope(agent, gc.nogc());
-    let promise = result_capability.promise.scope(agent, gc.nogc());
+    let promise = result_capability.promise().scope(agent, gc.nogc());
+    // NOTE: Every "then" call gets the same resultCapability.[[Resolve]] and
+    // resultCapability.[[Reject]] functions.
+    let resolve_function = result_capability
+        .resolve_function(agent)
+        .scope(agent, gc.nogc());
+    let reject_function = result_capability
+        .reject_function(agent)
+        .scope(agent, gc.nogc());
 
+    // 1. Repeat,
     loop {
         let iterator_record = IteratorRecord {
             iterator: iterator.get(agent),
@@ -1019,16 +1107,29 @@ fn perform_promise_race<'gc>(
         }
         .bind(gc.nogc());
 
+        // a. Let next be ? IteratorStepValue(iteratorRecord).
         let next = iterator_step_value(agent, iterator_record.unbind(), gc.reborrow())
-            .unbind()?
+            .unbind()
             .bind(gc.nogc());
+        let next = match next {
+            Ok(next) => next,
+            Err(err) => {
+                // NOTE: IteratorStepValue sets iteratorRecord.[[Done]] to
+                // true if it returns an abrupt completion.
+                *iterator_done = true;
+                return Err(err.unbind().bind(gc.into_nogc()));
+            }
+        };
 
+        // b. If next is done, then
         let Some(next) = next else {
             *iterator_done = true;
-            return Ok(promise.get(agent));
+            // i. Return resultCapability.[[Promise]].
+            return Ok(promise.get(agent).bind(gc.into_nogc()));
         };
 
-        let call_result = call_function(
+        // c. Let nextPromise be ? Call(promiseResolve, constructor, « next »).
+        let next_promise = call_function(
             agent,
             promise_resolve.get(agent),
             constructor.get(agent).into(),
@@ -1038,34 +1139,17 @@ fn perform_promise_race<'gc>(
         .unbind()?
         .bind(gc.nogc());
 
-        let next_promise = match call_result {
-            Value::Promise(next_promise) => next_promise,
-            _ => Promise::new_resolved(agent, call_result),
-        };
-
-        let promise_capability = PromiseCapability {
-            promise: promise.get(agent).bind(gc.nogc()),
-            must_be_unresolved: true,
-        };
-
-        inner_promise_then(
+        // d. Perform ? Invoke(nextPromise, "then", « resultCapability.[[Resolve]], resultCapability.[[Reject]] »).
+        invoke(
             agent,
             next_promise.unbind(),
-            PromiseReactionHandler::Empty,
-            PromiseReactionHandler::Empty,
-            Some(promise_capability),
-            gc.nogc(),
-        );
+            BUILTIN_STRING_MEMORY.then.to_property_key(),
+            Some(ArgumentsList::from_mut_slice(&mut [
+                resolve_function.get(agent).into(),
+                reject_function.get(agent).into(),
+            ])),
+            gc.reborrow(),
+        )
+        .unbind()?;
     }
 }
-
-fn throw_promise_subclassing_not_supported<'a>(
-    agent: &mut Agent,
-    gc: NoGcScope<'a, '_>,
-) -> JsError<'a> {
-    agent.throw_exception_with_static_message(
-        ExceptionType::Error,
-        "Promise subclassing is not supported",
-        gc,
-    )
-}
@@ -6,10 +6,10 @@ use crate::{
     ecmascript::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
         BuiltinPromiseFinallyFunction, ExceptionType, Function, JsResult, Object, Promise,
-        PromiseCapability, PromiseReactionHandler, PromiseReactionRecord, PromiseReactionType,
-        PromiseReactions, PromiseRejectionTrackerOperation, PromiseState, ProtoIntrinsics, Realm,
-        String, Value, builders::OrdinaryObjectBuilder, invoke, is_callable, is_constructor,
-        new_promise_reaction_job, species_constructor,
+        PromiseCapabilityRecord, PromiseReactionHandler, PromiseReactionRecord,
+        PromiseReactionType, PromiseReactions, PromiseRejectionTrackerOperation, PromiseState,
+        ProtoIntrinsics, Realm, String, Value, builders::OrdinaryObjectBuilder, invoke,
+        is_callable, is_constructor, new_promise_reaction_job, species_constructor,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable},
     heap::{ArenaAccessMut, CreateHeapData, WellKnownSymbols},
@@ -133,28 +133,46 @@ impl PromisePrototype {
         agent: &mut Agent,
         this_value: Value,
         args: ArgumentsList,
-        gc: GcScope<'gc, '_>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        let gc = gc.into_nogc();
-        let on_fulfilled = args.get(0).bind(gc);
-        let on_rejected = args.get(1).bind(gc);
+        let on_fulfilled = args.get(0).scope(agent, gc.nogc());
+        let on_rejected = args.get(1).scope(agent, gc.nogc());
         // 1. Let promise be the this value.
         // 2. If IsPromise(promise) is false, throw a TypeError exception.
-        let Value::Promise(promise) = this_value else {
+        let Value::Promise(promise) = this_value.bind(gc.nogc()) else {
             return Err(agent.throw_exception_with_static_message(
                 ExceptionType::TypeError,
                 "'this' is not a promise",
-                gc,
+                gc.into_nogc(),
             ));
         };
+        let scoped_promise = promise.scope(agent, gc.nogc());
 
         // 3. Let C be ? SpeciesConstructor(promise, %Promise%).
+        let c = species_constructor(
+            agent,
+            promise.unbind().into(),
+            ProtoIntrinsics::Promise,
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
         // 4. Let resultCapability be ? NewPromiseCapability(C).
-        // NOTE: We're ignoring species and subclasses.
-        let result_capability = PromiseCapability::new(agent, gc);
+        let result_capability =
+            PromiseCapabilityRecord::new(agent, c.unbind().into(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let result_capability = result_capability.bind(gc);
         let result_capability_promise = result_capability.promise();
 
         // 5. Return PerformPromiseThen(promise, onFulfilled, onRejected, resultCapability).
+        // SAFETY: not shared.
+        let (promise, on_fulfilled, on_rejected) = unsafe {
+            (
+                scoped_promise.take(agent).bind(gc),
+                on_fulfilled.take(agent).bind(gc),
+                on_rejected.take(agent).bind(gc),
+            )
+        };
         perform_promise_then(
             agent,
             promise,
@@ -197,7 +215,7 @@ pub(crate) fn perform_promise_then(
     promise: Promise,
     on_fulfilled: Value,
     on_rejected: Value,
-    result_capability: Option<PromiseCapability>,
+    result_capability: Option<PromiseCapabilityRecord>,
     gc: NoGcScope,
 ) {
     // 3. If IsCallable(onFulfilled) is false, then
@@ -236,7 +254,7 @@ pub(crate) fn inner_promise_then(
     promise: Promise,
     on_fulfilled: PromiseReactionHandler,
     on_rejected: PromiseReactionHandler,
-    result_capability: Option<PromiseCapability>,
+    result_capability: Option<PromiseCapabilityRecord>,
     gc: NoGcScope,
 ) {
     // 7. Let fulfillReaction be the PromiseReaction Record { [[Capability]]: resultCapability, [[Type]]: fulfill, [[Handler]]: onFulfilledJobCallback }.
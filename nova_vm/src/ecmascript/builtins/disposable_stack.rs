@@ -0,0 +1,138 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod data;
+
+pub(crate) use data::*;
+
+use crate::{
+    ecmascript::{
+        Agent, InternalMethods, InternalSlots, Object, OrdinaryObject, ProtoIntrinsics,
+        object_handle,
+    },
+    engine::Bindable,
+    heap::{
+        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
+        HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues, arena_vec_access,
+    },
+};
+
+/// ## [DisposableStack Objects](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects)
+///
+/// A DisposableStack is an object that can be used to contain one or more
+/// resources that should be disposed together. The same heap data is used for
+/// both DisposableStack and AsyncDisposableStack objects, and also for the
+/// hidden DisposeCapability of blocks containing `using` declarations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct DisposableStack<'a>(BaseIndex<'a, DisposableStackHeapData<'static>>);
+object_handle!(DisposableStack);
+arena_vec_access!(
+    DisposableStack,
+    'a,
+    DisposableStackHeapData,
+    disposable_stacks
+);
+
+impl<'a> DisposableStack<'a> {
+    /// Returns true if this is an AsyncDisposableStack object.
+    pub(crate) fn is_async(self, agent: &Agent) -> bool {
+        self.get(agent).is_async
+    }
+
+    /// Returns true if the \[\[DisposableState]] is disposed.
+    pub(crate) fn is_disposed(self, agent: &Agent) -> bool {
+        self.get(agent).state == DisposableState::Disposed
+    }
+
+    /// Set the \[\[DisposableState]] to disposed.
+    pub(crate) fn set_disposed(self, agent: &mut Agent) {
+        self.get_mut(agent).state = DisposableState::Disposed;
+    }
+
+    /// Returns true if the DisposeCapability contains no resources.
+    pub(crate) fn is_empty(self, agent: &Agent) -> bool {
+        self.get(agent).resources.is_empty()
+    }
+
+    pub(crate) fn push_resource(self, agent: &mut Agent, resource: DisposableResource) {
+        self.get_mut(agent).resources.push(resource.unbind());
+    }
+
+    pub(crate) fn pop_resource(self, agent: &mut Agent) -> Option<DisposableResource<'a>> {
+        self.get_mut(agent).resources.pop()
+    }
+
+    /// Move the DisposeCapability's resources into another DisposableStack,
+    /// leaving this DisposableStack with a new, empty DisposeCapability.
+    pub(crate) fn move_resources_to(self, agent: &mut Agent, target: DisposableStack) {
+        let resources = core::mem::take(&mut self.get_mut(agent).resources);
+        target.get_mut(agent).resources = resources;
+    }
+}
+
+impl<'a> InternalSlots<'a> for DisposableStack<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::DisposableStack;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        self.get(agent).object_index.unbind()
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(
+            self.get_mut(agent)
+                .object_index
+                .replace(backing_object.unbind())
+                .is_none()
+        );
+    }
+
+    fn internal_prototype(self, agent: &Agent) -> Option<Object<'static>> {
+        if let Some(object_index) = self.get_backing_object(agent) {
+            object_index.internal_prototype(agent)
+        } else {
+            let intrinsic = if self.is_async(agent) {
+                ProtoIntrinsics::AsyncDisposableStack
+            } else {
+                ProtoIntrinsics::DisposableStack
+            };
+            Some(
+                agent
+                    .current_realm_record()
+                    .intrinsics()
+                    .get_intrinsic_default_proto(intrinsic),
+            )
+        }
+    }
+}
+
+impl<'a> InternalMethods<'a> for DisposableStack<'a> {}
+
+impl<'a> CreateHeapData<DisposableStackHeapData<'a>, DisposableStack<'a>> for Heap {
+    fn create(&mut self, data: DisposableStackHeapData<'a>) -> DisposableStack<'a> {
+        self.disposable_stacks.push(data.unbind());
+        self.alloc_counter += core::mem::size_of::<DisposableStackHeapData<'static>>();
+        DisposableStack(BaseIndex::last(&self.disposable_stacks))
+    }
+}
+
+impl HeapMarkAndSweep for DisposableStack<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.disposable_stacks.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.disposable_stacks.shift_index(&mut self.0);
+    }
+}
+
+impl HeapSweepWeakReference for DisposableStack<'static> {
+    fn sweep_weak_reference(self, compactions: &CompactionLists) -> Option<Self> {
+        compactions
+            .disposable_stacks
+            .shift_weak_index(self.0)
+            .map(Self)
+    }
+}
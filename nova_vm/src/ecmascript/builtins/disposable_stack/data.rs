@@ -0,0 +1,174 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{Function, OrdinaryObject, Promise, Value},
+    engine::{NoGcScope, bindable_handle, trivially_bindable},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// ### \[\[DisposableState]]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisposableState {
+    Pending,
+    Disposed,
+}
+
+/// ### \[\[Hint]]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisposeHint {
+    SyncDispose,
+    AsyncDispose,
+}
+
+/// Describes how a DisposableResource's \[\[DisposeMethod]] is called.
+///
+/// The specification creates new builtin function closures in
+/// `DisposableStack.prototype.adopt` and in GetDisposeMethod; we instead
+/// store the captured function and remember which closure it stands for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum DisposeMethodKind {
+    /// `Call(method, value)`.
+    Method,
+    /// `Call(onDispose, undefined, « value »)`: the closure created by the
+    /// `adopt` methods.
+    Adopt,
+    /// `Call(method, value)` with the result ignored and awaited as
+    /// undefined: the closure created by GetDisposeMethod when an
+    /// async-dispose resource only has a `[Symbol.dispose]` method.
+    SyncFallback,
+}
+trivially_bindable!(DisposeMethodKind);
+
+/// ### [DisposableResource Records](https://tc39.es/proposal-explicit-resource-management/#sec-disposableresource-records)
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct DisposableResource<'a> {
+    /// ### \[\[ResourceValue]]
+    pub(crate) value: Value<'a>,
+    /// ### \[\[Hint]]
+    pub(crate) hint: DisposeHint,
+    /// ### \[\[DisposeMethod]]
+    pub(crate) method: Option<Function<'a>>,
+    pub(crate) kind: DisposeMethodKind,
+}
+bindable_handle!(DisposableResource);
+
+/// State of an in-progress asynchronous DisposeResources operation.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct AsyncDisposeState<'a> {
+    /// Promise that is settled with the final completion.
+    pub(crate) promise: Promise<'a>,
+    /// Thrown value of the current completion, if it is a throw completion.
+    pub(crate) error: Option<Value<'a>>,
+    pub(crate) needs_await: bool,
+    pub(crate) has_awaited: bool,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct DisposableStackHeapData<'a> {
+    pub(super) object_index: Option<OrdinaryObject<'a>>,
+    /// ### \[\[DisposableState]]
+    ///
+    /// Also the \[\[AsyncDisposableState]] of AsyncDisposableStack objects.
+    pub(crate) state: DisposableState,
+    /// True if this is an AsyncDisposableStack object.
+    pub(crate) is_async: bool,
+    /// ### \[\[DisposeCapability]]
+    ///
+    /// The \[\[DisposableResourceStack]] of the DisposeCapability Record.
+    pub(crate) resources: Vec<DisposableResource<'a>>,
+    /// State of an asynchronous DisposeResources operation that is waiting
+    /// for an Await to finish.
+    pub(crate) async_dispose: Option<AsyncDisposeState<'a>>,
+}
+
+impl DisposableStackHeapData<'_> {
+    pub(crate) fn new(is_async: bool) -> Self {
+        Self {
+            object_index: None,
+            state: DisposableState::Pending,
+            is_async,
+            resources: Vec::new(),
+            async_dispose: None,
+        }
+    }
+}
+
+bindable_handle!(DisposableStackHeapData);
+
+impl HeapMarkAndSweep for DisposableResource<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            value,
+            hint: _,
+            method,
+            kind: _,
+        } = self;
+        value.mark_values(queues);
+        method.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            value,
+            hint: _,
+            method,
+            kind: _,
+        } = self;
+        value.sweep_values(compactions);
+        method.sweep_values(compactions);
+    }
+}
+
+impl HeapMarkAndSweep for AsyncDisposeState<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            promise,
+            error,
+            needs_await: _,
+            has_awaited: _,
+        } = self;
+        promise.mark_values(queues);
+        error.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            promise,
+            error,
+            needs_await: _,
+            has_awaited: _,
+        } = self;
+        promise.sweep_values(compactions);
+        error.sweep_values(compactions);
+    }
+}
+
+impl HeapMarkAndSweep for DisposableStackHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            state: _,
+            is_async: _,
+            resources,
+            async_dispose,
+        } = self;
+        object_index.mark_values(queues);
+        resources.mark_values(queues);
+        async_dispose.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            state: _,
+            is_async: _,
+            resources,
+            async_dispose,
+        } = self;
+        object_index.sweep_values(compactions);
+        resources.sweep_values(compactions);
+        async_dispose.sweep_values(compactions);
+    }
+}
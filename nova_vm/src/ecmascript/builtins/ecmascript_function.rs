@@ -754,7 +754,7 @@ pub(crate) fn evaluate_body<'gc>(
     agent: &mut Agent,
     function_object: ECMAScriptFunction,
     arguments_list: ArgumentsList,
-    gc: GcScope<'gc, '_>,
+    mut gc: GcScope<'gc, '_>,
 ) -> JsResult<'gc, Value<'gc>> {
     let function_object = function_object.bind(gc.nogc());
     let f = function_object.get_ast(agent, gc.nogc());
@@ -764,10 +764,18 @@ pub(crate) fn evaluate_body<'gc>(
             // 1. Return ? EvaluateAsyncFunctionBody of AsyncFunctionBody with arguments functionObject and argumentsList.
             // AsyncConciseBody : ExpressionBody
             // 1. Return ? EvaluateAsyncConciseBody of AsyncConciseBody with arguments functionObject and argumentsList.
-            Ok(
-                evaluate_async_function_body(agent, function_object.unbind(), arguments_list, gc)
-                    .into(),
+            let promise = evaluate_async_function_body(
+                agent,
+                function_object.unbind(),
+                arguments_list,
+                gc.reborrow(),
             )
+            .unbind();
+            let gc = gc.into_nogc();
+            // Note: an interrupted async function body rejects the promise;
+            // the interrupt must still propagate to the caller.
+            agent.check_interrupt(gc)?;
+            Ok(promise.bind(gc).into())
         }
         (false, false) => {
             // FunctionBody : FunctionStatementList
@@ -969,7 +977,7 @@ pub(crate) fn make_constructor<'a>(
         Function::BuiltinConstructorFunction(_)
         | Function::BuiltinPromiseResolvingFunction(_)
         | Function::BuiltinPromiseFinallyFunction(_)
-        | Function::BuiltinProxyRevokerFunction => unreachable!(),
+        | Function::BuiltinProxyRevokerFunction(_) => unreachable!(),
     }
     // 5. If prototype is not present, then
     let prototype = prototype.unwrap_or_else(|| {
@@ -1161,7 +1169,7 @@ pub(crate) fn set_function_name<'a>(
         Function::BuiltinConstructorFunction(_)
         | Function::BuiltinPromiseResolvingFunction(_)
         | Function::BuiltinPromiseFinallyFunction(_)
-        | Function::BuiltinProxyRevokerFunction => unreachable!(),
+        | Function::BuiltinProxyRevokerFunction(_) => unreachable!(),
     }
 }
 
@@ -4,60 +4,191 @@
 
 mod data;
 
+use core::any::Any;
+
 pub(crate) use data::*;
 
 use crate::{
     ecmascript::{
-        InternalMethods, InternalSlots, Object, OrdinaryObject, execution::Agent, object_handle,
+        InternalMethods, InternalSlots, JsResult, Object, OrdinaryObject, ProtoIntrinsics, Value,
+        execution::Agent, object_handle,
     },
+    engine::{Bindable, NoGcScope},
     heap::{
-        BaseIndex, CompactionLists, HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues,
-        arena_vec_access,
+        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
+        HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues, arena_vec_access,
     },
 };
 
 /// Embedder objects are intended for embedders to create objects with native
-/// data embedded into them. The type is currently unimplemented but the
-/// intention will be that each embedder object is always provided with a
-/// backing object reference while the embedder provides the data.
+/// data embedded into them. Each embedder object carries an embedder-owned
+/// payload, and an optional backing object for its ordinary object features.
+///
+/// The payload is opaque to the engine: it is only dropped, or passed to the
+/// finalizer hook, when the embedder object is garbage collected. The payload
+/// must be [`Send`] as the Agent may be moved to another thread, and as
+/// garbage collection sweeps payloads on its worker threads. If the payload
+/// holds JavaScript values, the [`EmbedderObjectHooks`] must be used to mark
+/// and sweep them during garbage collection.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
 pub struct EmbedderObject<'a>(BaseIndex<'a, EmbedderObjectHeapData<'static>>);
 object_handle!(EmbedderObject);
 arena_vec_access!(EmbedderObject, 'a, EmbedderObjectHeapData, embedder_objects);
 
-impl<'a> InternalSlots<'a> for EmbedderObject<'a> {
-    #[inline(always)]
-    fn get_backing_object(self, _agent: &Agent) -> Option<OrdinaryObject<'static>> {
-        todo!();
+/// Garbage collection and finalization hooks of an [`EmbedderObject`].
+///
+/// All hooks are called during garbage collection, possibly on a garbage
+/// collection worker thread, and thus must not attempt to access the
+/// [`Agent`] or call into JavaScript.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EmbedderObjectHooks {
+    /// Called when the embedder object is found to be alive during the marking
+    /// phase of garbage collection. Any JavaScript values held in the payload
+    /// must be marked using the [`EmbedderObjectMarker`].
+    pub mark: Option<fn(data: &dyn Any, marker: &mut EmbedderObjectMarker<'_, '_>)>,
+    /// Called when the embedder object survives garbage collection. Any
+    /// JavaScript values held in the payload must be updated using the
+    /// [`EmbedderObjectSweeper`] as they may have moved during compaction.
+    pub sweep: Option<fn(data: &mut dyn Any, sweeper: &EmbedderObjectSweeper<'_>)>,
+    /// Called with the payload when the embedder object is garbage collected,
+    /// on a garbage collection worker thread, or when the Agent is dropped.
+    /// If not set, the payload is simply dropped.
+    pub finalize: Option<fn(data: Box<dyn Any + Send>)>,
+}
+
+impl EmbedderObjectHooks {
+    /// Embedder object hooks that do nothing.
+    pub const NONE: &'static Self = &Self {
+        mark: None,
+        sweep: None,
+        finalize: None,
+    };
+}
+
+/// Marker for JavaScript values held in an [`EmbedderObject`]'s payload.
+pub struct EmbedderObjectMarker<'a, 'b>(&'a mut WorkQueues<'b>);
+
+impl EmbedderObjectMarker<'_, '_> {
+    /// Mark a JavaScript value as reachable.
+    pub fn mark_value(&mut self, value: Value<'static>) {
+        value.mark_values(self.0);
     }
 
-    fn set_backing_object(self, _agent: &mut Agent, _backing_object: OrdinaryObject<'static>) {
-        todo!();
+    /// Mark a JavaScript object as reachable.
+    pub fn mark_object(&mut self, object: Object<'static>) {
+        object.mark_values(self.0);
     }
+}
 
-    fn create_backing_object(self, _agent: &mut Agent) -> OrdinaryObject<'static> {
-        todo!();
+/// Sweeper for JavaScript values held in an [`EmbedderObject`]'s payload.
+pub struct EmbedderObjectSweeper<'a>(&'a CompactionLists);
+
+impl EmbedderObjectSweeper<'_> {
+    /// Update a JavaScript value to point to its post-compaction location.
+    pub fn sweep_value(&self, value: &mut Value<'static>) {
+        value.sweep_values(self.0);
     }
-    fn internal_extensible(self, _agent: &Agent) -> bool {
-        todo!();
+
+    /// Update a JavaScript object to point to its post-compaction location.
+    pub fn sweep_object(&self, object: &mut Object<'static>) {
+        object.sweep_values(self.0);
+    }
+}
+
+impl<'a> EmbedderObject<'a> {
+    /// Create a new embedder object with the given payload and hooks.
+    ///
+    /// The `prototype` is the object's \[\[Prototype]]; `None` creates an
+    /// object with a null prototype. Use [`Realm::object_prototype`] to create
+    /// an object that inherits from `%Object.prototype%`. Throws a RangeError
+    /// if the object could not be allocated.
+    ///
+    /// The `finalize` hook, if any, is called with the payload on a garbage
+    /// collection worker thread once the object is garbage collected.
+    ///
+    /// [`Realm::object_prototype`]: crate::ecmascript::Realm::object_prototype
+    pub fn create(
+        agent: &mut Agent,
+        prototype: Option<Object>,
+        data: Box<dyn Any + Send>,
+        hooks: &'static EmbedderObjectHooks,
+        gc: NoGcScope<'a, '_>,
+    ) -> JsResult<'a, Self> {
+        let prototype = prototype.bind(gc);
+        let object_prototype = agent
+            .current_realm_record()
+            .intrinsics()
+            .get_intrinsic_default_proto(Self::DEFAULT_PROTOTYPE);
+        let backing_object = if prototype == Some(object_prototype) {
+            // The embedder object data implies the default prototype; no need
+            // to create a backing object yet.
+            None
+        } else {
+            Some(
+                OrdinaryObject::create_object(agent, prototype, &[])
+                    .map_err(|err| agent.throw_allocation_exception(err, gc))?,
+            )
+        };
+        Ok(agent
+            .heap
+            .create(EmbedderObjectHeapData::new(backing_object, data, hooks))
+            .bind(gc))
     }
 
-    fn internal_set_extensible(self, _agent: &mut Agent, _value: bool) {
-        todo!();
+    /// Access the embedder object's payload.
+    pub fn data(self, agent: &Agent) -> &dyn Any {
+        &*self.unbind().get(agent).data
     }
 
-    fn internal_prototype(self, _agent: &Agent) -> Option<Object<'static>> {
-        todo!();
+    /// Access the embedder object's payload as mutable.
+    pub fn data_mut(self, agent: &mut Agent) -> &mut dyn Any {
+        &mut *self.unbind().get_mut(agent).data
     }
 
-    fn internal_set_prototype(self, _agent: &mut Agent, _prototype: Option<Object>) {
-        todo!();
+    /// Access the embedder object's payload as a concrete type.
+    ///
+    /// Returns `None` if the payload is not of type `T`.
+    pub fn downcast_ref<T: Any>(self, agent: &Agent) -> Option<&T> {
+        self.data(agent).downcast_ref()
+    }
+
+    /// Access the embedder object's payload as a concrete mutable type.
+    ///
+    /// Returns `None` if the payload is not of type `T`.
+    pub fn downcast_mut<T: Any>(self, agent: &mut Agent) -> Option<&mut T> {
+        self.data_mut(agent).downcast_mut()
+    }
+}
+
+impl<'a> InternalSlots<'a> for EmbedderObject<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::Object;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        self.get(agent).backing_object.unbind()
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(
+            self.get_mut(agent)
+                .backing_object
+                .replace(backing_object.unbind())
+                .is_none()
+        );
     }
 }
 
 impl<'a> InternalMethods<'a> for EmbedderObject<'a> {}
 
+impl<'a> CreateHeapData<EmbedderObjectHeapData<'a>, EmbedderObject<'a>> for Heap {
+    fn create(&mut self, data: EmbedderObjectHeapData<'a>) -> EmbedderObject<'a> {
+        self.embedder_objects.push(data.unbind());
+        self.alloc_counter += core::mem::size_of::<EmbedderObjectHeapData<'static>>();
+        EmbedderObject(BaseIndex::last(&self.embedder_objects))
+    }
+}
+
 impl HeapMarkAndSweep for EmbedderObject<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
         queues.embedder_objects.push(*self);
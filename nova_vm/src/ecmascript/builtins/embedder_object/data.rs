@@ -2,24 +2,73 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+use core::any::Any;
+
 use crate::{
     ecmascript::types::OrdinaryObject,
+    engine::bindable_handle,
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
 
-#[derive(Debug, Clone)]
+use super::{EmbedderObjectHooks, EmbedderObjectMarker, EmbedderObjectSweeper};
+
+#[derive(Debug)]
 pub(crate) struct EmbedderObjectHeapData<'a> {
-    backing_object: Option<OrdinaryObject<'a>>,
+    pub(super) backing_object: Option<OrdinaryObject<'a>>,
+    /// Embedder-owned payload of the object.
+    pub(super) data: Box<dyn Any + Send>,
+    /// Garbage collection and finalization hooks of the payload.
+    pub(super) hooks: &'static EmbedderObjectHooks,
+}
+
+impl<'a> EmbedderObjectHeapData<'a> {
+    pub(super) fn new(
+        backing_object: Option<OrdinaryObject<'a>>,
+        data: Box<dyn Any + Send>,
+        hooks: &'static EmbedderObjectHooks,
+    ) -> Self {
+        Self {
+            backing_object,
+            data,
+            hooks,
+        }
+    }
 }
 
+impl Drop for EmbedderObjectHeapData<'_> {
+    fn drop(&mut self) {
+        if let Some(finalize) = self.hooks.finalize {
+            // Note: Box<()> does not allocate.
+            let data = core::mem::replace(&mut self.data, Box::new(()));
+            finalize(data);
+        }
+    }
+}
+
+bindable_handle!(EmbedderObjectHeapData);
+
 impl HeapMarkAndSweep for EmbedderObjectHeapData<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
-        let Self { backing_object } = self;
+        let Self {
+            backing_object,
+            data,
+            hooks,
+        } = self;
         backing_object.mark_values(queues);
+        if let Some(mark) = hooks.mark {
+            mark(&**data, &mut EmbedderObjectMarker(queues));
+        }
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
-        let Self { backing_object } = self;
+        let Self {
+            backing_object,
+            data,
+            hooks,
+        } = self;
         backing_object.sweep_values(compactions);
+        if let Some(sweep) = hooks.sweep {
+            sweep(&mut **data, &EmbedderObjectSweeper(compactions));
+        }
     }
 }
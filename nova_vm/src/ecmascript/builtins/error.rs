@@ -4,13 +4,16 @@
 
 mod data;
 
+use core::fmt::Write;
+
 pub(crate) use data::*;
 
 use crate::{
     ecmascript::{
         Agent, BUILTIN_STRING_MEMORY, ExceptionType, InternalMethods, InternalSlots, JsResult,
         Object, OrdinaryObject, PropertyDescriptor, PropertyKey, ProtoIntrinsics, SetResult,
-        String, TryGetResult, TryHasResult, TryResult, Value, object_handle, unwrap_try,
+        String, TryGetResult, TryHasResult, TryResult, Value, js_result_into_try, object_handle,
+        unwrap_try,
     },
     engine::{Bindable, GcScope, NoGcScope},
     heap::{
@@ -21,8 +24,9 @@ use crate::{
 };
 
 use super::ordinary::{
-    PropertyLookupCache, ordinary_delete, ordinary_get_own_property, ordinary_has_property,
-    ordinary_set, ordinary_try_get, ordinary_try_has_property, ordinary_try_set,
+    PropertyLookupCache, ordinary_define_own_property, ordinary_delete, ordinary_get_own_property,
+    ordinary_has_property, ordinary_set, ordinary_try_get, ordinary_try_has_property,
+    ordinary_try_set,
 };
 
 /// ## [20.5 Error Objects](https://tc39.es/ecma262/#sec-error-objects)
@@ -45,6 +49,111 @@ arena_vec_access!(
     errors
 );
 
+impl<'a> Error<'a> {
+    /// Returns true if the Error has a stack trace but its `stack` own
+    /// property has not yet been defined.
+    fn has_pending_stack_property(self, agent: &Agent) -> bool {
+        matches!(
+            self.get(agent).stack,
+            ErrorStack::Pending | ErrorStack::Captured { .. }
+        )
+    }
+
+    /// Captures the current stack trace into the Error, skipping the given
+    /// number of innermost execution contexts. The name and message of the
+    /// Error are read now and used as the header of the `stack` property.
+    pub(crate) fn capture_stack_trace(self, agent: &mut Agent, skip: usize, gc: NoGcScope) {
+        let header = self.stack_header(agent, gc).into_boxed_str();
+        let frames = agent.capture_stack_trace(skip, gc).unbind();
+        self.get_mut(agent).stack = ErrorStack::Captured { header, frames };
+    }
+
+    /// Formats the name and message of the Error like
+    /// `Error.prototype.toString` does, without calling into JavaScript.
+    fn stack_header(self, agent: &mut Agent, gc: NoGcScope) -> std::string::String {
+        let name = self
+            .try_get_string(agent, BUILTIN_STRING_MEMORY.name, gc)
+            .unwrap_or_else(|| "Error".into());
+        let message = self
+            .try_get_string(agent, BUILTIN_STRING_MEMORY.message, gc)
+            .unwrap_or_default();
+        if name.is_empty() {
+            message
+        } else if message.is_empty() {
+            name
+        } else {
+            format!("{name}: {message}")
+        }
+    }
+
+    /// Defines the `stack` own property of the Error from the captured stack
+    /// trace if the property key is `stack` and the property has not yet been
+    /// defined.
+    ///
+    /// The property is a non-enumerable data property, formatted like in V8:
+    /// the name and message of the Error at the time the stack trace was
+    /// captured, followed by one line per stack frame.
+    fn define_pending_stack_property(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        gc: NoGcScope,
+    ) {
+        if property_key != PropertyKey::from(BUILTIN_STRING_MEMORY.stack)
+            || !self.has_pending_stack_property(agent)
+        {
+            return;
+        }
+        // Note: an Error whose pending stack trace was never captured, eg.
+        // because a builtin used it as a promise rejection reason, has no
+        // stack frames.
+        let (mut stack, frames) = match core::mem::take(&mut self.get_mut(agent).stack) {
+            ErrorStack::Captured { header, frames } => (header.into_string(), frames),
+            _ => (self.stack_header(agent, gc), Box::default()),
+        };
+        for frame in frames.iter() {
+            let _ = write!(stack, "\n    at {}", frame.to_stack_frame(agent, gc));
+        }
+        self.get_mut(agent).stack = ErrorStack::Defined(frames);
+        let stack = String::from_string(agent, stack, gc);
+        let backing_object = self.get_or_create_backing_object(agent);
+        let defined = ordinary_define_own_property(
+            agent,
+            self.into(),
+            backing_object,
+            BUILTIN_STRING_MEMORY.stack.to_property_key(),
+            PropertyDescriptor {
+                value: Some(stack.into()),
+                writable: Some(true),
+                get: None,
+                set: None,
+                enumerable: Some(false),
+                configurable: Some(true),
+            },
+            None,
+            gc,
+        );
+        debug_assert!(matches!(defined, Ok(true)));
+    }
+
+    /// Gets a String-valued property of the Error without calling into
+    /// JavaScript.
+    fn try_get_string(
+        self,
+        agent: &mut Agent,
+        key: String<'static>,
+        gc: NoGcScope,
+    ) -> Option<std::string::String> {
+        let TryResult::Continue(TryGetResult::Value(value)) =
+            self.try_get(agent, key.to_property_key(), self.into(), None, gc)
+        else {
+            return None;
+        };
+        let value = String::try_from(value).ok()?;
+        Some(value.to_string_lossy(agent).into_owned())
+    }
+}
+
 impl<'a> InternalSlots<'a> for Error<'a> {
     const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::Error;
 
@@ -111,6 +220,7 @@ impl<'a> InternalSlots<'a> for Error<'a> {
                 ExceptionType::SyntaxError => ProtoIntrinsics::SyntaxError,
                 ExceptionType::TypeError => ProtoIntrinsics::TypeError,
                 ExceptionType::UriError => ProtoIntrinsics::URIError,
+                ExceptionType::SuppressedError => ProtoIntrinsics::SuppressedError,
             };
             Some(
                 agent
@@ -130,6 +240,7 @@ impl<'a> InternalMethods<'a> for Error<'a> {
         cache: Option<PropertyLookupCache>,
         gc: NoGcScope<'gc, '_>,
     ) -> TryResult<'gc, Option<PropertyDescriptor<'gc>>> {
+        self.define_pending_stack_property(agent, property_key, gc);
         match self.get_backing_object(agent) {
             Some(backing_object) => TryResult::Continue(
                 ordinary_get_own_property(
@@ -164,6 +275,27 @@ impl<'a> InternalMethods<'a> for Error<'a> {
         }
     }
 
+    fn try_define_own_property<'gc>(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        property_descriptor: PropertyDescriptor,
+        cache: Option<PropertyLookupCache>,
+        gc: NoGcScope<'gc, '_>,
+    ) -> TryResult<'gc, bool> {
+        self.define_pending_stack_property(agent, property_key, gc);
+        let backing_object = self.get_or_create_backing_object(agent);
+        js_result_into_try(ordinary_define_own_property(
+            agent,
+            self.into(),
+            backing_object,
+            property_key,
+            property_descriptor,
+            cache,
+            gc,
+        ))
+    }
+
     fn try_has_property<'gc>(
         self,
         agent: &mut Agent,
@@ -172,6 +304,7 @@ impl<'a> InternalMethods<'a> for Error<'a> {
         gc: NoGcScope<'gc, '_>,
     ) -> TryResult<'gc, TryHasResult<'gc>> {
         let error = self.bind(gc);
+        error.define_pending_stack_property(agent, property_key, gc);
         let backing_object = error.get_backing_object(agent);
         if backing_object.is_none()
             && property_key == PropertyKey::from(BUILTIN_STRING_MEMORY.message)
@@ -195,6 +328,7 @@ impl<'a> InternalMethods<'a> for Error<'a> {
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, bool> {
         let property_key = property_key.bind(gc.nogc());
+        self.define_pending_stack_property(agent, property_key, gc.nogc());
         match self.get_backing_object(agent) {
             Some(backing_object) => ordinary_has_property(
                 agent,
@@ -231,6 +365,7 @@ impl<'a> InternalMethods<'a> for Error<'a> {
         cache: Option<PropertyLookupCache>,
         gc: NoGcScope<'gc, '_>,
     ) -> TryResult<'gc, TryGetResult<'gc>> {
+        self.define_pending_stack_property(agent, property_key, gc);
         let backing_object = self.get_backing_object(agent);
         let property_value = if backing_object.is_none()
             && property_key == PropertyKey::from(BUILTIN_STRING_MEMORY.message)
@@ -265,6 +400,7 @@ impl<'a> InternalMethods<'a> for Error<'a> {
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
         let property_key = property_key.bind(gc.nogc());
+        self.define_pending_stack_property(agent, property_key, gc.nogc());
         match self.get_backing_object(agent) {
             Some(backing_object) => {
                 backing_object.internal_get(agent, property_key.unbind(), receiver, gc)
@@ -301,6 +437,7 @@ impl<'a> InternalMethods<'a> for Error<'a> {
         cache: Option<PropertyLookupCache>,
         gc: NoGcScope<'gc, '_>,
     ) -> TryResult<'gc, SetResult<'gc>> {
+        self.define_pending_stack_property(agent, property_key, gc);
         if self.get_backing_object(agent).is_some() {
             ordinary_try_set(agent, self, property_key, value, receiver, cache, gc)
         } else if property_key == PropertyKey::from(BUILTIN_STRING_MEMORY.message)
@@ -326,6 +463,7 @@ impl<'a> InternalMethods<'a> for Error<'a> {
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, bool> {
         let property_key = property_key.bind(gc.nogc());
+        self.define_pending_stack_property(agent, property_key, gc.nogc());
         if self.get_backing_object(agent).is_some() {
             ordinary_set(
                 agent,
@@ -362,6 +500,7 @@ impl<'a> InternalMethods<'a> for Error<'a> {
         property_key: PropertyKey,
         gc: NoGcScope<'gc, '_>,
     ) -> TryResult<'gc, bool> {
+        self.define_pending_stack_property(agent, property_key, gc);
         match self.get_backing_object(agent) {
             Some(backing_object) => TryResult::Continue(ordinary_delete(
                 agent,
@@ -388,16 +527,23 @@ impl<'a> InternalMethods<'a> for Error<'a> {
     ) -> TryResult<'gc, Vec<PropertyKey<'gc>>> {
         match self.get_backing_object(agent) {
             Some(backing_object) => {
-                TryResult::Continue(unwrap_try(backing_object.try_own_property_keys(agent, gc)))
+                let mut property_keys = unwrap_try(backing_object.try_own_property_keys(agent, gc));
+                if self.has_pending_stack_property(agent) {
+                    property_keys.push(BUILTIN_STRING_MEMORY.stack.into());
+                }
+                TryResult::Continue(property_keys)
             }
             None => {
-                let mut property_keys = Vec::with_capacity(2);
+                let mut property_keys = Vec::with_capacity(3);
                 if self.get_mut(agent).message.is_some() {
                     property_keys.push(BUILTIN_STRING_MEMORY.message.into());
                 }
                 if self.get_mut(agent).cause.is_some() {
                     property_keys.push(BUILTIN_STRING_MEMORY.cause.into());
                 }
+                if self.has_pending_stack_property(agent) {
+                    property_keys.push(BUILTIN_STRING_MEMORY.stack.into());
+                }
                 TryResult::Continue(property_keys)
             }
         }
@@ -3,18 +3,56 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::{
-    ecmascript::{OrdinaryObject, String, Value, execution::ExceptionType},
+    ecmascript::{OrdinaryObject, StackFrameRecord, String, Value, execution::ExceptionType},
     engine::bindable_handle,
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub(crate) struct ErrorHeapData<'a> {
     pub(crate) object_index: Option<OrdinaryObject<'a>>,
     pub(crate) kind: ExceptionType,
     pub(crate) message: Option<String<'a>>,
     pub(crate) cause: Option<Value<'a>>,
-    // TODO: stack? name?
+    pub(crate) stack: ErrorStack<'a>,
+}
+
+/// Stack trace of an Error object.
+#[derive(Debug, Clone, Default)]
+pub(crate) enum ErrorStack<'a> {
+    /// The Error has no stack trace.
+    #[default]
+    None,
+    /// The Error was thrown by the engine and its stack trace is captured
+    /// when the error leaves the builtin function or bytecode instruction
+    /// that threw it. Errors that are handled before that, eg. inside a
+    /// builtin, never capture a stack trace.
+    Pending,
+    /// The stack trace has been captured but the `stack` own property has not
+    /// yet been defined.
+    Captured {
+        /// Name and message of the Error at the time of capture.
+        header: Box<str>,
+        frames: Box<[StackFrameRecord<'a>]>,
+    },
+    /// The `stack` own property has been defined.
+    Defined(Box<[StackFrameRecord<'a>]>),
+}
+
+impl<'a> ErrorStack<'a> {
+    pub(crate) fn frames(&self) -> Option<&[StackFrameRecord<'a>]> {
+        match self {
+            Self::None | Self::Pending => None,
+            Self::Captured { frames, .. } | Self::Defined(frames) => Some(frames),
+        }
+    }
+
+    pub(crate) fn frames_mut(&mut self) -> Option<&mut [StackFrameRecord<'a>]> {
+        match self {
+            Self::None | Self::Pending => None,
+            Self::Captured { frames, .. } | Self::Defined(frames) => Some(frames),
+        }
+    }
 }
 
 impl<'a> ErrorHeapData<'a> {
@@ -28,12 +66,40 @@ impl<'a> ErrorHeapData<'a> {
             kind,
             message,
             cause,
+            stack: ErrorStack::None,
         }
     }
+
+    /// Capture the stack trace of the Error once it leaves the builtin
+    /// function or bytecode instruction that threw it.
+    pub(crate) fn with_pending_stack(mut self) -> Self {
+        self.stack = ErrorStack::Pending;
+        self
+    }
 }
 
 bindable_handle!(ErrorHeapData);
 
+impl HeapMarkAndSweep for ErrorStack<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match self {
+            Self::None | Self::Pending => {}
+            Self::Captured { header: _, frames } | Self::Defined(frames) => {
+                frames.mark_values(queues)
+            }
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match self {
+            Self::None | Self::Pending => {}
+            Self::Captured { header: _, frames } | Self::Defined(frames) => {
+                frames.sweep_values(compactions)
+            }
+        }
+    }
+}
+
 impl HeapMarkAndSweep for ErrorHeapData<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
         let Self {
@@ -41,11 +107,13 @@ impl HeapMarkAndSweep for ErrorHeapData<'static> {
             kind: _,
             message,
             cause,
+            stack,
         } = self;
 
         object_index.mark_values(queues);
         message.mark_values(queues);
         cause.mark_values(queues);
+        stack.mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
@@ -54,9 +122,11 @@ impl HeapMarkAndSweep for ErrorHeapData<'static> {
             kind: _,
             message,
             cause,
+            stack,
         } = self;
         object_index.sweep_values(compactions);
         message.sweep_values(compactions);
         cause.sweep_values(compactions);
+        stack.sweep_values(compactions);
     }
 }
@@ -67,7 +67,9 @@ impl Cells<'_> {
 pub(crate) struct CleanupRecord<'a> {
     cleanup_queue: Vec<Value<'a>>,
     /// \[\[CleanupCallback]]
-    callback: Function<'a>,
+    ///
+    /// This is None only before the FinalizationRegistry is initialised.
+    callback: Option<Function<'a>>,
     /// \[\[Realm]]
     realm: Realm<'a>,
     cleanup_requested: bool,
@@ -78,8 +80,7 @@ impl Default for CleanupRecord<'_> {
     fn default() -> Self {
         Self {
             cleanup_queue: Default::default(),
-            // Note: impossible value currently.
-            callback: Function::BuiltinProxyRevokerFunction,
+            callback: None,
             realm: Realm::_DEF,
             cleanup_requested: false,
         }
@@ -102,14 +103,17 @@ impl<'fr> CleanupRecord<'fr> {
     /// FinalizationRegistry must be previously uninitialised.
     pub(super) unsafe fn initialise(&mut self, realm: Realm, cleanup_callback: Function) {
         debug_assert_eq!(self.realm, Realm::_DEF);
-        debug_assert_eq!(self.callback, Function::BuiltinProxyRevokerFunction);
+        debug_assert!(self.callback.is_none());
         self.realm = realm.unbind();
-        self.callback = cleanup_callback.unbind();
+        self.callback = Some(cleanup_callback.unbind());
     }
 
     pub(super) fn get_cleanup_queue(&mut self) -> (Function<'fr>, Vec<Value<'fr>>) {
         self.cleanup_requested = false;
-        (self.callback, core::mem::take(&mut self.cleanup_queue))
+        let callback = self
+            .callback
+            .expect("FinalizationRegistry should be initialised");
+        (callback, core::mem::take(&mut self.cleanup_queue))
     }
 
     pub(super) fn push_cleanup_queue(&mut self, queue: Vec<Value<'fr>>) -> bool {
@@ -8,6 +8,8 @@ mod error_constructor;
 mod error_prototype;
 mod native_error_constructors;
 mod native_error_prototypes;
+mod suppressed_error_constructors;
+mod suppressed_error_prototypes;
 
 pub(crate) use aggregate_error_constructors::*;
 pub(crate) use aggregate_error_prototypes::*;
@@ -15,3 +17,5 @@ pub(crate) use error_constructor::*;
 pub(crate) use error_prototype::*;
 pub(crate) use native_error_constructors::*;
 pub(crate) use native_error_prototypes::*;
+pub(crate) use suppressed_error_constructors::*;
+pub(crate) use suppressed_error_prototypes::*;
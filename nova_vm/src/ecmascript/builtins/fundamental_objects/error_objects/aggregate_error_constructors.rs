@@ -47,7 +47,7 @@ impl AggregateErrorConstructor {
         .unbind()?
         .scope(agent, gc.nogc());
         // 5. Let errorsList be ? IteratorToList(? GetIterator(errors, sync)).
-        let Some(iterator_record) = get_iterator(agent, errors.get(agent), false, gc.reborrow())
+        let Some(iterator_record) = get_iterator(agent, errors.get(agent), gc.reborrow())
             .unbind()?
             .bind(gc.nogc())
             .into_iterator_record()
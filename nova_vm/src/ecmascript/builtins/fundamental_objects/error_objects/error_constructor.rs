@@ -62,6 +62,7 @@ impl ErrorConstructor {
             ExceptionType::SyntaxError => ProtoIntrinsics::SyntaxError,
             ExceptionType::TypeError => ProtoIntrinsics::TypeError,
             ExceptionType::UriError => ProtoIntrinsics::URIError,
+            ExceptionType::SuppressedError => ProtoIntrinsics::SuppressedError,
         };
 
         // 1. If NewTarget is undefined, let newTarget be the active function
@@ -146,6 +147,11 @@ impl ErrorConstructor {
             ));
         }
 
+        // NOTE: The stack trace skips the execution context of the
+        // constructor itself. Its header is fixed to the name and message of
+        // the Error at construction.
+        o.capture_stack_trace(agent, 1, gc.nogc());
+
         // 5. Return O.
         Ok(o.unbind())
     }
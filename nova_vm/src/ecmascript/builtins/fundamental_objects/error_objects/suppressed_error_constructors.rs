@@ -0,0 +1,120 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, Error, ErrorConstructor, ErrorHeapData, ExceptionType,
+        InternalMethods, JsResult, Object, PropertyDescriptor, Realm, String, Value,
+        builders::BuiltinFunctionBuilder, unwrap_try,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{CreateHeapData, IntrinsicConstructorIndexes},
+};
+
+pub(crate) struct SuppressedErrorConstructor;
+impl Builtin for SuppressedErrorConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.SuppressedError;
+
+    const LENGTH: u8 = 3;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for SuppressedErrorConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::SuppressedError;
+}
+
+impl SuppressedErrorConstructor {
+    /// ### [SuppressedError ( error, suppressed, message )](https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let error = arguments.get(0).scope(agent, gc.nogc());
+        let suppressed = arguments.get(1).scope(agent, gc.nogc());
+        let message = arguments.get(2).bind(gc.nogc());
+        // 1. If NewTarget is undefined, let newTarget be the active function
+        //    object; else let newTarget be NewTarget.
+        // 2. Let O be ? OrdinaryCreateFromConstructor(newTarget,
+        //    "%SuppressedError.prototype%", « [[ErrorData]] »).
+        // 3. If message is not undefined, then
+        // a. Let msg be ? ToString(message).
+        // b. Perform CreateNonEnumerableDataPropertyOrThrow(O, "message", msg).
+        let o = ErrorConstructor::base_constructor(
+            agent,
+            ExceptionType::SuppressedError,
+            ArgumentsList::from_mut_slice(&mut [message.unbind(), Value::Undefined]),
+            new_target,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let o = o.bind(gc);
+        // SAFETY: not shared.
+        let (error, suppressed) = unsafe { (error.take(agent), suppressed.take(agent)) };
+        // 4. Perform CreateNonEnumerableDataPropertyOrThrow(O, "error", error).
+        // 5. Perform CreateNonEnumerableDataPropertyOrThrow(O, "suppressed",
+        //    suppressed).
+        define_error_and_suppressed(agent, o, error, suppressed, gc);
+        // 6. Return O.
+        Ok(o.into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let error_constructor = intrinsics.error();
+        let suppressed_error_prototype = intrinsics.suppressed_error_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<SuppressedErrorConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(1)
+        .with_prototype(error_constructor)
+        .with_prototype_property(suppressed_error_prototype.into())
+        .build();
+    }
+}
+
+/// Create a new SuppressedError object with the given "error" and
+/// "suppressed" properties and no message, as done in the DisposeResources
+/// abstract operation.
+pub(crate) fn create_suppressed_error<'a>(
+    agent: &mut Agent,
+    error: Value,
+    suppressed: Value,
+    gc: NoGcScope<'a, '_>,
+) -> Error<'a> {
+    let o = agent
+        .heap
+        .create(ErrorHeapData::new(ExceptionType::SuppressedError, None, None).with_pending_stack())
+        .bind(gc);
+    define_error_and_suppressed(agent, o, error, suppressed, gc);
+    o
+}
+
+fn define_error_and_suppressed(
+    agent: &mut Agent,
+    o: Error,
+    error: Value,
+    suppressed: Value,
+    gc: NoGcScope,
+) {
+    unwrap_try(o.try_define_own_property(
+        agent,
+        BUILTIN_STRING_MEMORY.error.into(),
+        PropertyDescriptor::non_enumerable_data_descriptor(error),
+        None,
+        gc,
+    ));
+    unwrap_try(o.try_define_own_property(
+        agent,
+        BUILTIN_STRING_MEMORY.suppressed.into(),
+        PropertyDescriptor::non_enumerable_data_descriptor(suppressed),
+        None,
+        gc,
+    ));
+}
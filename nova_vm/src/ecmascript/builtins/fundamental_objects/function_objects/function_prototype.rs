@@ -409,16 +409,15 @@ impl FunctionPrototype {
             // The representation must have the syntax of a NativeFunction.
             Function::BoundFunction(_)
             | Function::BuiltinPromiseResolvingFunction(_)
-            | Function::BuiltinPromiseFinallyFunction(_) => {
-                // Promise resolving and finally functions have no initial name.
+            | Function::BuiltinPromiseFinallyFunction(_)
+            | Function::BuiltinProxyRevokerFunction(_) => {
+                // Promise resolving and finally functions and Proxy
+                // revocation functions have no initial name.
                 Ok(
                     Value::from_static_str(agent, "function () { [ native code ] }", gc.nogc())
                         .unbind(),
                 )
             }
-            Function::BuiltinProxyRevokerFunction => {
-                unreachable!()
-            }
         }
 
         // NOTE: NativeFunction means the following string:
@@ -1303,7 +1303,7 @@ pub(crate) fn add_entries_from_iterable_from_entries<'a>(
         iterator,
         next_method,
         ..
-    }) = get_iterator(agent, iterable.get(agent), false, gc.reborrow())
+    }) = get_iterator(agent, iterable.get(agent), gc.reborrow())
         .unbind()?
         .bind(gc.nogc())
         .into_iterator_record()
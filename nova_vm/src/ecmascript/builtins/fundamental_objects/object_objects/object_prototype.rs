@@ -193,8 +193,6 @@ impl ObjectPrototype {
             Value::Proxy(proxy) if proxy.is_callable(agent, gc.nogc()) => {
                 BUILTIN_STRING_MEMORY._object_Function_
             }
-            // TODO: Check for [[Call]] slot of EmbedderObject
-            Value::EmbedderObject(_) => todo!(),
             // 13. Else if O has a [[RegExpMatcher]] internal slot, let builtinTag be "RegExp".
             // 17. Return the string-concatenation of "[object ", tag, and "]".
             #[cfg(feature = "regexp")]
@@ -137,7 +137,15 @@ impl SymbolConstructor {
 
         let builder =
             BuiltinFunctionBuilder::new_intrinsic_constructor::<SymbolConstructor>(agent, realm)
-                .with_property_capacity(16)
+                .with_property_capacity(18)
+                .with_property(|builder| {
+                    builder
+                        .with_key(BUILTIN_STRING_MEMORY.asyncDispose.into())
+                        .with_value_readonly(WellKnownSymbols::AsyncDispose.into())
+                        .with_enumerable(false)
+                        .with_configurable(false)
+                        .build()
+                })
                 .with_property(|builder| {
                     builder
                         .with_key(BUILTIN_STRING_MEMORY.asyncIterator.into())
@@ -146,6 +154,14 @@ impl SymbolConstructor {
                         .with_configurable(false)
                         .build()
                 })
+                .with_property(|builder| {
+                    builder
+                        .with_key(BUILTIN_STRING_MEMORY.dispose.into())
+                        .with_value_readonly(WellKnownSymbols::Dispose.into())
+                        .with_enumerable(false)
+                        .with_configurable(false)
+                        .build()
+                })
                 .with_builtin_function_property::<SymbolFor>()
                 .with_property(|builder| {
                     builder
@@ -3,9 +3,11 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 mod array_constructor;
+mod array_from_async;
 mod array_iterator_objects;
 mod array_prototype;
 
 pub(crate) use array_constructor::*;
+pub(crate) use array_from_async::*;
 pub use array_iterator_objects::*;
 pub(crate) use array_prototype::*;
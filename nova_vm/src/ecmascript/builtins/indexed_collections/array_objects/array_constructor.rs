@@ -7,11 +7,12 @@ use crate::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
         BuiltinIntrinsicConstructor, ExceptionType, Function, IteratorRecord, JsResult, Number,
         Object, PropertyKey, ProtoIntrinsics, Realm, SmallInteger, String, Value, array_create,
-        builders::BuiltinFunctionBuilder, call_function, construct, create_data_property_or_throw,
-        get, get_iterator_from_method, get_method, get_prototype_from_constructor,
-        if_abrupt_close_iterator, is_array, is_callable, is_constructor, iterator_close_with_error,
-        iterator_step_value, length_of_array_like, same_value_zero, set, throw_not_callable,
-        to_object, to_uint32_number, try_create_data_property_or_throw, unwrap_try,
+        array_from_async, array_set_length_handling, builders::BuiltinFunctionBuilder,
+        call_function, construct, create_data_property_or_throw, get, get_iterator_from_method,
+        get_method, get_prototype_from_constructor, if_abrupt_close_iterator, is_array,
+        is_callable, is_constructor, iterator_close_with_error, iterator_step_value,
+        length_of_array_like, same_value_zero, set, throw_not_callable, to_object,
+        to_uint32_number, try_create_data_property_or_throw, unwrap_try,
     },
     engine::{Bindable, GcScope, Scopable},
     heap::{IntrinsicConstructorIndexes, WellKnownSymbols},
@@ -34,6 +35,12 @@ impl Builtin for ArrayFrom {
     const LENGTH: u8 = 1;
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.from;
 }
+struct ArrayFromAsync;
+impl Builtin for ArrayFromAsync {
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(ArrayConstructor::from_async);
+    const LENGTH: u8 = 1;
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.fromAsync;
+}
 struct ArrayIsArray;
 impl Builtin for ArrayIsArray {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(ArrayConstructor::is_array);
@@ -118,14 +125,13 @@ impl ArrayConstructor {
                         gc,
                     ));
                 }
-                let array = array_create(
-                    agent,
-                    int_len as usize,
-                    int_len as usize,
-                    proto.map(|p| p.get(agent)),
-                    gc,
-                )?;
+                let array = array_create(agent, 0, 0, proto.map(|p| p.get(agent)), gc)?;
                 // e. Perform ! Set(array, "length", intLen, true).
+                // Note: a large length switches the array to sparse storage.
+                if let Err(err) = array_set_length_handling(agent, array, int_len, None, None, None)
+                {
+                    return Err(agent.throw_allocation_exception(err, gc));
+                }
                 debug_assert_eq!(array.len(agent), int_len);
                 array
             } else {
@@ -476,6 +482,24 @@ impl ArrayConstructor {
         Ok(a.get(agent).into())
     }
 
+    /// ### [Array.fromAsync ( asyncItems \[ , mapfn \[ , thisArg \] \] )](https://tc39.es/proposal-array-from-async/#sec-array.fromAsync)
+    fn from_async<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        Ok(array_from_async(
+            agent,
+            this_value,
+            arguments.get(0),
+            arguments.get(1),
+            arguments.get(2),
+            gc,
+        )
+        .into())
+    }
+
     /// ### [23.1.2.2 Array.isArray ( arg )](https://tc39.es/ecma262/#sec-array.isarray)
     fn is_array<'gc>(
         agent: &mut Agent,
@@ -559,9 +583,10 @@ impl ArrayConstructor {
         let array_prototype = intrinsics.array_prototype();
 
         BuiltinFunctionBuilder::new_intrinsic_constructor::<ArrayConstructor>(agent, realm)
-            .with_property_capacity(5)
+            .with_property_capacity(6)
             .with_prototype(function_prototype)
             .with_builtin_function_property::<ArrayFrom>()
+            .with_builtin_function_property::<ArrayFromAsync>()
             .with_builtin_function_property::<ArrayIsArray>()
             .with_builtin_function_property::<ArrayOf>()
             .with_prototype_property(array_prototype.into())
@@ -0,0 +1,784 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ### [Array.fromAsync ( asyncItems \[ , mapfn \[ , thisArg \] \] )](https://tc39.es/proposal-array-from-async/#sec-array.fromAsync)
+//!
+//! The fromAsyncClosure of Array.fromAsync is performed natively: its state
+//! is kept in an ArrayFromAsync record, and each Await step of the closure
+//! suspends the operation on a promise reaction that resumes it.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, AsyncFromSyncIteratorPrototype, BUILTIN_STRING_MEMORY, ExceptionType,
+        Function, IteratorRecord, JsError, JsResult, MaybeInvalidIteratorRecord, Number, Object,
+        Promise, PromiseCapability, PromiseReactionHandler, PromiseReactionType, PropertyKey,
+        SmallInteger, Value, array_create, call_function, construct, create_data_property_or_throw,
+        get, get_iterator_from_method, get_method, get_object_method, inner_promise_then,
+        is_callable, is_constructor, iterator_complete, iterator_value, length_of_array_like, set,
+        throw_not_callable, to_object,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable, bindable_handle},
+    heap::{
+        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
+        HeapMarkAndSweep, WellKnownSymbols, WorkQueues, arena_vec_access, index_handle,
+    },
+};
+
+/// The items that an Array.fromAsync operation is collecting.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ArrayFromAsyncSource<'a> {
+    /// An async iterator, or a sync iterator adapted by
+    /// CreateAsyncFromSyncIterator.
+    Iterator {
+        iterator: Object<'a>,
+        next_method: Option<Function<'a>>,
+        from_sync: bool,
+    },
+    /// An array-like object that is neither an AsyncIterable nor an Iterable.
+    ArrayLike { array_like: Object<'a>, len: u64 },
+}
+
+/// The Await step that an Array.fromAsync operation is suspended on.
+#[derive(Debug, Clone, Copy)]
+enum ArrayFromAsyncStep<'a> {
+    /// Awaiting the result of calling the iterator's next method.
+    NextResult,
+    /// Awaiting an element of the array-like.
+    Value,
+    /// Awaiting the result of calling mapfn.
+    MappedValue,
+    /// Awaiting the result of the iterator's return method during
+    /// AsyncIteratorClose; the operation rejects with the error afterwards.
+    Close(Value<'a>),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ArrayFromAsyncRecord<'a> {
+    /// The promise returned by Array.fromAsync.
+    promise: Promise<'a>,
+    /// The result object A.
+    target: Object<'a>,
+    mapper: Option<Function<'a>>,
+    this_arg: Value<'a>,
+    source: ArrayFromAsyncSource<'a>,
+    k: u64,
+    step: ArrayFromAsyncStep<'a>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(transparent)]
+pub(crate) struct ArrayFromAsync<'a>(BaseIndex<'a, ArrayFromAsyncRecord<'static>>);
+index_handle!(ArrayFromAsync);
+arena_vec_access!(ArrayFromAsync, 'a, ArrayFromAsyncRecord, array_from_async_records);
+
+/// ### [Array.fromAsync ( asyncItems \[ , mapfn \[ , thisArg \] \] )](https://tc39.es/proposal-array-from-async/#sec-array.fromAsync)
+pub(crate) fn array_from_async<'gc>(
+    agent: &mut Agent,
+    c: Value,
+    async_items: Value,
+    mapfn: Value,
+    this_arg: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> Promise<'gc> {
+    let c = c.bind(gc.nogc());
+    let async_items = async_items.bind(gc.nogc());
+    let mapfn = mapfn.bind(gc.nogc());
+    let this_arg = this_arg.bind(gc.nogc());
+    // 1. Let C be the this value.
+    // 2. Let promiseCapability be ! NewPromiseCapability(%Promise%).
+    let promise = PromiseCapability::new(agent, gc.nogc()).promise();
+    let scoped_promise = promise.scope(agent, gc.nogc());
+    // 3. Let fromAsyncClosure be a new Abstract Closure with no parameters
+    //    that captures C, mapfn, and thisArg and performs the following
+    //    steps when called:
+    // 4. Perform AsyncFunctionStart(promiseCapability, fromAsyncClosure).
+    let result = from_async_closure(
+        agent,
+        promise.unbind(),
+        c.unbind(),
+        async_items.unbind(),
+        mapfn.unbind(),
+        this_arg.unbind(),
+        gc.reborrow(),
+    )
+    .unbind();
+    let gc = gc.into_nogc();
+    // SAFETY: scoped_promise is not shared.
+    let promise = unsafe { scoped_promise.take(agent) }.bind(gc);
+    if let Err(err) = result {
+        PromiseCapability::from_promise(promise, true).reject(agent, err.value(), gc);
+    }
+    // 5. Return promiseCapability.[[Promise]].
+    promise
+}
+
+/// The fromAsyncClosure of Array.fromAsync up to its first Await.
+fn from_async_closure<'a>(
+    agent: &mut Agent,
+    promise: Promise,
+    c: Value,
+    async_items: Value,
+    mapfn: Value,
+    this_arg: Value,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    let promise = promise.bind(gc.nogc());
+    let c = c.bind(gc.nogc());
+    let async_items = async_items.bind(gc.nogc());
+    let mapfn = mapfn.bind(gc.nogc());
+    let this_arg = this_arg.bind(gc.nogc());
+    // a. If mapfn is undefined, let mapping be false.
+    let mapper = if mapfn.is_undefined() {
+        None
+    } else {
+        // b. Else,
+        // i. If IsCallable(mapfn) is false, throw a TypeError exception.
+        let Some(mapfn) = is_callable(mapfn, gc.nogc()) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "The map function of Array.fromAsync is not callable",
+                gc.into_nogc(),
+            ));
+        };
+        // ii. Let mapping be true.
+        Some(mapfn.scope(agent, gc.nogc()))
+    };
+    let scoped_promise = promise.scope(agent, gc.nogc());
+    let scoped_c = c.scope(agent, gc.nogc());
+    let scoped_async_items = async_items.scope(agent, gc.nogc());
+    let scoped_this_arg = this_arg.scope(agent, gc.nogc());
+
+    // c. Let usingAsyncIterator be ? GetMethod(asyncItems, %Symbol.asyncIterator%).
+    let using_async_iterator = get_method(
+        agent,
+        async_items.unbind(),
+        WellKnownSymbols::AsyncIterator.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // d. If usingAsyncIterator is undefined, then
+    let (using_iterator, from_sync) = if using_async_iterator.is_some() {
+        (using_async_iterator, false)
+    } else {
+        // i. Let usingSyncIterator be ? GetMethod(asyncItems, %Symbol.iterator%).
+        let using_sync_iterator = get_method(
+            agent,
+            scoped_async_items.get(agent),
+            WellKnownSymbols::Iterator.into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        (using_sync_iterator, true)
+    };
+
+    // h. If iteratorRecord is not undefined, then
+    let (target, source) = if let Some(using_iterator) = using_iterator {
+        // f. If usingAsyncIterator is not undefined, then
+        // i. Set iteratorRecord to ? GetIteratorFromMethod(asyncItems, usingAsyncIterator).
+        // g. Else if usingSyncIterator is not undefined, then
+        // i. Set iteratorRecord to ? CreateAsyncFromSyncIterator(GetIteratorFromMethod(asyncItems, usingSyncIterator)).
+        // NOTE: The Async-from-Sync Iterator object is not materialised: the
+        // sync iterator is called through %AsyncFromSyncIteratorPrototype%
+        // methods instead.
+        let MaybeInvalidIteratorRecord {
+            iterator,
+            next_method,
+        } = get_iterator_from_method(
+            agent,
+            scoped_async_items.get(agent),
+            using_iterator.unbind(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        let iterator = iterator.scope(agent, gc.nogc());
+        let next_method = next_method.map(|f| f.scope(agent, gc.nogc()));
+        // i. If IsConstructor(C) is true, then
+        let target = if let Some(c) = is_constructor(agent, scoped_c.get(agent)) {
+            // 1. Let A be ? Construct(C).
+            construct(agent, c.unbind(), None, None, gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc())
+        } else {
+            // ii. Else,
+            // 1. Let A be ! ArrayCreate(0).
+            array_create(agent, 0, 0, None, gc.nogc()).unwrap().into()
+        };
+        let source = ArrayFromAsyncSource::Iterator {
+            iterator: iterator.get(agent).bind(gc.nogc()),
+            next_method: next_method.map(|f| f.get(agent).bind(gc.nogc())),
+            from_sync,
+        };
+        (target, source)
+    } else {
+        // i. Else,
+        // i. NOTE: asyncItems is neither an AsyncIterable nor an Iterable so
+        //    assume it is an array-like object.
+        // ii. Let arrayLike be ! ToObject(asyncItems).
+        let array_like = to_object(agent, scoped_async_items.get(agent), gc.nogc())
+            .unwrap()
+            .scope(agent, gc.nogc());
+        // iii. Let len be ? LengthOfArrayLike(arrayLike).
+        let len = length_of_array_like(agent, array_like.get(agent), gc.reborrow()).unbind()?;
+        // iv. If IsConstructor(C) is true, then
+        let target = if let Some(c) = is_constructor(agent, scoped_c.get(agent)) {
+            // 1. Let A be ? Construct(C, « 𝔽(len) »).
+            construct(
+                agent,
+                c.unbind(),
+                Some(ArgumentsList::from_mut_slice(&mut [
+                    Value::try_from(len).unwrap()
+                ])),
+                None,
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc())
+        } else {
+            // v. Else,
+            // 1. Let A be ? ArrayCreate(len).
+            array_create(agent, len as usize, len as usize, None, gc.nogc())
+                .unbind()?
+                .bind(gc.nogc())
+                .into()
+        };
+        let source = ArrayFromAsyncSource::ArrayLike {
+            array_like: array_like.get(agent).bind(gc.nogc()),
+            len: len as u64,
+        };
+        (target, source)
+    };
+
+    // iii. Let k be 0.
+    let operation = agent.heap.create(ArrayFromAsyncRecord {
+        promise: scoped_promise.get(agent).unbind(),
+        target: target.unbind(),
+        mapper: mapper.map(|f| f.get(agent)),
+        this_arg: scoped_this_arg.get(agent),
+        source: source.unbind(),
+        k: 0,
+        step: ArrayFromAsyncStep::NextResult,
+    });
+    operation.run(agent, gc)
+}
+
+impl<'a> ArrayFromAsync<'a> {
+    /// Resume the operation after an Await step.
+    pub(crate) fn resume(
+        self,
+        agent: &mut Agent,
+        reaction_type: PromiseReactionType,
+        value: Value<'a>,
+        mut gc: GcScope<'a, '_>,
+    ) {
+        let operation = self.bind(gc.nogc());
+        let value = value.bind(gc.nogc());
+        let scoped_operation = operation.scope(agent, gc.nogc());
+        let step = operation.get(agent).step.bind(gc.nogc());
+        let result = match (step, reaction_type) {
+            // AsyncIteratorClose
+            // 6. If completion is a throw completion, return ? completion.
+            (ArrayFromAsyncStep::Close(error), _) => Err(JsError::new(error)),
+            // d. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+            (ArrayFromAsyncStep::MappedValue, PromiseReactionType::Reject) => operation
+                .unbind()
+                .close(agent, JsError::new(value.unbind()), gc.reborrow())
+                .unbind()
+                .bind(gc.nogc()),
+            (_, PromiseReactionType::Reject) => Err(JsError::new(value)),
+            (ArrayFromAsyncStep::NextResult, PromiseReactionType::Fulfill) => operation
+                .unbind()
+                .next_result(agent, value.unbind(), gc.reborrow())
+                .unbind()
+                .bind(gc.nogc()),
+            (ArrayFromAsyncStep::Value, PromiseReactionType::Fulfill) => operation
+                .unbind()
+                .map_value(agent, value.unbind(), gc.reborrow())
+                .unbind()
+                .bind(gc.nogc()),
+            (ArrayFromAsyncStep::MappedValue, PromiseReactionType::Fulfill) => operation
+                .unbind()
+                .define_value(agent, value.unbind(), gc.reborrow())
+                .unbind()
+                .bind(gc.nogc()),
+        };
+        if let Err(err) = result {
+            let operation = scoped_operation.get(agent).bind(gc.nogc());
+            operation.reject(agent, err.value(), gc.nogc());
+        }
+    }
+
+    /// Perform the next iteration of the fromAsyncClosure loop, up to its
+    /// first Await step.
+    fn run(self, agent: &mut Agent, mut gc: GcScope<'a, '_>) -> JsResult<'a, ()> {
+        let operation = self.bind(gc.nogc());
+        let ArrayFromAsyncRecord { k, source, .. } = *operation.get(agent);
+        let scoped_operation = operation.scope(agent, gc.nogc());
+        match source {
+            ArrayFromAsyncSource::Iterator {
+                iterator,
+                next_method,
+                from_sync,
+            } => {
+                // 1. If k ≥ 2**53 - 1, then
+                if k >= SmallInteger::MAX as u64 {
+                    // a. Let error be ThrowCompletion(a newly created TypeError object).
+                    let error = agent.throw_exception_with_static_message(
+                        ExceptionType::TypeError,
+                        "Maximum array size of 2**53-1 exceeded",
+                        gc.nogc(),
+                    );
+                    // b. Return ? AsyncIteratorClose(iteratorRecord, error).
+                    return operation.unbind().close(agent, error.unbind(), gc);
+                }
+                // 3. Let nextResult be ? Call(iteratorRecord.[[NextMethod]], iteratorRecord.[[Iterator]]).
+                let Some(next_method) = next_method else {
+                    return Err(throw_not_callable(agent, gc.into_nogc()));
+                };
+                let next_result = if from_sync {
+                    AsyncFromSyncIteratorPrototype::next(
+                        agent,
+                        IteratorRecord {
+                            iterator: iterator.unbind(),
+                            next_method: next_method.unbind(),
+                        },
+                        None,
+                        gc.reborrow(),
+                    )
+                    .unbind()
+                    .bind(gc.nogc())
+                    .into()
+                } else {
+                    call_function(
+                        agent,
+                        next_method.unbind(),
+                        iterator.unbind().into(),
+                        None,
+                        gc.reborrow(),
+                    )
+                    .unbind()?
+                    .bind(gc.nogc())
+                };
+                // 4. Set nextResult to ? Await(nextResult).
+                scoped_operation.get(agent).await_value(
+                    agent,
+                    next_result.unbind(),
+                    ArrayFromAsyncStep::NextResult,
+                    gc,
+                )
+            }
+            ArrayFromAsyncSource::ArrayLike { array_like, len } => {
+                // vii. Repeat, while k < len,
+                if k >= len {
+                    // viii. Perform ? Set(A, "length", 𝔽(len), true).
+                    // ix. Return A.
+                    return operation.unbind().finish(agent, gc);
+                }
+                // 1. Let Pk be ! ToString(𝔽(k)).
+                let pk = PropertyKey::from(SmallInteger::try_from(k).unwrap());
+                // 2. Let kValue be ? Get(arrayLike, Pk).
+                let k_value = get(agent, array_like.unbind(), pk, gc.reborrow())
+                    .unbind()?
+                    .bind(gc.nogc());
+                // 3. Set kValue to ? Await(kValue).
+                scoped_operation.get(agent).await_value(
+                    agent,
+                    k_value.unbind(),
+                    ArrayFromAsyncStep::Value,
+                    gc,
+                )
+            }
+        }
+    }
+
+    /// Continue the iterator loop with the awaited result of calling the
+    /// next method.
+    fn next_result(
+        self,
+        agent: &mut Agent,
+        result: Value,
+        mut gc: GcScope<'a, '_>,
+    ) -> JsResult<'a, ()> {
+        let operation = self.bind(gc.nogc());
+        let result = result.bind(gc.nogc());
+        // 5. If nextResult is not an Object, throw a TypeError exception.
+        let Ok(result) = Object::try_from(result) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Iterator result is not an object",
+                gc.into_nogc(),
+            ));
+        };
+        let scoped_operation = operation.scope(agent, gc.nogc());
+        let scoped_result = result.scope(agent, gc.nogc());
+        // 6. Let done be ? IteratorComplete(nextResult).
+        let done = iterator_complete(agent, result.unbind(), gc.reborrow()).unbind()?;
+        // 7. If done is true,
+        if done {
+            // a. Perform ? Set(A, "length", 𝔽(k), true).
+            // b. Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+            return scoped_operation.get(agent).finish(agent, gc);
+        }
+        // 8. Let nextValue be ? IteratorValue(nextResult).
+        // SAFETY: scoped_result is not shared.
+        let result = unsafe { scoped_result.take(agent) }.bind(gc.nogc());
+        let next_value = iterator_value(agent, result.unbind(), gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        scoped_operation
+            .get(agent)
+            .map_value(agent, next_value.unbind(), gc)
+    }
+
+    /// Apply mapfn, if present, to the kth value.
+    fn map_value(
+        self,
+        agent: &mut Agent,
+        value: Value,
+        mut gc: GcScope<'a, '_>,
+    ) -> JsResult<'a, ()> {
+        let operation = self.bind(gc.nogc());
+        let value = value.bind(gc.nogc());
+        let ArrayFromAsyncRecord {
+            mapper,
+            this_arg,
+            k,
+            ..
+        } = *operation.get(agent);
+        // 10. Else, let mappedValue be nextValue.
+        let Some(mapper) = mapper else {
+            return operation.unbind().define_value(agent, value.unbind(), gc);
+        };
+        let scoped_operation = operation.scope(agent, gc.nogc());
+        let fk = Number::from(SmallInteger::try_from(k).unwrap()).into();
+        // 9. If mapping is true, then
+        // a. Let mappedValue be Call(mapfn, thisArg, « nextValue, 𝔽(k) »).
+        let mapped_value = call_function(
+            agent,
+            mapper.unbind(),
+            this_arg.unbind(),
+            Some(ArgumentsList::from_mut_slice(&mut [value.unbind(), fk])),
+            gc.reborrow(),
+        )
+        .unbind()
+        .bind(gc.nogc());
+        // b. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+        let mapped_value = match mapped_value {
+            Ok(mapped_value) => mapped_value,
+            Err(err) => {
+                return scoped_operation.get(agent).close(agent, err.unbind(), gc);
+            }
+        };
+        // c. Set mappedValue to Await(mappedValue).
+        let result = scoped_operation
+            .get(agent)
+            .await_value(
+                agent,
+                mapped_value.unbind(),
+                ArrayFromAsyncStep::MappedValue,
+                gc.reborrow(),
+            )
+            .unbind()
+            .bind(gc.nogc());
+        // d. IfAbruptCloseAsyncIterator(mappedValue, iteratorRecord).
+        match result {
+            Ok(()) => Ok(()),
+            Err(err) => scoped_operation.get(agent).close(agent, err.unbind(), gc),
+        }
+    }
+
+    /// Define the kth element of A and continue with the next iteration.
+    fn define_value(
+        self,
+        agent: &mut Agent,
+        value: Value,
+        mut gc: GcScope<'a, '_>,
+    ) -> JsResult<'a, ()> {
+        let operation = self.bind(gc.nogc());
+        let value = value.bind(gc.nogc());
+        let ArrayFromAsyncRecord { target, k, .. } = *operation.get(agent);
+        let scoped_operation = operation.scope(agent, gc.nogc());
+        // 2. Let Pk be ! ToString(𝔽(k)).
+        let pk = PropertyKey::from(SmallInteger::try_from(k).unwrap());
+        // 11. Let defineStatus be Completion(CreateDataPropertyOrThrow(A, Pk, mappedValue)).
+        let define_status = create_data_property_or_throw(
+            agent,
+            target.unbind(),
+            pk,
+            value.unbind(),
+            gc.reborrow(),
+        )
+        .unbind()
+        .bind(gc.nogc());
+        let operation = scoped_operation.get(agent).bind(gc.nogc());
+        // 12. If defineStatus is an abrupt completion, return ? AsyncIteratorClose(iteratorRecord, defineStatus).
+        if let Err(err) = define_status {
+            return operation.unbind().close(agent, err.unbind(), gc);
+        }
+        // 13. Set k to k + 1.
+        operation.get_mut(agent).k += 1;
+        operation.unbind().run(agent, gc)
+    }
+
+    /// Set the length of A and resolve the promise with it.
+    fn finish(self, agent: &mut Agent, mut gc: GcScope<'a, '_>) -> JsResult<'a, ()> {
+        let operation = self.bind(gc.nogc());
+        let ArrayFromAsyncRecord { target, k, .. } = *operation.get(agent);
+        let scoped_operation = operation.scope(agent, gc.nogc());
+        // Perform ? Set(A, "length", 𝔽(k), true).
+        set(
+            agent,
+            target.unbind(),
+            BUILTIN_STRING_MEMORY.length.into(),
+            Number::from(SmallInteger::try_from(k).unwrap()).into(),
+            true,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // Return Completion Record { [[Type]]: return, [[Value]]: A, [[Target]]: empty }.
+        let ArrayFromAsyncRecord {
+            promise, target, ..
+        } = *scoped_operation.get(agent).get(agent);
+        PromiseCapability::from_promise(promise, true).resolve(agent, target.into(), gc);
+        Ok(())
+    }
+
+    /// ### [7.4.13 AsyncIteratorClose ( iteratorRecord, completion )](https://tc39.es/ecma262/#sec-asynciteratorclose)
+    ///
+    /// Performed with completion being a throw completion of the given error.
+    /// An array-like has no iterator to close and the error is returned as
+    /// is.
+    fn close(self, agent: &mut Agent, error: JsError, mut gc: GcScope<'a, '_>) -> JsResult<'a, ()> {
+        let operation = self.bind(gc.nogc());
+        let error = error.bind(gc.nogc());
+        let ArrayFromAsyncSource::Iterator {
+            iterator,
+            from_sync,
+            ..
+        } = operation.get(agent).source
+        else {
+            return Err(error.unbind());
+        };
+        let iterator = iterator.bind(gc.nogc());
+        let scoped_operation = operation.scope(agent, gc.nogc());
+        let scoped_error = error.value().scope(agent, gc.nogc());
+        let inner_result = if from_sync {
+            // 4. Let innerResult be Completion(GetMethod(iterator, "return")).
+            // 5. If innerResult is a normal completion, then
+            // c. Set innerResult to Completion(Call(return, iterator)).
+            // NOTE: %AsyncFromSyncIteratorPrototype%.return always exists
+            // and never throws.
+            Some(
+                AsyncFromSyncIteratorPrototype::r#return(
+                    agent,
+                    iterator.unbind(),
+                    None,
+                    gc.reborrow(),
+                )
+                .unbind()
+                .bind(gc.nogc())
+                .into(),
+            )
+        } else {
+            let scoped_iterator = iterator.scope(agent, gc.nogc());
+            // 4. Let innerResult be Completion(GetMethod(iterator, "return")).
+            let r#return = get_object_method(
+                agent,
+                iterator.unbind(),
+                BUILTIN_STRING_MEMORY.r#return.to_property_key(),
+                gc.reborrow(),
+            )
+            .unbind()
+            .bind(gc.nogc());
+            match r#return {
+                // 5. If innerResult is a normal completion, then
+                // a. Let return be innerResult.[[Value]].
+                // b. If return is undefined, return ? completion.
+                Ok(None) | Err(_) => None,
+                Ok(Some(r#return)) => {
+                    // c. Set innerResult to Completion(Call(return, iterator)).
+                    call_function(
+                        agent,
+                        r#return.unbind(),
+                        scoped_iterator.get(agent).into(),
+                        None,
+                        gc.reborrow(),
+                    )
+                    .unbind()
+                    .bind(gc.nogc())
+                    .ok()
+                }
+            }
+        };
+        // d. If innerResult is a normal completion, set innerResult to
+        //    Completion(Await(innerResult.[[Value]])).
+        if let Some(inner_result) = inner_result {
+            let error = scoped_error.get(agent).bind(gc.nogc());
+            let result = scoped_operation
+                .get(agent)
+                .await_value(
+                    agent,
+                    inner_result.unbind(),
+                    ArrayFromAsyncStep::Close(error.unbind()),
+                    gc.reborrow(),
+                )
+                .unbind()
+                .bind(gc.nogc());
+            if result.is_ok() {
+                return Ok(());
+            }
+        }
+        // 6. If completion is a throw completion, return ? completion.
+        // SAFETY: scoped_error is not shared.
+        let error = unsafe { scoped_error.take(agent) };
+        Err(JsError::new(error).bind(gc.into_nogc()))
+    }
+
+    /// ### [6.2.3.1 Await ( value )](https://tc39.es/ecma262/#await)
+    ///
+    /// Suspend the operation on the given step until value settles.
+    fn await_value(
+        self,
+        agent: &mut Agent,
+        value: Value,
+        step: ArrayFromAsyncStep,
+        mut gc: GcScope<'a, '_>,
+    ) -> JsResult<'a, ()> {
+        let operation = self.bind(gc.nogc());
+        let value = value.bind(gc.nogc());
+        operation.get_mut(agent).step = step.unbind();
+        let scoped_operation = operation.scope(agent, gc.nogc());
+        // 2. Let promise be ? PromiseResolve(%Promise%, value).
+        let promise = Promise::resolve(agent, value.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let promise = promise.bind(gc);
+        // SAFETY: scoped_operation is not shared.
+        let operation = unsafe { scoped_operation.take(agent) }.bind(gc);
+        // 3-7. Perform PerformPromiseThen(promise, onFulfilled, onRejected).
+        inner_promise_then(
+            agent,
+            promise,
+            PromiseReactionHandler::ArrayFromAsync(operation),
+            PromiseReactionHandler::ArrayFromAsync(operation),
+            None,
+            gc,
+        );
+        Ok(())
+    }
+
+    fn reject(self, agent: &mut Agent, error: Value, gc: NoGcScope) {
+        let promise = self.get(agent).promise.bind(gc);
+        PromiseCapability::from_promise(promise, true).reject(agent, error, gc);
+    }
+}
+
+impl HeapMarkAndSweep for ArrayFromAsyncSource<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match self {
+            Self::Iterator {
+                iterator,
+                next_method,
+                from_sync: _,
+            } => {
+                iterator.mark_values(queues);
+                next_method.mark_values(queues);
+            }
+            Self::ArrayLike { array_like, len: _ } => array_like.mark_values(queues),
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match self {
+            Self::Iterator {
+                iterator,
+                next_method,
+                from_sync: _,
+            } => {
+                iterator.sweep_values(compactions);
+                next_method.sweep_values(compactions);
+            }
+            Self::ArrayLike { array_like, len: _ } => array_like.sweep_values(compactions),
+        }
+    }
+}
+
+impl HeapMarkAndSweep for ArrayFromAsyncStep<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match self {
+            Self::NextResult | Self::Value | Self::MappedValue => {}
+            Self::Close(error) => error.mark_values(queues),
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match self {
+            Self::NextResult | Self::Value | Self::MappedValue => {}
+            Self::Close(error) => error.sweep_values(compactions),
+        }
+    }
+}
+
+impl HeapMarkAndSweep for ArrayFromAsyncRecord<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            promise,
+            target,
+            mapper,
+            this_arg,
+            source,
+            k: _,
+            step,
+        } = self;
+        promise.mark_values(queues);
+        target.mark_values(queues);
+        mapper.mark_values(queues);
+        this_arg.mark_values(queues);
+        source.mark_values(queues);
+        step.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            promise,
+            target,
+            mapper,
+            this_arg,
+            source,
+            k: _,
+            step,
+        } = self;
+        promise.sweep_values(compactions);
+        target.sweep_values(compactions);
+        mapper.sweep_values(compactions);
+        this_arg.sweep_values(compactions);
+        source.sweep_values(compactions);
+        step.sweep_values(compactions);
+    }
+}
+
+impl HeapMarkAndSweep for ArrayFromAsync<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.array_from_async_records.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions
+            .array_from_async_records
+            .shift_index(&mut self.0);
+    }
+}
+
+bindable_handle!(ArrayFromAsyncSource);
+bindable_handle!(ArrayFromAsyncStep);
+bindable_handle!(ArrayFromAsyncRecord);
+
+impl<'a> CreateHeapData<ArrayFromAsyncRecord<'a>, ArrayFromAsync<'a>> for Heap {
+    fn create(&mut self, data: ArrayFromAsyncRecord<'a>) -> ArrayFromAsync<'a> {
+        self.array_from_async_records.push(data.unbind());
+        self.alloc_counter += core::mem::size_of::<ArrayFromAsyncRecord<'static>>();
+        ArrayFromAsync(BaseIndex::last(&self.array_from_async_records))
+    }
+}
@@ -125,8 +125,7 @@ impl ArrayIteratorPrototype {
                 let fast_path_result = match array {
                     Object::Array(array) => {
                         assert!(ARRAY_INDEX_RANGE.contains(&index));
-                        let idx = usize::try_from(index).unwrap();
-                        array.as_slice(agent)[idx]
+                        array.get_data_value(agent, u32::try_from(index).unwrap())
                     }
                     _ => None,
                 };
@@ -149,8 +148,7 @@ impl ArrayIteratorPrototype {
                 let fast_path_result = match array {
                     Object::Array(array) if array.get_backing_object(agent).is_none() => {
                         assert!(ARRAY_INDEX_RANGE.contains(&index));
-                        let idx = usize::try_from(index).unwrap();
-                        array.as_slice(agent)[idx]
+                        array.get_data_value(agent, u32::try_from(index).unwrap())
                     }
                     _ => None,
                 };
@@ -366,13 +366,19 @@ impl ArrayPrototype {
             .bind(gc.nogc());
 
         // Optimisation: Reserve space for all Arrays being concatenated.
+        // Sparse Arrays are skipped as their length says little about the
+        // number of elements in them.
         if let Object::Array(a) = a {
             let mut total_len = 0u32;
-            if let Object::Array(this_value) = scoped_o.get(agent) {
+            if let Object::Array(this_value) = scoped_o.get(agent)
+                && !this_value.is_sparse(agent)
+            {
                 total_len = total_len.saturating_add(this_value.len(agent));
             }
             items.iter().for_each(|item| {
-                if let Value::Array(item) = item.get(agent) {
+                if let Value::Array(item) = item.get(agent)
+                    && !item.is_sparse(agent)
+                {
                     total_len = item.len(agent);
                 }
             });
@@ -816,7 +822,8 @@ impl ArrayPrototype {
                 }
             }
             // d. Set k to k + 1.
-            k += 1;
+            // Note: holes that HasProperty cannot observe are skipped.
+            k = skip_holes(agent, o.get(agent), k + 1, len);
         }
         // 6. Return true.
         Ok(true.into())
@@ -1073,7 +1080,8 @@ impl ArrayPrototype {
                 }
             }
             // d. Set k to k + 1.
-            k += 1;
+            // Note: holes that HasProperty cannot observe are skipped.
+            k = skip_holes(agent, o.get(agent), k + 1, len);
         }
         // 8. Return A.
         Ok(a.get(agent).into())
@@ -1402,7 +1410,8 @@ impl ArrayPrototype {
                 .unbind()?;
             }
             // d. Set k to k + 1.
-            k += 1;
+            // Note: holes that HasProperty cannot observe are skipped.
+            k = skip_holes(agent, o.get(agent), k + 1, len);
         }
         // 6. Return undefined.
         Ok(Value::Undefined)
@@ -1449,6 +1458,7 @@ impl ArrayPrototype {
         let from_index = arguments.get(1).bind(nogc);
         if let (Value::Array(array), Value::Undefined | Value::Integer(_)) =
             (this_value, from_index)
+            && !array.is_sparse(agent)
         {
             let len = array.len(agent);
             if len == 0 {
@@ -1581,6 +1591,7 @@ impl ArrayPrototype {
         let from_index = arguments.get(1).bind(nogc);
         if let (Value::Array(array), Value::Undefined | Value::Integer(_)) =
             (this_value, from_index)
+            && !array.is_sparse(agent)
         {
             let len = array.len(agent);
             if len == 0 {
@@ -1680,7 +1691,8 @@ impl ArrayPrototype {
                 }
             }
             // d. Set k to k + 1.
-            k += 1;
+            // Note: holes that HasProperty cannot observe are skipped.
+            k = skip_holes(agent, o.get(agent), k + 1, len);
         }
         // 11. Return -1𝔽.
         Ok((-1).into())
@@ -1825,6 +1837,7 @@ impl ArrayPrototype {
         };
         if let (Value::Array(array), None | Some(Value::Undefined) | Some(Value::Integer(_))) =
             (this_value, from_index)
+            && !array.is_sparse(agent)
         {
             let len = array.len(agent);
             if len == 0 {
@@ -1914,7 +1927,8 @@ impl ArrayPrototype {
                 }
             }
             // d. Set k to k - 1.
-            k -= 1;
+            // Note: holes that HasProperty cannot observe are skipped.
+            k = skip_holes_backward(agent, o.get(agent), k - 1);
         }
         // 9. Return -1𝔽.
         Ok((-1).into())
@@ -2022,7 +2036,8 @@ impl ArrayPrototype {
                 .unbind()?;
             }
             // d. Set k to k + 1.
-            k += 1;
+            // Note: holes that HasProperty cannot observe are skipped.
+            k = skip_holes(agent, o.get(agent), k + 1, len);
         }
         // 7. Return A.
         Ok(a.get(agent).into())
@@ -2316,7 +2331,8 @@ impl ArrayPrototype {
                 }
 
                 // iv. Set k to k + 1.
-                k += 1;
+                // Note: holes that HasProperty cannot observe are skipped.
+                k = skip_holes(agent, o.get(agent), k + 1, len);
             }
 
             // c. If kPresent is false, throw a TypeError exception.
@@ -2365,7 +2381,8 @@ impl ArrayPrototype {
             }
 
             // d. Set k to k + 1.
-            k += 1;
+            // Note: holes that HasProperty cannot observe are skipped.
+            k = skip_holes(agent, o.get(agent), k + 1, len);
         }
 
         // 10. Return accumulator.
@@ -2483,7 +2500,8 @@ impl ArrayPrototype {
                 }
 
                 // iv. Set k to k - 1.
-                k -= 1;
+                // Note: holes that HasProperty cannot observe are skipped.
+                k = skip_holes_backward(agent, o.get(agent), k - 1);
             }
 
             // c. If kPresent is false, throw a TypeError exception.
@@ -2531,7 +2549,8 @@ impl ArrayPrototype {
             }
 
             // d. Set k to k - 1.
-            k -= 1;
+            // Note: holes that HasProperty cannot observe are skipped.
+            k = skip_holes_backward(agent, o.get(agent), k - 1);
         }
 
         // 10. Return accumulator.
@@ -3134,7 +3153,8 @@ impl ArrayPrototype {
                 }
             }
             // d. Set k to k + 1.
-            k += 1;
+            // Note: holes that HasProperty cannot observe are skipped.
+            k = skip_holes(agent, o.get(agent), k + 1, len);
         }
         // 6. Return false.
         Ok(false.into())
@@ -3221,11 +3241,14 @@ impl ArrayPrototype {
         // of holes that were detected and excluded from the sort.
 
         // 10. Repeat, while j < len,
-        for j in item_count..len {
+        let mut j = item_count;
+        while j < len {
             // a. Perform ? DeletePropertyOrThrow(obj, ! ToString(𝔽(j))).
             delete_property_or_throw(agent, obj.get(agent), j.try_into().unwrap(), gc.reborrow())
                 .unbind()?;
             // b. Set j to j + 1.
+            // Note: deleting a hole does nothing, so holes are skipped.
+            j = skip_holes(agent, obj.get(agent), j as i64 + 1, len as i64) as usize;
         }
         // 11. Return obj.
         Ok(obj.get(agent).into())
@@ -4409,13 +4432,26 @@ fn flatten_into_array<'a>(
     this_arg: Option<Scoped<Value>>,
     mut gc: GcScope<'a, '_>,
 ) -> JsResult<'a, usize> {
+    agent.check_native_stack(gc.nogc()).unbind()?;
     // 1. Assert: If mapperFunction is present, then IsCallable(mapperFunction) is true, thisArg is present, and depth is 1.
     assert!(mapper_function.is_none() || this_arg.is_some() && depth == Some(1));
     // 2. Let targetIndex be start.
     let mut target_index = start;
     // 3. Let sourceIndex be +0𝔽.
+    let mut next_source_index = 0;
     // 4. Repeat, while ℝ(sourceIndex) < sourceLen,
-    for source_index in 0..source_len {
+    loop {
+        // Note: holes that HasProperty cannot observe are skipped.
+        let source_index = skip_holes(
+            agent,
+            source.get(agent),
+            next_source_index as i64,
+            source_len as i64,
+        ) as usize;
+        if source_index >= source_len {
+            break;
+        }
+        next_source_index = source_index + 1;
         // a. Let P be ! ToString(sourceIndex).
         let source_index_number = Number::try_from(source_index).unwrap();
         let p = PropertyKey::try_from(source_index).unwrap();
@@ -4505,6 +4541,40 @@ fn flatten_into_array<'a>(
     Ok(target_index)
 }
 
+/// Returns the index of the first element present in `o` at or after `k`, or
+/// `len` if there is none, if `o` is a sparse Array whose holes HasProperty
+/// cannot observe. Otherwise returns `k`.
+///
+/// Loops that skip absent elements use this to step over the holes of sparse
+/// Arrays, taking time proportional to the number of elements present rather
+/// than to the length of the Array.
+fn skip_holes(agent: &Agent, o: Object, k: i64, len: i64) -> i64 {
+    if let Object::Array(array) = o
+        && let Ok(index) = u32::try_from(k)
+        && let Some(sparse) = array.get_sparse_with_unobservable_holes(agent)
+    {
+        sparse
+            .next_index(index)
+            .map_or(len, |index| i64::from(index).min(len))
+    } else {
+        k
+    }
+}
+
+/// Returns the index of the last element present in `o` at or before `k`, or
+/// -1 if there is none, if `o` is a sparse Array whose holes HasProperty
+/// cannot observe. Otherwise returns `k`.
+fn skip_holes_backward(agent: &Agent, o: Object, k: i64) -> i64 {
+    if let Object::Array(array) = o
+        && let Ok(index) = u32::try_from(k)
+        && let Some(sparse) = array.get_sparse_with_unobservable_holes(agent)
+    {
+        sparse.previous_index(index).map_or(-1, i64::from)
+    } else {
+        k
+    }
+}
+
 /// ### [23.1.3.30.1 SortIndexedProperties ( obj, len, SortCompare, holes )](https://tc39.es/ecma262/#sec-sortindexedproperties)
 ///
 /// The abstract operation SortIndexedProperties takes arguments obj (an
@@ -4567,7 +4637,15 @@ fn sort_indexed_properties<'gc, 'scope, const SKIP_HOLES: bool>(
 ) -> JsResult<'gc, Vec<Value<'gc>>> {
     let obj = obj.scope(agent, gc.nogc());
     // 1. Let items be a new empty List.
-    let mut items = Vec::with_capacity(len);
+    let capacity = match obj.get(agent) {
+        // Note: only the elements present in a sparse Array are read when
+        // skipping holes.
+        Object::Array(array) if SKIP_HOLES && array.is_sparse(agent) => {
+            array.get_elements(agent).get_sparse(agent).count() as usize
+        }
+        _ => len,
+    };
+    let mut items = Vec::with_capacity(capacity);
     // 2. Let k be 0.
     let mut k = 0;
     // 3. Repeat, while k < len,
@@ -4595,6 +4673,10 @@ fn sort_indexed_properties<'gc, 'scope, const SKIP_HOLES: bool>(
         }
         // e. Set k to k + 1.
         k += 1;
+        if SKIP_HOLES {
+            // Note: holes that HasProperty cannot observe are skipped.
+            k = skip_holes(agent, obj.get(agent), k as i64, len as i64) as usize;
+        }
     }
     // 4. Sort items using an implementation-defined sequence of calls to
     // SortCompare. If any such call returns an abrupt completion, stop before
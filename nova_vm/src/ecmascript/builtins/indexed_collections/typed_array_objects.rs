@@ -5,7 +5,9 @@
 mod abstract_operations;
 mod typed_array_constructors;
 mod typed_array_intrinsic_object;
+mod uint8_array_base64;
 
 pub(crate) use abstract_operations::*;
 pub(crate) use typed_array_constructors::*;
 pub(crate) use typed_array_intrinsic_object::*;
+pub(crate) use uint8_array_base64::*;
@@ -10,11 +10,15 @@ use crate::{
         Behaviour, Builtin, BuiltinIntrinsicConstructor, ExceptionType, Function, JsResult, Object,
         PropertyKey, Realm, String, TypedArrayAbstractOperations, U8Clamped, Value, Viewable,
         allocate_typed_array, builders::BuiltinFunctionBuilder, builders::OrdinaryObjectBuilder,
-        get_iterator_from_method, get_method, get_prototype_from_constructor,
+        encode_base64, encode_hex, from_base64, from_hex, get, get_alphabet_option,
+        get_iterator_from_method, get_last_chunk_handling_option, get_method, get_options_object,
+        get_prototype_from_constructor, get_uint8_array_bytes,
         initialize_typed_array_from_array_buffer, initialize_typed_array_from_array_like,
         initialize_typed_array_from_list, iterator_to_list,
-        make_typed_array_with_buffer_witness_record, throw_not_callable, to_index,
+        make_typed_array_with_buffer_witness_record, read_written_result_object,
+        set_uint8_array_bytes, throw_not_callable, to_boolean, to_index,
         try_get_prototype_from_constructor, try_result_into_js, try_to_index, typed_array_create,
+        uint8_array_from_bytes, validate_uint8_array,
     },
     engine::{Bindable, GcScope, Scopable},
     heap::{IntrinsicConstructorIndexes, WellKnownSymbols},
@@ -170,6 +174,24 @@ impl BuiltinIntrinsicConstructor for Float64ArrayConstructor {
     const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::Float64Array;
 }
 
+struct Uint8ArrayFromBase64;
+impl Builtin for Uint8ArrayFromBase64 {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.fromBase64;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TypedArrayConstructors::uint8_array_from_base64);
+}
+struct Uint8ArrayFromHex;
+impl Builtin for Uint8ArrayFromHex {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.fromHex;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TypedArrayConstructors::uint8_array_from_hex);
+}
+
 impl TypedArrayConstructors {
     fn int8_array_constructor<'gc>(
         agent: &mut Agent,
@@ -292,6 +314,91 @@ impl TypedArrayConstructors {
         typed_array_constructor::<f64>(agent, arguments, new_target, gc)
     }
 
+    /// ### [23.3.1.1 Uint8Array.fromBase64 ( string \[ , options \] )](https://tc39.es/ecma262/#sec-uint8array.frombase64)
+    fn uint8_array_from_base64<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let string = arguments.get(0).bind(gc.nogc());
+        let options = arguments.get(1).bind(gc.nogc());
+        // 1. If string is not a String, throw a TypeError exception.
+        let Ok(string) = String::try_from(string) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Expected string to be a String",
+                gc.into_nogc(),
+            ));
+        };
+        let string = string.scope(agent, gc.nogc());
+        // 2. Let opts be ? GetOptionsObject(options).
+        let opts = get_options_object(agent, options, gc.nogc())
+            .unbind()?
+            .map(|opts| opts.scope(agent, gc.nogc()));
+        // 3. Let alphabet be ? Get(opts, "alphabet").
+        // 4. If alphabet is undefined, set alphabet to "base64".
+        // 5. If alphabet is neither "base64" nor "base64url", throw a
+        //    TypeError exception.
+        let options = opts.as_ref().map(|opts| opts.get(agent));
+        let alphabet = get_alphabet_option(agent, options, gc.reborrow()).unbind()?;
+        // 6. Let lastChunkHandling be ? Get(opts, "lastChunkHandling").
+        // 7. If lastChunkHandling is undefined, set lastChunkHandling to
+        //    "loose".
+        // 8. If lastChunkHandling is not one of "loose", "strict", or
+        //    "stop-before-partial", throw a TypeError exception.
+        let options = opts.as_ref().map(|opts| opts.get(agent));
+        let last_chunk_handling =
+            get_last_chunk_handling_option(agent, options, gc.reborrow()).unbind()?;
+        // 9. Let result be FromBase64(string, alphabet, lastChunkHandling).
+        let result = from_base64(
+            string.get(agent).as_bytes(agent),
+            alphabet,
+            last_chunk_handling,
+            usize::MAX,
+        );
+        // 10. If result.[[Error]] is not none, then
+        if let Some(error) = result.error {
+            // a. Throw result.[[Error]].
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::SyntaxError,
+                error,
+                gc.into_nogc(),
+            ));
+        }
+        uint8_array_from_bytes(agent, &result.bytes, gc)
+    }
+
+    /// ### [23.3.1.2 Uint8Array.fromHex ( string )](https://tc39.es/ecma262/#sec-uint8array.fromhex)
+    fn uint8_array_from_hex<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let string = arguments.get(0).bind(gc.nogc());
+        // 1. If string is not a String, throw a TypeError exception.
+        let Ok(string) = String::try_from(string) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Expected string to be a String",
+                gc.into_nogc(),
+            ));
+        };
+        // 2. Let result be FromHex(string).
+        let result = from_hex(string.as_bytes(agent), string.utf16_len(agent), usize::MAX);
+        // 3. If result.[[Error]] is not none, then
+        if let Some(error) = result.error {
+            // a. Throw result.[[Error]].
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::SyntaxError,
+                error,
+                gc.into_nogc(),
+            ));
+        }
+        uint8_array_from_bytes(agent, &result.bytes, gc)
+    }
+
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
         let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
         let typed_array_constructor = intrinsics.typed_array();
@@ -325,7 +432,7 @@ impl TypedArrayConstructors {
             .build();
 
         BuiltinFunctionBuilder::new_intrinsic_constructor::<Uint8ArrayConstructor>(agent, realm)
-            .with_property_capacity(2)
+            .with_property_capacity(4)
             .with_prototype(typed_array_constructor)
             .with_property(|builder| {
                 builder
@@ -335,6 +442,8 @@ impl TypedArrayConstructors {
                     .with_configurable(false)
                     .build()
             })
+            .with_builtin_function_property::<Uint8ArrayFromBase64>()
+            .with_builtin_function_property::<Uint8ArrayFromHex>()
             .with_prototype_property(uint8_array_prototype.into())
             .build();
 
@@ -485,8 +594,263 @@ impl TypedArrayConstructors {
     }
 }
 
+struct Uint8ArrayPrototypeSetFromBase64;
+impl Builtin for Uint8ArrayPrototypeSetFromBase64 {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.setFromBase64;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TypedArrayPrototypes::set_from_base64);
+}
+struct Uint8ArrayPrototypeSetFromHex;
+impl Builtin for Uint8ArrayPrototypeSetFromHex {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.setFromHex;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TypedArrayPrototypes::set_from_hex);
+}
+struct Uint8ArrayPrototypeToBase64;
+impl Builtin for Uint8ArrayPrototypeToBase64 {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toBase64;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TypedArrayPrototypes::to_base64);
+}
+struct Uint8ArrayPrototypeToHex;
+impl Builtin for Uint8ArrayPrototypeToHex {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toHex;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TypedArrayPrototypes::to_hex);
+}
+
 pub(crate) struct TypedArrayPrototypes;
 impl TypedArrayPrototypes {
+    /// ### [23.3.2.1 Uint8Array.prototype.setFromBase64 ( string \[ , options \] )](https://tc39.es/ecma262/#sec-uint8array.prototype.setfrombase64)
+    fn set_from_base64<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let string = arguments.get(0).bind(gc.nogc());
+        let options = arguments.get(1).bind(gc.nogc());
+        // 1. Let into be the this value.
+        // 2. Perform ? ValidateUint8Array(into).
+        let into = validate_uint8_array(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. If string is not a String, throw a TypeError exception.
+        let Ok(string) = String::try_from(string) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Expected string to be a String",
+                gc.into_nogc(),
+            ));
+        };
+        let into = into.scope(agent, gc.nogc());
+        let string = string.scope(agent, gc.nogc());
+        // 4. Let opts be ? GetOptionsObject(options).
+        let opts = get_options_object(agent, options, gc.nogc())
+            .unbind()?
+            .map(|opts| opts.scope(agent, gc.nogc()));
+        // 5. Let alphabet be ? Get(opts, "alphabet").
+        // 6. If alphabet is undefined, set alphabet to "base64".
+        // 7. If alphabet is neither "base64" nor "base64url", throw a
+        //    TypeError exception.
+        let options = opts.as_ref().map(|opts| opts.get(agent));
+        let alphabet = get_alphabet_option(agent, options, gc.reborrow()).unbind()?;
+        // 8. Let lastChunkHandling be ? Get(opts, "lastChunkHandling").
+        // 9. If lastChunkHandling is undefined, set lastChunkHandling to
+        //    "loose".
+        // 10. If lastChunkHandling is not one of "loose", "strict", or
+        //     "stop-before-partial", throw a TypeError exception.
+        let options = opts.as_ref().map(|opts| opts.get(agent));
+        let last_chunk_handling =
+            get_last_chunk_handling_option(agent, options, gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        // SAFETY: not shared.
+        let into = unsafe { into.take(agent) }.bind(gc);
+        // 11. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(into, seq-cst).
+        let ta_record = make_typed_array_with_buffer_witness_record(agent, into, Ordering::SeqCst);
+        // 12. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError
+        //     exception.
+        if ta_record.is_typed_array_out_of_bounds(agent) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "TypedArray out of bounds",
+                gc,
+            ));
+        }
+        // 13. Let byteLength be TypedArrayLength(taRecord).
+        let byte_length = ta_record.typed_array_length(agent);
+        // 14. Let result be FromBase64(string, alphabet, lastChunkHandling,
+        //     byteLength).
+        let result = from_base64(
+            string.get(agent).as_bytes(agent),
+            alphabet,
+            last_chunk_handling,
+            byte_length,
+        );
+        // 15. Let bytes be result.[[Bytes]].
+        // 16. Let written be the length of bytes.
+        // 17. NOTE: FromBase64 does not invoke any user code, so the
+        //     ArrayBuffer backing into cannot have been detached or shrunk.
+        // 18. Assert: written ≤ byteLength.
+        debug_assert!(result.bytes.len() <= byte_length);
+        // 19. Perform SetUint8ArrayBytes(into, bytes).
+        set_uint8_array_bytes(agent, into, &result.bytes);
+        // 20. If result.[[Error]] is not none, then
+        if let Some(error) = result.error {
+            // a. Throw result.[[Error]].
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::SyntaxError,
+                error,
+                gc,
+            ));
+        }
+        Ok(read_written_result_object(agent, result.read, result.bytes.len(), gc).into())
+    }
+
+    /// ### [23.3.2.2 Uint8Array.prototype.setFromHex ( string )](https://tc39.es/ecma262/#sec-uint8array.prototype.setfromhex)
+    fn set_from_hex<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let string = arguments.get(0).bind(gc);
+        // 1. Let into be the this value.
+        // 2. Perform ? ValidateUint8Array(into).
+        let into = validate_uint8_array(agent, this_value, gc)?;
+        // 3. If string is not a String, throw a TypeError exception.
+        let Ok(string) = String::try_from(string) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Expected string to be a String",
+                gc,
+            ));
+        };
+        // 4. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(into, seq-cst).
+        let ta_record = make_typed_array_with_buffer_witness_record(agent, into, Ordering::SeqCst);
+        // 5. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError
+        //    exception.
+        if ta_record.is_typed_array_out_of_bounds(agent) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "TypedArray out of bounds",
+                gc,
+            ));
+        }
+        // 6. Let byteLength be TypedArrayLength(taRecord).
+        let byte_length = ta_record.typed_array_length(agent);
+        // 7. Let result be FromHex(string, byteLength).
+        let result = from_hex(string.as_bytes(agent), string.utf16_len(agent), byte_length);
+        // 8. Let bytes be result.[[Bytes]].
+        // 9. Let written be the length of bytes.
+        // 10. NOTE: FromHex does not invoke any user code, so the ArrayBuffer
+        //     backing into cannot have been detached or shrunk.
+        // 11. Assert: written ≤ byteLength.
+        debug_assert!(result.bytes.len() <= byte_length);
+        // 12. Perform SetUint8ArrayBytes(into, bytes).
+        set_uint8_array_bytes(agent, into, &result.bytes);
+        // 13. If result.[[Error]] is not none, then
+        if let Some(error) = result.error {
+            // a. Throw result.[[Error]].
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::SyntaxError,
+                error,
+                gc,
+            ));
+        }
+        Ok(read_written_result_object(agent, result.read, result.bytes.len(), gc).into())
+    }
+
+    /// ### [23.3.2.3 Uint8Array.prototype.toBase64 ( \[ options \] )](https://tc39.es/ecma262/#sec-uint8array.prototype.tobase64)
+    fn to_base64<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let options = arguments.get(0).bind(gc.nogc());
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateUint8Array(O).
+        let o = validate_uint8_array(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. Let opts be ? GetOptionsObject(options).
+        let opts = get_options_object(agent, options, gc.nogc())
+            .unbind()?
+            .map(|opts| opts.scope(agent, gc.nogc()));
+        // 4. Let alphabet be ? Get(opts, "alphabet").
+        // 5. If alphabet is undefined, set alphabet to "base64".
+        // 6. If alphabet is neither "base64" nor "base64url", throw a
+        //    TypeError exception.
+        let options = opts.as_ref().map(|opts| opts.get(agent));
+        let alphabet = get_alphabet_option(agent, options, gc.reborrow()).unbind()?;
+        // 7. Let omitPadding be ToBoolean(? Get(opts, "omitPadding")).
+        let omit_padding = if let Some(opts) = opts {
+            let omit_padding = get(
+                agent,
+                opts.get(agent),
+                BUILTIN_STRING_MEMORY.omitPadding.into(),
+                gc.reborrow(),
+            )
+            .unbind()?;
+            to_boolean(agent, omit_padding)
+        } else {
+            false
+        };
+        let gc = gc.into_nogc();
+        // SAFETY: not shared.
+        let o = unsafe { o.take(agent) }.bind(gc);
+        // 8. Let toEncode be ? GetUint8ArrayBytes(O).
+        // 9. If alphabet is "base64", then
+        //    a. Let outAscii be the sequence of code points which results from
+        //       encoding toEncode according to the base64 encoding specified
+        //       in section 4 of RFC 4648. Padding is included if and only if
+        //       omitPadding is false.
+        // 10. Else,
+        //     a. Assert: alphabet is "base64url".
+        //     b. Let outAscii be the sequence of code points which results
+        //        from encoding toEncode according to the base64url encoding
+        //        specified in section 5 of RFC 4648. Padding is included if
+        //        and only if omitPadding is false.
+        let out_ascii = encode_base64(
+            &get_uint8_array_bytes(agent, o, gc)?,
+            alphabet,
+            omit_padding,
+        );
+        // 11. Return CodePointsToString(outAscii).
+        Ok(String::from_string(agent, out_ascii, gc).into())
+    }
+
+    /// ### [23.3.2.4 Uint8Array.prototype.toHex ( )](https://tc39.es/ecma262/#sec-uint8array.prototype.tohex)
+    fn to_hex<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let O be the this value.
+        // 2. Perform ? ValidateUint8Array(O).
+        let o = validate_uint8_array(agent, this_value, gc)?;
+        // 3. Let toEncode be ? GetUint8ArrayBytes(O).
+        // 4. Let out be the empty String.
+        // 5. For each byte byte of toEncode, do
+        //    a. Let hex be Number::toString(𝔽(byte), 16).
+        //    b. Set hex to StringPad(hex, 2, "0", start).
+        //    c. Set out to the string-concatenation of out and hex.
+        let out = encode_hex(&get_uint8_array_bytes(agent, o, gc)?);
+        // 6. Return out.
+        Ok(String::from_string(agent, out, gc).into())
+    }
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
         let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
         let typed_array_prototype = intrinsics.typed_array_prototype();
@@ -533,7 +897,7 @@ impl TypedArrayPrototypes {
             .build();
 
         OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, uint8_array_prototype)
-            .with_property_capacity(2)
+            .with_property_capacity(6)
             .with_prototype(typed_array_prototype)
             .with_property(|builder| {
                 builder
@@ -544,6 +908,10 @@ impl TypedArrayPrototypes {
                     .build()
             })
             .with_constructor_property(uint8_array_constructor)
+            .with_builtin_function_property::<Uint8ArrayPrototypeSetFromBase64>()
+            .with_builtin_function_property::<Uint8ArrayPrototypeSetFromHex>()
+            .with_builtin_function_property::<Uint8ArrayPrototypeToBase64>()
+            .with_builtin_function_property::<Uint8ArrayPrototypeToHex>()
             .build();
 
         OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, uint8_clamped_array_prototype)
@@ -0,0 +1,646 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## [23.3 Uint8Array Objects](https://tc39.es/ecma262/#sec-uint8array-objects)
+//!
+//! Abstract operations for the base64 and hex conversions of Uint8Arrays.
+//! Decoding works on the WTF-8 bytes of the source String: any non-ASCII
+//! byte is outside both alphabets, so byte indexes and UTF-16 code unit
+//! indexes agree up to the point where decoding stops.
+
+use std::borrow::Cow;
+
+use ecmascript_atomics::Ordering;
+
+use crate::{
+    ecmascript::{
+        Agent, AnyTypedArray, BUILTIN_STRING_MEMORY, ExceptionType, JsResult, Number, Object,
+        OrdinaryObject, PropertyKey, String, Value, allocate_typed_array, get,
+        make_typed_array_with_buffer_witness_record, require_internal_slot_typed_array,
+    },
+    engine::{Bindable, GcScope, NoGcScope, trivially_bindable},
+    heap::ObjectEntry,
+};
+
+/// The `alphabet` option of the base64 methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Base64Alphabet {
+    Base64,
+    Base64Url,
+}
+trivially_bindable!(Base64Alphabet);
+
+/// The `lastChunkHandling` option of the base64 decoding methods.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum LastChunkHandling {
+    Loose,
+    Strict,
+    StopBeforePartial,
+}
+trivially_bindable!(LastChunkHandling);
+
+/// The Record returned by FromBase64 and FromHex.
+#[derive(Debug)]
+pub(crate) struct DecodeResult {
+    /// \[\[Read]]: the number of code units of the input that were consumed.
+    pub(crate) read: usize,
+    /// \[\[Bytes]]: the decoded bytes.
+    pub(crate) bytes: Vec<u8>,
+    /// \[\[Error]]: the message of the SyntaxError that stopped decoding, if
+    /// any.
+    pub(crate) error: Option<&'static str>,
+}
+
+impl DecodeResult {
+    fn ok(read: usize, bytes: Vec<u8>) -> Self {
+        Self {
+            read,
+            bytes,
+            error: None,
+        }
+    }
+
+    fn error(read: usize, bytes: Vec<u8>, error: &'static str) -> Self {
+        Self {
+            read,
+            bytes,
+            error: Some(error),
+        }
+    }
+}
+
+/// ### [23.3.3.1 ValidateUint8Array ( ta )](https://tc39.es/ecma262/#sec-validateuint8array)
+///
+/// The abstract operation ValidateUint8Array takes argument ta (an ECMAScript
+/// language value) and returns either a normal completion containing unused
+/// or a throw completion.
+pub(crate) fn validate_uint8_array<'a>(
+    agent: &mut Agent,
+    ta: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, AnyTypedArray<'a>> {
+    // 1. Perform ? RequireInternalSlot(ta, [[TypedArrayName]]).
+    let ta = require_internal_slot_typed_array(agent, ta, gc)?;
+    // 2. If ta.[[TypedArrayName]] is not "Uint8Array", throw a TypeError
+    //    exception.
+    match ta {
+        AnyTypedArray::Uint8Array(_) => Ok(ta),
+        #[cfg(feature = "shared-array-buffer")]
+        AnyTypedArray::SharedUint8Array(_) => Ok(ta),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Expected this to be Uint8Array",
+            gc,
+        )),
+    }
+}
+
+/// ### [23.3.3.2 GetUint8ArrayBytes ( ta )](https://tc39.es/ecma262/#sec-getuint8arraybytes)
+///
+/// The abstract operation GetUint8ArrayBytes takes argument ta (a Uint8Array)
+/// and returns either a normal completion containing a List of byte values or
+/// a throw completion. Non-shared arrays are read in place.
+pub(crate) fn get_uint8_array_bytes<'a, 'gc>(
+    agent: &'a mut Agent,
+    ta: AnyTypedArray,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, Cow<'a, [u8]>> {
+    let ta = ta.unbind();
+    // 1. Let buffer be ta.[[ViewedArrayBuffer]].
+    // 2. Let taRecord be MakeTypedArrayWithBufferWitnessRecord(ta, seq-cst).
+    let ta_record = make_typed_array_with_buffer_witness_record(agent, ta, Ordering::SeqCst);
+    // 3. If IsTypedArrayOutOfBounds(taRecord) is true, throw a TypeError
+    //    exception.
+    if ta_record.is_typed_array_out_of_bounds(agent) {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "TypedArray out of bounds",
+            gc,
+        ));
+    }
+    // 4. Let len be TypedArrayLength(taRecord).
+    let len = ta_record.typed_array_length(agent);
+    // 5. Let byteOffset be ta.[[ByteOffset]].
+    // 6. Let bytes be a new empty List.
+    // 7. Let index be 0.
+    // 8. Repeat, while index < len,
+    // a. Let byteIndex be byteOffset + index.
+    // b. Let byte be ℝ(GetValueFromBuffer(buffer, byteIndex, uint8, true, unordered)).
+    // c. Append byte to bytes.
+    // d. Set index to index + 1.
+    // 9. Return bytes.
+    match ta {
+        AnyTypedArray::Uint8Array(ta) => Ok(Cow::Borrowed(&ta.as_slice(agent)[..len])),
+        #[cfg(feature = "shared-array-buffer")]
+        AnyTypedArray::SharedUint8Array(ta) => {
+            let mut bytes = vec![0; len];
+            ta.as_slice(agent).slice_to(len).copy_into_slice(&mut bytes);
+            Ok(Cow::Owned(bytes))
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// ### [23.3.3.3 SetUint8ArrayBytes ( into, bytes )](https://tc39.es/ecma262/#sec-setuint8arraybytes)
+///
+/// The abstract operation SetUint8ArrayBytes takes arguments into (a
+/// Uint8Array) and bytes (a List of byte values) and returns unused. The
+/// caller must have checked that `bytes` fits into the array.
+pub(crate) fn set_uint8_array_bytes(agent: &mut Agent, into: AnyTypedArray, bytes: &[u8]) {
+    // 1. Let offset be into.[[ByteOffset]].
+    // 2. Let len be the length of bytes.
+    // 3. Let index be 0.
+    // 4. Repeat, while index < len,
+    // a. Let byte be bytes[index].
+    // b. Let byteIndexInBuffer be index + offset.
+    // c. Perform SetValueInBuffer(into.[[ViewedArrayBuffer]], byteIndexInBuffer, uint8, 𝔽(byte), true, unordered).
+    // d. Set index to index + 1.
+    match into {
+        AnyTypedArray::Uint8Array(ta) => {
+            ta.as_mut_slice(agent)[..bytes.len()].copy_from_slice(bytes);
+        }
+        #[cfg(feature = "shared-array-buffer")]
+        AnyTypedArray::SharedUint8Array(ta) => {
+            ta.as_slice(agent)
+                .slice_to(bytes.len())
+                .copy_from_slice(bytes);
+        }
+        _ => unreachable!(),
+    }
+}
+
+/// Creates a new Uint8Array holding the given bytes, as performed by the last
+/// steps of Uint8Array.fromBase64 and Uint8Array.fromHex.
+pub(crate) fn uint8_array_from_bytes<'gc>(
+    agent: &mut Agent,
+    bytes: &[u8],
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    // Let resultLength be the length of result.[[Bytes]].
+    // Let ta be ? AllocateTypedArray("Uint8Array", %Uint8Array%,
+    // %Uint8Array.prototype%, resultLength).
+    let uint8_array = agent.current_realm_record().intrinsics().uint8_array();
+    let ta = allocate_typed_array::<u8>(agent, uint8_array.into(), Some(bytes.len()), gc)?;
+    // Set the value at each index of ta.[[ViewedArrayBuffer]].[[ArrayBufferData]]
+    // to the value at the corresponding index of result.[[Bytes]].
+    ta.as_mut_slice(agent).copy_from_slice(bytes);
+    // Return ta.
+    Ok(ta.into())
+}
+
+/// Creates the `{ read, written }` result Object of
+/// Uint8Array.prototype.setFromBase64 and Uint8Array.prototype.setFromHex.
+pub(crate) fn read_written_result_object<'gc>(
+    agent: &mut Agent,
+    read: usize,
+    written: usize,
+    gc: NoGcScope<'gc, '_>,
+) -> OrdinaryObject<'gc> {
+    // Let resultObject be OrdinaryObjectCreate(%Object.prototype%).
+    // Perform ! CreateDataPropertyOrThrow(resultObject, "read", 𝔽(result.[[Read]])).
+    // Perform ! CreateDataPropertyOrThrow(resultObject, "written", 𝔽(written)).
+    let object_prototype = agent.current_realm_record().intrinsics().object_prototype();
+    OrdinaryObject::create_object(
+        agent,
+        Some(object_prototype.into()),
+        &[
+            ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.read.into(),
+                Number::try_from(read).unwrap().into(),
+            ),
+            ObjectEntry::new_data_entry(
+                BUILTIN_STRING_MEMORY.written.into(),
+                Number::try_from(written).unwrap().into(),
+            ),
+        ],
+    )
+    .expect("Should perform GC here")
+    .bind(gc)
+}
+
+/// Performs `? Get(opts, key)` on an options Object returned by
+/// [`get_options_object`].
+fn get_option<'gc>(
+    agent: &mut Agent,
+    options: Option<Object>,
+    key: PropertyKey,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    match options {
+        Some(options) => get(agent, options, key, gc),
+        None => Ok(Value::Undefined),
+    }
+}
+
+/// Reads and validates the `alphabet` option.
+pub(crate) fn get_alphabet_option<'gc>(
+    agent: &mut Agent,
+    options: Option<Object>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Base64Alphabet> {
+    // Let alphabet be ? Get(opts, "alphabet").
+    let alphabet = get_option(
+        agent,
+        options,
+        BUILTIN_STRING_MEMORY.alphabet.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let alphabet = alphabet.bind(gc);
+    // If alphabet is undefined, set alphabet to "base64".
+    if alphabet.is_undefined() {
+        return Ok(Base64Alphabet::Base64);
+    }
+    // If alphabet is neither "base64" nor "base64url", throw a TypeError
+    // exception.
+    let alphabet = String::try_from(alphabet).ok();
+    match alphabet.as_ref().map(|s| s.as_bytes(agent)) {
+        Some(b"base64") => Ok(Base64Alphabet::Base64),
+        Some(b"base64url") => Ok(Base64Alphabet::Base64Url),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "alphabet must be \"base64\" or \"base64url\"",
+            gc,
+        )),
+    }
+}
+
+/// Reads and validates the `lastChunkHandling` option.
+pub(crate) fn get_last_chunk_handling_option<'gc>(
+    agent: &mut Agent,
+    options: Option<Object>,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, LastChunkHandling> {
+    // Let lastChunkHandling be ? Get(opts, "lastChunkHandling").
+    let last_chunk_handling = get_option(
+        agent,
+        options,
+        BUILTIN_STRING_MEMORY.lastChunkHandling.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let last_chunk_handling = last_chunk_handling.bind(gc);
+    // If lastChunkHandling is undefined, set lastChunkHandling to "loose".
+    if last_chunk_handling.is_undefined() {
+        return Ok(LastChunkHandling::Loose);
+    }
+    // If lastChunkHandling is not one of "loose", "strict", or
+    // "stop-before-partial", throw a TypeError exception.
+    let last_chunk_handling = String::try_from(last_chunk_handling).ok();
+    match last_chunk_handling.as_ref().map(|s| s.as_bytes(agent)) {
+        Some(b"loose") => Ok(LastChunkHandling::Loose),
+        Some(b"strict") => Ok(LastChunkHandling::Strict),
+        Some(b"stop-before-partial") => Ok(LastChunkHandling::StopBeforePartial),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "lastChunkHandling must be \"loose\", \"strict\", or \"stop-before-partial\"",
+            gc,
+        )),
+    }
+}
+
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+const BASE64URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encodes bytes as base64 with the given alphabet, as performed by
+/// %Uint8Array.prototype%.toBase64.
+pub(crate) fn encode_base64(
+    bytes: &[u8],
+    alphabet: Base64Alphabet,
+    omit_padding: bool,
+) -> std::string::String {
+    let table = match alphabet {
+        Base64Alphabet::Base64 => BASE64_ALPHABET,
+        Base64Alphabet::Base64Url => BASE64URL_ALPHABET,
+    };
+    let mut result = std::string::String::with_capacity(bytes.len().div_ceil(3) * 4);
+    let mut chunks = bytes.chunks_exact(3);
+    for chunk in &mut chunks {
+        let n = u32::from(chunk[0]) << 16 | u32::from(chunk[1]) << 8 | u32::from(chunk[2]);
+        for shift in [18, 12, 6, 0] {
+            result.push(char::from(table[(n >> shift) as usize & 0x3F]));
+        }
+    }
+    let remainder = chunks.remainder();
+    if !remainder.is_empty() {
+        let n = u32::from(remainder[0]) << 16 | u32::from(*remainder.get(1).unwrap_or(&0)) << 8;
+        // One byte encodes into two characters, two bytes into three.
+        let encoded_length = remainder.len() + 1;
+        for shift in [18, 12, 6].into_iter().take(encoded_length) {
+            result.push(char::from(table[(n >> shift) as usize & 0x3F]));
+        }
+        if !omit_padding {
+            for _ in encoded_length..4 {
+                result.push('=');
+            }
+        }
+    }
+    result
+}
+
+/// Encodes bytes as lowercase hexadecimal, as performed by
+/// %Uint8Array.prototype%.toHex.
+pub(crate) fn encode_hex(bytes: &[u8]) -> std::string::String {
+    const HEX_DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut result = std::string::String::with_capacity(bytes.len() * 2);
+    for byte in bytes {
+        result.push(char::from(HEX_DIGITS[usize::from(byte >> 4)]));
+        result.push(char::from(HEX_DIGITS[usize::from(byte & 0xF)]));
+    }
+    result
+}
+
+/// ### [23.3.3.4 SkipAsciiWhitespace ( string, index )](https://tc39.es/ecma262/#sec-skipasciiwhitespace)
+fn skip_ascii_whitespace(string: &[u8], mut index: usize) -> usize {
+    // 1. Let length be the length of string.
+    // 2. Repeat, while index < length,
+    while let Some(&char) = string.get(index) {
+        // a. Let char be the code unit at index index of string.
+        // b. If char is neither 0x0009 (TAB), 0x000A (LF), 0x000C (FF),
+        //    0x000D (CR), nor 0x0020 (SPACE), then
+        if !matches!(char, b'\t' | b'\n' | b'\x0C' | b'\r' | b' ') {
+            // i. Return index.
+            return index;
+        }
+        // c. Set index to index + 1.
+        index += 1;
+    }
+    // 3. Return index.
+    index
+}
+
+/// ### [23.3.3.5 DecodeBase64Chunk ( chunk \[ , throwOnExtraBits \] )](https://tc39.es/ecma262/#sec-decodebase64chunk)
+///
+/// Takes the chunk as already-decoded 6-bit values and appends the decoded
+/// bytes to `bytes`. Returns false without appending anything if
+/// throwOnExtraBits is true and the padding bits are not zero.
+fn decode_base64_chunk(chunk: &[u8], throw_on_extra_bits: bool, bytes: &mut Vec<u8>) -> bool {
+    // 1. Let chunkLength be the length of chunk.
+    let chunk_length = chunk.len();
+    // 2. If chunkLength = 2, then
+    //    a. Set chunk to the string-concatenation of chunk and "AA".
+    // 3. Else if chunkLength = 3, then
+    //    a. Set chunk to the string-concatenation of chunk and "A".
+    // 4. Else,
+    //    a. Assert: chunkLength is 4.
+    debug_assert!((2..=4).contains(&chunk_length));
+    // 5. Let byteSequence be the unique sequence of 3 bytes resulting from
+    //    decoding chunk as base64.
+    let n = chunk
+        .iter()
+        .chain(core::iter::repeat(&0))
+        .take(4)
+        .fold(0u32, |n, &sextet| n << 6 | u32::from(sextet));
+    let byte_sequence = [(n >> 16) as u8, (n >> 8) as u8, n as u8];
+    // 6. Let bytes be the List whose elements are the elements of
+    //    byteSequence, in order.
+    // 7. If chunkLength = 2, then
+    //    a. Assert: throwOnExtraBits is present.
+    //    b. If throwOnExtraBits is true and bytes[1] ≠ 0, then
+    //       i. Throw a SyntaxError exception.
+    //    c. Return « bytes[0] ».
+    // 8. Else if chunkLength = 3, then
+    //    a. Assert: throwOnExtraBits is present.
+    //    b. If throwOnExtraBits is true and bytes[2] ≠ 0, then
+    //       i. Throw a SyntaxError exception.
+    //    c. Return « bytes[0], bytes[1] ».
+    // 9. Else,
+    //    a. Return bytes.
+    let byte_count = chunk_length - 1;
+    if throw_on_extra_bits && byte_sequence[byte_count..].iter().any(|&b| b != 0) {
+        return false;
+    }
+    bytes.extend_from_slice(&byte_sequence[..byte_count]);
+    true
+}
+
+/// ### [23.3.3.6 FromBase64 ( string, alphabet, lastChunkHandling \[ , maxLength \] )](https://tc39.es/ecma262/#sec-frombase64)
+///
+/// The abstract operation FromBase64 takes arguments string (a String),
+/// alphabet ("base64" or "base64url"), and lastChunkHandling ("loose",
+/// "strict", or "stop-before-partial") and optional argument maxLength (a
+/// non-negative integer) and returns a Record with fields \[\[Read]],
+/// \[\[Bytes]], and \[\[Error]]. `string` is the WTF-8 data of the String.
+pub(crate) fn from_base64(
+    string: &[u8],
+    alphabet: Base64Alphabet,
+    last_chunk_handling: LastChunkHandling,
+    max_length: usize,
+) -> DecodeResult {
+    const INVALID_CHARACTER: &str = "invalid character in base64 string";
+    const INCOMPLETE_CHUNK: &str = "incomplete base64 chunk";
+    const EXTRA_BITS: &str = "non-zero padding bits in base64 string";
+
+    // 3. If maxLength = 0, then
+    if max_length == 0 {
+        // a. Return the Record { [[Read]]: 0, [[Bytes]]: « », [[Error]]: none }.
+        return DecodeResult::ok(0, vec![]);
+    }
+    // 4. Let read be 0.
+    let mut read = 0;
+    // 5. Let bytes be « ».
+    let mut bytes = Vec::with_capacity(max_length.min(string.len() / 4 * 3 + 2));
+    // 6. Let chunk be the empty String.
+    let mut chunk = [0u8; 4];
+    // 7. Let chunkLength be 0.
+    let mut chunk_length = 0;
+    // 8. Let index be 0.
+    let mut index = 0;
+    // 9. Let length be the length of string.
+    let length = string.len();
+    // 10. Repeat,
+    loop {
+        // a. Set index to SkipAsciiWhitespace(string, index).
+        index = skip_ascii_whitespace(string, index);
+        // b. If index = length, then
+        if index == length {
+            // i. If chunkLength > 0, then
+            if chunk_length > 0 {
+                match last_chunk_handling {
+                    // 1. If lastChunkHandling is "stop-before-partial", then
+                    LastChunkHandling::StopBeforePartial => {
+                        // a. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                        return DecodeResult::ok(read, bytes);
+                    }
+                    // 2. Else if lastChunkHandling is "loose", then
+                    LastChunkHandling::Loose => {
+                        // a. If chunkLength = 1, then
+                        if chunk_length == 1 {
+                            // i. Let error be a new SyntaxError exception.
+                            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                            return DecodeResult::error(read, bytes, INCOMPLETE_CHUNK);
+                        }
+                        // b. Set bytes to the list-concatenation of bytes and
+                        //    ! DecodeBase64Chunk(chunk, false).
+                        decode_base64_chunk(&chunk[..chunk_length], false, &mut bytes);
+                    }
+                    // 3. Else,
+                    LastChunkHandling::Strict => {
+                        // a. Assert: lastChunkHandling is "strict".
+                        // b. Let error be a new SyntaxError exception.
+                        // c. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                        return DecodeResult::error(read, bytes, INCOMPLETE_CHUNK);
+                    }
+                }
+            }
+            // ii. Return the Record { [[Read]]: length, [[Bytes]]: bytes, [[Error]]: none }.
+            return DecodeResult::ok(length, bytes);
+        }
+        // c. Let char be the substring of string from index to index + 1.
+        let mut char = string[index];
+        // d. Set index to index + 1.
+        index += 1;
+        // e. If char is "=", then
+        if char == b'=' {
+            // i. If chunkLength < 2, then
+            if chunk_length < 2 {
+                // 1. Let error be a new SyntaxError exception.
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                return DecodeResult::error(read, bytes, INCOMPLETE_CHUNK);
+            }
+            // ii. Set index to SkipAsciiWhitespace(string, index).
+            index = skip_ascii_whitespace(string, index);
+            // iii. If chunkLength = 2, then
+            if chunk_length == 2 {
+                // 1. If index = length, then
+                if index == length {
+                    // a. If lastChunkHandling is "stop-before-partial", then
+                    if last_chunk_handling == LastChunkHandling::StopBeforePartial {
+                        // i. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                        return DecodeResult::ok(read, bytes);
+                    }
+                    // b. Let error be a new SyntaxError exception.
+                    // c. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                    return DecodeResult::error(read, bytes, INCOMPLETE_CHUNK);
+                }
+                // 2. Set char to the substring of string from index to index + 1.
+                // 3. If char is "=", then
+                if string[index] == b'=' {
+                    // a. Set index to SkipAsciiWhitespace(string, index + 1).
+                    index = skip_ascii_whitespace(string, index + 1);
+                }
+            }
+            // iv. If index < length, then
+            if index < length {
+                // 1. Let error be a new SyntaxError exception.
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                return DecodeResult::error(read, bytes, INVALID_CHARACTER);
+            }
+            // v. If lastChunkHandling is "strict", let throwOnExtraBits be
+            //    true.
+            // vi. Else, let throwOnExtraBits be false.
+            let throw_on_extra_bits = last_chunk_handling == LastChunkHandling::Strict;
+            // vii. Let decodeResult be Completion(DecodeBase64Chunk(chunk, throwOnExtraBits)).
+            // viii. If decodeResult is an abrupt completion, then
+            if !decode_base64_chunk(&chunk[..chunk_length], throw_on_extra_bits, &mut bytes) {
+                // 1. Let error be decodeResult.[[Value]].
+                // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                return DecodeResult::error(read, bytes, EXTRA_BITS);
+            }
+            // ix. Set bytes to the list-concatenation of bytes and ! decodeResult.
+            // x. Return the Record { [[Read]]: length, [[Bytes]]: bytes, [[Error]]: none }.
+            return DecodeResult::ok(length, bytes);
+        }
+        // f. If alphabet is "base64url", then
+        if alphabet == Base64Alphabet::Base64Url {
+            match char {
+                // i. If char is either "+" or "/", then
+                b'+' | b'/' => {
+                    // 1. Let error be a new SyntaxError exception.
+                    // 2. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+                    return DecodeResult::error(read, bytes, INVALID_CHARACTER);
+                }
+                // ii. Else if char is "-", then
+                // 1. Set char to "+".
+                b'-' => char = b'+',
+                // iii. Else if char is "_", then
+                // 1. Set char to "/".
+                b'_' => char = b'/',
+                _ => {}
+            }
+        }
+        // g. If the sole code unit of char is not an element of the
+        //    standard base64 alphabet, then
+        let Some(sextet) = BASE64_ALPHABET.iter().position(|&c| c == char) else {
+            // i. Let error be a new SyntaxError exception.
+            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+            return DecodeResult::error(read, bytes, INVALID_CHARACTER);
+        };
+        // h. Let remaining be maxLength - the length of bytes.
+        let remaining = max_length - bytes.len();
+        // i. If remaining = 1 and chunkLength = 2, or if remaining = 2 and
+        //    chunkLength = 3, then
+        if (remaining == 1 && chunk_length == 2) || (remaining == 2 && chunk_length == 3) {
+            // i. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+            return DecodeResult::ok(read, bytes);
+        }
+        // j. Set chunk to the string-concatenation of chunk and char.
+        chunk[chunk_length] = sextet as u8;
+        // k. Set chunkLength to the length of chunk.
+        chunk_length += 1;
+        // l. If chunkLength = 4, then
+        if chunk_length == 4 {
+            // i. Set bytes to the list-concatenation of bytes and
+            //    ! DecodeBase64Chunk(chunk).
+            decode_base64_chunk(&chunk, false, &mut bytes);
+            // ii. Set chunk to the empty String.
+            // iii. Set chunkLength to 0.
+            chunk_length = 0;
+            // iv. Set read to index.
+            read = index;
+            // v. If the length of bytes = maxLength, then
+            if bytes.len() == max_length {
+                // 1. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+                return DecodeResult::ok(read, bytes);
+            }
+        }
+    }
+}
+
+/// ### [23.3.3.7 FromHex ( string \[ , maxLength \] )](https://tc39.es/ecma262/#sec-fromhex)
+///
+/// The abstract operation FromHex takes argument string (a String) and
+/// optional argument maxLength (a non-negative integer) and returns a Record
+/// with fields \[\[Read]], \[\[Bytes]], and \[\[Error]]. `string` is the
+/// WTF-8 data of the String and `length` its length in code units.
+pub(crate) fn from_hex(string: &[u8], length: usize, max_length: usize) -> DecodeResult {
+    // 2. Let length be the length of string.
+    // 3. Let bytes be « ».
+    // 4. Let read be 0.
+    // 5. If length modulo 2 ≠ 0, then
+    if !length.is_multiple_of(2) {
+        // a. Let error be a new SyntaxError exception.
+        // b. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+        return DecodeResult::error(0, vec![], "hex string must have an even length");
+    }
+    let mut bytes = Vec::with_capacity(max_length.min(length / 2));
+    let mut read = 0;
+    // 6. Repeat, while read < length and the length of bytes < maxLength,
+    while read < length && bytes.len() < max_length {
+        // a. Let hexits be the substring of string from read to read + 2.
+        // b. If hexits contains any code units which are not in
+        //    "0123456789abcdefABCDEF", then
+        // c. Set read to read + 2.
+        // d. Let byte be the integer value represented by hexits in base-16
+        //    notation, using the letters A-F and a-f for digits with values 10
+        //    through 15.
+        let (Some(high), Some(low)) = (
+            char::from(string[read]).to_digit(16),
+            char::from(string[read + 1]).to_digit(16),
+        ) else {
+            // i. Let error be a new SyntaxError exception.
+            // ii. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: error }.
+            return DecodeResult::error(read, bytes, "invalid character in hex string");
+        };
+        read += 2;
+        // e. Append byte to bytes.
+        bytes.push((high << 4 | low) as u8);
+    }
+    // 7. Return the Record { [[Read]]: read, [[Bytes]]: bytes, [[Error]]: none }.
+    DecodeResult::ok(read, bytes)
+}
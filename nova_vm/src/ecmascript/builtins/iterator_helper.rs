@@ -0,0 +1,144 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod data;
+
+pub(crate) use data::*;
+
+use crate::{
+    ecmascript::{
+        Agent, Function, InternalMethods, InternalSlots, IteratorRecord, Object, OrdinaryObject,
+        ProtoIntrinsics, object_handle,
+    },
+    engine::Bindable,
+    heap::{
+        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
+        HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues, arena_vec_access,
+    },
+};
+
+/// ## [27.1.2.1 Iterator Helper Objects](https://tc39.es/ecma262/#sec-iterator-helper-objects)
+///
+/// An Iterator Helper object is an ordinary object that represents a lazy
+/// transformation of some specific source iterator object. There is not a
+/// named constructor for Iterator Helper objects. Instead, Iterator Helper
+/// objects are created by calling certain methods of Iterator instance
+/// objects, or by `Iterator.concat`.
+///
+/// The same heap data is also used for the wrapper objects created by
+/// `Iterator.from`, which inherit from %WrapForValidIteratorPrototype%.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct IteratorHelper<'a>(BaseIndex<'a, IteratorHelperHeapData<'static>>);
+object_handle!(IteratorHelper);
+arena_vec_access!(IteratorHelper, 'a, IteratorHelperHeapData, iterator_helpers);
+
+impl<'a> IteratorHelper<'a> {
+    /// Returns true if this is a %WrapForValidIteratorPrototype% object.
+    pub(crate) fn is_wrap_for_valid_iterator(self, agent: &Agent) -> bool {
+        matches!(
+            self.get(agent).kind,
+            IteratorHelperKind::WrapForValidIterator
+        )
+    }
+
+    pub(crate) fn state(self, agent: &Agent) -> IteratorHelperState {
+        self.get(agent).state
+    }
+
+    pub(crate) fn set_state(self, agent: &mut Agent, state: IteratorHelperState) {
+        self.get_mut(agent).state = state;
+    }
+
+    /// ### \[\[UnderlyingIterator]]
+    ///
+    /// Returns the underlying iterator object, if one exists.
+    pub(crate) fn iterator(self, agent: &Agent) -> Option<Object<'a>> {
+        self.get(agent).iterator
+    }
+
+    /// Returns the underlying Iterator Record, or None if the underlying
+    /// iterator's `next` method is not callable.
+    pub(crate) fn iterator_record(self, agent: &Agent) -> Option<IteratorRecord<'a>> {
+        let data = self.get(agent);
+        Some(IteratorRecord {
+            iterator: data.iterator?,
+            next_method: data.next_method?,
+        })
+    }
+
+    /// Returns the \[\[NextMethod]] of the underlying Iterator Record.
+    pub(crate) fn next_method(self, agent: &Agent) -> Option<Function<'a>> {
+        self.get(agent).next_method
+    }
+
+    pub(crate) fn kind(self, agent: &Agent) -> &IteratorHelperKind<'a> {
+        &self.get(agent).kind
+    }
+}
+
+impl<'a> InternalSlots<'a> for IteratorHelper<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::IteratorHelper;
+
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        self.get(agent).object_index.unbind()
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(
+            self.get_mut(agent)
+                .object_index
+                .replace(backing_object.unbind())
+                .is_none()
+        );
+    }
+
+    fn internal_prototype(self, agent: &Agent) -> Option<Object<'static>> {
+        if let Some(object_index) = self.get_backing_object(agent) {
+            object_index.internal_prototype(agent)
+        } else {
+            let intrinsic = if self.is_wrap_for_valid_iterator(agent) {
+                ProtoIntrinsics::WrapForValidIterator
+            } else {
+                ProtoIntrinsics::IteratorHelper
+            };
+            Some(
+                agent
+                    .current_realm_record()
+                    .intrinsics()
+                    .get_intrinsic_default_proto(intrinsic),
+            )
+        }
+    }
+}
+
+impl<'a> InternalMethods<'a> for IteratorHelper<'a> {}
+
+impl<'a> CreateHeapData<IteratorHelperHeapData<'a>, IteratorHelper<'a>> for Heap {
+    fn create(&mut self, data: IteratorHelperHeapData<'a>) -> IteratorHelper<'a> {
+        self.iterator_helpers.push(data.unbind());
+        self.alloc_counter += core::mem::size_of::<IteratorHelperHeapData<'static>>();
+        IteratorHelper(BaseIndex::last(&self.iterator_helpers))
+    }
+}
+
+impl HeapMarkAndSweep for IteratorHelper<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.iterator_helpers.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.iterator_helpers.shift_index(&mut self.0);
+    }
+}
+
+impl HeapSweepWeakReference for IteratorHelper<'static> {
+    fn sweep_weak_reference(self, compactions: &CompactionLists) -> Option<Self> {
+        compactions
+            .iterator_helpers
+            .shift_weak_index(self.0)
+            .map(Self)
+    }
+}
@@ -0,0 +1,218 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{Function, IteratorRecord, Object, OrdinaryObject},
+    engine::bindable_handle,
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// ### \[\[GeneratorState]]
+///
+/// Iterator Helper objects are specified as generators created from abstract
+/// closures; this is the part of the generator state that remains observable.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum IteratorHelperState {
+    SuspendedStart,
+    SuspendedYield,
+    Executing,
+    Completed,
+}
+
+/// An iterable passed to `Iterator.concat`.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct ConcatIterable<'a> {
+    /// ### \[\[OpenMethod]]
+    pub(crate) open_method: Function<'a>,
+    /// ### \[\[Iterable]]
+    pub(crate) iterable: Object<'a>,
+}
+bindable_handle!(ConcatIterable);
+
+/// The abstract closure of an Iterator Helper object, and the variables it
+/// captures.
+///
+/// The specification creates an abstract closure for each Iterator Helper; we
+/// instead store the captured values and resume the closure at its single
+/// Yield point manually.
+#[derive(Debug, Clone)]
+pub(crate) enum IteratorHelperKind<'a> {
+    /// ### [27.1.4.8 Iterator.prototype.map ( mapper )](https://tc39.es/ecma262/#sec-iterator.prototype.map)
+    Map { mapper: Function<'a>, counter: u64 },
+    /// ### [27.1.4.4 Iterator.prototype.filter ( predicate )](https://tc39.es/ecma262/#sec-iterator.prototype.filter)
+    Filter {
+        predicate: Function<'a>,
+        counter: u64,
+    },
+    /// ### [27.1.4.11 Iterator.prototype.take ( limit )](https://tc39.es/ecma262/#sec-iterator.prototype.take)
+    Take { remaining: f64 },
+    /// ### [27.1.4.2 Iterator.prototype.drop ( limit )](https://tc39.es/ecma262/#sec-iterator.prototype.drop)
+    Drop { remaining: f64 },
+    /// ### [27.1.4.6 Iterator.prototype.flatMap ( mapper )](https://tc39.es/ecma262/#sec-iterator.prototype.flatmap)
+    FlatMap {
+        mapper: Function<'a>,
+        counter: u64,
+        /// The iterator returned by the mapper that is currently being
+        /// flattened.
+        inner: Option<IteratorRecord<'a>>,
+    },
+    /// ### [Iterator.concat ( ...items )](https://tc39.es/proposal-iterator-sequencing/#sec-iterator.concat)
+    Concat {
+        iterables: Vec<ConcatIterable<'a>>,
+        /// Index of the next iterable to open.
+        index: usize,
+        /// The iterator of the iterable that is currently being iterated.
+        inner: Option<IteratorRecord<'a>>,
+    },
+    /// ### [27.1.3.2.1.1 %WrapForValidIteratorPrototype%](https://tc39.es/ecma262/#sec-%wrapforvaliditeratorprototype%-object)
+    ///
+    /// Not an Iterator Helper but a wrapper object created by
+    /// `Iterator.from`; it only has an \[\[Iterated]] internal slot.
+    WrapForValidIterator,
+}
+
+#[derive(Debug, Clone)]
+pub(crate) struct IteratorHelperHeapData<'a> {
+    pub(super) object_index: Option<OrdinaryObject<'a>>,
+    /// ### \[\[GeneratorState]]
+    pub(crate) state: IteratorHelperState,
+    /// ### \[\[UnderlyingIterator]]
+    ///
+    /// Also the \[\[Iterated]] Iterator Record of a %WrapForValidIteratorPrototype%
+    /// object. This is None for `Iterator.concat` helpers, which have an empty
+    /// \[\[UnderlyingIterators]] List.
+    pub(crate) iterator: Option<Object<'a>>,
+    /// The \[\[NextMethod]] of the underlying Iterator Record. This is None
+    /// if the `next` property was not callable; calling it throws a TypeError.
+    pub(crate) next_method: Option<Function<'a>>,
+    pub(crate) kind: IteratorHelperKind<'a>,
+}
+
+impl<'a> IteratorHelperHeapData<'a> {
+    pub(crate) fn new(
+        iterator: Option<Object<'a>>,
+        next_method: Option<Function<'a>>,
+        kind: IteratorHelperKind<'a>,
+    ) -> Self {
+        Self {
+            object_index: None,
+            state: IteratorHelperState::SuspendedStart,
+            iterator,
+            next_method,
+            kind,
+        }
+    }
+}
+
+bindable_handle!(IteratorHelperHeapData);
+
+impl HeapMarkAndSweep for ConcatIterable<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            open_method,
+            iterable,
+        } = self;
+        open_method.mark_values(queues);
+        iterable.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            open_method,
+            iterable,
+        } = self;
+        open_method.sweep_values(compactions);
+        iterable.sweep_values(compactions);
+    }
+}
+
+impl HeapMarkAndSweep for IteratorHelperKind<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        match self {
+            Self::Map { mapper, counter: _ } => mapper.mark_values(queues),
+            Self::Filter {
+                predicate,
+                counter: _,
+            } => predicate.mark_values(queues),
+            Self::Take { remaining: _ }
+            | Self::Drop { remaining: _ }
+            | Self::WrapForValidIterator => {}
+            Self::FlatMap {
+                mapper,
+                counter: _,
+                inner,
+            } => {
+                mapper.mark_values(queues);
+                inner.mark_values(queues);
+            }
+            Self::Concat {
+                iterables,
+                index: _,
+                inner,
+            } => {
+                iterables.mark_values(queues);
+                inner.mark_values(queues);
+            }
+        }
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        match self {
+            Self::Map { mapper, counter: _ } => mapper.sweep_values(compactions),
+            Self::Filter {
+                predicate,
+                counter: _,
+            } => predicate.sweep_values(compactions),
+            Self::Take { remaining: _ }
+            | Self::Drop { remaining: _ }
+            | Self::WrapForValidIterator => {}
+            Self::FlatMap {
+                mapper,
+                counter: _,
+                inner,
+            } => {
+                mapper.sweep_values(compactions);
+                inner.sweep_values(compactions);
+            }
+            Self::Concat {
+                iterables,
+                index: _,
+                inner,
+            } => {
+                iterables.sweep_values(compactions);
+                inner.sweep_values(compactions);
+            }
+        }
+    }
+}
+
+impl HeapMarkAndSweep for IteratorHelperHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            state: _,
+            iterator,
+            next_method,
+            kind,
+        } = self;
+        object_index.mark_values(queues);
+        iterator.mark_values(queues);
+        next_method.mark_values(queues);
+        kind.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            state: _,
+            iterator,
+            next_method,
+            kind,
+        } = self;
+        object_index.sweep_values(compactions);
+        iterator.sweep_values(compactions);
+        next_method.sweep_values(compactions);
+        kind.sweep_values(compactions);
+    }
+}
@@ -420,7 +420,7 @@ pub(crate) fn add_entries_from_iterable<'a, T: Into<Object<'a>> + TryFrom<Object
         iterator,
         next_method,
         ..
-    }) = get_iterator(agent, iterable.unbind(), false, gc.reborrow())
+    }) = get_iterator(agent, iterable.unbind(), gc.reborrow())
         .unbind()?
         .bind(gc.nogc())
         .into_iterator_record()
@@ -55,6 +55,22 @@ impl Builtin for MapPrototypeGet {
     const LENGTH: u8 = 1;
     const BEHAVIOUR: Behaviour = Behaviour::Regular(MapPrototype::get);
 }
+#[cfg(feature = "proposal-upsert")]
+struct MapPrototypeGetOrInsert;
+#[cfg(feature = "proposal-upsert")]
+impl Builtin for MapPrototypeGetOrInsert {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.getOrInsert;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(MapPrototype::get_or_insert);
+}
+#[cfg(feature = "proposal-upsert")]
+struct MapPrototypeGetOrInsertComputed;
+#[cfg(feature = "proposal-upsert")]
+impl Builtin for MapPrototypeGetOrInsertComputed {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.getOrInsertComputed;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(MapPrototype::get_or_insert_computed);
+}
 struct MapPrototypeHas;
 impl Builtin for MapPrototypeHas {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.has;
@@ -328,6 +344,196 @@ impl MapPrototype {
         }
     }
 
+    #[cfg(feature = "proposal-upsert")]
+    /// ### [24.1.3.7 Map.prototype.getOrInsert ( key, value )](https://tc39.es/proposal-upsert/#sec-map.prototype.getorinsert)
+    fn get_or_insert<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let key = arguments.get(0).bind(gc);
+        let value = arguments.get(1).bind(gc);
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[MapData]]).
+        let m = require_map_data_internal_slot(agent, this_value, gc)?;
+
+        let Heap {
+            bigints,
+            numbers,
+            strings,
+            maps,
+            ..
+        } = &mut agent.heap;
+        let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
+        let (map_data, keys, values) = m.get_map_data_mut(maps, &primitive_heap);
+
+        // 3. Set key to CanonicalizeKeyedCollectionKey(key).
+        let key = canonicalize_keyed_collection_key(numbers, key);
+        let hasher = |value: Value| {
+            let mut hasher = AHasher::default();
+            value.hash(&primitive_heap, &mut hasher);
+            hasher.finish()
+        };
+        let key_hash = hasher(key);
+
+        // 4. For each Record { [[Key]], [[Value]] } p of M.[[MapData]], do
+        // a. If p.[[Key]] is not EMPTY and SameValue(p.[[Key]], key) is true, return p.[[Value]].
+        let entry = map_data.entry(
+            key_hash,
+            |hash_equal_index| {
+                let found_key = keys[*hash_equal_index as usize].unwrap();
+                // Quick check: Equal keys have the same value.
+                found_key == key || same_value(&primitive_heap, found_key, key)
+            },
+            |index_to_hash| hasher(keys[*index_to_hash as usize].unwrap()),
+        );
+        match entry {
+            hashbrown::hash_table::Entry::Occupied(occupied) => {
+                Ok(values[*occupied.get() as usize].unwrap().unbind().bind(gc))
+            }
+            hashbrown::hash_table::Entry::Vacant(vacant) => {
+                // 5. Let p be the Record { [[Key]]: key, [[Value]]: value }.
+                // 6. Append p to M.[[MapData]].
+                let index = u32::try_from(values.len()).unwrap();
+                vacant.insert(index);
+                keys.push(Some(key.unbind()));
+                values.push(Some(value.unbind()));
+                // 7. Return value.
+                Ok(value)
+            }
+        }
+    }
+
+    #[cfg(feature = "proposal-upsert")]
+    /// ### [24.1.3.8 Map.prototype.getOrInsertComputed ( key, callbackfn )](https://tc39.es/proposal-upsert/#sec-map.prototype.getorinsertcomputed)
+    fn get_or_insert_computed<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let key = arguments.get(0).bind(nogc);
+        let callback_fn = arguments.get(1).bind(nogc);
+        // 1. Let M be the this value.
+        // 2. Perform ? RequireInternalSlot(M, [[MapData]]).
+        let m = require_map_data_internal_slot(agent, this_value, nogc)
+            .unbind()?
+            .bind(nogc);
+        // 3. If IsCallable(callbackfn) is false, throw a TypeError exception.
+        let Some(callback_fn) = is_callable(callback_fn, nogc) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Callback function is not a function",
+                gc.into_nogc(),
+            ));
+        };
+
+        let Heap {
+            bigints,
+            numbers,
+            strings,
+            maps,
+            ..
+        } = &mut agent.heap;
+        let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
+        let (map_data, keys, values) = m.get_map_data_mut(maps, &primitive_heap);
+
+        // 4. Set key to CanonicalizeKeyedCollectionKey(key).
+        let key = canonicalize_keyed_collection_key(numbers, key);
+        let key_hash = {
+            let mut hasher = AHasher::default();
+            key.hash(&primitive_heap, &mut hasher);
+            hasher.finish()
+        };
+
+        // 5. For each Record { [[Key]], [[Value]] } p of M.[[MapData]], do
+        // a. If p.[[Key]] is not EMPTY and SameValue(p.[[Key]], key) is true, return p.[[Value]].
+        let found = map_data.find(key_hash, |hash_equal_index| {
+            let found_key = keys[*hash_equal_index as usize].unwrap();
+            // Quick check: Equal keys have the same value.
+            found_key == key || same_value(&primitive_heap, found_key, key)
+        });
+        if let Some(index) = found {
+            return Ok(values[*index as usize]
+                .unwrap()
+                .unbind()
+                .bind(gc.into_nogc()));
+        }
+
+        let old_key = key.unbind();
+        let scoped_m = m.scope(agent, nogc);
+        let scoped_key = key.scope(agent, nogc);
+        // 6. Let value be ? Call(callbackfn, undefined, « key »).
+        let value = call_function(
+            agent,
+            callback_fn.unbind(),
+            Value::Undefined,
+            Some(ArgumentsList::from_mut_slice(&mut [key.unbind()])),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let value = value.bind(gc);
+        // SAFETY: not shared.
+        let m = unsafe { scoped_m.take(agent) }.bind(gc);
+        // SAFETY: not shared.
+        let key = unsafe { scoped_key.take(agent) }.bind(gc);
+
+        let Heap {
+            bigints,
+            numbers,
+            strings,
+            maps,
+            ..
+        } = &mut agent.heap;
+        let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
+        let (map_data, keys, values) = m.get_map_data_mut(maps, &primitive_heap);
+        let hasher = |value: Value| {
+            let mut hasher = AHasher::default();
+            value.hash(&primitive_heap, &mut hasher);
+            hasher.finish()
+        };
+        // Note: The key's hash only changes if garbage collection moved the
+        // key during the call; otherwise the earlier hash can be reused.
+        let key_hash = if key.unbind() == old_key {
+            key_hash
+        } else {
+            hasher(key)
+        };
+
+        // 7. For each Record { [[Key]], [[Value]] } p of M.[[MapData]], do
+        // a. If p.[[Key]] is not EMPTY and SameValue(p.[[Key]], key) is true, then
+        let entry = map_data.entry(
+            key_hash,
+            |hash_equal_index| {
+                let found_key = keys[*hash_equal_index as usize].unwrap();
+                // Quick check: Equal keys have the same value.
+                found_key == key || same_value(&primitive_heap, found_key, key)
+            },
+            |index_to_hash| hasher(keys[*index_to_hash as usize].unwrap()),
+        );
+        match entry {
+            hashbrown::hash_table::Entry::Occupied(occupied) => {
+                // i. Set p.[[Value]] to value.
+                // ii. Return value.
+                values[*occupied.get() as usize] = Some(value.unbind());
+            }
+            hashbrown::hash_table::Entry::Vacant(vacant) => {
+                // 8. Let p be the Record { [[Key]]: key, [[Value]]: value }.
+                // 9. Append p to M.[[MapData]].
+                let index = u32::try_from(values.len()).unwrap();
+                vacant.insert(index);
+                keys.push(Some(key.unbind()));
+                values.push(Some(value.unbind()));
+            }
+        }
+        // 10. Return value.
+        Ok(value)
+    }
+
     /// ### [24.1.3.7 Map.prototype.has ( key )](https://tc39.es/ecma262/#sec-map.prototype.has)
     fn has<'gc>(
         agent: &mut Agent,
@@ -490,15 +696,27 @@ impl MapPrototype {
         let map_constructor = intrinsics.map();
         let map_prototype_entries = intrinsics.map_prototype_entries();
 
-        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
-            .with_property_capacity(13)
+        let mut property_capacity = 13;
+        if cfg!(feature = "proposal-upsert") {
+            property_capacity += 2;
+        }
+
+        let builder = OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(property_capacity)
             .with_prototype(object_prototype)
             .with_builtin_function_property::<MapPrototypeClear>()
             .with_constructor_property(map_constructor)
             .with_builtin_function_property::<MapPrototypeDelete>()
             .with_builtin_intrinsic_function_property::<MapPrototypeEntries>()
             .with_builtin_function_property::<MapPrototypeForEach>()
-            .with_builtin_function_property::<MapPrototypeGet>()
+            .with_builtin_function_property::<MapPrototypeGet>();
+
+        #[cfg(feature = "proposal-upsert")]
+        let builder = builder
+            .with_builtin_function_property::<MapPrototypeGetOrInsert>()
+            .with_builtin_function_property::<MapPrototypeGetOrInsertComputed>();
+
+        builder
             .with_builtin_function_property::<MapPrototypeHas>()
             .with_builtin_function_property::<MapPrototypeKeys>()
             .with_builtin_function_property::<MapPrototypeSet>()
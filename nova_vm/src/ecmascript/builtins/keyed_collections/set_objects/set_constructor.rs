@@ -179,7 +179,7 @@ impl SetConstructor {
         let Some(IteratorRecord {
             iterator,
             next_method,
-        }) = get_iterator(agent, scoped_iterable.get(agent), false, gc.reborrow())
+        }) = get_iterator(agent, scoped_iterable.get(agent), gc.reborrow())
             .unbind()?
             .bind(gc.nogc())
             .into_iterator_record()
@@ -2,21 +2,23 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use core::hash::Hasher;
+use core::{cell::RefCell, hash::Hasher};
 
 use ahash::AHasher;
 
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
-        BuiltinIntrinsic, CollectionIteratorKind, ExceptionType, JsResult, Number, PropertyKey,
-        Realm, Set, SetIterator, String, Value, builders::OrdinaryObjectBuilder, call_function,
-        canonicalize_keyed_collection_key, is_callable, same_value,
+        BuiltinIntrinsic, CollectionIteratorKind, ExceptionType, Function, IteratorRecord,
+        JsResult, Number, Object, PropertyKey, Realm, Set, SetHeapData, SetIterator, String, Value,
+        builders::OrdinaryObjectBuilder, call_function, canonicalize_keyed_collection_key, get,
+        get_iterator_from_method, is_callable, iterator_close_with_value, iterator_step_value,
+        same_value, throw_not_callable, to_boolean, to_integer_or_infinity_f64, to_number,
     },
-    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    engine::{Bindable, GcScope, NoGcScope, Scopable, Scoped},
     heap::{
-        ArenaAccessSoA, ArenaAccessSoAMut, DirectArenaAccessSoA, DirectArenaAccessSoAMut, Heap,
-        IntrinsicFunctionIndexes, PrimitiveHeap, WellKnownSymbols,
+        ArenaAccessSoA, ArenaAccessSoAMut, CreateHeapData, DirectArenaAccessSoA,
+        DirectArenaAccessSoAMut, Heap, IntrinsicFunctionIndexes, PrimitiveHeap, WellKnownSymbols,
     },
 };
 
@@ -40,6 +42,12 @@ impl Builtin for SetPrototypeDelete {
     const LENGTH: u8 = 1;
     const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::delete);
 }
+struct SetPrototypeDifference;
+impl Builtin for SetPrototypeDifference {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.difference;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::difference);
+}
 struct SetPrototypeEntries;
 impl Builtin for SetPrototypeEntries {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.entries;
@@ -58,6 +66,30 @@ impl Builtin for SetPrototypeHas {
     const LENGTH: u8 = 1;
     const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::has);
 }
+struct SetPrototypeIntersection;
+impl Builtin for SetPrototypeIntersection {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.intersection;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::intersection);
+}
+struct SetPrototypeIsDisjointFrom;
+impl Builtin for SetPrototypeIsDisjointFrom {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.isDisjointFrom;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::is_disjoint_from);
+}
+struct SetPrototypeIsSubsetOf;
+impl Builtin for SetPrototypeIsSubsetOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.isSubsetOf;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::is_subset_of);
+}
+struct SetPrototypeIsSupersetOf;
+impl Builtin for SetPrototypeIsSupersetOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.isSupersetOf;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::is_superset_of);
+}
 struct SetPrototypeGetSize;
 impl Builtin for SetPrototypeGetSize {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_size;
@@ -66,6 +98,18 @@ impl Builtin for SetPrototypeGetSize {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::get_size);
 }
 impl BuiltinGetter for SetPrototypeGetSize {}
+struct SetPrototypeSymmetricDifference;
+impl Builtin for SetPrototypeSymmetricDifference {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.symmetricDifference;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::symmetric_difference);
+}
+struct SetPrototypeUnion;
+impl Builtin for SetPrototypeUnion {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.union;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(SetPrototype::union);
+}
 struct SetPrototypeValues;
 impl Builtin for SetPrototypeValues {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.values;
@@ -219,6 +263,95 @@ impl SetPrototype {
         }
     }
 
+    /// ### [24.2.4.5 Set.prototype.difference ( other )](https://tc39.es/ecma262/#sec-set.prototype.difference)
+    fn difference<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let other = arguments.get(0).bind(nogc);
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = require_set_data_internal_slot(agent, this_value, nogc)
+            .unbind()?
+            .bind(nogc);
+        let scoped_o = o.scope(agent, nogc);
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = get_set_record(agent, other.unbind(), gc.reborrow()).unbind()?;
+        // 4. Let resultSetData be a copy of O.[[SetData]].
+        let result = copy_set(agent, scoped_o.get(agent), gc.nogc()).scope(agent, gc.nogc());
+        // 5. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if set_data_size(agent, scoped_o.get(agent)) as f64 <= other_rec.size {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            let this_size = scoped_o.get(agent).get(agent).values.len();
+            // b. Let index be 0.
+            let mut index = 0;
+            // c. Repeat, while index < thisSize,
+            while index < this_size {
+                // i. Let e be resultSetData[index].
+                let e = result.get(agent).get(agent).values[index];
+                // ii. If e is not EMPTY, then
+                if let Some(e) = e {
+                    // 1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+                    let in_other =
+                        call_set_record_has(agent, &other_rec, e.unbind(), gc.reborrow())
+                            .unbind()?;
+                    // 2. If inOther is true, then
+                    if in_other {
+                        // a. Set resultSetData[index] to EMPTY.
+                        set_data_remove(agent, result.get(agent), index);
+                    }
+                }
+                // iii. Set index to index + 1.
+                index += 1;
+            }
+        } else {
+            // 6. Else,
+            // a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+            let IteratorRecord {
+                iterator,
+                next_method,
+            } = get_keys_iterator(agent, &other_rec, gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            let iterator = iterator.scope(agent, gc.nogc());
+            let next_method = next_method.scope(agent, gc.nogc());
+            // b. Let next be NOT-STARTED.
+            // c. Repeat, while next is not DONE,
+            loop {
+                // i. Set next to ? IteratorStepValue(keysIter).
+                let next = iterator_step_value(
+                    agent,
+                    IteratorRecord {
+                        iterator: iterator.get(agent),
+                        next_method: next_method.get(agent),
+                    },
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
+                // ii. If next is not DONE, then
+                let Some(next) = next else {
+                    break;
+                };
+                // 1. Set next to CanonicalizeKeyedCollectionKey(next).
+                // 2. Let valueIndex be SetDataIndex(resultSetData, next).
+                // 3. If valueIndex is not NOT-FOUND, then
+                if let Some(value_index) = set_data_index(agent, result.get(agent), next) {
+                    // a. Set resultSetData[valueIndex] to EMPTY.
+                    set_data_remove(agent, result.get(agent), value_index as usize);
+                }
+            }
+        }
+        // 7. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 8. Set result.[[SetData]] to resultSetData.
+        // 9. Return result.
+        Ok(result.get(agent).into())
+    }
+
     /// ### [24.2.4.6 Set.prototype.entries ( )](https://tc39.es/ecma262/#sec-set.prototype.entries)
     ///
     /// > NOTE: For iteration purposes, a Set appears similar to a Map where
@@ -384,6 +517,316 @@ impl SetPrototype {
         Ok(found.into())
     }
 
+    /// ### [24.2.4.9 Set.prototype.intersection ( other )](https://tc39.es/ecma262/#sec-set.prototype.intersection)
+    fn intersection<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let other = arguments.get(0).bind(nogc);
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = require_set_data_internal_slot(agent, this_value, nogc)
+            .unbind()?
+            .bind(nogc);
+        let scoped_o = o.scope(agent, nogc);
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = get_set_record(agent, other.unbind(), gc.reborrow()).unbind()?;
+        // 4. Let resultSetData be a new empty List.
+        let result = agent
+            .heap
+            .create(SetHeapData::default())
+            .scope(agent, gc.nogc());
+        // 5. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if set_data_size(agent, scoped_o.get(agent)) as f64 <= other_rec.size {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            let mut this_size = scoped_o.get(agent).get(agent).values.len();
+            // b. Let index be 0.
+            let mut index = 0;
+            // c. Repeat, while index < thisSize,
+            while index < this_size {
+                // i. Let e be O.[[SetData]][index].
+                let e = scoped_o.get(agent).get(agent).values[index];
+                // ii. Set index to index + 1.
+                index += 1;
+                // iii. If e is not EMPTY, then
+                let Some(e) = e else {
+                    continue;
+                };
+                let e = e.scope(agent, gc.nogc());
+                // 1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+                let in_other =
+                    call_set_record_has(agent, &other_rec, e.get(agent), gc.reborrow()).unbind()?;
+                // 2. If inOther is true, then
+                if in_other {
+                    // a. NOTE: It is possible for earlier calls to
+                    //    otherRec.[[Has]] to remove and re-add an element of
+                    //    O.[[SetData]], which can cause elements to be visited
+                    //    twice during this iteration.
+                    // b. If SetDataHas(resultSetData, e) is false, then
+                    let e = e.get(agent);
+                    if !set_data_has(agent, result.get(agent), e) {
+                        // i. Append e to resultSetData.
+                        set_data_append(agent, result.get(agent), e);
+                    }
+                }
+                // 3. NOTE: The number of elements in O.[[SetData]] may have
+                //    increased during execution of otherRec.[[Has]].
+                // 4. Set thisSize to the number of elements in O.[[SetData]].
+                this_size = scoped_o.get(agent).get(agent).values.len();
+            }
+        } else {
+            // 6. Else,
+            // a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+            let IteratorRecord {
+                iterator,
+                next_method,
+            } = get_keys_iterator(agent, &other_rec, gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            let iterator = iterator.scope(agent, gc.nogc());
+            let next_method = next_method.scope(agent, gc.nogc());
+            // b. Let next be NOT-STARTED.
+            // c. Repeat, while next is not DONE,
+            loop {
+                // i. Set next to ? IteratorStepValue(keysIter).
+                let next = iterator_step_value(
+                    agent,
+                    IteratorRecord {
+                        iterator: iterator.get(agent),
+                        next_method: next_method.get(agent),
+                    },
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
+                // ii. If next is not DONE, then
+                let Some(next) = next else {
+                    break;
+                };
+                // 1. Set next to CanonicalizeKeyedCollectionKey(next).
+                let next = canonicalize_keyed_collection_key(agent, next);
+                // 2. Let inThis be SetDataHas(O.[[SetData]], next).
+                let in_this = set_data_has(agent, scoped_o.get(agent), next);
+                // 3. If inThis is true, then
+                // a. NOTE: Because other is an arbitrary object, it is
+                //    possible for its "keys" iterator to produce the same
+                //    value more than once.
+                // b. If SetDataHas(resultSetData, next) is false, then
+                if in_this && !set_data_has(agent, result.get(agent), next) {
+                    // i. Append next to resultSetData.
+                    set_data_append(agent, result.get(agent), next);
+                }
+            }
+        }
+        // 7. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 8. Set result.[[SetData]] to resultSetData.
+        // 9. Return result.
+        Ok(result.get(agent).into())
+    }
+
+    /// ### [24.2.4.10 Set.prototype.isDisjointFrom ( other )](https://tc39.es/ecma262/#sec-set.prototype.isdisjointfrom)
+    fn is_disjoint_from<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let other = arguments.get(0).bind(nogc);
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = require_set_data_internal_slot(agent, this_value, nogc)
+            .unbind()?
+            .bind(nogc);
+        let scoped_o = o.scope(agent, nogc);
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = get_set_record(agent, other.unbind(), gc.reborrow()).unbind()?;
+        // 4. If SetDataSize(O.[[SetData]]) ≤ otherRec.[[Size]], then
+        if set_data_size(agent, scoped_o.get(agent)) as f64 <= other_rec.size {
+            // a. Let thisSize be the number of elements in O.[[SetData]].
+            let mut this_size = scoped_o.get(agent).get(agent).values.len();
+            // b. Let index be 0.
+            let mut index = 0;
+            // c. Repeat, while index < thisSize,
+            while index < this_size {
+                // i. Let e be O.[[SetData]][index].
+                let e = scoped_o.get(agent).get(agent).values[index];
+                // ii. Set index to index + 1.
+                index += 1;
+                // iii. If e is not EMPTY, then
+                let Some(e) = e else {
+                    continue;
+                };
+                // 1. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+                let in_other =
+                    call_set_record_has(agent, &other_rec, e.unbind(), gc.reborrow()).unbind()?;
+                // 2. If inOther is true, return false.
+                if in_other {
+                    return Ok(false.into());
+                }
+                // 3. NOTE: The number of elements in O.[[SetData]] may have
+                //    increased during execution of otherRec.[[Has]].
+                // 4. Set thisSize to the number of elements in O.[[SetData]].
+                this_size = scoped_o.get(agent).get(agent).values.len();
+            }
+        } else {
+            // 5. Else,
+            // a. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+            let IteratorRecord {
+                iterator,
+                next_method,
+            } = get_keys_iterator(agent, &other_rec, gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc());
+            let iterator = iterator.scope(agent, gc.nogc());
+            let next_method = next_method.scope(agent, gc.nogc());
+            // b. Let next be NOT-STARTED.
+            // c. Repeat, while next is not DONE,
+            loop {
+                // i. Set next to ? IteratorStepValue(keysIter).
+                let next = iterator_step_value(
+                    agent,
+                    IteratorRecord {
+                        iterator: iterator.get(agent),
+                        next_method: next_method.get(agent),
+                    },
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
+                // ii. If next is not DONE, then
+                let Some(next) = next else {
+                    break;
+                };
+                // 1. If SetDataHas(O.[[SetData]], next) is true, then
+                if set_data_has(agent, scoped_o.get(agent), next) {
+                    // a. Perform ? IteratorClose(keysIter, NormalCompletion(UNUSED)).
+                    // b. Return false.
+                    return iterator_close_with_value(agent, iterator.get(agent), false.into(), gc);
+                }
+            }
+        }
+        // 6. Return true.
+        Ok(true.into())
+    }
+
+    /// ### [24.2.4.11 Set.prototype.isSubsetOf ( other )](https://tc39.es/ecma262/#sec-set.prototype.issubsetof)
+    fn is_subset_of<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let other = arguments.get(0).bind(nogc);
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = require_set_data_internal_slot(agent, this_value, nogc)
+            .unbind()?
+            .bind(nogc);
+        let scoped_o = o.scope(agent, nogc);
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = get_set_record(agent, other.unbind(), gc.reborrow()).unbind()?;
+        // 4. If SetDataSize(O.[[SetData]]) > otherRec.[[Size]], return false.
+        if set_data_size(agent, scoped_o.get(agent)) as f64 > other_rec.size {
+            return Ok(false.into());
+        }
+        // 5. Let thisSize be the number of elements in O.[[SetData]].
+        let mut this_size = scoped_o.get(agent).get(agent).values.len();
+        // 6. Let index be 0.
+        let mut index = 0;
+        // 7. Repeat, while index < thisSize,
+        while index < this_size {
+            // a. Let e be O.[[SetData]][index].
+            let e = scoped_o.get(agent).get(agent).values[index];
+            // b. Set index to index + 1.
+            index += 1;
+            // c. If e is not EMPTY, then
+            let Some(e) = e else {
+                continue;
+            };
+            // i. Let inOther be ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « e »)).
+            let in_other =
+                call_set_record_has(agent, &other_rec, e.unbind(), gc.reborrow()).unbind()?;
+            // ii. If inOther is false, return false.
+            if !in_other {
+                return Ok(false.into());
+            }
+            // iii. NOTE: The number of elements in O.[[SetData]] may have
+            //      increased during execution of otherRec.[[Has]].
+            // iv. Set thisSize to the number of elements in O.[[SetData]].
+            this_size = scoped_o.get(agent).get(agent).values.len();
+        }
+        // 8. Return true.
+        Ok(true.into())
+    }
+
+    /// ### [24.2.4.12 Set.prototype.isSupersetOf ( other )](https://tc39.es/ecma262/#sec-set.prototype.issupersetof)
+    fn is_superset_of<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let other = arguments.get(0).bind(nogc);
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = require_set_data_internal_slot(agent, this_value, nogc)
+            .unbind()?
+            .bind(nogc);
+        let scoped_o = o.scope(agent, nogc);
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = get_set_record(agent, other.unbind(), gc.reborrow()).unbind()?;
+        // 4. If SetDataSize(O.[[SetData]]) < otherRec.[[Size]], return false.
+        if (set_data_size(agent, scoped_o.get(agent)) as f64) < other_rec.size {
+            return Ok(false.into());
+        }
+        // 5. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+        let IteratorRecord {
+            iterator,
+            next_method,
+        } = get_keys_iterator(agent, &other_rec, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        let iterator = iterator.scope(agent, gc.nogc());
+        let next_method = next_method.scope(agent, gc.nogc());
+        // 6. Let next be NOT-STARTED.
+        // 7. Repeat, while next is not DONE,
+        loop {
+            // a. Set next to ? IteratorStepValue(keysIter).
+            let next = iterator_step_value(
+                agent,
+                IteratorRecord {
+                    iterator: iterator.get(agent),
+                    next_method: next_method.get(agent),
+                },
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            // b. If next is not DONE, then
+            let Some(next) = next else {
+                break;
+            };
+            // i. If SetDataHas(O.[[SetData]], next) is false, then
+            if !set_data_has(agent, scoped_o.get(agent), next) {
+                // 1. Perform ? IteratorClose(keysIter, NormalCompletion(UNUSED)).
+                // 2. Return false.
+                return iterator_close_with_value(agent, iterator.get(agent), false.into(), gc);
+            }
+        }
+        // 8. Return true.
+        Ok(true.into())
+    }
+
     /// ### [24.2.4.14 get Set.prototype.size](https://tc39.es/ecma262/#sec-get-set.prototype.size)
     ///
     /// Set.prototype.size is an accessor property whose set accessor function
@@ -405,6 +848,138 @@ impl SetPrototype {
         Ok(Number::from(size).into())
     }
 
+    /// ### [24.2.4.15 Set.prototype.symmetricDifference ( other )](https://tc39.es/ecma262/#sec-set.prototype.symmetricdifference)
+    fn symmetric_difference<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let other = arguments.get(0).bind(nogc);
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = require_set_data_internal_slot(agent, this_value, nogc)
+            .unbind()?
+            .bind(nogc);
+        let scoped_o = o.scope(agent, nogc);
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = get_set_record(agent, other.unbind(), gc.reborrow()).unbind()?;
+        // 4. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+        let IteratorRecord {
+            iterator,
+            next_method,
+        } = get_keys_iterator(agent, &other_rec, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        let iterator = iterator.scope(agent, gc.nogc());
+        let next_method = next_method.scope(agent, gc.nogc());
+        // 5. Let resultSetData be a copy of O.[[SetData]].
+        let result = copy_set(agent, scoped_o.get(agent), gc.nogc()).scope(agent, gc.nogc());
+        // 6. Let next be NOT-STARTED.
+        // 7. Repeat, while next is not DONE,
+        loop {
+            // a. Set next to ? IteratorStepValue(keysIter).
+            let next = iterator_step_value(
+                agent,
+                IteratorRecord {
+                    iterator: iterator.get(agent),
+                    next_method: next_method.get(agent),
+                },
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            // b. If next is not DONE, then
+            let Some(next) = next else {
+                break;
+            };
+            // i. Set next to CanonicalizeKeyedCollectionKey(next).
+            let next = canonicalize_keyed_collection_key(agent, next);
+            // ii. Let resultIndex be SetDataIndex(resultSetData, next).
+            // iii. If resultIndex is NOT-FOUND, let alreadyInResult be false.
+            //      Otherwise let alreadyInResult be true.
+            let result_index = set_data_index(agent, result.get(agent), next);
+            // iv. If SetDataHas(O.[[SetData]], next) is true, then
+            if set_data_has(agent, scoped_o.get(agent), next) {
+                // 1. If alreadyInResult is true, set resultSetData[resultIndex] to EMPTY.
+                if let Some(result_index) = result_index {
+                    set_data_remove(agent, result.get(agent), result_index as usize);
+                }
+            } else if result_index.is_none() {
+                // v. Else,
+                // 1. If alreadyInResult is false, append next to resultSetData.
+                set_data_append(agent, result.get(agent), next);
+            }
+        }
+        // 8. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 9. Set result.[[SetData]] to resultSetData.
+        // 10. Return result.
+        Ok(result.get(agent).into())
+    }
+
+    /// ### [24.2.4.16 Set.prototype.union ( other )](https://tc39.es/ecma262/#sec-set.prototype.union)
+    fn union<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let this_value = this_value.bind(nogc);
+        let other = arguments.get(0).bind(nogc);
+        // 1. Let O be the this value.
+        // 2. Perform ? RequireInternalSlot(O, [[SetData]]).
+        let o = require_set_data_internal_slot(agent, this_value, nogc)
+            .unbind()?
+            .bind(nogc);
+        let scoped_o = o.scope(agent, nogc);
+        // 3. Let otherRec be ? GetSetRecord(other).
+        let other_rec = get_set_record(agent, other.unbind(), gc.reborrow()).unbind()?;
+        // 4. Let keysIter be ? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]]).
+        let IteratorRecord {
+            iterator,
+            next_method,
+        } = get_keys_iterator(agent, &other_rec, gc.reborrow())
+            .unbind()?
+            .bind(gc.nogc());
+        let iterator = iterator.scope(agent, gc.nogc());
+        let next_method = next_method.scope(agent, gc.nogc());
+        // 5. Let resultSetData be a copy of O.[[SetData]].
+        let result = copy_set(agent, scoped_o.get(agent), gc.nogc()).scope(agent, gc.nogc());
+        // 6. Let next be NOT-STARTED.
+        // 7. Repeat, while next is not DONE,
+        loop {
+            // a. Set next to ? IteratorStepValue(keysIter).
+            let next = iterator_step_value(
+                agent,
+                IteratorRecord {
+                    iterator: iterator.get(agent),
+                    next_method: next_method.get(agent),
+                },
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            // b. If next is not DONE, then
+            let Some(next) = next else {
+                break;
+            };
+            // i. Set next to CanonicalizeKeyedCollectionKey(next).
+            let next = canonicalize_keyed_collection_key(agent, next);
+            // ii. If SetDataHas(resultSetData, next) is false, then
+            if !set_data_has(agent, result.get(agent), next) {
+                // 1. Append next to resultSetData.
+                set_data_append(agent, result.get(agent), next);
+            }
+        }
+        // 8. Let result be OrdinaryObjectCreate(%Set.prototype%, « [[SetData]] »).
+        // 9. Set result.[[SetData]] to resultSetData.
+        // 10. Return result.
+        Ok(result.get(agent).into())
+    }
+
     /// ### [24.2.4.17 Set.prototype.values ( )](https://tc39.es/ecma262/#sec-set.prototype.values)
     fn values<'gc>(
         agent: &mut Agent,
@@ -431,15 +1006,20 @@ impl SetPrototype {
         let set_prototype_values = intrinsics.set_prototype_values();
 
         OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
-            .with_property_capacity(12)
+            .with_property_capacity(19)
             .with_prototype(object_prototype)
             .with_builtin_function_property::<SetPrototypeAdd>()
             .with_builtin_function_property::<SetPrototypeClear>()
             .with_constructor_property(set_constructor)
             .with_builtin_function_property::<SetPrototypeDelete>()
+            .with_builtin_function_property::<SetPrototypeDifference>()
             .with_builtin_function_property::<SetPrototypeEntries>()
             .with_builtin_function_property::<SetPrototypeForEach>()
             .with_builtin_function_property::<SetPrototypeHas>()
+            .with_builtin_function_property::<SetPrototypeIntersection>()
+            .with_builtin_function_property::<SetPrototypeIsDisjointFrom>()
+            .with_builtin_function_property::<SetPrototypeIsSubsetOf>()
+            .with_builtin_function_property::<SetPrototypeIsSupersetOf>()
             .with_property(|builder| {
                 builder
                     .with_key(BUILTIN_STRING_MEMORY.keys.to_property_key())
@@ -449,6 +1029,8 @@ impl SetPrototype {
                     .build()
             })
             .with_builtin_function_getter_property::<SetPrototypeGetSize>()
+            .with_builtin_function_property::<SetPrototypeSymmetricDifference>()
+            .with_builtin_function_property::<SetPrototypeUnion>()
             .with_builtin_intrinsic_function_property::<SetPrototypeValues>()
             .with_property(|builder| {
                 builder
@@ -485,3 +1067,276 @@ fn require_set_data_internal_slot<'a>(
         )),
     }
 }
+
+/// ### [24.2.1.1 Set Records](https://tc39.es/ecma262/#sec-set-records)
+///
+/// A Set Record is a Record value used to encapsulate the interface of a Set
+/// or similar object.
+struct SetRecord<'scope> {
+    /// ### \[\[SetObject]]
+    set_object: Scoped<'scope, Object<'static>>,
+    /// ### \[\[Size]]
+    ///
+    /// The reported size of the object. This is a non-negative integer or
+    /// +∞.
+    size: f64,
+    /// ### \[\[Has]]
+    has: Scoped<'scope, Function<'static>>,
+    /// ### \[\[Keys]]
+    keys: Scoped<'scope, Function<'static>>,
+}
+
+// SAFETY: Scoped handles are not bound to the garbage collector lifetime.
+unsafe impl<'scope> Bindable for SetRecord<'scope> {
+    type Of<'a> = SetRecord<'scope>;
+
+    #[inline(always)]
+    fn unbind(self) -> Self::Of<'static> {
+        self
+    }
+
+    #[inline(always)]
+    fn bind<'a>(self, _gc: NoGcScope<'a, '_>) -> Self::Of<'a> {
+        self
+    }
+}
+
+/// ### [24.2.1.2 GetSetRecord ( obj )](https://tc39.es/ecma262/#sec-getsetrecord)
+fn get_set_record<'gc, 'scope>(
+    agent: &mut Agent,
+    obj: Value,
+    mut gc: GcScope<'gc, 'scope>,
+) -> JsResult<'gc, SetRecord<'scope>> {
+    let obj = obj.bind(gc.nogc());
+    // 1. If obj is not an Object, throw a TypeError exception.
+    let Ok(obj) = Object::try_from(obj) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Set-like argument is not an object",
+            gc.into_nogc(),
+        ));
+    };
+    let set_object = obj.scope(agent, gc.nogc());
+    // 2. Let rawSize be ? Get(obj, "size").
+    let raw_size = get(
+        agent,
+        obj.unbind(),
+        BUILTIN_STRING_MEMORY.size.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 3. Let numSize be ? ToNumber(rawSize).
+    // 4. NOTE: If rawSize is undefined, then numSize will be NaN.
+    let num_size = to_number(agent, raw_size.unbind(), gc.reborrow())
+        .unbind()?
+        .into_f64(agent);
+    // 5. If numSize is NaN, throw a TypeError exception.
+    if num_size.is_nan() {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Set-like size is not a number",
+            gc.into_nogc(),
+        ));
+    }
+    // 6. Let intSize be ! ToIntegerOrInfinity(numSize).
+    let int_size = to_integer_or_infinity_f64(num_size);
+    // 7. If intSize < 0, throw a RangeError exception.
+    if int_size < 0.0 {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Set-like size is negative",
+            gc.into_nogc(),
+        ));
+    }
+    // 8. Let has be ? Get(obj, "has").
+    let has = get(
+        agent,
+        set_object.get(agent),
+        BUILTIN_STRING_MEMORY.has.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 9. If IsCallable(has) is false, throw a TypeError exception.
+    let Some(has) = is_callable(has, gc.nogc()) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Set-like has method is not callable",
+            gc.into_nogc(),
+        ));
+    };
+    let has = has.scope(agent, gc.nogc());
+    // 10. Let keys be ? Get(obj, "keys").
+    let keys = get(
+        agent,
+        set_object.get(agent),
+        BUILTIN_STRING_MEMORY.keys.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    // 11. If IsCallable(keys) is false, throw a TypeError exception.
+    let Some(keys) = is_callable(keys, gc.nogc()) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Set-like keys method is not callable",
+            gc.into_nogc(),
+        ));
+    };
+    let keys = keys.scope(agent, gc.nogc());
+    // 12. Return a new Set Record { [[SetObject]]: obj, [[Size]]: intSize, [[Has]]: has, [[Keys]]: keys }.
+    Ok(SetRecord {
+        set_object,
+        size: int_size,
+        has,
+        keys,
+    })
+}
+
+/// Performs `ToBoolean(? Call(otherRec.[[Has]], otherRec.[[SetObject]], « value »))`.
+fn call_set_record_has<'gc>(
+    agent: &mut Agent,
+    other_rec: &SetRecord,
+    value: Value,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, bool> {
+    let result = call_function(
+        agent,
+        other_rec.has.get(agent),
+        other_rec.set_object.get(agent).into(),
+        Some(ArgumentsList::from_mut_slice(&mut [value.unbind()])),
+        gc,
+    )?;
+    Ok(to_boolean(agent, result))
+}
+
+/// Performs `? GetIteratorFromMethod(otherRec.[[SetObject]], otherRec.[[Keys]])`.
+fn get_keys_iterator<'gc>(
+    agent: &mut Agent,
+    other_rec: &SetRecord,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, IteratorRecord<'gc>> {
+    let keys_iter = get_iterator_from_method(
+        agent,
+        other_rec.set_object.get(agent).into(),
+        other_rec.keys.get(agent),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let Some(keys_iter) = keys_iter.bind(gc).into_iterator_record() else {
+        return Err(throw_not_callable(agent, gc));
+    };
+    Ok(keys_iter)
+}
+
+fn hash_value(primitive_heap: &PrimitiveHeap, value: Value) -> u64 {
+    let mut hasher = AHasher::default();
+    value.hash(primitive_heap, &mut hasher);
+    hasher.finish()
+}
+
+/// ### [24.2.1.3 SetDataHas ( setData, value )](https://tc39.es/ecma262/#sec-setdatahas)
+fn set_data_has(agent: &Agent, set: Set, value: Value) -> bool {
+    // 1. If SetDataIndex(setData, value) is NOT-FOUND, return false.
+    // 2. Return true.
+    set_data_index(agent, set, value).is_some()
+}
+
+/// ### [24.2.1.4 SetDataIndex ( setData, value )](https://tc39.es/ecma262/#sec-setdataindex)
+fn set_data_index(agent: &Agent, set: Set, value: Value) -> Option<u32> {
+    let Heap {
+        bigints,
+        numbers,
+        strings,
+        sets,
+        ..
+    } = &agent.heap;
+    let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
+    // 1. Set value to CanonicalizeKeyedCollectionKey(value).
+    let value = canonicalize_keyed_collection_key(&primitive_heap, value);
+    let set_heap_data = set.get_direct(sets);
+    let values = set_heap_data.values;
+    let set_data = set_heap_data.set_data.borrow();
+    // 2. Let size be the number of elements in setData.
+    // 3. Let index be 0.
+    // 4. Repeat, while index < size,
+    // a. Let e be setData[index].
+    // b. If e is not EMPTY and e is value, then
+    // i. Return index.
+    // c. Set index to index + 1.
+    // 5. Return NOT-FOUND.
+    set_data
+        .find(hash_value(&primitive_heap, value), |hash_equal_index| {
+            let found_value = values[*hash_equal_index as usize].unwrap();
+            // Quick check: Equal values have the same value.
+            found_value == value || same_value(&primitive_heap, found_value, value)
+        })
+        .copied()
+}
+
+/// ### [24.2.1.5 SetDataSize ( setData )](https://tc39.es/ecma262/#sec-setdatasize)
+fn set_data_size(agent: &Agent, set: Set) -> usize {
+    set.get(agent).set_data.borrow().len()
+}
+
+/// Appends a canonicalized value to the set's \[\[SetData]]. The value must
+/// not already be present in the set.
+fn set_data_append(agent: &mut Agent, set: Set, value: Value) {
+    let Heap {
+        bigints,
+        numbers,
+        strings,
+        sets,
+        ..
+    } = &mut agent.heap;
+    let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
+    let set_heap_data = set.get_direct_mut(sets);
+    let values = set_heap_data.values;
+    let set_data = set_heap_data.set_data.get_mut();
+    let index = u32::try_from(values.len()).unwrap();
+    set_data.insert_unique(hash_value(&primitive_heap, value), index, |index_to_hash| {
+        hash_value(&primitive_heap, values[*index_to_hash as usize].unwrap())
+    });
+    values.push(Some(value.unbind()));
+}
+
+/// Replaces the element at the given index of the set's \[\[SetData]] with
+/// EMPTY.
+fn set_data_remove(agent: &mut Agent, set: Set, index: usize) {
+    let Heap {
+        bigints,
+        numbers,
+        strings,
+        sets,
+        ..
+    } = &mut agent.heap;
+    let primitive_heap = PrimitiveHeap::new(bigints, numbers, strings);
+    let set_heap_data = set.get_direct_mut(sets);
+    let values = set_heap_data.values;
+    let set_data = set_heap_data.set_data.get_mut();
+    let Some(value) = values[index].take() else {
+        return;
+    };
+    if let Ok(entry) = set_data.find_entry(hash_value(&primitive_heap, value), |equal_index| {
+        *equal_index as usize == index
+    }) {
+        entry.remove();
+    }
+}
+
+/// Creates a new Set object with a copy of the given set's \[\[SetData]].
+fn copy_set<'a>(agent: &mut Agent, set: Set, gc: NoGcScope<'a, '_>) -> Set<'a> {
+    let data = set.get(agent);
+    let set_data = RefCell::new(data.set_data.borrow().clone());
+    let values = data.values.clone();
+    agent
+        .heap
+        .create(SetHeapData {
+            set_data,
+            values,
+            ..Default::default()
+        })
+        .bind(gc)
+}
@@ -11,6 +11,11 @@ use crate::{
     engine::{Bindable, GcScope, NoGcScope},
     heap::WellKnownSymbols,
 };
+#[cfg(feature = "proposal-upsert")]
+use crate::{
+    ecmascript::{call_function, is_callable},
+    engine::Scopable,
+};
 
 pub(crate) struct WeakMapPrototype;
 
@@ -26,6 +31,22 @@ impl Builtin for WeakMapPrototypeGet {
     const LENGTH: u8 = 1;
     const BEHAVIOUR: Behaviour = Behaviour::Regular(WeakMapPrototype::get);
 }
+#[cfg(feature = "proposal-upsert")]
+struct WeakMapPrototypeGetOrInsert;
+#[cfg(feature = "proposal-upsert")]
+impl Builtin for WeakMapPrototypeGetOrInsert {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.getOrInsert;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(WeakMapPrototype::get_or_insert);
+}
+#[cfg(feature = "proposal-upsert")]
+struct WeakMapPrototypeGetOrInsertComputed;
+#[cfg(feature = "proposal-upsert")]
+impl Builtin for WeakMapPrototypeGetOrInsertComputed {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.getOrInsertComputed;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(WeakMapPrototype::get_or_insert_computed);
+}
 struct WeakMapPrototypeHas;
 impl Builtin for WeakMapPrototypeHas {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.has;
@@ -96,6 +117,99 @@ impl WeakMapPrototype {
         Ok(m.get_v(agent, key).unwrap_or(Value::Undefined))
     }
 
+    #[cfg(feature = "proposal-upsert")]
+    /// ### [24.3.3.4 WeakMap.prototype.getOrInsert ( key, value )](https://tc39.es/proposal-upsert/#sec-weakmap.prototype.getorinsert)
+    fn get_or_insert<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let key = arguments.get(0).bind(gc);
+        let value = arguments.get(1).bind(gc);
+        // 1. Let M be the this value.
+        let m = this_value.bind(gc);
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let m = require_internal_slot_weak_map_data(agent, m, gc)?;
+        // 3. If CanBeHeldWeakly(key) is false, throw a TypeError exception.
+        let Some(key) = can_be_held_weakly(agent, key) else {
+            return Err(throw_not_weak_key_error(agent, key, gc));
+        };
+        // 4. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
+        // a. If p.[[Key]] is not EMPTY and SameValue(p.[[Key]], key) is true, return p.[[Value]].
+        // 5. Let p be the Record { [[Key]]: key, [[Value]]: value }.
+        // 6. Append p to M.[[WeakMapData]].
+        // 7. Return value.
+        Ok(m.get_or_insert(agent, key, value))
+    }
+
+    #[cfg(feature = "proposal-upsert")]
+    /// ### [24.3.3.5 WeakMap.prototype.getOrInsertComputed ( key, callbackfn )](https://tc39.es/proposal-upsert/#sec-weakmap.prototype.getorinsertcomputed)
+    fn get_or_insert_computed<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let nogc = gc.nogc();
+        let key = arguments.get(0).bind(nogc);
+        let callback_fn = arguments.get(1).bind(nogc);
+        // 1. Let M be the this value.
+        let m = this_value.bind(nogc);
+        // 2. Perform ? RequireInternalSlot(M, [[WeakMapData]]).
+        let m = require_internal_slot_weak_map_data(agent, m, nogc)
+            .unbind()?
+            .bind(nogc);
+        // 3. If CanBeHeldWeakly(key) is false, throw a TypeError exception.
+        let Some(key) = can_be_held_weakly(agent, key) else {
+            return Err(throw_not_weak_key_error(
+                agent,
+                key.unbind(),
+                gc.into_nogc(),
+            ));
+        };
+        // 4. If IsCallable(callbackfn) is false, throw a TypeError exception.
+        let Some(callback_fn) = is_callable(callback_fn, nogc) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Callback function is not a function",
+                gc.into_nogc(),
+            ));
+        };
+        // 5. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
+        // a. If p.[[Key]] is not EMPTY and SameValue(p.[[Key]], key) is true, return p.[[Value]].
+        if let Some(value) = m.get_v(agent, key) {
+            return Ok(value.unbind().bind(gc.into_nogc()));
+        }
+        let scoped_m = m.scope(agent, nogc);
+        let scoped_key = key.scope(agent, nogc);
+        // 6. Let value be ? Call(callbackfn, undefined, « key »).
+        let value = call_function(
+            agent,
+            callback_fn.unbind(),
+            Value::Undefined,
+            Some(ArgumentsList::from_mut_slice(&mut [key.unbind().into()])),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let value = value.bind(gc);
+        // SAFETY: not shared.
+        let m = unsafe { scoped_m.take(agent) }.bind(gc);
+        // SAFETY: not shared.
+        let key = unsafe { scoped_key.take(agent) }.bind(gc);
+        // 7. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
+        // a. If p.[[Key]] is not EMPTY and SameValue(p.[[Key]], key) is true, then
+        // i. Set p.[[Value]] to value.
+        // ii. Return value.
+        // 8. Let p be the Record { [[Key]]: key, [[Value]]: value }.
+        // 9. Append p to M.[[WeakMapData]].
+        m.set(agent, key, value);
+        // 10. Return value.
+        Ok(value)
+    }
+
     /// ### [24.3.3.4 WeakMap.prototype.has ( key )](https://tc39.es/ecma262/#sec-weakmap.prototype.has)
     fn has<'gc>(
         agent: &mut Agent,
@@ -155,12 +269,24 @@ impl WeakMapPrototype {
         let this = intrinsics.weak_map_prototype();
         let weak_map_constructor = intrinsics.weak_map();
 
-        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
-            .with_property_capacity(6)
+        let mut property_capacity = 6;
+        if cfg!(feature = "proposal-upsert") {
+            property_capacity += 2;
+        }
+
+        let builder = OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(property_capacity)
             .with_prototype(object_prototype)
             .with_constructor_property(weak_map_constructor)
             .with_builtin_function_property::<WeakMapPrototypeDelete>()
-            .with_builtin_function_property::<WeakMapPrototypeGet>()
+            .with_builtin_function_property::<WeakMapPrototypeGet>();
+
+        #[cfg(feature = "proposal-upsert")]
+        let builder = builder
+            .with_builtin_function_property::<WeakMapPrototypeGetOrInsert>()
+            .with_builtin_function_property::<WeakMapPrototypeGetOrInsertComputed>();
+
+        builder
             .with_builtin_function_property::<WeakMapPrototypeHas>()
             .with_builtin_function_property::<WeakMapPrototypeSet>()
             .with_property(|builder| {
@@ -127,7 +127,7 @@ fn weak_set_constructor_slow_path<'a>(
         iterator,
         next_method,
         ..
-    }) = get_iterator(agent, scoped_iterable.get(agent), false, gc.reborrow())
+    }) = get_iterator(agent, scoped_iterable.get(agent), gc.reborrow())
         .unbind()?
         .bind(gc.nogc())
         .into_iterator_record()
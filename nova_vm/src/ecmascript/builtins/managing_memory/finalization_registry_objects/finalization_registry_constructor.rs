@@ -98,7 +98,9 @@ impl FinalizationRegistryConstructor {
             Function::BuiltinPromiseFinallyFunction(_) => {
                 unreachable!("builtin promise finally function constructing FinalizationRegistry")
             }
-            Function::BuiltinProxyRevokerFunction => todo!(),
+            Function::BuiltinProxyRevokerFunction(_) => {
+                unreachable!("builtin proxy revoker function constructing FinalizationRegistry")
+            }
         };
         // 6. Set finalizationRegistry.[[CleanupCallback]] to
         //    HostMakeJobCallback(cleanupCallback).
@@ -16,9 +16,9 @@ use crate::{
     },
     engine::Bindable,
     heap::{
-        ArenaAccessSoA, ArenaAccessSoAMut, CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep,
-        HeapSweepWeakReference, PrimitiveHeapAccess, WorkQueues, arena_vec_access,
-        {BaseIndex, HeapIndexHandle},
+        Arena, ArenaAccessSoA, ArenaAccessSoAMut, CompactionLists, CreateHeapData, Heap,
+        HeapMarkAndSweep, HeapSweepWeakReference, PrimitiveHeapAccess, WorkQueues,
+        arena_vec_access, {BaseIndex, HeapIndexHandle},
     },
 };
 
@@ -75,7 +75,7 @@ impl<'gc> Map<'gc> {
 
     pub(crate) fn get_map_data<'soa>(
         self,
-        maps: &'soa mut SoAVec<MapHeapData<'static>>,
+        maps: &'soa mut Arena<SoAVec<MapHeapData<'static>>>,
         arena: &impl PrimitiveHeapAccess,
     ) -> (
         &'soa HashTable<u32>,
@@ -95,7 +95,7 @@ impl<'gc> Map<'gc> {
 
     pub(crate) fn get_map_data_mut<'soa>(
         self,
-        maps: &'soa mut SoAVec<MapHeapData<'static>>,
+        maps: &'soa mut Arena<SoAVec<MapHeapData<'static>>>,
         arena: &impl PrimitiveHeapAccess,
     ) -> (
         &'soa mut HashTable<u32>,
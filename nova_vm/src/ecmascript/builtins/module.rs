@@ -3,6 +3,7 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 mod data;
+mod deferred_namespace;
 
 pub(crate) use data::*;
 
@@ -11,10 +12,10 @@ use std::ops::ControlFlow;
 use crate::{
     ecmascript::{
         AbstractModule, AbstractModuleMethods, AbstractModuleSlots, Agent, BUILTIN_STRING_MEMORY,
-        ExceptionType, InternalMethods, InternalSlots, JsResult, Object, OrdinaryObject,
-        PropertyDescriptor, PropertyKey, ResolvedBinding, SetResult, String, TryError,
-        TryGetResult, TryHasResult, TryResult, Value, get_module_namespace, object_handle,
-        same_value, throw_uninitialized_binding,
+        ExceptionType, ImportPhase, InternalMethods, InternalSlots, JsResult, Object,
+        OrdinaryObject, PropertyDescriptor, PropertyKey, ResolvedBinding, SetResult, String,
+        TryError, TryGetResult, TryHasResult, TryResult, Value, get_module_namespace,
+        object_handle, same_value, throw_uninitialized_binding, unwrap_try,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable},
     heap::{
@@ -142,7 +143,7 @@ impl<'a> InternalMethods<'a> for Module<'a> {
                 // 1. If P is a Symbol, return OrdinaryGetOwnProperty(O, P).
                 if symbol == WellKnownSymbols::ToStringTag.into() {
                     TryResult::Continue(Some(PropertyDescriptor {
-                        value: Some(BUILTIN_STRING_MEMORY.Module.into()),
+                        value: Some(self.to_string_tag(agent).into()),
                         writable: Some(false),
                         get: None,
                         set: None,
@@ -155,6 +156,13 @@ impl<'a> InternalMethods<'a> for Module<'a> {
             }
             PropertyKey::PrivateName(_) => unreachable!(),
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
+                // 1. If IsSymbolLikeNamespaceKey(P, O), return
+                //    OrdinaryGetOwnProperty(O, P).
+                if self.is_symbol_like_namespace_key(agent, property_key) {
+                    return TryResult::Continue(None);
+                }
+                // 2. Let exports be ? GetModuleExportsList(O).
+                self.try_get_module_exports_list(agent)?;
                 let key = match property_key {
                     PropertyKey::SmallString(data) => String::SmallString(data),
                     PropertyKey::String(data) => String::String(data),
@@ -195,7 +203,7 @@ impl<'a> InternalMethods<'a> for Module<'a> {
         self,
         agent: &mut Agent,
         property_key: PropertyKey,
-        gc: GcScope<'gc, '_>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Option<PropertyDescriptor<'gc>>> {
         let property_key = property_key.bind(gc.nogc());
         match property_key {
@@ -203,7 +211,7 @@ impl<'a> InternalMethods<'a> for Module<'a> {
                 // 1. If P is a Symbol, return OrdinaryGetOwnProperty(O, P).
                 if symbol == WellKnownSymbols::ToStringTag.into() {
                     Ok(Some(PropertyDescriptor {
-                        value: Some(BUILTIN_STRING_MEMORY.Module.into()),
+                        value: Some(self.to_string_tag(agent).into()),
                         writable: Some(false),
                         get: None,
                         set: None,
@@ -216,6 +224,17 @@ impl<'a> InternalMethods<'a> for Module<'a> {
             }
             PropertyKey::PrivateName(_) => unreachable!(),
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
+                // 1. If IsSymbolLikeNamespaceKey(P, O), return
+                //    OrdinaryGetOwnProperty(O, P).
+                if self.is_symbol_like_namespace_key(agent, property_key) {
+                    return Ok(None);
+                }
+                // 2. Let exports be ? GetModuleExportsList(O).
+                let (o, property_key) = self
+                    .get_module_exports_list(agent, Some(property_key.unbind()), gc.reborrow())
+                    .unbind()?
+                    .bind(gc.nogc());
+                let property_key = property_key.unwrap();
                 let key = match property_key {
                     PropertyKey::SmallString(data) => String::SmallString(data),
                     PropertyKey::String(data) => String::String(data),
@@ -224,15 +243,15 @@ impl<'a> InternalMethods<'a> for Module<'a> {
                     }
                     PropertyKey::Symbol(_) | PropertyKey::PrivateName(_) => unreachable!(),
                 };
-                // 2. Let exports be O.[[Exports]].
-                let exports: &[String] = &self.get(agent).exports;
+                let exports: &[String] = &o.get(agent).exports;
                 let exports_contains_p = exports.contains(&key);
                 // 3. If exports does not contain P, return undefined.
                 if !exports_contains_p {
                     Ok(None)
                 } else {
                     // 4. Let value be ? O.[[Get]](P, O).
-                    let value = self.internal_get(agent, property_key.unbind(), self.into(), gc)?;
+                    let o = o.unbind();
+                    let value = o.internal_get(agent, property_key.unbind(), o.into(), gc)?;
                     // 5. Return PropertyDescriptor { [[Value]]: value, [[Writable]]: true, [[Enumerable]]: true, [[Configurable]]: false }.
                     Ok(Some(PropertyDescriptor {
                         value: Some(value.unbind()),
@@ -260,13 +279,12 @@ impl<'a> InternalMethods<'a> for Module<'a> {
             PropertyKey::Symbol(symbol) => {
                 // 1. If P is a Symbol, return ! OrdinaryDefineOwnProperty(O, P, Desc).
                 if symbol == WellKnownSymbols::ToStringTag.into() {
+                    let tag = self.to_string_tag(agent);
                     // Note: it's always okay for a field to not exist on the
                     // descriptor. It just means that the defineOwnProperty
                     // isn't trying to change it. Hence the map_or checks below.
                     TryResult::Continue(
-                        property_descriptor
-                            .value
-                            .is_none_or(|v| v == BUILTIN_STRING_MEMORY.Module.into())
+                        property_descriptor.value.is_none_or(|v| v == tag.into())
                             && property_descriptor.writable.is_none_or(|v| !v)
                             && property_descriptor.get.is_none()
                             && property_descriptor.set.is_none()
@@ -331,9 +349,8 @@ impl<'a> InternalMethods<'a> for Module<'a> {
                     // descriptor. It just means that the defineOwnProperty
                     // isn't trying to change it. Hence the is_none_or usage
                     // below.
-                    Ok(property_descriptor
-                        .value
-                        .is_none_or(|v| v == BUILTIN_STRING_MEMORY.Module.into())
+                    let tag = o.to_string_tag(agent);
+                    Ok(property_descriptor.value.is_none_or(|v| v == tag.into())
                         && property_descriptor.writable.is_none_or(|v| !v)
                         && property_descriptor.get.is_none()
                         && property_descriptor.set.is_none()
@@ -403,13 +420,19 @@ impl<'a> InternalMethods<'a> for Module<'a> {
     ) -> TryResult<'gc, TryHasResult<'gc>> {
         match property_key {
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
+                // 1. If IsSymbolLikeNamespaceKey(P, O), return
+                //    ! OrdinaryHasProperty(O, P).
+                if self.is_symbol_like_namespace_key(agent, property_key) {
+                    return TryHasResult::Unset.into();
+                }
                 let p = match property_key {
                     PropertyKey::String(data) => String::String(data),
                     PropertyKey::SmallString(data) => String::SmallString(data),
                     PropertyKey::Integer(_data) => todo!(),
                     _ => unreachable!(),
                 };
-                // 2. Let exports be O.[[Exports]].
+                // 2. Let exports be ? GetModuleExportsList(O).
+                self.try_get_module_exports_list(agent)?;
                 let exports: &[String] = &self.get(agent).exports;
                 // 3. If exports contains P, return true.
                 if exports.contains(&p) {
@@ -435,10 +458,15 @@ impl<'a> InternalMethods<'a> for Module<'a> {
         self,
         agent: &mut Agent,
         property_key: PropertyKey,
-        gc: GcScope<'gc, '_>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, bool> {
+        let (o, property_key) = self
+            .get_module_exports_list(agent, Some(property_key), gc.reborrow())
+            .unbind()?;
+        let gc = gc.into_nogc();
         Ok(!matches!(
-            self.try_has_property(agent, property_key, None, gc.into_nogc()),
+            o.bind(gc)
+                .try_has_property(agent, property_key.unwrap().bind(gc), None, gc),
             ControlFlow::Continue(TryHasResult::Unset)
         ))
     }
@@ -463,14 +491,18 @@ impl<'a> InternalMethods<'a> for Module<'a> {
             PropertyKey::Symbol(symbol) => {
                 // a. Return ! OrdinaryGet(O, P, Receiver).
                 if symbol == WellKnownSymbols::ToStringTag.into() {
-                    TryGetResult::Value(BUILTIN_STRING_MEMORY.Module.into()).into()
+                    TryGetResult::Value(self.to_string_tag(agent).into()).into()
                 } else {
                     TryGetResult::Unset.into()
                 }
             }
             PropertyKey::PrivateName(_) => unreachable!(),
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
-                // 2. Let exports be O.[[Exports]].
+                if self.is_symbol_like_namespace_key(agent, property_key) {
+                    return TryGetResult::Unset.into();
+                }
+                // 2. Let exports be ? GetModuleExportsList(O).
+                self.try_get_module_exports_list(agent)?;
                 let exports: &[String] = &self.get(agent).exports;
                 let key = match property_key {
                     PropertyKey::SmallString(data) => String::SmallString(data),
@@ -501,7 +533,13 @@ impl<'a> InternalMethods<'a> for Module<'a> {
                     let Some(binding_name) = binding_name else {
                         // a. Return GetModuleNamespace(targetModule).
                         return TryGetResult::Value(
-                            get_module_namespace(agent, target_module.unbind(), gc).into(),
+                            get_module_namespace(
+                                agent,
+                                target_module.unbind(),
+                                ImportPhase::Evaluation,
+                                gc,
+                            )
+                            .into(),
                         )
                         .into();
                     };
@@ -535,10 +573,16 @@ impl<'a> InternalMethods<'a> for Module<'a> {
         agent: &mut Agent,
         property_key: PropertyKey,
         _receiver: Value,
-        gc: GcScope<'gc, '_>,
+        mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
+        // Note: GetModuleExportsList is performed first so that the rest of
+        // the method cannot trigger garbage collection.
+        let (o, property_key) = self
+            .get_module_exports_list(agent, Some(property_key), gc.reborrow())
+            .unbind()?;
         let gc = gc.into_nogc();
-        let property_key = property_key.bind(gc);
+        let o = o.bind(gc);
+        let property_key = property_key.unwrap().bind(gc);
 
         // NOTE: ResolveExport is side-effect free. Each time this operation
         // is called with a specific exportName, resolveSet pair as arguments
@@ -551,15 +595,18 @@ impl<'a> InternalMethods<'a> for Module<'a> {
             PropertyKey::Symbol(symbol) => {
                 // a. Return ! OrdinaryGet(O, P, Receiver).
                 if symbol == WellKnownSymbols::ToStringTag.into() {
-                    Ok(BUILTIN_STRING_MEMORY.Module.into())
+                    Ok(o.to_string_tag(agent).into())
                 } else {
                     Ok(Value::Undefined)
                 }
             }
             PropertyKey::PrivateName(_) => unreachable!(),
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
-                // 2. Let exports be O.[[Exports]].
-                let exports: &[String] = &self.get(agent).exports;
+                if o.is_symbol_like_namespace_key(agent, property_key) {
+                    return Ok(Value::Undefined);
+                }
+                // 2. Let exports be ? GetModuleExportsList(O).
+                let exports: &[String] = &o.get(agent).exports;
                 let key = match property_key {
                     PropertyKey::SmallString(data) => String::SmallString(data),
                     PropertyKey::String(data) => String::String(data),
@@ -573,7 +620,7 @@ impl<'a> InternalMethods<'a> for Module<'a> {
                     Ok(Value::Undefined)
                 } else {
                     // 4. Let m be O.[[Module]].
-                    let m = &self.get(agent).module;
+                    let m = &o.get(agent).module;
                     // 5. Let binding be m.ResolveExport(P).
                     let binding = m.resolve_export(agent, key, &mut vec![], gc);
                     // 6. Assert: binding is a ResolvedBinding Record.
@@ -589,7 +636,13 @@ impl<'a> InternalMethods<'a> for Module<'a> {
                     // 9. If binding.[[BindingName]] is NAMESPACE, then
                     let Some(binding_name) = binding_name else {
                         // a. Return GetModuleNamespace(targetModule).
-                        return Ok(get_module_namespace(agent, target_module.unbind(), gc).into());
+                        return Ok(get_module_namespace(
+                            agent,
+                            target_module.unbind(),
+                            ImportPhase::Evaluation,
+                            gc,
+                        )
+                        .into());
                     };
                     // 10. Let targetEnv be targetModule.[[Environment]].
                     let target_env = target_module.environment(agent, gc);
@@ -657,13 +710,19 @@ impl<'a> InternalMethods<'a> for Module<'a> {
                 unreachable!()
             }
             PropertyKey::Integer(_) | PropertyKey::SmallString(_) | PropertyKey::String(_) => {
+                // 1. If IsSymbolLikeNamespaceKey(P, O), then
+                //    a. Return ! OrdinaryDelete(O, P).
+                if self.is_symbol_like_namespace_key(agent, property_key) {
+                    return TryResult::Continue(true);
+                }
                 let p = match property_key {
                     PropertyKey::String(data) => String::String(data),
                     PropertyKey::SmallString(data) => String::SmallString(data),
                     PropertyKey::Integer(_) => todo!(),
                     _ => unreachable!(),
                 };
-                // 2. Let exports be O.[[Exports]].
+                // 2. Let exports be ? GetModuleExportsList(O).
+                self.try_get_module_exports_list(agent)?;
                 let exports = &self.get(agent).exports;
                 // 3. If exports contains P,
                 if exports.contains(&p) {
@@ -677,13 +736,32 @@ impl<'a> InternalMethods<'a> for Module<'a> {
         }
     }
 
+    /// ### [10.4.6.10 \[\[Delete\]\] ( P )](https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-delete-p)
+    fn internal_delete<'gc>(
+        self,
+        agent: &mut Agent,
+        property_key: PropertyKey,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, bool> {
+        let (o, property_key) = self
+            .get_module_exports_list(agent, Some(property_key), gc.reborrow())
+            .unbind()?;
+        let gc = gc.into_nogc();
+        Ok(unwrap_try(o.bind(gc).try_delete(
+            agent,
+            property_key.unwrap().bind(gc),
+            gc,
+        )))
+    }
+
     /// ### [10.4.6.11 \[\[OwnPropertyKeys\]\] ( )](https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-ownpropertykeys)
     fn try_own_property_keys<'gc>(
         self,
         agent: &mut Agent,
         gc: NoGcScope<'gc, '_>,
     ) -> TryResult<'gc, Vec<PropertyKey<'gc>>> {
-        // 1. Let exports be O.[[Exports]].
+        // 1. Let exports be ? GetModuleExportsList(O).
+        self.try_get_module_exports_list(agent)?;
         let exports = self
             .bind(gc)
             .get(agent)
@@ -699,6 +777,19 @@ impl<'a> InternalMethods<'a> for Module<'a> {
         TryResult::Continue(own_property_keys)
     }
 
+    /// ### [10.4.6.11 \[\[OwnPropertyKeys\]\] ( )](https://tc39.es/ecma262/#sec-module-namespace-exotic-objects-ownpropertykeys)
+    fn internal_own_property_keys<'gc>(
+        self,
+        agent: &mut Agent,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Vec<PropertyKey<'gc>>> {
+        let (o, _) = self
+            .get_module_exports_list(agent, None, gc.reborrow())
+            .unbind()?;
+        let gc = gc.into_nogc();
+        Ok(unwrap_try(o.bind(gc).try_own_property_keys(agent, gc)))
+    }
+
     #[inline(always)]
     fn get_own_property_at_offset<'gc>(
         self,
@@ -710,34 +801,61 @@ impl<'a> InternalMethods<'a> for Module<'a> {
     }
 }
 
-/// ### [10.4.6.12 ModuleNamespaceCreate ( module, exports )](https://tc39.es/ecma262/#sec-modulenamespacecreate)
+/// ### [10.4.6.12 ModuleNamespaceCreate ( module, exports, phase )](https://tc39.es/proposal-defer-import-eval/#sec-modulenamespacecreate)
 ///
 /// The abstract operation ModuleNamespaceCreate takes arguments module (a
-/// Module Record) and exports (a List of Strings) and returns a module
-/// namespace exotic object. It is used to specify the creation of new module
-/// namespace exotic objects.
+/// Module Record), exports (a List of Strings), and phase (defer or
+/// evaluation) and returns a module namespace exotic object. It is used to
+/// specify the creation of new module namespace exotic objects.
 pub(crate) fn module_namespace_create<'a>(
     agent: &mut Agent,
     module: AbstractModule<'a>,
-    mut exports: Box<[String<'a>]>,
+    exports: Box<[String<'a>]>,
+    phase: ImportPhase,
     gc: NoGcScope<'a, '_>,
 ) -> Module<'a> {
+    let deferred = phase == ImportPhase::Defer;
     // 1. Assert: module.[[Namespace]] is empty.
-    debug_assert!(module.namespace(agent, gc).is_none());
+    // 2. If phase is defer, assert: module.[[DeferredNamespace]] is empty.
+    debug_assert!(if deferred {
+        module.deferred_namespace(agent, gc).is_none()
+    } else {
+        module.namespace(agent, gc).is_none()
+    });
     // 2. Let internalSlotsList be the internal slots listed in Table 33.
     // 3. Let M be MakeBasicObject(internalSlotsList).
     // 4. Set M's essential internal methods to the definitions specified in 10.4.6.
     // 5. Set M.[[Module]] to module.
     // 6. Let sortedExports be a List whose elements are the elements of
     //    exports, sorted according to lexicographic code unit order.
+    // Note: "then" is symbol-like on deferred namespaces and is thus never
+    // exposed as an export.
+    let mut exports = if deferred {
+        exports
+            .into_iter()
+            .filter(|e| *e != BUILTIN_STRING_MEMORY.then)
+            .collect::<Box<[String]>>()
+    } else {
+        exports
+    };
     // TODO: this implements UTF-8 lexicographic order, not UTF-16.
     exports.sort_by(|a, b| a.as_wtf8_(agent).cmp(b.as_wtf8_(agent)));
     // 7. Set M.[[Exports]] to sortedExports.
-    // 8. Create own properties of M corresponding to the definitions in 28.3.
-    let m = agent.heap.create(ModuleHeapData { module, exports });
-    // 9. Set module.[[Namespace]] to M.
-    module.set_namespace(agent, m);
-    // 10. Return M.
+    // 8. Set M.[[Deferred]] to true if phase is defer, or false otherwise.
+    // 9. Create own properties of M corresponding to the definitions in 28.3.
+    let m = agent.heap.create(ModuleHeapData {
+        module,
+        exports,
+        deferred,
+    });
+    // 10. If phase is defer, set module.[[DeferredNamespace]] to M.
+    // 11. Else, set module.[[Namespace]] to M.
+    if deferred {
+        module.set_deferred_namespace(agent, m);
+    } else {
+        module.set_namespace(agent, m);
+    }
+    // 12. Return M.
     m
 }
 
@@ -14,6 +14,11 @@ use super::Module;
 pub(crate) struct ModuleHeapData<'a> {
     pub(super) module: AbstractModule<'a>,
     pub(super) exports: Box<[String<'a>]>,
+    /// ### \[\[Deferred]]
+    ///
+    /// Whether this is a deferred module namespace object, created by an
+    /// `import defer` declaration or an `import.defer()` call.
+    pub(super) deferred: bool,
 }
 
 impl<'a> CreateHeapData<ModuleHeapData<'a>, Module<'a>> for Heap {
@@ -29,7 +34,11 @@ bindable_handle!(ModuleHeapData);
 
 impl HeapMarkAndSweep for ModuleHeapData<'static> {
     fn mark_values(&self, queues: &mut WorkQueues) {
-        let Self { module, exports } = self;
+        let Self {
+            module,
+            exports,
+            deferred: _,
+        } = self;
         module.mark_values(queues);
         for ele in exports.iter() {
             ele.mark_values(queues);
@@ -37,7 +46,11 @@ impl HeapMarkAndSweep for ModuleHeapData<'static> {
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
-        let Self { module, exports } = self;
+        let Self {
+            module,
+            exports,
+            deferred: _,
+        } = self;
         module.sweep_values(compactions);
         for ele in exports.iter_mut() {
             ele.sweep_values(compactions);
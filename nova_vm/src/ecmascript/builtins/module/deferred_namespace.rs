@@ -0,0 +1,116 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## [Deferred Module Namespace Objects](https://tc39.es/proposal-defer-import-eval/#sec-module-namespace-exotic-objects)
+//!
+//! A deferred module namespace object is a module namespace exotic object
+//! whose \[\[Deferred]] slot is true. Accessing any of its exports
+//! synchronously evaluates the underlying module.
+
+use crate::{
+    ecmascript::{
+        Agent, BUILTIN_STRING_MEMORY, ExceptionType, JsResult, PropertyKey, String, TryError,
+        TryResult, evaluate_module_sync, ready_for_sync_execution,
+    },
+    engine::{Bindable, GcScope, Scopable},
+    heap::ArenaAccess,
+};
+
+use super::Module;
+
+impl<'a> Module<'a> {
+    /// Returns true if this is a deferred module namespace object.
+    pub(super) fn is_deferred(self, agent: &Agent) -> bool {
+        self.get(agent).deferred
+    }
+
+    /// Returns the value of the namespace object's @@toStringTag property.
+    pub(super) fn to_string_tag(self, agent: &Agent) -> String<'static> {
+        if self.is_deferred(agent) {
+            BUILTIN_STRING_MEMORY.Deferred_Module
+        } else {
+            BUILTIN_STRING_MEMORY.Module
+        }
+    }
+
+    /// ### [IsSymbolLikeNamespaceKey ( P, O )](https://tc39.es/proposal-defer-import-eval/#sec-IsSymbolLikeNamespaceKey)
+    ///
+    /// The abstract operation IsSymbolLikeNamespaceKey takes arguments P (a
+    /// property key) and O (a module namespace exotic object) and returns a
+    /// Boolean.
+    pub(super) fn is_symbol_like_namespace_key(self, agent: &Agent, p: PropertyKey) -> bool {
+        // 1. If P is a Symbol, return true.
+        if p.is_symbol() {
+            return true;
+        }
+        // 2. If ns.[[Deferred]] is true and P is "then", return true.
+        // 3. Return false.
+        self.is_deferred(agent) && p == BUILTIN_STRING_MEMORY.then.to_property_key()
+    }
+
+    /// Try variant of GetModuleExportsList: returns a GcError if the
+    /// deferred namespace's module has not yet been successfully evaluated.
+    pub(super) fn try_get_module_exports_list<'gc>(self, agent: &Agent) -> TryResult<'gc, ()> {
+        let data = self.get(agent);
+        if !data.deferred {
+            return TryResult::Continue(());
+        }
+        if data.module.is_evaluated_without_error(agent) {
+            TryResult::Continue(())
+        } else {
+            TryError::GcError.into()
+        }
+    }
+
+    /// ### [GetModuleExportsList ( O )](https://tc39.es/proposal-defer-import-eval/#sec-GetModuleExportsList)
+    ///
+    /// The abstract operation GetModuleExportsList takes argument O (a module
+    /// namespace exotic object) and returns either a normal completion
+    /// containing a List of Strings or a throw completion.
+    ///
+    /// > NOTE: The exports list is read from O by the caller; this method
+    /// > only performs the evaluation steps, returning O and P rebound
+    /// > after garbage collection.
+    pub(super) fn get_module_exports_list<'gc>(
+        self,
+        agent: &mut Agent,
+        p: Option<PropertyKey>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, (Module<'gc>, Option<PropertyKey<'gc>>)> {
+        let o = self.bind(gc.nogc());
+        let p = p.bind(gc.nogc());
+        // 1. If O.[[Deferred]] is true, then
+        if !o.is_deferred(agent) || p.is_some_and(|p| o.is_symbol_like_namespace_key(agent, p)) {
+            return Ok((o.unbind(), p.unbind()));
+        }
+        // a. Let m be O.[[Module]].
+        let m = o.get(agent).module.bind(gc.nogc());
+        if m.is_evaluated_without_error(agent) {
+            return Ok((o.unbind(), p.unbind()));
+        }
+        // b. If m is a Cyclic Module Record, m.[[Status]] is not evaluated,
+        //    and ReadyForSyncExecution(m) is false, throw a TypeError
+        //    exception.
+        if !m.is_evaluated(agent) && !ready_for_sync_execution(agent, m, &mut vec![], gc.nogc()) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Deferred module cannot be evaluated synchronously",
+                gc.into_nogc(),
+            ));
+        }
+        let scoped_o = o.scope(agent, gc.nogc());
+        let scoped_p = p.map(|p| p.scope(agent, gc.nogc()));
+        // c. Perform ? EvaluateModuleSync(m).
+        evaluate_module_sync(agent, m.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        // 2. Return O.[[Exports]].
+        // SAFETY: not shared.
+        unsafe {
+            Ok((
+                scoped_o.take(agent).bind(gc),
+                scoped_p.map(|p| p.take(agent).bind(gc)),
+            ))
+        }
+    }
+}
@@ -2,13 +2,26 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+#[cfg(not(feature = "temporal"))]
+use std::sync::LazyLock;
+
+#[cfg(not(feature = "temporal"))]
+use timezone_provider::tzif::CompiledTzdbProvider;
+use timezone_provider::{
+    epoch_nanoseconds::EpochNanoseconds,
+    provider::{
+        CandidateEpochNanoseconds, EpochNanosecondsAndOffset, IsoDateTime, TimeZoneId,
+        TimeZoneProvider, UtcOffsetSeconds,
+    },
+};
+
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinIntrinsic, Date,
-        DateValue, ExceptionType, JsResult, Number, Object, PreferredType, PropertyKey, Realm,
-        String, Value, abstract_operations::to_number, builders::OrdinaryObjectBuilder, invoke,
-        ordinary_to_primitive, to_integer_or_infinity_f64, to_object,
-        to_zero_padded_decimal_string,
+        DateValue, ExceptionType, JsResult, LocaleDateFormat, Number, Object, PreferredType,
+        PropertyKey, Realm, String, Value, abstract_operations::to_number,
+        builders::OrdinaryObjectBuilder, invoke, ordinary_to_primitive, to_integer_or_infinity_f64,
+        to_object, to_zero_padded_decimal_string,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable},
     heap::{IntrinsicFunctionIndexes, WellKnownSymbols},
@@ -1081,11 +1094,31 @@ impl DatePrototype {
     /// ECMA-402 support must not use those parameter positions for anything else.
     fn to_locale_date_string<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
+        this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Date.prototype.toLocaleDateString", gc.into_nogc()))
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        let date_object = require_internal_slot_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Let tv be dateObject.[[DateValue]].
+        let tv = date_object.date_value(agent);
+        // 4. If tv is NaN, return "Invalid Date".
+        let Some(tv) = tv.get_f64() else {
+            return Ok(Value::from_static_str(
+                agent,
+                "Invalid Date",
+                gc.into_nogc(),
+            ));
+        };
+        // 5. Let t be LocalTime(tv).
+        let t = local_time(agent, tv);
+        // 6. Return an implementation-defined String that represents the
+        //    date portion of t in a human-readable form.
+        let s = to_locale_string(agent, tv, t, LocaleDateFormat::Date);
+        Ok(Value::from_string(agent, s, gc.into_nogc()))
     }
 
     /// ### [21.4.4.39 Date.prototype.toLocaleString ( \[ reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/#sec-date.prototype.tolocalestring)
@@ -1105,11 +1138,31 @@ impl DatePrototype {
     /// ECMA-402 support must not use those parameter positions for anything else.
     fn to_locale_string<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
+        this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Date.prototype.toLocaleString", gc.into_nogc()))
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        let date_object = require_internal_slot_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Let tv be dateObject.[[DateValue]].
+        let tv = date_object.date_value(agent);
+        // 4. If tv is NaN, return "Invalid Date".
+        let Some(tv) = tv.get_f64() else {
+            return Ok(Value::from_static_str(
+                agent,
+                "Invalid Date",
+                gc.into_nogc(),
+            ));
+        };
+        // 5. Let t be LocalTime(tv).
+        let t = local_time(agent, tv);
+        // 6. Return an implementation-defined String that represents t in a
+        //    human-readable form.
+        let s = to_locale_string(agent, tv, t, LocaleDateFormat::DateTime);
+        Ok(Value::from_string(agent, s, gc.into_nogc()))
     }
 
     /// ### [21.4.4.40 Date.prototype.toLocaleTimeString ( \[ reserved1 \[ , reserved2 \] \] )](https://tc39.es/ecma262/#sec-date.prototype.tolocaletimestring)
@@ -1130,11 +1183,31 @@ impl DatePrototype {
     /// ECMA-402 support must not use those parameter positions for anything else.
     fn to_locale_time_string<'gc>(
         agent: &mut Agent,
-        _this_value: Value,
+        this_value: Value,
         _: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        Err(agent.todo("Date.prototype.toLocaleTimeString", gc.into_nogc()))
+        // 1. Let dateObject be the this value.
+        // 2. Perform ? RequireInternalSlot(dateObject, [[DateValue]]).
+        let date_object = require_internal_slot_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Let tv be dateObject.[[DateValue]].
+        let tv = date_object.date_value(agent);
+        // 4. If tv is NaN, return "Invalid Date".
+        let Some(tv) = tv.get_f64() else {
+            return Ok(Value::from_static_str(
+                agent,
+                "Invalid Date",
+                gc.into_nogc(),
+            ));
+        };
+        // 5. Let t be LocalTime(tv).
+        let t = local_time(agent, tv);
+        // 6. Return an implementation-defined String that represents the
+        //    time portion of t in a human-readable form.
+        let s = to_locale_string(agent, tv, t, LocaleDateFormat::Time);
+        Ok(Value::from_string(agent, s, gc.into_nogc()))
     }
 
     /// ### [21.4.4.41 Date.prototype.toString ( )](https://tc39.es/ecma262/#sec-date.prototype.tostring)
@@ -1735,7 +1808,7 @@ fn get_utc_epoch_nanoseconds(
     millisecond: u16,
     microsecond: u16,
     nanosecond: u16,
-) -> i64 {
+) -> i128 {
     // 1. Let date be MakeDay(𝔽(year), 𝔽(month - 1), 𝔽(day)).
     let date = make_day(year as f64, (month - 1) as f64, day as f64);
     // 2. Let time be MakeTime(𝔽(hour), 𝔽(minute), 𝔽(second), 𝔽(millisecond)).
@@ -1750,7 +1823,7 @@ fn get_utc_epoch_nanoseconds(
     // 4. Assert: ms is an integral Number.
     assert!(ms.fract() == 0.0);
     // 5. Return ℤ(ℝ(ms) × 10**6 + microsecond × 10**3 + nanosecond).
-    (ms * 1e6 + (microsecond as f64) * 1e3 + (nanosecond as f64)) as i64
+    ms as i128 * 1_000_000 + microsecond as i128 * 1_000 + nanosecond as i128
 }
 
 /// ### [21.4.1.20 GetNamedTimeZoneEpochNanoseconds ( timeZoneIdentifier, year, month, day, hour, minute, second, millisecond, microsecond, nanosecond )](https://tc39.es/ecma262/#sec-getnamedtimezoneepochnanoseconds)
@@ -1779,10 +1852,6 @@ fn get_utc_epoch_nanoseconds(
 /// returned List will be empty. Otherwise, the returned List will have one
 /// element.
 ///
-/// The default implementation of GetNamedTimeZoneEpochNanoseconds, to be used
-/// for ECMAScript implementations that do not include local political rules
-/// for any time zones, performs the following steps when called:
-///
 /// > NOTE: It is required for time zone aware implementations (and recommended
 /// > for all others) to use the time zone information of the IANA Time Zone
 /// > Database https://www.iana.org/time-zones/.
@@ -1797,6 +1866,10 @@ fn get_utc_epoch_nanoseconds(
 /// > 2:30 AM on 12 March 2017 in America/New_York does not exist, so
 /// > GetNamedTimeZoneEpochNanoseconds("America/New_York", 2017, 3, 12, 2, 30,
 /// > 0, 0, 0, 0) would return an empty List.
+///
+/// Nova uses the time zone information of the IANA Time Zone Database for
+/// named time zones other than "UTC". An empty List is returned with the
+/// epoch nanoseconds of the skipped time zone transition.
 #[allow(clippy::too_many_arguments)]
 fn get_named_time_zone_epoch_nanoseconds(
     time_zone_identifier: &str,
@@ -1809,9 +1882,27 @@ fn get_named_time_zone_epoch_nanoseconds(
     millisecond: u16,
     microsecond: u16,
     nanosecond: u16,
-) -> [i64; 1] {
+) -> CandidateEpochNanoseconds {
+    if let Some(time_zone) = get_named_time_zone(time_zone_identifier) {
+        let local_date_time = IsoDateTime {
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            millisecond,
+            microsecond,
+            nanosecond,
+        };
+        if let Ok(possible_instants) = TIME_ZONE_PROVIDER
+            .candidate_nanoseconds_for_local_epoch_nanoseconds(time_zone, local_date_time)
+        {
+            return possible_instants;
+        }
+    }
+    // Default implementation for "UTC":
     // 1. Assert: timeZoneIdentifier is "UTC".
-    assert_eq!(time_zone_identifier, "UTC");
     // 2. Let epochNanoseconds be GetUTCEpochNanoseconds(year, month, day, hour, minute, second, millisecond, microsecond, nanosecond).
     let epoch_nanoseconds = get_utc_epoch_nanoseconds(
         year,
@@ -1825,7 +1916,10 @@ fn get_named_time_zone_epoch_nanoseconds(
         nanosecond,
     );
     // 3. Return « epochNanoseconds ».
-    [epoch_nanoseconds]
+    CandidateEpochNanoseconds::One(EpochNanosecondsAndOffset {
+        ns: EpochNanoseconds(epoch_nanoseconds),
+        offset: UtcOffsetSeconds(0),
+    })
 }
 
 /// ### [21.4.1.21 GetNamedTimeZoneOffsetNanoseconds ( timeZoneIdentifier, epochNanoseconds )](https://tc39.es/ecma262/#sec-getnamedtimezoneoffsetnanoseconds)
@@ -1838,48 +1932,53 @@ fn get_named_time_zone_epoch_nanoseconds(
 /// identified by timeZoneIdentifier, at the instant corresponding with
 /// epochNanoseconds relative to the epoch, both in nanoseconds.
 ///
-/// The default implementation of GetNamedTimeZoneOffsetNanoseconds, to be used
-/// for ECMAScript implementations that do not include local political rules
-/// for any time zones, performs the following steps when called:
-///
 /// > NOTE: Time zone offset values may be positive or negative.
+///
+/// Nova uses the time zone information of the IANA Time Zone Database for
+/// named time zones other than "UTC".
 fn get_named_time_zone_offset_nanoseconds(
     time_zone_identifier: &str,
-    _epoch_nanoseconds: i64,
+    epoch_nanoseconds: i128,
 ) -> f64 {
+    if let Some(time_zone) = get_named_time_zone(time_zone_identifier)
+        && let Ok(UtcOffsetSeconds(offset_seconds)) = TIME_ZONE_PROVIDER
+            .transition_nanoseconds_for_utc_epoch_nanoseconds(time_zone, epoch_nanoseconds)
+    {
+        return offset_seconds as f64 * 1e9;
+    }
+    // Default implementation for "UTC":
     // 1. Assert: timeZoneIdentifier is "UTC".
-    assert_eq!(time_zone_identifier, "UTC");
     // 2. Return 0.
     0.0
 }
 
-/// ### [21.4.1.24 SystemTimeZoneIdentifier ( )](https://tc39.es/ecma262/#sec-systemtimezoneidentifier)
-///
-/// The implementation-defined abstract operation SystemTimeZoneIdentifier
-/// takes no arguments and returns a String. It returns a String representing
-/// the host environment's current time zone, which is either a String
-/// representing a UTC offset for which IsTimeZoneOffsetString returns true, or
-/// a primary time zone identifier.
-///
-/// > NOTE: To ensure the level of functionality that implementations commonly
-/// > provide in the methods of the Date object, it is recommended that
-/// > SystemTimeZoneIdentifier return an IANA time zone name corresponding to
-/// > the host environment's time zone setting, if such a thing exists.
-/// > GetNamedTimeZoneEpochNanoseconds and GetNamedTimeZoneOffsetNanoseconds
-/// > must reflect the local political rules for standard time and daylight
-/// > saving time in that time zone, if such rules exist.
-/// >
-/// > For example, if the host environment is a browser on a system where the
-/// > user has chosen US Eastern Time as their time zone,
-/// > SystemTimeZoneIdentifier returns "America/New_York".
-fn system_time_zone_identifier(_agent: &Agent) -> &'static str {
-    // 1. If the implementation only supports the UTC time zone, return "UTC".
-    // 2. Let systemTimeZoneString be the String representing the host environment's
-    // current time zone, either a primary time zone identifier or an offset time zone identifier.
-    // 3. Return systemTimeZoneString.
+/// Time zone database used for named time zones.
+#[cfg(not(feature = "temporal"))]
+static TIME_ZONE_PROVIDER: LazyLock<CompiledTzdbProvider> = LazyLock::new(Default::default);
+/// Time zone database used for named time zones. This is the same database
+/// that Temporal uses.
+#[cfg(feature = "temporal")]
+use temporal_rs::provider::COMPILED_TZ_PROVIDER as TIME_ZONE_PROVIDER;
+
+/// Find a named time zone other than "UTC" from the time zone database.
+fn get_named_time_zone(time_zone_identifier: &str) -> Option<TimeZoneId> {
+    if time_zone_identifier == "UTC" {
+        return None;
+    }
+    TIME_ZONE_PROVIDER.get(time_zone_identifier.as_bytes()).ok()
+}
 
-    // TODO: implement this
-    "UTC"
+/// Normalises a host time zone to an offset time zone identifier or to a
+/// primary time zone identifier from the time zone database. Returns `None`
+/// if the time zone is not recognised.
+pub(crate) fn to_primary_time_zone_identifier(time_zone: &str) -> Option<Box<str>> {
+    if is_time_zone_offset_string(time_zone) {
+        return Some(time_zone.into());
+    }
+    get_named_time_zone(time_zone)
+        .and_then(|id| TIME_ZONE_PROVIDER.canonicalized(id).ok())
+        .and_then(|id| TIME_ZONE_PROVIDER.identifier(id).ok())
+        .map(|identifier| identifier.into())
 }
 
 /// ### [21.4.1.25 LocalTime ( t )](https://tc39.es/ecma262/#sec-localtime)
@@ -1907,7 +2006,7 @@ fn system_time_zone_identifier(_agent: &Agent) -> &'static str {
 /// > Correspondingly, UTC(LocalTime(tUTC)) is not necessarily always equal to tUTC.
 fn local_time(agent: &Agent, t: f64) -> f64 {
     // 1. Let systemTimeZoneIdentifier be SystemTimeZoneIdentifier().
-    let system_time_zone_identifier = system_time_zone_identifier(agent);
+    let system_time_zone_identifier = agent.system_time_zone_identifier();
     // 2. If IsTimeZoneOffsetString(systemTimeZoneIdentifier) is true, then
     let offset_ns = if is_time_zone_offset_string(system_time_zone_identifier) {
         // a. Let offsetNs be ParseTimeZoneOffsetString(systemTimeZoneIdentifier).
@@ -1918,7 +2017,7 @@ fn local_time(agent: &Agent, t: f64) -> f64 {
         // a. Let offsetNs be GetNamedTimeZoneOffsetNanoseconds(systemTimeZoneIdentifier, ℤ(ℝ(t) × 10**6)).
         get_named_time_zone_offset_nanoseconds(
             system_time_zone_identifier,
-            (t * 1_000_000.0) as i64,
+            (t * 1_000_000.0) as i128,
         )
     };
     // 4. Let offsetMs be truncate(offsetNs / 10**6).
@@ -1974,7 +2073,7 @@ pub(super) fn utc(agent: &Agent, t: f64) -> f64 {
         return f64::NAN;
     }
     // 2. Let systemTimeZoneIdentifier be SystemTimeZoneIdentifier().
-    let system_time_zone_identifier = system_time_zone_identifier(agent);
+    let system_time_zone_identifier = agent.system_time_zone_identifier();
     // 3. If IsTimeZoneOffsetString(systemTimeZoneIdentifier) is true, then
     let offset_ns = if is_time_zone_offset_string(system_time_zone_identifier) {
         // a. Let offsetNs be ParseTimeZoneOffsetString(systemTimeZoneIdentifier).
@@ -2004,9 +2103,9 @@ pub(super) fn utc(agent: &Agent, t: f64) -> f64 {
         // change), t is interpreted using the time zone offset before the
         // transition.
         // c. If possibleInstants is not empty, then
-        let disambiguated_instant = if !possible_instants.is_empty() {
+        let disambiguated_instant = if let Some(first) = possible_instants.as_slice().first() {
             // i. Let disambiguatedInstant be possibleInstants[0].
-            possible_instants[0]
+            first.ns.0
         }
         // d. Else,
         else {
@@ -2022,7 +2121,13 @@ pub(super) fn utc(agent: &Agent, t: f64) -> f64 {
             // possibleInstantsBefore is not empty (i.e., tBefore represents
             // the last local time before the transition).
             // iii. Let disambiguatedInstant be the last element of possibleInstantsBefore.
-            todo!()
+            let CandidateEpochNanoseconds::Zero(gap) = possible_instants else {
+                unreachable!()
+            };
+            // Note: tBefore is the last millisecond before the transition, and
+            // the last element of possibleInstantsBefore is thus the instant
+            // one millisecond before the transition's epoch nanoseconds.
+            gap.transition_epoch.0 - 1_000_000
         };
         // e. Let offsetNs be GetNamedTimeZoneOffsetNanoseconds(systemTimeZoneIdentifier, disambiguatedInstant).
         get_named_time_zone_offset_nanoseconds(system_time_zone_identifier, disambiguated_instant)
@@ -2188,11 +2293,11 @@ pub(super) fn make_full_year(year: f64) -> f64 {
 /// (a String) and returns a Boolean. The return value indicates whether
 /// offsetString conforms to the grammar given by UTCOffset. It performs the
 /// following steps when called:
-fn is_time_zone_offset_string(_offset_string: &str) -> bool {
+fn is_time_zone_offset_string(offset_string: &str) -> bool {
     // 1. Let parseResult be ParseText(offsetString, UTCOffset).
-    let parse_result: Result<(), ()> = Err(()); // TODO:
+    let parse_result = parse_utc_offset(offset_string);
     // 2. If parseResult is a List of errors, return false.
-    if parse_result.is_err() {
+    if parse_result.is_none() {
         return false;
     }
     // 3. Return true.
@@ -2205,7 +2310,7 @@ fn is_time_zone_offset_string(_offset_string: &str) -> bool {
 /// offsetString (a String) and returns an integer. The return value is the UTC
 /// offset, as a number of nanoseconds, that corresponds to the String
 /// offsetString.
-fn parse_time_zone_offset_string(_offset_string: &str) -> f64 {
+fn parse_time_zone_offset_string(offset_string: &str) -> f64 {
     // 1. Let parseResult be ParseText(offsetString, UTCOffset).
     // 2. Assert: parseResult is not a List of errors.
     // 3. Assert: parseResult contains a ASCIISign Parse Node.
@@ -2236,7 +2341,69 @@ fn parse_time_zone_offset_string(_offset_string: &str) -> f64 {
     //     c. Let nanosecondsString be the substring of fraction from 1 to 10.
     //     d. Let nanoseconds be ℝ(StringToNumber(nanosecondsString)).
     // 17. Return sign × (((hours × 60 + minutes) × 60 + seconds) × 10**9 + nanoseconds).
-    todo!()
+    // Note: The above steps are performed by parse_utc_offset.
+    parse_utc_offset(offset_string).unwrap() as f64
+}
+
+/// Parses a string matching the UTCOffset grammar into its offset from UTC in
+/// nanoseconds, or returns `None` if the string does not match the grammar.
+///
+/// ```text
+/// UTCOffset :::
+///   ASCIISign Hour
+///   ASCIISign Hour HourSubcomponents[+Extended]
+///   ASCIISign Hour HourSubcomponents[~Extended]
+///
+/// HourSubcomponents[Extended] :::
+///   TimeSeparator[?Extended] MinuteSecond
+///   TimeSeparator[?Extended] MinuteSecond TimeSeparator[?Extended] MinuteSecond TemporalDecimalFraction[opt]
+/// ```
+fn parse_utc_offset(offset_string: &str) -> Option<i64> {
+    fn parse_two_digits(bytes: &[u8], max: i64) -> Option<i64> {
+        let [tens @ b'0'..=b'9', ones @ b'0'..=b'9', ..] = *bytes else {
+            return None;
+        };
+        let value = ((tens - b'0') * 10 + (ones - b'0')) as i64;
+        (value <= max).then_some(value)
+    }
+
+    let (sign, rest) = match offset_string.as_bytes().split_first()? {
+        (b'+', rest) => (1, rest),
+        (b'-', rest) => (-1, rest),
+        _ => return None,
+    };
+    let hours = parse_two_digits(rest, 23)?;
+    let mut rest = &rest[2..];
+    let extended = rest.first() == Some(&b':');
+    let (mut minutes, mut seconds, mut nanoseconds) = (0, 0, 0);
+    if !rest.is_empty() {
+        if extended {
+            rest = &rest[1..];
+        }
+        minutes = parse_two_digits(rest, 59)?;
+        rest = &rest[2..];
+    }
+    if !rest.is_empty() {
+        if extended {
+            rest = rest.strip_prefix(b":")?;
+        }
+        seconds = parse_two_digits(rest, 59)?;
+        rest = &rest[2..];
+    }
+    if let Some((separator, fraction)) = rest.split_first() {
+        if !matches!(separator, b'.' | b',')
+            || fraction.is_empty()
+            || fraction.len() > 9
+            || !fraction.iter().all(u8::is_ascii_digit)
+        {
+            return None;
+        }
+        nanoseconds = fraction
+            .iter()
+            .chain(core::iter::repeat_n(&b'0', 9 - fraction.len()))
+            .fold(0, |acc, digit| acc * 10 + (digit - b'0') as i64);
+    }
+    Some(sign * (((hours * 60 + minutes) * 60 + seconds) * 1_000_000_000 + nanoseconds))
 }
 
 /// ### [21.4.4.41.1 TimeString ( tv )](https://tc39.es/ecma262/#sec-timestring)
@@ -2305,7 +2472,7 @@ fn date_string(tv: f64) -> std::string::String {
 /// Number) and returns a String.
 fn time_zone_string(agent: &Agent, tv: f64) -> std::string::String {
     // 1. Let systemTimeZoneIdentifier be SystemTimeZoneIdentifier().
-    let system_time_zone_identifier = system_time_zone_identifier(agent);
+    let system_time_zone_identifier = agent.system_time_zone_identifier();
     // 2. If IsTimeZoneOffsetString(systemTimeZoneIdentifier) is true, then
     let offset_ns = if is_time_zone_offset_string(system_time_zone_identifier) {
         // a. Let offsetNs be ParseTimeZoneOffsetString(systemTimeZoneIdentifier).
@@ -2316,7 +2483,7 @@ fn time_zone_string(agent: &Agent, tv: f64) -> std::string::String {
         // a. Let offsetNs be GetNamedTimeZoneOffsetNanoseconds(systemTimeZoneIdentifier, ℤ(ℝ(tv) × 10**6)).
         get_named_time_zone_offset_nanoseconds(
             system_time_zone_identifier,
-            (tv * 1_000_000.0) as i64,
+            (tv * 1_000_000.0) as i128,
         )
     };
     // 4. Let offset be 𝔽(truncate(offsetNs / 10**6)).
@@ -2347,6 +2514,50 @@ fn time_zone_string(agent: &Agent, tv: f64) -> std::string::String {
     format!("{offset_sign}{offset_hour}{offset_min}{tz_name}")
 }
 
+/// Implementation-defined format of the Date.prototype.toLocaleString,
+/// Date.prototype.toLocaleDateString, and Date.prototype.toLocaleTimeString
+/// methods when ECMA-402 is not supported.
+///
+/// The host may provide its own format through
+/// [`HostHooks::format_date_for_locale`](crate::ecmascript::HostHooks::format_date_for_locale).
+/// The built-in fallback does not look at the host's locale: it always
+/// follows the conventions of the "en-US" locale, as in "1/31/2020, 1:05:09
+/// PM".
+fn to_locale_string(
+    agent: &Agent,
+    tv: f64,
+    t: f64,
+    format: LocaleDateFormat,
+) -> std::string::String {
+    if let Some(s) = agent.host_hooks.format_date_for_locale(tv, t, format) {
+        return s;
+    }
+    let date = || {
+        format!(
+            "{}/{}/{}",
+            month_from_time(t) + 1,
+            date_from_time(t),
+            year_from_time(t)
+        )
+    };
+    let time = || {
+        let (hour, period) = match hour_from_time(t) {
+            0 => (12, "AM"),
+            hour @ 1..=11 => (hour, "AM"),
+            12 => (12, "PM"),
+            hour => (hour - 12, "PM"),
+        };
+        let minute = to_zero_padded_decimal_string(min_from_time(t), 2);
+        let second = to_zero_padded_decimal_string(sec_from_time(t), 2);
+        format!("{hour}:{minute}:{second} {period}")
+    };
+    match format {
+        LocaleDateFormat::DateTime => format!("{}, {}", date(), time()),
+        LocaleDateFormat::Date => date(),
+        LocaleDateFormat::Time => time(),
+    }
+}
+
 /// ### [21.4.4.41.4 ToDateString ( tv )](https://tc39.es/ecma262/#sec-todatestring)
 ///
 /// The abstract operation ToDateString takes argument tv (an integral Number
@@ -1721,7 +1721,7 @@ impl MathObject {
         let Some(IteratorRecord {
             iterator,
             next_method,
-        }) = get_iterator(agent, items.unbind(), false, gc.reborrow())
+        }) = get_iterator(agent, items.unbind(), gc.reborrow())
             .unbind()?
             .bind(gc.nogc())
             .into_iterator_record()
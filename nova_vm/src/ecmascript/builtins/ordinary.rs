@@ -8,18 +8,17 @@ mod shape;
 pub use caches::*;
 pub use shape::*;
 
-use std::{
-    collections::{TryReserveError, hash_map::Entry},
-    ops::ControlFlow,
-    vec,
-};
+use std::{collections::hash_map::Entry, ops::ControlFlow, vec};
 
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::SharedDataViewRecord;
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::try_get_result_into_value;
 #[cfg(feature = "temporal")]
-use crate::ecmascript::{DurationRecord, InstantRecord, PlainTimeRecord};
+use crate::ecmascript::{
+    DurationRecord, InstantRecord, PlainDateRecord, PlainDateTimeRecord, PlainMonthDayRecord,
+    PlainTimeRecord, PlainYearMonthRecord, ZonedDateTimeRecord,
+};
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, ExceptionType, Function, InternalMethods,
@@ -31,7 +30,7 @@ use crate::{
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable, Scoped},
     heap::{
-        CreateHeapData, WellKnownSymbols, {ElementStorageRef, PropertyStorageRef},
+        AllocationError, CreateHeapData, WellKnownSymbols, {ElementStorageRef, PropertyStorageRef},
     },
 };
 
@@ -44,9 +43,9 @@ use crate::ecmascript::SharedArrayBufferRecord;
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::{ArrayBufferHeapData, DataViewRecord, TypedArrayRecord};
 use crate::ecmascript::{
-    ArrayHeapData, ArrayIteratorHeapData, AsyncGeneratorHeapData, ErrorHeapData,
-    FinalizationRegistryRecord, GeneratorHeapData, MapHeapData, MapIteratorHeapData, Module,
-    PrimitiveObjectRecord, PromiseHeapData, StringIteratorHeapData,
+    ArrayHeapData, ArrayIteratorHeapData, AsyncGeneratorHeapData, DisposableStackHeapData,
+    ErrorHeapData, FinalizationRegistryRecord, GeneratorHeapData, MapHeapData, MapIteratorHeapData,
+    Module, PrimitiveObjectRecord, PromiseHeapData, StringIteratorHeapData,
 };
 #[cfg(feature = "set")]
 use crate::ecmascript::{SetHeapData, SetIteratorHeapData};
@@ -319,7 +318,7 @@ pub(crate) fn is_compatible_property_descriptor(
     descriptor: PropertyDescriptor,
     current: Option<PropertyDescriptor>,
     gc: NoGcScope,
-) -> Result<bool, TryReserveError> {
+) -> Result<bool, AllocationError> {
     let property_key = PropertyKey::from_str(agent, "", gc);
     validate_and_apply_property_descriptor(
         agent,
@@ -341,7 +340,7 @@ fn validate_and_apply_property_descriptor(
     descriptor: PropertyDescriptor,
     current: Option<PropertyDescriptor>,
     gc: NoGcScope,
-) -> Result<bool, TryReserveError> {
+) -> Result<bool, AllocationError> {
     // 1. Assert: IsPropertyKey(P) is true.
 
     // 2. If current is undefined, then
@@ -1659,6 +1658,24 @@ pub(crate) fn ordinary_object_create_with_intrinsics<'a>(
         ProtoIntrinsics::TemporalDuration => agent.heap.create(DurationRecord::default()).into(),
         #[cfg(feature = "temporal")]
         ProtoIntrinsics::TemporalPlainTime => agent.heap.create(PlainTimeRecord::default()).into(),
+        #[cfg(feature = "temporal")]
+        ProtoIntrinsics::TemporalPlainDate => agent.heap.create(PlainDateRecord::default()).into(),
+        #[cfg(feature = "temporal")]
+        ProtoIntrinsics::TemporalPlainDateTime => {
+            agent.heap.create(PlainDateTimeRecord::default()).into()
+        }
+        #[cfg(feature = "temporal")]
+        ProtoIntrinsics::TemporalZonedDateTime => {
+            agent.heap.create(ZonedDateTimeRecord::default()).into()
+        }
+        #[cfg(feature = "temporal")]
+        ProtoIntrinsics::TemporalPlainYearMonth => {
+            agent.heap.create(PlainYearMonthRecord::default()).into()
+        }
+        #[cfg(feature = "temporal")]
+        ProtoIntrinsics::TemporalPlainMonthDay => {
+            agent.heap.create(PlainMonthDayRecord::default()).into()
+        }
         ProtoIntrinsics::TypeError => agent
             .heap
             .create(ErrorHeapData::new(ExceptionType::TypeError, None, None))
@@ -1675,6 +1692,21 @@ pub(crate) fn ordinary_object_create_with_intrinsics<'a>(
                 None,
             ))
             .into(),
+        ProtoIntrinsics::SuppressedError => agent
+            .heap
+            .create(ErrorHeapData::new(
+                ExceptionType::SuppressedError,
+                None,
+                None,
+            ))
+            .into(),
+        ProtoIntrinsics::AsyncDisposableStack => {
+            agent.heap.create(DisposableStackHeapData::new(true)).into()
+        }
+        ProtoIntrinsics::DisposableStack => agent
+            .heap
+            .create(DisposableStackHeapData::new(false))
+            .into(),
         ProtoIntrinsics::AsyncFunction => todo!(),
         ProtoIntrinsics::AsyncGenerator => {
             agent.heap.create(AsyncGeneratorHeapData::default()).into()
@@ -1737,6 +1769,7 @@ pub(crate) fn ordinary_object_create_with_intrinsics<'a>(
         )
         .expect("Should perform GC here")
         .into(),
+        ProtoIntrinsics::IteratorHelper => unreachable!(),
         ProtoIntrinsics::Map => agent.heap.create(MapHeapData::default()).into(),
         ProtoIntrinsics::MapIterator => agent.heap.create(MapIteratorHeapData::default()).into(),
         ProtoIntrinsics::Promise => agent.heap.create(PromiseHeapData::default()).into(),
@@ -1774,6 +1807,7 @@ pub(crate) fn ordinary_object_create_with_intrinsics<'a>(
         ProtoIntrinsics::WeakRef => agent.heap.create(WeakRefHeapData::default()).into(),
         #[cfg(feature = "weak-refs")]
         ProtoIntrinsics::WeakSet => agent.heap.create(WeakSetHeapData::default()).into(),
+        ProtoIntrinsics::WrapForValidIterator => unreachable!(),
     }
     .bind(gc);
 
@@ -2015,6 +2049,9 @@ fn get_intrinsic_constructor<'a>(
     let intrinsics = agent.get_realm_record_by_id(function_realm).intrinsics();
     match intrinsic_default_proto {
         ProtoIntrinsics::AggregateError => Some(intrinsics.aggregate_error().into()),
+        ProtoIntrinsics::AsyncDisposableStack => Some(intrinsics.async_disposable_stack().into()),
+        ProtoIntrinsics::DisposableStack => Some(intrinsics.disposable_stack().into()),
+        ProtoIntrinsics::SuppressedError => Some(intrinsics.suppressed_error().into()),
         ProtoIntrinsics::Array => Some(intrinsics.array().into()),
         ProtoIntrinsics::ArrayIterator => None,
         #[cfg(feature = "array-buffer")]
@@ -2092,12 +2129,31 @@ fn get_intrinsic_constructor<'a>(
         ProtoIntrinsics::WeakRef => Some(intrinsics.weak_ref().into()),
         #[cfg(feature = "weak-refs")]
         ProtoIntrinsics::WeakSet => Some(intrinsics.weak_set().into()),
+        ProtoIntrinsics::IteratorHelper | ProtoIntrinsics::WrapForValidIterator => None,
         #[cfg(feature = "temporal")]
         ProtoIntrinsics::TemporalInstant => Some(intrinsics.temporal_instant().into()),
         #[cfg(feature = "temporal")]
         ProtoIntrinsics::TemporalDuration => Some(intrinsics.temporal_duration().into()),
         #[cfg(feature = "temporal")]
         ProtoIntrinsics::TemporalPlainTime => Some(intrinsics.temporal_plain_time().into()),
+        #[cfg(feature = "temporal")]
+        ProtoIntrinsics::TemporalPlainDate => Some(intrinsics.temporal_plain_date().into()),
+        #[cfg(feature = "temporal")]
+        ProtoIntrinsics::TemporalPlainDateTime => {
+            Some(intrinsics.temporal_plain_date_time().into())
+        }
+        #[cfg(feature = "temporal")]
+        ProtoIntrinsics::TemporalZonedDateTime => {
+            Some(intrinsics.temporal_zoned_date_time().into())
+        }
+        #[cfg(feature = "temporal")]
+        ProtoIntrinsics::TemporalPlainYearMonth => {
+            Some(intrinsics.temporal_plain_year_month().into())
+        }
+        #[cfg(feature = "temporal")]
+        ProtoIntrinsics::TemporalPlainMonthDay => {
+            Some(intrinsics.temporal_plain_month_day().into())
+        }
     }
 }
 
@@ -417,6 +417,12 @@ fn prototype_chain_includes_object(
 }
 
 impl Caches<'static> {
+    /// Number of bytes used by a single property lookup cache.
+    pub(crate) const fn cache_byte_size() -> usize {
+        size_of::<PropertyLookupCacheRecord<'static>>()
+            + size_of::<PropertyLookupCacheRecordPrototypes<'static>>()
+    }
+
     pub(crate) fn mark_cache(&self, index: usize, queues: &mut WorkQueues) {
         self.property_lookup_caches[index].mark_values(queues);
         self.property_lookup_cache_prototypes[index].mark_values(queues);
@@ -2,9 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use std::{
-    cmp::Ordering, collections::TryReserveError, marker::PhantomData, num::NonZeroU32, ptr::NonNull,
-};
+use std::{cmp::Ordering, marker::PhantomData, num::NonZeroU32, ptr::NonNull};
 
 use ahash::AHashMap;
 use hashbrown::{HashTable, hash_table::Entry};
@@ -16,8 +14,8 @@ use crate::{
     },
     engine::{Bindable, GcToken, NoGcScope, bindable_handle},
     heap::{
-        ArenaAccess, ArenaAccessMut, CompactionLists, CreateHeapData, DirectArenaAccess,
-        DirectArenaAccessMut, Heap, HeapMarkAndSweep, HeapSweepWeakReference,
+        AllocationError, Arena, ArenaAccess, ArenaAccessMut, CompactionLists, CreateHeapData,
+        DirectArenaAccess, DirectArenaAccessMut, Heap, HeapMarkAndSweep, HeapSweepWeakReference,
         IntrinsicObjectShapes, PropertyKeyHeap, WeakReference, WorkQueues,
         {ElementArrayKey, ElementArrays}, {HeapIndexHandle, PropertyKeyIndex},
     },
@@ -290,7 +288,7 @@ impl<'a> ObjectShape<'a> {
         self,
         agent: &mut Agent,
         key: PropertyKey<'a>,
-    ) -> Result<(), TryReserveError> {
+    ) -> Result<(), AllocationError> {
         debug_assert_eq!(self.values_capacity(agent), self.len(agent).into());
         let ObjectShapeRecord {
             prototype: _,
@@ -316,7 +314,7 @@ impl<'a> ObjectShape<'a> {
         self,
         agent: &mut Agent,
         key: PropertyKey<'a>,
-    ) -> Result<Self, TryReserveError> {
+    ) -> Result<Self, AllocationError> {
         if self.is_intrinsic(agent) {
             // SAFETY: self is intrinsic.
             unsafe { self.push_key(agent, key)? };
@@ -457,7 +455,7 @@ impl<'a> ObjectShape<'a> {
         self,
         agent: &mut Agent,
         index: u32,
-    ) -> Result<Self, TryReserveError> {
+    ) -> Result<Self, AllocationError> {
         let len = self.len(agent);
         debug_assert!(index < len);
         let keys_cap = self.keys_capacity(agent);
@@ -527,7 +525,7 @@ impl<'a> ObjectShape<'a> {
         agent: &mut Agent,
         private_fields: &[PrivateField<'a>],
         insertion_index: usize,
-    ) -> Result<(Self, usize), TryReserveError> {
+    ) -> Result<(Self, usize), AllocationError> {
         let ObjectShapeRecord {
             prototype: _,
             keys,
@@ -575,7 +573,7 @@ impl<'a> ObjectShape<'a> {
         self,
         agent: &mut Agent,
         private_fields: NonNull<[PrivateField<'a>]>,
-    ) -> Result<(Self, usize), TryReserveError> {
+    ) -> Result<(Self, usize), AllocationError> {
         // SAFETY: User guarantees that the fields are not backed by memory
         // that we're going to be mutating.
         let private_fields = unsafe { private_fields.as_ref() };
@@ -746,7 +744,7 @@ impl<'a> ObjectShape<'a> {
     }
 
     /// Create an intrinsic copy of the given Object Shape.
-    pub(crate) fn make_intrinsic(self, agent: &mut Agent) -> Result<Self, TryReserveError> {
+    pub(crate) fn make_intrinsic(self, agent: &mut Agent) -> Result<Self, AllocationError> {
         let properties_count = self.len(agent);
         let prototype = self.get_prototype(agent);
         // Note: intrinsics must always own their keys uniquely, so a copy must
@@ -1102,9 +1100,9 @@ impl AsRef<Vec<ObjectShapeRecord<'static>>> for Agent {
 }
 
 #[doc(hidden)]
-impl AsMut<Vec<ObjectShapeRecord<'static>>> for Agent {
+impl AsMut<Arena<Vec<ObjectShapeRecord<'static>>>> for Agent {
     #[inline(always)]
-    fn as_mut(&mut self) -> &mut Vec<ObjectShapeRecord<'static>> {
+    fn as_mut(&mut self) -> &mut Arena<Vec<ObjectShapeRecord<'static>>> {
         &mut self.heap.object_shapes
     }
 }
@@ -1215,6 +1213,7 @@ impl HeapMarkAndSweep for ObjectShapeRecord<'static> {
             ElementArrayKey::E16 => queues.k_2_16.push(*keys),
             ElementArrayKey::E24 => queues.k_2_24.push(*keys),
             ElementArrayKey::E32 => queues.k_2_32.push(*keys),
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         }
     }
 
@@ -1242,6 +1241,7 @@ impl HeapMarkAndSweep for ObjectShapeRecord<'static> {
             ElementArrayKey::E16 => compactions.k_2_16.shift_index(keys),
             ElementArrayKey::E24 => compactions.k_2_24.shift_index(keys),
             ElementArrayKey::E32 => compactions.k_2_32.shift_index(keys),
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         }
     }
 }
@@ -9,7 +9,8 @@ pub(crate) use data::*;
 use crate::{
     ecmascript::{
         Agent, BUILTIN_STRING_MEMORY, InternalMethods, InternalSlots, JsError, JsResult,
-        OrdinaryObject, PromiseCapability, ProtoIntrinsics, Value, get, object_handle,
+        OrdinaryObject, PromiseCapability, PromiseRejectionTrackerOperation, ProtoIntrinsics,
+        Value, get, object_handle,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable},
     heap::{
@@ -43,10 +44,6 @@ use crate::{
 /// resolved. An unresolved promise is always in the pending state. A resolved
 /// promise may be pending, fulfilled or rejected.
 ///
-/// ## Support status
-///
-/// `Promise` in Nova does not currently support subclassing.
-///
 /// [Job]: crate::ecmascript::Job
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 #[repr(transparent)]
@@ -95,6 +92,21 @@ impl<'a> Promise<'a> {
         }
     }
 
+    /// Mark a rejected Promise as handled, notifying the host if it was
+    /// previously unhandled.
+    pub(crate) fn set_handled(self, agent: &mut Agent) {
+        let PromiseState::Rejected { is_handled, .. } = &mut self.get_mut(agent).promise_state
+        else {
+            return;
+        };
+        if core::mem::replace(is_handled, true) {
+            return;
+        }
+        agent
+            .host_hooks
+            .promise_rejection_tracker(self, PromiseRejectionTrackerOperation::Handle);
+    }
+
     pub(crate) fn set_already_resolved(self, agent: &mut Agent) {
         match &mut self.get_mut(agent).promise_state {
             PromiseState::Pending { is_resolved, .. } => *is_resolved = true,
@@ -4,9 +4,11 @@
 
 mod abstract_operations;
 mod data;
+mod revoker_function;
 
 pub(crate) use abstract_operations::*;
 pub(crate) use data::*;
+pub use revoker_function::*;
 
 use std::collections::VecDeque;
 
@@ -24,8 +26,8 @@ use crate::{
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable, ScopableCollection},
     heap::{
-        ArenaAccess, BaseIndex, CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep,
-        HeapSweepWeakReference, WorkQueues, arena_vec_access,
+        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
+        HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues, arena_vec_access,
     },
 };
 
@@ -57,10 +59,23 @@ impl Proxy<'_> {
                     is_callable(*proxy_target, gc).is_some()
                 }
             }
-            ProxyHeapData::_RevokedCallable => true,
-            ProxyHeapData::_Revoked => false,
+            ProxyHeapData::RevokedCallable => true,
+            ProxyHeapData::Revoked => false,
         }
     }
+
+    /// Sets the \[\[ProxyTarget]] and \[\[ProxyHandler]] of the Proxy to
+    /// null.
+    ///
+    /// The callability of a revoked Proxy is retained.
+    pub(crate) fn revoke(self, agent: &mut Agent, gc: NoGcScope) {
+        let revoked = if self.is_callable(agent, gc) {
+            ProxyHeapData::RevokedCallable
+        } else {
+            ProxyHeapData::Revoked
+        };
+        *self.get_mut(agent) = revoked;
+    }
 }
 
 impl<'a> InternalSlots<'a> for Proxy<'a> {
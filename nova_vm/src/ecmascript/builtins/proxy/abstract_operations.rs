@@ -29,6 +29,9 @@ pub(crate) fn validate_non_revoked_proxy<'a>(
     proxy: Proxy,
     gc: NoGcScope<'a, '_>,
 ) -> JsResult<'a, NonRevokedProxy<'a>> {
+    // Note: chains of Proxies recurse through their targets without calling
+    // functions.
+    agent.check_native_stack(gc)?;
     let ProxyHeapData::NonRevoked {
         proxy_handler: handler,
         proxy_target: target,
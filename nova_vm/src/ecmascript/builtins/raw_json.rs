@@ -0,0 +1,109 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod data;
+
+pub(crate) use data::*;
+
+use crate::{
+    ecmascript::{
+        Agent, BUILTIN_STRING_MEMORY, InternalMethods, InternalSlots, OrdinaryObject, String,
+        object_handle,
+    },
+    engine::{Bindable, NoGcScope},
+    heap::{
+        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
+        HeapMarkAndSweep, HeapSweepWeakReference, ObjectEntry, ObjectEntryPropertyDescriptor,
+        WorkQueues, arena_vec_access,
+    },
+};
+
+/// ## [JSON.rawJSON ( text )](https://tc39.es/proposal-json-parse-with-source/#sec-json.rawjson)
+///
+/// A raw JSON object is a frozen, null-prototype ordinary object with a
+/// single `rawJSON` data property and an \[\[IsRawJSON]] internal slot.
+/// `JSON.stringify` serializes raw JSON objects as their `rawJSON` text
+/// verbatim.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct RawJSON<'a>(BaseIndex<'a, RawJSONHeapData<'static>>);
+object_handle!(RawJSON);
+arena_vec_access!(RawJSON, 'a, RawJSONHeapData, raw_json_objects);
+
+impl<'a> RawJSON<'a> {
+    /// Creates a new raw JSON object for an already validated JSON text.
+    pub(crate) fn create(agent: &mut Agent, raw_json: String, gc: NoGcScope<'a, '_>) -> Self {
+        let raw_json = raw_json.bind(gc);
+        let object_index = OrdinaryObject::create_object(
+            agent,
+            None,
+            &[ObjectEntry {
+                key: BUILTIN_STRING_MEMORY.rawJSON.into(),
+                value: ObjectEntryPropertyDescriptor::Data {
+                    value: raw_json.into(),
+                    writable: false,
+                    enumerable: true,
+                    configurable: false,
+                },
+            }],
+        )
+        .expect("Should perform GC here")
+        .bind(gc);
+        object_index.internal_set_extensible(agent, false);
+        agent.heap.create(RawJSONHeapData {
+            object_index: Some(object_index.unbind()),
+            raw_json: raw_json.unbind(),
+        })
+    }
+
+    /// Returns the JSON text of this raw JSON object.
+    pub(crate) fn raw_json(self, agent: &Agent) -> String<'a> {
+        self.get(agent).raw_json
+    }
+}
+
+impl<'a> InternalSlots<'a> for RawJSON<'a> {
+    #[inline(always)]
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        self.get(agent).object_index.unbind()
+    }
+
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(
+            self.get_mut(agent)
+                .object_index
+                .replace(backing_object.unbind())
+                .is_none()
+        );
+    }
+}
+
+impl<'a> InternalMethods<'a> for RawJSON<'a> {}
+
+impl<'a> CreateHeapData<RawJSONHeapData<'a>, RawJSON<'a>> for Heap {
+    fn create(&mut self, data: RawJSONHeapData<'a>) -> RawJSON<'a> {
+        self.raw_json_objects.push(data.unbind());
+        self.alloc_counter += core::mem::size_of::<RawJSONHeapData<'static>>();
+        RawJSON(BaseIndex::last(&self.raw_json_objects))
+    }
+}
+
+impl HeapMarkAndSweep for RawJSON<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.raw_json_objects.push(*self);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.raw_json_objects.shift_index(&mut self.0);
+    }
+}
+
+impl HeapSweepWeakReference for RawJSON<'static> {
+    fn sweep_weak_reference(self, compactions: &CompactionLists) -> Option<Self> {
+        compactions
+            .raw_json_objects
+            .shift_weak_index(self.0)
+            .map(Self)
+    }
+}
@@ -0,0 +1,38 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{OrdinaryObject, String},
+    engine::bindable_handle,
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct RawJSONHeapData<'a> {
+    /// The frozen, null-prototype object holding the `rawJSON` property.
+    pub(super) object_index: Option<OrdinaryObject<'a>>,
+    /// The JSON text of a primitive value, as validated by `JSON.rawJSON`.
+    pub(super) raw_json: String<'a>,
+}
+bindable_handle!(RawJSONHeapData);
+
+impl HeapMarkAndSweep for RawJSONHeapData<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            raw_json,
+        } = self;
+        object_index.mark_values(queues);
+        raw_json.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            raw_json,
+        } = self;
+        object_index.sweep_values(compactions);
+        raw_json.sweep_values(compactions);
+    }
+}
@@ -2,8 +2,12 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+mod abstract_module_source_constructor;
+mod abstract_module_source_prototype;
 mod proxy_constructor;
 mod reflect_object;
 
+pub(crate) use abstract_module_source_constructor::*;
+pub(crate) use abstract_module_source_prototype::*;
 pub(crate) use proxy_constructor::*;
 pub(crate) use reflect_object::*;
@@ -0,0 +1,58 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, ExceptionType, JsResult, Object, Realm, String, Value,
+        builders::BuiltinFunctionBuilder,
+    },
+    engine::GcScope,
+    heap::IntrinsicConstructorIndexes,
+};
+
+pub(crate) struct AbstractModuleSourceConstructor;
+impl Builtin for AbstractModuleSourceConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.AbstractModuleSource;
+
+    const LENGTH: u8 = 0;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(Self::constructor);
+}
+impl BuiltinIntrinsicConstructor for AbstractModuleSourceConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::AbstractModuleSource;
+}
+
+impl AbstractModuleSourceConstructor {
+    /// ### [28.1.1.1 AbstractModuleSource ( )](https://tc39.es/proposal-source-phase-imports/#sec-abstractmodulesource)
+    ///
+    /// This function is not intended to be called directly, and is only
+    /// present as the base of module source objects defined by hosts.
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        _arguments: ArgumentsList,
+        _new_target: Option<Object>,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Throw a TypeError exception.
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "AbstractModuleSource cannot be constructed",
+            gc.into_nogc(),
+        ))
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let abstract_module_source_prototype = intrinsics.abstract_module_source_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<AbstractModuleSourceConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(1)
+        .with_prototype_property(abstract_module_source_prototype.into())
+        .build();
+    }
+}
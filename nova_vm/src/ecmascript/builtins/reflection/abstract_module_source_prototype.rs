@@ -0,0 +1,62 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter, JsResult,
+        PropertyKey, Realm, String, Value, builders::OrdinaryObjectBuilder,
+    },
+    engine::GcScope,
+    heap::WellKnownSymbols,
+};
+
+pub(crate) struct AbstractModuleSourcePrototype;
+
+struct AbstractModuleSourcePrototypeGetToStringTag;
+impl Builtin for AbstractModuleSourcePrototypeGetToStringTag {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get__Symbol_toStringTag_;
+    const KEY: Option<PropertyKey<'static>> = Some(WellKnownSymbols::ToStringTag.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(AbstractModuleSourcePrototype::get_to_string_tag);
+}
+impl BuiltinGetter for AbstractModuleSourcePrototypeGetToStringTag {}
+
+impl AbstractModuleSourcePrototype {
+    /// ### [28.1.3.2 get %AbstractModuleSource%.prototype \[ %Symbol.toStringTag% \]](https://tc39.es/proposal-source-phase-imports/#sec-get-%abstractmodulesource%.prototype.@@tostringtag)
+    fn get_to_string_tag<'gc>(
+        _agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        _gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Let O be the this value.
+        // 2. If O is not an Object, return undefined.
+        if !this_value.is_object() {
+            return Ok(Value::Undefined);
+        }
+        // 3. If O does not have a [[ModuleSourceClassName]] internal slot,
+        //    return undefined.
+        // Note: no module source objects exist yet, so none have a
+        // [[ModuleSourceClassName]] internal slot.
+        // 4. Let name be O.[[ModuleSourceClassName]].
+        // 5. Assert: name is a String.
+        // 6. Return name.
+        Ok(Value::Undefined)
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.abstract_module_source_prototype();
+        let abstract_module_source_constructor = intrinsics.abstract_module_source();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(2)
+            .with_prototype(object_prototype)
+            .with_constructor_property(abstract_module_source_constructor)
+            .with_builtin_function_getter_property::<AbstractModuleSourcePrototypeGetToStringTag>()
+            .build();
+    }
+}
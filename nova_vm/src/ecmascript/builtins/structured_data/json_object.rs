@@ -9,12 +9,13 @@ use crate::{
     ecmascript::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, ExceptionType, Function,
         InternalMethods, JsResult, Number, Object, Primitive, PrimitiveObject, PrimitiveObjectData,
-        PropertyDescriptor, PropertyKey, ProtoIntrinsics, Realm, ScopedPropertyKey, SmallInteger,
-        String, Value, array_create, builders::OrdinaryObjectBuilder, call_function,
+        PropertyDescriptor, PropertyKey, ProtoIntrinsics, RawJSON, Realm, ScopedPropertyKey,
+        SmallInteger, String, Value, array_create, builders::OrdinaryObjectBuilder, call_function,
         create_data_property, enumerable_own_keys, get, get_v, is_array, is_callable,
-        length_of_array_like, ordinary_object_create_with_intrinsics, scoped_enumerable_own_keys,
-        to_integer_or_infinity_number, to_number, to_property_key, to_property_key_simple,
-        to_string, try_create_data_property, try_create_data_property_or_throw, unwrap_try,
+        length_of_array_like, ordinary_object_create_with_intrinsics, same_value,
+        scoped_enumerable_own_keys, to_integer_or_infinity_number, to_number, to_property_key,
+        to_property_key_simple, to_string, try_create_data_property,
+        try_create_data_property_or_throw, unwrap_try,
     },
     engine::{
         Bindable, GcScope, NoGcScope, Scopable, ScopableCollection, Scoped, ScopedCollection,
@@ -33,6 +34,24 @@ impl Builtin for JSONObjectParse {
     const BEHAVIOUR: Behaviour = Behaviour::Regular(JSONObject::parse);
 }
 
+struct JSONObjectIsRawJSON;
+impl Builtin for JSONObjectIsRawJSON {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.isRawJSON;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(JSONObject::is_raw_json);
+}
+
+struct JSONObjectRawJSON;
+impl Builtin for JSONObjectRawJSON {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.rawJSON;
+
+    const LENGTH: u8 = 1;
+
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(JSONObject::raw_json);
+}
+
 struct JSONObjectStringify;
 impl Builtin for JSONObjectStringify {
     const NAME: String<'static> = BUILTIN_STRING_MEMORY.stringify;
@@ -43,6 +62,18 @@ impl Builtin for JSONObjectStringify {
 }
 
 impl JSONObject {
+    /// ### [JSON.isRawJSON ( O )](https://tc39.es/proposal-json-parse-with-source/#sec-json.israwjson)
+    fn is_raw_json<'gc>(
+        _agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        _gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. If O is an Object and O has an [[IsRawJSON]] internal slot, return true.
+        // 2. Return false.
+        Ok(matches!(arguments.get(0), Value::RawJSON(_)).into())
+    }
+
     /// ### [25.5.1 JSON.parse ( text \[ , reviver \] )](https://tc39.es/ecma262/#sec-json.parse)
     ///
     /// This function parses a JSON text (a JSON-formatted String) and produces
@@ -89,24 +120,35 @@ impl JSONObject {
             .bind(gc.nogc());
 
         // 2. Parse StringToCodePoints(jsonString) as a JSON text as specified in ECMA-404. Throw a SyntaxError exception if it is not a valid JSON text as defined in that specification.
-        let json_value =
-            match sonic_rs::from_str::<sonic_rs::Value>(&json_string.to_string_lossy_(agent)) {
-                Ok(value) => value,
-                Err(error) => {
-                    return Err(agent.throw_exception(
-                        ExceptionType::SyntaxError,
-                        error.to_string(),
-                        gc.into_nogc(),
-                    ));
-                }
-            };
+        let json_text = json_string.to_string_lossy_(agent).into_owned();
+        let json_value = match sonic_rs::from_str::<sonic_rs::Value>(&json_text) {
+            Ok(value) => value,
+            Err(error) => {
+                return Err(agent.throw_exception(
+                    ExceptionType::SyntaxError,
+                    error.to_string(),
+                    gc.into_nogc(),
+                ));
+            }
+        };
+        let reviver = reviver.get(agent).bind(gc.nogc());
+        let reviver = is_callable(reviver, gc.nogc());
 
         // 3. Let scriptString be the string-concatenation of "(", jsonString, and ");".
         // 4. Let script be ParseText(scriptString, Script).
         // 5. NOTE: The early error rules defined in 13.2.5.1 have special handling for the above invocation of ParseText.
         // 6. Assert: script is a Parse Node.
         // 7. Let completion be Completion(Evaluation of script).
-        let completion = value_from_json(agent, &json_value, gc.nogc());
+        // Note: If a reviver is given, we also need the JSON Parse Record
+        // which is created from the JSON source text instead.
+        let (completion, parse_record) = if reviver.is_some() {
+            let json_source = sonic_rs::from_str::<sonic_rs::LazyValue>(&json_text)
+                .expect("JSON text was already validated");
+            let (value, parse_record) = create_json_parse_record(agent, json_source, gc.nogc());
+            (value, Some(parse_record))
+        } else {
+            (value_from_json(agent, &json_value, gc.nogc()), None)
+        };
 
         // 8. NOTE: The PropertyDefinitionEvaluation semantics defined in 13.2.5.5 have special handling for the above evaluation.
         // 9. Let unfiltered be completion.[[Value]].
@@ -122,8 +164,7 @@ impl JSONObject {
         );
 
         // 11. If IsCallable(reviver) is true, then
-        let reviver = reviver.get(agent).bind(gc.nogc());
-        if let Some(reviver) = is_callable(reviver, gc.nogc()) {
+        if let Some(reviver) = reviver {
             let reviver = reviver.bind(gc.nogc());
             // a. Let root be OrdinaryObjectCreate(%Object.prototype%).
             let Object::Object(root) = ordinary_object_create_with_intrinsics(
@@ -151,10 +192,18 @@ impl JSONObject {
                 gc.nogc(),
             ));
 
-            // d. Return ? InternalizeJSONProperty(root, rootName, reviver).
+            // d. Let snapshot be CreateJSONParseRecord(script, rootName, unfiltered).
+            // e. Return ? InternalizeJSONProperty(root, rootName, reviver, snapshot).
             let root = Object::from(root).unbind().scope(agent, gc.nogc());
             let reviver = reviver.unbind().scope(agent, gc.nogc());
-            return internalize_json_property(agent, root, root_name, reviver, gc);
+            return internalize_json_property(
+                agent,
+                root,
+                root_name,
+                reviver,
+                parse_record.as_ref(),
+                gc,
+            );
         }
 
         // 12. Else,
@@ -443,15 +492,78 @@ impl JSONObject {
         }
     }
 
+    /// ### [JSON.rawJSON ( text )](https://tc39.es/proposal-json-parse-with-source/#sec-json.rawjson)
+    ///
+    /// This function returns an object representing raw JSON text of a
+    /// string, number, boolean, or null value. `JSON.stringify` serializes
+    /// such objects as their JSON text verbatim, which allows emitting values
+    /// that are not representable as ECMAScript Numbers.
+    fn raw_json<'gc>(
+        agent: &mut Agent,
+        _this_value: Value,
+        arguments: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let text = arguments.get(0).bind(gc.nogc());
+        // 1. Let jsonString be ? ToString(text).
+        let json_string = to_string(agent, text.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let json_string = json_string.bind(gc);
+        // 2. Throw a SyntaxError exception if jsonString is the empty String,
+        //    or if either the first or last code unit of jsonString is any of
+        //    0x0009 (CHARACTER TABULATION), 0x000A (LINE FEED), 0x000D
+        //    (CARRIAGE RETURN), or 0x0020 (SPACE).
+        let bytes = json_string.as_bytes(agent);
+        let is_json_whitespace = |b: &u8| matches!(b, b'\t' | b'\n' | b'\r' | b' ');
+        if bytes.first().is_none_or(is_json_whitespace)
+            || bytes.last().is_some_and(is_json_whitespace)
+        {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::SyntaxError,
+                "Raw JSON text cannot be empty or start or end with whitespace",
+                gc,
+            ));
+        }
+        // 3. Parse StringToCodePoints(jsonString) as a JSON text as specified
+        //    in ECMA-404. Throw a SyntaxError exception if it is not a valid
+        //    JSON text as defined in that specification, or if its outermost
+        //    value is an object or array as defined in that specification.
+        match sonic_rs::from_str::<sonic_rs::Value>(&json_string.to_string_lossy_(agent)) {
+            Ok(value) if value.is_object() || value.is_array() => {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::SyntaxError,
+                    "Raw JSON text must be a primitive value",
+                    gc,
+                ));
+            }
+            Ok(_) => {}
+            Err(error) => {
+                return Err(agent.throw_exception(
+                    ExceptionType::SyntaxError,
+                    error.to_string(),
+                    gc,
+                ));
+            }
+        }
+        // 4. Let internalSlotsList be « [[IsRawJSON]] ».
+        // 5. Let obj be OrdinaryObjectCreate(null, internalSlotsList).
+        // 6. Perform ! CreateDataPropertyOrThrow(obj, "rawJSON", jsonString).
+        // 7. Perform ! SetIntegrityLevel(obj, frozen).
+        // 8. Return obj.
+        Ok(RawJSON::create(agent, json_string, gc).into())
+    }
+
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>) {
         let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
         let object_prototype = intrinsics.object_prototype();
         let this = intrinsics.json();
 
         OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
-            .with_property_capacity(3)
+            .with_property_capacity(5)
             .with_prototype(object_prototype)
+            .with_builtin_function_property::<JSONObjectIsRawJSON>()
             .with_builtin_function_property::<JSONObjectParse>()
+            .with_builtin_function_property::<JSONObjectRawJSON>()
             .with_builtin_function_property::<JSONObjectStringify>()
             .with_property(|builder| {
                 builder
@@ -465,12 +577,12 @@ impl JSONObject {
     }
 }
 
-/// ### [25.5.1.1 InternalizeJSONProperty ( holder, name, reviver )](https://tc39.es/ecma262/#sec-internalizejsonproperty)
+/// ### [25.5.1.1 InternalizeJSONProperty ( holder, name, reviver, parseRecord )](https://tc39.es/proposal-json-parse-with-source/#sec-internalizejsonproperty)
 ///
 /// The abstract operation InternalizeJSONProperty takes arguments holder (an
-/// Object), name (a String), and reviver (a function object) and returns
-/// either a normal completion containing an ECMAScript language value or a
-/// throw completion.
+/// Object), name (a String), reviver (a function object), and parseRecord
+/// (either a JSON Parse Record or empty) and returns either a normal
+/// completion containing an ECMAScript language value or a throw completion.
 ///
 /// > Note 1
 /// > This algorithm intentionally does not throw an exception if either
@@ -484,8 +596,10 @@ fn internalize_json_property<'a>(
     holder: Scoped<Object>,
     name: impl IndirectPropertyKey,
     reviver: Scoped<Function>,
+    parse_record: Option<&JSONParseRecord>,
     mut gc: GcScope<'a, '_>,
 ) -> JsResult<'a, Value<'a>> {
+    agent.check_native_stack(gc.nogc()).unbind()?;
     // 1. Let val be ? Get(holder, name).
     let val = get(
         agent,
@@ -495,7 +609,16 @@ fn internalize_json_property<'a>(
     )
     .unbind()?
     .bind(gc.nogc());
-    // 2. If val is an Object, then
+    // 2. Let context be OrdinaryObjectCreate(%Object.prototype%).
+    // Note: We create the context object only right before calling the
+    // reviver, as it is not observable before that.
+    // 3. If parseRecord is a JSON Parse Record and SameValue(parseRecord.[[Value]], val) is true, then
+    // 4. Else,
+    // a. Let elementRecords be a new empty List.
+    // b. Let entryRecords be a new empty List.
+    let parse_record =
+        parse_record.filter(|record| same_value(agent, record.value.get(agent), val));
+    // 5. If val is an Object, then
     let val = if let Ok(val) = Object::try_from(val) {
         // a. Let isArray be ? IsArray(val).
         // b. If isArray is true, then
@@ -511,12 +634,17 @@ fn internalize_json_property<'a>(
                 // 1. Let prop be ! ToString(𝔽(I)).
                 let prop = PropertyKey::from(SmallInteger::try_from(i).unwrap()).scope_static();
 
-                // 2. Let newElement be ? InternalizeJSONProperty(val, prop, reviver).
+                // 2. If I < the number of elements in elementRecords, let elementRecord be elementRecords[I]. Otherwise, let elementRecord be empty.
+                let element_record =
+                    parse_record.and_then(|record| record.elements.get(i as usize));
+
+                // 3. Let newElement be ? InternalizeJSONProperty(val, prop, reviver, elementRecord).
                 let new_element = internalize_json_property(
                     agent,
                     scoped_val.clone(),
                     prop.clone(),
                     reviver.clone(),
+                    element_record,
                     gc.reborrow(),
                 )
                 .unbind()?
@@ -557,12 +685,20 @@ fn internalize_json_property<'a>(
 
             // ii. For each String P of keys, do
             for p in keys.iter(agent) {
-                // 1. Let newElement be ? InternalizeJSONProperty(val, P, reviver).
+                // 1. If entryRecords contains a Record whose [[Key]] field is P, let entryRecord be the value of the [[Value]] field of that Record. Otherwise, let entryRecord be empty.
+                let entry_record = parse_record.and_then(|record| {
+                    let key = p.get_key(agent, gc.nogc());
+                    let key = key.as_display(agent).to_string();
+                    record.get_entry(&key)
+                });
+
+                // 2. Let newElement be ? InternalizeJSONProperty(val, P, reviver, entryRecord).
                 let new_element = internalize_json_property(
                     agent,
                     scoped_val.clone(),
                     p,
                     reviver.clone(),
+                    entry_record,
                     gc.reborrow(),
                 )
                 .unbind()?
@@ -597,7 +733,26 @@ fn internalize_json_property<'a>(
         val
     };
 
-    // 3. Return ? Call(reviver, holder, « name, val »).
+    let Object::Object(context) =
+        ordinary_object_create_with_intrinsics(agent, ProtoIntrinsics::Object, None, gc.nogc())
+    else {
+        unreachable!()
+    };
+    // 3.a. If val is not an Object, then
+    if let Some(source) = parse_record.and_then(|record| record.source.as_deref()) {
+        // i. Perform ! CreateDataPropertyOrThrow(context, "source", CodePointsToString(parseRecord.[[ParseNode]]'s source text)).
+        let source = String::from_str(agent, source, gc.nogc());
+        unwrap_try(try_create_data_property_or_throw(
+            agent,
+            context,
+            BUILTIN_STRING_MEMORY.source.to_property_key(),
+            source.into(),
+            None,
+            gc.nogc(),
+        ));
+    }
+
+    // 6. Return ? Call(reviver, holder, « name, val, context »).
     // Note: Because this call gets holder as `this`, it can do dirty things to
     // it, such as `holder[other_key] = new Proxy()`.
     let name = name
@@ -610,11 +765,42 @@ fn internalize_json_property<'a>(
         Some(ArgumentsList::from_mut_slice(&mut [
             name.unbind().into(),
             val.unbind(),
+            context.unbind().into(),
         ])),
         gc,
     )
 }
 
+/// ### [JSON Parse Record](https://tc39.es/proposal-json-parse-with-source/#sec-json-parse-record)
+///
+/// A JSON Parse Record is a Record value used to describe the initial state of
+/// a value parsed from JSON text.
+struct JSONParseRecord<'a> {
+    /// ### \[\[ParseNode]]
+    ///
+    /// The source text of a primitive value; objects and arrays have no
+    /// source text exposed to the reviver.
+    source: Option<Box<str>>,
+    /// ### \[\[Value]]
+    value: Scoped<'a, Value<'static>>,
+    /// ### \[\[Elements]]
+    elements: Vec<JSONParseRecord<'a>>,
+    /// ### \[\[Entries]]
+    ///
+    /// Duplicate keys are kept in source order; the last one is used.
+    entries: Vec<(Box<str>, JSONParseRecord<'a>)>,
+}
+
+impl JSONParseRecord<'_> {
+    fn get_entry(&self, key: &str) -> Option<&Self> {
+        self.entries
+            .iter()
+            .rev()
+            .find(|(entry_key, _)| entry_key.as_ref() == key)
+            .map(|(_, record)| record)
+    }
+}
+
 struct JSONSerializationRecord<'a> {
     result: Wtf8Buf,
     replacer_function: Option<Scoped<'a, Function<'static>>>,
@@ -798,6 +984,12 @@ fn serialize_json_property_value<'a, 'b>(
         Value::Boolean(false) => {
             state.result.push_str("false");
         }
+        // 4.e. If value has an [[IsRawJSON]] internal slot, return ! Get(value, "rawJSON").
+        Value::RawJSON(value) => {
+            state
+                .result
+                .push_wtf8(value.raw_json(agent).as_wtf8_(agent));
+        }
         // 8. If value is a String, return QuoteJSONString(value).
         Value::String(_) | Value::SmallString(_) => {
             let value = String::try_from(value).unwrap();
@@ -940,6 +1132,7 @@ fn serialize_json_object<'a, 'b>(
     value: Object<'static>,
     mut gc: GcScope<'a, 'b>,
 ) -> JsResult<'a, ()> {
+    agent.check_native_stack(gc.nogc()).unbind()?;
     let value = value.bind(gc.nogc());
     // 1. If state.[[Stack]] contains value, throw a TypeError exception
     //    because the structure is cyclical.
@@ -1110,6 +1303,7 @@ fn serialize_json_array<'a, 'b>(
     value: Object<'static>,
     mut gc: GcScope<'a, 'b>,
 ) -> JsResult<'a, ()> {
+    agent.check_native_stack(gc.nogc()).unbind()?;
     let value = value.bind(gc.nogc());
     // 1. If state.[[Stack]] contains value, throw a TypeError exception because the structure is cyclical.
     if state
@@ -1277,3 +1471,72 @@ pub(crate) fn value_from_json<'gc>(
         }
     }
 }
+
+/// ### [CreateJSONParseRecord ( parseNode, key, val )](https://tc39.es/proposal-json-parse-with-source/#sec-createjsonparserecord)
+///
+/// Creates the ECMAScript value of a JSON text alongside its JSON Parse
+/// Record.
+fn create_json_parse_record<'gc, 'scope>(
+    agent: &mut Agent,
+    json: sonic_rs::LazyValue,
+    gc: NoGcScope<'gc, 'scope>,
+) -> (Value<'gc>, JSONParseRecord<'scope>) {
+    let mut elements = Vec::new();
+    let mut entries = Vec::new();
+    let mut source = None;
+    let value: Value = match json.get_type() {
+        sonic_rs::JsonType::Array => {
+            let json_elements = json
+                .into_array_iter()
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .expect("JSON text was already validated");
+            let len = json_elements.len();
+            let array_obj = array_create(agent, len, len, None, gc).unwrap();
+            elements.reserve_exact(len);
+            for (i, json_element) in json_elements.into_iter().enumerate() {
+                let prop = PropertyKey::from(SmallInteger::try_from(i as i64).unwrap());
+                let (js_value, element_record) = create_json_parse_record(agent, json_element, gc);
+                unwrap_try(try_create_data_property(
+                    agent, array_obj, prop, js_value, None, gc,
+                ));
+                elements.push(element_record);
+            }
+            array_obj.into()
+        }
+        sonic_rs::JsonType::Object => {
+            let Object::Object(object) =
+                ordinary_object_create_with_intrinsics(agent, ProtoIntrinsics::Object, None, gc)
+            else {
+                unreachable!()
+            };
+            for entry in json.into_object_iter().unwrap() {
+                let (key, json_value) = entry.expect("JSON text was already validated");
+                let prop = PropertyKey::from_str(agent, &key, gc);
+                let (js_value, entry_record) = create_json_parse_record(agent, json_value, gc);
+                unwrap_try(try_create_data_property(
+                    agent, object, prop, js_value, None, gc,
+                ));
+                entries.push((key.into(), entry_record));
+            }
+            object.into()
+        }
+        _ => {
+            // Note: The raw text of a value may include trailing whitespace.
+            let json_source = json
+                .as_raw_str()
+                .trim_matches(|c| matches!(c, '\t' | '\n' | '\r' | ' '));
+            let json_value = sonic_rs::from_str::<sonic_rs::Value>(json_source)
+                .expect("JSON text was already validated");
+            source = Some(json_source.into());
+            value_from_json(agent, &json_value, gc)
+        }
+    };
+    let record = JSONParseRecord {
+        source,
+        value: value.scope(agent, gc),
+        elements,
+        entries,
+    };
+    (value, record)
+}
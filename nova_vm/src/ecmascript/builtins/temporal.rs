@@ -2,21 +2,41 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
+mod calendar;
 mod duration;
 mod error;
 mod instant;
+mod now;
 mod options;
+mod plain_date;
+mod plain_date_time;
+mod plain_month_day;
 mod plain_time;
+mod plain_year_month;
+mod time_zone;
+mod zoned_date_time;
 
+pub(crate) use calendar::*;
 pub use duration::*;
 pub(crate) use error::*;
 pub use instant::*;
+pub(crate) use now::*;
 pub(crate) use options::*;
+pub use plain_date::*;
+pub use plain_date_time::*;
+pub use plain_month_day::*;
 pub use plain_time::*;
+pub use plain_year_month::*;
+pub(crate) use time_zone::*;
+pub use zoned_date_time::*;
 
 use temporal_rs::{
-    options::{DifferenceSettings, RoundingIncrement, RoundingMode, Unit, UnitGroup},
+    options::{
+        DifferenceSettings, Disambiguation, DisplayCalendar, DisplayOffset, DisplayTimeZone,
+        OffsetDisambiguation, Overflow, RoundingIncrement, RoundingMode, Unit, UnitGroup,
+    },
     parsers::Precision,
+    provider::TransitionDirection,
 };
 
 use crate::{
@@ -39,9 +59,15 @@ impl TemporalObject {
         let instant_constructor = intrinsics.temporal_instant();
         let duration_constructor = intrinsics.temporal_duration();
         let plain_time_constructor = intrinsics.temporal_plain_time();
+        let plain_date_time_constructor = intrinsics.temporal_plain_date_time();
+        let plain_date_constructor = intrinsics.temporal_plain_date();
+        let plain_year_month_constructor = intrinsics.temporal_plain_year_month();
+        let plain_month_day_constructor = intrinsics.temporal_plain_month_day();
+        let zoned_date_time_constructor = intrinsics.temporal_zoned_date_time();
+        let now_object = intrinsics.temporal_now();
 
         OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
-            .with_property_capacity(4)
+            .with_property_capacity(10)
             .with_prototype(object_prototype)
             // 1.2.1 Temporal.Instant ( . . . )
             .with_property(|builder| {
@@ -53,7 +79,23 @@ impl TemporalObject {
                     .build()
             })
             // 1.2.2 Temporal.PlainDateTime ( . . . )
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.PlainDateTime.into())
+                    .with_value(plain_date_time_constructor.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
             // 1.2.3 Temporal.PlainDate ( . . . )
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.PlainDate.into())
+                    .with_value(plain_date_constructor.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
             // 1.2.4 Temporal.PlainTime ( . . . )
             .with_property(|builder| {
                 builder
@@ -64,7 +106,23 @@ impl TemporalObject {
                     .build()
             })
             // 1.2.5 Temporal.PlainYearMonth ( . . . )
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.PlainYearMonth.into())
+                    .with_value(plain_year_month_constructor.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
             // 1.2.6 Temporal.PlainMonthDay ( . . . )
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.PlainMonthDay.into())
+                    .with_value(plain_month_day_constructor.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
             // 1.2.7 Temporal.Duration ( . . . )
             .with_property(|builder| {
                 builder
@@ -75,7 +133,23 @@ impl TemporalObject {
                     .build()
             })
             // 1.2.8 Temporal.ZonedDateTime ( . . . )
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.ZonedDateTime.into())
+                    .with_value(zoned_date_time_constructor.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
             // 1.3.1 Temporal.Now
+            .with_property(|builder| {
+                builder
+                    .with_key(BUILTIN_STRING_MEMORY.Now.into())
+                    .with_value(now_object.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
             .with_property(|builder| {
                 builder
                     .with_key(WellKnownSymbols::ToStringTag.into())
@@ -94,6 +168,13 @@ trivially_bindable!(Unit);
 trivially_bindable!(RoundingMode);
 trivially_bindable!(RoundingIncrement);
 trivially_bindable!(Precision);
+trivially_bindable!(Overflow);
+trivially_bindable!(Disambiguation);
+trivially_bindable!(OffsetDisambiguation);
+trivially_bindable!(DisplayCalendar);
+trivially_bindable!(DisplayOffset);
+trivially_bindable!(DisplayTimeZone);
+trivially_bindable!(TransitionDirection);
 
 /// [13.15 GetTemporalFractionalSecondDigitsOption ( options )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporalfractionalseconddigitsoption)
 /// The abstract operation GetTemporalFractionalSecondDigitsOption takes argument
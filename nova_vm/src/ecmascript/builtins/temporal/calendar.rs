@@ -0,0 +1,708 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::str::FromStr;
+
+use temporal_rs::{
+    Calendar, MonthCode, TimeZone, TinyAsciiStr, UtcOffset,
+    fields::{CalendarFields, YearMonthCalendarFields},
+    options::Overflow,
+    partial::PartialTime,
+};
+
+use crate::{
+    ecmascript::{
+        Agent, BUILTIN_STRING_MEMORY, ExceptionType, JsResult, Object, PreferredType, PropertyKey,
+        String, Value, get, temporal_err_to_js_err, to_integer_with_truncation, to_primitive,
+        to_string, to_temporal_time_zone_identifier,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable, trivially_bindable},
+};
+
+trivially_bindable!(Calendar);
+trivially_bindable!(CalendarFields);
+trivially_bindable!(YearMonthCalendarFields);
+trivially_bindable!(MonthCode);
+trivially_bindable!(UtcOffset);
+trivially_bindable!(PartialTime);
+trivially_bindable!(TemporalFields);
+
+/// Returns the \[\[Calendar]] internal slot of a Temporal object, if the value
+/// is a Temporal object that has one.
+pub(crate) fn get_temporal_calendar_slot(agent: &Agent, value: Value) -> Option<Calendar> {
+    match value {
+        Value::PlainDate(d) => Some(d.inner_plain_date(agent).calendar().clone()),
+        Value::PlainDateTime(d) => Some(d.inner_plain_date_time(agent).calendar().clone()),
+        Value::PlainMonthDay(d) => Some(d.inner_plain_month_day(agent).calendar().clone()),
+        Value::PlainYearMonth(d) => Some(d.inner_plain_year_month(agent).calendar().clone()),
+        Value::ZonedDateTime(d) => Some(d.inner_zoned_date_time(agent).calendar().clone()),
+        _ => None,
+    }
+}
+
+/// ### [12.2.9 ToTemporalCalendarIdentifier ( temporalCalendarLike )](https://tc39.es/proposal-temporal/#sec-temporal-totemporalcalendaridentifier)
+///
+/// The abstract operation ToTemporalCalendarIdentifier takes argument
+/// temporalCalendarLike (an ECMAScript language value) and returns either a
+/// normal completion containing a calendar type or a throw completion. It
+/// attempts to derive a calendar type from temporalCalendarLike.
+pub(crate) fn to_temporal_calendar_identifier<'gc>(
+    agent: &mut Agent,
+    temporal_calendar_like: Value,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, Calendar> {
+    let temporal_calendar_like = temporal_calendar_like.bind(gc);
+    // 1. If temporalCalendarLike is an Object, then
+    //     a. If temporalCalendarLike has an [[InitializedTemporalDate]],
+    //        [[InitializedTemporalDateTime]], [[InitializedTemporalMonthDay]],
+    //        [[InitializedTemporalYearMonth]], or
+    //        [[InitializedTemporalZonedDateTime]] internal slot, then
+    //         i. Return temporalCalendarLike.[[Calendar]].
+    if let Some(calendar) = get_temporal_calendar_slot(agent, temporal_calendar_like) {
+        return Ok(calendar);
+    }
+    // 2. If temporalCalendarLike is not a String, throw a TypeError exception.
+    let Ok(temporal_calendar_like) = String::try_from(temporal_calendar_like) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Calendar must be a string",
+            gc,
+        ));
+    };
+    // 3. Let identifier be ? ParseTemporalCalendarString(temporalCalendarLike).
+    // 4. Return ? CanonicalizeCalendar(identifier).
+    let identifier = temporal_calendar_like.to_string_lossy(agent);
+    Calendar::from_str(&identifier).map_err(|err| temporal_err_to_js_err(agent, err, gc))
+}
+
+/// Resolves the calendar argument of a Temporal constructor.
+///
+/// This performs the constructor steps: "If calendar is undefined, set
+/// calendar to "iso8601". If calendar is not a String, throw a TypeError
+/// exception. Set calendar to ? CanonicalizeCalendar(calendar)."
+pub(crate) fn to_constructor_calendar<'gc>(
+    agent: &mut Agent,
+    calendar: Value,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, Calendar> {
+    let calendar = calendar.bind(gc);
+    if calendar.is_undefined() {
+        return Ok(Calendar::ISO);
+    }
+    let Ok(calendar) = String::try_from(calendar) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Calendar must be a string",
+            gc,
+        ));
+    };
+    let identifier = calendar.to_string_lossy(agent);
+    Calendar::from_str(&identifier).map_err(|err| temporal_err_to_js_err(agent, err, gc))
+}
+
+/// ### [12.2.10 GetTemporalCalendarIdentifierWithISODefault ( item )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporalcalendarslotvaluewithisodefault)
+///
+/// The abstract operation GetTemporalCalendarIdentifierWithISODefault takes
+/// argument item (an Object) and returns either a normal completion containing
+/// a calendar type or a throw completion. It looks for a calendar property on
+/// the given item and converts its value into a calendar type. If no such
+/// property is present, the built-in ISO 8601 calendar is returned.
+pub(crate) fn get_temporal_calendar_identifier_with_iso_default<'gc>(
+    agent: &mut Agent,
+    item: Object,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Calendar> {
+    let item = item.bind(gc.nogc());
+    // 1. If item has an [[InitializedTemporalDate]],
+    //    [[InitializedTemporalDateTime]], [[InitializedTemporalMonthDay]],
+    //    [[InitializedTemporalYearMonth]], or
+    //    [[InitializedTemporalZonedDateTime]] internal slot, then
+    //     a. Return item.[[Calendar]].
+    if let Some(calendar) = get_temporal_calendar_slot(agent, item.into()) {
+        return Ok(calendar);
+    }
+    // 2. Let calendarLike be ? Get(item, "calendar").
+    let calendar_like = get(
+        agent,
+        item.unbind(),
+        BUILTIN_STRING_MEMORY.calendar.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    let gc = gc.into_nogc();
+    let calendar_like = calendar_like.bind(gc);
+    // 3. If calendarLike is undefined, return "iso8601".
+    if calendar_like.is_undefined() {
+        return Ok(Calendar::ISO);
+    }
+    // 4. Return ? ToTemporalCalendarIdentifier(calendarLike).
+    to_temporal_calendar_identifier(agent, calendar_like, gc)
+}
+
+/// The set of property names that PrepareCalendarFields reads from an object.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct CalendarFieldNames {
+    year: bool,
+    month: bool,
+    day: bool,
+    time: bool,
+    offset: bool,
+    time_zone: bool,
+}
+
+impl CalendarFieldNames {
+    /// « year, month, month-code, day »
+    pub(crate) const DATE: Self = Self {
+        year: true,
+        month: true,
+        day: true,
+        time: false,
+        offset: false,
+        time_zone: false,
+    };
+    /// « year, month, month-code »
+    pub(crate) const YEAR_MONTH: Self = Self {
+        day: false,
+        ..Self::DATE
+    };
+    /// « year, month, month-code, day, hour, minute, second, millisecond,
+    /// microsecond, nanosecond »
+    pub(crate) const DATE_TIME: Self = Self {
+        time: true,
+        ..Self::DATE
+    };
+    /// « year, month, month-code, day, hour, minute, second, millisecond,
+    /// microsecond, nanosecond, offset, time-zone »
+    pub(crate) const ZONED_DATE_TIME: Self = Self {
+        offset: true,
+        time_zone: true,
+        ..Self::DATE_TIME
+    };
+    /// « year, month, month-code, day, hour, minute, second, millisecond,
+    /// microsecond, nanosecond, offset »
+    pub(crate) const ZONED_DATE_TIME_WITH: Self = Self {
+        time_zone: false,
+        ..Self::ZONED_DATE_TIME
+    };
+    /// « hour, minute, second, millisecond, microsecond, nanosecond »
+    pub(crate) const TIME: Self = Self {
+        year: false,
+        month: false,
+        day: false,
+        time: true,
+        offset: false,
+        time_zone: false,
+    };
+    /// « year »
+    pub(crate) const YEAR: Self = Self {
+        year: true,
+        time: false,
+        ..Self::TIME
+    };
+    /// « day »
+    pub(crate) const DAY: Self = Self {
+        day: true,
+        time: false,
+        ..Self::TIME
+    };
+}
+
+/// A Calendar Fields Record as returned by PrepareCalendarFields. Integer
+/// fields are kept at full precision; they are narrowed to their storage
+/// types by the conversion methods, which apply the overflow behaviour.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct TemporalFields {
+    pub(crate) day: Option<i64>,
+    pub(crate) era: Option<TinyAsciiStr<19>>,
+    pub(crate) era_year: Option<i64>,
+    pub(crate) hour: Option<i64>,
+    pub(crate) microsecond: Option<i64>,
+    pub(crate) millisecond: Option<i64>,
+    pub(crate) minute: Option<i64>,
+    pub(crate) month: Option<i64>,
+    pub(crate) month_code: Option<MonthCode>,
+    pub(crate) nanosecond: Option<i64>,
+    pub(crate) offset: Option<UtcOffset>,
+    pub(crate) second: Option<i64>,
+    pub(crate) time_zone: Option<TimeZone>,
+    pub(crate) year: Option<i64>,
+}
+
+impl TemporalFields {
+    pub(crate) fn to_calendar_fields<'gc>(
+        &self,
+        agent: &mut Agent,
+        overflow: Overflow,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, CalendarFields> {
+        Ok(CalendarFields {
+            year: self.year.map(narrow_year),
+            month: narrow_field(agent, self.month, u8::MAX, overflow, gc)?,
+            month_code: self.month_code,
+            day: narrow_field(agent, self.day, u8::MAX, overflow, gc)?,
+            era: self.era,
+            era_year: self.era_year.map(narrow_year),
+        })
+    }
+
+    pub(crate) fn to_year_month_calendar_fields<'gc>(
+        &self,
+        agent: &mut Agent,
+        overflow: Overflow,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, YearMonthCalendarFields> {
+        Ok(YearMonthCalendarFields {
+            year: self.year.map(narrow_year),
+            month: narrow_field(agent, self.month, u8::MAX, overflow, gc)?,
+            month_code: self.month_code,
+            era: self.era,
+            era_year: self.era_year.map(narrow_year),
+        })
+    }
+
+    pub(crate) fn to_partial_time<'gc>(
+        &self,
+        agent: &mut Agent,
+        overflow: Overflow,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, PartialTime> {
+        Ok(PartialTime {
+            hour: narrow_field(agent, self.hour, u8::MAX, overflow, gc)?,
+            minute: narrow_field(agent, self.minute, u8::MAX, overflow, gc)?,
+            second: narrow_field(agent, self.second, u8::MAX, overflow, gc)?,
+            millisecond: narrow_field(agent, self.millisecond, u16::MAX, overflow, gc)?,
+            microsecond: narrow_field(agent, self.microsecond, u16::MAX, overflow, gc)?,
+            nanosecond: narrow_field(agent, self.nanosecond, u16::MAX, overflow, gc)?,
+        })
+    }
+}
+
+fn narrow_year(year: i64) -> i32 {
+    // Note: years outside of the i32 range are outside of the valid ISO date
+    // range, and are rejected by the calendar.
+    year.clamp(i32::MIN.into(), i32::MAX.into()) as i32
+}
+
+/// Narrows an integer field value into its storage type. Values above the
+/// storage type's range are out of the field's valid range as well, so they
+/// are saturated and left to the calendar to constrain or reject. Negative
+/// values are constrained to zero, or rejected if overflow is reject.
+fn narrow_field<'gc, T: TryFrom<i64>>(
+    agent: &mut Agent,
+    value: Option<i64>,
+    max: T,
+    overflow: Overflow,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, Option<T>> {
+    let Some(value) = value else {
+        return Ok(None);
+    };
+    if value < 0 && overflow == Overflow::Reject {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Temporal field value is out of range",
+            gc,
+        ));
+    }
+    Ok(Some(T::try_from(value.max(0)).unwrap_or(max)))
+}
+
+/// ### [12.2.3 PrepareCalendarFields ( calendar, fields, calendarFieldNames, nonCalendarFieldNames, requiredFieldNames )](https://tc39.es/proposal-temporal/#sec-temporal-preparecalendarfields)
+///
+/// The abstract operation PrepareCalendarFields takes arguments calendar (a
+/// calendar type), fields (an Object), calendarFieldNames (a List of Calendar
+/// Field Record field names), nonCalendarFieldNames (a List of Calendar Field
+/// Record field names), and requiredFieldNames (partial or a List of Calendar
+/// Field Record field names) and returns either a normal completion containing
+/// a Calendar Fields Record or a throw completion. It reads the given fields
+/// from fields in alphabetical order, converting each present value.
+///
+/// If `partial` is true, requiredFieldNames is partial and a TypeError is
+/// thrown if none of the fields are present. Otherwise requiredFieldNames is
+/// empty and missing fields are left for the calendar to report.
+pub(crate) fn prepare_calendar_fields<'gc>(
+    agent: &mut Agent,
+    calendar: &Calendar,
+    fields: Object,
+    field_names: CalendarFieldNames,
+    partial: bool,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, TemporalFields> {
+    let fields = fields.scope(agent, gc.nogc());
+    // 1. Let fieldNames be the list-concatenation of calendarFieldNames and
+    //    nonCalendarFieldNames.
+    // 2. Let extraFieldNames be CalendarExtraFields(calendar, calendarFieldNames).
+    // 3. Set fieldNames to the list-concatenation of fieldNames and extraFieldNames.
+    // 4. Assert: fieldNames contains no duplicate elements.
+    let read_era = field_names.year && !calendar.is_iso();
+    // 5. Let result be a Calendar Fields Record with all fields equal to unset.
+    let mut result = TemporalFields::default();
+    // 6. Let any be false.
+    // NOTE: any is computed from the result once all fields have been read.
+    // 7. Let sortedPropertyNames be a List whose elements are the values in the
+    //    Property Key column of Table 19 corresponding to the elements of
+    //    fieldNames, sorted according to lexicographic code unit order.
+    // 8. For each property name property of sortedPropertyNames, do
+    //     a. Let key be the value in the Enumeration Key column of Table 19
+    //        corresponding to the row whose Property Key value is property.
+    //     b. Let value be ? Get(fields, property).
+    //     c. If value is not undefined, then
+    //         i. Set any to true.
+    //         ii. Let Conversion be the Conversion value of the same row.
+    //         iii-x. Convert value according to Conversion.
+    //         xi. Set result's field whose name is given in the Field Name
+    //             column of the same row to value.
+    if field_names.day {
+        result.day = get_integer_field(
+            agent,
+            fields.get(agent),
+            BUILTIN_STRING_MEMORY.day.into(),
+            true,
+            gc.reborrow(),
+        )
+        .unbind()?;
+    }
+    if read_era {
+        if let Some(era) = get_string_field(
+            agent,
+            fields.get(agent),
+            BUILTIN_STRING_MEMORY.era.into(),
+            true,
+            gc.reborrow(),
+        )
+        .unbind()?
+        {
+            let gc = gc.nogc();
+            let era = era.bind(gc);
+            let era = era.to_string_lossy(agent);
+            let Ok(era) = TinyAsciiStr::<19>::try_from_str(&era) else {
+                return Err(agent
+                    .throw_exception_with_static_message(
+                        ExceptionType::RangeError,
+                        "Invalid era",
+                        gc,
+                    )
+                    .unbind());
+            };
+            result.era = Some(era);
+        }
+        result.era_year = get_integer_field(
+            agent,
+            fields.get(agent),
+            BUILTIN_STRING_MEMORY.eraYear.into(),
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+    }
+    if field_names.time {
+        result.hour = get_integer_field(
+            agent,
+            fields.get(agent),
+            BUILTIN_STRING_MEMORY.hour.into(),
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        result.microsecond = get_integer_field(
+            agent,
+            fields.get(agent),
+            BUILTIN_STRING_MEMORY.microsecond.into(),
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        result.millisecond = get_integer_field(
+            agent,
+            fields.get(agent),
+            BUILTIN_STRING_MEMORY.millisecond.into(),
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        result.minute = get_integer_field(
+            agent,
+            fields.get(agent),
+            BUILTIN_STRING_MEMORY.minute.into(),
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+    }
+    if field_names.month {
+        result.month = get_integer_field(
+            agent,
+            fields.get(agent),
+            BUILTIN_STRING_MEMORY.month.into(),
+            true,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        if let Some(month_code) = get_string_field(
+            agent,
+            fields.get(agent),
+            BUILTIN_STRING_MEMORY.monthCode.into(),
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?
+        {
+            let gc = gc.nogc();
+            let month_code = MonthCode::try_from_utf8(month_code.bind(gc).as_bytes(agent))
+                .map_err(|err| temporal_err_to_js_err(agent, err, gc))
+                .unbind()?;
+            result.month_code = Some(month_code);
+        }
+    }
+    if field_names.time {
+        result.nanosecond = get_integer_field(
+            agent,
+            fields.get(agent),
+            BUILTIN_STRING_MEMORY.nanosecond.into(),
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+    }
+    if field_names.offset
+        && let Some(offset) = get_string_field(
+            agent,
+            fields.get(agent),
+            BUILTIN_STRING_MEMORY.offset.into(),
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?
+    {
+        let gc = gc.nogc();
+        let offset = offset.bind(gc);
+        let offset = offset.to_string_lossy(agent);
+        let offset = UtcOffset::from_str(&offset)
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))
+            .unbind()?;
+        result.offset = Some(offset);
+    }
+    if field_names.time {
+        result.second = get_integer_field(
+            agent,
+            fields.get(agent),
+            BUILTIN_STRING_MEMORY.second.into(),
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+    }
+    if field_names.time_zone {
+        let time_zone = get(
+            agent,
+            fields.get(agent),
+            BUILTIN_STRING_MEMORY.timeZone.into(),
+            gc.reborrow(),
+        )
+        .unbind()?
+        .bind(gc.nogc());
+        if !time_zone.is_undefined() {
+            result.time_zone = Some(
+                to_temporal_time_zone_identifier(agent, time_zone.unbind(), gc.nogc()).unbind()?,
+            );
+        }
+    }
+    if field_names.year {
+        result.year = get_integer_field(
+            agent,
+            fields.get(agent),
+            BUILTIN_STRING_MEMORY.year.into(),
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+    }
+    let TemporalFields {
+        day,
+        era,
+        era_year,
+        hour,
+        microsecond,
+        millisecond,
+        minute,
+        month,
+        month_code,
+        nanosecond,
+        offset,
+        second,
+        time_zone,
+        year,
+    } = &result;
+    let any = day.is_some()
+        || era.is_some()
+        || era_year.is_some()
+        || hour.is_some()
+        || microsecond.is_some()
+        || millisecond.is_some()
+        || minute.is_some()
+        || month.is_some()
+        || month_code.is_some()
+        || nanosecond.is_some()
+        || offset.is_some()
+        || second.is_some()
+        || time_zone.is_some()
+        || year.is_some();
+    // 9. If requiredFieldNames is partial and any is false, then
+    if partial && !any {
+        // a. Throw a TypeError exception.
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Object must contain at least one Temporal field",
+            gc.into_nogc(),
+        ));
+    }
+    // 10. Return result.
+    Ok(result)
+}
+
+/// Reads an integer-valued field for PrepareCalendarFields, converting it with
+/// ToPositiveIntegerWithTruncation if `positive` is true and with
+/// ToIntegerWithTruncation otherwise.
+fn get_integer_field<'gc>(
+    agent: &mut Agent,
+    fields: Object,
+    property: PropertyKey,
+    positive: bool,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<i64>> {
+    let value = get(agent, fields, property, gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    let value = if positive {
+        to_positive_integer_with_truncation(agent, value.unbind(), gc)?
+    } else {
+        to_integer_with_truncation(agent, value.unbind(), gc)?
+    };
+    Ok(Some(value))
+}
+
+/// Reads a string-valued field for PrepareCalendarFields, converting it with
+/// ToString if `to_string` is true. Otherwise the value is converted with
+/// ToPrimitive and must be a String.
+fn get_string_field<'gc>(
+    agent: &mut Agent,
+    fields: Object,
+    property: PropertyKey,
+    convert_to_string: bool,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<String<'gc>>> {
+    let value = get(agent, fields, property, gc.reborrow())
+        .unbind()?
+        .bind(gc.nogc());
+    if value.is_undefined() {
+        return Ok(None);
+    }
+    if convert_to_string {
+        return to_string(agent, value.unbind(), gc).map(Some);
+    }
+    let value = to_primitive(
+        agent,
+        value.unbind(),
+        Some(PreferredType::String),
+        gc.reborrow(),
+    )
+    .unbind()?
+    .bind(gc.nogc());
+    let Ok(value) = String::try_from(value) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Temporal field value must be a string",
+            gc.into_nogc(),
+        ));
+    };
+    Ok(Some(value.unbind().bind(gc.into_nogc())))
+}
+
+/// ### [13.39 ToPositiveIntegerWithTruncation ( argument )](https://tc39.es/proposal-temporal/#sec-temporal-topositiveintegerwithtruncation)
+///
+/// The abstract operation ToPositiveIntegerWithTruncation takes argument
+/// argument (an ECMAScript language value) and returns either a normal
+/// completion containing a positive integer or a throw completion. It converts
+/// argument to an integer representing its Number value, with fractional part
+/// truncated, or throws a RangeError when that value is not finite or not
+/// positive.
+fn to_positive_integer_with_truncation<'gc>(
+    agent: &mut Agent,
+    argument: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, i64> {
+    // 1. Let integer be ? ToIntegerWithTruncation(argument).
+    let integer = to_integer_with_truncation(agent, argument, gc.reborrow()).unbind()?;
+    // 2. If integer ≤ 0, throw a RangeError exception.
+    if integer <= 0 {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Value must be a positive integer",
+            gc.into_nogc(),
+        ));
+    }
+    // 3. Return integer.
+    Ok(integer)
+}
+
+/// ### [13.43 IsPartialTemporalObject ( value )](https://tc39.es/proposal-temporal/#sec-temporal-ispartialtemporalobject)
+///
+/// The abstract operation IsPartialTemporalObject takes argument value (an
+/// ECMAScript language value) and returns either a normal completion
+/// containing a Boolean or a throw completion. It determines whether value is
+/// suitable for use as the argument to the `with` method of a Temporal type:
+/// an object that is not itself a Temporal object and has no calendar or time
+/// zone property.
+pub(crate) fn is_partial_temporal_object<'gc>(
+    agent: &mut Agent,
+    value: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, bool> {
+    let value = value.bind(gc.nogc());
+    // 1. If value is not an Object, return false.
+    let Ok(value) = Object::try_from(value) else {
+        return Ok(false);
+    };
+    // 2. If value has an [[InitializedTemporalDate]],
+    //    [[InitializedTemporalDateTime]], [[InitializedTemporalMonthDay]],
+    //    [[InitializedTemporalTime]], [[InitializedTemporalYearMonth]], or
+    //    [[InitializedTemporalZonedDateTime]] internal slot, return false.
+    if matches!(
+        value,
+        Object::PlainDate(_)
+            | Object::PlainDateTime(_)
+            | Object::PlainMonthDay(_)
+            | Object::PlainTime(_)
+            | Object::PlainYearMonth(_)
+            | Object::ZonedDateTime(_)
+    ) {
+        return Ok(false);
+    }
+    let scoped_value = value.scope(agent, gc.nogc());
+    // 3. Let calendarProperty be ? Get(value, "calendar").
+    let calendar_property = get(
+        agent,
+        value.unbind(),
+        BUILTIN_STRING_MEMORY.calendar.into(),
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 4. If calendarProperty is not undefined, return false.
+    if !calendar_property.is_undefined() {
+        return Ok(false);
+    }
+    // 5. Let timeZoneProperty be ? Get(value, "timeZone").
+    let time_zone_property = get(
+        agent,
+        scoped_value.get(agent),
+        BUILTIN_STRING_MEMORY.timeZone.into(),
+        gc,
+    )?;
+    // 6. If timeZoneProperty is not undefined, return false.
+    // 7. Return true.
+    Ok(time_zone_property.is_undefined())
+}
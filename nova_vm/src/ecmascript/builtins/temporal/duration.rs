@@ -86,6 +86,22 @@ impl<'a> CreateHeapData<DurationRecord<'a>, TemporalDuration<'a>> for Heap {
         TemporalDuration(BaseIndex::last(&self.durations))
     }
 }
+#[inline(always)]
+fn require_internal_slot_temporal_duration<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, TemporalDuration<'a>> {
+    match value {
+        Value::Duration(duration) => Ok(duration.bind(gc)),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Object is not a Temporal Duration",
+            gc,
+        )),
+    }
+}
+
 /// [7.5.19 CreateTemporalDuration ( years, months, weeks, days, hours, minutes, seconds, milliseconds, microseconds, nanoseconds [ , newTarget ] )](https://tc39.es/proposal-temporal/#sec-temporal-createtemporalduration)
 /// The abstract operation CreateTemporalDuration takes arguments
 /// years (an integer), months (an integer),
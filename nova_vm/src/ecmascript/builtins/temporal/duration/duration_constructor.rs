@@ -33,16 +33,16 @@ impl TemporalDurationConstructor {
         new_target: Option<Object>,
         mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        let years = args.get(1).scope(agent, gc.nogc());
-        let months = args.get(2).scope(agent, gc.nogc());
-        let weeks = args.get(3).scope(agent, gc.nogc());
-        let days = args.get(4).scope(agent, gc.nogc());
-        let hours = args.get(5).scope(agent, gc.nogc());
-        let minutes = args.get(6).scope(agent, gc.nogc());
-        let seconds = args.get(7).scope(agent, gc.nogc());
-        let milliseconds = args.get(8).scope(agent, gc.nogc());
-        let microseconds = args.get(9).scope(agent, gc.nogc());
-        let nanoseconds = args.get(10).scope(agent, gc.nogc());
+        let years = args.get(0).scope(agent, gc.nogc());
+        let months = args.get(1).scope(agent, gc.nogc());
+        let weeks = args.get(2).scope(agent, gc.nogc());
+        let days = args.get(3).scope(agent, gc.nogc());
+        let hours = args.get(4).scope(agent, gc.nogc());
+        let minutes = args.get(5).scope(agent, gc.nogc());
+        let seconds = args.get(6).scope(agent, gc.nogc());
+        let milliseconds = args.get(7).scope(agent, gc.nogc());
+        let microseconds = args.get(8).scope(agent, gc.nogc());
+        let nanoseconds = args.get(9).scope(agent, gc.nogc());
         let new_target = new_target.bind(gc.nogc());
         // 1. If NewTarget is undefined, throw a TypeError exception.
         let Some(new_target) = new_target else {
@@ -3,13 +3,323 @@
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
 use crate::{
-    ecmascript::{Agent, BUILTIN_STRING_MEMORY, Realm, builders::OrdinaryObjectBuilder},
-    engine::NoGcScope,
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter, JsResult,
+        PropertyKey, Realm, String, Value, builders::OrdinaryObjectBuilder,
+        builtins::temporal::duration::require_internal_slot_temporal_duration,
+    },
+    engine::{GcScope, NoGcScope},
     heap::WellKnownSymbols,
 };
 
 pub(crate) struct TemporalDurationPrototype;
+
+struct TemporalDurationPrototypeGetYears;
+impl Builtin for TemporalDurationPrototypeGetYears {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_years;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.years.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalDurationPrototype::get_years);
+}
+impl BuiltinGetter for TemporalDurationPrototypeGetYears {}
+
+struct TemporalDurationPrototypeGetMonths;
+impl Builtin for TemporalDurationPrototypeGetMonths {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_months;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.months.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalDurationPrototype::get_months);
+}
+impl BuiltinGetter for TemporalDurationPrototypeGetMonths {}
+
+struct TemporalDurationPrototypeGetWeeks;
+impl Builtin for TemporalDurationPrototypeGetWeeks {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_weeks;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.weeks.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalDurationPrototype::get_weeks);
+}
+impl BuiltinGetter for TemporalDurationPrototypeGetWeeks {}
+
+struct TemporalDurationPrototypeGetDays;
+impl Builtin for TemporalDurationPrototypeGetDays {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_days;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.days.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalDurationPrototype::get_days);
+}
+impl BuiltinGetter for TemporalDurationPrototypeGetDays {}
+
+struct TemporalDurationPrototypeGetHours;
+impl Builtin for TemporalDurationPrototypeGetHours {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_hours;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.hours.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalDurationPrototype::get_hours);
+}
+impl BuiltinGetter for TemporalDurationPrototypeGetHours {}
+
+struct TemporalDurationPrototypeGetMinutes;
+impl Builtin for TemporalDurationPrototypeGetMinutes {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_minutes;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.minutes.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalDurationPrototype::get_minutes);
+}
+impl BuiltinGetter for TemporalDurationPrototypeGetMinutes {}
+
+struct TemporalDurationPrototypeGetSeconds;
+impl Builtin for TemporalDurationPrototypeGetSeconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_seconds;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.seconds.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalDurationPrototype::get_seconds);
+}
+impl BuiltinGetter for TemporalDurationPrototypeGetSeconds {}
+
+struct TemporalDurationPrototypeGetMilliseconds;
+impl Builtin for TemporalDurationPrototypeGetMilliseconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_milliseconds;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.milliseconds.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalDurationPrototype::get_milliseconds);
+}
+impl BuiltinGetter for TemporalDurationPrototypeGetMilliseconds {}
+
+struct TemporalDurationPrototypeGetMicroseconds;
+impl Builtin for TemporalDurationPrototypeGetMicroseconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_microseconds;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.microseconds.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalDurationPrototype::get_microseconds);
+}
+impl BuiltinGetter for TemporalDurationPrototypeGetMicroseconds {}
+
+struct TemporalDurationPrototypeGetNanoseconds;
+impl Builtin for TemporalDurationPrototypeGetNanoseconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_nanoseconds;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.nanoseconds.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalDurationPrototype::get_nanoseconds);
+}
+impl BuiltinGetter for TemporalDurationPrototypeGetNanoseconds {}
+
+struct TemporalDurationPrototypeGetSign;
+impl Builtin for TemporalDurationPrototypeGetSign {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_sign;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.sign.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalDurationPrototype::get_sign);
+}
+impl BuiltinGetter for TemporalDurationPrototypeGetSign {}
+
+struct TemporalDurationPrototypeGetBlank;
+impl Builtin for TemporalDurationPrototypeGetBlank {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_blank;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.blank.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalDurationPrototype::get_blank);
+}
+impl BuiltinGetter for TemporalDurationPrototypeGetBlank {}
+
 impl TemporalDurationPrototype {
+    /// ### [7.3.3 get Temporal.Duration.prototype.years](https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.years)
+    fn get_years<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = require_internal_slot_temporal_duration(agent, this_value, gc)?;
+        // 3. Return 𝔽(duration.[[Years]]).
+        let value = duration.inner_duration(agent).years();
+        Ok(Value::from_i64(agent, value, gc))
+    }
+
+    /// ### [7.3.4 get Temporal.Duration.prototype.months](https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.months)
+    fn get_months<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = require_internal_slot_temporal_duration(agent, this_value, gc)?;
+        // 3. Return 𝔽(duration.[[Months]]).
+        let value = duration.inner_duration(agent).months();
+        Ok(Value::from_i64(agent, value, gc))
+    }
+
+    /// ### [7.3.5 get Temporal.Duration.prototype.weeks](https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.weeks)
+    fn get_weeks<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = require_internal_slot_temporal_duration(agent, this_value, gc)?;
+        // 3. Return 𝔽(duration.[[Weeks]]).
+        let value = duration.inner_duration(agent).weeks();
+        Ok(Value::from_i64(agent, value, gc))
+    }
+
+    /// ### [7.3.6 get Temporal.Duration.prototype.days](https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.days)
+    fn get_days<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = require_internal_slot_temporal_duration(agent, this_value, gc)?;
+        // 3. Return 𝔽(duration.[[Days]]).
+        let value = duration.inner_duration(agent).days();
+        Ok(Value::from_i64(agent, value, gc))
+    }
+
+    /// ### [7.3.7 get Temporal.Duration.prototype.hours](https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.hours)
+    fn get_hours<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = require_internal_slot_temporal_duration(agent, this_value, gc)?;
+        // 3. Return 𝔽(duration.[[Hours]]).
+        let value = duration.inner_duration(agent).hours();
+        Ok(Value::from_i64(agent, value, gc))
+    }
+
+    /// ### [7.3.8 get Temporal.Duration.prototype.minutes](https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.minutes)
+    fn get_minutes<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = require_internal_slot_temporal_duration(agent, this_value, gc)?;
+        // 3. Return 𝔽(duration.[[Minutes]]).
+        let value = duration.inner_duration(agent).minutes();
+        Ok(Value::from_i64(agent, value, gc))
+    }
+
+    /// ### [7.3.9 get Temporal.Duration.prototype.seconds](https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.seconds)
+    fn get_seconds<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = require_internal_slot_temporal_duration(agent, this_value, gc)?;
+        // 3. Return 𝔽(duration.[[Seconds]]).
+        let value = duration.inner_duration(agent).seconds();
+        Ok(Value::from_i64(agent, value, gc))
+    }
+
+    /// ### [7.3.10 get Temporal.Duration.prototype.milliseconds](https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.milliseconds)
+    fn get_milliseconds<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = require_internal_slot_temporal_duration(agent, this_value, gc)?;
+        // 3. Return 𝔽(duration.[[Milliseconds]]).
+        let value = duration.inner_duration(agent).milliseconds();
+        Ok(Value::from_i64(agent, value, gc))
+    }
+
+    /// ### [7.3.11 get Temporal.Duration.prototype.microseconds](https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.microseconds)
+    fn get_microseconds<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = require_internal_slot_temporal_duration(agent, this_value, gc)?;
+        // 3. Return 𝔽(duration.[[Microseconds]]).
+        let value = duration.inner_duration(agent).microseconds();
+        // Note: the value may not be exactly representable as a Number.
+        Ok(Value::from_f64(agent, value as f64, gc))
+    }
+
+    /// ### [7.3.12 get Temporal.Duration.prototype.nanoseconds](https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.nanoseconds)
+    fn get_nanoseconds<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = require_internal_slot_temporal_duration(agent, this_value, gc)?;
+        // 3. Return 𝔽(duration.[[Nanoseconds]]).
+        let value = duration.inner_duration(agent).nanoseconds();
+        // Note: the value may not be exactly representable as a Number.
+        Ok(Value::from_f64(agent, value as f64, gc))
+    }
+
+    /// ### [7.3.13 get Temporal.Duration.prototype.sign](https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.sign)
+    fn get_sign<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = require_internal_slot_temporal_duration(agent, this_value, gc)?;
+        // 3. Return 𝔽(DurationSign(duration)).
+        let value = duration.inner_duration(agent).sign() as i8;
+        Ok(Value::Integer(value.into()))
+    }
+
+    /// ### [7.3.14 get Temporal.Duration.prototype.blank](https://tc39.es/proposal-temporal/#sec-get-temporal.duration.prototype.blank)
+    fn get_blank<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let duration be the this value.
+        // 2. Perform ? RequireInternalSlot(duration, [[InitializedTemporalDuration]]).
+        let duration = require_internal_slot_temporal_duration(agent, this_value, gc)?;
+        // 3. If DurationSign(duration) = 0, return true.
+        // 4. Return false.
+        Ok(duration.inner_duration(agent).is_zero().into())
+    }
+
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>, _: NoGcScope) {
         let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
         let this = intrinsics.temporal_duration_prototype();
@@ -17,9 +327,21 @@ impl TemporalDurationPrototype {
         let duration_constructor = intrinsics.temporal_duration();
 
         OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
-            .with_property_capacity(2)
+            .with_property_capacity(14)
             .with_prototype(object_prototype)
             .with_constructor_property(duration_constructor)
+            .with_builtin_function_getter_property::<TemporalDurationPrototypeGetYears>()
+            .with_builtin_function_getter_property::<TemporalDurationPrototypeGetMonths>()
+            .with_builtin_function_getter_property::<TemporalDurationPrototypeGetWeeks>()
+            .with_builtin_function_getter_property::<TemporalDurationPrototypeGetDays>()
+            .with_builtin_function_getter_property::<TemporalDurationPrototypeGetHours>()
+            .with_builtin_function_getter_property::<TemporalDurationPrototypeGetMinutes>()
+            .with_builtin_function_getter_property::<TemporalDurationPrototypeGetSeconds>()
+            .with_builtin_function_getter_property::<TemporalDurationPrototypeGetMilliseconds>()
+            .with_builtin_function_getter_property::<TemporalDurationPrototypeGetMicroseconds>()
+            .with_builtin_function_getter_property::<TemporalDurationPrototypeGetNanoseconds>()
+            .with_builtin_function_getter_property::<TemporalDurationPrototypeGetSign>()
+            .with_builtin_function_getter_property::<TemporalDurationPrototypeGetBlank>()
             .with_property(|builder| {
                 builder
                     .with_key(WellKnownSymbols::ToStringTag.into())
@@ -16,9 +16,9 @@ use crate::{
     ecmascript::{
         Agent, DurationRecord, ExceptionType, Function, InternalMethods, InternalSlots, JsResult,
         Object, OrdinaryObject, PreferredType, Primitive, ProtoIntrinsics, String,
-        TemporalDuration, Value, get_difference_settings, get_options_object, object_handle,
-        ordinary_populate_from_constructor, temporal_err_to_js_err, to_primitive_object,
-        to_temporal_duration,
+        TemporalDuration, TemporalZonedDateTime, Value, get_difference_settings,
+        get_options_object, object_handle, ordinary_populate_from_constructor,
+        temporal_err_to_js_err, to_primitive_object, to_temporal_duration,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable},
     heap::{
@@ -146,10 +146,12 @@ pub(crate) fn to_temporal_instant<'gc>(
     let item = if let Ok(item) = Object::try_from(item) {
         // a. If item has an [[InitializedTemporalInstant]] or [[InitializedTemporalZonedDateTime]]
         // internal slot, then
-        // TODO: TemporalZonedDateTime::try_from(item)
         if let Ok(item) = TemporalInstant::try_from(item) {
             // i. Return ! CreateTemporalInstant(item.[[EpochNanoseconds]]).
             return Ok(*item.inner_instant(agent));
+        } else if let Ok(item) = TemporalZonedDateTime::try_from(item) {
+            // i. Return ! CreateTemporalInstant(item.[[EpochNanoseconds]]).
+            return Ok(item.inner_zoned_date_time(agent).to_instant());
         }
         // b. NOTE: This use of ToPrimitive allows Instant-like objects to be converted.
         // c. Set item to ? ToPrimitive(item, string).
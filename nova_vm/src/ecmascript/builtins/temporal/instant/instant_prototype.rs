@@ -2,15 +2,13 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use temporal_rs::{
-    TimeZone,
-    options::{RoundingMode, RoundingOptions, ToStringRoundingOptions, Unit},
-};
+use temporal_rs::options::{RoundingMode, RoundingOptions, ToStringRoundingOptions, Unit};
 
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, BigInt, Builtin, BuiltinGetter,
         ExceptionType, JsResult, Object, PropertyKey, Realm, String, StringOptionType, Value,
+        ZonedDateTimeRecord,
         builders::OrdinaryObjectBuilder,
         builtins::temporal::instant::{
             add_duration_to_instant, difference_temporal_instant,
@@ -19,10 +17,10 @@ use crate::{
         create_temporal_instant, get, get_option, get_options_object,
         get_rounding_increment_option, get_rounding_mode_option,
         get_temporal_fractional_second_digits_option, temporal_err_to_js_err, to_number,
-        to_temporal_instant,
+        to_temporal_instant, to_temporal_time_zone_identifier,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable},
-    heap::WellKnownSymbols,
+    heap::{CreateHeapData, WellKnownSymbols},
 };
 
 pub(crate) struct TemporalInstantPrototype;
@@ -469,7 +467,11 @@ impl TemporalInstantPrototype {
         // 11. If timeZone is not undefined, then
         let time_zone = if !tz.is_undefined() {
             // a. Set timeZone to ? ToTemporalTimeZoneIdentifier(timeZone).
-            Some(TimeZone::utc())
+            Some(
+                to_temporal_time_zone_identifier(agent, tz.unbind(), gc.nogc())
+                    .unbind()?
+                    .bind(gc.nogc()),
+            )
         } else {
             None
         };
@@ -518,10 +520,7 @@ impl TemporalInstantPrototype {
             .bind(gc.nogc());
         // 3. Return TemporalInstantToString(instant, undefined, AUTO).
         let options: ToStringRoundingOptions = ToStringRoundingOptions::default(); // defaults Precision to Auto
-        match instant
-            .inner_instant(agent)
-            .to_ixdtf_string(Some(TimeZone::utc()), options)
-        {
+        match instant.inner_instant(agent).to_ixdtf_string(None, options) {
             Ok(string) => Ok(Value::from_string(agent, string, gc.into_nogc())),
             Err(err) => Err(temporal_err_to_js_err(agent, err, gc.into_nogc())),
         }
@@ -542,10 +541,7 @@ impl TemporalInstantPrototype {
             .bind(gc.nogc());
         // 3. Return TemporalInstantToString(instant, undefined, AUTO).
         let options: ToStringRoundingOptions = ToStringRoundingOptions::default();
-        match instant
-            .inner_instant(agent)
-            .to_ixdtf_string(Some(TimeZone::utc()), options)
-        {
+        match instant.inner_instant(agent).to_ixdtf_string(None, options) {
             Ok(string) => Ok(Value::from_string(agent, string, gc.into_nogc())),
             Err(err) => Err(temporal_err_to_js_err(agent, err, gc.into_nogc())),
         }
@@ -578,19 +574,29 @@ impl TemporalInstantPrototype {
     fn to_zoned_date_time_iso<'gc>(
         agent: &mut Agent,
         this_value: Value,
-        _args: ArgumentsList,
+        args: ArgumentsList,
         gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let time_zone = args.get(0).bind(gc);
         // 1. Let instant be the this value.
-        let value = this_value.bind(gc.nogc());
+        let value = this_value.bind(gc);
         // 2. Perform ? RequireInternalSlot(instant, [[InitializedTemporalInstant]]).
-        let _instant = require_internal_slot_temporal_instant(agent, value.unbind(), gc.nogc())
-            .unbind()?
-            .bind(gc.nogc());
+        let instant = require_internal_slot_temporal_instant(agent, value, gc)?;
         // 3. Set timeZone to ? ToTemporalTimeZoneIdentifier(timeZone).
-        let _time_zone = Some(TimeZone::utc());
+        let time_zone = to_temporal_time_zone_identifier(agent, time_zone, gc)?;
         // 4. Return ! CreateTemporalZonedDateTime(instant.[[EpochNanoseconds]], timeZone, "iso8601").
-        unimplemented!()
+        let zoned_date_time = instant
+            .inner_instant(agent)
+            .to_zoned_date_time_iso(time_zone)
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        Ok(agent
+            .heap
+            .create(ZonedDateTimeRecord {
+                object_index: None,
+                zoned_date_time,
+            })
+            .into())
     }
 
     pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>, _: NoGcScope) {
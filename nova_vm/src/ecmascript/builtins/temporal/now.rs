@@ -0,0 +1,244 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use temporal_rs::{Calendar, TimeZone};
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, InstantRecord, JsResult,
+        PlainDateRecord, PlainDateTimeRecord, PlainTimeRecord, Realm, String, Value,
+        ZonedDateTimeRecord, builders::OrdinaryObjectBuilder, temporal_err_to_js_err,
+        to_temporal_time_zone_identifier,
+    },
+    engine::{Bindable, GcScope, NoGcScope},
+    heap::{CreateHeapData, WellKnownSymbols},
+};
+
+/// ### [2 The Temporal.Now Object](https://tc39.es/proposal-temporal/#sec-temporal-now-object)
+pub(crate) struct TemporalNowObject;
+
+struct TemporalNowTimeZoneId;
+impl Builtin for TemporalNowTimeZoneId {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.timeZoneId;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalNowObject::time_zone_id);
+}
+
+struct TemporalNowInstant;
+impl Builtin for TemporalNowInstant {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.instant;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalNowObject::instant);
+}
+
+struct TemporalNowPlainDateTimeISO;
+impl Builtin for TemporalNowPlainDateTimeISO {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.plainDateTimeISO;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalNowObject::plain_date_time_iso);
+}
+
+struct TemporalNowZonedDateTimeISO;
+impl Builtin for TemporalNowZonedDateTimeISO {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.zonedDateTimeISO;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalNowObject::zoned_date_time_iso);
+}
+
+struct TemporalNowPlainDateISO;
+impl Builtin for TemporalNowPlainDateISO {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.plainDateISO;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalNowObject::plain_date_iso);
+}
+
+struct TemporalNowPlainTimeISO;
+impl Builtin for TemporalNowPlainTimeISO {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.plainTimeISO;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalNowObject::plain_time_iso);
+}
+
+impl TemporalNowObject {
+    /// ### [2.2.1 Temporal.Now.timeZoneId ( )](https://tc39.es/proposal-temporal/#sec-temporal.now.timezoneid)
+    fn time_zone_id<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Return SystemTimeZoneIdentifier().
+        let time_zone = agent.system_time_zone_identifier().to_owned();
+        Ok(Value::from_str(agent, &time_zone, gc.into_nogc()))
+    }
+
+    /// ### [2.2.2 Temporal.Now.instant ( )](https://tc39.es/proposal-temporal/#sec-temporal.now.instant)
+    fn instant<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let ns be SystemUTCEpochNanoseconds().
+        let ns = agent.host_hooks.get_system_utc_epoch_nanoseconds();
+        // 2. Return ! CreateTemporalInstant(ns).
+        let instant = temporal_rs::Instant::try_new(ns)
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        Ok(agent
+            .heap
+            .create(InstantRecord {
+                object_index: None,
+                instant,
+            })
+            .into())
+    }
+
+    /// ### [2.2.3 Temporal.Now.plainDateTimeISO ( [ temporalTimeZoneLike ] )](https://tc39.es/proposal-temporal/#sec-temporal.now.plaindatetimeiso)
+    fn plain_date_time_iso<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let temporal_time_zone_like = args.get(0).bind(gc);
+        // 1. Let isoDateTime be ? SystemDateTime(temporalTimeZoneLike).
+        let zoned_date_time = system_zoned_date_time(agent, temporal_time_zone_like, gc)?;
+        // 2. Return ! CreateTemporalDateTime(isoDateTime, "iso8601").
+        let plain_date_time = zoned_date_time.to_plain_date_time();
+        Ok(agent
+            .heap
+            .create(PlainDateTimeRecord {
+                object_index: None,
+                plain_date_time,
+            })
+            .into())
+    }
+
+    /// ### [2.2.4 Temporal.Now.zonedDateTimeISO ( [ temporalTimeZoneLike ] )](https://tc39.es/proposal-temporal/#sec-temporal.now.zoneddatetimeiso)
+    fn zoned_date_time_iso<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let temporal_time_zone_like = args.get(0).bind(gc);
+        // 1. If temporalTimeZoneLike is undefined, then
+        //     a. Let timeZone be SystemTimeZoneIdentifier().
+        // 2. Else,
+        //     a. Let timeZone be ? ToTemporalTimeZoneIdentifier(temporalTimeZoneLike).
+        // 3. Let ns be SystemUTCEpochNanoseconds().
+        // 4. Return ! CreateTemporalZonedDateTime(ns, timeZone, "iso8601").
+        let zoned_date_time = system_zoned_date_time(agent, temporal_time_zone_like, gc)?;
+        Ok(agent
+            .heap
+            .create(ZonedDateTimeRecord {
+                object_index: None,
+                zoned_date_time,
+            })
+            .into())
+    }
+
+    /// ### [2.2.5 Temporal.Now.plainDateISO ( [ temporalTimeZoneLike ] )](https://tc39.es/proposal-temporal/#sec-temporal.now.plaindateiso)
+    fn plain_date_iso<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let temporal_time_zone_like = args.get(0).bind(gc);
+        // 1. Let isoDateTime be ? SystemDateTime(temporalTimeZoneLike).
+        let zoned_date_time = system_zoned_date_time(agent, temporal_time_zone_like, gc)?;
+        // 2. Return ! CreateTemporalDate(isoDateTime.[[ISODate]], "iso8601").
+        let plain_date = zoned_date_time.to_plain_date();
+        Ok(agent
+            .heap
+            .create(PlainDateRecord {
+                object_index: None,
+                plain_date,
+            })
+            .into())
+    }
+
+    /// ### [2.2.6 Temporal.Now.plainTimeISO ( [ temporalTimeZoneLike ] )](https://tc39.es/proposal-temporal/#sec-temporal.now.plaintimeiso)
+    fn plain_time_iso<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let temporal_time_zone_like = args.get(0).bind(gc);
+        // 1. Let isoDateTime be ? SystemDateTime(temporalTimeZoneLike).
+        let zoned_date_time = system_zoned_date_time(agent, temporal_time_zone_like, gc)?;
+        // 2. Return ! CreateTemporalTime(isoDateTime.[[Time]]).
+        let plain_time = zoned_date_time.to_plain_time();
+        Ok(agent
+            .heap
+            .create(PlainTimeRecord {
+                object_index: None,
+                plain_time,
+            })
+            .into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>, _: NoGcScope) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let object_prototype = intrinsics.object_prototype();
+        let this = intrinsics.temporal_now();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(7)
+            .with_prototype(object_prototype)
+            // 2.1.1 Temporal.Now [ %Symbol.toStringTag% ]
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Temporal_Now.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .with_builtin_function_property::<TemporalNowTimeZoneId>()
+            .with_builtin_function_property::<TemporalNowInstant>()
+            .with_builtin_function_property::<TemporalNowPlainDateTimeISO>()
+            .with_builtin_function_property::<TemporalNowZonedDateTimeISO>()
+            .with_builtin_function_property::<TemporalNowPlainDateISO>()
+            .with_builtin_function_property::<TemporalNowPlainTimeISO>()
+            .build();
+    }
+}
+
+/// ### [2.3.1 SystemDateTime ( temporalTimeZoneLike )](https://tc39.es/proposal-temporal/#sec-temporal-systemdatetime)
+///
+/// The abstract operation SystemDateTime takes argument temporalTimeZoneLike
+/// (an ECMAScript language value) and returns either a normal completion
+/// containing an ISO Date-Time Record or a throw completion.
+///
+/// This returns the current time as a Temporal.ZonedDateTime in the ISO 8601
+/// calendar, from which the ISO Date-Time Record can be read.
+fn system_zoned_date_time<'gc>(
+    agent: &mut Agent,
+    temporal_time_zone_like: Value,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, temporal_rs::ZonedDateTime> {
+    // 1. If temporalTimeZoneLike is undefined, then
+    let time_zone = if temporal_time_zone_like.is_undefined() {
+        // a. Let timeZone be SystemTimeZoneIdentifier().
+        TimeZone::try_from_identifier_str(agent.system_time_zone_identifier())
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?
+    } else {
+        // 2. Else,
+        //     a. Let timeZone be ? ToTemporalTimeZoneIdentifier(temporalTimeZoneLike).
+        to_temporal_time_zone_identifier(agent, temporal_time_zone_like, gc)?
+    };
+    // 3. Let epochNs be SystemUTCEpochNanoseconds().
+    let epoch_ns = agent.host_hooks.get_system_utc_epoch_nanoseconds();
+    // 4. Return GetISODateTimeFor(timeZone, epochNs).
+    temporal_rs::ZonedDateTime::try_new(epoch_ns, time_zone, Calendar::ISO)
+        .map_err(|err| temporal_err_to_js_err(agent, err, gc))
+}
@@ -4,12 +4,16 @@
 
 use std::str::FromStr;
 
-use temporal_rs::options::{RoundingIncrement, RoundingMode, Unit};
+use temporal_rs::options::{
+    Disambiguation, DisplayCalendar, DisplayOffset, DisplayTimeZone, OffsetDisambiguation,
+    Overflow, RoundingIncrement, RoundingMode, Unit,
+};
+use temporal_rs::provider::TransitionDirection;
 
 use crate::{
     ecmascript::{
         Agent, BUILTIN_STRING_MEMORY, ExceptionType, JsResult, Object, PropertyKey, String, Value,
-        get, temporal_err_to_js_err, to_integer_with_truncation, to_string,
+        get, get_options_object, temporal_err_to_js_err, to_integer_with_truncation, to_string,
     },
     engine::{Bindable, GcScope, NoGcScope},
 };
@@ -68,34 +72,88 @@ impl StringOptionType for Unit {
     }
 }
 
-/// ### [14.5.2.1 GetOptionsObject ( options )](https://tc39.es/proposal-temporal/#sec-getoptionsobject)
-///
-/// The abstract operation GetOptionsObject takes argument options (an ECMAScript language value)
-/// and returns either a normal completion containing an Object or a throw completion. It returns
-/// an Object suitable for use with GetOption, either options itself or a default empty Object. It
-/// throws a TypeError if options is not undefined and not an Object. It performs the following
-/// steps when called:
-pub(crate) fn get_options_object<'gc>(
-    agent: &mut Agent,
-    options: Value,
-    gc: NoGcScope<'gc, '_>,
-) -> JsResult<'gc, Option<Object<'gc>>> {
-    let options = options.bind(gc);
-    // 1. If options is undefined, then
-    if options.is_undefined() {
-        // a. Return OrdinaryObjectCreate(null).
-        Ok(None)
-    } else if let Ok(options) = Object::try_from(options) {
-        // 2. If options is an Object, then
-        // a. Return options.
-        Ok(Some(options))
-    } else {
-        // 3. Throw a TypeError exception.
-        Err(agent.throw_exception_with_static_message(
-            ExceptionType::TypeError,
-            "options provided to GetOptionsObject is not an object",
-            gc,
-        ))
+impl StringOptionType for Overflow {
+    fn from_string<'gc>(
+        agent: &mut Agent,
+        value: String,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Self> {
+        let value = value.as_str(agent).unwrap_or("");
+        Self::from_str(value).map_err(|err| {
+            agent.throw_exception(ExceptionType::RangeError, format!("{err}"), gc.into_nogc())
+        })
+    }
+}
+
+impl StringOptionType for Disambiguation {
+    fn from_string<'gc>(
+        agent: &mut Agent,
+        value: String,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Self> {
+        let value = value.as_str(agent).unwrap_or("");
+        Self::from_str(value).map_err(|err| {
+            agent.throw_exception(ExceptionType::RangeError, format!("{err}"), gc.into_nogc())
+        })
+    }
+}
+
+impl StringOptionType for OffsetDisambiguation {
+    fn from_string<'gc>(
+        agent: &mut Agent,
+        value: String,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Self> {
+        let value = value.as_str(agent).unwrap_or("");
+        Self::from_str(value).map_err(|err| {
+            agent.throw_exception(ExceptionType::RangeError, format!("{err}"), gc.into_nogc())
+        })
+    }
+}
+
+impl StringOptionType for DisplayCalendar {
+    fn from_string<'gc>(
+        agent: &mut Agent,
+        value: String,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Self> {
+        let value = value.as_str(agent).unwrap_or("");
+        Self::from_str(value).map_err(|err| temporal_err_to_js_err(agent, err, gc.into_nogc()))
+    }
+}
+
+impl StringOptionType for DisplayOffset {
+    fn from_string<'gc>(
+        agent: &mut Agent,
+        value: String,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Self> {
+        let value = value.as_str(agent).unwrap_or("");
+        Self::from_str(value).map_err(|err| temporal_err_to_js_err(agent, err, gc.into_nogc()))
+    }
+}
+
+impl StringOptionType for DisplayTimeZone {
+    fn from_string<'gc>(
+        agent: &mut Agent,
+        value: String,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Self> {
+        let value = value.as_str(agent).unwrap_or("");
+        Self::from_str(value).map_err(|err| temporal_err_to_js_err(agent, err, gc.into_nogc()))
+    }
+}
+
+impl StringOptionType for TransitionDirection {
+    fn from_string<'gc>(
+        agent: &mut Agent,
+        value: String,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, Self> {
+        let value = value.as_str(agent).unwrap_or("");
+        Self::from_str(value).map_err(|err| {
+            agent.throw_exception(ExceptionType::RangeError, format!("{err}"), gc.into_nogc())
+        })
     }
 }
 
@@ -209,3 +267,207 @@ pub(crate) fn get_rounding_increment_option<'gc>(
     RoundingIncrement::try_new(u32::try_from(integer_increment).unwrap_or(u32::MAX))
         .map_err(|err| temporal_err_to_js_err(agent, err, gc.into_nogc()))
 }
+
+/// ### [13.6 GetTemporalOverflowOption ( options )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporaloverflowoption)
+///
+/// The abstract operation GetTemporalOverflowOption takes argument options (an
+/// Object) and returns either a normal completion containing either constrain
+/// or reject, or a throw completion. It fetches and validates the "overflow"
+/// property from options, returning a default if absent.
+pub(crate) fn get_temporal_overflow_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Overflow> {
+    let options = options.bind(gc.nogc());
+    // 1. Let stringValue be ? GetOption(options, "overflow", string, « "constrain", "reject" », "constrain").
+    // 2. If stringValue is "constrain", return constrain.
+    // 3. Return reject.
+    Ok(get_option::<Overflow>(
+        agent,
+        options.unbind(),
+        BUILTIN_STRING_MEMORY.overflow.into(),
+        gc,
+    )?
+    .unwrap_or_default())
+}
+
+/// Performs GetOptionsObject on options and then GetTemporalOverflowOption on
+/// the resulting options object, as is done by many Temporal operations.
+pub(crate) fn resolve_temporal_overflow_option<'gc>(
+    agent: &mut Agent,
+    options: Value,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Overflow> {
+    // 1. Let resolvedOptions be ? GetOptionsObject(options).
+    let resolved_options = get_options_object(agent, options, gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    // 2. Return ? GetTemporalOverflowOption(resolvedOptions).
+    match resolved_options {
+        Some(resolved_options) => {
+            get_temporal_overflow_option(agent, resolved_options.unbind(), gc)
+        }
+        None => Ok(Overflow::default()),
+    }
+}
+
+/// ### [13.7 GetTemporalDisambiguationOption ( options )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporaldisambiguationoption)
+///
+/// The abstract operation GetTemporalDisambiguationOption takes argument
+/// options (an Object) and returns either a normal completion containing
+/// either earlier, later, compatible, or reject, or a throw completion. It
+/// fetches and validates the "disambiguation" property from options, returning
+/// a default if absent.
+pub(crate) fn get_temporal_disambiguation_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Disambiguation> {
+    let options = options.bind(gc.nogc());
+    // 1. Let stringValue be ? GetOption(options, "disambiguation", string, « "compatible", "earlier", "later", "reject" », "compatible").
+    // 2. If stringValue is "compatible", return compatible.
+    // 3. If stringValue is "earlier", return earlier.
+    // 4. If stringValue is "later", return later.
+    // 5. Return reject.
+    Ok(get_option::<Disambiguation>(
+        agent,
+        options.unbind(),
+        BUILTIN_STRING_MEMORY.disambiguation.into(),
+        gc,
+    )?
+    .unwrap_or_default())
+}
+
+/// ### [13.9 GetTemporalOffsetOption ( options, fallback )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporaloffsetoption)
+///
+/// The abstract operation GetTemporalOffsetOption takes arguments options (an
+/// Object) and fallback (prefer, use, ignore, or reject) and returns either a
+/// normal completion containing either prefer, use, ignore, or reject, or a
+/// throw completion. It fetches and validates the "offset" property from
+/// options, returning fallback as a default if absent.
+pub(crate) fn get_temporal_offset_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    fallback: OffsetDisambiguation,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, OffsetDisambiguation> {
+    let options = options.bind(gc.nogc());
+    // 1. If fallback is prefer, let stringFallback be "prefer".
+    // 2. Else if fallback is use, let stringFallback be "use".
+    // 3. Else if fallback is ignore, let stringFallback be "ignore".
+    // 4. Else, let stringFallback be "reject".
+    // 5. Let stringValue be ? GetOption(options, "offset", string, « "prefer", "use", "ignore", "reject" », stringFallback).
+    // 6-9. Return the matching offset option.
+    Ok(get_option::<OffsetDisambiguation>(
+        agent,
+        options.unbind(),
+        BUILTIN_STRING_MEMORY.offset.into(),
+        gc,
+    )?
+    .unwrap_or(fallback))
+}
+
+/// ### [13.10 GetTemporalShowCalendarNameOption ( options )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporalshowcalendarnameoption)
+///
+/// The abstract operation GetTemporalShowCalendarNameOption takes argument
+/// options (an Object) and returns either a normal completion containing
+/// either auto, always, never, or critical, or a throw completion. It fetches
+/// and validates the "calendarName" property from options, returning a
+/// default if absent.
+pub(crate) fn get_temporal_show_calendar_name_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, DisplayCalendar> {
+    let options = options.bind(gc.nogc());
+    // 1. Let stringValue be ? GetOption(options, "calendarName", string, « "auto", "always", "never", "critical" », "auto").
+    // 2-5. Return the matching calendar name option.
+    Ok(get_option::<DisplayCalendar>(
+        agent,
+        options.unbind(),
+        BUILTIN_STRING_MEMORY.calendarName.into(),
+        gc,
+    )?
+    .unwrap_or_default())
+}
+
+/// ### [13.11 GetTemporalShowTimeZoneNameOption ( options )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporalshowtimezonenameoption)
+///
+/// The abstract operation GetTemporalShowTimeZoneNameOption takes argument
+/// options (an Object) and returns either a normal completion containing
+/// either auto, never, or critical, or a throw completion. It fetches and
+/// validates the "timeZoneName" property from options, returning a default if
+/// absent.
+pub(crate) fn get_temporal_show_time_zone_name_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, DisplayTimeZone> {
+    let options = options.bind(gc.nogc());
+    // 1. Let stringValue be ? GetOption(options, "timeZoneName", string, « "auto", "never", "critical" », "auto").
+    // 2-4. Return the matching time zone name option.
+    Ok(get_option::<DisplayTimeZone>(
+        agent,
+        options.unbind(),
+        BUILTIN_STRING_MEMORY.timeZoneName.into(),
+        gc,
+    )?
+    .unwrap_or_default())
+}
+
+/// ### [13.12 GetTemporalShowOffsetOption ( options )](https://tc39.es/proposal-temporal/#sec-temporal-gettemporalshowoffsetoption)
+///
+/// The abstract operation GetTemporalShowOffsetOption takes argument options
+/// (an Object) and returns either a normal completion containing either auto
+/// or never, or a throw completion. It fetches and validates the "offset"
+/// property from options, returning a default if absent.
+pub(crate) fn get_temporal_show_offset_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, DisplayOffset> {
+    let options = options.bind(gc.nogc());
+    // 1. Let stringValue be ? GetOption(options, "offset", string, « "auto", "never" », "auto").
+    // 2. If stringValue is "never", return never.
+    // 3. Return auto.
+    Ok(get_option::<DisplayOffset>(
+        agent,
+        options.unbind(),
+        BUILTIN_STRING_MEMORY.offset.into(),
+        gc,
+    )?
+    .unwrap_or_default())
+}
+
+/// ### [6.5.11 GetDirectionOption ( options )](https://tc39.es/proposal-temporal/#sec-temporal-getdirectionoption)
+///
+/// The abstract operation GetDirectionOption takes argument options (an
+/// Object) and returns either a normal completion containing either next or
+/// previous, or a throw completion. It fetches and validates the "direction"
+/// property from options, throwing if absent.
+pub(crate) fn get_direction_option<'gc>(
+    agent: &mut Agent,
+    options: Object,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, TransitionDirection> {
+    let options = options.bind(gc.nogc());
+    // 1. Let stringValue be ? GetOption(options, "direction", string, « "next", "previous" », required).
+    let Some(direction) = get_option::<TransitionDirection>(
+        agent,
+        options.unbind(),
+        BUILTIN_STRING_MEMORY.direction.into(),
+        gc.reborrow(),
+    )
+    .unbind()?
+    else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "direction option is required",
+            gc.into_nogc(),
+        ));
+    };
+    // 2. If stringValue is "next", return next.
+    // 3. Return previous.
+    Ok(direction)
+}
@@ -0,0 +1,352 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod data;
+mod plain_date_constructor;
+mod plain_date_prototype;
+
+pub(crate) use data::*;
+pub(crate) use plain_date_constructor::*;
+pub(crate) use plain_date_prototype::*;
+
+use temporal_rs::{
+    options::{Unit, UnitGroup},
+    partial::PartialDate,
+};
+
+use crate::{
+    ecmascript::{
+        Agent, CalendarFieldNames, DurationRecord, ExceptionType, Function, InternalMethods,
+        InternalSlots, JsResult, Object, OrdinaryObject, ProtoIntrinsics, String, TemporalDuration,
+        Value, get_difference_settings, get_options_object,
+        get_temporal_calendar_identifier_with_iso_default, object_handle,
+        ordinary_populate_from_constructor, prepare_calendar_fields,
+        resolve_temporal_overflow_option, temporal_err_to_js_err, to_temporal_duration,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{
+        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
+        HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues, arena_vec_access,
+    },
+};
+
+/// # [3 Temporal.PlainDate Objects](https://tc39.es/proposal-temporal/#sec-temporal-plaindate-objects)
+///
+/// A Temporal.PlainDate object is an Object that contains integers
+/// corresponding to a particular year, month, and day in the ISO 8601
+/// calendar, as well as an Object indicating the calendar system used to
+/// interpret them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct TemporalPlainDate<'a>(BaseIndex<'a, PlainDateRecord<'static>>);
+object_handle!(TemporalPlainDate, PlainDate);
+arena_vec_access!(
+    TemporalPlainDate,
+    'a,
+    PlainDateRecord,
+    plain_dates
+);
+
+impl TemporalPlainDate<'_> {
+    pub(crate) fn inner_plain_date(self, agent: &Agent) -> &temporal_rs::PlainDate {
+        &self.unbind().get(agent).plain_date
+    }
+}
+
+impl<'a> InternalSlots<'a> for TemporalPlainDate<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::TemporalPlainDate;
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        self.get(agent).object_index.unbind()
+    }
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(
+            self.get_mut(agent)
+                .object_index
+                .replace(backing_object)
+                .is_none()
+        );
+    }
+}
+
+impl<'a> InternalMethods<'a> for TemporalPlainDate<'a> {}
+
+impl HeapMarkAndSweep for TemporalPlainDate<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.plain_dates.push(*self);
+    }
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.plain_dates.shift_index(&mut self.0);
+    }
+}
+
+impl HeapSweepWeakReference for TemporalPlainDate<'static> {
+    fn sweep_weak_reference(self, compactions: &CompactionLists) -> Option<Self> {
+        compactions.plain_dates.shift_weak_index(self.0).map(Self)
+    }
+}
+
+impl<'a> CreateHeapData<PlainDateRecord<'a>, TemporalPlainDate<'a>> for Heap {
+    fn create(&mut self, data: PlainDateRecord<'a>) -> TemporalPlainDate<'a> {
+        self.plain_dates.push(data.unbind());
+        self.alloc_counter += core::mem::size_of::<PlainDateRecord<'static>>();
+        TemporalPlainDate(BaseIndex::last(&self.plain_dates))
+    }
+}
+
+#[inline(always)]
+fn require_internal_slot_temporal_plain_date<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, TemporalPlainDate<'a>> {
+    match value {
+        Value::PlainDate(plain_date) => Ok(plain_date.bind(gc)),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Object is not a Temporal PlainDate",
+            gc,
+        )),
+    }
+}
+
+/// ### [3.5.5 CreateTemporalDate ( isoDate, calendar [ , newTarget ] )](https://tc39.es/proposal-temporal/#sec-temporal-createtemporaldate)
+pub(crate) fn create_temporal_plain_date<'gc>(
+    agent: &mut Agent,
+    plain_date: temporal_rs::PlainDate,
+    new_target: Option<Function>,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, TemporalPlainDate<'gc>> {
+    // 1. If newTarget is not present, set newTarget to %Temporal.PlainDate%.
+    let new_target = new_target.unwrap_or_else(|| {
+        agent
+            .current_realm_record()
+            .intrinsics()
+            .temporal_plain_date()
+            .into()
+    });
+    // 2. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.PlainDate.prototype%", « [[InitializedTemporalDate]], [[ISODate]], [[Calendar]] »).
+    // 3. Set the internal slots of object.
+    // 4. Return object.
+    let object = agent.heap.create(PlainDateRecord {
+        object_index: None,
+        plain_date,
+    });
+    Ok(
+        TemporalPlainDate::try_from(ordinary_populate_from_constructor(
+            agent,
+            object.unbind().into(),
+            new_target,
+            ProtoIntrinsics::TemporalPlainDate,
+            gc,
+        )?)
+        .unwrap(),
+    )
+}
+
+/// ### [3.5.2 ToTemporalDate ( item [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal-totemporaldate)
+///
+/// The abstract operation ToTemporalDate takes argument item (an ECMAScript
+/// language value) and optional argument options (an ECMAScript language
+/// value) and returns either a normal completion containing a
+/// Temporal.PlainDate or a throw completion. It returns its argument item if it
+/// is already a Temporal.PlainDate instance, converts item to a new
+/// Temporal.PlainDate instance if possible, and throws otherwise.
+pub(crate) fn to_temporal_date<'gc>(
+    agent: &mut Agent,
+    item: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, temporal_rs::PlainDate> {
+    let item = item.bind(gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 1. If options is not present, set options to undefined.
+    // 2. If item is an Object, then
+    let result = if let Ok(item) = Object::try_from(item) {
+        let plain_date = match item {
+            // a. If item has an [[InitializedTemporalDate]] internal slot, then
+            //     iii. Return ! CreateTemporalDate(item.[[ISODate]], item.[[Calendar]]).
+            Object::PlainDate(item) => Some(item.inner_plain_date(agent).clone()),
+            // b. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+            //     i. Let isoDateTime be GetISODateTimeFor(item.[[TimeZone]], item.[[EpochNanoseconds]]).
+            //     iv. Return ! CreateTemporalDate(isoDateTime.[[ISODate]], item.[[Calendar]]).
+            Object::ZonedDateTime(item) => Some(item.inner_zoned_date_time(agent).to_plain_date()),
+            // c. If item has an [[InitializedTemporalDateTime]] internal slot, then
+            //     iii. Return ! CreateTemporalDate(item.[[ISODateTime]].[[ISODate]], item.[[Calendar]]).
+            Object::PlainDateTime(item) => Some(item.inner_plain_date_time(agent).to_plain_date()),
+            _ => None,
+        };
+        if let Some(plain_date) = plain_date {
+            // i. Let resolvedOptions be ? GetOptionsObject(options).
+            // ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+            return Ok(plain_date);
+        }
+        let item = item.scope(agent, gc.nogc());
+        // d. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(item).
+        let calendar = get_temporal_calendar_identifier_with_iso_default(
+            agent,
+            item.get(agent),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // e. Let fields be ? PrepareCalendarFields(calendar, item, « year, month, month-code, day », « », « »).
+        let fields = prepare_calendar_fields(
+            agent,
+            &calendar,
+            item.get(agent),
+            CalendarFieldNames::DATE,
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // f. Let resolvedOptions be ? GetOptionsObject(options).
+        // g. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow =
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+        // h. Let isoDate be ? CalendarDateFromFields(calendar, fields, overflow).
+        // i. Return ! CreateTemporalDate(isoDate, calendar).
+        let calendar_fields = fields
+            .to_calendar_fields(agent, overflow, gc.nogc())
+            .unbind()?;
+        temporal_rs::PlainDate::from_partial(
+            PartialDate {
+                calendar_fields,
+                calendar,
+            },
+            Some(overflow),
+        )
+    } else {
+        // 3. If item is not a String, throw a TypeError exception.
+        let Ok(item) = String::try_from(item) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Item is not a String",
+                gc.into_nogc(),
+            ));
+        };
+        // 4. Let result be ? ParseISODateTime(item, « TemporalDateTimeString[~Zoned] »).
+        // 5. Let calendar be result.[[Calendar]].
+        // 6. If calendar is empty, set calendar to "iso8601".
+        // 7. Set calendar to ? CanonicalizeCalendar(calendar).
+        let result = temporal_rs::PlainDate::from_utf8(item.as_bytes(agent));
+        // 8. Let resolvedOptions be ? GetOptionsObject(options).
+        // 9. Perform ? GetTemporalOverflowOption(resolvedOptions).
+        if result.is_ok() {
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+        }
+        // 10. Let isoDate be CreateISODateRecord(result.[[Year]], result.[[Month]], result.[[Day]]).
+        // 11. Return ? CreateTemporalDate(isoDate, calendar).
+        result
+    };
+    result.map_err(|err| temporal_err_to_js_err(agent, err, gc.into_nogc()))
+}
+
+/// ### [3.5.14 AddDurationToDate ( operation, temporalDate, temporalDurationLike, options )](https://tc39.es/proposal-temporal/#sec-temporal-adddurationtodate)
+///
+/// The abstract operation AddDurationToDate takes arguments operation (add or
+/// subtract), temporalDate (a Temporal.PlainDate), temporalDurationLike (an
+/// ECMAScript language value), and options (an ECMAScript language value) and
+/// returns either a normal completion containing a Temporal.PlainDate or a
+/// throw completion. It adds/subtracts temporalDurationLike to/from
+/// temporalDate.
+fn add_duration_to_date<'gc, const IS_ADD: bool>(
+    agent: &mut Agent,
+    plain_date: TemporalPlainDate,
+    duration: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, TemporalPlainDate<'gc>> {
+    let plain_date = plain_date.scope(agent, gc.nogc());
+    let duration = duration.bind(gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 1. Let calendar be temporalDate.[[Calendar]].
+    // 2. Let duration be ? ToTemporalDuration(temporalDurationLike).
+    let duration = to_temporal_duration(agent, duration.unbind(), gc.reborrow()).unbind()?;
+    // 3. If operation is subtract, set duration to CreateNegatedTemporalDuration(duration).
+    // 4. Let dateDuration be ToDateDurationRecordWithoutTime(duration).
+    // 5. Let resolvedOptions be ? GetOptionsObject(options).
+    // 6. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+    let overflow =
+        resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+    // 7. Let result be ? CalendarDateAdd(calendar, temporalDate.[[ISODate]], dateDuration, overflow).
+    let plain_date = plain_date.get(agent).inner_plain_date(agent);
+    let result = if IS_ADD {
+        plain_date.add(&duration, Some(overflow))
+    } else {
+        plain_date.subtract(&duration, Some(overflow))
+    };
+    let gc = gc.into_nogc();
+    let plain_date = result.map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+    // 8. Return ! CreateTemporalDate(result, calendar).
+    Ok(agent.heap.create(PlainDateRecord {
+        object_index: None,
+        plain_date,
+    }))
+}
+
+/// ### [3.5.13 DifferenceTemporalPlainDate ( operation, temporalDate, other, options )](https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplaindate)
+///
+/// The abstract operation DifferenceTemporalPlainDate takes arguments
+/// operation (since or until), temporalDate (a Temporal.PlainDate), other (an
+/// ECMAScript language value), and options (an ECMAScript language value) and
+/// returns either a normal completion containing a Temporal.Duration or a
+/// throw completion. It computes the difference between the two dates
+/// represented by temporalDate and other, optionally rounds it, and returns it
+/// as a Temporal.Duration object.
+fn difference_temporal_plain_date<'gc, const IS_UNTIL: bool>(
+    agent: &mut Agent,
+    plain_date: TemporalPlainDate,
+    other: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, TemporalDuration<'gc>> {
+    let plain_date = plain_date.scope(agent, gc.nogc());
+    let other = other.bind(gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 1. Set other to ? ToTemporalDate(other).
+    let other =
+        to_temporal_date(agent, other.unbind(), Value::Undefined, gc.reborrow()).unbind()?;
+    // 2. If CalendarEquals(temporalDate.[[Calendar]], other.[[Calendar]]) is
+    //    false, throw a RangeError exception.
+    // 3. Let resolvedOptions be ? GetOptionsObject(options).
+    let resolved_options = get_options_object(agent, options.get(agent), gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    // 4. Let settings be ? GetDifferenceSettings(operation, resolvedOptions,
+    //    date, « », day, day).
+    let settings = get_difference_settings::<IS_UNTIL>(
+        agent,
+        resolved_options.unbind(),
+        UnitGroup::Date,
+        &[],
+        Unit::Day,
+        Unit::Day,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 5. If CompareISODate(temporalDate.[[ISODate]], other.[[ISODate]]) = 0,
+    //    then
+    //     a. Return ! CreateTemporalDuration(0, 0, 0, 0, 0, 0, 0, 0, 0, 0).
+    // 6. Let dateDifference be CalendarDateUntil(calendar,
+    //    temporalDate.[[ISODate]], other.[[ISODate]], settings.[[LargestUnit]]).
+    // 7. Let duration be CombineDateAndTimeDuration(dateDifference, 0).
+    // 8. If settings.[[SmallestUnit]] is not day or
+    //    settings.[[RoundingIncrement]] ≠ 1, then
+    //     a. Round the duration.
+    // 9. Let result be ! TemporalDurationFromInternal(duration, day).
+    // 10. If operation is since, set result to CreateNegatedTemporalDuration(result).
+    let plain_date = plain_date.get(agent).inner_plain_date(agent);
+    let duration = if IS_UNTIL {
+        plain_date.until(&other, settings)
+    } else {
+        plain_date.since(&other, settings)
+    };
+    let gc = gc.into_nogc();
+    let duration = duration.map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+    // 11. Return result.
+    Ok(agent.heap.create(DurationRecord {
+        object_index: None,
+        duration,
+    }))
+}
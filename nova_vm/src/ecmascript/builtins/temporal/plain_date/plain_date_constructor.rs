@@ -0,0 +1,145 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, ExceptionType, Function, JsResult, Object, PlainDateRecord,
+        Realm, String, Value, builders::BuiltinFunctionBuilder, create_temporal_plain_date,
+        temporal_err_to_js_err, to_constructor_calendar, to_integer_with_truncation,
+        to_temporal_date,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{CreateHeapData, IntrinsicConstructorIndexes},
+};
+
+/// Constructor function object for %Temporal.PlainDate%.
+pub(crate) struct TemporalPlainDateConstructor;
+
+impl Builtin for TemporalPlainDateConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.PlainDate;
+    const LENGTH: u8 = 3;
+    const BEHAVIOUR: Behaviour = Behaviour::Constructor(TemporalPlainDateConstructor::constructor);
+}
+impl BuiltinIntrinsicConstructor for TemporalPlainDateConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::TemporalPlainDate;
+}
+
+struct TemporalPlainDateFrom;
+impl Builtin for TemporalPlainDateFrom {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.from;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateConstructor::from);
+}
+
+struct TemporalPlainDateCompare;
+impl Builtin for TemporalPlainDateCompare {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.compare;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateConstructor::compare);
+}
+
+impl TemporalPlainDateConstructor {
+    /// ### [3.1.1 Temporal.PlainDate ( isoYear, isoMonth, isoDay [ , calendar ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let iso_year = args.get(0).scope(agent, gc.nogc());
+        let iso_month = args.get(1).scope(agent, gc.nogc());
+        let iso_day = args.get(2).scope(agent, gc.nogc());
+        let calendar = args.get(3).scope(agent, gc.nogc());
+        let new_target = new_target.bind(gc.nogc());
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "calling a builtin Temporal.PlainDate constructor without new is forbidden",
+                gc.into_nogc(),
+            ));
+        };
+        let Ok(new_target) = Function::try_from(new_target) else {
+            unreachable!()
+        };
+        let new_target = new_target.scope(agent, gc.nogc());
+        // 2. Let y be ? ToIntegerWithTruncation(isoYear).
+        let y = to_integer_with_truncation(agent, iso_year.get(agent), gc.reborrow()).unbind()?;
+        // 3. Let m be ? ToIntegerWithTruncation(isoMonth).
+        let m = to_integer_with_truncation(agent, iso_month.get(agent), gc.reborrow()).unbind()?;
+        // 4. Let d be ? ToIntegerWithTruncation(isoDay).
+        let d = to_integer_with_truncation(agent, iso_day.get(agent), gc.reborrow()).unbind()?;
+        // 5. If calendar is undefined, set calendar to "iso8601".
+        // 6. If calendar is not a String, throw a TypeError exception.
+        // 7. Set calendar to ? CanonicalizeCalendar(calendar).
+        let calendar = to_constructor_calendar(agent, calendar.get(agent), gc.nogc()).unbind()?;
+        // 8. If IsValidISODate(y, m, d) is false, throw a RangeError exception.
+        // 9. Let isoDate be CreateISODateRecord(y, m, d).
+        let plain_date = temporal_rs::PlainDate::try_new(
+            y.clamp(i32::MIN.into(), i32::MAX.into()) as i32,
+            u8::try_from(m).unwrap_or(u8::MAX),
+            u8::try_from(d).unwrap_or(u8::MAX),
+            calendar,
+        )
+        .map_err(|err| temporal_err_to_js_err(agent, err, gc.nogc()))
+        .unbind()?;
+        // 10. Return ? CreateTemporalDate(isoDate, calendar, NewTarget).
+        create_temporal_plain_date(agent, plain_date, Some(new_target.get(agent)), gc)
+            .map(Value::from)
+    }
+
+    /// ### [3.2.2 Temporal.PlainDate.from ( item [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.from)
+    fn from<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let item = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Return ? ToTemporalDate(item, options).
+        let plain_date = to_temporal_date(agent, item.unbind(), options.unbind(), gc)?;
+        Ok(agent
+            .heap
+            .create(PlainDateRecord {
+                object_index: None,
+                plain_date,
+            })
+            .into())
+    }
+
+    /// ### [3.2.3 Temporal.PlainDate.compare ( one, two )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.compare)
+    fn compare<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let one = args.get(0).bind(gc.nogc());
+        let two = args.get(1).scope(agent, gc.nogc());
+        // 1. Set one to ? ToTemporalDate(one).
+        let one =
+            to_temporal_date(agent, one.unbind(), Value::Undefined, gc.reborrow()).unbind()?;
+        // 2. Set two to ? ToTemporalDate(two).
+        let two = to_temporal_date(agent, two.get(agent), Value::Undefined, gc)?;
+        // 3. Return 𝔽(CompareISODate(one.[[ISODate]], two.[[ISODate]])).
+        Ok((one.compare_iso(&two) as i8).into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>, _gc: NoGcScope) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let plain_date_prototype = intrinsics.temporal_plain_date_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<TemporalPlainDateConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(3)
+        .with_prototype_property(plain_date_prototype.into())
+        .with_builtin_function_property::<TemporalPlainDateFrom>()
+        .with_builtin_function_property::<TemporalPlainDateCompare>()
+        .build();
+    }
+}
@@ -0,0 +1,1085 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use temporal_rs::options::DisplayCalendar;
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
+        CalendarFieldNames, ExceptionType, JsResult, Object, PlainDateRecord, PlainDateTimeRecord,
+        PlainMonthDayRecord, PlainYearMonthRecord, PropertyKey, Realm, String, Value,
+        ZonedDateTimeRecord,
+        builders::OrdinaryObjectBuilder,
+        builtins::temporal::plain_date::{
+            add_duration_to_date, difference_temporal_plain_date,
+            require_internal_slot_temporal_plain_date,
+        },
+        get, get_options_object, get_temporal_show_calendar_name_option,
+        is_partial_temporal_object, prepare_calendar_fields, resolve_temporal_overflow_option,
+        temporal_err_to_js_err, to_temporal_calendar_identifier, to_temporal_date,
+        to_temporal_time_zone_identifier, to_time_record_or_midnight,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{CreateHeapData, WellKnownSymbols},
+};
+
+pub(crate) struct TemporalPlainDatePrototype;
+
+struct TemporalPlainDatePrototypeGetCalendarId;
+impl Builtin for TemporalPlainDatePrototypeGetCalendarId {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_calendarId;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.calendarId.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::get_calendar_id);
+}
+impl BuiltinGetter for TemporalPlainDatePrototypeGetCalendarId {}
+
+struct TemporalPlainDatePrototypeGetEra;
+impl Builtin for TemporalPlainDatePrototypeGetEra {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_era;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.era.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::get_era);
+}
+impl BuiltinGetter for TemporalPlainDatePrototypeGetEra {}
+
+struct TemporalPlainDatePrototypeGetEraYear;
+impl Builtin for TemporalPlainDatePrototypeGetEraYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_eraYear;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.eraYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::get_era_year);
+}
+impl BuiltinGetter for TemporalPlainDatePrototypeGetEraYear {}
+
+struct TemporalPlainDatePrototypeGetYear;
+impl Builtin for TemporalPlainDatePrototypeGetYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_year;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.year.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::get_year);
+}
+impl BuiltinGetter for TemporalPlainDatePrototypeGetYear {}
+
+struct TemporalPlainDatePrototypeGetMonth;
+impl Builtin for TemporalPlainDatePrototypeGetMonth {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_month;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.month.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::get_month);
+}
+impl BuiltinGetter for TemporalPlainDatePrototypeGetMonth {}
+
+struct TemporalPlainDatePrototypeGetMonthCode;
+impl Builtin for TemporalPlainDatePrototypeGetMonthCode {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_monthCode;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.monthCode.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::get_month_code);
+}
+impl BuiltinGetter for TemporalPlainDatePrototypeGetMonthCode {}
+
+struct TemporalPlainDatePrototypeGetDay;
+impl Builtin for TemporalPlainDatePrototypeGetDay {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_day;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.day.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::get_day);
+}
+impl BuiltinGetter for TemporalPlainDatePrototypeGetDay {}
+
+struct TemporalPlainDatePrototypeGetDayOfWeek;
+impl Builtin for TemporalPlainDatePrototypeGetDayOfWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_dayOfWeek;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.dayOfWeek.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::get_day_of_week);
+}
+impl BuiltinGetter for TemporalPlainDatePrototypeGetDayOfWeek {}
+
+struct TemporalPlainDatePrototypeGetDayOfYear;
+impl Builtin for TemporalPlainDatePrototypeGetDayOfYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_dayOfYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.dayOfYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::get_day_of_year);
+}
+impl BuiltinGetter for TemporalPlainDatePrototypeGetDayOfYear {}
+
+struct TemporalPlainDatePrototypeGetWeekOfYear;
+impl Builtin for TemporalPlainDatePrototypeGetWeekOfYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_weekOfYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.weekOfYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::get_week_of_year);
+}
+impl BuiltinGetter for TemporalPlainDatePrototypeGetWeekOfYear {}
+
+struct TemporalPlainDatePrototypeGetYearOfWeek;
+impl Builtin for TemporalPlainDatePrototypeGetYearOfWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_yearOfWeek;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.yearOfWeek.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::get_year_of_week);
+}
+impl BuiltinGetter for TemporalPlainDatePrototypeGetYearOfWeek {}
+
+struct TemporalPlainDatePrototypeGetDaysInWeek;
+impl Builtin for TemporalPlainDatePrototypeGetDaysInWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInWeek;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInWeek.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::get_days_in_week);
+}
+impl BuiltinGetter for TemporalPlainDatePrototypeGetDaysInWeek {}
+
+struct TemporalPlainDatePrototypeGetDaysInMonth;
+impl Builtin for TemporalPlainDatePrototypeGetDaysInMonth {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInMonth;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInMonth.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::get_days_in_month);
+}
+impl BuiltinGetter for TemporalPlainDatePrototypeGetDaysInMonth {}
+
+struct TemporalPlainDatePrototypeGetDaysInYear;
+impl Builtin for TemporalPlainDatePrototypeGetDaysInYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::get_days_in_year);
+}
+impl BuiltinGetter for TemporalPlainDatePrototypeGetDaysInYear {}
+
+struct TemporalPlainDatePrototypeGetMonthsInYear;
+impl Builtin for TemporalPlainDatePrototypeGetMonthsInYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_monthsInYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.monthsInYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::get_months_in_year);
+}
+impl BuiltinGetter for TemporalPlainDatePrototypeGetMonthsInYear {}
+
+struct TemporalPlainDatePrototypeGetInLeapYear;
+impl Builtin for TemporalPlainDatePrototypeGetInLeapYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_inLeapYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.inLeapYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::get_in_leap_year);
+}
+impl BuiltinGetter for TemporalPlainDatePrototypeGetInLeapYear {}
+
+struct TemporalPlainDatePrototypeToPlainYearMonth;
+impl Builtin for TemporalPlainDatePrototypeToPlainYearMonth {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toPlainYearMonth;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainDatePrototype::to_plain_year_month);
+}
+
+struct TemporalPlainDatePrototypeToPlainMonthDay;
+impl Builtin for TemporalPlainDatePrototypeToPlainMonthDay {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toPlainMonthDay;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::to_plain_month_day);
+}
+
+struct TemporalPlainDatePrototypeAdd;
+impl Builtin for TemporalPlainDatePrototypeAdd {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.add;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::add);
+}
+
+struct TemporalPlainDatePrototypeSubtract;
+impl Builtin for TemporalPlainDatePrototypeSubtract {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.subtract;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::subtract);
+}
+
+struct TemporalPlainDatePrototypeWith;
+impl Builtin for TemporalPlainDatePrototypeWith {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.with;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::with);
+}
+
+struct TemporalPlainDatePrototypeWithCalendar;
+impl Builtin for TemporalPlainDatePrototypeWithCalendar {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.withCalendar;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::with_calendar);
+}
+
+struct TemporalPlainDatePrototypeUntil;
+impl Builtin for TemporalPlainDatePrototypeUntil {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.until;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::until);
+}
+
+struct TemporalPlainDatePrototypeSince;
+impl Builtin for TemporalPlainDatePrototypeSince {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.since;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::since);
+}
+
+struct TemporalPlainDatePrototypeEquals;
+impl Builtin for TemporalPlainDatePrototypeEquals {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.equals;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::equals);
+}
+
+struct TemporalPlainDatePrototypeToPlainDateTime;
+impl Builtin for TemporalPlainDatePrototypeToPlainDateTime {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toPlainDateTime;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::to_plain_date_time);
+}
+
+struct TemporalPlainDatePrototypeToZonedDateTime;
+impl Builtin for TemporalPlainDatePrototypeToZonedDateTime {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toZonedDateTime;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::to_zoned_date_time);
+}
+
+struct TemporalPlainDatePrototypeToString;
+impl Builtin for TemporalPlainDatePrototypeToString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toString;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::to_string);
+}
+
+struct TemporalPlainDatePrototypeToLocaleString;
+impl Builtin for TemporalPlainDatePrototypeToLocaleString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toLocaleString;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::to_locale_string);
+}
+
+struct TemporalPlainDatePrototypeToJSON;
+impl Builtin for TemporalPlainDatePrototypeToJSON {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toJSON;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::to_json);
+}
+
+struct TemporalPlainDatePrototypeValueOf;
+impl Builtin for TemporalPlainDatePrototypeValueOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.valueOf;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDatePrototype::value_of);
+}
+
+impl TemporalPlainDatePrototype {
+    /// ### [3.3.3 get Temporal.PlainDate.prototype.calendarId](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.calendarid)
+    fn get_calendar_id<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return plainDate.[[Calendar]].
+        let value = plain_date.inner_plain_date(agent).calendar().identifier();
+        Ok(Value::from_static_str(agent, value, gc))
+    }
+
+    /// ### [3.3.4 get Temporal.PlainDate.prototype.era](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.era)
+    fn get_era<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return CalendarISOToDate(plainDate.[[Calendar]], plainDate.[[ISODate]]).[[Era]].
+        let value = plain_date.inner_plain_date(agent).era();
+        Ok(value.map_or(Value::Undefined, |era| {
+            Value::from_str(agent, era.as_str(), gc)
+        }))
+    }
+
+    /// ### [3.3.5 get Temporal.PlainDate.prototype.eraYear](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.erayear)
+    fn get_era_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDate.[[Calendar]], plainDate.[[ISODate]]).[[EraYear]]).
+        let value = plain_date.inner_plain_date(agent).era_year();
+        Ok(value.into())
+    }
+
+    /// ### [3.3.6 get Temporal.PlainDate.prototype.year](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.year)
+    fn get_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDate.[[Calendar]], plainDate.[[ISODate]]).[[Year]]).
+        let value = plain_date.inner_plain_date(agent).year();
+        Ok(value.into())
+    }
+
+    /// ### [3.3.7 get Temporal.PlainDate.prototype.month](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.month)
+    fn get_month<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDate.[[Calendar]], plainDate.[[ISODate]]).[[Month]]).
+        let value = plain_date.inner_plain_date(agent).month();
+        Ok(value.into())
+    }
+
+    /// ### [3.3.8 get Temporal.PlainDate.prototype.monthCode](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.monthcode)
+    fn get_month_code<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return CalendarISOToDate(plainDate.[[Calendar]], plainDate.[[ISODate]]).[[MonthCode]].
+        let value = plain_date.inner_plain_date(agent).month_code();
+        Ok(Value::from_str(agent, value.as_str(), gc))
+    }
+
+    /// ### [3.3.9 get Temporal.PlainDate.prototype.day](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.day)
+    fn get_day<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDate.[[Calendar]], plainDate.[[ISODate]]).[[Day]]).
+        let value = plain_date.inner_plain_date(agent).day();
+        Ok(value.into())
+    }
+
+    /// ### [3.3.10 get Temporal.PlainDate.prototype.dayOfWeek](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.dayofweek)
+    fn get_day_of_week<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDate.[[Calendar]], plainDate.[[ISODate]]).[[DayOfWeek]]).
+        let value = plain_date.inner_plain_date(agent).day_of_week();
+        Ok(value.into())
+    }
+
+    /// ### [3.3.11 get Temporal.PlainDate.prototype.dayOfYear](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.dayofyear)
+    fn get_day_of_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDate.[[Calendar]], plainDate.[[ISODate]]).[[DayOfYear]]).
+        let value = plain_date.inner_plain_date(agent).day_of_year();
+        Ok(value.into())
+    }
+
+    /// ### [3.3.12 get Temporal.PlainDate.prototype.weekOfYear](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.weekofyear)
+    fn get_week_of_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDate.[[Calendar]], plainDate.[[ISODate]]).[[WeekOfYear].[[Week]]).
+        let value = plain_date.inner_plain_date(agent).week_of_year();
+        Ok(value.into())
+    }
+
+    /// ### [3.3.13 get Temporal.PlainDate.prototype.yearOfWeek](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.yearofweek)
+    fn get_year_of_week<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDate.[[Calendar]], plainDate.[[ISODate]]).[[WeekOfYear].[[Year]]).
+        let value = plain_date.inner_plain_date(agent).year_of_week();
+        Ok(value.into())
+    }
+
+    /// ### [3.3.14 get Temporal.PlainDate.prototype.daysInWeek](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.daysinweek)
+    fn get_days_in_week<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDate.[[Calendar]], plainDate.[[ISODate]]).[[DaysInWeek]]).
+        let value = plain_date.inner_plain_date(agent).days_in_week();
+        Ok(value.into())
+    }
+
+    /// ### [3.3.15 get Temporal.PlainDate.prototype.daysInMonth](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.daysinmonth)
+    fn get_days_in_month<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDate.[[Calendar]], plainDate.[[ISODate]]).[[DaysInMonth]]).
+        let value = plain_date.inner_plain_date(agent).days_in_month();
+        Ok(value.into())
+    }
+
+    /// ### [3.3.16 get Temporal.PlainDate.prototype.daysInYear](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.daysinyear)
+    fn get_days_in_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDate.[[Calendar]], plainDate.[[ISODate]]).[[DaysInYear]]).
+        let value = plain_date.inner_plain_date(agent).days_in_year();
+        Ok(value.into())
+    }
+
+    /// ### [3.3.17 get Temporal.PlainDate.prototype.monthsInYear](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.monthsinyear)
+    fn get_months_in_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDate.[[Calendar]], plainDate.[[ISODate]]).[[MonthsInYear]]).
+        let value = plain_date.inner_plain_date(agent).months_in_year();
+        Ok(value.into())
+    }
+
+    /// ### [3.3.18 get Temporal.PlainDate.prototype.inLeapYear](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindate.prototype.inleapyear)
+    fn get_in_leap_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDate.[[Calendar]], plainDate.[[ISODate]]).[[InLeapYear]]).
+        let value = plain_date.inner_plain_date(agent).in_leap_year();
+        Ok(value.into())
+    }
+
+    /// ### [3.3.19 Temporal.PlainDate.prototype.toPlainYearMonth ( )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.toplainyearmonth)
+    fn to_plain_year_month<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Let calendar be plainDate.[[Calendar]].
+        // 4. Let fields be ISODateToFields(calendar, plainDate.[[ISODate]], date).
+        // 5. Let isoDate be ? CalendarYearMonthFromFields(calendar, fields, constrain).
+        let plain_year_month = plain_date
+            .inner_plain_date(agent)
+            .to_plain_year_month()
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        // 6. Return ! CreateTemporalYearMonth(isoDate, calendar).
+        Ok(agent
+            .heap
+            .create(PlainYearMonthRecord {
+                object_index: None,
+                plain_year_month,
+            })
+            .into())
+    }
+
+    /// ### [3.3.20 Temporal.PlainDate.prototype.toPlainMonthDay ( )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.toplainmonthday)
+    fn to_plain_month_day<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Let calendar be plainDate.[[Calendar]].
+        // 4. Let fields be ISODateToFields(calendar, plainDate.[[ISODate]], date).
+        // 5. Let isoDate be ? CalendarMonthDayFromFields(calendar, fields, constrain).
+        let plain_month_day = plain_date
+            .inner_plain_date(agent)
+            .to_plain_month_day()
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        // 6. Return ! CreateTemporalMonthDay(isoDate, calendar).
+        Ok(agent
+            .heap
+            .create(PlainMonthDayRecord {
+                object_index: None,
+                plain_month_day,
+            })
+            .into())
+    }
+
+    /// ### [3.3.21 Temporal.PlainDate.prototype.add ( temporalDurationLike [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.add)
+    fn add<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let duration = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? AddDurationToDate(add, plainDate, temporalDurationLike, options).
+        const ADD: bool = true;
+        add_duration_to_date::<ADD>(
+            agent,
+            plain_date.unbind(),
+            duration.unbind(),
+            options.unbind(),
+            gc,
+        )
+        .map(Value::from)
+    }
+
+    /// ### [3.3.22 Temporal.PlainDate.prototype.subtract ( temporalDurationLike [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.subtract)
+    fn subtract<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let duration = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? AddDurationToDate(subtract, plainDate, temporalDurationLike, options).
+        const SUBTRACT: bool = false;
+        add_duration_to_date::<SUBTRACT>(
+            agent,
+            plain_date.unbind(),
+            duration.unbind(),
+            options.unbind(),
+            gc,
+        )
+        .map(Value::from)
+    }
+
+    /// ### [3.3.23 Temporal.PlainDate.prototype.with ( temporalDateLike [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.with)
+    fn with<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let temporal_date_like = args.get(0).scope(agent, gc.nogc());
+        let options = args.get(1).scope(agent, gc.nogc());
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. If ? IsPartialTemporalObject(temporalDateLike) is false, throw a
+        //    TypeError exception.
+        if !is_partial_temporal_object(agent, temporal_date_like.get(agent), gc.reborrow())
+            .unbind()?
+        {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Argument is not a partial Temporal.PlainDate-like object",
+                gc.into_nogc(),
+            ));
+        }
+        let temporal_date_like = Object::try_from(temporal_date_like.get(agent)).unwrap();
+        // 4. Let calendar be plainDate.[[Calendar]].
+        let calendar = plain_date
+            .get(agent)
+            .inner_plain_date(agent)
+            .calendar()
+            .clone();
+        // 5. Let fields be ISODateToFields(calendar, plainDate.[[ISODate]], date).
+        // 6. Let partialDate be ? PrepareCalendarFields(calendar,
+        //    temporalDateLike, « year, month, month-code, day », « », partial).
+        let partial_date = prepare_calendar_fields(
+            agent,
+            &calendar,
+            temporal_date_like,
+            CalendarFieldNames::DATE,
+            true,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 7. Set fields to CalendarMergeFields(calendar, fields, partialDate).
+        // 8. Let resolvedOptions be ? GetOptionsObject(options).
+        // 9. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow =
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let fields = partial_date.to_calendar_fields(agent, overflow, gc)?;
+        // 10. Let isoDate be ? CalendarDateFromFields(calendar, fields, overflow).
+        let plain_date = plain_date
+            .get(agent)
+            .inner_plain_date(agent)
+            .with(fields, Some(overflow))
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        // 11. Return ! CreateTemporalDate(isoDate, calendar).
+        Ok(agent
+            .heap
+            .create(PlainDateRecord {
+                object_index: None,
+                plain_date,
+            })
+            .into())
+    }
+
+    /// ### [3.3.24 Temporal.PlainDate.prototype.withCalendar ( calendarLike )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.withcalendar)
+    fn with_calendar<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let calendar_like = args.get(0).bind(gc);
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Let calendar be ? ToTemporalCalendarIdentifier(calendarLike).
+        let calendar = to_temporal_calendar_identifier(agent, calendar_like, gc)?;
+        // 4. Return ! CreateTemporalDate(plainDate.[[ISODate]], calendar).
+        let plain_date = plain_date.inner_plain_date(agent).with_calendar(calendar);
+        Ok(agent
+            .heap
+            .create(PlainDateRecord {
+                object_index: None,
+                plain_date,
+            })
+            .into())
+    }
+
+    /// ### [3.3.25 Temporal.PlainDate.prototype.until ( other [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.until)
+    fn until<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let other = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalPlainDate(until, plainDate, other, options).
+        const UNTIL: bool = true;
+        difference_temporal_plain_date::<UNTIL>(
+            agent,
+            plain_date.unbind(),
+            other.unbind(),
+            options.unbind(),
+            gc,
+        )
+        .map(Value::from)
+    }
+
+    /// ### [3.3.26 Temporal.PlainDate.prototype.since ( other [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.since)
+    fn since<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let other = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalPlainDate(since, plainDate, other, options).
+        const SINCE: bool = false;
+        difference_temporal_plain_date::<SINCE>(
+            agent,
+            plain_date.unbind(),
+            other.unbind(),
+            options.unbind(),
+            gc,
+        )
+        .map(Value::from)
+    }
+
+    /// ### [3.3.27 Temporal.PlainDate.prototype.equals ( other )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.equals)
+    fn equals<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let other = args.get(0).bind(gc.nogc());
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. Set other to ? ToTemporalDate(other).
+        let other =
+            to_temporal_date(agent, other.unbind(), Value::Undefined, gc.reborrow()).unbind()?;
+        // 4. If CompareISODate(plainDate.[[ISODate]], other.[[ISODate]]) ≠ 0,
+        //    return false.
+        // 5. Return CalendarEquals(plainDate.[[Calendar]], other.[[Calendar]]).
+        Ok((plain_date.get(agent).inner_plain_date(agent) == &other).into())
+    }
+
+    /// ### [3.3.28 Temporal.PlainDate.prototype.toPlainDateTime ( [ temporalTime ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.toplaindatetime)
+    fn to_plain_date_time<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let temporal_time = args.get(0).bind(gc.nogc());
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. Let time be ? ToTimeRecordOrMidnight(temporalTime).
+        let time =
+            to_time_record_or_midnight(agent, temporal_time.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        // 4. Let isoDateTime be CombineISODateAndTimeRecord(plainDate.[[ISODate]], time).
+        // 5. Return ? CreateTemporalDateTime(isoDateTime, plainDate.[[Calendar]]).
+        let plain_date_time = plain_date
+            .get(agent)
+            .inner_plain_date(agent)
+            .to_plain_date_time(time)
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        Ok(agent
+            .heap
+            .create(PlainDateTimeRecord {
+                object_index: None,
+                plain_date_time,
+            })
+            .into())
+    }
+
+    /// ### [3.3.29 Temporal.PlainDate.prototype.toZonedDateTime ( item )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tozoneddatetime)
+    fn to_zoned_date_time<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let item = args.get(0).bind(gc.nogc());
+        // 1. Let temporalDate be the this value.
+        // 2. Perform ? RequireInternalSlot(temporalDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. If item is an Object, then
+        let (time_zone, temporal_time) = if let Ok(item) = Object::try_from(item) {
+            let scoped_item = item.scope(agent, gc.nogc());
+            // a. Let timeZoneLike be ? Get(item, "timeZone").
+            let time_zone_like = get(
+                agent,
+                item.unbind(),
+                BUILTIN_STRING_MEMORY.timeZone.into(),
+                gc.reborrow(),
+            )
+            .unbind()?
+            .bind(gc.nogc());
+            // b. If timeZoneLike is undefined, then
+            if time_zone_like.is_undefined() {
+                // i. Let timeZone be ? ToTemporalTimeZoneIdentifier(item).
+                // ii. Let temporalTime be undefined.
+                let time_zone = to_temporal_time_zone_identifier(
+                    agent,
+                    scoped_item.get(agent).into(),
+                    gc.nogc(),
+                )
+                .unbind()?;
+                (time_zone, None)
+            } else {
+                // c. Else,
+                //     i. Let timeZone be ? ToTemporalTimeZoneIdentifier(timeZoneLike).
+                let time_zone =
+                    to_temporal_time_zone_identifier(agent, time_zone_like, gc.nogc()).unbind()?;
+                //     ii. Let temporalTime be ? Get(item, "plainTime").
+                let temporal_time = get(
+                    agent,
+                    scoped_item.get(agent),
+                    BUILTIN_STRING_MEMORY.plainTime.into(),
+                    gc.reborrow(),
+                )
+                .unbind()?
+                .bind(gc.nogc());
+                // 6. Else,
+                //     a. Set temporalTime to ? ToTemporalTime(temporalTime).
+                let temporal_time =
+                    to_time_record_or_midnight(agent, temporal_time.unbind(), gc.reborrow())
+                        .unbind()?;
+                (time_zone, temporal_time)
+            }
+        } else {
+            // 4. Else,
+            //     a. Let timeZone be ? ToTemporalTimeZoneIdentifier(item).
+            //     b. Let temporalTime be undefined.
+            let time_zone = to_temporal_time_zone_identifier(agent, item, gc.nogc()).unbind()?;
+            (time_zone, None)
+        };
+        let gc = gc.into_nogc();
+        // 5. If temporalTime is undefined, then
+        //     a. Let epochNs be ? GetStartOfDay(timeZone, temporalDate.[[ISODate]]).
+        // 6. Else,
+        //     b. Let isoDateTime be CombineISODateAndTimeRecord(temporalDate.[[ISODate]], temporalTime.[[Time]]).
+        //     c. If ISODateTimeWithinLimits(isoDateTime) is false, throw a RangeError exception.
+        //     d. Let epochNs be ? GetEpochNanosecondsFor(timeZone, isoDateTime, compatible).
+        let zoned_date_time = plain_date
+            .get(agent)
+            .inner_plain_date(agent)
+            .to_zoned_date_time(time_zone, temporal_time)
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        // 7. Return ! CreateTemporalZonedDateTime(epochNs, timeZone, temporalDate.[[Calendar]]).
+        Ok(agent
+            .heap
+            .create(ZonedDateTimeRecord {
+                object_index: None,
+                zoned_date_time,
+            })
+            .into())
+    }
+
+    /// ### [3.3.30 Temporal.PlainDate.prototype.toString ( [ options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tostring)
+    fn to_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let options = args.get(0).bind(gc.nogc());
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc.nogc())
+            .unbind()?
+            .scope(agent, gc.nogc());
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let resolved_options = get_options_object(agent, options, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 4. Let showCalendar be ? GetTemporalShowCalendarNameOption(resolvedOptions).
+        let show_calendar = match resolved_options {
+            Some(resolved_options) => get_temporal_show_calendar_name_option(
+                agent,
+                resolved_options.unbind(),
+                gc.reborrow(),
+            )
+            .unbind()?,
+            None => DisplayCalendar::default(),
+        };
+        // 5. Return TemporalDateToString(plainDate, showCalendar).
+        let string = plain_date
+            .get(agent)
+            .inner_plain_date(agent)
+            .to_ixdtf_string(show_calendar);
+        Ok(Value::from_string(agent, string, gc.into_nogc()))
+    }
+
+    /// ### [3.3.31 Temporal.PlainDate.prototype.toLocaleString ( [ locales [ , options ] ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tolocalestring)
+    ///
+    /// An ECMAScript implementation that includes the ECMA-402
+    /// Internationalization API must implement this method as specified in the
+    /// ECMA-402 specification. If an ECMAScript implementation does not
+    /// include the ECMA-402 API the following specification of this method is
+    /// used.
+    fn to_locale_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return TemporalDateToString(plainDate, auto).
+        let string = plain_date
+            .inner_plain_date(agent)
+            .to_ixdtf_string(DisplayCalendar::Auto);
+        Ok(Value::from_string(agent, string, gc))
+    }
+
+    /// ### [3.3.32 Temporal.PlainDate.prototype.toJSON ( )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.tojson)
+    fn to_json<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDate be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDate, [[InitializedTemporalDate]]).
+        let plain_date = require_internal_slot_temporal_plain_date(agent, this_value, gc)?;
+        // 3. Return TemporalDateToString(plainDate, auto).
+        let string = plain_date
+            .inner_plain_date(agent)
+            .to_ixdtf_string(DisplayCalendar::Auto);
+        Ok(Value::from_string(agent, string, gc))
+    }
+
+    /// ### [3.3.33 Temporal.PlainDate.prototype.valueOf ( )](https://tc39.es/proposal-temporal/#sec-temporal.plaindate.prototype.valueof)
+    fn value_of<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Throw a TypeError exception.
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "`valueOf` not supported by Temporal built-ins. See 'compare', 'equals', or `toString`",
+            gc.into_nogc(),
+        ))
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>, _: NoGcScope) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let this = intrinsics.temporal_plain_date_prototype();
+        let object_prototype = intrinsics.object_prototype();
+        let plain_date_constructor = intrinsics.temporal_plain_date();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(33)
+            .with_prototype(object_prototype)
+            .with_constructor_property(plain_date_constructor)
+            .with_builtin_function_getter_property::<TemporalPlainDatePrototypeGetCalendarId>()
+            .with_builtin_function_getter_property::<TemporalPlainDatePrototypeGetEra>()
+            .with_builtin_function_getter_property::<TemporalPlainDatePrototypeGetEraYear>()
+            .with_builtin_function_getter_property::<TemporalPlainDatePrototypeGetYear>()
+            .with_builtin_function_getter_property::<TemporalPlainDatePrototypeGetMonth>()
+            .with_builtin_function_getter_property::<TemporalPlainDatePrototypeGetMonthCode>()
+            .with_builtin_function_getter_property::<TemporalPlainDatePrototypeGetDay>()
+            .with_builtin_function_getter_property::<TemporalPlainDatePrototypeGetDayOfWeek>()
+            .with_builtin_function_getter_property::<TemporalPlainDatePrototypeGetDayOfYear>()
+            .with_builtin_function_getter_property::<TemporalPlainDatePrototypeGetWeekOfYear>()
+            .with_builtin_function_getter_property::<TemporalPlainDatePrototypeGetYearOfWeek>()
+            .with_builtin_function_getter_property::<TemporalPlainDatePrototypeGetDaysInWeek>()
+            .with_builtin_function_getter_property::<TemporalPlainDatePrototypeGetDaysInMonth>()
+            .with_builtin_function_getter_property::<TemporalPlainDatePrototypeGetDaysInYear>()
+            .with_builtin_function_getter_property::<TemporalPlainDatePrototypeGetMonthsInYear>()
+            .with_builtin_function_getter_property::<TemporalPlainDatePrototypeGetInLeapYear>()
+            .with_builtin_function_property::<TemporalPlainDatePrototypeToPlainYearMonth>()
+            .with_builtin_function_property::<TemporalPlainDatePrototypeToPlainMonthDay>()
+            .with_builtin_function_property::<TemporalPlainDatePrototypeAdd>()
+            .with_builtin_function_property::<TemporalPlainDatePrototypeSubtract>()
+            .with_builtin_function_property::<TemporalPlainDatePrototypeWith>()
+            .with_builtin_function_property::<TemporalPlainDatePrototypeWithCalendar>()
+            .with_builtin_function_property::<TemporalPlainDatePrototypeUntil>()
+            .with_builtin_function_property::<TemporalPlainDatePrototypeSince>()
+            .with_builtin_function_property::<TemporalPlainDatePrototypeEquals>()
+            .with_builtin_function_property::<TemporalPlainDatePrototypeToPlainDateTime>()
+            .with_builtin_function_property::<TemporalPlainDatePrototypeToZonedDateTime>()
+            .with_builtin_function_property::<TemporalPlainDatePrototypeToString>()
+            .with_builtin_function_property::<TemporalPlainDatePrototypeToLocaleString>()
+            .with_builtin_function_property::<TemporalPlainDatePrototypeToJSON>()
+            .with_builtin_function_property::<TemporalPlainDatePrototypeValueOf>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Temporal_PlainDate.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
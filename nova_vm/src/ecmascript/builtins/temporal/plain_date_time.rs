@@ -0,0 +1,366 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod data;
+mod plain_date_time_constructor;
+mod plain_date_time_prototype;
+
+pub(crate) use data::*;
+pub(crate) use plain_date_time_constructor::*;
+pub(crate) use plain_date_time_prototype::*;
+
+use temporal_rs::{
+    fields::DateTimeFields,
+    options::{Unit, UnitGroup},
+    partial::PartialDateTime,
+};
+
+use crate::{
+    ecmascript::{
+        Agent, CalendarFieldNames, DurationRecord, ExceptionType, Function, InternalMethods,
+        InternalSlots, JsResult, Object, OrdinaryObject, ProtoIntrinsics, String, TemporalDuration,
+        Value, get_difference_settings, get_options_object,
+        get_temporal_calendar_identifier_with_iso_default, object_handle,
+        ordinary_populate_from_constructor, prepare_calendar_fields,
+        resolve_temporal_overflow_option, temporal_err_to_js_err, to_temporal_duration,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{
+        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
+        HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues, arena_vec_access,
+    },
+};
+
+/// # [5 Temporal.PlainDateTime Objects](https://tc39.es/proposal-temporal/#sec-temporal-plaindatetime-objects)
+///
+/// A Temporal.PlainDateTime object is an Object that contains integers
+/// corresponding to a particular year, month, day, hour, minute, second,
+/// millisecond, microsecond, and nanosecond, as well as an Object indicating
+/// the calendar system used to interpret them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct TemporalPlainDateTime<'a>(BaseIndex<'a, PlainDateTimeRecord<'static>>);
+object_handle!(TemporalPlainDateTime, PlainDateTime);
+arena_vec_access!(
+    TemporalPlainDateTime,
+    'a,
+    PlainDateTimeRecord,
+    plain_date_times
+);
+
+impl TemporalPlainDateTime<'_> {
+    pub(crate) fn inner_plain_date_time(self, agent: &Agent) -> &temporal_rs::PlainDateTime {
+        &self.unbind().get(agent).plain_date_time
+    }
+}
+
+impl<'a> InternalSlots<'a> for TemporalPlainDateTime<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::TemporalPlainDateTime;
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        self.get(agent).object_index.unbind()
+    }
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(
+            self.get_mut(agent)
+                .object_index
+                .replace(backing_object)
+                .is_none()
+        );
+    }
+}
+
+impl<'a> InternalMethods<'a> for TemporalPlainDateTime<'a> {}
+
+impl HeapMarkAndSweep for TemporalPlainDateTime<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.plain_date_times.push(*self);
+    }
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.plain_date_times.shift_index(&mut self.0);
+    }
+}
+
+impl HeapSweepWeakReference for TemporalPlainDateTime<'static> {
+    fn sweep_weak_reference(self, compactions: &CompactionLists) -> Option<Self> {
+        compactions
+            .plain_date_times
+            .shift_weak_index(self.0)
+            .map(Self)
+    }
+}
+
+impl<'a> CreateHeapData<PlainDateTimeRecord<'a>, TemporalPlainDateTime<'a>> for Heap {
+    fn create(&mut self, data: PlainDateTimeRecord<'a>) -> TemporalPlainDateTime<'a> {
+        self.plain_date_times.push(data.unbind());
+        self.alloc_counter += core::mem::size_of::<PlainDateTimeRecord<'static>>();
+        TemporalPlainDateTime(BaseIndex::last(&self.plain_date_times))
+    }
+}
+
+#[inline(always)]
+fn require_internal_slot_temporal_plain_date_time<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, TemporalPlainDateTime<'a>> {
+    match value {
+        Value::PlainDateTime(plain_date_time) => Ok(plain_date_time.bind(gc)),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Object is not a Temporal PlainDateTime",
+            gc,
+        )),
+    }
+}
+
+/// ### [5.5.6 CreateTemporalDateTime ( isoDateTime, calendar [ , newTarget ] )](https://tc39.es/proposal-temporal/#sec-temporal-createtemporaldatetime)
+pub(crate) fn create_temporal_plain_date_time<'gc>(
+    agent: &mut Agent,
+    plain_date_time: temporal_rs::PlainDateTime,
+    new_target: Option<Function>,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, TemporalPlainDateTime<'gc>> {
+    // 1. If newTarget is not present, set newTarget to %Temporal.PlainDateTime%.
+    let new_target = new_target.unwrap_or_else(|| {
+        agent
+            .current_realm_record()
+            .intrinsics()
+            .temporal_plain_date_time()
+            .into()
+    });
+    // 2. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.PlainDateTime.prototype%", « [[InitializedTemporalDateTime]], [[ISODateTime]], [[Calendar]] »).
+    // 3. Set the internal slots of object.
+    // 4. Return object.
+    let object = agent.heap.create(PlainDateTimeRecord {
+        object_index: None,
+        plain_date_time,
+    });
+    Ok(
+        TemporalPlainDateTime::try_from(ordinary_populate_from_constructor(
+            agent,
+            object.unbind().into(),
+            new_target,
+            ProtoIntrinsics::TemporalPlainDateTime,
+            gc,
+        )?)
+        .unwrap(),
+    )
+}
+
+/// ### [5.5.4 ToTemporalDateTime ( item [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal-totemporaldatetime)
+///
+/// The abstract operation ToTemporalDateTime takes argument item (an
+/// ECMAScript language value) and optional argument options (an ECMAScript
+/// language value) and returns either a normal completion containing a
+/// Temporal.PlainDateTime or a throw completion. It returns its argument item
+/// if it is already a Temporal.PlainDateTime instance, converts item to a new
+/// Temporal.PlainDateTime instance if possible, and throws otherwise.
+pub(crate) fn to_temporal_date_time<'gc>(
+    agent: &mut Agent,
+    item: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, temporal_rs::PlainDateTime> {
+    let item = item.bind(gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 1. If options is not present, set options to undefined.
+    // 2. If item is an Object, then
+    let result = if let Ok(item) = Object::try_from(item) {
+        let plain_date_time = match item {
+            // a. If item has an [[InitializedTemporalDateTime]] internal slot, then
+            //     iii. Return ! CreateTemporalDateTime(item.[[ISODateTime]], item.[[Calendar]]).
+            Object::PlainDateTime(item) => Some(item.inner_plain_date_time(agent).clone()),
+            // b. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+            //     i. Let isoDateTime be GetISODateTimeFor(item.[[TimeZone]], item.[[EpochNanoseconds]]).
+            //     iv. Return ! CreateTemporalDateTime(isoDateTime, item.[[Calendar]]).
+            Object::ZonedDateTime(item) => {
+                Some(item.inner_zoned_date_time(agent).to_plain_date_time())
+            }
+            // c. If item has an [[InitializedTemporalDate]] internal slot, then
+            //     iii. Let isoDateTime be CombineISODateAndTimeRecord(item.[[ISODate]], MidnightTimeRecord()).
+            //     iv. Return ? CreateTemporalDateTime(isoDateTime, item.[[Calendar]]).
+            Object::PlainDate(item) => Some(
+                item.inner_plain_date(agent)
+                    .to_plain_date_time(None)
+                    .map_err(|err| temporal_err_to_js_err(agent, err, gc.nogc()))
+                    .unbind()?,
+            ),
+            _ => None,
+        };
+        if let Some(plain_date_time) = plain_date_time {
+            // i. Let resolvedOptions be ? GetOptionsObject(options).
+            // ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+            return Ok(plain_date_time);
+        }
+        let item = item.scope(agent, gc.nogc());
+        // d. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(item).
+        let calendar = get_temporal_calendar_identifier_with_iso_default(
+            agent,
+            item.get(agent),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // e. Let fields be ? PrepareCalendarFields(calendar, item, « year,
+        //    month, month-code, day », « hour, minute, second, millisecond,
+        //    microsecond, nanosecond », « »).
+        let fields = prepare_calendar_fields(
+            agent,
+            &calendar,
+            item.get(agent),
+            CalendarFieldNames::DATE_TIME,
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // f. Let resolvedOptions be ? GetOptionsObject(options).
+        // g. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow =
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+        // h. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, overflow).
+        let gc = gc.nogc();
+        let calendar_fields = fields.to_calendar_fields(agent, overflow, gc).unbind()?;
+        let time = fields.to_partial_time(agent, overflow, gc).unbind()?;
+        temporal_rs::PlainDateTime::from_partial(
+            PartialDateTime {
+                fields: DateTimeFields {
+                    calendar_fields,
+                    time,
+                },
+                calendar,
+            },
+            Some(overflow),
+        )
+    } else {
+        // 3. Else,
+        //     a. If item is not a String, throw a TypeError exception.
+        let Ok(item) = String::try_from(item) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Item is not a String",
+                gc.into_nogc(),
+            ));
+        };
+        //     b. Let result be ? ParseISODateTime(item, « TemporalDateTimeString[~Zoned] »).
+        //     c. If result.[[Time]] is start-of-day, let time be MidnightTimeRecord(); else let time be result.[[Time]].
+        //     d. Let calendar be result.[[Calendar]].
+        //     e. If calendar is empty, set calendar to "iso8601".
+        //     f. Set calendar to ? CanonicalizeCalendar(calendar).
+        let result = temporal_rs::PlainDateTime::from_utf8(item.as_bytes(agent));
+        //     g. Let resolvedOptions be ? GetOptionsObject(options).
+        //     h. Perform ? GetTemporalOverflowOption(resolvedOptions).
+        if result.is_ok() {
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+        }
+        //     i. Let isoDate be CreateISODateRecord(result.[[Year]], result.[[Month]], result.[[Day]]).
+        //     j. Set result to CombineISODateAndTimeRecord(isoDate, time).
+        // 4. Return ? CreateTemporalDateTime(result, calendar).
+        result
+    };
+    result.map_err(|err| temporal_err_to_js_err(agent, err, gc.into_nogc()))
+}
+
+/// ### [5.5.17 AddDurationToDateTime ( operation, dateTime, temporalDurationLike, options )](https://tc39.es/proposal-temporal/#sec-temporal-adddurationtodatetime)
+///
+/// The abstract operation AddDurationToDateTime takes arguments operation (add
+/// or subtract), dateTime (a Temporal.PlainDateTime), temporalDurationLike (an
+/// ECMAScript language value), and options (an ECMAScript language value) and
+/// returns either a normal completion containing a Temporal.PlainDateTime or a
+/// throw completion. It adds/subtracts temporalDurationLike to/from dateTime.
+fn add_duration_to_date_time<'gc, const IS_ADD: bool>(
+    agent: &mut Agent,
+    plain_date_time: TemporalPlainDateTime,
+    duration: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, TemporalPlainDateTime<'gc>> {
+    let plain_date_time = plain_date_time.scope(agent, gc.nogc());
+    let duration = duration.bind(gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 1. Let duration be ? ToTemporalDuration(temporalDurationLike).
+    let duration = to_temporal_duration(agent, duration.unbind(), gc.reborrow()).unbind()?;
+    // 2. If operation is subtract, set duration to CreateNegatedTemporalDuration(duration).
+    // 3. Let resolvedOptions be ? GetOptionsObject(options).
+    // 4. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+    let overflow =
+        resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+    // 5. Let internalDuration be ToInternalDurationRecordWith24HourDays(duration).
+    // 6. Let timeResult be AddTime(dateTime.[[ISODateTime]].[[Time]], internalDuration.[[Time]]).
+    // 7. Let dateDuration be ? AdjustDateDurationRecord(internalDuration.[[Date]], timeResult.[[Days]]).
+    // 8. Let addedDate be ? CalendarDateAdd(dateTime.[[Calendar]], dateTime.[[ISODateTime]].[[ISODate]], dateDuration, overflow).
+    // 9. Let result be CombineISODateAndTimeRecord(addedDate, timeResult).
+    let plain_date_time = plain_date_time.get(agent).inner_plain_date_time(agent);
+    let result = if IS_ADD {
+        plain_date_time.add(&duration, Some(overflow))
+    } else {
+        plain_date_time.subtract(&duration, Some(overflow))
+    };
+    let gc = gc.into_nogc();
+    let plain_date_time = result.map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+    // 10. Return ? CreateTemporalDateTime(result, dateTime.[[Calendar]]).
+    Ok(agent.heap.create(PlainDateTimeRecord {
+        object_index: None,
+        plain_date_time,
+    }))
+}
+
+/// ### [5.5.16 DifferenceTemporalPlainDateTime ( operation, dateTime, other, options )](https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplaindatetime)
+///
+/// The abstract operation DifferenceTemporalPlainDateTime takes arguments
+/// operation (since or until), dateTime (a Temporal.PlainDateTime), other (an
+/// ECMAScript language value), and options (an ECMAScript language value) and
+/// returns either a normal completion containing a Temporal.Duration or a
+/// throw completion. It computes the difference between the two times
+/// represented by dateTime and other, optionally rounds it, and returns it as
+/// a Temporal.Duration object.
+fn difference_temporal_plain_date_time<'gc, const IS_UNTIL: bool>(
+    agent: &mut Agent,
+    plain_date_time: TemporalPlainDateTime,
+    other: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, TemporalDuration<'gc>> {
+    let plain_date_time = plain_date_time.scope(agent, gc.nogc());
+    let other = other.bind(gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 1. Set other to ? ToTemporalDateTime(other).
+    let other =
+        to_temporal_date_time(agent, other.unbind(), Value::Undefined, gc.reborrow()).unbind()?;
+    // 2. If CalendarEquals(dateTime.[[Calendar]], other.[[Calendar]]) is
+    //    false, throw a RangeError exception.
+    // 3. Let resolvedOptions be ? GetOptionsObject(options).
+    let resolved_options = get_options_object(agent, options.get(agent), gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    // 4. Let settings be ? GetDifferenceSettings(operation, resolvedOptions,
+    //    datetime, « », nanosecond, day).
+    let settings = get_difference_settings::<IS_UNTIL>(
+        agent,
+        resolved_options.unbind(),
+        UnitGroup::DateTime,
+        &[],
+        Unit::Nanosecond,
+        Unit::Day,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 5. If CompareISODateTime(dateTime.[[ISODateTime]], other.[[ISODateTime]]) = 0, then
+    //     a. Return ! CreateTemporalDuration(0, 0, 0, 0, 0, 0, 0, 0, 0, 0).
+    // 6. Let internalDuration be ? DifferencePlainDateTimeWithRounding(...).
+    // 7. Let result be ? TemporalDurationFromInternal(internalDuration, settings.[[LargestUnit]]).
+    // 8. If operation is since, set result to CreateNegatedTemporalDuration(result).
+    let plain_date_time = plain_date_time.get(agent).inner_plain_date_time(agent);
+    let duration = if IS_UNTIL {
+        plain_date_time.until(&other, settings)
+    } else {
+        plain_date_time.since(&other, settings)
+    };
+    let gc = gc.into_nogc();
+    let duration = duration.map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+    // 9. Return result.
+    Ok(agent.heap.create(DurationRecord {
+        object_index: None,
+        duration,
+    }))
+}
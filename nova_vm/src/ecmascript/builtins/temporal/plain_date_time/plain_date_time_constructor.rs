@@ -0,0 +1,185 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, ExceptionType, Function, JsResult, Object,
+        PlainDateTimeRecord, Realm, String, Value, builders::BuiltinFunctionBuilder,
+        create_temporal_plain_date_time, temporal_err_to_js_err, to_constructor_calendar,
+        to_integer_with_truncation, to_temporal_date_time,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{CreateHeapData, IntrinsicConstructorIndexes},
+};
+
+/// Constructor function object for %Temporal.PlainDateTime%.
+pub(crate) struct TemporalPlainDateTimeConstructor;
+
+impl Builtin for TemporalPlainDateTimeConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.PlainDateTime;
+    const LENGTH: u8 = 3;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Constructor(TemporalPlainDateTimeConstructor::constructor);
+}
+impl BuiltinIntrinsicConstructor for TemporalPlainDateTimeConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::TemporalPlainDateTime;
+}
+
+struct TemporalPlainDateTimeFrom;
+impl Builtin for TemporalPlainDateTimeFrom {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.from;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimeConstructor::from);
+}
+
+struct TemporalPlainDateTimeCompare;
+impl Builtin for TemporalPlainDateTimeCompare {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.compare;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimeConstructor::compare);
+}
+
+impl TemporalPlainDateTimeConstructor {
+    /// ### [5.1.1 Temporal.PlainDateTime ( isoYear, isoMonth, isoDay [ , hour [ , minute [ , second [ , millisecond [ , microsecond [ , nanosecond [ , calendar ] ] ] ] ] ] ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let fields = [
+            args.get(0).scope(agent, gc.nogc()),
+            args.get(1).scope(agent, gc.nogc()),
+            args.get(2).scope(agent, gc.nogc()),
+            args.get(3).scope(agent, gc.nogc()),
+            args.get(4).scope(agent, gc.nogc()),
+            args.get(5).scope(agent, gc.nogc()),
+            args.get(6).scope(agent, gc.nogc()),
+            args.get(7).scope(agent, gc.nogc()),
+            args.get(8).scope(agent, gc.nogc()),
+        ];
+        let calendar = args.get(9).scope(agent, gc.nogc());
+        let new_target = new_target.bind(gc.nogc());
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "calling a builtin Temporal.PlainDateTime constructor without new is forbidden",
+                gc.into_nogc(),
+            ));
+        };
+        let Ok(new_target) = Function::try_from(new_target) else {
+            unreachable!()
+        };
+        let new_target = new_target.scope(agent, gc.nogc());
+        // 2. Set isoYear to ? ToIntegerWithTruncation(isoYear).
+        // 3. Set isoMonth to ? ToIntegerWithTruncation(isoMonth).
+        // 4. Set isoDay to ? ToIntegerWithTruncation(isoDay).
+        // 5. If hour is undefined, set hour to 0; else set hour to ? ToIntegerWithTruncation(hour).
+        // 6. If minute is undefined, set minute to 0; else set minute to ? ToIntegerWithTruncation(minute).
+        // 7. If second is undefined, set second to 0; else set second to ? ToIntegerWithTruncation(second).
+        // 8. If millisecond is undefined, set millisecond to 0; else set millisecond to ? ToIntegerWithTruncation(millisecond).
+        // 9. If microsecond is undefined, set microsecond to 0; else set microsecond to ? ToIntegerWithTruncation(microsecond).
+        // 10. If nanosecond is undefined, set nanosecond to 0; else set nanosecond to ? ToIntegerWithTruncation(nanosecond).
+        let mut values = [0i64; 9];
+        for (i, (field, value)) in fields.iter().zip(values.iter_mut()).enumerate() {
+            let field = field.get(agent);
+            if i >= 3 && field.is_undefined() {
+                continue;
+            }
+            *value = to_integer_with_truncation(agent, field, gc.reborrow()).unbind()?;
+        }
+        let [
+            year,
+            month,
+            day,
+            hour,
+            minute,
+            second,
+            millisecond,
+            microsecond,
+            nanosecond,
+        ] = values;
+        // 11. If calendar is undefined, set calendar to "iso8601".
+        // 12. If calendar is not a String, throw a TypeError exception.
+        // 13. Set calendar to ? CanonicalizeCalendar(calendar).
+        let calendar = to_constructor_calendar(agent, calendar.get(agent), gc.nogc()).unbind()?;
+        // 14. If IsValidISODate(isoYear, isoMonth, isoDay) is false, throw a RangeError exception.
+        // 15. Let isoDate be CreateISODateRecord(isoYear, isoMonth, isoDay).
+        // 16. If IsValidTime(hour, minute, second, millisecond, microsecond, nanosecond) is false, throw a RangeError exception.
+        // 17. Let time be CreateTimeRecord(hour, minute, second, millisecond, microsecond, nanosecond).
+        // 18. Let isoDateTime be CombineISODateAndTimeRecord(isoDate, time).
+        let plain_date_time = temporal_rs::PlainDateTime::try_new(
+            year.clamp(i32::MIN.into(), i32::MAX.into()) as i32,
+            u8::try_from(month).unwrap_or(u8::MAX),
+            u8::try_from(day).unwrap_or(u8::MAX),
+            u8::try_from(hour).unwrap_or(u8::MAX),
+            u8::try_from(minute).unwrap_or(u8::MAX),
+            u8::try_from(second).unwrap_or(u8::MAX),
+            u16::try_from(millisecond).unwrap_or(u16::MAX),
+            u16::try_from(microsecond).unwrap_or(u16::MAX),
+            u16::try_from(nanosecond).unwrap_or(u16::MAX),
+            calendar,
+        )
+        .map_err(|err| temporal_err_to_js_err(agent, err, gc.nogc()))
+        .unbind()?;
+        // 19. Return ? CreateTemporalDateTime(isoDateTime, calendar, NewTarget).
+        create_temporal_plain_date_time(agent, plain_date_time, Some(new_target.get(agent)), gc)
+            .map(Value::from)
+    }
+
+    /// ### [5.2.2 Temporal.PlainDateTime.from ( item [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.from)
+    fn from<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let item = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Return ? ToTemporalDateTime(item, options).
+        let plain_date_time = to_temporal_date_time(agent, item.unbind(), options.unbind(), gc)?;
+        Ok(agent
+            .heap
+            .create(PlainDateTimeRecord {
+                object_index: None,
+                plain_date_time,
+            })
+            .into())
+    }
+
+    /// ### [5.2.3 Temporal.PlainDateTime.compare ( one, two )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.compare)
+    fn compare<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let one = args.get(0).bind(gc.nogc());
+        let two = args.get(1).scope(agent, gc.nogc());
+        // 1. Set one to ? ToTemporalDateTime(one).
+        let one =
+            to_temporal_date_time(agent, one.unbind(), Value::Undefined, gc.reborrow()).unbind()?;
+        // 2. Set two to ? ToTemporalDateTime(two).
+        let two = to_temporal_date_time(agent, two.get(agent), Value::Undefined, gc)?;
+        // 3. Return 𝔽(CompareISODateTime(one.[[ISODateTime]], two.[[ISODateTime]])).
+        Ok((one.compare_iso(&two) as i8).into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>, _gc: NoGcScope) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let plain_date_time_prototype = intrinsics.temporal_plain_date_time_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<TemporalPlainDateTimeConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(3)
+        .with_prototype_property(plain_date_time_prototype.into())
+        .with_builtin_function_property::<TemporalPlainDateTimeFrom>()
+        .with_builtin_function_property::<TemporalPlainDateTimeCompare>()
+        .build();
+    }
+}
@@ -0,0 +1,1443 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use temporal_rs::{
+    fields::DateTimeFields,
+    options::{
+        Disambiguation, DisplayCalendar, RoundingMode, RoundingOptions, ToStringRoundingOptions,
+        Unit,
+    },
+};
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
+        CalendarFieldNames, ExceptionType, JsResult, Object, PlainDateRecord, PlainDateTimeRecord,
+        PlainTimeRecord, PropertyKey, Realm, String, StringOptionType, Value, ZonedDateTimeRecord,
+        builders::OrdinaryObjectBuilder,
+        builtins::temporal::plain_date_time::{
+            add_duration_to_date_time, difference_temporal_plain_date_time,
+            require_internal_slot_temporal_plain_date_time,
+        },
+        get_options_object, get_rounding_increment_option, get_rounding_mode_option,
+        get_temporal_disambiguation_option, get_temporal_fractional_second_digits_option,
+        get_temporal_show_calendar_name_option, get_temporal_unit_valued_option,
+        is_partial_temporal_object, prepare_calendar_fields, resolve_temporal_overflow_option,
+        temporal_err_to_js_err, to_temporal_calendar_identifier, to_temporal_date_time,
+        to_temporal_time_zone_identifier, to_time_record_or_midnight,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{CreateHeapData, WellKnownSymbols},
+};
+
+pub(crate) struct TemporalPlainDateTimePrototype;
+
+struct TemporalPlainDateTimePrototypeGetCalendarId;
+impl Builtin for TemporalPlainDateTimePrototypeGetCalendarId {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_calendarId;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.calendarId.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainDateTimePrototype::get_calendar_id);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetCalendarId {}
+
+struct TemporalPlainDateTimePrototypeGetEra;
+impl Builtin for TemporalPlainDateTimePrototypeGetEra {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_era;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.era.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::get_era);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetEra {}
+
+struct TemporalPlainDateTimePrototypeGetEraYear;
+impl Builtin for TemporalPlainDateTimePrototypeGetEraYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_eraYear;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.eraYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::get_era_year);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetEraYear {}
+
+struct TemporalPlainDateTimePrototypeGetYear;
+impl Builtin for TemporalPlainDateTimePrototypeGetYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_year;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.year.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::get_year);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetYear {}
+
+struct TemporalPlainDateTimePrototypeGetMonth;
+impl Builtin for TemporalPlainDateTimePrototypeGetMonth {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_month;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.month.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::get_month);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetMonth {}
+
+struct TemporalPlainDateTimePrototypeGetMonthCode;
+impl Builtin for TemporalPlainDateTimePrototypeGetMonthCode {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_monthCode;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.monthCode.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::get_month_code);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetMonthCode {}
+
+struct TemporalPlainDateTimePrototypeGetDay;
+impl Builtin for TemporalPlainDateTimePrototypeGetDay {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_day;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.day.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::get_day);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetDay {}
+
+struct TemporalPlainDateTimePrototypeGetHour;
+impl Builtin for TemporalPlainDateTimePrototypeGetHour {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_hour;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.hour.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::get_hour);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetHour {}
+
+struct TemporalPlainDateTimePrototypeGetMinute;
+impl Builtin for TemporalPlainDateTimePrototypeGetMinute {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_minute;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.minute.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::get_minute);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetMinute {}
+
+struct TemporalPlainDateTimePrototypeGetSecond;
+impl Builtin for TemporalPlainDateTimePrototypeGetSecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_second;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.second.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::get_second);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetSecond {}
+
+struct TemporalPlainDateTimePrototypeGetMillisecond;
+impl Builtin for TemporalPlainDateTimePrototypeGetMillisecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_millisecond;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.millisecond.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainDateTimePrototype::get_millisecond);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetMillisecond {}
+
+struct TemporalPlainDateTimePrototypeGetMicrosecond;
+impl Builtin for TemporalPlainDateTimePrototypeGetMicrosecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_microsecond;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.microsecond.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainDateTimePrototype::get_microsecond);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetMicrosecond {}
+
+struct TemporalPlainDateTimePrototypeGetNanosecond;
+impl Builtin for TemporalPlainDateTimePrototypeGetNanosecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_nanosecond;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.nanosecond.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::get_nanosecond);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetNanosecond {}
+
+struct TemporalPlainDateTimePrototypeGetDayOfWeek;
+impl Builtin for TemporalPlainDateTimePrototypeGetDayOfWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_dayOfWeek;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.dayOfWeek.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainDateTimePrototype::get_day_of_week);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetDayOfWeek {}
+
+struct TemporalPlainDateTimePrototypeGetDayOfYear;
+impl Builtin for TemporalPlainDateTimePrototypeGetDayOfYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_dayOfYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.dayOfYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainDateTimePrototype::get_day_of_year);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetDayOfYear {}
+
+struct TemporalPlainDateTimePrototypeGetWeekOfYear;
+impl Builtin for TemporalPlainDateTimePrototypeGetWeekOfYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_weekOfYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.weekOfYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainDateTimePrototype::get_week_of_year);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetWeekOfYear {}
+
+struct TemporalPlainDateTimePrototypeGetYearOfWeek;
+impl Builtin for TemporalPlainDateTimePrototypeGetYearOfWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_yearOfWeek;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.yearOfWeek.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainDateTimePrototype::get_year_of_week);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetYearOfWeek {}
+
+struct TemporalPlainDateTimePrototypeGetDaysInWeek;
+impl Builtin for TemporalPlainDateTimePrototypeGetDaysInWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInWeek;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInWeek.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainDateTimePrototype::get_days_in_week);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetDaysInWeek {}
+
+struct TemporalPlainDateTimePrototypeGetDaysInMonth;
+impl Builtin for TemporalPlainDateTimePrototypeGetDaysInMonth {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInMonth;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInMonth.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainDateTimePrototype::get_days_in_month);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetDaysInMonth {}
+
+struct TemporalPlainDateTimePrototypeGetDaysInYear;
+impl Builtin for TemporalPlainDateTimePrototypeGetDaysInYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainDateTimePrototype::get_days_in_year);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetDaysInYear {}
+
+struct TemporalPlainDateTimePrototypeGetMonthsInYear;
+impl Builtin for TemporalPlainDateTimePrototypeGetMonthsInYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_monthsInYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.monthsInYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainDateTimePrototype::get_months_in_year);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetMonthsInYear {}
+
+struct TemporalPlainDateTimePrototypeGetInLeapYear;
+impl Builtin for TemporalPlainDateTimePrototypeGetInLeapYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_inLeapYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.inLeapYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainDateTimePrototype::get_in_leap_year);
+}
+impl BuiltinGetter for TemporalPlainDateTimePrototypeGetInLeapYear {}
+
+struct TemporalPlainDateTimePrototypeWith;
+impl Builtin for TemporalPlainDateTimePrototypeWith {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.with;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::with);
+}
+
+struct TemporalPlainDateTimePrototypeWithPlainTime;
+impl Builtin for TemporalPlainDateTimePrototypeWithPlainTime {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.withPlainTime;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainDateTimePrototype::with_plain_time);
+}
+
+struct TemporalPlainDateTimePrototypeWithCalendar;
+impl Builtin for TemporalPlainDateTimePrototypeWithCalendar {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.withCalendar;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::with_calendar);
+}
+
+struct TemporalPlainDateTimePrototypeAdd;
+impl Builtin for TemporalPlainDateTimePrototypeAdd {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.add;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::add);
+}
+
+struct TemporalPlainDateTimePrototypeSubtract;
+impl Builtin for TemporalPlainDateTimePrototypeSubtract {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.subtract;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::subtract);
+}
+
+struct TemporalPlainDateTimePrototypeUntil;
+impl Builtin for TemporalPlainDateTimePrototypeUntil {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.until;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::until);
+}
+
+struct TemporalPlainDateTimePrototypeSince;
+impl Builtin for TemporalPlainDateTimePrototypeSince {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.since;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::since);
+}
+
+struct TemporalPlainDateTimePrototypeRound;
+impl Builtin for TemporalPlainDateTimePrototypeRound {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.round;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::round);
+}
+
+struct TemporalPlainDateTimePrototypeEquals;
+impl Builtin for TemporalPlainDateTimePrototypeEquals {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.equals;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::equals);
+}
+
+struct TemporalPlainDateTimePrototypeToString;
+impl Builtin for TemporalPlainDateTimePrototypeToString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toString;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::to_string);
+}
+
+struct TemporalPlainDateTimePrototypeToLocaleString;
+impl Builtin for TemporalPlainDateTimePrototypeToLocaleString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toLocaleString;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainDateTimePrototype::to_locale_string);
+}
+
+struct TemporalPlainDateTimePrototypeToJSON;
+impl Builtin for TemporalPlainDateTimePrototypeToJSON {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toJSON;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::to_json);
+}
+
+struct TemporalPlainDateTimePrototypeValueOf;
+impl Builtin for TemporalPlainDateTimePrototypeValueOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.valueOf;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::value_of);
+}
+
+struct TemporalPlainDateTimePrototypeToZonedDateTime;
+impl Builtin for TemporalPlainDateTimePrototypeToZonedDateTime {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toZonedDateTime;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainDateTimePrototype::to_zoned_date_time);
+}
+
+struct TemporalPlainDateTimePrototypeToPlainDate;
+impl Builtin for TemporalPlainDateTimePrototypeToPlainDate {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toPlainDate;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::to_plain_date);
+}
+
+struct TemporalPlainDateTimePrototypeToPlainTime;
+impl Builtin for TemporalPlainDateTimePrototypeToPlainTime {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toPlainTime;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainDateTimePrototype::to_plain_time);
+}
+
+impl TemporalPlainDateTimePrototype {
+    /// ### [5.3.3 get Temporal.PlainDateTime.prototype.calendarId](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.calendarid)
+    fn get_calendar_id<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return plainDateTime.[[Calendar]].
+        let value = plain_date_time
+            .inner_plain_date_time(agent)
+            .calendar()
+            .identifier();
+        Ok(Value::from_static_str(agent, value, gc))
+    }
+
+    /// ### [5.3.4 get Temporal.PlainDateTime.prototype.era](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.era)
+    fn get_era<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return CalendarISOToDate(plainDateTime.[[Calendar]], plainDateTime.[[ISODateTime]].[[ISODate]]).[[Era]].
+        let value = plain_date_time.inner_plain_date_time(agent).era();
+        Ok(value.map_or(Value::Undefined, |era| {
+            Value::from_str(agent, era.as_str(), gc)
+        }))
+    }
+
+    /// ### [5.3.5 get Temporal.PlainDateTime.prototype.eraYear](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.erayear)
+    fn get_era_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDateTime.[[Calendar]], plainDateTime.[[ISODateTime]].[[ISODate]]).[[EraYear]]).
+        let value = plain_date_time.inner_plain_date_time(agent).era_year();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.6 get Temporal.PlainDateTime.prototype.year](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.year)
+    fn get_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDateTime.[[Calendar]], plainDateTime.[[ISODateTime]].[[ISODate]]).[[Year]]).
+        let value = plain_date_time.inner_plain_date_time(agent).year();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.7 get Temporal.PlainDateTime.prototype.month](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.month)
+    fn get_month<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDateTime.[[Calendar]], plainDateTime.[[ISODateTime]].[[ISODate]]).[[Month]]).
+        let value = plain_date_time.inner_plain_date_time(agent).month();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.8 get Temporal.PlainDateTime.prototype.monthCode](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.monthcode)
+    fn get_month_code<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return CalendarISOToDate(plainDateTime.[[Calendar]], plainDateTime.[[ISODateTime]].[[ISODate]]).[[MonthCode]].
+        let value = plain_date_time.inner_plain_date_time(agent).month_code();
+        Ok(Value::from_str(agent, value.as_str(), gc))
+    }
+
+    /// ### [5.3.9 get Temporal.PlainDateTime.prototype.day](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.day)
+    fn get_day<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDateTime.[[Calendar]], plainDateTime.[[ISODateTime]].[[ISODate]]).[[Day]]).
+        let value = plain_date_time.inner_plain_date_time(agent).day();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.10 get Temporal.PlainDateTime.prototype.hour](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.hour)
+    fn get_hour<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(plainDateTime.[[ISODateTime]].[[Time]].[[Hour]]).
+        let value = plain_date_time.inner_plain_date_time(agent).hour();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.11 get Temporal.PlainDateTime.prototype.minute](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.minute)
+    fn get_minute<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(plainDateTime.[[ISODateTime]].[[Time]].[[Minute]]).
+        let value = plain_date_time.inner_plain_date_time(agent).minute();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.12 get Temporal.PlainDateTime.prototype.second](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.second)
+    fn get_second<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(plainDateTime.[[ISODateTime]].[[Time]].[[Second]]).
+        let value = plain_date_time.inner_plain_date_time(agent).second();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.13 get Temporal.PlainDateTime.prototype.millisecond](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.millisecond)
+    fn get_millisecond<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(plainDateTime.[[ISODateTime]].[[Time]].[[Millisecond]]).
+        let value = plain_date_time.inner_plain_date_time(agent).millisecond();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.14 get Temporal.PlainDateTime.prototype.microsecond](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.microsecond)
+    fn get_microsecond<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(plainDateTime.[[ISODateTime]].[[Time]].[[Microsecond]]).
+        let value = plain_date_time.inner_plain_date_time(agent).microsecond();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.15 get Temporal.PlainDateTime.prototype.nanosecond](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.nanosecond)
+    fn get_nanosecond<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(plainDateTime.[[ISODateTime]].[[Time]].[[Nanosecond]]).
+        let value = plain_date_time.inner_plain_date_time(agent).nanosecond();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.16 get Temporal.PlainDateTime.prototype.dayOfWeek](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.dayofweek)
+    fn get_day_of_week<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDateTime.[[Calendar]], plainDateTime.[[ISODateTime]].[[ISODate]]).[[DayOfWeek]]).
+        let value = plain_date_time.inner_plain_date_time(agent).day_of_week();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.17 get Temporal.PlainDateTime.prototype.dayOfYear](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.dayofyear)
+    fn get_day_of_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDateTime.[[Calendar]], plainDateTime.[[ISODateTime]].[[ISODate]]).[[DayOfYear]]).
+        let value = plain_date_time.inner_plain_date_time(agent).day_of_year();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.18 get Temporal.PlainDateTime.prototype.weekOfYear](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.weekofyear)
+    fn get_week_of_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDateTime.[[Calendar]], plainDateTime.[[ISODateTime]].[[ISODate]]).[[WeekOfYear].[[Week]]).
+        let value = plain_date_time.inner_plain_date_time(agent).week_of_year();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.19 get Temporal.PlainDateTime.prototype.yearOfWeek](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.yearofweek)
+    fn get_year_of_week<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDateTime.[[Calendar]], plainDateTime.[[ISODateTime]].[[ISODate]]).[[WeekOfYear].[[Year]]).
+        let value = plain_date_time.inner_plain_date_time(agent).year_of_week();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.20 get Temporal.PlainDateTime.prototype.daysInWeek](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.daysinweek)
+    fn get_days_in_week<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDateTime.[[Calendar]], plainDateTime.[[ISODateTime]].[[ISODate]]).[[DaysInWeek]]).
+        let value = plain_date_time.inner_plain_date_time(agent).days_in_week();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.21 get Temporal.PlainDateTime.prototype.daysInMonth](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.daysinmonth)
+    fn get_days_in_month<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDateTime.[[Calendar]], plainDateTime.[[ISODateTime]].[[ISODate]]).[[DaysInMonth]]).
+        let value = plain_date_time.inner_plain_date_time(agent).days_in_month();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.22 get Temporal.PlainDateTime.prototype.daysInYear](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.daysinyear)
+    fn get_days_in_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDateTime.[[Calendar]], plainDateTime.[[ISODateTime]].[[ISODate]]).[[DaysInYear]]).
+        let value = plain_date_time.inner_plain_date_time(agent).days_in_year();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.23 get Temporal.PlainDateTime.prototype.monthsInYear](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.monthsinyear)
+    fn get_months_in_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDateTime.[[Calendar]], plainDateTime.[[ISODateTime]].[[ISODate]]).[[MonthsInYear]]).
+        let value = plain_date_time
+            .inner_plain_date_time(agent)
+            .months_in_year();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.24 get Temporal.PlainDateTime.prototype.inLeapYear](https://tc39.es/proposal-temporal/#sec-get-temporal.plaindatetime.prototype.inleapyear)
+    fn get_in_leap_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(plainDateTime.[[Calendar]], plainDateTime.[[ISODateTime]].[[ISODate]]).[[InLeapYear]]).
+        let value = plain_date_time.inner_plain_date_time(agent).in_leap_year();
+        Ok(value.into())
+    }
+
+    /// ### [5.3.25 Temporal.PlainDateTime.prototype.with ( temporalDateTimeLike [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.with)
+    fn with<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let temporal_date_time_like = args.get(0).scope(agent, gc.nogc());
+        let options = args.get(1).scope(agent, gc.nogc());
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. If ? IsPartialTemporalObject(temporalDateTimeLike) is false, throw
+        //    a TypeError exception.
+        if !is_partial_temporal_object(agent, temporal_date_time_like.get(agent), gc.reborrow())
+            .unbind()?
+        {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Argument is not a partial Temporal.PlainDateTime-like object",
+                gc.into_nogc(),
+            ));
+        }
+        let temporal_date_time_like = Object::try_from(temporal_date_time_like.get(agent)).unwrap();
+        // 4. Let calendar be plainDateTime.[[Calendar]].
+        let calendar = plain_date_time
+            .get(agent)
+            .inner_plain_date_time(agent)
+            .calendar()
+            .clone();
+        // 5. Let fields be ISODateToFields(calendar, plainDateTime.[[ISODateTime]].[[ISODate]], date).
+        // 6. Set fields.[[Hour]] to plainDateTime.[[ISODateTime]].[[Time]].[[Hour]].
+        // 7-11. Set the remaining time fields.
+        // 12. Let partialDateTime be ? PrepareCalendarFields(calendar,
+        //     temporalDateTimeLike, « year, month, month-code, day », « hour,
+        //     minute, second, millisecond, microsecond, nanosecond », partial).
+        let partial_date_time = prepare_calendar_fields(
+            agent,
+            &calendar,
+            temporal_date_time_like,
+            CalendarFieldNames::DATE_TIME,
+            true,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 13. Set fields to CalendarMergeFields(calendar, fields, partialDateTime).
+        // 14. Let resolvedOptions be ? GetOptionsObject(options).
+        // 15. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow =
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let calendar_fields = partial_date_time.to_calendar_fields(agent, overflow, gc)?;
+        let time = partial_date_time.to_partial_time(agent, overflow, gc)?;
+        // 16. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, overflow).
+        let plain_date_time = plain_date_time
+            .get(agent)
+            .inner_plain_date_time(agent)
+            .with(
+                DateTimeFields {
+                    calendar_fields,
+                    time,
+                },
+                Some(overflow),
+            )
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        // 17. Return ? CreateTemporalDateTime(result, calendar).
+        Ok(agent
+            .heap
+            .create(PlainDateTimeRecord {
+                object_index: None,
+                plain_date_time,
+            })
+            .into())
+    }
+
+    /// ### [5.3.26 Temporal.PlainDateTime.prototype.withPlainTime ( [ plainTimeLike ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.withplaintime)
+    fn with_plain_time<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let plain_time_like = args.get(0).bind(gc.nogc());
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. Let time be ? ToTimeRecordOrMidnight(plainTimeLike).
+        let time =
+            to_time_record_or_midnight(agent, plain_time_like.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        // 4. Let isoDateTime be CombineISODateAndTimeRecord(plainDateTime.[[ISODateTime]].[[ISODate]], time).
+        // 5. Return ? CreateTemporalDateTime(isoDateTime, plainDateTime.[[Calendar]]).
+        let plain_date_time = plain_date_time
+            .get(agent)
+            .inner_plain_date_time(agent)
+            .with_time(time)
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        Ok(agent
+            .heap
+            .create(PlainDateTimeRecord {
+                object_index: None,
+                plain_date_time,
+            })
+            .into())
+    }
+
+    /// ### [5.3.27 Temporal.PlainDateTime.prototype.withCalendar ( calendarLike )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.withcalendar)
+    fn with_calendar<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let calendar_like = args.get(0).bind(gc);
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Let calendar be ? ToTemporalCalendarIdentifier(calendarLike).
+        let calendar = to_temporal_calendar_identifier(agent, calendar_like, gc)?;
+        // 4. Return ! CreateTemporalDateTime(plainDateTime.[[ISODateTime]], calendar).
+        let plain_date_time = plain_date_time
+            .inner_plain_date_time(agent)
+            .with_calendar(calendar);
+        Ok(agent
+            .heap
+            .create(PlainDateTimeRecord {
+                object_index: None,
+                plain_date_time,
+            })
+            .into())
+    }
+
+    /// ### [5.3.28 Temporal.PlainDateTime.prototype.add ( temporalDurationLike [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.add)
+    fn add<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let duration = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .bind(gc.nogc());
+        // 3. Return ? AddDurationToDateTime(add, plainDateTime, temporalDurationLike, options).
+        const ADD: bool = true;
+        add_duration_to_date_time::<ADD>(
+            agent,
+            plain_date_time.unbind(),
+            duration.unbind(),
+            options.unbind(),
+            gc,
+        )
+        .map(Value::from)
+    }
+
+    /// ### [5.3.29 Temporal.PlainDateTime.prototype.subtract ( temporalDurationLike [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.subtract)
+    fn subtract<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let duration = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .bind(gc.nogc());
+        // 3. Return ? AddDurationToDateTime(subtract, plainDateTime, temporalDurationLike, options).
+        const SUBTRACT: bool = false;
+        add_duration_to_date_time::<SUBTRACT>(
+            agent,
+            plain_date_time.unbind(),
+            duration.unbind(),
+            options.unbind(),
+            gc,
+        )
+        .map(Value::from)
+    }
+
+    /// ### [5.3.30 Temporal.PlainDateTime.prototype.until ( other [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.until)
+    fn until<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let other = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalPlainDateTime(until, plainDateTime, other, options).
+        const UNTIL: bool = true;
+        difference_temporal_plain_date_time::<UNTIL>(
+            agent,
+            plain_date_time.unbind(),
+            other.unbind(),
+            options.unbind(),
+            gc,
+        )
+        .map(Value::from)
+    }
+
+    /// ### [5.3.31 Temporal.PlainDateTime.prototype.since ( other [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.since)
+    fn since<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let other = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalPlainDateTime(since, plainDateTime, other, options).
+        const SINCE: bool = false;
+        difference_temporal_plain_date_time::<SINCE>(
+            agent,
+            plain_date_time.unbind(),
+            other.unbind(),
+            options.unbind(),
+            gc,
+        )
+        .map(Value::from)
+    }
+
+    /// ### [5.3.32 Temporal.PlainDateTime.prototype.round ( roundTo )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.round)
+    fn round<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let round_to = args.get(0).bind(gc.nogc());
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. If roundTo is undefined, then
+        if round_to.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "roundTo cannot be undefined",
+                gc.into_nogc(),
+            ));
+        }
+        // 4. If roundTo is a String, then
+        let options = if let Ok(round_to) = String::try_from(round_to) {
+            // a. Let paramString be roundTo.
+            // b. Set roundTo to OrdinaryObjectCreate(null).
+            // c. Perform ! CreateDataPropertyOrThrow(roundTo, "smallestUnit", paramString).
+            let mut options = RoundingOptions::default();
+            options.smallest_unit =
+                Some(Unit::from_string(agent, round_to.unbind(), gc.nogc()).unbind()?);
+            options
+        } else {
+            // 5. Else,
+            //     a. Set roundTo to ? GetOptionsObject(roundTo).
+            let round_to = get_options_object(agent, round_to, gc.nogc())
+                .unbind()?
+                .map(|r| r.scope(agent, gc.nogc()));
+            // 6. NOTE: The following steps read options and perform independent
+            //    validation in alphabetical order (GetRoundingIncrementOption
+            //    reads "roundingIncrement" and GetRoundingModeOption reads
+            //    "roundingMode").
+            let mut options = RoundingOptions::default();
+            if let Some(round_to) = round_to {
+                // 7. Let roundingIncrement be ? GetRoundingIncrementOption(roundTo).
+                options.increment = Some(
+                    get_rounding_increment_option(agent, round_to.get(agent), gc.reborrow())
+                        .unbind()?,
+                );
+                // 8. Let roundingMode be ? GetRoundingModeOption(roundTo, half-expand).
+                options.rounding_mode = Some(
+                    get_rounding_mode_option(
+                        agent,
+                        round_to.get(agent),
+                        RoundingMode::default(),
+                        gc.reborrow(),
+                    )
+                    .unbind()?,
+                );
+                // 9. Let smallestUnit be ? GetTemporalUnitValuedOption(roundTo, "smallestUnit", required).
+                options.smallest_unit = get_temporal_unit_valued_option(
+                    agent,
+                    round_to.get(agent),
+                    BUILTIN_STRING_MEMORY.smallestUnit.into(),
+                    gc.reborrow(),
+                )
+                .unbind()?;
+            }
+            options
+        };
+        let gc = gc.into_nogc();
+        // 10. Perform ? ValidateTemporalUnitValue(smallestUnit, time, « day »).
+        // 11. If smallestUnit is day, then
+        //     a. Let maximum be 1.
+        //     b. Let inclusive be true.
+        // 12. Else,
+        //     a. Let maximum be MaximumTemporalDurationRoundingIncrement(smallestUnit).
+        //     b. Assert: maximum is not unset.
+        //     c. Let inclusive be false.
+        // 13. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, inclusive).
+        // 14. If smallestUnit is nanosecond and roundingIncrement = 1, then
+        //     a. Return ! CreateTemporalDateTime(plainDateTime.[[ISODateTime]], plainDateTime.[[Calendar]]).
+        // 15. Let result be RoundISODateTime(plainDateTime.[[ISODateTime]], roundingIncrement, smallestUnit, roundingMode).
+        let plain_date_time = plain_date_time
+            .get(agent)
+            .inner_plain_date_time(agent)
+            .round(options)
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        // 16. Return ? CreateTemporalDateTime(result, plainDateTime.[[Calendar]]).
+        Ok(agent
+            .heap
+            .create(PlainDateTimeRecord {
+                object_index: None,
+                plain_date_time,
+            })
+            .into())
+    }
+
+    /// ### [5.3.33 Temporal.PlainDateTime.prototype.equals ( other )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.equals)
+    fn equals<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let other = args.get(0).bind(gc.nogc());
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. Set other to ? ToTemporalDateTime(other).
+        let other = to_temporal_date_time(agent, other.unbind(), Value::Undefined, gc.reborrow())
+            .unbind()?;
+        // 4. If CompareISODateTime(plainDateTime.[[ISODateTime]], other.[[ISODateTime]]) ≠ 0, return false.
+        // 5. Return CalendarEquals(plainDateTime.[[Calendar]], other.[[Calendar]]).
+        Ok((plain_date_time.get(agent).inner_plain_date_time(agent) == &other).into())
+    }
+
+    /// ### [5.3.34 Temporal.PlainDateTime.prototype.toString ( [ options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tostring)
+    fn to_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let options = args.get(0).bind(gc.nogc());
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let resolved_options = get_options_object(agent, options, gc.nogc())
+            .unbind()?
+            .map(|r| r.scope(agent, gc.nogc()));
+        let (show_calendar, digits, rounding_mode, smallest_unit) =
+            if let Some(resolved_options) = resolved_options {
+                // 4. NOTE: The following steps read options and perform
+                //    independent validation in alphabetical order
+                //    (GetTemporalShowCalendarNameOption reads "calendarName",
+                //    GetTemporalFractionalSecondDigitsOption reads
+                //    "fractionalSecondDigits", and GetRoundingModeOption reads
+                //    "roundingMode").
+                // 5. Let showCalendar be ? GetTemporalShowCalendarNameOption(resolvedOptions).
+                let show_calendar = get_temporal_show_calendar_name_option(
+                    agent,
+                    resolved_options.get(agent),
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                // 6. Let digits be ? GetTemporalFractionalSecondDigitsOption(resolvedOptions).
+                let digits = get_temporal_fractional_second_digits_option(
+                    agent,
+                    resolved_options.get(agent),
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                // 7. Let roundingMode be ? GetRoundingModeOption(resolvedOptions, trunc).
+                let rounding_mode = get_rounding_mode_option(
+                    agent,
+                    resolved_options.get(agent),
+                    RoundingMode::Trunc,
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                // 8. Let smallestUnit be ? GetTemporalUnitValuedOption(resolvedOptions, "smallestUnit", unset).
+                let smallest_unit = get_temporal_unit_valued_option(
+                    agent,
+                    resolved_options.get(agent),
+                    BUILTIN_STRING_MEMORY.smallestUnit.to_property_key(),
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                (show_calendar, digits, rounding_mode, smallest_unit)
+            } else {
+                (
+                    DisplayCalendar::default(),
+                    Default::default(),
+                    RoundingMode::Trunc,
+                    None,
+                )
+            };
+        let gc = gc.into_nogc();
+        // 9. Perform ? ValidateTemporalUnitValue(smallestUnit, time).
+        if !smallest_unit.is_none_or(|su| su.is_time_unit()) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "smallestUnit is not a valid time unit",
+                gc,
+            ));
+        }
+        // 10. If smallestUnit is hour, throw a RangeError exception.
+        if smallest_unit == Some(Unit::Hour) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "smallestUnit is hour",
+                gc,
+            ));
+        }
+        // 11. Let precision be ToSecondsStringPrecisionRecord(smallestUnit, digits).
+        // 12. Let result be RoundISODateTime(plainDateTime.[[ISODateTime]], precision.[[Increment]], precision.[[Unit]], roundingMode).
+        // 13. If ISODateTimeWithinLimits(result) is false, throw a RangeError exception.
+        // 14. Return ISODateTimeToString(result, plainDateTime.[[Calendar]], precision.[[Precision]], showCalendar).
+        let options = ToStringRoundingOptions {
+            precision: digits,
+            smallest_unit,
+            rounding_mode: Some(rounding_mode),
+        };
+        match plain_date_time
+            .get(agent)
+            .inner_plain_date_time(agent)
+            .to_ixdtf_string(options, show_calendar)
+        {
+            Ok(string) => Ok(Value::from_string(agent, string, gc)),
+            Err(err) => Err(temporal_err_to_js_err(agent, err, gc)),
+        }
+    }
+
+    /// ### [5.3.35 Temporal.PlainDateTime.prototype.toLocaleString ( [ locales [ , options ] ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tolocalestring)
+    ///
+    /// An ECMAScript implementation that includes the ECMA-402
+    /// Internationalization API must implement this method as specified in the
+    /// ECMA-402 specification. If an ECMAScript implementation does not
+    /// include the ECMA-402 API the following specification of this method is
+    /// used.
+    fn to_locale_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return ISODateTimeToString(plainDateTime.[[ISODateTime]], plainDateTime.[[Calendar]], auto, auto).
+        match plain_date_time
+            .inner_plain_date_time(agent)
+            .to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto)
+        {
+            Ok(string) => Ok(Value::from_string(agent, string, gc)),
+            Err(err) => Err(temporal_err_to_js_err(agent, err, gc)),
+        }
+    }
+
+    /// ### [5.3.36 Temporal.PlainDateTime.prototype.toJSON ( )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tojson)
+    fn to_json<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return ISODateTimeToString(plainDateTime.[[ISODateTime]], plainDateTime.[[Calendar]], auto, auto).
+        match plain_date_time
+            .inner_plain_date_time(agent)
+            .to_ixdtf_string(ToStringRoundingOptions::default(), DisplayCalendar::Auto)
+        {
+            Ok(string) => Ok(Value::from_string(agent, string, gc)),
+            Err(err) => Err(temporal_err_to_js_err(agent, err, gc)),
+        }
+    }
+
+    /// ### [5.3.37 Temporal.PlainDateTime.prototype.valueOf ( )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.valueof)
+    fn value_of<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Throw a TypeError exception.
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "`valueOf` not supported by Temporal built-ins. See 'compare', 'equals', or `toString`",
+            gc.into_nogc(),
+        ))
+    }
+
+    /// ### [5.3.38 Temporal.PlainDateTime.prototype.toZonedDateTime ( temporalTimeZoneLike [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.tozoneddatetime)
+    fn to_zoned_date_time<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let temporal_time_zone_like = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. Let timeZone be ? ToTemporalTimeZoneIdentifier(temporalTimeZoneLike).
+        let time_zone =
+            to_temporal_time_zone_identifier(agent, temporal_time_zone_like, gc.nogc()).unbind()?;
+        // 4. Let resolvedOptions be ? GetOptionsObject(options).
+        let resolved_options = get_options_object(agent, options, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 5. Let disambiguation be ? GetTemporalDisambiguationOption(resolvedOptions).
+        let disambiguation = match resolved_options {
+            Some(resolved_options) => {
+                get_temporal_disambiguation_option(agent, resolved_options.unbind(), gc.reborrow())
+                    .unbind()?
+            }
+            None => Disambiguation::default(),
+        };
+        let gc = gc.into_nogc();
+        // 6. Let epochNs be ? GetEpochNanosecondsFor(timeZone, plainDateTime.[[ISODateTime]], disambiguation).
+        let zoned_date_time = plain_date_time
+            .get(agent)
+            .inner_plain_date_time(agent)
+            .to_zoned_date_time(time_zone, disambiguation)
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        // 7. Return ! CreateTemporalZonedDateTime(epochNs, timeZone, plainDateTime.[[Calendar]]).
+        Ok(agent
+            .heap
+            .create(ZonedDateTimeRecord {
+                object_index: None,
+                zoned_date_time,
+            })
+            .into())
+    }
+
+    /// ### [5.3.39 Temporal.PlainDateTime.prototype.toPlainDate ( )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.toplaindate)
+    fn to_plain_date<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return ! CreateTemporalDate(plainDateTime.[[ISODateTime]].[[ISODate]], plainDateTime.[[Calendar]]).
+        let plain_date = plain_date_time.inner_plain_date_time(agent).to_plain_date();
+        Ok(agent
+            .heap
+            .create(PlainDateRecord {
+                object_index: None,
+                plain_date,
+            })
+            .into())
+    }
+
+    /// ### [5.3.40 Temporal.PlainDateTime.prototype.toPlainTime ( )](https://tc39.es/proposal-temporal/#sec-temporal.plaindatetime.prototype.toplaintime)
+    fn to_plain_time<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let plainDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(plainDateTime, [[InitializedTemporalDateTime]]).
+        let plain_date_time =
+            require_internal_slot_temporal_plain_date_time(agent, this_value, gc)?;
+        // 3. Return ! CreateTemporalTime(plainDateTime.[[ISODateTime]].[[Time]]).
+        let plain_time = plain_date_time.inner_plain_date_time(agent).to_plain_time();
+        Ok(agent
+            .heap
+            .create(PlainTimeRecord {
+                object_index: None,
+                plain_time,
+            })
+            .into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>, _: NoGcScope) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let this = intrinsics.temporal_plain_date_time_prototype();
+        let object_prototype = intrinsics.object_prototype();
+        let plain_date_time_constructor = intrinsics.temporal_plain_date_time();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(40)
+            .with_prototype(object_prototype)
+            .with_constructor_property(plain_date_time_constructor)
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetCalendarId>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetEra>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetEraYear>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetYear>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetMonth>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetMonthCode>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetDay>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetHour>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetMinute>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetSecond>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetMillisecond>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetMicrosecond>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetNanosecond>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetDayOfWeek>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetDayOfYear>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetWeekOfYear>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetYearOfWeek>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetDaysInWeek>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetDaysInMonth>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetDaysInYear>()
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetMonthsInYear>(
+            )
+            .with_builtin_function_getter_property::<TemporalPlainDateTimePrototypeGetInLeapYear>()
+            .with_builtin_function_property::<TemporalPlainDateTimePrototypeWith>()
+            .with_builtin_function_property::<TemporalPlainDateTimePrototypeWithPlainTime>()
+            .with_builtin_function_property::<TemporalPlainDateTimePrototypeWithCalendar>()
+            .with_builtin_function_property::<TemporalPlainDateTimePrototypeAdd>()
+            .with_builtin_function_property::<TemporalPlainDateTimePrototypeSubtract>()
+            .with_builtin_function_property::<TemporalPlainDateTimePrototypeUntil>()
+            .with_builtin_function_property::<TemporalPlainDateTimePrototypeSince>()
+            .with_builtin_function_property::<TemporalPlainDateTimePrototypeRound>()
+            .with_builtin_function_property::<TemporalPlainDateTimePrototypeEquals>()
+            .with_builtin_function_property::<TemporalPlainDateTimePrototypeToString>()
+            .with_builtin_function_property::<TemporalPlainDateTimePrototypeToLocaleString>()
+            .with_builtin_function_property::<TemporalPlainDateTimePrototypeToJSON>()
+            .with_builtin_function_property::<TemporalPlainDateTimePrototypeValueOf>()
+            .with_builtin_function_property::<TemporalPlainDateTimePrototypeToZonedDateTime>()
+            .with_builtin_function_property::<TemporalPlainDateTimePrototypeToPlainDate>()
+            .with_builtin_function_property::<TemporalPlainDateTimePrototypeToPlainTime>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Temporal_PlainDateTime.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
@@ -0,0 +1,234 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod data;
+mod plain_month_day_constructor;
+mod plain_month_day_prototype;
+
+pub(crate) use data::*;
+pub(crate) use plain_month_day_constructor::*;
+pub(crate) use plain_month_day_prototype::*;
+
+use temporal_rs::partial::PartialDate;
+
+use crate::{
+    ecmascript::{
+        Agent, CalendarFieldNames, ExceptionType, Function, InternalMethods, InternalSlots,
+        JsResult, Object, OrdinaryObject, ProtoIntrinsics, String, Value,
+        get_temporal_calendar_identifier_with_iso_default, object_handle,
+        ordinary_populate_from_constructor, prepare_calendar_fields,
+        resolve_temporal_overflow_option, temporal_err_to_js_err,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{
+        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
+        HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues, arena_vec_access,
+    },
+};
+
+/// # [10 Temporal.PlainMonthDay Objects](https://tc39.es/proposal-temporal/#sec-temporal-plainmonthday-objects)
+///
+/// A Temporal.PlainMonthDay object is an Object that contains integers
+/// corresponding to a particular month and day in the ISO 8601 calendar, as
+/// well as an Object indicating the calendar system used to interpret them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct TemporalPlainMonthDay<'a>(BaseIndex<'a, PlainMonthDayRecord<'static>>);
+object_handle!(TemporalPlainMonthDay, PlainMonthDay);
+arena_vec_access!(
+    TemporalPlainMonthDay,
+    'a,
+    PlainMonthDayRecord,
+    plain_month_days
+);
+
+impl TemporalPlainMonthDay<'_> {
+    pub(crate) fn inner_plain_month_day(self, agent: &Agent) -> &temporal_rs::PlainMonthDay {
+        &self.unbind().get(agent).plain_month_day
+    }
+}
+
+impl<'a> InternalSlots<'a> for TemporalPlainMonthDay<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::TemporalPlainMonthDay;
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        self.get(agent).object_index.unbind()
+    }
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(
+            self.get_mut(agent)
+                .object_index
+                .replace(backing_object)
+                .is_none()
+        );
+    }
+}
+
+impl<'a> InternalMethods<'a> for TemporalPlainMonthDay<'a> {}
+
+impl HeapMarkAndSweep for TemporalPlainMonthDay<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.plain_month_days.push(*self);
+    }
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.plain_month_days.shift_index(&mut self.0);
+    }
+}
+
+impl HeapSweepWeakReference for TemporalPlainMonthDay<'static> {
+    fn sweep_weak_reference(self, compactions: &CompactionLists) -> Option<Self> {
+        compactions
+            .plain_month_days
+            .shift_weak_index(self.0)
+            .map(Self)
+    }
+}
+
+impl<'a> CreateHeapData<PlainMonthDayRecord<'a>, TemporalPlainMonthDay<'a>> for Heap {
+    fn create(&mut self, data: PlainMonthDayRecord<'a>) -> TemporalPlainMonthDay<'a> {
+        self.plain_month_days.push(data.unbind());
+        self.alloc_counter += core::mem::size_of::<PlainMonthDayRecord<'static>>();
+        TemporalPlainMonthDay(BaseIndex::last(&self.plain_month_days))
+    }
+}
+
+#[inline(always)]
+fn require_internal_slot_temporal_plain_month_day<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, TemporalPlainMonthDay<'a>> {
+    match value {
+        Value::PlainMonthDay(plain_month_day) => Ok(plain_month_day.bind(gc)),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Object is not a Temporal PlainMonthDay",
+            gc,
+        )),
+    }
+}
+
+/// ### [10.5.3 CreateTemporalMonthDay ( isoDate, calendar [ , newTarget ] )](https://tc39.es/proposal-temporal/#sec-temporal-createtemporalmonthday)
+pub(crate) fn create_temporal_plain_month_day<'gc>(
+    agent: &mut Agent,
+    plain_month_day: temporal_rs::PlainMonthDay,
+    new_target: Option<Function>,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, TemporalPlainMonthDay<'gc>> {
+    // 1. If newTarget is not present, set newTarget to %Temporal.PlainMonthDay%.
+    let new_target = new_target.unwrap_or_else(|| {
+        agent
+            .current_realm_record()
+            .intrinsics()
+            .temporal_plain_month_day()
+            .into()
+    });
+    // 2. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.PlainMonthDay.prototype%", « [[InitializedTemporalMonthDay]], [[ISODate]], [[Calendar]] »).
+    // 3. Set the internal slots of object.
+    // 4. Return object.
+    let object = agent.heap.create(PlainMonthDayRecord {
+        object_index: None,
+        plain_month_day,
+    });
+    Ok(
+        TemporalPlainMonthDay::try_from(ordinary_populate_from_constructor(
+            agent,
+            object.unbind().into(),
+            new_target,
+            ProtoIntrinsics::TemporalPlainMonthDay,
+            gc,
+        )?)
+        .unwrap(),
+    )
+}
+
+/// ### [10.5.1 ToTemporalMonthDay ( item [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal-totemporalmonthday)
+///
+/// The abstract operation ToTemporalMonthDay takes argument item (an
+/// ECMAScript language value) and optional argument options (an ECMAScript
+/// language value) and returns either a normal completion containing a
+/// Temporal.PlainMonthDay, or a throw completion. Converts item to a new
+/// Temporal.PlainMonthDay instance if possible, and throws otherwise.
+pub(crate) fn to_temporal_month_day<'gc>(
+    agent: &mut Agent,
+    item: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, temporal_rs::PlainMonthDay> {
+    let item = item.bind(gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 1. If options is not present, set options to undefined.
+    // 2. If item is a Object, then
+    let result = if let Ok(item) = Object::try_from(item) {
+        // a. If item has an [[InitializedTemporalMonthDay]] internal slot, then
+        if let Object::PlainMonthDay(item) = item {
+            let plain_month_day = item.inner_plain_month_day(agent).clone();
+            // i. Let resolvedOptions be ? GetOptionsObject(options).
+            // ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+            // iii. Return ! CreateTemporalMonthDay(item.[[ISODate]], item.[[Calendar]]).
+            return Ok(plain_month_day);
+        }
+        let item = item.scope(agent, gc.nogc());
+        // b. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(item).
+        let calendar = get_temporal_calendar_identifier_with_iso_default(
+            agent,
+            item.get(agent),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // c. Let fields be ? PrepareCalendarFields(calendar, item, « year, month, month-code, day », « », « »).
+        let fields = prepare_calendar_fields(
+            agent,
+            &calendar,
+            item.get(agent),
+            CalendarFieldNames::DATE,
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // d. Let resolvedOptions be ? GetOptionsObject(options).
+        // e. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow =
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+        // f. Let isoDate be ? CalendarMonthDayFromFields(calendar, fields, overflow).
+        // g. Return ! CreateTemporalMonthDay(isoDate, calendar).
+        let calendar_fields = fields
+            .to_calendar_fields(agent, overflow, gc.nogc())
+            .unbind()?;
+        temporal_rs::PlainMonthDay::from_partial(
+            PartialDate {
+                calendar_fields,
+                calendar,
+            },
+            Some(overflow),
+        )
+    } else {
+        // 3. If item is not a String, throw a TypeError exception.
+        let Ok(item) = String::try_from(item) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Item is not a String",
+                gc.into_nogc(),
+            ));
+        };
+        // 4. Let result be ? ParseISODateTime(item, « TemporalMonthDayString »).
+        // 5. Let calendar be result.[[Calendar]].
+        // 6. If calendar is empty, set calendar to "iso8601".
+        // 7. Set calendar to ? CanonicalizeCalendar(calendar).
+        let result = temporal_rs::PlainMonthDay::from_utf8(item.as_bytes(agent));
+        // 8. Let resolvedOptions be ? GetOptionsObject(options).
+        // 9. Perform ? GetTemporalOverflowOption(resolvedOptions).
+        if result.is_ok() {
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+        }
+        // 10. If calendar is "iso8601", then
+        //     a. Let referenceISOYear be 1972 (the first ISO 8601 leap year
+        //        after the epoch).
+        //     b. Let isoDate be CreateISODateRecord(referenceISOYear, result.[[Month]], result.[[Day]]).
+        //     c. Return ! CreateTemporalMonthDay(isoDate, calendar).
+        // 11-17. Otherwise resolve the month and day in the calendar.
+        result
+    };
+    result.map_err(|err| temporal_err_to_js_err(agent, err, gc.into_nogc()))
+}
@@ -0,0 +1,131 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use temporal_rs::options::Overflow;
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, ExceptionType, Function, JsResult, Object,
+        PlainMonthDayRecord, Realm, String, Value, builders::BuiltinFunctionBuilder,
+        create_temporal_plain_month_day, temporal_err_to_js_err, to_constructor_calendar,
+        to_integer_with_truncation, to_temporal_month_day,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{CreateHeapData, IntrinsicConstructorIndexes},
+};
+
+/// Constructor function object for %Temporal.PlainMonthDay%.
+pub(crate) struct TemporalPlainMonthDayConstructor;
+
+impl Builtin for TemporalPlainMonthDayConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.PlainMonthDay;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Constructor(TemporalPlainMonthDayConstructor::constructor);
+}
+impl BuiltinIntrinsicConstructor for TemporalPlainMonthDayConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::TemporalPlainMonthDay;
+}
+
+struct TemporalPlainMonthDayFrom;
+impl Builtin for TemporalPlainMonthDayFrom {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.from;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainMonthDayConstructor::from);
+}
+
+impl TemporalPlainMonthDayConstructor {
+    /// ### [10.1.1 Temporal.PlainMonthDay ( isoMonth, isoDay [ , calendar [ , referenceISOYear ] ] )](https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let iso_month = args.get(0).scope(agent, gc.nogc());
+        let iso_day = args.get(1).scope(agent, gc.nogc());
+        let calendar = args.get(2).scope(agent, gc.nogc());
+        let reference_iso_year = args.get(3).scope(agent, gc.nogc());
+        let new_target = new_target.bind(gc.nogc());
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "calling a builtin Temporal.PlainMonthDay constructor without new is forbidden",
+                gc.into_nogc(),
+            ));
+        };
+        let Ok(new_target) = Function::try_from(new_target) else {
+            unreachable!()
+        };
+        let new_target = new_target.scope(agent, gc.nogc());
+        // 2. If referenceISOYear is undefined, then
+        //     a. Set referenceISOYear to 1972𝔽 (the first ISO 8601 leap year
+        //        after the epoch).
+        // 3. Let m be ? ToIntegerWithTruncation(isoMonth).
+        let m = to_integer_with_truncation(agent, iso_month.get(agent), gc.reborrow()).unbind()?;
+        // 4. Let d be ? ToIntegerWithTruncation(isoDay).
+        let d = to_integer_with_truncation(agent, iso_day.get(agent), gc.reborrow()).unbind()?;
+        // 5. If calendar is undefined, set calendar to "iso8601".
+        // 6. If calendar is not a String, throw a TypeError exception.
+        // 7. Set calendar to ? CanonicalizeCalendar(calendar).
+        let calendar = to_constructor_calendar(agent, calendar.get(agent), gc.nogc()).unbind()?;
+        // 8. Let y be ? ToIntegerWithTruncation(referenceISOYear).
+        let reference_iso_year = reference_iso_year.get(agent);
+        let y = if reference_iso_year.is_undefined() {
+            1972
+        } else {
+            to_integer_with_truncation(agent, reference_iso_year, gc.reborrow()).unbind()?
+        };
+        // 9. If IsValidISODate(y, m, d) is false, throw a RangeError exception.
+        // 10. Let isoDate be CreateISODateRecord(y, m, d).
+        let plain_month_day = temporal_rs::PlainMonthDay::new_with_overflow(
+            u8::try_from(m).unwrap_or(u8::MAX),
+            u8::try_from(d).unwrap_or(u8::MAX),
+            calendar,
+            Overflow::Reject,
+            Some(y.clamp(i32::MIN.into(), i32::MAX.into()) as i32),
+        )
+        .map_err(|err| temporal_err_to_js_err(agent, err, gc.nogc()))
+        .unbind()?;
+        // 11. Return ? CreateTemporalMonthDay(isoDate, calendar, NewTarget).
+        create_temporal_plain_month_day(agent, plain_month_day, Some(new_target.get(agent)), gc)
+            .map(Value::from)
+    }
+
+    /// ### [10.2.2 Temporal.PlainMonthDay.from ( item [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday.from)
+    fn from<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let item = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Return ? ToTemporalMonthDay(item, options).
+        let plain_month_day = to_temporal_month_day(agent, item.unbind(), options.unbind(), gc)?;
+        Ok(agent
+            .heap
+            .create(PlainMonthDayRecord {
+                object_index: None,
+                plain_month_day,
+            })
+            .into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>, _gc: NoGcScope) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let plain_month_day_prototype = intrinsics.temporal_plain_month_day_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<TemporalPlainMonthDayConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(2)
+        .with_prototype_property(plain_month_day_prototype.into())
+        .with_builtin_function_property::<TemporalPlainMonthDayFrom>()
+        .build();
+    }
+}
@@ -0,0 +1,434 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use temporal_rs::options::{DisplayCalendar, Overflow};
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
+        CalendarFieldNames, ExceptionType, JsResult, Object, PlainDateRecord, PlainMonthDayRecord,
+        PropertyKey, Realm, String, Value, builders::OrdinaryObjectBuilder,
+        builtins::temporal::plain_month_day::require_internal_slot_temporal_plain_month_day,
+        get_options_object, get_temporal_show_calendar_name_option, is_partial_temporal_object,
+        prepare_calendar_fields, resolve_temporal_overflow_option, temporal_err_to_js_err,
+        to_temporal_month_day,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{CreateHeapData, WellKnownSymbols},
+};
+
+pub(crate) struct TemporalPlainMonthDayPrototype;
+
+struct TemporalPlainMonthDayPrototypeGetCalendarId;
+impl Builtin for TemporalPlainMonthDayPrototypeGetCalendarId {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_calendarId;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.calendarId.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainMonthDayPrototype::get_calendar_id);
+}
+impl BuiltinGetter for TemporalPlainMonthDayPrototypeGetCalendarId {}
+
+struct TemporalPlainMonthDayPrototypeGetMonthCode;
+impl Builtin for TemporalPlainMonthDayPrototypeGetMonthCode {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_monthCode;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.monthCode.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainMonthDayPrototype::get_month_code);
+}
+impl BuiltinGetter for TemporalPlainMonthDayPrototypeGetMonthCode {}
+
+struct TemporalPlainMonthDayPrototypeGetDay;
+impl Builtin for TemporalPlainMonthDayPrototypeGetDay {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_day;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.day.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainMonthDayPrototype::get_day);
+}
+impl BuiltinGetter for TemporalPlainMonthDayPrototypeGetDay {}
+
+struct TemporalPlainMonthDayPrototypeWith;
+impl Builtin for TemporalPlainMonthDayPrototypeWith {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.with;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainMonthDayPrototype::with);
+}
+
+struct TemporalPlainMonthDayPrototypeEquals;
+impl Builtin for TemporalPlainMonthDayPrototypeEquals {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.equals;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainMonthDayPrototype::equals);
+}
+
+struct TemporalPlainMonthDayPrototypeToString;
+impl Builtin for TemporalPlainMonthDayPrototypeToString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toString;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainMonthDayPrototype::to_string);
+}
+
+struct TemporalPlainMonthDayPrototypeToLocaleString;
+impl Builtin for TemporalPlainMonthDayPrototypeToLocaleString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toLocaleString;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainMonthDayPrototype::to_locale_string);
+}
+
+struct TemporalPlainMonthDayPrototypeToJSON;
+impl Builtin for TemporalPlainMonthDayPrototypeToJSON {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toJSON;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainMonthDayPrototype::to_json);
+}
+
+struct TemporalPlainMonthDayPrototypeValueOf;
+impl Builtin for TemporalPlainMonthDayPrototypeValueOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.valueOf;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainMonthDayPrototype::value_of);
+}
+
+struct TemporalPlainMonthDayPrototypeToPlainDate;
+impl Builtin for TemporalPlainMonthDayPrototypeToPlainDate {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toPlainDate;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainMonthDayPrototype::to_plain_date);
+}
+
+impl TemporalPlainMonthDayPrototype {
+    /// ### [10.3.3 get Temporal.PlainMonthDay.prototype.calendarId](https://tc39.es/proposal-temporal/#sec-get-temporal.plainmonthday.prototype.calendarid)
+    fn get_calendar_id<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let monthDay be the this value.
+        // 2. Perform ? RequireInternalSlot(monthDay, [[InitializedTemporalMonthDay]]).
+        let plain_month_day =
+            require_internal_slot_temporal_plain_month_day(agent, this_value, gc)?;
+        // 3. Return monthDay.[[Calendar]].
+        let value = plain_month_day
+            .inner_plain_month_day(agent)
+            .calendar()
+            .identifier();
+        Ok(Value::from_static_str(agent, value, gc))
+    }
+
+    /// ### [10.3.4 get Temporal.PlainMonthDay.prototype.monthCode](https://tc39.es/proposal-temporal/#sec-get-temporal.plainmonthday.prototype.monthcode)
+    fn get_month_code<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let monthDay be the this value.
+        // 2. Perform ? RequireInternalSlot(monthDay, [[InitializedTemporalMonthDay]]).
+        let plain_month_day =
+            require_internal_slot_temporal_plain_month_day(agent, this_value, gc)?;
+        // 3. Return CalendarISOToDate(monthDay.[[Calendar]], monthDay.[[ISODate]]).[[MonthCode]].
+        let value = plain_month_day.inner_plain_month_day(agent).month_code();
+        Ok(Value::from_str(agent, value.as_str(), gc))
+    }
+
+    /// ### [10.3.5 get Temporal.PlainMonthDay.prototype.day](https://tc39.es/proposal-temporal/#sec-get-temporal.plainmonthday.prototype.day)
+    fn get_day<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let monthDay be the this value.
+        // 2. Perform ? RequireInternalSlot(monthDay, [[InitializedTemporalMonthDay]]).
+        let plain_month_day =
+            require_internal_slot_temporal_plain_month_day(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(monthDay.[[Calendar]], monthDay.[[ISODate]]).[[Day]]).
+        let value = plain_month_day.inner_plain_month_day(agent).day();
+        Ok(value.into())
+    }
+
+    /// ### [10.3.6 Temporal.PlainMonthDay.prototype.with ( temporalMonthDayLike [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday.prototype.with)
+    fn with<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let temporal_month_day_like = args.get(0).scope(agent, gc.nogc());
+        let options = args.get(1).scope(agent, gc.nogc());
+        // 1. Let monthDay be the this value.
+        // 2. Perform ? RequireInternalSlot(monthDay, [[InitializedTemporalMonthDay]]).
+        let plain_month_day =
+            require_internal_slot_temporal_plain_month_day(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. If ? IsPartialTemporalObject(temporalMonthDayLike) is false, throw
+        //    a TypeError exception.
+        if !is_partial_temporal_object(agent, temporal_month_day_like.get(agent), gc.reborrow())
+            .unbind()?
+        {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Argument is not a partial Temporal.PlainMonthDay-like object",
+                gc.into_nogc(),
+            ));
+        }
+        let temporal_month_day_like = Object::try_from(temporal_month_day_like.get(agent)).unwrap();
+        // 4. Let calendar be monthDay.[[Calendar]].
+        let calendar = plain_month_day
+            .get(agent)
+            .inner_plain_month_day(agent)
+            .calendar()
+            .clone();
+        // 5. Let fields be ISODateToFields(calendar, monthDay.[[ISODate]], month-day).
+        // 6. Let partialMonthDay be ? PrepareCalendarFields(calendar,
+        //    temporalMonthDayLike, « year, month, month-code, day », « »,
+        //    partial).
+        let partial_month_day = prepare_calendar_fields(
+            agent,
+            &calendar,
+            temporal_month_day_like,
+            CalendarFieldNames::DATE,
+            true,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 7. Set fields to CalendarMergeFields(calendar, fields, partialMonthDay).
+        // 8. Let resolvedOptions be ? GetOptionsObject(options).
+        // 9. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow =
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let calendar_fields = partial_month_day.to_calendar_fields(agent, overflow, gc)?;
+        // 10. Let isoDate be ? CalendarMonthDayFromFields(calendar, fields, overflow).
+        let plain_month_day = plain_month_day
+            .get(agent)
+            .inner_plain_month_day(agent)
+            .with(calendar_fields, Some(overflow))
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        // 11. Return ! CreateTemporalMonthDay(isoDate, calendar).
+        Ok(agent
+            .heap
+            .create(PlainMonthDayRecord {
+                object_index: None,
+                plain_month_day,
+            })
+            .into())
+    }
+
+    /// ### [10.3.7 Temporal.PlainMonthDay.prototype.equals ( other )](https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday.prototype.equals)
+    fn equals<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let other = args.get(0).bind(gc.nogc());
+        // 1. Let monthDay be the this value.
+        // 2. Perform ? RequireInternalSlot(monthDay, [[InitializedTemporalMonthDay]]).
+        let plain_month_day =
+            require_internal_slot_temporal_plain_month_day(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. Set other to ? ToTemporalMonthDay(other).
+        let other = to_temporal_month_day(agent, other.unbind(), Value::Undefined, gc.reborrow())
+            .unbind()?;
+        // 4. If CompareISODate(monthDay.[[ISODate]], other.[[ISODate]]) ≠ 0, return false.
+        // 5. Return CalendarEquals(monthDay.[[Calendar]], other.[[Calendar]]).
+        Ok((plain_month_day.get(agent).inner_plain_month_day(agent) == &other).into())
+    }
+
+    /// ### [10.3.8 Temporal.PlainMonthDay.prototype.toString ( [ options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday.prototype.tostring)
+    fn to_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let options = args.get(0).bind(gc.nogc());
+        // 1. Let monthDay be the this value.
+        // 2. Perform ? RequireInternalSlot(monthDay, [[InitializedTemporalMonthDay]]).
+        let plain_month_day =
+            require_internal_slot_temporal_plain_month_day(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let resolved_options = get_options_object(agent, options, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 4. Let showCalendar be ? GetTemporalShowCalendarNameOption(resolvedOptions).
+        let show_calendar = match resolved_options {
+            Some(resolved_options) => get_temporal_show_calendar_name_option(
+                agent,
+                resolved_options.unbind(),
+                gc.reborrow(),
+            )
+            .unbind()?,
+            None => DisplayCalendar::default(),
+        };
+        let gc = gc.into_nogc();
+        // 5. Return TemporalMonthDayToString(monthDay, showCalendar).
+        let string = plain_month_day
+            .get(agent)
+            .inner_plain_month_day(agent)
+            .to_ixdtf_string(show_calendar);
+        Ok(Value::from_string(agent, string, gc))
+    }
+
+    /// ### [10.3.9 Temporal.PlainMonthDay.prototype.toLocaleString ( [ locales [ , options ] ] )](https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday.prototype.tolocalestring)
+    ///
+    /// An ECMAScript implementation that includes the ECMA-402
+    /// Internationalization API must implement this method as specified in the
+    /// ECMA-402 specification. If an ECMAScript implementation does not
+    /// include the ECMA-402 API the following specification of this method is
+    /// used.
+    fn to_locale_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let monthDay be the this value.
+        // 2. Perform ? RequireInternalSlot(monthDay, [[InitializedTemporalMonthDay]]).
+        let plain_month_day =
+            require_internal_slot_temporal_plain_month_day(agent, this_value, gc)?;
+        // 3. Return TemporalMonthDayToString(monthDay, auto).
+        let string = plain_month_day
+            .inner_plain_month_day(agent)
+            .to_ixdtf_string(DisplayCalendar::Auto);
+        Ok(Value::from_string(agent, string, gc))
+    }
+
+    /// ### [10.3.10 Temporal.PlainMonthDay.prototype.toJSON ( )](https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday.prototype.tojson)
+    fn to_json<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let monthDay be the this value.
+        // 2. Perform ? RequireInternalSlot(monthDay, [[InitializedTemporalMonthDay]]).
+        let plain_month_day =
+            require_internal_slot_temporal_plain_month_day(agent, this_value, gc)?;
+        // 3. Return TemporalMonthDayToString(monthDay, auto).
+        let string = plain_month_day
+            .inner_plain_month_day(agent)
+            .to_ixdtf_string(DisplayCalendar::Auto);
+        Ok(Value::from_string(agent, string, gc))
+    }
+
+    /// ### [10.3.11 Temporal.PlainMonthDay.prototype.valueOf ( )](https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday.prototype.valueof)
+    fn value_of<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Throw a TypeError exception.
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "`valueOf` not supported by Temporal built-ins. See 'compare', 'equals', or `toString`",
+            gc.into_nogc(),
+        ))
+    }
+
+    /// ### [10.3.12 Temporal.PlainMonthDay.prototype.toPlainDate ( item )](https://tc39.es/proposal-temporal/#sec-temporal.plainmonthday.prototype.toplaindate)
+    fn to_plain_date<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let item = args.get(0).bind(gc.nogc());
+        // 1. Let monthDay be the this value.
+        // 2. Perform ? RequireInternalSlot(monthDay, [[InitializedTemporalMonthDay]]).
+        let plain_month_day =
+            require_internal_slot_temporal_plain_month_day(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. If item is not an Object, then
+        let Ok(item) = Object::try_from(item) else {
+            // a. Throw a TypeError exception.
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Argument is not an object",
+                gc.into_nogc(),
+            ));
+        };
+        // 4. Let calendar be monthDay.[[Calendar]].
+        let calendar = plain_month_day
+            .get(agent)
+            .inner_plain_month_day(agent)
+            .calendar()
+            .clone();
+        // 5. Let fields be ISODateToFields(calendar, monthDay.[[ISODate]], month-day).
+        // 6. Let inputFields be ? PrepareCalendarFields(calendar, item, « year », « », « »).
+        let input_fields = prepare_calendar_fields(
+            agent,
+            &calendar,
+            item.unbind(),
+            CalendarFieldNames::YEAR,
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let input_fields = input_fields.to_calendar_fields(agent, Overflow::Constrain, gc)?;
+        // 7. Let mergedFields be CalendarMergeFields(calendar, fields, inputFields).
+        // 8. Let isoDate be ? CalendarDateFromFields(calendar, mergedFields, constrain).
+        let plain_date = plain_month_day
+            .get(agent)
+            .inner_plain_month_day(agent)
+            .to_plain_date(Some(input_fields))
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        // 9. Return ! CreateTemporalDate(isoDate, calendar).
+        Ok(agent
+            .heap
+            .create(PlainDateRecord {
+                object_index: None,
+                plain_date,
+            })
+            .into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>, _: NoGcScope) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let this = intrinsics.temporal_plain_month_day_prototype();
+        let object_prototype = intrinsics.object_prototype();
+        let plain_month_day_constructor = intrinsics.temporal_plain_month_day();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(12)
+            .with_prototype(object_prototype)
+            .with_constructor_property(plain_month_day_constructor)
+            .with_builtin_function_getter_property::<TemporalPlainMonthDayPrototypeGetCalendarId>()
+            .with_builtin_function_getter_property::<TemporalPlainMonthDayPrototypeGetMonthCode>()
+            .with_builtin_function_getter_property::<TemporalPlainMonthDayPrototypeGetDay>()
+            .with_builtin_function_property::<TemporalPlainMonthDayPrototypeWith>()
+            .with_builtin_function_property::<TemporalPlainMonthDayPrototypeEquals>()
+            .with_builtin_function_property::<TemporalPlainMonthDayPrototypeToString>()
+            .with_builtin_function_property::<TemporalPlainMonthDayPrototypeToLocaleString>()
+            .with_builtin_function_property::<TemporalPlainMonthDayPrototypeToJSON>()
+            .with_builtin_function_property::<TemporalPlainMonthDayPrototypeValueOf>()
+            .with_builtin_function_property::<TemporalPlainMonthDayPrototypeToPlainDate>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Temporal_PlainMonthDay.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
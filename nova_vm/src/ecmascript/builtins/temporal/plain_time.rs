@@ -10,12 +10,16 @@ pub(crate) use data::*;
 pub(crate) use plain_time_constructor::*;
 pub(crate) use plain_time_prototype::*;
 
+use temporal_rs::Calendar;
+
 use crate::{
     ecmascript::{
-        Agent, ExceptionType, Function, InternalMethods, InternalSlots, JsResult, OrdinaryObject,
-        ProtoIntrinsics, Value, object_handle, ordinary_populate_from_constructor,
+        Agent, CalendarFieldNames, ExceptionType, Function, InternalMethods, InternalSlots,
+        JsResult, Object, OrdinaryObject, ProtoIntrinsics, String, Value, object_handle,
+        ordinary_populate_from_constructor, prepare_calendar_fields,
+        resolve_temporal_overflow_option, temporal_err_to_js_err,
     },
-    engine::{Bindable, GcScope, NoGcScope},
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
     heap::{
         ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
         HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues, arena_vec_access,
@@ -133,3 +137,108 @@ pub(crate) fn create_temporal_plain_time<'gc>(
         .unwrap(),
     )
 }
+
+/// ### [4.5.6 ToTemporalTime ( item [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal-totemporaltime)
+///
+/// The abstract operation ToTemporalTime takes argument item (an ECMAScript
+/// language value) and optional argument options (an ECMAScript language
+/// value) and returns either a normal completion containing a
+/// Temporal.PlainTime or a throw completion. It returns its argument item if
+/// it is already a Temporal.PlainTime instance, converts item to a new
+/// Temporal.PlainTime instance if possible, and throws otherwise.
+pub(crate) fn to_temporal_time<'gc>(
+    agent: &mut Agent,
+    item: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, temporal_rs::PlainTime> {
+    let item = item.bind(gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 1. If options is not present, set options to undefined.
+    // 2. If item is an Object, then
+    let result = if let Ok(item) = Object::try_from(item) {
+        let plain_time = match item {
+            // a. If item has an [[InitializedTemporalTime]] internal slot, then
+            //     iii. Return ! CreateTemporalTime(item.[[Time]]).
+            Object::PlainTime(item) => Some(*item.inner_plain_time(agent)),
+            // b. If item has an [[InitializedTemporalDateTime]] internal slot, then
+            //     iii. Return ! CreateTemporalTime(item.[[ISODateTime]].[[Time]]).
+            Object::PlainDateTime(item) => Some(item.inner_plain_date_time(agent).to_plain_time()),
+            // c. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+            //     i. Let isoDateTime be GetISODateTimeFor(item.[[TimeZone]], item.[[EpochNanoseconds]]).
+            //     iv. Return ! CreateTemporalTime(isoDateTime.[[Time]]).
+            Object::ZonedDateTime(item) => Some(item.inner_zoned_date_time(agent).to_plain_time()),
+            _ => None,
+        };
+        if let Some(plain_time) = plain_time {
+            // i. Let resolvedOptions be ? GetOptionsObject(options).
+            // ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+            return Ok(plain_time);
+        }
+        // d. Let result be ? ToTemporalTimeRecord(item).
+        let fields = prepare_calendar_fields(
+            agent,
+            &Calendar::ISO,
+            item.unbind(),
+            CalendarFieldNames::TIME,
+            true,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // e. Let resolvedOptions be ? GetOptionsObject(options).
+        // f. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow =
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+        // g. Set result to ? RegulateTime(result.[[Hour]], result.[[Minute]],
+        //    result.[[Second]], result.[[Millisecond]], result.[[Microsecond]],
+        //    result.[[Nanosecond]], overflow).
+        let partial = fields
+            .to_partial_time(agent, overflow, gc.nogc())
+            .unbind()?;
+        temporal_rs::PlainTime::from_partial(partial, Some(overflow))
+    } else {
+        // 3. Else,
+        //     a. If item is not a String, throw a TypeError exception.
+        let Ok(item) = String::try_from(item) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Item is not a String",
+                gc.into_nogc(),
+            ));
+        };
+        //     b. Let parseResult be ? ParseTemporalTimeString(item).
+        //     c. Assert: parseResult.[[Time]] is not start-of-day.
+        //     d. Set result to parseResult.[[Time]].
+        let result = temporal_rs::PlainTime::from_utf8(item.as_bytes(agent));
+        //     e. NOTE: A successful parse using TemporalTimeString guarantees
+        //        a time that is valid.
+        //     f. Let resolvedOptions be ? GetOptionsObject(options).
+        //     g. Perform ? GetTemporalOverflowOption(resolvedOptions).
+        if result.is_ok() {
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+        }
+        result
+    };
+    // 4. Return ! CreateTemporalTime(result).
+    result.map_err(|err| temporal_err_to_js_err(agent, err, gc.into_nogc()))
+}
+
+/// ### [4.5.7 ToTimeRecordOrMidnight ( item )](https://tc39.es/proposal-temporal/#sec-temporal-totimerecordormidnight)
+///
+/// The abstract operation ToTimeRecordOrMidnight takes argument item (an
+/// ECMAScript language value) and returns either a normal completion
+/// containing a Time Record or a throw completion.
+pub(crate) fn to_time_record_or_midnight<'gc>(
+    agent: &mut Agent,
+    item: Value,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Option<temporal_rs::PlainTime>> {
+    // 1. If item is undefined, return MidnightTimeRecord().
+    if item.is_undefined() {
+        return Ok(None);
+    }
+    // 2. Let plainTime be ? ToTemporalTime(item).
+    // 3. Return plainTime.[[Time]].
+    to_temporal_time(agent, item, Value::Undefined, gc).map(Some)
+}
@@ -34,12 +34,12 @@ impl TemporalPlainTimeConstructor {
         new_target: Option<Object>,
         mut gc: GcScope<'gc, '_>,
     ) -> JsResult<'gc, Value<'gc>> {
-        let hours = args.get(1).scope(agent, gc.nogc());
-        let minutes = args.get(2).scope(agent, gc.nogc());
-        let seconds = args.get(3).scope(agent, gc.nogc());
-        let milliseconds = args.get(4).scope(agent, gc.nogc());
-        let microseconds = args.get(5).scope(agent, gc.nogc());
-        let nanoseconds = args.get(6).scope(agent, gc.nogc());
+        let hours = args.get(0).scope(agent, gc.nogc());
+        let minutes = args.get(1).scope(agent, gc.nogc());
+        let seconds = args.get(2).scope(agent, gc.nogc());
+        let milliseconds = args.get(3).scope(agent, gc.nogc());
+        let microseconds = args.get(4).scope(agent, gc.nogc());
+        let nanoseconds = args.get(5).scope(agent, gc.nogc());
 
         let new_target = new_target.bind(gc.nogc());
 
@@ -0,0 +1,340 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod data;
+mod plain_year_month_constructor;
+mod plain_year_month_prototype;
+
+pub(crate) use data::*;
+pub(crate) use plain_year_month_constructor::*;
+pub(crate) use plain_year_month_prototype::*;
+
+use temporal_rs::{
+    options::{Unit, UnitGroup},
+    partial::PartialYearMonth,
+};
+
+use crate::{
+    ecmascript::{
+        Agent, CalendarFieldNames, DurationRecord, ExceptionType, Function, InternalMethods,
+        InternalSlots, JsResult, Object, OrdinaryObject, ProtoIntrinsics, String, TemporalDuration,
+        Value, get_difference_settings, get_options_object,
+        get_temporal_calendar_identifier_with_iso_default, object_handle,
+        ordinary_populate_from_constructor, prepare_calendar_fields,
+        resolve_temporal_overflow_option, temporal_err_to_js_err, to_temporal_duration,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{
+        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
+        HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues, arena_vec_access,
+    },
+};
+
+/// # [9 Temporal.PlainYearMonth Objects](https://tc39.es/proposal-temporal/#sec-temporal-plainyearmonth-objects)
+///
+/// A Temporal.PlainYearMonth object is an Object that contains integers
+/// corresponding to a particular year and month in the ISO 8601 calendar, as
+/// well as an Object indicating the calendar system used to interpret them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct TemporalPlainYearMonth<'a>(BaseIndex<'a, PlainYearMonthRecord<'static>>);
+object_handle!(TemporalPlainYearMonth, PlainYearMonth);
+arena_vec_access!(
+    TemporalPlainYearMonth,
+    'a,
+    PlainYearMonthRecord,
+    plain_year_months
+);
+
+impl TemporalPlainYearMonth<'_> {
+    pub(crate) fn inner_plain_year_month(self, agent: &Agent) -> &temporal_rs::PlainYearMonth {
+        &self.unbind().get(agent).plain_year_month
+    }
+}
+
+impl<'a> InternalSlots<'a> for TemporalPlainYearMonth<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::TemporalPlainYearMonth;
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        self.get(agent).object_index.unbind()
+    }
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(
+            self.get_mut(agent)
+                .object_index
+                .replace(backing_object)
+                .is_none()
+        );
+    }
+}
+
+impl<'a> InternalMethods<'a> for TemporalPlainYearMonth<'a> {}
+
+impl HeapMarkAndSweep for TemporalPlainYearMonth<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.plain_year_months.push(*self);
+    }
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.plain_year_months.shift_index(&mut self.0);
+    }
+}
+
+impl HeapSweepWeakReference for TemporalPlainYearMonth<'static> {
+    fn sweep_weak_reference(self, compactions: &CompactionLists) -> Option<Self> {
+        compactions
+            .plain_year_months
+            .shift_weak_index(self.0)
+            .map(Self)
+    }
+}
+
+impl<'a> CreateHeapData<PlainYearMonthRecord<'a>, TemporalPlainYearMonth<'a>> for Heap {
+    fn create(&mut self, data: PlainYearMonthRecord<'a>) -> TemporalPlainYearMonth<'a> {
+        self.plain_year_months.push(data.unbind());
+        self.alloc_counter += core::mem::size_of::<PlainYearMonthRecord<'static>>();
+        TemporalPlainYearMonth(BaseIndex::last(&self.plain_year_months))
+    }
+}
+
+#[inline(always)]
+fn require_internal_slot_temporal_plain_year_month<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, TemporalPlainYearMonth<'a>> {
+    match value {
+        Value::PlainYearMonth(plain_year_month) => Ok(plain_year_month.bind(gc)),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Object is not a Temporal PlainYearMonth",
+            gc,
+        )),
+    }
+}
+
+/// ### [9.5.6 CreateTemporalYearMonth ( isoDate, calendar [ , newTarget ] )](https://tc39.es/proposal-temporal/#sec-temporal-createtemporalyearmonth)
+pub(crate) fn create_temporal_plain_year_month<'gc>(
+    agent: &mut Agent,
+    plain_year_month: temporal_rs::PlainYearMonth,
+    new_target: Option<Function>,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, TemporalPlainYearMonth<'gc>> {
+    // 1. If newTarget is not present, set newTarget to %Temporal.PlainYearMonth%.
+    let new_target = new_target.unwrap_or_else(|| {
+        agent
+            .current_realm_record()
+            .intrinsics()
+            .temporal_plain_year_month()
+            .into()
+    });
+    // 2. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.PlainYearMonth.prototype%", « [[InitializedTemporalYearMonth]], [[ISODate]], [[Calendar]] »).
+    // 3. Set the internal slots of object.
+    // 4. Return object.
+    let object = agent.heap.create(PlainYearMonthRecord {
+        object_index: None,
+        plain_year_month,
+    });
+    Ok(
+        TemporalPlainYearMonth::try_from(ordinary_populate_from_constructor(
+            agent,
+            object.unbind().into(),
+            new_target,
+            ProtoIntrinsics::TemporalPlainYearMonth,
+            gc,
+        )?)
+        .unwrap(),
+    )
+}
+
+/// ### [9.5.2 ToTemporalYearMonth ( item [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal-totemporalyearmonth)
+///
+/// The abstract operation ToTemporalYearMonth takes argument item (an
+/// ECMAScript language value) and optional argument options (an ECMAScript
+/// language value) and returns either a normal completion containing a
+/// Temporal.PlainYearMonth, or a throw completion. Converts item to a new
+/// Temporal.PlainYearMonth instance if possible, and throws otherwise.
+pub(crate) fn to_temporal_year_month<'gc>(
+    agent: &mut Agent,
+    item: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, temporal_rs::PlainYearMonth> {
+    let item = item.bind(gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 1. If options is not present, set options to undefined.
+    // 2. If item is an Object, then
+    let result = if let Ok(item) = Object::try_from(item) {
+        // a. If item has an [[InitializedTemporalYearMonth]] internal slot, then
+        if let Object::PlainYearMonth(item) = item {
+            let plain_year_month = item.inner_plain_year_month(agent).clone();
+            // i. Let resolvedOptions be ? GetOptionsObject(options).
+            // ii. Perform ? GetTemporalOverflowOption(resolvedOptions).
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+            // iii. Return ! CreateTemporalYearMonth(item.[[ISODate]], item.[[Calendar]]).
+            return Ok(plain_year_month);
+        }
+        let item = item.scope(agent, gc.nogc());
+        // b. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(item).
+        let calendar = get_temporal_calendar_identifier_with_iso_default(
+            agent,
+            item.get(agent),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // c. Let fields be ? PrepareCalendarFields(calendar, item, « year, month, month-code », « », « »).
+        let fields = prepare_calendar_fields(
+            agent,
+            &calendar,
+            item.get(agent),
+            CalendarFieldNames::YEAR_MONTH,
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // d. Let resolvedOptions be ? GetOptionsObject(options).
+        // e. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow =
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+        // f. Let isoDate be ? CalendarYearMonthFromFields(calendar, fields, overflow).
+        // g. Return ! CreateTemporalYearMonth(isoDate, calendar).
+        let calendar_fields = fields
+            .to_year_month_calendar_fields(agent, overflow, gc.nogc())
+            .unbind()?;
+        temporal_rs::PlainYearMonth::from_partial(
+            PartialYearMonth {
+                calendar_fields,
+                calendar,
+            },
+            Some(overflow),
+        )
+    } else {
+        // 3. If item is not a String, throw a TypeError exception.
+        let Ok(item) = String::try_from(item) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Item is not a String",
+                gc.into_nogc(),
+            ));
+        };
+        // 4. Let result be ? ParseISODateTime(item, « TemporalYearMonthString »).
+        // 5. Let calendar be result.[[Calendar]].
+        // 6. If calendar is empty, set calendar to "iso8601".
+        // 7. Set calendar to ? CanonicalizeCalendar(calendar).
+        let result = temporal_rs::PlainYearMonth::from_utf8(item.as_bytes(agent));
+        // 8. Let resolvedOptions be ? GetOptionsObject(options).
+        // 9. Perform ? GetTemporalOverflowOption(resolvedOptions).
+        if result.is_ok() {
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+        }
+        // 10. Let isoDate be CreateISODateRecord(result.[[Year]], result.[[Month]], result.[[Day]]).
+        // 11. If ISOYearMonthWithinLimits(isoDate) is false, throw a RangeError exception.
+        // 12. Set result to ISODateToFields(calendar, isoDate, year-month).
+        // 13. NOTE: The following operation is called with constrain regardless
+        //     of the value of overflow, in order for the calendar to store a
+        //     canonical value in the [[Day]] field of the [[ISODate]] internal
+        //     slot of the result.
+        // 14. Set isoDate to ? CalendarYearMonthFromFields(calendar, result, constrain).
+        // 15. Return ! CreateTemporalYearMonth(isoDate, calendar).
+        result
+    };
+    result.map_err(|err| temporal_err_to_js_err(agent, err, gc.into_nogc()))
+}
+
+/// ### [9.5.10 AddDurationToYearMonth ( operation, yearMonth, temporalDurationLike, options )](https://tc39.es/proposal-temporal/#sec-temporal-adddurationtoyearmonth)
+///
+/// The abstract operation AddDurationToYearMonth takes arguments operation
+/// (add or subtract), yearMonth (a Temporal.PlainYearMonth),
+/// temporalDurationLike (an ECMAScript language value), and options (an
+/// ECMAScript language value) and returns either a normal completion
+/// containing a Temporal.PlainYearMonth or a throw completion. It
+/// adds/subtracts temporalDurationLike to/from yearMonth.
+fn add_duration_to_year_month<'gc, const IS_ADD: bool>(
+    agent: &mut Agent,
+    plain_year_month: TemporalPlainYearMonth,
+    duration: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, TemporalPlainYearMonth<'gc>> {
+    let plain_year_month = plain_year_month.scope(agent, gc.nogc());
+    let duration = duration.bind(gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 1. Let duration be ? ToTemporalDuration(temporalDurationLike).
+    let duration = to_temporal_duration(agent, duration.unbind(), gc.reborrow()).unbind()?;
+    // 2. If operation is subtract, set duration to CreateNegatedTemporalDuration(duration).
+    // 3. Let resolvedOptions be ? GetOptionsObject(options).
+    // 4. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+    let overflow =
+        resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+    // 5-16. Add the duration to the first day of the month and constrain the
+    //       result to a year and month.
+    let plain_year_month = plain_year_month.get(agent).inner_plain_year_month(agent);
+    let result = if IS_ADD {
+        plain_year_month.add(&duration, overflow)
+    } else {
+        plain_year_month.subtract(&duration, overflow)
+    };
+    let gc = gc.into_nogc();
+    let plain_year_month = result.map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+    // 17. Return ! CreateTemporalYearMonth(isoDate, calendar).
+    Ok(agent.heap.create(PlainYearMonthRecord {
+        object_index: None,
+        plain_year_month,
+    }))
+}
+
+/// ### [9.5.9 DifferenceTemporalPlainYearMonth ( operation, yearMonth, other, options )](https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalplainyearmonth)
+///
+/// The abstract operation DifferenceTemporalPlainYearMonth takes arguments
+/// operation (since or until), yearMonth (a Temporal.PlainYearMonth), other
+/// (an ECMAScript language value), and options (an ECMAScript language value)
+/// and returns either a normal completion containing a Temporal.Duration or a
+/// throw completion. It computes the difference between the two times
+/// represented by yearMonth and other, optionally rounds it, and returns it as
+/// a Temporal.Duration object.
+fn difference_temporal_plain_year_month<'gc, const IS_UNTIL: bool>(
+    agent: &mut Agent,
+    plain_year_month: TemporalPlainYearMonth,
+    other: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, TemporalDuration<'gc>> {
+    let plain_year_month = plain_year_month.scope(agent, gc.nogc());
+    let other = other.bind(gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 1. Set other to ? ToTemporalYearMonth(other).
+    let other =
+        to_temporal_year_month(agent, other.unbind(), Value::Undefined, gc.reborrow()).unbind()?;
+    // 2. Let calendar be yearMonth.[[Calendar]].
+    // 3. If CalendarEquals(calendar, other.[[Calendar]]) is false, throw a
+    //    RangeError exception.
+    // 4. Let resolvedOptions be ? GetOptionsObject(options).
+    let resolved_options = get_options_object(agent, options.get(agent), gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    // 5. Let settings be ? GetDifferenceSettings(operation, resolvedOptions,
+    //    date, « week, day », month, year).
+    let settings = get_difference_settings::<IS_UNTIL>(
+        agent,
+        resolved_options.unbind(),
+        UnitGroup::Date,
+        &[Unit::Week, Unit::Day],
+        Unit::Month,
+        Unit::Year,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 6-16. Compute the difference, round it and create the duration.
+    let plain_year_month = plain_year_month.get(agent).inner_plain_year_month(agent);
+    let duration = if IS_UNTIL {
+        plain_year_month.until(&other, settings)
+    } else {
+        plain_year_month.since(&other, settings)
+    };
+    let gc = gc.into_nogc();
+    let duration = duration.map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+    // 17. Return result.
+    Ok(agent.heap.create(DurationRecord {
+        object_index: None,
+        duration,
+    }))
+}
@@ -0,0 +1,50 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::OrdinaryObject,
+    engine::{NoGcScope, bindable_handle, trivially_bindable},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+#[derive(Debug, Clone)]
+pub struct PlainYearMonthRecord<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    pub(crate) plain_year_month: temporal_rs::PlainYearMonth,
+}
+
+impl PlainYearMonthRecord<'_> {
+    pub fn default() -> Self {
+        Self {
+            object_index: None,
+            plain_year_month: temporal_rs::PlainYearMonth::try_new_iso(1970, 1, None).unwrap(),
+        }
+    }
+}
+
+trivially_bindable!(temporal_rs::PlainYearMonth);
+bindable_handle!(PlainYearMonthRecord);
+
+// SAFETY: The calendar held by the record only references compiled calendar
+// data with a static lifetime, which is never mutated or dropped.
+unsafe impl Send for PlainYearMonthRecord<'_> {}
+
+impl HeapMarkAndSweep for PlainYearMonthRecord<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            plain_year_month: _,
+        } = self;
+
+        object_index.mark_values(queues);
+    }
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            plain_year_month: _,
+        } = self;
+
+        object_index.sweep_values(compactions);
+    }
+}
@@ -0,0 +1,153 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin,
+        BuiltinIntrinsicConstructor, ExceptionType, Function, JsResult, Object,
+        PlainYearMonthRecord, Realm, String, Value, builders::BuiltinFunctionBuilder,
+        create_temporal_plain_year_month, temporal_err_to_js_err, to_constructor_calendar,
+        to_integer_with_truncation, to_temporal_year_month,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{CreateHeapData, IntrinsicConstructorIndexes},
+};
+
+/// Constructor function object for %Temporal.PlainYearMonth%.
+pub(crate) struct TemporalPlainYearMonthConstructor;
+
+impl Builtin for TemporalPlainYearMonthConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.PlainYearMonth;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Constructor(TemporalPlainYearMonthConstructor::constructor);
+}
+impl BuiltinIntrinsicConstructor for TemporalPlainYearMonthConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::TemporalPlainYearMonth;
+}
+
+struct TemporalPlainYearMonthFrom;
+impl Builtin for TemporalPlainYearMonthFrom {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.from;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainYearMonthConstructor::from);
+}
+
+struct TemporalPlainYearMonthCompare;
+impl Builtin for TemporalPlainYearMonthCompare {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.compare;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainYearMonthConstructor::compare);
+}
+
+impl TemporalPlainYearMonthConstructor {
+    /// ### [9.1.1 Temporal.PlainYearMonth ( isoYear, isoMonth [ , calendar [ , referenceISODay ] ] )](https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let iso_year = args.get(0).scope(agent, gc.nogc());
+        let iso_month = args.get(1).scope(agent, gc.nogc());
+        let calendar = args.get(2).scope(agent, gc.nogc());
+        let reference_iso_day = args.get(3).scope(agent, gc.nogc());
+        let new_target = new_target.bind(gc.nogc());
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "calling a builtin Temporal.PlainYearMonth constructor without new is forbidden",
+                gc.into_nogc(),
+            ));
+        };
+        let Ok(new_target) = Function::try_from(new_target) else {
+            unreachable!()
+        };
+        let new_target = new_target.scope(agent, gc.nogc());
+        // 2. If referenceISODay is undefined, then
+        //     a. Set referenceISODay to 1𝔽.
+        // 3. Let y be ? ToIntegerWithTruncation(isoYear).
+        let y = to_integer_with_truncation(agent, iso_year.get(agent), gc.reborrow()).unbind()?;
+        // 4. Let m be ? ToIntegerWithTruncation(isoMonth).
+        let m = to_integer_with_truncation(agent, iso_month.get(agent), gc.reborrow()).unbind()?;
+        // 5. If calendar is undefined, set calendar to "iso8601".
+        // 6. If calendar is not a String, throw a TypeError exception.
+        // 7. Set calendar to ? CanonicalizeCalendar(calendar).
+        let calendar = to_constructor_calendar(agent, calendar.get(agent), gc.nogc()).unbind()?;
+        // 8. Let ref be ? ToIntegerWithTruncation(referenceISODay).
+        let reference_iso_day = reference_iso_day.get(agent);
+        let r = if reference_iso_day.is_undefined() {
+            1
+        } else {
+            to_integer_with_truncation(agent, reference_iso_day, gc.reborrow()).unbind()?
+        };
+        // 9. If IsValidISODate(y, m, ref) is false, throw a RangeError exception.
+        // 10. Let isoDate be CreateISODateRecord(y, m, ref).
+        let plain_year_month = temporal_rs::PlainYearMonth::try_new(
+            y.clamp(i32::MIN.into(), i32::MAX.into()) as i32,
+            u8::try_from(m).unwrap_or(u8::MAX),
+            Some(u8::try_from(r).unwrap_or(u8::MAX)),
+            calendar,
+        )
+        .map_err(|err| temporal_err_to_js_err(agent, err, gc.nogc()))
+        .unbind()?;
+        // 11. Return ? CreateTemporalYearMonth(isoDate, calendar, NewTarget).
+        create_temporal_plain_year_month(agent, plain_year_month, Some(new_target.get(agent)), gc)
+            .map(Value::from)
+    }
+
+    /// ### [9.2.2 Temporal.PlainYearMonth.from ( item [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.from)
+    fn from<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let item = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Return ? ToTemporalYearMonth(item, options).
+        let plain_year_month = to_temporal_year_month(agent, item.unbind(), options.unbind(), gc)?;
+        Ok(agent
+            .heap
+            .create(PlainYearMonthRecord {
+                object_index: None,
+                plain_year_month,
+            })
+            .into())
+    }
+
+    /// ### [9.2.3 Temporal.PlainYearMonth.compare ( one, two )](https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.compare)
+    fn compare<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let one = args.get(0).bind(gc.nogc());
+        let two = args.get(1).scope(agent, gc.nogc());
+        // 1. Set one to ? ToTemporalYearMonth(one).
+        let one = to_temporal_year_month(agent, one.unbind(), Value::Undefined, gc.reborrow())
+            .unbind()?;
+        // 2. Set two to ? ToTemporalYearMonth(two).
+        let two = to_temporal_year_month(agent, two.get(agent), Value::Undefined, gc)?;
+        // 3. Return 𝔽(CompareISODate(one.[[ISODate]], two.[[ISODate]])).
+        Ok((one.compare_iso(&two) as i8).into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>, _gc: NoGcScope) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let plain_year_month_prototype = intrinsics.temporal_plain_year_month_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<TemporalPlainYearMonthConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(3)
+        .with_prototype_property(plain_year_month_prototype.into())
+        .with_builtin_function_property::<TemporalPlainYearMonthFrom>()
+        .with_builtin_function_property::<TemporalPlainYearMonthCompare>()
+        .build();
+    }
+}
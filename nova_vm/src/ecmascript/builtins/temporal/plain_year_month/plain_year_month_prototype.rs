@@ -0,0 +1,787 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use temporal_rs::options::{DisplayCalendar, Overflow};
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, Builtin, BuiltinGetter,
+        CalendarFieldNames, ExceptionType, JsResult, Object, PlainDateRecord, PlainYearMonthRecord,
+        PropertyKey, Realm, String, Value,
+        builders::OrdinaryObjectBuilder,
+        builtins::temporal::plain_year_month::{
+            add_duration_to_year_month, difference_temporal_plain_year_month,
+            require_internal_slot_temporal_plain_year_month,
+        },
+        get_options_object, get_temporal_show_calendar_name_option, is_partial_temporal_object,
+        prepare_calendar_fields, resolve_temporal_overflow_option, temporal_err_to_js_err,
+        to_temporal_year_month,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{CreateHeapData, WellKnownSymbols},
+};
+
+pub(crate) struct TemporalPlainYearMonthPrototype;
+
+struct TemporalPlainYearMonthPrototypeGetCalendarId;
+impl Builtin for TemporalPlainYearMonthPrototypeGetCalendarId {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_calendarId;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.calendarId.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainYearMonthPrototype::get_calendar_id);
+}
+impl BuiltinGetter for TemporalPlainYearMonthPrototypeGetCalendarId {}
+
+struct TemporalPlainYearMonthPrototypeGetEra;
+impl Builtin for TemporalPlainYearMonthPrototypeGetEra {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_era;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.era.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainYearMonthPrototype::get_era);
+}
+impl BuiltinGetter for TemporalPlainYearMonthPrototypeGetEra {}
+
+struct TemporalPlainYearMonthPrototypeGetEraYear;
+impl Builtin for TemporalPlainYearMonthPrototypeGetEraYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_eraYear;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.eraYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainYearMonthPrototype::get_era_year);
+}
+impl BuiltinGetter for TemporalPlainYearMonthPrototypeGetEraYear {}
+
+struct TemporalPlainYearMonthPrototypeGetYear;
+impl Builtin for TemporalPlainYearMonthPrototypeGetYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_year;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.year.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainYearMonthPrototype::get_year);
+}
+impl BuiltinGetter for TemporalPlainYearMonthPrototypeGetYear {}
+
+struct TemporalPlainYearMonthPrototypeGetMonth;
+impl Builtin for TemporalPlainYearMonthPrototypeGetMonth {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_month;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.month.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainYearMonthPrototype::get_month);
+}
+impl BuiltinGetter for TemporalPlainYearMonthPrototypeGetMonth {}
+
+struct TemporalPlainYearMonthPrototypeGetMonthCode;
+impl Builtin for TemporalPlainYearMonthPrototypeGetMonthCode {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_monthCode;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.monthCode.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainYearMonthPrototype::get_month_code);
+}
+impl BuiltinGetter for TemporalPlainYearMonthPrototypeGetMonthCode {}
+
+struct TemporalPlainYearMonthPrototypeGetDaysInYear;
+impl Builtin for TemporalPlainYearMonthPrototypeGetDaysInYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainYearMonthPrototype::get_days_in_year);
+}
+impl BuiltinGetter for TemporalPlainYearMonthPrototypeGetDaysInYear {}
+
+struct TemporalPlainYearMonthPrototypeGetDaysInMonth;
+impl Builtin for TemporalPlainYearMonthPrototypeGetDaysInMonth {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInMonth;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInMonth.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainYearMonthPrototype::get_days_in_month);
+}
+impl BuiltinGetter for TemporalPlainYearMonthPrototypeGetDaysInMonth {}
+
+struct TemporalPlainYearMonthPrototypeGetMonthsInYear;
+impl Builtin for TemporalPlainYearMonthPrototypeGetMonthsInYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_monthsInYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.monthsInYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainYearMonthPrototype::get_months_in_year);
+}
+impl BuiltinGetter for TemporalPlainYearMonthPrototypeGetMonthsInYear {}
+
+struct TemporalPlainYearMonthPrototypeGetInLeapYear;
+impl Builtin for TemporalPlainYearMonthPrototypeGetInLeapYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_inLeapYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.inLeapYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainYearMonthPrototype::get_in_leap_year);
+}
+impl BuiltinGetter for TemporalPlainYearMonthPrototypeGetInLeapYear {}
+
+struct TemporalPlainYearMonthPrototypeWith;
+impl Builtin for TemporalPlainYearMonthPrototypeWith {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.with;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainYearMonthPrototype::with);
+}
+
+struct TemporalPlainYearMonthPrototypeAdd;
+impl Builtin for TemporalPlainYearMonthPrototypeAdd {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.add;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainYearMonthPrototype::add);
+}
+
+struct TemporalPlainYearMonthPrototypeSubtract;
+impl Builtin for TemporalPlainYearMonthPrototypeSubtract {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.subtract;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainYearMonthPrototype::subtract);
+}
+
+struct TemporalPlainYearMonthPrototypeUntil;
+impl Builtin for TemporalPlainYearMonthPrototypeUntil {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.until;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainYearMonthPrototype::until);
+}
+
+struct TemporalPlainYearMonthPrototypeSince;
+impl Builtin for TemporalPlainYearMonthPrototypeSince {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.since;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainYearMonthPrototype::since);
+}
+
+struct TemporalPlainYearMonthPrototypeEquals;
+impl Builtin for TemporalPlainYearMonthPrototypeEquals {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.equals;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainYearMonthPrototype::equals);
+}
+
+struct TemporalPlainYearMonthPrototypeToString;
+impl Builtin for TemporalPlainYearMonthPrototypeToString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toString;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainYearMonthPrototype::to_string);
+}
+
+struct TemporalPlainYearMonthPrototypeToLocaleString;
+impl Builtin for TemporalPlainYearMonthPrototypeToLocaleString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toLocaleString;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalPlainYearMonthPrototype::to_locale_string);
+}
+
+struct TemporalPlainYearMonthPrototypeToJSON;
+impl Builtin for TemporalPlainYearMonthPrototypeToJSON {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toJSON;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainYearMonthPrototype::to_json);
+}
+
+struct TemporalPlainYearMonthPrototypeValueOf;
+impl Builtin for TemporalPlainYearMonthPrototypeValueOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.valueOf;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainYearMonthPrototype::value_of);
+}
+
+struct TemporalPlainYearMonthPrototypeToPlainDate;
+impl Builtin for TemporalPlainYearMonthPrototypeToPlainDate {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toPlainDate;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalPlainYearMonthPrototype::to_plain_date);
+}
+
+impl TemporalPlainYearMonthPrototype {
+    /// ### [9.3.3 get Temporal.PlainYearMonth.prototype.calendarId](https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.calendarid)
+    fn get_calendar_id<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc)?;
+        // 3. Return yearMonth.[[Calendar]].
+        let value = plain_year_month
+            .inner_plain_year_month(agent)
+            .calendar()
+            .identifier();
+        Ok(Value::from_static_str(agent, value, gc))
+    }
+
+    /// ### [9.3.4 get Temporal.PlainYearMonth.prototype.era](https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.era)
+    fn get_era<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc)?;
+        // 3. Return CalendarISOToDate(yearMonth.[[Calendar]], yearMonth.[[ISODate]]).[[Era]].
+        let value = plain_year_month.inner_plain_year_month(agent).era();
+        Ok(value.map_or(Value::Undefined, |era| {
+            Value::from_str(agent, era.as_str(), gc)
+        }))
+    }
+
+    /// ### [9.3.5 get Temporal.PlainYearMonth.prototype.eraYear](https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.erayear)
+    fn get_era_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(yearMonth.[[Calendar]], yearMonth.[[ISODate]]).[[EraYear]]).
+        let value = plain_year_month.inner_plain_year_month(agent).era_year();
+        Ok(value.into())
+    }
+
+    /// ### [9.3.6 get Temporal.PlainYearMonth.prototype.year](https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.year)
+    fn get_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(yearMonth.[[Calendar]], yearMonth.[[ISODate]]).[[Year]]).
+        let value = plain_year_month.inner_plain_year_month(agent).year();
+        Ok(value.into())
+    }
+
+    /// ### [9.3.7 get Temporal.PlainYearMonth.prototype.month](https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.month)
+    fn get_month<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(yearMonth.[[Calendar]], yearMonth.[[ISODate]]).[[Month]]).
+        let value = plain_year_month.inner_plain_year_month(agent).month();
+        Ok(value.into())
+    }
+
+    /// ### [9.3.8 get Temporal.PlainYearMonth.prototype.monthCode](https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.monthcode)
+    fn get_month_code<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc)?;
+        // 3. Return CalendarISOToDate(yearMonth.[[Calendar]], yearMonth.[[ISODate]]).[[MonthCode]].
+        let value = plain_year_month.inner_plain_year_month(agent).month_code();
+        Ok(Value::from_str(agent, value.as_str(), gc))
+    }
+
+    /// ### [9.3.9 get Temporal.PlainYearMonth.prototype.daysInYear](https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.daysinyear)
+    fn get_days_in_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(yearMonth.[[Calendar]], yearMonth.[[ISODate]]).[[DaysInYear]]).
+        let value = plain_year_month
+            .inner_plain_year_month(agent)
+            .days_in_year();
+        Ok(value.into())
+    }
+
+    /// ### [9.3.10 get Temporal.PlainYearMonth.prototype.daysInMonth](https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.daysinmonth)
+    fn get_days_in_month<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(yearMonth.[[Calendar]], yearMonth.[[ISODate]]).[[DaysInMonth]]).
+        let value = plain_year_month
+            .inner_plain_year_month(agent)
+            .days_in_month();
+        Ok(value.into())
+    }
+
+    /// ### [9.3.11 get Temporal.PlainYearMonth.prototype.monthsInYear](https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.monthsinyear)
+    fn get_months_in_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(yearMonth.[[Calendar]], yearMonth.[[ISODate]]).[[MonthsInYear]]).
+        let value = plain_year_month
+            .inner_plain_year_month(agent)
+            .months_in_year();
+        Ok(value.into())
+    }
+
+    /// ### [9.3.12 get Temporal.PlainYearMonth.prototype.inLeapYear](https://tc39.es/proposal-temporal/#sec-get-temporal.plainyearmonth.prototype.inleapyear)
+    fn get_in_leap_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc)?;
+        // 3. Return 𝔽(CalendarISOToDate(yearMonth.[[Calendar]], yearMonth.[[ISODate]]).[[InLeapYear]]).
+        let value = plain_year_month
+            .inner_plain_year_month(agent)
+            .in_leap_year();
+        Ok(value.into())
+    }
+
+    /// ### [9.3.13 Temporal.PlainYearMonth.prototype.with ( temporalYearMonthLike [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.with)
+    fn with<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let temporal_year_month_like = args.get(0).scope(agent, gc.nogc());
+        let options = args.get(1).scope(agent, gc.nogc());
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. If ? IsPartialTemporalObject(temporalYearMonthLike) is false,
+        //    throw a TypeError exception.
+        if !is_partial_temporal_object(agent, temporal_year_month_like.get(agent), gc.reborrow())
+            .unbind()?
+        {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Argument is not a partial Temporal.PlainYearMonth-like object",
+                gc.into_nogc(),
+            ));
+        }
+        let temporal_year_month_like =
+            Object::try_from(temporal_year_month_like.get(agent)).unwrap();
+        // 4. Let calendar be yearMonth.[[Calendar]].
+        let calendar = plain_year_month
+            .get(agent)
+            .inner_plain_year_month(agent)
+            .calendar()
+            .clone();
+        // 5. Let fields be ISODateToFields(calendar, yearMonth.[[ISODate]], year-month).
+        // 6. Let partialYearMonth be ? PrepareCalendarFields(calendar,
+        //    temporalYearMonthLike, « year, month, month-code », « », partial).
+        let partial_year_month = prepare_calendar_fields(
+            agent,
+            &calendar,
+            temporal_year_month_like,
+            CalendarFieldNames::YEAR_MONTH,
+            true,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 7. Set fields to CalendarMergeFields(calendar, fields, partialYearMonth).
+        // 8. Let resolvedOptions be ? GetOptionsObject(options).
+        // 9. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let overflow =
+            resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let calendar_fields =
+            partial_year_month.to_year_month_calendar_fields(agent, overflow, gc)?;
+        // 10. Let isoDate be ? CalendarYearMonthFromFields(calendar, fields, overflow).
+        let plain_year_month = plain_year_month
+            .get(agent)
+            .inner_plain_year_month(agent)
+            .with(calendar_fields, Some(overflow))
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        // 11. Return ! CreateTemporalYearMonth(isoDate, calendar).
+        Ok(agent
+            .heap
+            .create(PlainYearMonthRecord {
+                object_index: None,
+                plain_year_month,
+            })
+            .into())
+    }
+
+    /// ### [9.3.14 Temporal.PlainYearMonth.prototype.add ( temporalDurationLike [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.add)
+    fn add<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let duration = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc.nogc())
+                .unbind()?
+                .bind(gc.nogc());
+        // 3. Return ? AddDurationToYearMonth(add, yearMonth, temporalDurationLike, options).
+        const ADD: bool = true;
+        add_duration_to_year_month::<ADD>(
+            agent,
+            plain_year_month.unbind(),
+            duration.unbind(),
+            options.unbind(),
+            gc,
+        )
+        .map(Value::from)
+    }
+
+    /// ### [9.3.15 Temporal.PlainYearMonth.prototype.subtract ( temporalDurationLike [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.subtract)
+    fn subtract<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let duration = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc.nogc())
+                .unbind()?
+                .bind(gc.nogc());
+        // 3. Return ? AddDurationToYearMonth(subtract, yearMonth, temporalDurationLike, options).
+        const SUBTRACT: bool = false;
+        add_duration_to_year_month::<SUBTRACT>(
+            agent,
+            plain_year_month.unbind(),
+            duration.unbind(),
+            options.unbind(),
+            gc,
+        )
+        .map(Value::from)
+    }
+
+    /// ### [9.3.16 Temporal.PlainYearMonth.prototype.until ( other [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.until)
+    fn until<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let other = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc.nogc())
+                .unbind()?
+                .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalPlainYearMonth(until, yearMonth, other, options).
+        const UNTIL: bool = true;
+        difference_temporal_plain_year_month::<UNTIL>(
+            agent,
+            plain_year_month.unbind(),
+            other.unbind(),
+            options.unbind(),
+            gc,
+        )
+        .map(Value::from)
+    }
+
+    /// ### [9.3.17 Temporal.PlainYearMonth.prototype.since ( other [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.since)
+    fn since<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let other = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc.nogc())
+                .unbind()?
+                .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalPlainYearMonth(since, yearMonth, other, options).
+        const SINCE: bool = false;
+        difference_temporal_plain_year_month::<SINCE>(
+            agent,
+            plain_year_month.unbind(),
+            other.unbind(),
+            options.unbind(),
+            gc,
+        )
+        .map(Value::from)
+    }
+
+    /// ### [9.3.18 Temporal.PlainYearMonth.prototype.equals ( other )](https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.equals)
+    fn equals<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let other = args.get(0).bind(gc.nogc());
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. Set other to ? ToTemporalYearMonth(other).
+        let other = to_temporal_year_month(agent, other.unbind(), Value::Undefined, gc.reborrow())
+            .unbind()?;
+        // 4. If CompareISODate(yearMonth.[[ISODate]], other.[[ISODate]]) ≠ 0, return false.
+        // 5. Return CalendarEquals(yearMonth.[[Calendar]], other.[[Calendar]]).
+        Ok((plain_year_month.get(agent).inner_plain_year_month(agent) == &other).into())
+    }
+
+    /// ### [9.3.19 Temporal.PlainYearMonth.prototype.toString ( [ options ] )](https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.tostring)
+    fn to_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let options = args.get(0).bind(gc.nogc());
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let resolved_options = get_options_object(agent, options, gc.nogc())
+            .unbind()?
+            .bind(gc.nogc());
+        // 4. Let showCalendar be ? GetTemporalShowCalendarNameOption(resolvedOptions).
+        let show_calendar = match resolved_options {
+            Some(resolved_options) => get_temporal_show_calendar_name_option(
+                agent,
+                resolved_options.unbind(),
+                gc.reborrow(),
+            )
+            .unbind()?,
+            None => DisplayCalendar::default(),
+        };
+        let gc = gc.into_nogc();
+        // 5. Return TemporalYearMonthToString(yearMonth, showCalendar).
+        let string = plain_year_month
+            .get(agent)
+            .inner_plain_year_month(agent)
+            .to_ixdtf_string(show_calendar);
+        Ok(Value::from_string(agent, string, gc))
+    }
+
+    /// ### [9.3.20 Temporal.PlainYearMonth.prototype.toLocaleString ( [ locales [ , options ] ] )](https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.tolocalestring)
+    ///
+    /// An ECMAScript implementation that includes the ECMA-402
+    /// Internationalization API must implement this method as specified in the
+    /// ECMA-402 specification. If an ECMAScript implementation does not
+    /// include the ECMA-402 API the following specification of this method is
+    /// used.
+    fn to_locale_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc)?;
+        // 3. Return TemporalYearMonthToString(yearMonth, auto).
+        let string = plain_year_month
+            .inner_plain_year_month(agent)
+            .to_ixdtf_string(DisplayCalendar::Auto);
+        Ok(Value::from_string(agent, string, gc))
+    }
+
+    /// ### [9.3.21 Temporal.PlainYearMonth.prototype.toJSON ( )](https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.tojson)
+    fn to_json<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc)?;
+        // 3. Return TemporalYearMonthToString(yearMonth, auto).
+        let string = plain_year_month
+            .inner_plain_year_month(agent)
+            .to_ixdtf_string(DisplayCalendar::Auto);
+        Ok(Value::from_string(agent, string, gc))
+    }
+
+    /// ### [9.3.22 Temporal.PlainYearMonth.prototype.valueOf ( )](https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.valueof)
+    fn value_of<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Throw a TypeError exception.
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "`valueOf` not supported by Temporal built-ins. See 'compare', 'equals', or `toString`",
+            gc.into_nogc(),
+        ))
+    }
+
+    /// ### [9.3.23 Temporal.PlainYearMonth.prototype.toPlainDate ( item )](https://tc39.es/proposal-temporal/#sec-temporal.plainyearmonth.prototype.toplaindate)
+    fn to_plain_date<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let item = args.get(0).bind(gc.nogc());
+        // 1. Let yearMonth be the this value.
+        // 2. Perform ? RequireInternalSlot(yearMonth, [[InitializedTemporalYearMonth]]).
+        let plain_year_month =
+            require_internal_slot_temporal_plain_year_month(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. If item is not an Object, then
+        let Ok(item) = Object::try_from(item) else {
+            // a. Throw a TypeError exception.
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Argument is not an object",
+                gc.into_nogc(),
+            ));
+        };
+        // 4. Let calendar be yearMonth.[[Calendar]].
+        let calendar = plain_year_month
+            .get(agent)
+            .inner_plain_year_month(agent)
+            .calendar()
+            .clone();
+        // 5. Let fields be ISODateToFields(calendar, yearMonth.[[ISODate]], year-month).
+        // 6. Let inputFields be ? PrepareCalendarFields(calendar, item, « day », « », « »).
+        let input_fields = prepare_calendar_fields(
+            agent,
+            &calendar,
+            item.unbind(),
+            CalendarFieldNames::DAY,
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let input_fields = input_fields.to_calendar_fields(agent, Overflow::Constrain, gc)?;
+        // 7. Let mergedFields be CalendarMergeFields(calendar, fields, inputFields).
+        // 8. Let isoDate be ? CalendarDateFromFields(calendar, mergedFields, constrain).
+        let plain_date = plain_year_month
+            .get(agent)
+            .inner_plain_year_month(agent)
+            .to_plain_date(Some(input_fields))
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        // 9. Return ! CreateTemporalDate(isoDate, calendar).
+        Ok(agent
+            .heap
+            .create(PlainDateRecord {
+                object_index: None,
+                plain_date,
+            })
+            .into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>, _: NoGcScope) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let this = intrinsics.temporal_plain_year_month_prototype();
+        let object_prototype = intrinsics.object_prototype();
+        let plain_year_month_constructor = intrinsics.temporal_plain_year_month();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(23)
+            .with_prototype(object_prototype)
+            .with_constructor_property(plain_year_month_constructor)
+            .with_builtin_function_getter_property::<TemporalPlainYearMonthPrototypeGetCalendarId>()
+            .with_builtin_function_getter_property::<TemporalPlainYearMonthPrototypeGetEra>()
+            .with_builtin_function_getter_property::<TemporalPlainYearMonthPrototypeGetEraYear>()
+            .with_builtin_function_getter_property::<TemporalPlainYearMonthPrototypeGetYear>()
+            .with_builtin_function_getter_property::<TemporalPlainYearMonthPrototypeGetMonth>()
+            .with_builtin_function_getter_property::<TemporalPlainYearMonthPrototypeGetMonthCode>()
+            .with_builtin_function_getter_property::<TemporalPlainYearMonthPrototypeGetDaysInYear>()
+            .with_builtin_function_getter_property::<TemporalPlainYearMonthPrototypeGetDaysInMonth>()
+            .with_builtin_function_getter_property::<TemporalPlainYearMonthPrototypeGetMonthsInYear>()
+            .with_builtin_function_getter_property::<TemporalPlainYearMonthPrototypeGetInLeapYear>()
+            .with_builtin_function_property::<TemporalPlainYearMonthPrototypeWith>()
+            .with_builtin_function_property::<TemporalPlainYearMonthPrototypeAdd>()
+            .with_builtin_function_property::<TemporalPlainYearMonthPrototypeSubtract>()
+            .with_builtin_function_property::<TemporalPlainYearMonthPrototypeUntil>()
+            .with_builtin_function_property::<TemporalPlainYearMonthPrototypeSince>()
+            .with_builtin_function_property::<TemporalPlainYearMonthPrototypeEquals>()
+            .with_builtin_function_property::<TemporalPlainYearMonthPrototypeToString>()
+            .with_builtin_function_property::<TemporalPlainYearMonthPrototypeToLocaleString>()
+            .with_builtin_function_property::<TemporalPlainYearMonthPrototypeToJSON>()
+            .with_builtin_function_property::<TemporalPlainYearMonthPrototypeValueOf>()
+            .with_builtin_function_property::<TemporalPlainYearMonthPrototypeToPlainDate>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Temporal_PlainYearMonth.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
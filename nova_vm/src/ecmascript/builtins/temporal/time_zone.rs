@@ -0,0 +1,51 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use temporal_rs::TimeZone;
+
+use crate::{
+    ecmascript::{Agent, ExceptionType, JsResult, String, Value, temporal_err_to_js_err},
+    engine::{Bindable, NoGcScope, trivially_bindable},
+};
+
+trivially_bindable!(TimeZone);
+
+/// ### [11.1.9 ToTemporalTimeZoneIdentifier ( temporalTimeZoneLike )](https://tc39.es/proposal-temporal/#sec-temporal-totemporaltimezoneidentifier)
+///
+/// The abstract operation ToTemporalTimeZoneIdentifier takes argument
+/// temporalTimeZoneLike (an ECMAScript language value) and returns either a
+/// normal completion containing an available time zone identifier or a throw
+/// completion. It attempts to derive an available time zone identifier from
+/// temporalTimeZoneLike.
+pub(crate) fn to_temporal_time_zone_identifier<'gc>(
+    agent: &mut Agent,
+    temporal_time_zone_like: Value,
+    gc: NoGcScope<'gc, '_>,
+) -> JsResult<'gc, TimeZone> {
+    let temporal_time_zone_like = temporal_time_zone_like.bind(gc);
+    // 1. If temporalTimeZoneLike is an Object, then
+    //     a. If temporalTimeZoneLike has an [[InitializedTemporalZonedDateTime]]
+    //        internal slot, then
+    //         i. Return temporalTimeZoneLike.[[TimeZone]].
+    if let Value::ZonedDateTime(zoned_date_time) = temporal_time_zone_like {
+        return Ok(*zoned_date_time.inner_zoned_date_time(agent).time_zone());
+    }
+    // 2. If temporalTimeZoneLike is not a String, throw a TypeError exception.
+    let Ok(temporal_time_zone_like) = String::try_from(temporal_time_zone_like) else {
+        return Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Time zone must be a string",
+            gc,
+        ));
+    };
+    // 3. Let parseResult be ? ParseTemporalTimeZoneString(temporalTimeZoneLike).
+    // 4. Let offsetMinutes be parseResult.[[OffsetMinutes]].
+    // 5. If offsetMinutes is not empty, return FormatOffsetTimeZoneIdentifier(offsetMinutes).
+    // 6. Let name be parseResult.[[Name]].
+    // 7. Let timeZoneIdentifierRecord be GetAvailableNamedTimeZoneIdentifier(name).
+    // 8. If timeZoneIdentifierRecord is empty, throw a RangeError exception.
+    // 9. Return timeZoneIdentifierRecord.[[Identifier]].
+    let identifier = temporal_time_zone_like.to_string_lossy(agent);
+    TimeZone::try_from_str(&identifier).map_err(|err| temporal_err_to_js_err(agent, err, gc))
+}
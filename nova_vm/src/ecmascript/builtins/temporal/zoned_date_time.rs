@@ -0,0 +1,444 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+mod data;
+mod zoned_date_time_constructor;
+mod zoned_date_time_prototype;
+
+pub(crate) use data::*;
+pub(crate) use zoned_date_time_constructor::*;
+pub(crate) use zoned_date_time_prototype::*;
+
+use temporal_rs::{
+    fields::ZonedDateTimeFields,
+    options::{Disambiguation, OffsetDisambiguation, Overflow, Unit, UnitGroup},
+    partial::PartialZonedDateTime,
+};
+
+use crate::{
+    ecmascript::{
+        Agent, CalendarFieldNames, DurationRecord, ExceptionType, Function, InternalMethods,
+        InternalSlots, JsResult, Object, OrdinaryObject, ProtoIntrinsics, String, TemporalDuration,
+        Value, get_difference_settings, get_options_object,
+        get_temporal_calendar_identifier_with_iso_default, get_temporal_disambiguation_option,
+        get_temporal_offset_option, get_temporal_overflow_option, object_handle,
+        ordinary_populate_from_constructor, prepare_calendar_fields,
+        resolve_temporal_overflow_option, temporal_err_to_js_err, to_temporal_duration,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{
+        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, Heap,
+        HeapMarkAndSweep, HeapSweepWeakReference, WorkQueues, arena_vec_access,
+    },
+};
+
+/// # [6 Temporal.ZonedDateTime Objects](https://tc39.es/proposal-temporal/#sec-temporal-zoneddatetime-objects)
+///
+/// A Temporal.ZonedDateTime object is an Object referencing a fixed point in
+/// time with nanoseconds precision, and containing Object values
+/// corresponding to a particular time zone and calendar system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct TemporalZonedDateTime<'a>(BaseIndex<'a, ZonedDateTimeRecord<'static>>);
+object_handle!(TemporalZonedDateTime, ZonedDateTime);
+arena_vec_access!(
+    TemporalZonedDateTime,
+    'a,
+    ZonedDateTimeRecord,
+    zoned_date_times
+);
+
+impl TemporalZonedDateTime<'_> {
+    pub(crate) fn inner_zoned_date_time(self, agent: &Agent) -> &temporal_rs::ZonedDateTime {
+        &self.unbind().get(agent).zoned_date_time
+    }
+}
+
+impl<'a> InternalSlots<'a> for TemporalZonedDateTime<'a> {
+    const DEFAULT_PROTOTYPE: ProtoIntrinsics = ProtoIntrinsics::TemporalZonedDateTime;
+    fn get_backing_object(self, agent: &Agent) -> Option<OrdinaryObject<'static>> {
+        self.get(agent).object_index.unbind()
+    }
+    fn set_backing_object(self, agent: &mut Agent, backing_object: OrdinaryObject<'static>) {
+        assert!(
+            self.get_mut(agent)
+                .object_index
+                .replace(backing_object)
+                .is_none()
+        );
+    }
+}
+
+impl<'a> InternalMethods<'a> for TemporalZonedDateTime<'a> {}
+
+impl HeapMarkAndSweep for TemporalZonedDateTime<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        queues.zoned_date_times.push(*self);
+    }
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        compactions.zoned_date_times.shift_index(&mut self.0);
+    }
+}
+
+impl HeapSweepWeakReference for TemporalZonedDateTime<'static> {
+    fn sweep_weak_reference(self, compactions: &CompactionLists) -> Option<Self> {
+        compactions
+            .zoned_date_times
+            .shift_weak_index(self.0)
+            .map(Self)
+    }
+}
+
+impl<'a> CreateHeapData<ZonedDateTimeRecord<'a>, TemporalZonedDateTime<'a>> for Heap {
+    fn create(&mut self, data: ZonedDateTimeRecord<'a>) -> TemporalZonedDateTime<'a> {
+        self.zoned_date_times.push(data.unbind());
+        self.alloc_counter += core::mem::size_of::<ZonedDateTimeRecord<'static>>();
+        TemporalZonedDateTime(BaseIndex::last(&self.zoned_date_times))
+    }
+}
+
+#[inline(always)]
+fn require_internal_slot_temporal_zoned_date_time<'a>(
+    agent: &mut Agent,
+    value: Value,
+    gc: NoGcScope<'a, '_>,
+) -> JsResult<'a, TemporalZonedDateTime<'a>> {
+    match value {
+        Value::ZonedDateTime(zoned_date_time) => Ok(zoned_date_time.bind(gc)),
+        _ => Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "Object is not a Temporal ZonedDateTime",
+            gc,
+        )),
+    }
+}
+
+/// ### [6.5.3 CreateTemporalZonedDateTime ( epochNanoseconds, timeZone, calendar [ , newTarget ] )](https://tc39.es/proposal-temporal/#sec-temporal-createtemporalzoneddatetime)
+pub(crate) fn create_temporal_zoned_date_time<'gc>(
+    agent: &mut Agent,
+    zoned_date_time: temporal_rs::ZonedDateTime,
+    new_target: Option<Function>,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, TemporalZonedDateTime<'gc>> {
+    // 1. If newTarget is not present, set newTarget to %Temporal.ZonedDateTime%.
+    let new_target = new_target.unwrap_or_else(|| {
+        agent
+            .current_realm_record()
+            .intrinsics()
+            .temporal_zoned_date_time()
+            .into()
+    });
+    // 2. Let object be ? OrdinaryCreateFromConstructor(newTarget, "%Temporal.ZonedDateTime.prototype%", « [[InitializedTemporalZonedDateTime]], [[EpochNanoseconds]], [[TimeZone]], [[Calendar]] »).
+    // 3. Set the internal slots of object.
+    // 4. Return object.
+    let object = agent.heap.create(ZonedDateTimeRecord {
+        object_index: None,
+        zoned_date_time,
+    });
+    Ok(
+        TemporalZonedDateTime::try_from(ordinary_populate_from_constructor(
+            agent,
+            object.unbind().into(),
+            new_target,
+            ProtoIntrinsics::TemporalZonedDateTime,
+            gc,
+        )?)
+        .unwrap(),
+    )
+}
+
+/// Reads the "disambiguation", "offset" and "overflow" options, in that order,
+/// as is done by ToTemporalZonedDateTime and
+/// Temporal.ZonedDateTime.prototype.with.
+fn get_zoned_date_time_options<'gc>(
+    agent: &mut Agent,
+    options: Value,
+    offset_fallback: OffsetDisambiguation,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, (Disambiguation, OffsetDisambiguation, Overflow)> {
+    // 1. Let resolvedOptions be ? GetOptionsObject(options).
+    let Some(resolved_options) = get_options_object(agent, options, gc.nogc())
+        .unbind()?
+        .map(|r| r.scope(agent, gc.nogc()))
+    else {
+        return Ok((
+            Disambiguation::default(),
+            offset_fallback,
+            Overflow::default(),
+        ));
+    };
+    // 2. Let disambiguation be ? GetTemporalDisambiguationOption(resolvedOptions).
+    let disambiguation =
+        get_temporal_disambiguation_option(agent, resolved_options.get(agent), gc.reborrow())
+            .unbind()?;
+    // 3. Let offsetOption be ? GetTemporalOffsetOption(resolvedOptions, fallback).
+    let offset_option = get_temporal_offset_option(
+        agent,
+        resolved_options.get(agent),
+        offset_fallback,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 4. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+    let overflow = get_temporal_overflow_option(agent, resolved_options.get(agent), gc)?;
+    Ok((disambiguation, offset_option, overflow))
+}
+
+/// ### [6.5.2 ToTemporalZonedDateTime ( item [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal-totemporalzoneddatetime)
+///
+/// The abstract operation ToTemporalZonedDateTime takes argument item (an
+/// ECMAScript language value) and optional argument options (an ECMAScript
+/// language value) and returns either a normal completion containing a
+/// Temporal.ZonedDateTime, or a throw completion. It returns its argument item
+/// if it is already a Temporal.ZonedDateTime instance, converts item to a new
+/// Temporal.ZonedDateTime instance if possible, and throws otherwise.
+pub(crate) fn to_temporal_zoned_date_time<'gc>(
+    agent: &mut Agent,
+    item: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, temporal_rs::ZonedDateTime> {
+    let item = item.bind(gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 1. If options is not present, set options to undefined.
+    // 2. Let offsetBehaviour be option.
+    // 3. Let matchBehaviour be match-exactly.
+    // 4. If item is an Object, then
+    let result = if let Ok(item) = Object::try_from(item) {
+        // a. If item has an [[InitializedTemporalZonedDateTime]] internal slot, then
+        if let Object::ZonedDateTime(item) = item {
+            let zoned_date_time = item.inner_zoned_date_time(agent).clone();
+            // i. NOTE: The following steps, and similar ones below, read
+            //    options and perform independent validation in alphabetical
+            //    order (GetTemporalDisambiguationOption reads
+            //    "disambiguation", GetTemporalOffsetOption reads "offset",
+            //    and GetTemporalOverflowOption reads "overflow").
+            // ii. Let resolvedOptions be ? GetOptionsObject(options).
+            // iii. Perform ? GetTemporalDisambiguationOption(resolvedOptions).
+            // iv. Perform ? GetTemporalOffsetOption(resolvedOptions, reject).
+            // v. Perform ? GetTemporalOverflowOption(resolvedOptions).
+            get_zoned_date_time_options(
+                agent,
+                options.get(agent),
+                OffsetDisambiguation::Reject,
+                gc.reborrow(),
+            )
+            .unbind()?;
+            // vi. Return ! CreateTemporalZonedDateTime(item.[[EpochNanoseconds]], item.[[TimeZone]], item.[[Calendar]]).
+            return Ok(zoned_date_time);
+        }
+        let item = item.scope(agent, gc.nogc());
+        // b. Let calendar be ? GetTemporalCalendarIdentifierWithISODefault(item).
+        let calendar = get_temporal_calendar_identifier_with_iso_default(
+            agent,
+            item.get(agent),
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // c. Let fields be ? PrepareCalendarFields(calendar, item, « year,
+        //    month, month-code, day », « hour, minute, second, millisecond,
+        //    microsecond, nanosecond, offset, time-zone », « time-zone »).
+        let fields = prepare_calendar_fields(
+            agent,
+            &calendar,
+            item.get(agent),
+            CalendarFieldNames::ZONED_DATE_TIME,
+            false,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // d. Let timeZone be fields.[[TimeZone]].
+        let Some(time_zone) = fields.time_zone else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "timeZone is required",
+                gc.into_nogc(),
+            ));
+        };
+        // e. Let offsetString be fields.[[OffsetString]].
+        // f. If offsetString is unset, then
+        //     i. Set offsetBehaviour to wall.
+        // g. Let resolvedOptions be ? GetOptionsObject(options).
+        // h. Let disambiguation be ? GetTemporalDisambiguationOption(resolvedOptions).
+        // i. Let offsetOption be ? GetTemporalOffsetOption(resolvedOptions, reject).
+        // j. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let (disambiguation, offset_option, overflow) = get_zoned_date_time_options(
+            agent,
+            options.get(agent),
+            OffsetDisambiguation::Reject,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // k. Let result be ? InterpretTemporalDateTimeFields(calendar, fields, overflow).
+        // l. Let isoDate be result.[[ISODate]].
+        // m. Let time be result.[[Time]].
+        let gc = gc.nogc();
+        let calendar_fields = fields.to_calendar_fields(agent, overflow, gc).unbind()?;
+        let time = fields.to_partial_time(agent, overflow, gc).unbind()?;
+        // 6. Let offsetNanoseconds be 0.
+        // 7. If offsetBehaviour is option, then
+        //     a. Set offsetNanoseconds to ! ParseDateTimeUTCOffset(offsetString).
+        // 8. Let epochNanoseconds be ? InterpretISODateTimeOffset(isoDate,
+        //    time, offsetBehaviour, offsetNanoseconds, timeZone,
+        //    disambiguation, offsetOption, matchBehaviour).
+        // 9. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        temporal_rs::ZonedDateTime::from_partial(
+            PartialZonedDateTime {
+                fields: ZonedDateTimeFields {
+                    calendar_fields,
+                    time,
+                    offset: fields.offset,
+                },
+                timezone: Some(time_zone),
+                calendar,
+            },
+            Some(overflow),
+            Some(disambiguation),
+            Some(offset_option),
+        )
+    } else {
+        // 5. Else,
+        //     a. If item is not a String, throw a TypeError exception.
+        let Ok(item) = String::try_from(item) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Item is not a String",
+                gc.into_nogc(),
+            ));
+        };
+        let item = item.scope(agent, gc.nogc());
+        //     b. Let result be ? ParseISODateTime(item, « TemporalDateTimeString[+Zoned] »).
+        //     c. Let annotation be result.[[TimeZone]].[[TimeZoneAnnotation]].
+        //     d. Assert: annotation is not empty.
+        //     e. Let timeZone be ? ToTemporalTimeZoneIdentifier(annotation).
+        //     f. Let offsetString be result.[[TimeZone]].[[OffsetString]].
+        //     g. If result.[[TimeZone]].[[Z]] is true, then
+        //         i. Set offsetBehaviour to exact.
+        //     h. Else if offsetString is empty, then
+        //         i. Set offsetBehaviour to wall.
+        //     i. Let calendar be result.[[Calendar]].
+        //     j. If calendar is empty, set calendar to "iso8601".
+        //     k. Set calendar to ? CanonicalizeCalendar(calendar).
+        //     l. Set matchBehaviour to match-minutes.
+        //     m. Let resolvedOptions be ? GetOptionsObject(options).
+        //     n. Let disambiguation be ? GetTemporalDisambiguationOption(resolvedOptions).
+        //     o. Let offsetOption be ? GetTemporalOffsetOption(resolvedOptions, reject).
+        //     p. Perform ? GetTemporalOverflowOption(resolvedOptions).
+        let (disambiguation, offset_option, _) = get_zoned_date_time_options(
+            agent,
+            options.get(agent),
+            OffsetDisambiguation::Reject,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        //     q. Let isoDate be CreateISODateRecord(result.[[Year]], result.[[Month]], result.[[Day]]).
+        //     r. Let time be result.[[Time]].
+        temporal_rs::ZonedDateTime::from_utf8(
+            item.get(agent).as_bytes(agent),
+            disambiguation,
+            offset_option,
+        )
+    };
+    result.map_err(|err| temporal_err_to_js_err(agent, err, gc.into_nogc()))
+}
+
+/// ### [6.5.10 AddDurationToZonedDateTime ( operation, zonedDateTime, temporalDurationLike, options )](https://tc39.es/proposal-temporal/#sec-temporal-adddurationtozoneddatetime)
+///
+/// The abstract operation AddDurationToZonedDateTime takes arguments operation
+/// (add or subtract), zonedDateTime (a Temporal.ZonedDateTime),
+/// temporalDurationLike (an ECMAScript language value), and options (an
+/// ECMAScript language value) and returns either a normal completion
+/// containing a Temporal.ZonedDateTime or a throw completion. It adds/subtracts
+/// temporalDurationLike to/from zonedDateTime.
+fn add_duration_to_zoned_date_time<'gc, const IS_ADD: bool>(
+    agent: &mut Agent,
+    zoned_date_time: TemporalZonedDateTime,
+    duration: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, TemporalZonedDateTime<'gc>> {
+    let zoned_date_time = zoned_date_time.scope(agent, gc.nogc());
+    let duration = duration.bind(gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 1. Let duration be ? ToTemporalDuration(temporalDurationLike).
+    let duration = to_temporal_duration(agent, duration.unbind(), gc.reborrow()).unbind()?;
+    // 2. If operation is subtract, set duration to CreateNegatedTemporalDuration(duration).
+    // 3. Let resolvedOptions be ? GetOptionsObject(options).
+    // 4. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+    let overflow =
+        resolve_temporal_overflow_option(agent, options.get(agent), gc.reborrow()).unbind()?;
+    // 5. Let calendar be zonedDateTime.[[Calendar]].
+    // 6. Let timeZone be zonedDateTime.[[TimeZone]].
+    // 7. Let internalDuration be ToInternalDurationRecord(duration).
+    // 8. Let epochNanoseconds be ? AddZonedDateTime(zonedDateTime.[[EpochNanoseconds]], timeZone, calendar, internalDuration, overflow).
+    let zoned_date_time = zoned_date_time.get(agent).inner_zoned_date_time(agent);
+    let result = if IS_ADD {
+        zoned_date_time.add(&duration, Some(overflow))
+    } else {
+        zoned_date_time.subtract(&duration, Some(overflow))
+    };
+    let gc = gc.into_nogc();
+    let zoned_date_time = result.map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+    // 9. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+    Ok(agent.heap.create(ZonedDateTimeRecord {
+        object_index: None,
+        zoned_date_time,
+    }))
+}
+
+/// ### [6.5.9 DifferenceTemporalZonedDateTime ( operation, zonedDateTime, other, options )](https://tc39.es/proposal-temporal/#sec-temporal-differencetemporalzoneddatetime)
+///
+/// The abstract operation DifferenceTemporalZonedDateTime takes arguments
+/// operation (since or until), zonedDateTime (a Temporal.ZonedDateTime), other
+/// (an ECMAScript language value), and options (an ECMAScript language value)
+/// and returns either a normal completion containing a Temporal.Duration or a
+/// throw completion. It computes the difference between the two times
+/// represented by zonedDateTime and other, optionally rounds it, and returns
+/// it as a Temporal.Duration object.
+fn difference_temporal_zoned_date_time<'gc, const IS_UNTIL: bool>(
+    agent: &mut Agent,
+    zoned_date_time: TemporalZonedDateTime,
+    other: Value,
+    options: Value,
+    mut gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, TemporalDuration<'gc>> {
+    let zoned_date_time = zoned_date_time.scope(agent, gc.nogc());
+    let other = other.bind(gc.nogc());
+    let options = options.scope(agent, gc.nogc());
+    // 1. Set other to ? ToTemporalZonedDateTime(other).
+    let other = to_temporal_zoned_date_time(agent, other.unbind(), Value::Undefined, gc.reborrow())
+        .unbind()?;
+    // 2. If CalendarEquals(zonedDateTime.[[Calendar]], other.[[Calendar]]) is
+    //    false, throw a RangeError exception.
+    // 3. Let resolvedOptions be ? GetOptionsObject(options).
+    let resolved_options = get_options_object(agent, options.get(agent), gc.nogc())
+        .unbind()?
+        .bind(gc.nogc());
+    // 4. Let settings be ? GetDifferenceSettings(operation, resolvedOptions,
+    //    datetime, « », nanosecond, hour).
+    let settings = get_difference_settings::<IS_UNTIL>(
+        agent,
+        resolved_options.unbind(),
+        UnitGroup::DateTime,
+        &[],
+        Unit::Nanosecond,
+        Unit::Hour,
+        gc.reborrow(),
+    )
+    .unbind()?;
+    // 5-13. Compute the difference, round it and create the duration.
+    let zoned_date_time = zoned_date_time.get(agent).inner_zoned_date_time(agent);
+    let duration = if IS_UNTIL {
+        zoned_date_time.until(&other, settings)
+    } else {
+        zoned_date_time.since(&other, settings)
+    };
+    let gc = gc.into_nogc();
+    let duration = duration.map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+    // 14. Return result.
+    Ok(agent.heap.create(DurationRecord {
+        object_index: None,
+        duration,
+    }))
+}
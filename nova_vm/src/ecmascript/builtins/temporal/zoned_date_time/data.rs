@@ -0,0 +1,55 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use crate::{
+    ecmascript::OrdinaryObject,
+    engine::{NoGcScope, bindable_handle, trivially_bindable},
+    heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+#[derive(Debug, Clone)]
+pub struct ZonedDateTimeRecord<'a> {
+    pub(crate) object_index: Option<OrdinaryObject<'a>>,
+    pub(crate) zoned_date_time: temporal_rs::ZonedDateTime,
+}
+
+impl ZonedDateTimeRecord<'_> {
+    pub fn default() -> Self {
+        Self {
+            object_index: None,
+            zoned_date_time: temporal_rs::ZonedDateTime::try_new(
+                0,
+                temporal_rs::TimeZone::utc(),
+                temporal_rs::Calendar::ISO,
+            )
+            .unwrap(),
+        }
+    }
+}
+
+trivially_bindable!(temporal_rs::ZonedDateTime);
+bindable_handle!(ZonedDateTimeRecord);
+
+// SAFETY: The calendar held by the record only references compiled calendar
+// data with a static lifetime, which is never mutated or dropped.
+unsafe impl Send for ZonedDateTimeRecord<'_> {}
+
+impl HeapMarkAndSweep for ZonedDateTimeRecord<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            object_index,
+            zoned_date_time: _,
+        } = self;
+
+        object_index.mark_values(queues);
+    }
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            object_index,
+            zoned_date_time: _,
+        } = self;
+
+        object_index.sweep_values(compactions);
+    }
+}
@@ -0,0 +1,164 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use temporal_rs::TimeZone;
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, BigInt, Builtin,
+        BuiltinIntrinsicConstructor, ExceptionType, Function, JsResult, Object, Realm, String,
+        Value, ZonedDateTimeRecord, builders::BuiltinFunctionBuilder,
+        create_temporal_zoned_date_time, temporal_err_to_js_err, to_big_int,
+        to_constructor_calendar, to_temporal_zoned_date_time,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{CreateHeapData, IntrinsicConstructorIndexes},
+};
+
+/// Constructor function object for %Temporal.ZonedDateTime%.
+pub(crate) struct TemporalZonedDateTimeConstructor;
+
+impl Builtin for TemporalZonedDateTimeConstructor {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.ZonedDateTime;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Constructor(TemporalZonedDateTimeConstructor::constructor);
+}
+impl BuiltinIntrinsicConstructor for TemporalZonedDateTimeConstructor {
+    const INDEX: IntrinsicConstructorIndexes = IntrinsicConstructorIndexes::TemporalZonedDateTime;
+}
+
+struct TemporalZonedDateTimeFrom;
+impl Builtin for TemporalZonedDateTimeFrom {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.from;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimeConstructor::from);
+}
+
+struct TemporalZonedDateTimeCompare;
+impl Builtin for TemporalZonedDateTimeCompare {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.compare;
+    const LENGTH: u8 = 2;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimeConstructor::compare);
+}
+
+impl TemporalZonedDateTimeConstructor {
+    /// ### [6.1.1 Temporal.ZonedDateTime ( epochNanoseconds, timeZone [ , calendar ] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime)
+    fn constructor<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        new_target: Option<Object>,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let epoch_nanoseconds = args.get(0).bind(gc.nogc());
+        let time_zone = args.get(1).scope(agent, gc.nogc());
+        let calendar = args.get(2).scope(agent, gc.nogc());
+        let new_target = new_target.bind(gc.nogc());
+        // 1. If NewTarget is undefined, throw a TypeError exception.
+        let Some(new_target) = new_target else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "calling a builtin Temporal.ZonedDateTime constructor without new is forbidden",
+                gc.into_nogc(),
+            ));
+        };
+        let Ok(new_target) = Function::try_from(new_target) else {
+            unreachable!()
+        };
+        let new_target = new_target.scope(agent, gc.nogc());
+        // 2. Set epochNanoseconds to ? ToBigInt(epochNanoseconds).
+        let epoch_nanoseconds = if let Ok(epoch_nanoseconds) = BigInt::try_from(epoch_nanoseconds) {
+            epoch_nanoseconds
+        } else {
+            to_big_int(agent, epoch_nanoseconds.unbind(), gc.reborrow())
+                .unbind()?
+                .bind(gc.nogc())
+        };
+        let epoch_nanoseconds = epoch_nanoseconds.try_into_i128(agent).unwrap_or(i128::MAX);
+        // 3. If IsValidEpochNanoseconds(epochNanoseconds) is false, throw a RangeError exception.
+        // 4. If timeZone is not a String, throw a TypeError exception.
+        let Ok(time_zone) = String::try_from(time_zone.get(agent)) else {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Time zone must be a string",
+                gc.into_nogc(),
+            ));
+        };
+        // 5. Let timeZoneParse be ? ParseTimeZoneIdentifier(timeZone).
+        // 6. If timeZoneParse.[[OffsetMinutes]] is empty, then
+        //     a. Let identifierRecord be GetAvailableNamedTimeZoneIdentifier(timeZoneParse.[[Name]]).
+        //     b. If identifierRecord is empty, throw a RangeError exception.
+        //     c. Set timeZone to identifierRecord.[[Identifier]].
+        // 7. Else,
+        //     a. Set timeZone to FormatOffsetTimeZoneIdentifier(timeZoneParse.[[OffsetMinutes]]).
+        let time_zone = TimeZone::try_from_identifier_str(&time_zone.to_string_lossy(agent))
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc.nogc()))
+            .unbind()?;
+        // 8. If calendar is undefined, set calendar to "iso8601".
+        // 9. If calendar is not a String, throw a TypeError exception.
+        // 10. Set calendar to ? CanonicalizeCalendar(calendar).
+        let calendar = to_constructor_calendar(agent, calendar.get(agent), gc.nogc()).unbind()?;
+        let zoned_date_time =
+            temporal_rs::ZonedDateTime::try_new(epoch_nanoseconds, time_zone, calendar)
+                .map_err(|err| temporal_err_to_js_err(agent, err, gc.nogc()))
+                .unbind()?;
+        // 11. Return ? CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar, NewTarget).
+        create_temporal_zoned_date_time(agent, zoned_date_time, Some(new_target.get(agent)), gc)
+            .map(Value::from)
+    }
+
+    /// ### [6.2.2 Temporal.ZonedDateTime.from ( item [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.from)
+    fn from<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let item = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Return ? ToTemporalZonedDateTime(item, options).
+        let zoned_date_time =
+            to_temporal_zoned_date_time(agent, item.unbind(), options.unbind(), gc)?;
+        Ok(agent
+            .heap
+            .create(ZonedDateTimeRecord {
+                object_index: None,
+                zoned_date_time,
+            })
+            .into())
+    }
+
+    /// ### [6.2.3 Temporal.ZonedDateTime.compare ( one, two )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.compare)
+    fn compare<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let one = args.get(0).bind(gc.nogc());
+        let two = args.get(1).scope(agent, gc.nogc());
+        // 1. Set one to ? ToTemporalZonedDateTime(one).
+        let one = to_temporal_zoned_date_time(agent, one.unbind(), Value::Undefined, gc.reborrow())
+            .unbind()?;
+        // 2. Set two to ? ToTemporalZonedDateTime(two).
+        let two = to_temporal_zoned_date_time(agent, two.get(agent), Value::Undefined, gc)?;
+        // 3. Return 𝔽(CompareEpochNanoseconds(one.[[EpochNanoseconds]], two.[[EpochNanoseconds]])).
+        Ok((one.compare_instant(&two) as i8).into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>, _gc: NoGcScope) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let zoned_date_time_prototype = intrinsics.temporal_zoned_date_time_prototype();
+
+        BuiltinFunctionBuilder::new_intrinsic_constructor::<TemporalZonedDateTimeConstructor>(
+            agent, realm,
+        )
+        .with_property_capacity(3)
+        .with_prototype_property(zoned_date_time_prototype.into())
+        .with_builtin_function_property::<TemporalZonedDateTimeFrom>()
+        .with_builtin_function_property::<TemporalZonedDateTimeCompare>()
+        .build();
+    }
+}
@@ -0,0 +1,1901 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use temporal_rs::{
+    fields::ZonedDateTimeFields,
+    options::{
+        DisplayCalendar, DisplayOffset, DisplayTimeZone, OffsetDisambiguation, RoundingMode,
+        RoundingOptions, ToStringRoundingOptions, Unit,
+    },
+    provider::TransitionDirection,
+};
+
+use crate::{
+    ecmascript::{
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, Behaviour, BigInt, Builtin, BuiltinGetter,
+        CalendarFieldNames, ExceptionType, InstantRecord, JsResult, Object, PlainDateRecord,
+        PlainDateTimeRecord, PlainTimeRecord, PropertyKey, Realm, String, StringOptionType, Value,
+        ZonedDateTimeRecord,
+        builders::OrdinaryObjectBuilder,
+        builtins::temporal::zoned_date_time::{
+            add_duration_to_zoned_date_time, difference_temporal_zoned_date_time,
+            get_zoned_date_time_options, require_internal_slot_temporal_zoned_date_time,
+        },
+        get_direction_option, get_options_object, get_rounding_increment_option,
+        get_rounding_mode_option, get_temporal_fractional_second_digits_option,
+        get_temporal_show_calendar_name_option, get_temporal_show_offset_option,
+        get_temporal_show_time_zone_name_option, get_temporal_unit_valued_option,
+        is_partial_temporal_object, prepare_calendar_fields, temporal_err_to_js_err,
+        to_temporal_calendar_identifier, to_temporal_time_zone_identifier,
+        to_temporal_zoned_date_time, to_time_record_or_midnight,
+    },
+    engine::{Bindable, GcScope, NoGcScope, Scopable},
+    heap::{CreateHeapData, WellKnownSymbols},
+};
+
+pub(crate) struct TemporalZonedDateTimePrototype;
+
+struct TemporalZonedDateTimePrototypeGetCalendarId;
+impl Builtin for TemporalZonedDateTimePrototypeGetCalendarId {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_calendarId;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.calendarId.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_calendar_id);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetCalendarId {}
+
+struct TemporalZonedDateTimePrototypeGetTimeZoneId;
+impl Builtin for TemporalZonedDateTimePrototypeGetTimeZoneId {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_timeZoneId;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.timeZoneId.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_time_zone_id);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetTimeZoneId {}
+
+struct TemporalZonedDateTimePrototypeGetEra;
+impl Builtin for TemporalZonedDateTimePrototypeGetEra {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_era;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.era.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::get_era);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetEra {}
+
+struct TemporalZonedDateTimePrototypeGetEraYear;
+impl Builtin for TemporalZonedDateTimePrototypeGetEraYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_eraYear;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.eraYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::get_era_year);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetEraYear {}
+
+struct TemporalZonedDateTimePrototypeGetYear;
+impl Builtin for TemporalZonedDateTimePrototypeGetYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_year;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.year.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::get_year);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetYear {}
+
+struct TemporalZonedDateTimePrototypeGetMonth;
+impl Builtin for TemporalZonedDateTimePrototypeGetMonth {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_month;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.month.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::get_month);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetMonth {}
+
+struct TemporalZonedDateTimePrototypeGetMonthCode;
+impl Builtin for TemporalZonedDateTimePrototypeGetMonthCode {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_monthCode;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.monthCode.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::get_month_code);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetMonthCode {}
+
+struct TemporalZonedDateTimePrototypeGetDay;
+impl Builtin for TemporalZonedDateTimePrototypeGetDay {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_day;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.day.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::get_day);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetDay {}
+
+struct TemporalZonedDateTimePrototypeGetHour;
+impl Builtin for TemporalZonedDateTimePrototypeGetHour {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_hour;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.hour.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::get_hour);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetHour {}
+
+struct TemporalZonedDateTimePrototypeGetMinute;
+impl Builtin for TemporalZonedDateTimePrototypeGetMinute {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_minute;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.minute.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::get_minute);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetMinute {}
+
+struct TemporalZonedDateTimePrototypeGetSecond;
+impl Builtin for TemporalZonedDateTimePrototypeGetSecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_second;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.second.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::get_second);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetSecond {}
+
+struct TemporalZonedDateTimePrototypeGetMillisecond;
+impl Builtin for TemporalZonedDateTimePrototypeGetMillisecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_millisecond;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.millisecond.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_millisecond);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetMillisecond {}
+
+struct TemporalZonedDateTimePrototypeGetMicrosecond;
+impl Builtin for TemporalZonedDateTimePrototypeGetMicrosecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_microsecond;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.microsecond.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_microsecond);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetMicrosecond {}
+
+struct TemporalZonedDateTimePrototypeGetNanosecond;
+impl Builtin for TemporalZonedDateTimePrototypeGetNanosecond {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_nanosecond;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.nanosecond.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::get_nanosecond);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetNanosecond {}
+
+struct TemporalZonedDateTimePrototypeGetEpochMilliseconds;
+impl Builtin for TemporalZonedDateTimePrototypeGetEpochMilliseconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_epochMilliseconds;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.epochMilliseconds.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_epoch_milliseconds);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetEpochMilliseconds {}
+
+struct TemporalZonedDateTimePrototypeGetEpochNanoseconds;
+impl Builtin for TemporalZonedDateTimePrototypeGetEpochNanoseconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_epochNanoseconds;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.epochNanoseconds.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_epoch_nanoseconds);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetEpochNanoseconds {}
+
+struct TemporalZonedDateTimePrototypeGetDayOfWeek;
+impl Builtin for TemporalZonedDateTimePrototypeGetDayOfWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_dayOfWeek;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.dayOfWeek.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_day_of_week);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetDayOfWeek {}
+
+struct TemporalZonedDateTimePrototypeGetDayOfYear;
+impl Builtin for TemporalZonedDateTimePrototypeGetDayOfYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_dayOfYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.dayOfYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_day_of_year);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetDayOfYear {}
+
+struct TemporalZonedDateTimePrototypeGetWeekOfYear;
+impl Builtin for TemporalZonedDateTimePrototypeGetWeekOfYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_weekOfYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.weekOfYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_week_of_year);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetWeekOfYear {}
+
+struct TemporalZonedDateTimePrototypeGetYearOfWeek;
+impl Builtin for TemporalZonedDateTimePrototypeGetYearOfWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_yearOfWeek;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.yearOfWeek.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_year_of_week);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetYearOfWeek {}
+
+struct TemporalZonedDateTimePrototypeGetHoursInDay;
+impl Builtin for TemporalZonedDateTimePrototypeGetHoursInDay {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_hoursInDay;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.hoursInDay.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_hours_in_day);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetHoursInDay {}
+
+struct TemporalZonedDateTimePrototypeGetDaysInWeek;
+impl Builtin for TemporalZonedDateTimePrototypeGetDaysInWeek {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInWeek;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInWeek.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_days_in_week);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetDaysInWeek {}
+
+struct TemporalZonedDateTimePrototypeGetDaysInMonth;
+impl Builtin for TemporalZonedDateTimePrototypeGetDaysInMonth {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInMonth;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInMonth.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_days_in_month);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetDaysInMonth {}
+
+struct TemporalZonedDateTimePrototypeGetDaysInYear;
+impl Builtin for TemporalZonedDateTimePrototypeGetDaysInYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_daysInYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.daysInYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_days_in_year);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetDaysInYear {}
+
+struct TemporalZonedDateTimePrototypeGetMonthsInYear;
+impl Builtin for TemporalZonedDateTimePrototypeGetMonthsInYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_monthsInYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.monthsInYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_months_in_year);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetMonthsInYear {}
+
+struct TemporalZonedDateTimePrototypeGetInLeapYear;
+impl Builtin for TemporalZonedDateTimePrototypeGetInLeapYear {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_inLeapYear;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.inLeapYear.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_in_leap_year);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetInLeapYear {}
+
+struct TemporalZonedDateTimePrototypeGetOffsetNanoseconds;
+impl Builtin for TemporalZonedDateTimePrototypeGetOffsetNanoseconds {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_offsetNanoseconds;
+    const KEY: Option<PropertyKey<'static>> =
+        Some(BUILTIN_STRING_MEMORY.offsetNanoseconds.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_offset_nanoseconds);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetOffsetNanoseconds {}
+
+struct TemporalZonedDateTimePrototypeGetOffset;
+impl Builtin for TemporalZonedDateTimePrototypeGetOffset {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.get_offset;
+    const KEY: Option<PropertyKey<'static>> = Some(BUILTIN_STRING_MEMORY.offset.to_property_key());
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::get_offset);
+}
+impl BuiltinGetter for TemporalZonedDateTimePrototypeGetOffset {}
+
+struct TemporalZonedDateTimePrototypeWith;
+impl Builtin for TemporalZonedDateTimePrototypeWith {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.with;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::with);
+}
+
+struct TemporalZonedDateTimePrototypeWithPlainTime;
+impl Builtin for TemporalZonedDateTimePrototypeWithPlainTime {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.withPlainTime;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::with_plain_time);
+}
+
+struct TemporalZonedDateTimePrototypeWithTimeZone;
+impl Builtin for TemporalZonedDateTimePrototypeWithTimeZone {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.withTimeZone;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::with_time_zone);
+}
+
+struct TemporalZonedDateTimePrototypeWithCalendar;
+impl Builtin for TemporalZonedDateTimePrototypeWithCalendar {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.withCalendar;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::with_calendar);
+}
+
+struct TemporalZonedDateTimePrototypeAdd;
+impl Builtin for TemporalZonedDateTimePrototypeAdd {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.add;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::add);
+}
+
+struct TemporalZonedDateTimePrototypeSubtract;
+impl Builtin for TemporalZonedDateTimePrototypeSubtract {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.subtract;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::subtract);
+}
+
+struct TemporalZonedDateTimePrototypeUntil;
+impl Builtin for TemporalZonedDateTimePrototypeUntil {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.until;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::until);
+}
+
+struct TemporalZonedDateTimePrototypeSince;
+impl Builtin for TemporalZonedDateTimePrototypeSince {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.since;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::since);
+}
+
+struct TemporalZonedDateTimePrototypeRound;
+impl Builtin for TemporalZonedDateTimePrototypeRound {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.round;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::round);
+}
+
+struct TemporalZonedDateTimePrototypeEquals;
+impl Builtin for TemporalZonedDateTimePrototypeEquals {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.equals;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::equals);
+}
+
+struct TemporalZonedDateTimePrototypeToString;
+impl Builtin for TemporalZonedDateTimePrototypeToString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toString;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::to_string);
+}
+
+struct TemporalZonedDateTimePrototypeToLocaleString;
+impl Builtin for TemporalZonedDateTimePrototypeToLocaleString {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toLocaleString;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::to_locale_string);
+}
+
+struct TemporalZonedDateTimePrototypeToJSON;
+impl Builtin for TemporalZonedDateTimePrototypeToJSON {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toJSON;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::to_json);
+}
+
+struct TemporalZonedDateTimePrototypeValueOf;
+impl Builtin for TemporalZonedDateTimePrototypeValueOf {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.valueOf;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::value_of);
+}
+
+struct TemporalZonedDateTimePrototypeStartOfDay;
+impl Builtin for TemporalZonedDateTimePrototypeStartOfDay {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.startOfDay;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::start_of_day);
+}
+
+struct TemporalZonedDateTimePrototypeGetTimeZoneTransition;
+impl Builtin for TemporalZonedDateTimePrototypeGetTimeZoneTransition {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.getTimeZoneTransition;
+    const LENGTH: u8 = 1;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::get_time_zone_transition);
+}
+
+struct TemporalZonedDateTimePrototypeToInstant;
+impl Builtin for TemporalZonedDateTimePrototypeToInstant {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toInstant;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::to_instant);
+}
+
+struct TemporalZonedDateTimePrototypeToPlainDate;
+impl Builtin for TemporalZonedDateTimePrototypeToPlainDate {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toPlainDate;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::to_plain_date);
+}
+
+struct TemporalZonedDateTimePrototypeToPlainTime;
+impl Builtin for TemporalZonedDateTimePrototypeToPlainTime {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toPlainTime;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour = Behaviour::Regular(TemporalZonedDateTimePrototype::to_plain_time);
+}
+
+struct TemporalZonedDateTimePrototypeToPlainDateTime;
+impl Builtin for TemporalZonedDateTimePrototypeToPlainDateTime {
+    const NAME: String<'static> = BUILTIN_STRING_MEMORY.toPlainDateTime;
+    const LENGTH: u8 = 0;
+    const BEHAVIOUR: Behaviour =
+        Behaviour::Regular(TemporalZonedDateTimePrototype::to_plain_date_time);
+}
+
+impl TemporalZonedDateTimePrototype {
+    /// ### [6.3.3 get Temporal.ZonedDateTime.prototype.calendarId](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.calendarid)
+    fn get_calendar_id<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Return zonedDateTime.[[Calendar]].
+        let value = zoned_date_time
+            .inner_zoned_date_time(agent)
+            .calendar()
+            .identifier();
+        Ok(Value::from_static_str(agent, value, gc))
+    }
+
+    /// ### [6.3.4 get Temporal.ZonedDateTime.prototype.timeZoneId](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.timezoneid)
+    fn get_time_zone_id<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Return zonedDateTime.[[TimeZone]].
+        match zoned_date_time
+            .inner_zoned_date_time(agent)
+            .time_zone()
+            .identifier()
+        {
+            Ok(value) => Ok(Value::from_string(agent, value, gc)),
+            Err(err) => Err(temporal_err_to_js_err(agent, err, gc)),
+        }
+    }
+
+    /// ### [6.3.5 get Temporal.ZonedDateTime.prototype.era](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.era)
+    fn get_era<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return CalendarISOToDate(zonedDateTime.[[Calendar]], isoDateTime.[[ISODate]]).[[Era]].
+        let value = zoned_date_time.inner_zoned_date_time(agent).era();
+        Ok(value.map_or(Value::Undefined, |era| {
+            Value::from_str(agent, era.as_str(), gc)
+        }))
+    }
+
+    /// ### [6.3.6 get Temporal.ZonedDateTime.prototype.eraYear](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.erayear)
+    fn get_era_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(CalendarISOToDate(zonedDateTime.[[Calendar]], isoDateTime.[[ISODate]]).[[EraYear]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).era_year();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.7 get Temporal.ZonedDateTime.prototype.year](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.year)
+    fn get_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(CalendarISOToDate(zonedDateTime.[[Calendar]], isoDateTime.[[ISODate]]).[[Year]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).year();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.8 get Temporal.ZonedDateTime.prototype.month](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.month)
+    fn get_month<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(CalendarISOToDate(zonedDateTime.[[Calendar]], isoDateTime.[[ISODate]]).[[Month]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).month();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.9 get Temporal.ZonedDateTime.prototype.monthCode](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.monthcode)
+    fn get_month_code<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return CalendarISOToDate(zonedDateTime.[[Calendar]], isoDateTime.[[ISODate]]).[[MonthCode]].
+        let value = zoned_date_time.inner_zoned_date_time(agent).month_code();
+        Ok(Value::from_str(agent, value.as_str(), gc))
+    }
+
+    /// ### [6.3.10 get Temporal.ZonedDateTime.prototype.day](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.day)
+    fn get_day<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(CalendarISOToDate(zonedDateTime.[[Calendar]], isoDateTime.[[ISODate]]).[[Day]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).day();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.11 get Temporal.ZonedDateTime.prototype.hour](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.hour)
+    fn get_hour<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(isoDateTime.[[Time]].[[Hour]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).hour();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.12 get Temporal.ZonedDateTime.prototype.minute](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.minute)
+    fn get_minute<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(isoDateTime.[[Time]].[[Minute]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).minute();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.13 get Temporal.ZonedDateTime.prototype.second](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.second)
+    fn get_second<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(isoDateTime.[[Time]].[[Second]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).second();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.14 get Temporal.ZonedDateTime.prototype.millisecond](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.millisecond)
+    fn get_millisecond<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(isoDateTime.[[Time]].[[Millisecond]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).millisecond();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.15 get Temporal.ZonedDateTime.prototype.microsecond](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.microsecond)
+    fn get_microsecond<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(isoDateTime.[[Time]].[[Microsecond]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).microsecond();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.16 get Temporal.ZonedDateTime.prototype.nanosecond](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.nanosecond)
+    fn get_nanosecond<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(isoDateTime.[[Time]].[[Nanosecond]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).nanosecond();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.17 get Temporal.ZonedDateTime.prototype.epochMilliseconds](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.epochmilliseconds)
+    fn get_epoch_milliseconds<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let ns be zonedDateTime.[[EpochNanoseconds]].
+        // 4. Let ms be floor(ℝ(ns) / 10**6).
+        // 5. Return 𝔽(ms).
+        let value = zoned_date_time
+            .inner_zoned_date_time(agent)
+            .epoch_milliseconds();
+        Ok(Value::from_i64(agent, value, gc))
+    }
+
+    /// ### [6.3.18 get Temporal.ZonedDateTime.prototype.epochNanoseconds](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.epochnanoseconds)
+    fn get_epoch_nanoseconds<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Return zonedDateTime.[[EpochNanoseconds]].
+        let value = zoned_date_time
+            .inner_zoned_date_time(agent)
+            .epoch_nanoseconds()
+            .as_i128();
+        Ok(BigInt::from_i128(agent, value, gc).into())
+    }
+
+    /// ### [6.3.19 get Temporal.ZonedDateTime.prototype.dayOfWeek](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.dayofweek)
+    fn get_day_of_week<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(CalendarISOToDate(zonedDateTime.[[Calendar]], isoDateTime.[[ISODate]]).[[DayOfWeek]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).day_of_week();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.20 get Temporal.ZonedDateTime.prototype.dayOfYear](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.dayofyear)
+    fn get_day_of_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(CalendarISOToDate(zonedDateTime.[[Calendar]], isoDateTime.[[ISODate]]).[[DayOfYear]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).day_of_year();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.21 get Temporal.ZonedDateTime.prototype.weekOfYear](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.weekofyear)
+    fn get_week_of_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(CalendarISOToDate(zonedDateTime.[[Calendar]], isoDateTime.[[ISODate]]).[[WeekOfYear].[[Week]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).week_of_year();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.22 get Temporal.ZonedDateTime.prototype.yearOfWeek](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.yearofweek)
+    fn get_year_of_week<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(CalendarISOToDate(zonedDateTime.[[Calendar]], isoDateTime.[[ISODate]]).[[WeekOfYear].[[Year]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).year_of_week();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.23 get Temporal.ZonedDateTime.prototype.hoursInDay](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.hoursinday)
+    fn get_hours_in_day<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 4. Let isoDateTime be GetISODateTimeFor(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        // 5. Let today be isoDateTime.[[ISODate]].
+        // 6. Let tomorrow be BalanceISODate(today.[[Year]], today.[[Month]], today.[[Day]] + 1).
+        // 7. Let todayNs be ? GetStartOfDay(timeZone, today).
+        // 8. Let tomorrowNs be ? GetStartOfDay(timeZone, tomorrow).
+        // 9. Let diff be TimeDurationFromEpochNanosecondsDifference(tomorrowNs, todayNs).
+        // 10. Return 𝔽(TotalTimeDuration(diff, hour)).
+        match zoned_date_time.inner_zoned_date_time(agent).hours_in_day() {
+            Ok(value) => Ok(Value::from_f64(agent, value, gc)),
+            Err(err) => Err(temporal_err_to_js_err(agent, err, gc)),
+        }
+    }
+
+    /// ### [6.3.24 get Temporal.ZonedDateTime.prototype.daysInWeek](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.daysinweek)
+    fn get_days_in_week<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(CalendarISOToDate(zonedDateTime.[[Calendar]], isoDateTime.[[ISODate]]).[[DaysInWeek]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).days_in_week();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.25 get Temporal.ZonedDateTime.prototype.daysInMonth](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.daysinmonth)
+    fn get_days_in_month<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(CalendarISOToDate(zonedDateTime.[[Calendar]], isoDateTime.[[ISODate]]).[[DaysInMonth]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).days_in_month();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.26 get Temporal.ZonedDateTime.prototype.daysInYear](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.daysinyear)
+    fn get_days_in_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(CalendarISOToDate(zonedDateTime.[[Calendar]], isoDateTime.[[ISODate]]).[[DaysInYear]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).days_in_year();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.27 get Temporal.ZonedDateTime.prototype.monthsInYear](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.monthsinyear)
+    fn get_months_in_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(CalendarISOToDate(zonedDateTime.[[Calendar]], isoDateTime.[[ISODate]]).[[MonthsInYear]]).
+        let value = zoned_date_time
+            .inner_zoned_date_time(agent)
+            .months_in_year();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.28 get Temporal.ZonedDateTime.prototype.inLeapYear](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.inleapyear)
+    fn get_in_leap_year<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return 𝔽(CalendarISOToDate(zonedDateTime.[[Calendar]], isoDateTime.[[ISODate]]).[[InLeapYear]]).
+        let value = zoned_date_time.inner_zoned_date_time(agent).in_leap_year();
+        Ok(value.into())
+    }
+
+    /// ### [6.3.29 get Temporal.ZonedDateTime.prototype.offsetNanoseconds](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.offsetnanoseconds)
+    fn get_offset_nanoseconds<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Return 𝔽(GetOffsetNanosecondsFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]])).
+        let value = zoned_date_time
+            .inner_zoned_date_time(agent)
+            .offset_nanoseconds();
+        Ok(Value::from_i64(agent, value, gc))
+    }
+
+    /// ### [6.3.30 get Temporal.ZonedDateTime.prototype.offset](https://tc39.es/proposal-temporal/#sec-get-temporal.zoneddatetime.prototype.offset)
+    fn get_offset<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let offsetNanoseconds be GetOffsetNanosecondsFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return FormatUTCOffsetNanoseconds(offsetNanoseconds).
+        let value = zoned_date_time.inner_zoned_date_time(agent).offset();
+        Ok(Value::from_string(agent, value, gc))
+    }
+
+    /// ### [6.3.31 Temporal.ZonedDateTime.prototype.with ( temporalZonedDateTimeLike [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.with)
+    fn with<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let temporal_zoned_date_time_like = args.get(0).scope(agent, gc.nogc());
+        let options = args.get(1).scope(agent, gc.nogc());
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. If ? IsPartialTemporalObject(temporalZonedDateTimeLike) is false,
+        //    throw a TypeError exception.
+        if !is_partial_temporal_object(
+            agent,
+            temporal_zoned_date_time_like.get(agent),
+            gc.reborrow(),
+        )
+        .unbind()?
+        {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "Argument is not a partial Temporal.ZonedDateTime-like object",
+                gc.into_nogc(),
+            ));
+        }
+        let temporal_zoned_date_time_like =
+            Object::try_from(temporal_zoned_date_time_like.get(agent)).unwrap();
+        // 4. Let epochNs be zonedDateTime.[[EpochNanoseconds]].
+        // 5. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 6. Let calendar be zonedDateTime.[[Calendar]].
+        let calendar = zoned_date_time
+            .get(agent)
+            .inner_zoned_date_time(agent)
+            .calendar()
+            .clone();
+        // 7. Let offsetNanoseconds be GetOffsetNanosecondsFor(timeZone, epochNs).
+        // 8. Let isoDateTime be GetISODateTimeFor(timeZone, epochNs).
+        // 9. Let fields be ISODateToFields(calendar, isoDateTime.[[ISODate]], date).
+        // 10-16. Set the time and offset fields.
+        // 17. Let partialZonedDateTime be ? PrepareCalendarFields(calendar,
+        //     temporalZonedDateTimeLike, « year, month, month-code, day », «
+        //     hour, minute, second, millisecond, microsecond, nanosecond,
+        //     offset », partial).
+        let partial_zoned_date_time = prepare_calendar_fields(
+            agent,
+            &calendar,
+            temporal_zoned_date_time_like,
+            CalendarFieldNames::ZONED_DATE_TIME_WITH,
+            true,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        // 18. Set fields to CalendarMergeFields(calendar, fields, partialZonedDateTime).
+        // 19. Let resolvedOptions be ? GetOptionsObject(options).
+        // 20. Let disambiguation be ? GetTemporalDisambiguationOption(resolvedOptions).
+        // 21. Let offset be ? GetTemporalOffsetOption(resolvedOptions, prefer).
+        // 22. Let overflow be ? GetTemporalOverflowOption(resolvedOptions).
+        let (disambiguation, offset_option, overflow) = get_zoned_date_time_options(
+            agent,
+            options.get(agent),
+            OffsetDisambiguation::Prefer,
+            gc.reborrow(),
+        )
+        .unbind()?;
+        let gc = gc.into_nogc();
+        let calendar_fields = partial_zoned_date_time.to_calendar_fields(agent, overflow, gc)?;
+        let time = partial_zoned_date_time.to_partial_time(agent, overflow, gc)?;
+        // 23. Let dateTimeResult be ? InterpretTemporalDateTimeFields(calendar, fields, overflow).
+        // 24. Let newOffsetNanoseconds be ! ParseDateTimeUTCOffset(fields.[[OffsetString]]).
+        // 25. Let epochNanoseconds be ? InterpretISODateTimeOffset(
+        //     dateTimeResult.[[ISODate]], dateTimeResult.[[Time]], option,
+        //     newOffsetNanoseconds, timeZone, disambiguation, offset,
+        //     match-exactly).
+        let zoned_date_time = zoned_date_time
+            .get(agent)
+            .inner_zoned_date_time(agent)
+            .with(
+                ZonedDateTimeFields {
+                    calendar_fields,
+                    time,
+                    offset: partial_zoned_date_time.offset,
+                },
+                Some(disambiguation),
+                Some(offset_option),
+                Some(overflow),
+            )
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        // 26. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Ok(agent
+            .heap
+            .create(ZonedDateTimeRecord {
+                object_index: None,
+                zoned_date_time,
+            })
+            .into())
+    }
+
+    /// ### [6.3.32 Temporal.ZonedDateTime.prototype.withPlainTime ( [ plainTimeLike ] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.withplaintime)
+    fn with_plain_time<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let plain_time_like = args.get(0).bind(gc.nogc());
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 4. Let calendar be zonedDateTime.[[Calendar]].
+        // 5. Let isoDateTime be GetISODateTimeFor(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        // 6. If plainTimeLike is undefined, then
+        //     a. Let epochNs be ? GetStartOfDay(timeZone, isoDateTime.[[ISODate]]).
+        // 7. Else,
+        //     a. Let plainTime be ? ToTemporalTime(plainTimeLike).
+        //     b. Let resultISODateTime be CombineISODateAndTimeRecord(isoDateTime.[[ISODate]], plainTime.[[Time]]).
+        //     c. Let epochNs be ? GetEpochNanosecondsFor(timeZone, resultISODateTime, compatible).
+        let time =
+            to_time_record_or_midnight(agent, plain_time_like.unbind(), gc.reborrow()).unbind()?;
+        let gc = gc.into_nogc();
+        let zoned_date_time = zoned_date_time
+            .get(agent)
+            .inner_zoned_date_time(agent)
+            .with_plain_time(time)
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        // 8. Return ! CreateTemporalZonedDateTime(epochNs, timeZone, calendar).
+        Ok(agent
+            .heap
+            .create(ZonedDateTimeRecord {
+                object_index: None,
+                zoned_date_time,
+            })
+            .into())
+    }
+
+    /// ### [6.3.33 Temporal.ZonedDateTime.prototype.withTimeZone ( timeZoneLike )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.withtimezone)
+    fn with_time_zone<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let time_zone_like = args.get(0).bind(gc);
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let timeZone be ? ToTemporalTimeZoneIdentifier(timeZoneLike).
+        let time_zone = to_temporal_time_zone_identifier(agent, time_zone_like, gc)?;
+        // 4. Return ! CreateTemporalZonedDateTime(zonedDateTime.[[EpochNanoseconds]], timeZone, zonedDateTime.[[Calendar]]).
+        let zoned_date_time = zoned_date_time
+            .inner_zoned_date_time(agent)
+            .with_timezone(time_zone)
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        Ok(agent
+            .heap
+            .create(ZonedDateTimeRecord {
+                object_index: None,
+                zoned_date_time,
+            })
+            .into())
+    }
+
+    /// ### [6.3.34 Temporal.ZonedDateTime.prototype.withCalendar ( calendarLike )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.withcalendar)
+    fn with_calendar<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        let calendar_like = args.get(0).bind(gc);
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let calendar be ? ToTemporalCalendarIdentifier(calendarLike).
+        let calendar = to_temporal_calendar_identifier(agent, calendar_like, gc)?;
+        // 4. Return ! CreateTemporalZonedDateTime(zonedDateTime.[[EpochNanoseconds]], zonedDateTime.[[TimeZone]], calendar).
+        let zoned_date_time = zoned_date_time
+            .inner_zoned_date_time(agent)
+            .with_calendar(calendar);
+        Ok(agent
+            .heap
+            .create(ZonedDateTimeRecord {
+                object_index: None,
+                zoned_date_time,
+            })
+            .into())
+    }
+
+    /// ### [6.3.35 Temporal.ZonedDateTime.prototype.add ( temporalDurationLike [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.add)
+    fn add<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let duration = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .bind(gc.nogc());
+        // 3. Return ? AddDurationToZonedDateTime(add, zonedDateTime, temporalDurationLike, options).
+        const ADD: bool = true;
+        add_duration_to_zoned_date_time::<ADD>(
+            agent,
+            zoned_date_time.unbind(),
+            duration.unbind(),
+            options.unbind(),
+            gc,
+        )
+        .map(Value::from)
+    }
+
+    /// ### [6.3.36 Temporal.ZonedDateTime.prototype.subtract ( temporalDurationLike [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.subtract)
+    fn subtract<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let duration = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .bind(gc.nogc());
+        // 3. Return ? AddDurationToZonedDateTime(subtract, zonedDateTime, temporalDurationLike, options).
+        const SUBTRACT: bool = false;
+        add_duration_to_zoned_date_time::<SUBTRACT>(
+            agent,
+            zoned_date_time.unbind(),
+            duration.unbind(),
+            options.unbind(),
+            gc,
+        )
+        .map(Value::from)
+    }
+
+    /// ### [6.3.37 Temporal.ZonedDateTime.prototype.until ( other [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.until)
+    fn until<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let other = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalZonedDateTime(until, zonedDateTime, other, options).
+        const UNTIL: bool = true;
+        difference_temporal_zoned_date_time::<UNTIL>(
+            agent,
+            zoned_date_time.unbind(),
+            other.unbind(),
+            options.unbind(),
+            gc,
+        )
+        .map(Value::from)
+    }
+
+    /// ### [6.3.38 Temporal.ZonedDateTime.prototype.since ( other [ , options ] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.since)
+    fn since<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let other = args.get(0).bind(gc.nogc());
+        let options = args.get(1).bind(gc.nogc());
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .bind(gc.nogc());
+        // 3. Return ? DifferenceTemporalZonedDateTime(since, zonedDateTime, other, options).
+        const SINCE: bool = false;
+        difference_temporal_zoned_date_time::<SINCE>(
+            agent,
+            zoned_date_time.unbind(),
+            other.unbind(),
+            options.unbind(),
+            gc,
+        )
+        .map(Value::from)
+    }
+
+    /// ### [6.3.39 Temporal.ZonedDateTime.prototype.round ( roundTo )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.round)
+    fn round<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let round_to = args.get(0).bind(gc.nogc());
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. If roundTo is undefined, then
+        if round_to.is_undefined() {
+            // a. Throw a TypeError exception.
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "roundTo cannot be undefined",
+                gc.into_nogc(),
+            ));
+        }
+        // 4. If roundTo is a String, then
+        let options = if let Ok(round_to) = String::try_from(round_to) {
+            // a. Let paramString be roundTo.
+            // b. Set roundTo to OrdinaryObjectCreate(null).
+            // c. Perform ! CreateDataPropertyOrThrow(roundTo, "smallestUnit", paramString).
+            let mut options = RoundingOptions::default();
+            options.smallest_unit =
+                Some(Unit::from_string(agent, round_to.unbind(), gc.nogc()).unbind()?);
+            options
+        } else {
+            // 5. Else,
+            //     a. Set roundTo to ? GetOptionsObject(roundTo).
+            let round_to = get_options_object(agent, round_to, gc.nogc())
+                .unbind()?
+                .map(|r| r.scope(agent, gc.nogc()));
+            // 6. NOTE: The following steps read options and perform independent
+            //    validation in alphabetical order (GetRoundingIncrementOption
+            //    reads "roundingIncrement" and GetRoundingModeOption reads
+            //    "roundingMode").
+            let mut options = RoundingOptions::default();
+            if let Some(round_to) = round_to {
+                // 7. Let roundingIncrement be ? GetRoundingIncrementOption(roundTo).
+                options.increment = Some(
+                    get_rounding_increment_option(agent, round_to.get(agent), gc.reborrow())
+                        .unbind()?,
+                );
+                // 8. Let roundingMode be ? GetRoundingModeOption(roundTo, half-expand).
+                options.rounding_mode = Some(
+                    get_rounding_mode_option(
+                        agent,
+                        round_to.get(agent),
+                        RoundingMode::default(),
+                        gc.reborrow(),
+                    )
+                    .unbind()?,
+                );
+                // 9. Let smallestUnit be ? GetTemporalUnitValuedOption(roundTo, "smallestUnit", required).
+                options.smallest_unit = get_temporal_unit_valued_option(
+                    agent,
+                    round_to.get(agent),
+                    BUILTIN_STRING_MEMORY.smallestUnit.into(),
+                    gc.reborrow(),
+                )
+                .unbind()?;
+            }
+            options
+        };
+        let gc = gc.into_nogc();
+        // 10. Perform ? ValidateTemporalUnitValue(smallestUnit, time, « day »).
+        // 11. If smallestUnit is day, then
+        //     a. Let maximum be 1.
+        //     b. Let inclusive be true.
+        // 12. Else,
+        //     a. Let maximum be MaximumTemporalDurationRoundingIncrement(smallestUnit).
+        //     b. Assert: maximum is not unset.
+        //     c. Let inclusive be false.
+        // 13. Perform ? ValidateTemporalRoundingIncrement(roundingIncrement, maximum, inclusive).
+        // 14. If smallestUnit is nanosecond and roundingIncrement = 1, then
+        //     a. Return ! CreateTemporalZonedDateTime(zonedDateTime.[[EpochNanoseconds]], zonedDateTime.[[TimeZone]], zonedDateTime.[[Calendar]]).
+        // 15-19. Round the local date-time and interpret it in the time zone.
+        let zoned_date_time = zoned_date_time
+            .get(agent)
+            .inner_zoned_date_time(agent)
+            .round(options)
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        // 20. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, zonedDateTime.[[Calendar]]).
+        Ok(agent
+            .heap
+            .create(ZonedDateTimeRecord {
+                object_index: None,
+                zoned_date_time,
+            })
+            .into())
+    }
+
+    /// ### [6.3.40 Temporal.ZonedDateTime.prototype.equals ( other )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.equals)
+    fn equals<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let other = args.get(0).bind(gc.nogc());
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. Set other to ? ToTemporalZonedDateTime(other).
+        let other =
+            to_temporal_zoned_date_time(agent, other.unbind(), Value::Undefined, gc.reborrow())
+                .unbind()?;
+        let gc = gc.into_nogc();
+        // 4. If zonedDateTime.[[EpochNanoseconds]] ≠ other.[[EpochNanoseconds]], return false.
+        // 5. If TimeZoneEquals(zonedDateTime.[[TimeZone]], other.[[TimeZone]]) is false, return false.
+        // 6. Return CalendarEquals(zonedDateTime.[[Calendar]], other.[[Calendar]]).
+        match zoned_date_time
+            .get(agent)
+            .inner_zoned_date_time(agent)
+            .equals(&other)
+        {
+            Ok(equals) => Ok(equals.into()),
+            Err(err) => Err(temporal_err_to_js_err(agent, err, gc)),
+        }
+    }
+
+    /// ### [6.3.41 Temporal.ZonedDateTime.prototype.toString ( [ options ] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.tostring)
+    fn to_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let options = args.get(0).bind(gc.nogc());
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. Let resolvedOptions be ? GetOptionsObject(options).
+        let resolved_options = get_options_object(agent, options, gc.nogc())
+            .unbind()?
+            .map(|r| r.scope(agent, gc.nogc()));
+        let (show_calendar, digits, show_offset, rounding_mode, smallest_unit, show_time_zone) =
+            if let Some(resolved_options) = resolved_options {
+                // 4. NOTE: The following steps read options and perform
+                //    independent validation in alphabetical order
+                //    (GetTemporalShowCalendarNameOption reads "calendarName",
+                //    GetTemporalFractionalSecondDigitsOption reads
+                //    "fractionalSecondDigits", GetTemporalShowOffsetOption
+                //    reads "offset", and GetRoundingModeOption reads
+                //    "roundingMode").
+                // 5. Let showCalendar be ? GetTemporalShowCalendarNameOption(resolvedOptions).
+                let show_calendar = get_temporal_show_calendar_name_option(
+                    agent,
+                    resolved_options.get(agent),
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                // 6. Let digits be ? GetTemporalFractionalSecondDigitsOption(resolvedOptions).
+                let digits = get_temporal_fractional_second_digits_option(
+                    agent,
+                    resolved_options.get(agent),
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                // 7. Let showOffset be ? GetTemporalShowOffsetOption(resolvedOptions).
+                let show_offset = get_temporal_show_offset_option(
+                    agent,
+                    resolved_options.get(agent),
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                // 8. Let roundingMode be ? GetRoundingModeOption(resolvedOptions, trunc).
+                let rounding_mode = get_rounding_mode_option(
+                    agent,
+                    resolved_options.get(agent),
+                    RoundingMode::Trunc,
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                // 9. Let smallestUnit be ? GetTemporalUnitValuedOption(resolvedOptions, "smallestUnit", unset).
+                let smallest_unit = get_temporal_unit_valued_option(
+                    agent,
+                    resolved_options.get(agent),
+                    BUILTIN_STRING_MEMORY.smallestUnit.to_property_key(),
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                // 10. Let showTimeZone be ? GetTemporalShowTimeZoneNameOption(resolvedOptions).
+                let show_time_zone = get_temporal_show_time_zone_name_option(
+                    agent,
+                    resolved_options.get(agent),
+                    gc.reborrow(),
+                )
+                .unbind()?;
+                (
+                    show_calendar,
+                    digits,
+                    show_offset,
+                    rounding_mode,
+                    smallest_unit,
+                    show_time_zone,
+                )
+            } else {
+                (
+                    DisplayCalendar::default(),
+                    Default::default(),
+                    DisplayOffset::default(),
+                    RoundingMode::Trunc,
+                    None,
+                    DisplayTimeZone::default(),
+                )
+            };
+        let gc = gc.into_nogc();
+        // 11. Perform ? ValidateTemporalUnitValue(smallestUnit, time).
+        if !smallest_unit.is_none_or(|su| su.is_time_unit()) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "smallestUnit is not a valid time unit",
+                gc,
+            ));
+        }
+        // 12. If smallestUnit is hour, throw a RangeError exception.
+        if smallest_unit == Some(Unit::Hour) {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::RangeError,
+                "smallestUnit is hour",
+                gc,
+            ));
+        }
+        // 13. Let precision be ToSecondsStringPrecisionRecord(smallestUnit, digits).
+        // 14. Return TemporalZonedDateTimeToString(zonedDateTime, precision.[[Precision]], showCalendar, showTimeZone, showOffset, precision.[[Increment]], precision.[[Unit]], roundingMode).
+        let options = ToStringRoundingOptions {
+            precision: digits,
+            smallest_unit,
+            rounding_mode: Some(rounding_mode),
+        };
+        match zoned_date_time
+            .get(agent)
+            .inner_zoned_date_time(agent)
+            .to_ixdtf_string(show_offset, show_time_zone, show_calendar, options)
+        {
+            Ok(string) => Ok(Value::from_string(agent, string, gc)),
+            Err(err) => Err(temporal_err_to_js_err(agent, err, gc)),
+        }
+    }
+
+    /// ### [6.3.42 Temporal.ZonedDateTime.prototype.toLocaleString ( [ locales [ , options ] ] )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.tolocalestring)
+    ///
+    /// An ECMAScript implementation that includes the ECMA-402
+    /// Internationalization API must implement this method as specified in the
+    /// ECMA-402 specification. If an ECMAScript implementation does not
+    /// include the ECMA-402 API the following specification of this method is
+    /// used.
+    fn to_locale_string<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Return TemporalZonedDateTimeToString(zonedDateTime, auto, auto, auto, auto).
+        match zoned_date_time
+            .inner_zoned_date_time(agent)
+            .to_ixdtf_string(
+                DisplayOffset::Auto,
+                DisplayTimeZone::Auto,
+                DisplayCalendar::Auto,
+                ToStringRoundingOptions::default(),
+            ) {
+            Ok(string) => Ok(Value::from_string(agent, string, gc)),
+            Err(err) => Err(temporal_err_to_js_err(agent, err, gc)),
+        }
+    }
+
+    /// ### [6.3.43 Temporal.ZonedDateTime.prototype.toJSON ( )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.tojson)
+    fn to_json<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Return TemporalZonedDateTimeToString(zonedDateTime, auto, auto, auto, auto).
+        match zoned_date_time
+            .inner_zoned_date_time(agent)
+            .to_ixdtf_string(
+                DisplayOffset::Auto,
+                DisplayTimeZone::Auto,
+                DisplayCalendar::Auto,
+                ToStringRoundingOptions::default(),
+            ) {
+            Ok(string) => Ok(Value::from_string(agent, string, gc)),
+            Err(err) => Err(temporal_err_to_js_err(agent, err, gc)),
+        }
+    }
+
+    /// ### [6.3.44 Temporal.ZonedDateTime.prototype.valueOf ( )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.valueof)
+    fn value_of<'gc>(
+        agent: &mut Agent,
+        _: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        // 1. Throw a TypeError exception.
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::TypeError,
+            "`valueOf` not supported by Temporal built-ins. See 'compare', 'equals', or `toString`",
+            gc.into_nogc(),
+        ))
+    }
+
+    /// ### [6.3.45 Temporal.ZonedDateTime.prototype.startOfDay ( )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.startofday)
+    fn start_of_day<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 4. Let calendar be zonedDateTime.[[Calendar]].
+        // 5. Let isoDateTime be GetISODateTimeFor(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        // 6. Let epochNanoseconds be ? GetStartOfDay(timeZone, isoDateTime.[[ISODate]]).
+        let zoned_date_time = zoned_date_time
+            .inner_zoned_date_time(agent)
+            .start_of_day()
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        // 7. Return ! CreateTemporalZonedDateTime(epochNanoseconds, timeZone, calendar).
+        Ok(agent
+            .heap
+            .create(ZonedDateTimeRecord {
+                object_index: None,
+                zoned_date_time,
+            })
+            .into())
+    }
+
+    /// ### [6.3.46 Temporal.ZonedDateTime.prototype.getTimeZoneTransition ( directionParam )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.gettimezonetransition)
+    fn get_time_zone_transition<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        args: ArgumentsList,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let direction_param = args.get(0).bind(gc.nogc());
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc.nogc())
+                .unbind()?
+                .scope(agent, gc.nogc());
+        // 3. Let timeZone be zonedDateTime.[[TimeZone]].
+        // 4. If directionParam is undefined, throw a TypeError exception.
+        if direction_param.is_undefined() {
+            return Err(agent.throw_exception_with_static_message(
+                ExceptionType::TypeError,
+                "directionParam cannot be undefined",
+                gc.into_nogc(),
+            ));
+        }
+        // 5. If directionParam is a String, then
+        let direction = if let Ok(direction_param) = String::try_from(direction_param) {
+            // a. Let paramString be directionParam.
+            // b. Set directionParam to OrdinaryObjectCreate(null).
+            // c. Perform ! CreateDataPropertyOrThrow(directionParam, "direction", paramString).
+            TransitionDirection::from_string(agent, direction_param.unbind(), gc.nogc()).unbind()?
+        } else {
+            // 6. Else,
+            //     a. Set directionParam to ? GetOptionsObject(directionParam).
+            let Some(direction_param) = get_options_object(agent, direction_param, gc.nogc())
+                .unbind()?
+                .bind(gc.nogc())
+            else {
+                return Err(agent.throw_exception_with_static_message(
+                    ExceptionType::RangeError,
+                    "direction option is required",
+                    gc.into_nogc(),
+                ));
+            };
+            // 7. Let direction be ? GetDirectionOption(directionParam).
+            get_direction_option(agent, direction_param.unbind(), gc.reborrow()).unbind()?
+        };
+        let gc = gc.into_nogc();
+        // 8. If IsOffsetTimeZoneIdentifier(timeZone) is true, return null.
+        // 9. If direction is next, then
+        //     a. Let transition be GetNamedTimeZoneNextTransition(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        // 10. Else,
+        //     a. Assert: direction is previous.
+        //     b. Let transition be GetNamedTimeZonePreviousTransition(timeZone, zonedDateTime.[[EpochNanoseconds]]).
+        let transition = zoned_date_time
+            .get(agent)
+            .inner_zoned_date_time(agent)
+            .get_time_zone_transition(direction)
+            .map_err(|err| temporal_err_to_js_err(agent, err, gc))?;
+        // 11. If transition is null, return null.
+        let Some(zoned_date_time) = transition else {
+            return Ok(Value::Null);
+        };
+        // 12. Return ! CreateTemporalZonedDateTime(transition, timeZone, zonedDateTime.[[Calendar]]).
+        Ok(agent
+            .heap
+            .create(ZonedDateTimeRecord {
+                object_index: None,
+                zoned_date_time,
+            })
+            .into())
+    }
+
+    /// ### [6.3.47 Temporal.ZonedDateTime.prototype.toInstant ( )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toinstant)
+    fn to_instant<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Return ! CreateTemporalInstant(zonedDateTime.[[EpochNanoseconds]]).
+        let instant = zoned_date_time.inner_zoned_date_time(agent).to_instant();
+        Ok(agent
+            .heap
+            .create(InstantRecord {
+                object_index: None,
+                instant,
+            })
+            .into())
+    }
+
+    /// ### [6.3.48 Temporal.ZonedDateTime.prototype.toPlainDate ( )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toplaindate)
+    fn to_plain_date<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return ! CreateTemporalDate(isoDateTime.[[ISODate]], zonedDateTime.[[Calendar]]).
+        let plain_date = zoned_date_time.inner_zoned_date_time(agent).to_plain_date();
+        Ok(agent
+            .heap
+            .create(PlainDateRecord {
+                object_index: None,
+                plain_date,
+            })
+            .into())
+    }
+
+    /// ### [6.3.49 Temporal.ZonedDateTime.prototype.toPlainTime ( )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toplaintime)
+    fn to_plain_time<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return ! CreateTemporalTime(isoDateTime.[[Time]]).
+        let plain_time = zoned_date_time.inner_zoned_date_time(agent).to_plain_time();
+        Ok(agent
+            .heap
+            .create(PlainTimeRecord {
+                object_index: None,
+                plain_time,
+            })
+            .into())
+    }
+
+    /// ### [6.3.50 Temporal.ZonedDateTime.prototype.toPlainDateTime ( )](https://tc39.es/proposal-temporal/#sec-temporal.zoneddatetime.prototype.toplaindatetime)
+    fn to_plain_date_time<'gc>(
+        agent: &mut Agent,
+        this_value: Value,
+        _: ArgumentsList,
+        gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, Value<'gc>> {
+        let gc = gc.into_nogc();
+        // 1. Let zonedDateTime be the this value.
+        // 2. Perform ? RequireInternalSlot(zonedDateTime, [[InitializedTemporalZonedDateTime]]).
+        let zoned_date_time =
+            require_internal_slot_temporal_zoned_date_time(agent, this_value, gc)?;
+        // 3. Let isoDateTime be GetISODateTimeFor(zonedDateTime.[[TimeZone]], zonedDateTime.[[EpochNanoseconds]]).
+        // 4. Return ! CreateTemporalDateTime(isoDateTime, zonedDateTime.[[Calendar]]).
+        let plain_date_time = zoned_date_time
+            .inner_zoned_date_time(agent)
+            .to_plain_date_time();
+        Ok(agent
+            .heap
+            .create(PlainDateTimeRecord {
+                object_index: None,
+                plain_date_time,
+            })
+            .into())
+    }
+
+    pub(crate) fn create_intrinsic(agent: &mut Agent, realm: Realm<'static>, _: NoGcScope) {
+        let intrinsics = agent.get_realm_record_by_id(realm).intrinsics();
+        let this = intrinsics.temporal_zoned_date_time_prototype();
+        let object_prototype = intrinsics.object_prototype();
+        let zoned_date_time_constructor = intrinsics.temporal_zoned_date_time();
+
+        OrdinaryObjectBuilder::new_intrinsic_object(agent, realm, this)
+            .with_property_capacity(50)
+            .with_prototype(object_prototype)
+            .with_constructor_property(zoned_date_time_constructor)
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetCalendarId>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetTimeZoneId>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetEra>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetEraYear>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetYear>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetMonth>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetMonthCode>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetDay>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetHour>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetMinute>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetSecond>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetMillisecond>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetMicrosecond>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetNanosecond>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetEpochMilliseconds>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetEpochNanoseconds>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetDayOfWeek>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetDayOfYear>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetWeekOfYear>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetYearOfWeek>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetHoursInDay>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetDaysInWeek>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetDaysInMonth>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetDaysInYear>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetMonthsInYear>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetInLeapYear>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetOffsetNanoseconds>()
+            .with_builtin_function_getter_property::<TemporalZonedDateTimePrototypeGetOffset>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeWith>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeWithPlainTime>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeWithTimeZone>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeWithCalendar>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeAdd>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeSubtract>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeUntil>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeSince>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeRound>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeEquals>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeToString>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeToLocaleString>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeToJSON>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeValueOf>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeStartOfDay>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeGetTimeZoneTransition>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeToInstant>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeToPlainDate>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeToPlainTime>()
+            .with_builtin_function_property::<TemporalZonedDateTimePrototypeToPlainDateTime>()
+            .with_property(|builder| {
+                builder
+                    .with_key(WellKnownSymbols::ToStringTag.into())
+                    .with_value_readonly(BUILTIN_STRING_MEMORY.Temporal_ZonedDateTime.into())
+                    .with_enumerable(false)
+                    .with_configurable(true)
+                    .build()
+            })
+            .build();
+    }
+}
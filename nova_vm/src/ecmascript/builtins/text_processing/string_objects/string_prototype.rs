@@ -1633,6 +1633,12 @@ impl StringPrototype {
             return Ok(s.unbind().into());
         }
 
+        // Note: the result must fit within the heap size limit.
+        let byte_length = s.len_(agent).saturating_mul(n as usize);
+        agent
+            .check_heap_allocation(byte_length, gc.nogc())
+            .unbind()?;
+
         // 6. Return the String value that is made from n copies of S appended together.
         Ok(Value::from_string(
             agent,
@@ -3281,7 +3287,11 @@ fn string_pad<'gc>(
     let wtf8_index = fill_string
         .utf8_index_(agent, overflow_len)
         .unwrap_or(overflow_len * 3);
-    let mut buf = Wtf8Buf::with_capacity(s.len_(agent) + fill_count * fill_buf.len() + wtf8_index);
+    let byte_length = s.len_(agent) + fill_count.saturating_mul(fill_buf.len()) + wtf8_index;
+    // Note: the result must fit within the heap size limit.
+    agent.check_heap_allocation(byte_length, gc)?;
+    let fill_buf = fill_string.as_wtf8_(agent);
+    let mut buf = Wtf8Buf::with_capacity(byte_length);
 
     // 6. If placement is start, return the string-concatenation of
     //    truncatedStringFiller and S.
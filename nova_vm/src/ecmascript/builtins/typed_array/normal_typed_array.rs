@@ -36,9 +36,9 @@ use crate::{
     },
     engine::{Bindable, GcScope, HeapRootData, NoGcScope, Scopable, Scoped, bindable_handle},
     heap::{
-        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, DirectArenaAccess,
-        DirectArenaAccessMut, Heap, HeapIndexHandle, HeapMarkAndSweep, HeapSweepWeakReference,
-        WorkQueues,
+        Arena, ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData,
+        DirectArenaAccess, DirectArenaAccessMut, Heap, HeapIndexHandle, HeapMarkAndSweep,
+        HeapSweepWeakReference, WorkQueues,
     },
 };
 
@@ -2305,9 +2305,9 @@ impl AsRef<Vec<TypedArrayRecord<'static>>> for Agent {
     }
 }
 #[doc(hidden)]
-impl AsMut<Vec<TypedArrayRecord<'static>>> for Agent {
+impl AsMut<Arena<Vec<TypedArrayRecord<'static>>>> for Agent {
     #[inline(always)]
-    fn as_mut(&mut self) -> &mut Vec<TypedArrayRecord<'static>> {
+    fn as_mut(&mut self) -> &mut Arena<Vec<TypedArrayRecord<'static>>> {
         &mut self.heap.typed_arrays
     }
 }
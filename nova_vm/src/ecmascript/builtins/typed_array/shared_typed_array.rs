@@ -2329,9 +2329,9 @@ impl AsRef<Vec<SharedTypedArrayRecord<'static>>> for crate::ecmascript::Agent {
     }
 }
 #[doc(hidden)]
-impl AsMut<Vec<SharedTypedArrayRecord<'static>>> for crate::ecmascript::Agent {
+impl AsMut<crate::heap::Arena<Vec<SharedTypedArrayRecord<'static>>>> for crate::ecmascript::Agent {
     #[inline(always)]
-    fn as_mut(&mut self) -> &mut Vec<SharedTypedArrayRecord<'static>> {
+    fn as_mut(&mut self) -> &mut crate::heap::Arena<Vec<SharedTypedArrayRecord<'static>>> {
         &mut self.heap.shared_typed_arrays
     }
 }
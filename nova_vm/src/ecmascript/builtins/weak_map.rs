@@ -41,6 +41,16 @@ impl<'m> WeakMap<'m> {
         self.get_mut(agent).get(key)
     }
 
+    #[cfg(feature = "proposal-upsert")]
+    pub(crate) fn get_or_insert(
+        self,
+        agent: &mut Agent,
+        key: WeakKey<'m>,
+        value: Value<'m>,
+    ) -> Value<'m> {
+        self.get_mut(agent).get_or_insert(key, value)
+    }
+
     pub(crate) fn has(self, agent: &mut Agent, key: WeakKey<'m>) -> bool {
         self.get_mut(agent).has(key)
     }
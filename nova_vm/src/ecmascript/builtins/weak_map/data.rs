@@ -35,6 +35,16 @@ impl<'a> WeakMapRecord<'a> {
         self.weak_map_data.get(&key).cloned()
     }
 
+    #[cfg(feature = "proposal-upsert")]
+    pub(super) fn get_or_insert(&mut self, key: WeakKey<'a>, value: Value<'a>) -> Value<'a> {
+        // 4. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
+        // a. If p.[[Key]] is not EMPTY and SameValue(p.[[Key]], key) is true, return p.[[Value]].
+        // 5. Let p be the Record { [[Key]]: key, [[Value]]: value }.
+        // 6. Append p to M.[[WeakMapData]].
+        // 7. Return value.
+        *self.weak_map_data.entry(key).or_insert(value)
+    }
+
     pub(super) fn has(&mut self, key: WeakKey<'a>) -> bool {
         // 4. For each Record { [[Key]], [[Value]] } p of M.[[WeakMapData]], do
         // a. If p.[[Key]] is not empty and SameValue(p.[[Key]], key) is true, return true.
@@ -8,7 +8,9 @@ mod agent;
 mod default_host_hooks;
 mod environments;
 mod execution_context;
+mod profiler;
 mod realm;
+mod stack_trace;
 #[cfg(feature = "weak-refs")]
 mod weak_key;
 #[cfg(feature = "weak-refs")]
@@ -18,7 +20,9 @@ pub use agent::*;
 pub use default_host_hooks::*;
 pub(crate) use environments::*;
 pub(crate) use execution_context::*;
+pub use profiler::*;
 pub use realm::*;
+pub use stack_trace::*;
 #[cfg(feature = "weak-refs")]
 pub(crate) use weak_key::*;
 #[cfg(feature = "weak-refs")]
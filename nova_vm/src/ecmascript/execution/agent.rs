@@ -27,31 +27,44 @@ use crate::ecmascript::GlobalEnvironment;
 use crate::ecmascript::SharedArrayBuffer;
 #[cfg(feature = "atomics")]
 use crate::ecmascript::WaitAsyncJob;
+#[cfg(feature = "date")]
+use crate::ecmascript::to_primary_time_zone_identifier;
 #[cfg(feature = "weak-refs")]
 use crate::ecmascript::{FinalizationRegistryCleanupJob, clear_kept_objects};
 use crate::{
     ecmascript::{
-        AbstractModuleMethods, Environment, ErrorHeapData, ExecutionContext, Function,
-        GraphLoadingStateRecord, HostDefined, ModuleRequest, Object, OrdinaryObject,
-        PrivateEnvironment, PrivateName, Promise, PromiseReactionJob, PromiseResolveThenableJob,
-        PropertyKey, PropertyLookupCache, Realm, RealmRecord, Reference, Referrer, ScriptOrModule,
-        SourceCode, SourceTextModule, String, Symbol, Value, ValueRootRepr,
-        get_identifier_reference, initialize_default_realm, initialize_host_defined_realm,
-        parse_script, script_evaluation, to_string, try_get_identifier_reference,
+        AbstractModuleMethods, CpuProfile, CpuProfiler, Environment, ErrorHeapData,
+        ExecutionContext, Function, GraphLoadingStateRecord, HostDefined, ModuleRequest, Object,
+        OrdinaryObject, PrivateEnvironment, PrivateName, Promise, PromiseReactionJob,
+        PromiseResolveThenableJob, PropertyKey, PropertyLookupCache, Realm, RealmRecord, Reference,
+        Referrer, ScriptOrModule, SourceCode, SourceTextModule, String, Symbol, Value,
+        ValueRootRepr, get_identifier_reference, initialize_default_realm,
+        initialize_host_defined_realm, parse_script, script_evaluation, to_string,
+        try_get_identifier_reference,
     },
     engine::{
         Bindable, GcScope, Global, HeapRootCollection, HeapRootData, HeapRootRef, NoGcScope,
         Rootable, Vm, bindable_handle,
     },
     heap::{
-        ArenaAccess, CompactionLists, CreateHeapData, Heap, HeapIndexHandle, HeapMarkAndSweep,
-        PrimitiveHeapAccess, WorkQueues, heap_gc,
+        AllocationError, ArenaAccess, CompactionLists, CreateHeapData, Heap, HeapIndexHandle,
+        HeapMarkAndSweep, HeapStatistics, PrimitiveHeapAccess, WorkQueues, heap_gc,
+        write_heap_snapshot,
     },
     ndt,
 };
 
-use core::{any::Any, cell::RefCell, ops::ControlFlow, ptr::NonNull};
-use std::collections::TryReserveError;
+#[cfg(feature = "date")]
+use core::cell::OnceCell;
+use core::{
+    any::Any,
+    cell::RefCell,
+    ops::ControlFlow,
+    ptr::NonNull,
+    sync::atomic::{AtomicU8, Ordering},
+    time::Duration,
+};
+use std::sync::Arc;
 
 /// Creation options for [`GcAgent`].
 ///
@@ -67,6 +80,134 @@ pub struct AgentOptions {
     /// calling `Atomics.wait()` will throw an error to signal that blocking the
     /// main thread is not allowed.
     pub no_block: bool,
+    /// Limits the number of backward jumps and function calls the Agent may
+    /// perform. When the fuel runs out, the Agent is interrupted as if by an
+    /// [`InterruptHandle`]. The remaining fuel can be read and refilled using
+    /// [`Agent::remaining_fuel`] and [`Agent::set_fuel`].
+    pub fuel: Option<u64>,
+    /// Limits the estimated number of bytes the Agent's heap may use. When the
+    /// limit is reached, the Agent performs garbage collection and, if the
+    /// heap is still over the limit, calls [`HostHooks::near_heap_limit`].
+    /// If the host does not raise the limit, a RangeError is thrown.
+    pub max_heap_bytes: Option<usize>,
+    /// Limits the number of nested function calls. When the limit is
+    /// exceeded, a RangeError is thrown.
+    ///
+    /// If neither this nor [`AgentOptions::native_stack_reserve`] is set, the
+    /// call depth is limited to 3500 nested calls.
+    pub max_call_depth: Option<usize>,
+    /// Number of bytes of native stack that must remain free when a function
+    /// is called or a builtin recurses into nested objects, eg. in
+    /// `JSON.stringify`, `Array.prototype.flat` or through chains of Proxies.
+    /// If less stack remains, a RangeError is thrown instead. If not set, the
+    /// remaining native stack is not checked.
+    ///
+    /// If the `native-stack-limit` feature is disabled or the remaining native
+    /// stack cannot be determined on the current platform, and no
+    /// [`AgentOptions::max_call_depth`] is set, the call depth is limited to
+    /// 3500 nested calls instead.
+    pub native_stack_reserve: Option<usize>,
+    /// Enables generational garbage collection. Garbage collections triggered
+    /// by allocation then only collect the young generation, ie. data
+    /// allocated since the previous garbage collection, unless the old
+    /// generation has grown enough to warrant a full garbage collection.
+    ///
+    /// Explicit calls to [`GcAgent::gc`] and [`Agent::gc`] always collect the
+    /// whole heap.
+    pub generational_gc: bool,
+    /// Controls when the Agent performs garbage collection as a result of
+    /// allocation. Defaults to [`GcTrigger::AllocatedBytes`] with a threshold
+    /// of 2 MiB.
+    pub gc_trigger: GcTrigger,
+}
+
+/// Policy for triggering garbage collection during JavaScript execution.
+///
+/// Regardless of the policy, garbage collection is also performed when the
+/// heap is over its size limit, see [`AgentOptions::max_heap_bytes`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GcTrigger {
+    /// Perform garbage collection after the given number of bytes has been
+    /// allocated since the last garbage collection.
+    AllocatedBytes(usize),
+    /// Perform garbage collection once the heap has grown by the given factor
+    /// compared to its size after the last garbage collection, but only after
+    /// at least `min_bytes` bytes have been allocated.
+    ///
+    /// For example, a factor of 2.0 collects garbage whenever the heap has
+    /// doubled in size.
+    HeapGrowth {
+        /// Growth factor of the heap that triggers garbage collection.
+        factor: f64,
+        /// Minimum number of bytes allocated between garbage collections.
+        min_bytes: usize,
+    },
+}
+
+impl Default for GcTrigger {
+    fn default() -> Self {
+        Self::AllocatedBytes(1024 * 1024 * 2)
+    }
+}
+
+/// # Agent interrupt handle
+///
+/// A thread-safe handle for stopping JavaScript execution in an [`Agent`]
+/// from the outside, eg. from a watchdog thread. The handle can be obtained
+/// using [`GcAgent::interrupt_handle`] or [`Agent::interrupt_handle`].
+///
+/// Interrupting the Agent makes the running JavaScript code throw an
+/// uncatchable error at its next backward jump or function call:
+/// `catch` and `finally` blocks are not run, builtins such as the Promise
+/// constructor do not turn the error into a promise rejection, and the error
+/// propagates out to the embedder. The interrupt is cleared when control returns from
+/// [`GcAgent::run_in_realm`] or [`GcAgent::run_job`].
+#[derive(Debug, Clone)]
+pub struct InterruptHandle(Arc<SafePointRequests>);
+
+impl InterruptHandle {
+    /// Interrupt the JavaScript execution of the Agent.
+    pub fn interrupt(&self) {
+        self.0.request(SafePointRequests::INTERRUPT);
+    }
+
+    /// Returns true if the Agent has been interrupted through an
+    /// InterruptHandle and the interrupt has not yet been cleared. Running out
+    /// of fuel is not reported here.
+    pub fn is_interrupted(&self) -> bool {
+        self.0.load() & SafePointRequests::INTERRUPT != 0
+    }
+}
+
+/// Requests for an Agent to act at its next safe point, ie. its next backward
+/// jump or function call. The requests are set from other threads and share a
+/// single atomic so that a safe point only performs a single load.
+#[derive(Debug, Default)]
+pub(crate) struct SafePointRequests(AtomicU8);
+
+impl SafePointRequests {
+    /// The Agent's JavaScript execution should be interrupted.
+    pub(crate) const INTERRUPT: u8 = 1 << 0;
+    /// The CPU profiler should sample the JavaScript call stack.
+    pub(crate) const CPU_PROFILE_SAMPLE: u8 = 1 << 1;
+    /// The Agent has run out of fuel and its JavaScript execution should be
+    /// interrupted.
+    pub(crate) const FUEL_EXHAUSTED: u8 = 1 << 2;
+    /// Requests that interrupt the Agent's JavaScript execution.
+    pub(crate) const INTERRUPTS: u8 = Self::INTERRUPT | Self::FUEL_EXHAUSTED;
+
+    #[inline(always)]
+    pub(crate) fn load(&self) -> u8 {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    pub(crate) fn request(&self, requests: u8) {
+        self.0.fetch_or(requests, Ordering::Relaxed);
+    }
+
+    pub(crate) fn clear(&self, requests: u8) {
+        self.0.fetch_and(!requests, Ordering::Relaxed);
+    }
 }
 
 /// Result of methods that may throw a JavaScript error.
@@ -291,7 +432,7 @@ impl Job {
     ///
     /// The execution of a Job never returns any result but it may throw an
     /// error value.
-    pub fn run<'a>(self, agent: &mut Agent, gc: GcScope<'a, '_>) -> JsResult<'a, ()> {
+    pub fn run<'a>(self, agent: &mut Agent, mut gc: GcScope<'a, '_>) -> JsResult<'a, ()> {
         let mut id = 0;
         ndt::job_evaluation_start!(|| {
             id = core::ptr::from_ref(&self).addr() as u64;
@@ -311,16 +452,25 @@ impl Job {
         }
 
         let result = match self.inner {
-            InnerJob::PromiseResolveThenable(job) => job.run(agent, gc),
-            InnerJob::PromiseReaction(job) => job.run(agent, gc),
+            InnerJob::PromiseResolveThenable(job) => job.run(agent, gc.reborrow()),
+            InnerJob::PromiseReaction(job) => job.run(agent, gc.reborrow()),
             #[cfg(feature = "atomics")]
-            InnerJob::WaitAsync(job) => job.run(agent, gc),
+            InnerJob::WaitAsync(job) => job.run(agent, gc.reborrow()),
             #[cfg(feature = "weak-refs")]
             InnerJob::FinalizationRegistry(job) => {
-                job.run(agent, gc);
+                job.run(agent, gc.reborrow());
                 Ok(())
             }
-        };
+        }
+        .unbind();
+        let gc = gc.into_nogc();
+        let mut result = result.bind(gc);
+        // Note: the Job may have turned an interrupt into a promise rejection;
+        // the interrupt must still propagate out to the embedder.
+        if result.is_ok() && agent.is_interrupted() {
+            let requests = agent.safe_point_requests.load();
+            result = Err(agent.throw_interrupt(requests, gc));
+        }
 
         if pushed_context {
             agent.execution_context_stack.pop();
@@ -357,6 +507,21 @@ pub enum GrowSharedArrayBufferResult {
     Handled = 1,
 }
 
+/// Parameter to the [`HostHooks::format_date_for_locale`] embedder hook.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg(feature = "date")]
+pub enum LocaleDateFormat {
+    /// Format both the date and time portions of the Date, as in
+    /// `Date.prototype.toLocaleString`.
+    DateTime,
+    /// Format the date portion of the Date, as in
+    /// `Date.prototype.toLocaleDateString`.
+    Date,
+    /// Format the time portion of the Date, as in
+    /// `Date.prototype.toLocaleTimeString`.
+    Time,
+}
+
 /// Trait the Nova JavaScript engine to interact with the embedder. The embedder
 /// calls methods are defined by the ECMAScript specification.
 pub trait HostHooks: core::fmt::Debug {
@@ -378,6 +543,17 @@ pub trait HostHooks: core::fmt::Debug {
         true
     }
 
+    /// Get the name of a Script or Module source for use in stack traces,
+    /// such as its file name or URL. The `host_defined` argument is the
+    /// \[\[HostDefined]] field of the Script or Module Record.
+    ///
+    /// The default implementation returns None, in which case the source is
+    /// shown as `<anonymous>`.
+    #[allow(unused_variables)]
+    fn get_source_name(&self, host_defined: Option<HostDefined>) -> Option<std::string::String> {
+        None
+    }
+
     /// ### [9.5.4 HostEnqueueGenericJob ( job, realm )](https://tc39.es/ecma262/#sec-hostenqueuegenericjob)
     ///
     /// The host-defined abstract operation HostEnqueueGenericJob takes
@@ -647,6 +823,78 @@ pub trait HostHooks: core::fmt::Debug {
         Ok(GrowSharedArrayBufferResult::Unhandled)
     }
 
+    /// ### [21.4.1.24 SystemTimeZoneIdentifier ( )](https://tc39.es/ecma262/#sec-systemtimezoneidentifier)
+    ///
+    /// Returns the host environment's current time zone, which should be
+    /// either an IANA time zone name or a UTC offset string. If `None` is
+    /// returned, or if the returned time zone is not recognised, the engine
+    /// uses the UTC time zone.
+    ///
+    /// The time zone is queried once per Agent, the first time it is needed.
+    ///
+    /// The default implementation uses the `TZ` environment variable if set,
+    /// and otherwise the time zone setting of the host system.
+    #[cfg(feature = "date")]
+    fn get_system_time_zone_identifier(&self) -> Option<std::string::String> {
+        if let Ok(tz) = std::env::var("TZ") {
+            // Note: POSIX allows prefixing the TZ value with a colon.
+            return Some(tz.strip_prefix(':').unwrap_or(&tz).to_string());
+        }
+        iana_time_zone::get_timezone().ok()
+    }
+
+    /// ### [2.3.2 SystemUTCEpochNanoseconds ( )](https://tc39.es/proposal-temporal/#sec-temporal-systemutcepochnanoseconds)
+    ///
+    /// Returns the host environment's current time as the number of
+    /// nanoseconds since the epoch, 1970-01-01T00:00:00Z. This is used by the
+    /// `Temporal.Now` functions.
+    ///
+    /// The default implementation uses the system clock.
+    #[cfg(feature = "temporal")]
+    fn get_system_utc_epoch_nanoseconds(&self) -> i128 {
+        let now = std::time::SystemTime::now();
+        match now.duration_since(std::time::SystemTime::UNIX_EPOCH) {
+            Ok(duration) => duration.as_nanos() as i128,
+            Err(err) => -(err.duration().as_nanos() as i128),
+        }
+    }
+
+    /// Formats a Date in a human-readable form that corresponds to the
+    /// conventions of the host environment's current locale. This is used by
+    /// `Date.prototype.toLocaleString`, `Date.prototype.toLocaleDateString`,
+    /// and `Date.prototype.toLocaleTimeString`.
+    ///
+    /// `time_value` is the Date's time value in milliseconds since the epoch,
+    /// and `local_time` is the same time converted to the system time zone.
+    /// Both are always finite.
+    ///
+    /// The default implementation returns `None`, in which case the Date is
+    /// always formatted following the conventions of the "en-US" locale,
+    /// whatever the host's locale is. Hosts that want their locale to be
+    /// followed must implement this hook.
+    #[allow(unused_variables)]
+    #[cfg(feature = "date")]
+    fn format_date_for_locale(
+        &self,
+        time_value: f64,
+        local_time: f64,
+        format: LocaleDateFormat,
+    ) -> Option<std::string::String> {
+        None
+    }
+
+    /// Called when the Agent's heap is over its size limit even after garbage
+    /// collection. `heap_bytes` is the estimated current size of the heap,
+    /// and `max_heap_bytes` is the current limit.
+    ///
+    /// The host can return a new, larger limit to let the execution continue.
+    /// The default implementation returns `None`, in which case a RangeError
+    /// is thrown.
+    #[allow(unused_variables)]
+    fn near_heap_limit(&self, heap_bytes: usize, max_heap_bytes: usize) -> Option<usize> {
+        None
+    }
+
     /// Get access to the Host data, useful to share state between calls of
     /// built-in functions.
     ///
@@ -800,6 +1048,9 @@ impl GcAgent {
         let realm = self.get_realm_by_root(realm);
         assert!(self.agent.execution_context_stack.is_empty());
         let result = self.agent.run_in_realm(realm, func);
+        self.agent
+            .safe_point_requests
+            .clear(SafePointRequests::INTERRUPTS);
         #[cfg(feature = "weak-refs")]
         clear_kept_objects(&mut self.agent);
         assert!(self.agent.execution_context_stack.is_empty());
@@ -819,6 +1070,9 @@ impl GcAgent {
     {
         assert!(self.agent.execution_context_stack.is_empty());
         let result = self.agent.run_job(job, then);
+        self.agent
+            .safe_point_requests
+            .clear(SafePointRequests::INTERRUPTS);
         #[cfg(feature = "weak-refs")]
         clear_kept_objects(&mut self.agent);
         assert!(self.agent.execution_context_stack.is_empty());
@@ -827,6 +1081,38 @@ impl GcAgent {
         result
     }
 
+    /// Get a handle for interrupting JavaScript execution in this Agent from
+    /// another thread.
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        self.agent.interrupt_handle()
+    }
+
+    /// Returns statistics of the Agent's heap and of the last garbage
+    /// collection.
+    pub fn heap_statistics(&self) -> HeapStatistics {
+        self.agent.heap_statistics()
+    }
+
+    /// Start sampling the JavaScript call stack at the given interval.
+    ///
+    /// See [`Agent::start_cpu_profiler`].
+    pub fn start_cpu_profiler(&mut self, interval: Duration) {
+        self.agent.start_cpu_profiler(interval);
+    }
+
+    /// Stop the CPU profiler and return the collected profile, or `None` if
+    /// the profiler was not running.
+    pub fn stop_cpu_profiler(&mut self) -> Option<CpuProfile> {
+        self.agent.stop_cpu_profiler()
+    }
+
+    /// Writes a snapshot of the Agent's heap in the Chrome DevTools
+    /// `.heapsnapshot` format. The snapshot can be loaded into the Memory tab
+    /// of Chrome DevTools for inspection.
+    pub fn write_heap_snapshot(&self, writer: impl std::io::Write) -> std::io::Result<()> {
+        write_heap_snapshot(&self.agent, &self.realm_roots, writer)
+    }
+
     fn get_realm_by_root(&self, realm_root: &RealmRoot) -> Realm<'static> {
         let index = realm_root.index;
         let error_message = "Couldn't find Realm by RealmRoot";
@@ -852,7 +1138,7 @@ impl GcAgent {
         let Self {
             agent, realm_roots, ..
         } = self;
-        heap_gc(agent, realm_roots, gc);
+        heap_gc(agent, realm_roots, false, gc);
     }
 }
 
@@ -870,7 +1156,7 @@ pub struct Agent {
     symbol_id: usize,
     pub(crate) global_symbol_registry: AHashMap<String<'static>, Symbol<'static>>,
     pub(crate) host_hooks: &'static dyn HostHooks,
-    execution_context_stack: Vec<ExecutionContext>,
+    pub(crate) execution_context_stack: Vec<ExecutionContext>,
     /// Temporary storage for on-stack heap roots.
     ///
     /// TODO: With Realm-specific heaps we'll need a side-table to define which
@@ -894,12 +1180,25 @@ pub struct Agent {
     /// \[\[AsyncEvaluationOrder]] field of modules that are asynchronous or
     /// have asynchronous dependencies.
     module_async_evaluation_count: u32,
+    /// Cached result of SystemTimeZoneIdentifier.
+    #[cfg(feature = "date")]
+    system_time_zone_identifier: OnceCell<Box<str>>,
+    /// Interrupt and CPU profiler requests checked at safe points.
+    pub(crate) safe_point_requests: Arc<SafePointRequests>,
+    /// Remaining number of backward jumps and function calls the Agent may
+    /// perform.
+    fuel: Option<u64>,
+    /// Running sampling CPU profiler, if any.
+    pub(crate) cpu_profiler: Option<CpuProfiler>,
 }
 
 impl Agent {
     pub(crate) fn new(options: AgentOptions, host_hooks: &'static dyn HostHooks) -> Self {
+        let fuel = options.fuel;
+        let mut heap = Heap::new();
+        heap.set_max_heap_bytes(options.max_heap_bytes);
         Self {
-            heap: Heap::new(),
+            heap,
             options,
             symbol_id: 0,
             global_symbol_registry: AHashMap::default(),
@@ -912,6 +1211,11 @@ impl Agent {
             kept_alive: false,
             private_names_counter: 0,
             module_async_evaluation_count: 0,
+            #[cfg(feature = "date")]
+            system_time_zone_identifier: OnceCell::new(),
+            safe_point_requests: Arc::default(),
+            fuel,
+            cpu_profiler: None,
         }
     }
 
@@ -934,16 +1238,152 @@ impl Agent {
             .enumerate()
             .map(|(i, _)| Some(Realm::from_index(i)))
             .collect::<Vec<_>>();
-        heap_gc(self, &mut root_realms, gc);
+        heap_gc(self, &mut root_realms, false, gc);
     }
 
-    /// Checks if garbage collection should be performed based on the number of
-    /// bytes allocated since last garbage collection.
+    /// Perform young generation garbage collection on the Agent's heap. Only
+    /// data allocated since the previous garbage collection is collected.
+    ///
+    /// If generational garbage collection is not enabled in the
+    /// [`AgentOptions`], the heap is over its size limit, or the old
+    /// generation has grown enough since the last full garbage collection,
+    /// a full garbage collection is performed instead.
+    ///
+    /// Like [`Agent::gc`], this invalidates all handles.
+    pub fn young_gc(&mut self, gc: GcScope) {
+        if !self.options.generational_gc
+            || self.heap.is_over_heap_limit()
+            || self.heap.is_old_generation_grown()
+        {
+            return self.gc(gc);
+        }
+        let mut root_realms = self
+            .heap
+            .realms
+            .iter()
+            .enumerate()
+            .map(|(i, _)| Some(Realm::from_index(i)))
+            .collect::<Vec<_>>();
+        heap_gc(self, &mut root_realms, true, gc);
+    }
+
+    /// Checks if garbage collection should be performed based on the
+    /// [`GcTrigger`] policy and the heap size limit.
     pub(crate) fn check_gc(&mut self) -> bool {
-        // Perform garbage collection if over 2 MiB of allocations have been
-        // performed since last GC.
-        const ALLOC_COUNTER_LIMIT: usize = 1024 * 1024 * 2;
-        self.heap.alloc_counter > ALLOC_COUNTER_LIMIT
+        let triggered = match self.options.gc_trigger {
+            GcTrigger::AllocatedBytes(bytes) => self.heap.alloc_counter > bytes,
+            GcTrigger::HeapGrowth { factor, min_bytes } => {
+                self.heap.alloc_counter > min_bytes
+                    && self.heap.estimated_byte_size() as f64
+                        > self.heap.retained_bytes as f64 * factor
+            }
+        };
+        triggered || self.heap.is_over_heap_limit()
+    }
+
+    /// Returns statistics of the Agent's heap and of the last garbage
+    /// collection.
+    pub fn heap_statistics(&self) -> HeapStatistics {
+        HeapStatistics::new(&self.heap)
+    }
+
+    /// Writes a snapshot of the Agent's heap in the Chrome DevTools
+    /// `.heapsnapshot` format. All Realms are treated as roots.
+    pub fn write_heap_snapshot(&self, writer: impl std::io::Write) -> std::io::Result<()> {
+        let root_realms = self
+            .heap
+            .realms
+            .iter()
+            .enumerate()
+            .map(|(i, _)| Some(Realm::from_index(i)))
+            .collect::<Vec<_>>();
+        write_heap_snapshot(self, &root_realms, writer)
+    }
+
+    /// Checks that the heap is not over its size limit after garbage
+    /// collection. If it is, the host is asked for a new limit and if none is
+    /// given, a RangeError is thrown.
+    pub(crate) fn check_heap_limit<'a>(&mut self, gc: NoGcScope<'a, '_>) -> JsResult<'a, ()> {
+        if !self.heap.is_over_heap_limit() {
+            return Ok(());
+        }
+        let heap_bytes = self.heap.estimated_byte_size();
+        self.near_heap_limit(heap_bytes, gc)
+    }
+
+    /// Checks that a single large allocation of `bytes` bytes, such as the
+    /// data of a string or an ArrayBuffer, fits within the heap size limit.
+    /// The allocation does not fit if it would take the heap over the limit
+    /// even if everything allocated since the last garbage collection was
+    /// garbage. If it does not fit, the host is asked for a new limit and if
+    /// none is given, a RangeError is thrown.
+    pub(crate) fn check_heap_allocation<'a>(
+        &mut self,
+        bytes: usize,
+        gc: NoGcScope<'a, '_>,
+    ) -> JsResult<'a, ()> {
+        let Some(max_heap_bytes) = self.heap.max_heap_bytes else {
+            return Ok(());
+        };
+        let heap_bytes = self.heap.retained_bytes.saturating_add(bytes);
+        if heap_bytes <= max_heap_bytes + self.heap.heap_limit_grace_bytes {
+            return Ok(());
+        }
+        self.near_heap_limit(heap_bytes, gc)
+    }
+
+    fn near_heap_limit<'a>(
+        &mut self,
+        heap_bytes: usize,
+        gc: NoGcScope<'a, '_>,
+    ) -> JsResult<'a, ()> {
+        let max_heap_bytes = self.heap.max_heap_bytes.unwrap();
+        match self.host_hooks.near_heap_limit(heap_bytes, max_heap_bytes) {
+            Some(new_limit) if new_limit > heap_bytes => {
+                self.heap.set_max_heap_bytes(Some(new_limit));
+                Ok(())
+            }
+            _ => Err(self.throw_heap_limit_exceeded(gc)),
+        }
+    }
+
+    /// ### [21.4.1.24 SystemTimeZoneIdentifier ( )](https://tc39.es/ecma262/#sec-systemtimezoneidentifier)
+    ///
+    /// The implementation-defined abstract operation SystemTimeZoneIdentifier
+    /// takes no arguments and returns a String. It returns a String
+    /// representing the host environment's current time zone, which is either
+    /// a String representing a UTC offset for which IsTimeZoneOffsetString
+    /// returns true, or a primary time zone identifier.
+    ///
+    /// > NOTE: To ensure the level of functionality that implementations
+    /// > commonly provide in the methods of the Date object, it is recommended
+    /// > that SystemTimeZoneIdentifier return an IANA time zone name
+    /// > corresponding to the host environment's time zone setting, if such a
+    /// > thing exists. GetNamedTimeZoneEpochNanoseconds and
+    /// > GetNamedTimeZoneOffsetNanoseconds must reflect the local political
+    /// > rules for standard time and daylight saving time in that time zone,
+    /// > if such rules exist.
+    /// >
+    /// > For example, if the host environment is a browser on a system where
+    /// > the user has chosen US Eastern Time as their time zone,
+    /// > SystemTimeZoneIdentifier returns "America/New_York".
+    ///
+    /// The host's time zone is queried once and cached for the lifetime of the
+    /// Agent. This is shared by Date and Temporal.Now.
+    #[cfg(feature = "date")]
+    pub(crate) fn system_time_zone_identifier(&self) -> &str {
+        self.system_time_zone_identifier.get_or_init(|| {
+            // 1. If the implementation only supports the UTC time zone, return "UTC".
+            // 2. Let systemTimeZoneString be the String representing the host environment's
+            // current time zone, either a primary time zone identifier or an offset time zone identifier.
+            let Some(time_zone) = self.host_hooks.get_system_time_zone_identifier() else {
+                return "UTC".into();
+            };
+            // Note: unknown time zones are treated as UTC; known time zones are
+            // normalised to their primary time zone identifier.
+            to_primary_time_zone_identifier(&time_zone).unwrap_or_else(|| "UTC".into())
+            // 3. Return systemTimeZoneString.
+        })
     }
 
     fn get_created_realm_root(&mut self) -> Realm<'static> {
@@ -1118,18 +1558,12 @@ impl Agent {
         gc: NoGcScope<'a, '_>,
     ) -> Value<'a> {
         let message = String::from_static_str(self, message, gc).unbind();
-        self.heap
+        let error = self
+            .heap
             .create(ErrorHeapData::new(kind, Some(message), None))
-            .into()
-    }
-
-    #[must_use]
-    pub(crate) fn todo<'a>(&mut self, feature: &'static str, gc: NoGcScope<'a, '_>) -> JsError<'a> {
-        self.throw_exception(
-            ExceptionType::Error,
-            format!("{feature} not implemented"),
-            gc,
-        )
+            .bind(gc);
+        error.capture_stack_trace(self, 0, gc);
+        error.into()
     }
 
     /// ### [5.2.3.2 Throw an Exception](https://tc39.es/ecma262/#sec-throw-an-exception)
@@ -1140,9 +1574,12 @@ impl Agent {
         message: &'static str,
         gc: NoGcScope<'a, '_>,
     ) -> JsError<'a> {
+        let message = String::from_static_str(self, message, gc).unbind();
         JsError(
-            self.create_exception_with_static_message(kind, message, gc)
-                .unbind(),
+            self.heap
+                .create(ErrorHeapData::new(kind, Some(message), None).with_pending_stack())
+                .bind(gc)
+                .into(),
         )
     }
 
@@ -1157,7 +1594,8 @@ impl Agent {
         let message = String::from_string(self, message, gc).unbind();
         JsError(
             self.heap
-                .create(ErrorHeapData::new(kind, Some(message), None))
+                .create(ErrorHeapData::new(kind, Some(message), None).with_pending_stack())
+                .bind(gc)
                 .into(),
         )
     }
@@ -1172,7 +1610,7 @@ impl Agent {
     ) -> JsError<'a> {
         JsError(
             self.heap
-                .create(ErrorHeapData::new(kind, Some(message.unbind()), None))
+                .create(ErrorHeapData::new(kind, Some(message.unbind()), None).with_pending_stack())
                 .bind(gc)
                 .into(),
         )
@@ -1182,10 +1620,31 @@ impl Agent {
     #[must_use]
     pub(crate) fn throw_allocation_exception<'a>(
         &mut self,
-        error: TryReserveError,
+        error: impl Into<AllocationError>,
         gc: NoGcScope<'a, '_>,
     ) -> JsError<'a> {
-        self.throw_exception(ExceptionType::RangeError, error.to_string(), gc)
+        match error.into() {
+            AllocationError::HeapLimitExceeded => self.throw_heap_limit_exceeded(gc),
+            AllocationError::TryReserve(error) => {
+                self.throw_exception(ExceptionType::RangeError, error.to_string(), gc)
+            }
+        }
+    }
+
+    /// Throws the RangeError for an allocation that would take the heap over
+    /// its size limit.
+    #[cold]
+    #[inline(never)]
+    pub(crate) fn throw_heap_limit_exceeded<'a>(&mut self, gc: NoGcScope<'a, '_>) -> JsError<'a> {
+        // Give exception handlers some room to release memory before the
+        // limit is checked again.
+        const HEAP_LIMIT_GRACE_BYTES: usize = 1024 * 1024;
+        self.heap.heap_limit_grace_bytes = HEAP_LIMIT_GRACE_BYTES;
+        self.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Out of memory: heap size limit exceeded",
+            gc,
+        )
     }
 
     pub(crate) fn running_execution_context(&self) -> &ExecutionContext {
@@ -1207,19 +1666,168 @@ impl Agent {
     }
 
     pub(crate) fn check_call_depth<'gc>(&mut self, gc: NoGcScope<'gc, '_>) -> JsResult<'gc, ()> {
-        // Experimental number that caused stack overflow on local machine. A
-        // better limit creation logic would be nice.
-        if self.execution_context_stack.len() > 3500 {
-            Err(self.throw_exception_with_static_message(
-                ExceptionType::RangeError,
-                "Maximum call stack size exceeded",
-                gc,
-            ))
+        // Note: function calls are safe points.
+        self.check_safe_point(gc)?;
+        /// Experimental number that caused stack overflow on local machine;
+        /// used unless the embedder configured the call depth or native stack
+        /// limits.
+        const DEFAULT_MAX_CALL_DEPTH: usize = 3500;
+        let depth = self.execution_context_stack.len();
+        let exceeded = match (
+            self.options.max_call_depth,
+            self.is_native_stack_exhausted(),
+        ) {
+            (Some(max_call_depth), exhausted) => depth > max_call_depth || exhausted == Some(true),
+            (None, Some(exhausted)) => exhausted,
+            (None, None) => depth > DEFAULT_MAX_CALL_DEPTH,
+        };
+        if exceeded {
+            Err(self.throw_call_stack_exceeded(gc))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Throws a RangeError if less than [`AgentOptions::native_stack_reserve`]
+    /// bytes of native stack remain. Builtins that recurse without calling
+    /// functions, eg. `JSON.stringify` on nested objects, call this on every
+    /// level of recursion.
+    pub(crate) fn check_native_stack<'gc>(&mut self, gc: NoGcScope<'gc, '_>) -> JsResult<'gc, ()> {
+        if self.is_native_stack_exhausted() == Some(true) {
+            Err(self.throw_call_stack_exceeded(gc))
         } else {
             Ok(())
         }
     }
 
+    /// Returns true if less than [`AgentOptions::native_stack_reserve`] bytes
+    /// of native stack remain, or `None` if no reserve is configured or the
+    /// remaining native stack cannot be determined.
+    #[inline]
+    fn is_native_stack_exhausted(&self) -> Option<bool> {
+        let reserve = self.options.native_stack_reserve?;
+        #[cfg(feature = "native-stack-limit")]
+        let remaining_stack = stacker::remaining_stack();
+        #[cfg(not(feature = "native-stack-limit"))]
+        let remaining_stack: Option<usize> = None;
+        Some(remaining_stack? < reserve)
+    }
+
+    #[cold]
+    #[inline(never)]
+    fn throw_call_stack_exceeded<'gc>(&mut self, gc: NoGcScope<'gc, '_>) -> JsError<'gc> {
+        self.throw_exception_with_static_message(
+            ExceptionType::RangeError,
+            "Maximum call stack size exceeded",
+            gc,
+        )
+    }
+
+    /// Get a handle for interrupting JavaScript execution in this Agent from
+    /// another thread.
+    pub fn interrupt_handle(&self) -> InterruptHandle {
+        InterruptHandle(self.safe_point_requests.clone())
+    }
+
+    /// Returns the remaining number of backward jumps and function calls the
+    /// Agent may perform, or `None` if execution is not limited.
+    pub fn remaining_fuel(&self) -> Option<u64> {
+        self.fuel
+    }
+
+    /// Set the number of backward jumps and function calls the Agent may
+    /// perform, or `None` to not limit execution.
+    pub fn set_fuel(&mut self, fuel: Option<u64>) {
+        self.fuel = fuel;
+    }
+
+    /// Consumes fuel, samples the call stack if the CPU profiler requested it
+    /// and throws an uncatchable error if the Agent's JavaScript execution
+    /// has been interrupted. This is called on function calls; the VM checks
+    /// its own safe points using [`Agent::take_safe_point_requests`].
+    #[inline(always)]
+    pub(crate) fn check_safe_point<'gc>(&mut self, gc: NoGcScope<'gc, '_>) -> JsResult<'gc, ()> {
+        let requests = self.take_safe_point_requests();
+        if requests == 0 {
+            return Ok(());
+        }
+        if requests & SafePointRequests::CPU_PROFILE_SAMPLE != 0 {
+            // Note: the calling VM, if any, is on the VM stack and provides
+            // the source position of the innermost frame.
+            self.record_cpu_profile_sample(0, None, gc);
+        }
+        if requests & SafePointRequests::INTERRUPTS != 0 {
+            return Err(self.throw_interrupt(requests, gc));
+        }
+        Ok(())
+    }
+
+    /// Consumes fuel for a safe point and returns the pending
+    /// [`SafePointRequests`].
+    #[inline(always)]
+    pub(crate) fn take_safe_point_requests(&mut self) -> u8 {
+        self.consume_fuel();
+        self.safe_point_requests.load()
+    }
+
+    /// Consume fuel for a single safe point. Running out of fuel interrupts
+    /// the Agent.
+    #[inline(always)]
+    fn consume_fuel(&mut self) {
+        if let Some(fuel) = &mut self.fuel {
+            if let Some(remaining) = fuel.checked_sub(1) {
+                *fuel = remaining;
+            } else {
+                self.safe_point_requests
+                    .request(SafePointRequests::FUEL_EXHAUSTED);
+            }
+        }
+    }
+
+    /// Returns true if the Agent's JavaScript execution has been interrupted.
+    /// Interrupts cannot be caught by JavaScript code.
+    #[inline(always)]
+    pub(crate) fn is_interrupted(&self) -> bool {
+        self.safe_point_requests.load() & SafePointRequests::INTERRUPTS != 0
+    }
+
+    /// Throws an uncatchable error if the Agent's JavaScript execution has
+    /// been interrupted.
+    #[inline(always)]
+    pub(crate) fn check_interrupt<'gc>(&mut self, gc: NoGcScope<'gc, '_>) -> JsResult<'gc, ()> {
+        if self.is_interrupted() {
+            let requests = self.safe_point_requests.load();
+            Err(self.throw_interrupt(requests, gc))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Throws the uncatchable interrupt error.
+    ///
+    /// Builtins that turn abrupt completions into promise rejections or
+    /// otherwise handle them would also swallow the interrupt error; callers
+    /// of such builtins must check [`Agent::is_interrupted`] and use this
+    /// method to propagate the interrupt further.
+    ///
+    /// `requests` are the pending [`SafePointRequests`]; they tell whether the
+    /// Agent was interrupted through its [`InterruptHandle`] or ran out of
+    /// fuel.
+    #[cold]
+    #[inline(never)]
+    pub(crate) fn throw_interrupt<'gc>(
+        &mut self,
+        requests: u8,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsError<'gc> {
+        let message = if requests & SafePointRequests::INTERRUPT != 0 {
+            "Execution interrupted"
+        } else {
+            "Execution fuel exhausted"
+        };
+        self.throw_exception_with_static_message(ExceptionType::Error, message, gc)
+    }
+
     /// Returns the realm of the previous execution context.
     ///
     /// See steps 6-8 of [27.6.3.8 AsyncGeneratorYield ( value )](https://tc39.es/ecma262/#sec-asyncgeneratoryield).
@@ -1372,8 +1980,10 @@ impl Agent {
         count
     }
 
+    /// Returns the function object of the running execution context.
+    ///
     /// Panics if no active function object exists.
-    pub(crate) fn active_function_object<'a>(&self, gc: NoGcScope<'a, '_>) -> Function<'a> {
+    pub fn active_function_object<'a>(&self, gc: NoGcScope<'a, '_>) -> Function<'a> {
         let Some(f) = self
             .execution_context_stack
             .last()
@@ -1587,6 +2197,8 @@ pub enum ExceptionType {
     TypeError,
     /// ### [19.3.39 URIError ( . . . )](https://tc39.es/ecma262/#sec-constructor-properties-of-the-global-object-urierror)
     UriError,
+    /// ### [SuppressedError ( . . . )](https://tc39.es/proposal-explicit-resource-management/#sec-suppressederror-constructor)
+    SuppressedError,
 }
 
 impl TryFrom<u16> for ExceptionType {
@@ -1602,6 +2214,7 @@ impl TryFrom<u16> for ExceptionType {
             5 => Ok(Self::SyntaxError),
             6 => Ok(Self::TypeError),
             7 => Ok(Self::UriError),
+            8 => Ok(Self::SuppressedError),
             _ => Err(()),
         }
     }
@@ -1625,6 +2238,11 @@ impl HeapMarkAndSweep for Agent {
                 kept_alive: _,
             private_names_counter: _,
             module_async_evaluation_count: _,
+            #[cfg(feature = "date")]
+                system_time_zone_identifier: _,
+            safe_point_requests: _,
+            cpu_profiler: _,
+            fuel: _,
         } = self;
 
         execution_context_stack.iter().for_each(|ctx| {
@@ -1676,6 +2294,11 @@ impl HeapMarkAndSweep for Agent {
                 kept_alive: _,
             private_names_counter: _,
             module_async_evaluation_count: _,
+            #[cfg(feature = "date")]
+                system_time_zone_identifier: _,
+            safe_point_requests: _,
+            cpu_profiler: _,
+            fuel: _,
         } = self;
 
         execution_context_stack
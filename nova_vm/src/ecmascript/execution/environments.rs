@@ -46,7 +46,7 @@ use crate::{
         String, TryError, TryHasResult, TryResult, Value, js_result_into_try,
     },
     engine::{Bindable, GcScope, HeapRootData, NoGcScope, Scopable, bindable_handle},
-    heap::{CompactionLists, HeapIndexHandle, HeapMarkAndSweep, WorkQueues},
+    heap::{Arena, CompactionLists, HeapIndexHandle, HeapMarkAndSweep, WorkQueues},
 };
 
 /// ### [\[\[OuterEnv\]\]](https://tc39.es/ecma262/#sec-environment-records)
@@ -118,9 +118,9 @@ macro_rules! create_environment_index {
         }
 
         #[doc(hidden)]
-        impl AsMut<Vec<$record>> for crate::ecmascript::Agent {
+        impl AsMut<crate::heap::Arena<Vec<$record>>> for crate::ecmascript::Agent {
             #[inline(always)]
-            fn as_mut(&mut self) -> &mut Vec<$record> {
+            fn as_mut(&mut self) -> &mut crate::heap::Arena<Vec<$record>> {
                 &mut self.heap.environments.$entry
             }
         }
@@ -715,23 +715,68 @@ impl HeapMarkAndSweep for Environment<'static> {
 
 #[derive(Debug)]
 pub(crate) struct Environments {
-    pub(crate) declarative: Vec<DeclarativeEnvironmentRecord>,
-    pub(crate) function: Vec<FunctionEnvironmentRecord>,
-    pub(crate) global: Vec<GlobalEnvironmentRecord>,
-    pub(crate) object: Vec<ObjectEnvironmentRecord>,
-    pub(crate) module: Vec<ModuleEnvironmentRecord>,
-    pub(crate) private: Vec<PrivateEnvironmentRecord>,
+    pub(crate) declarative: Arena<Vec<DeclarativeEnvironmentRecord>>,
+    pub(crate) function: Arena<Vec<FunctionEnvironmentRecord>>,
+    pub(crate) global: Arena<Vec<GlobalEnvironmentRecord>>,
+    pub(crate) object: Arena<Vec<ObjectEnvironmentRecord>>,
+    pub(crate) module: Arena<Vec<ModuleEnvironmentRecord>>,
+    pub(crate) private: Arena<Vec<PrivateEnvironmentRecord>>,
+}
+
+impl Environments {
+    /// Calls `f` with the name, number of records, and number of bytes used by
+    /// each kind of environment record.
+    pub(crate) fn for_each_arena(&self, f: &mut impl FnMut(&'static str, usize, usize)) {
+        let Self {
+            declarative,
+            function,
+            global,
+            object,
+            module,
+            private,
+        } = self;
+        f(
+            "declarative_environments",
+            declarative.len(),
+            size_of_val(declarative.as_slice()),
+        );
+        f(
+            "function_environments",
+            function.len(),
+            size_of_val(function.as_slice()),
+        );
+        f(
+            "global_environments",
+            global.len(),
+            size_of_val(global.as_slice()),
+        );
+        f(
+            "object_environments",
+            object.len(),
+            size_of_val(object.as_slice()),
+        );
+        f(
+            "module_environments",
+            module.len(),
+            size_of_val(module.as_slice()),
+        );
+        f(
+            "private_environments",
+            private.len(),
+            size_of_val(private.as_slice()),
+        );
+    }
 }
 
 impl Default for Environments {
     fn default() -> Self {
         Self {
-            declarative: Vec::with_capacity(256),
-            function: Vec::with_capacity(1024),
-            global: Vec::with_capacity(1),
-            object: Vec::with_capacity(1024),
-            module: Vec::with_capacity(8),
-            private: Vec::with_capacity(0),
+            declarative: Arena::new(Vec::with_capacity(256)),
+            function: Arena::new(Vec::with_capacity(1024)),
+            global: Arena::new(Vec::with_capacity(1)),
+            object: Arena::new(Vec::with_capacity(1024)),
+            module: Arena::new(Vec::with_capacity(8)),
+            private: Arena::new(Vec::with_capacity(0)),
         }
     }
 }
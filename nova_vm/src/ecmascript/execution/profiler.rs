@@ -0,0 +1,316 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Sampling CPU profiler
+//!
+//! The profiler samples the JavaScript call stack of an Agent at a fixed
+//! interval. A timer thread flags the Agent when a sample is due, and the
+//! execution context stack is recorded at the Agent's next safe point, ie. its
+//! next backward jump or function call.
+//! The collected [`CpuProfile`] can be written in the Chrome DevTools
+//! `.cpuprofile` format or as folded stacks for flame graph tools.
+
+use core::time::Duration;
+use std::{
+    collections::BTreeMap,
+    io::{self, BufWriter, Write},
+    sync::{
+        Arc,
+        mpsc::{self, RecvTimeoutError},
+    },
+    time::Instant,
+};
+
+use ahash::AHashMap;
+
+use crate::{
+    ecmascript::{Agent, SafePointRequests, StackFrame},
+    engine::NoGcScope,
+    heap::write_json_string,
+};
+
+/// A CPU profile collected by the sampling profiler.
+///
+/// See [`Agent::start_cpu_profiler`] and [`Agent::stop_cpu_profiler`].
+#[derive(Debug, Clone)]
+pub struct CpuProfile {
+    /// All distinct stack frames seen in the samples.
+    pub frames: Vec<StackFrame>,
+    /// Samples in the order they were taken.
+    pub samples: Vec<CpuProfileSample>,
+    /// Time from starting to stopping the profiler.
+    pub duration: Duration,
+}
+
+/// A single sample of the JavaScript call stack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CpuProfileSample {
+    /// Time when the sample was taken, relative to the start of profiling.
+    pub timestamp: Duration,
+    /// Indexes into [`CpuProfile::frames`], innermost frame first.
+    pub stack: Vec<usize>,
+}
+
+/// State of a running CPU profiler.
+pub(crate) struct CpuProfiler {
+    /// Dropping the sender stops the timer thread.
+    _stop_timer: mpsc::Sender<()>,
+    start_time: Instant,
+    frames: Vec<StackFrame>,
+    frame_indexes: AHashMap<StackFrame, usize>,
+    samples: Vec<CpuProfileSample>,
+}
+
+impl CpuProfiler {
+    fn start(interval: Duration, safe_point_requests: Arc<SafePointRequests>) -> Self {
+        let (stop_timer, timer_stopped) = mpsc::channel::<()>();
+        std::thread::spawn(move || {
+            while let Err(RecvTimeoutError::Timeout) = timer_stopped.recv_timeout(interval) {
+                safe_point_requests.request(SafePointRequests::CPU_PROFILE_SAMPLE);
+            }
+        });
+        Self {
+            _stop_timer: stop_timer,
+            start_time: Instant::now(),
+            frames: Vec::new(),
+            frame_indexes: AHashMap::default(),
+            samples: Vec::new(),
+        }
+    }
+
+    fn record_sample(&mut self, frames: Vec<StackFrame>) {
+        let timestamp = self.start_time.elapsed();
+        let stack = frames
+            .into_iter()
+            .map(|frame| {
+                if let Some(&index) = self.frame_indexes.get(&frame) {
+                    return index;
+                }
+                let index = self.frames.len();
+                self.frames.push(frame.clone());
+                self.frame_indexes.insert(frame, index);
+                index
+            })
+            .collect();
+        self.samples.push(CpuProfileSample { timestamp, stack });
+    }
+
+    fn finish(self) -> CpuProfile {
+        CpuProfile {
+            duration: self.start_time.elapsed(),
+            frames: self.frames,
+            samples: self.samples,
+        }
+    }
+}
+
+impl Agent {
+    /// Start sampling the JavaScript call stack at the given interval. If the
+    /// profiler is already running, the samples collected so far are
+    /// discarded.
+    ///
+    /// Samples are only taken while the Agent is executing bytecode; time
+    /// spent in the embedder is not visible in the profile.
+    pub fn start_cpu_profiler(&mut self, interval: Duration) {
+        self.cpu_profiler = Some(CpuProfiler::start(
+            interval,
+            self.safe_point_requests.clone(),
+        ));
+    }
+
+    /// Stop the CPU profiler and return the collected profile, or `None` if
+    /// the profiler was not running.
+    pub fn stop_cpu_profiler(&mut self) -> Option<CpuProfile> {
+        let profile = self.cpu_profiler.take().map(CpuProfiler::finish);
+        self.safe_point_requests
+            .clear(SafePointRequests::CPU_PROFILE_SAMPLE);
+        profile
+    }
+
+    /// Record a sample of the current JavaScript call stack, as seen by a VM
+    /// running in the execution context at the given depth.
+    #[cold]
+    #[inline(never)]
+    pub(crate) fn record_cpu_profile_sample(
+        &mut self,
+        execution_context_depth: usize,
+        source_position: Option<u32>,
+        gc: NoGcScope,
+    ) {
+        self.safe_point_requests
+            .clear(SafePointRequests::CPU_PROFILE_SAMPLE);
+        if self.cpu_profiler.is_none() {
+            return;
+        }
+        let frames = self.sample_stack_trace(execution_context_depth, source_position, gc);
+        if let Some(profiler) = &mut self.cpu_profiler {
+            profiler.record_sample(frames);
+        }
+    }
+}
+
+/// A node of the call tree in a `.cpuprofile` file.
+struct CallTreeNode<'a> {
+    frame: Option<&'a StackFrame>,
+    children: Vec<usize>,
+    hit_count: usize,
+    /// Number of hits per one-based line number.
+    position_ticks: BTreeMap<u32, usize>,
+}
+
+impl CpuProfile {
+    /// Write the profile in the Chrome DevTools `.cpuprofile` format. The
+    /// file can be loaded into the Performance tab of Chrome DevTools.
+    pub fn write_cpuprofile(&self, writer: impl Write) -> io::Result<()> {
+        let mut writer = BufWriter::new(writer);
+        // Call tree nodes are identified by function, not by call site.
+        let mut nodes = vec![CallTreeNode {
+            frame: None,
+            children: Vec::new(),
+            hit_count: 0,
+            position_ticks: BTreeMap::new(),
+        }];
+        let mut node_indexes = AHashMap::<(usize, Option<&str>, Option<&str>), usize>::default();
+        let mut sample_nodes = Vec::with_capacity(self.samples.len());
+        for sample in &self.samples {
+            let mut node = 0;
+            for &frame in sample.stack.iter().rev() {
+                let frame = &self.frames[frame];
+                let key = (
+                    node,
+                    frame.function_name.as_deref(),
+                    frame.source_name.as_deref(),
+                );
+                node = *node_indexes.entry(key).or_insert_with(|| {
+                    nodes.push(CallTreeNode {
+                        frame: Some(frame),
+                        children: Vec::new(),
+                        hit_count: 0,
+                        position_ticks: BTreeMap::new(),
+                    });
+                    let child = nodes.len() - 1;
+                    nodes[node].children.push(child);
+                    child
+                });
+            }
+            nodes[node].hit_count += 1;
+            if let Some(line) = sample
+                .stack
+                .first()
+                .and_then(|&frame| self.frames[frame].line)
+            {
+                *nodes[node].position_ticks.entry(line).or_default() += 1;
+            }
+            sample_nodes.push(node);
+        }
+
+        let mut script_ids = AHashMap::<&str, usize>::default();
+        writer.write_all(b"{\"nodes\":[")?;
+        for (index, node) in nodes.iter().enumerate() {
+            if index != 0 {
+                writer.write_all(b",\n")?;
+            }
+            let (function_name, url, line, column) = match node.frame {
+                None => ("(root)", "", -1, -1),
+                Some(frame) => (
+                    frame.function_name.as_deref().unwrap_or_default(),
+                    frame.source_name.as_deref().unwrap_or_default(),
+                    frame.line.map_or(-1, |line| i64::from(line) - 1),
+                    frame.column.map_or(-1, |column| i64::from(column) - 1),
+                ),
+            };
+            let next_script_id = script_ids.len() + 1;
+            let script_id = if url.is_empty() {
+                0
+            } else {
+                *script_ids.entry(url).or_insert(next_script_id)
+            };
+            write!(writer, "{{\"id\":{},\"callFrame\":{{", index + 1)?;
+            writer.write_all(b"\"functionName\":")?;
+            write_json_string(&mut writer, function_name)?;
+            write!(writer, ",\"scriptId\":\"{script_id}\",\"url\":")?;
+            write_json_string(&mut writer, url)?;
+            write!(
+                writer,
+                ",\"lineNumber\":{line},\"columnNumber\":{column}}},\"hitCount\":{}",
+                node.hit_count
+            )?;
+            if !node.children.is_empty() {
+                writer.write_all(b",\"children\":[")?;
+                for (i, child) in node.children.iter().enumerate() {
+                    let separator = if i == 0 { "" } else { "," };
+                    write!(writer, "{separator}{}", child + 1)?;
+                }
+                writer.write_all(b"]")?;
+            }
+            if !node.position_ticks.is_empty() {
+                writer.write_all(b",\"positionTicks\":[")?;
+                for (i, (line, ticks)) in node.position_ticks.iter().enumerate() {
+                    let separator = if i == 0 { "" } else { "," };
+                    write!(writer, "{separator}{{\"line\":{line},\"ticks\":{ticks}}}")?;
+                }
+                writer.write_all(b"]")?;
+            }
+            writer.write_all(b"}")?;
+        }
+        write!(
+            writer,
+            "],\n\"startTime\":0,\"endTime\":{},\n\"samples\":[",
+            self.duration.as_micros()
+        )?;
+        for (i, node) in sample_nodes.iter().enumerate() {
+            let separator = if i == 0 { "" } else { "," };
+            write!(writer, "{separator}{}", node + 1)?;
+        }
+        writer.write_all(b"],\n\"timeDeltas\":[")?;
+        let mut previous = Duration::ZERO;
+        for (i, sample) in self.samples.iter().enumerate() {
+            let separator = if i == 0 { "" } else { "," };
+            let delta = sample.timestamp.saturating_sub(previous);
+            write!(writer, "{separator}{}", delta.as_micros())?;
+            previous = sample.timestamp;
+        }
+        writer.write_all(b"]}\n")?;
+        writer.flush()
+    }
+
+    /// Write the profile as folded stacks: one line per distinct call stack,
+    /// with frames from outermost to innermost separated by semicolons and
+    /// followed by the number of samples. This is the input format of
+    /// `flamegraph.pl` and compatible tools.
+    pub fn write_folded_stacks(&self, writer: impl Write) -> io::Result<()> {
+        let mut writer = BufWriter::new(writer);
+        let labels = self
+            .frames
+            .iter()
+            .map(|frame| {
+                let function_name = match frame.function_name.as_deref() {
+                    None | Some("") => "(anonymous)",
+                    Some(function_name) => function_name,
+                };
+                let label = match (frame.source_name.as_deref(), frame.line) {
+                    (_, None) => function_name.to_owned(),
+                    (source_name, Some(line)) => format!(
+                        "{function_name} ({}:{line})",
+                        source_name.unwrap_or("<anonymous>")
+                    ),
+                };
+                label.replace(';', ",")
+            })
+            .collect::<Vec<_>>();
+        let mut stacks = BTreeMap::<std::string::String, usize>::new();
+        for sample in &self.samples {
+            let mut stack = std::string::String::from("(root)");
+            for &frame in sample.stack.iter().rev() {
+                stack.push(';');
+                stack.push_str(&labels[frame]);
+            }
+            *stacks.entry(stack).or_default() += 1;
+        }
+        for (stack, count) in stacks {
+            writeln!(writer, "{stack} {count}")?;
+        }
+        writer.flush()
+    }
+}
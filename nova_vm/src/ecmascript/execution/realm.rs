@@ -11,8 +11,9 @@ use super::{
 };
 use crate::{
     ecmascript::{
-        AbstractModule, BUILTIN_STRING_MEMORY, HostDefined, LoadedModules, ModuleRequest, Number,
-        Object, OrdinaryObject, PropertyDescriptor, PropertyKey, Value, define_property_or_throw,
+        AbstractModule, BUILTIN_STRING_MEMORY, Function, HostDefined, LoadedModules, ModuleRequest,
+        Number, Object, OrdinaryObject, PropertyDescriptor, PropertyKey, Value,
+        define_property_or_throw,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable, bindable_handle},
     heap::{
@@ -68,6 +69,25 @@ impl<'r> Realm<'r> {
         self.get(agent).global_object
     }
 
+    /// ### %Object.prototype%
+    ///
+    /// Hosts can use this as the prototype of objects they create, such as
+    /// [`EmbedderObject`]s.
+    ///
+    /// [`EmbedderObject`]: crate::ecmascript::EmbedderObject
+    pub fn object_prototype(self, agent: &Agent) -> Object<'r> {
+        self.get(agent).intrinsics().object_prototype().into()
+    }
+
+    /// ### %AbstractModuleSource%
+    ///
+    /// The AbstractModuleSource intrinsic is not a property of the global
+    /// object. Hosts can use this to expose it to scripts, or as the
+    /// constructor of their own module source objects.
+    pub fn abstract_module_source(self, agent: &Agent) -> Function<'r> {
+        self.get(agent).intrinsics().abstract_module_source().into()
+    }
+
     /// ### \[\[GlobalEnv]]
     pub(crate) fn global_env<'gc>(
         self,
@@ -431,6 +451,9 @@ pub(crate) fn set_default_global_bindings<'a>(
         #[cfg(feature = "array-buffer")]
         define_property!(intrinsic ArrayBuffer, array_buffer);
 
+        // AsyncDisposableStack ( )
+        define_property!(intrinsic AsyncDisposableStack, async_disposable_stack);
+
         // 19.3.4 BigInt ( . . . )
         define_property!(intrinsic BigInt, big_int);
 
@@ -453,6 +476,9 @@ pub(crate) fn set_default_global_bindings<'a>(
         #[cfg(feature = "date")]
         define_property!(intrinsic Date, date);
 
+        // DisposableStack ( )
+        define_property!(intrinsic DisposableStack, disposable_stack);
+
         // 19.3.10 Error ( . . . )
         define_property!(intrinsic Error, error);
 
@@ -528,6 +554,9 @@ pub(crate) fn set_default_global_bindings<'a>(
         // 19.3.31 String ( . . . )
         define_property!(intrinsic String, string);
 
+        // SuppressedError ( . . . )
+        define_property!(intrinsic SuppressedError, suppressed_error);
+
         // 19.3.32 Symbol ( . . . )
         define_property!(intrinsic Symbol, symbol);
 
@@ -27,8 +27,11 @@ use crate::ecmascript::{SharedArrayBufferConstructor, SharedArrayBufferPrototype
 #[cfg(feature = "temporal")]
 use crate::ecmascript::{
     TemporalDurationConstructor, TemporalDurationPrototype, TemporalInstantConstructor,
-    TemporalInstantPrototype, TemporalObject, TemporalPlainTimeConstructor,
-    TemporalPlainTimePrototype,
+    TemporalInstantPrototype, TemporalNowObject, TemporalObject, TemporalPlainDateConstructor,
+    TemporalPlainDatePrototype, TemporalPlainDateTimeConstructor, TemporalPlainDateTimePrototype,
+    TemporalPlainMonthDayConstructor, TemporalPlainMonthDayPrototype, TemporalPlainTimeConstructor,
+    TemporalPlainTimePrototype, TemporalPlainYearMonthConstructor, TemporalPlainYearMonthPrototype,
+    TemporalZonedDateTimeConstructor, TemporalZonedDateTimePrototype,
 };
 #[cfg(feature = "weak-refs")]
 use crate::ecmascript::{
@@ -38,20 +41,25 @@ use crate::ecmascript::{
 use crate::ecmascript::{WeakRefConstructor, WeakRefPrototype};
 use crate::{
     ecmascript::{
-        Agent, AggregateErrorConstructor, AggregateErrorPrototype, Array, ArrayConstructor,
-        ArrayIteratorPrototype, ArrayPrototype, AsyncFunctionConstructor, AsyncFunctionPrototype,
+        AbstractModuleSourceConstructor, AbstractModuleSourcePrototype, Agent,
+        AggregateErrorConstructor, AggregateErrorPrototype, Array, ArrayConstructor,
+        ArrayIteratorPrototype, ArrayPrototype, AsyncDisposableStackConstructor,
+        AsyncDisposableStackPrototype, AsyncFunctionConstructor, AsyncFunctionPrototype,
         AsyncGeneratorFunctionConstructor, AsyncGeneratorFunctionPrototype,
         AsyncGeneratorPrototype, AsyncIteratorPrototype, BigIntConstructor, BigIntPrototype,
         BooleanConstructor, BooleanPrototype, BuiltinFunction, BuiltinFunctionHeapData,
-        ErrorConstructor, ErrorPrototype, FinalizationRegistryConstructor,
-        FinalizationRegistryPrototype, Function, FunctionConstructor, FunctionPrototype,
-        GeneratorFunctionConstructor, GeneratorFunctionPrototype, GeneratorPrototype, GlobalObject,
-        IteratorConstructor, IteratorPrototype, MapConstructor, MapIteratorPrototype, MapPrototype,
-        NativeErrorConstructors, NativeErrorPrototypes, NumberConstructor, NumberPrototype, Object,
-        ObjectConstructor, ObjectPrototype, ObjectRecord, ObjectShape, OrdinaryObject,
-        PrimitiveObject, PrimitiveObjectRecord, PromiseConstructor, PromisePrototype,
-        ProxyConstructor, ReflectObject, StringConstructor, StringIteratorPrototype,
-        StringPrototype, SymbolConstructor, SymbolPrototype,
+        DisposableStackConstructor, DisposableStackPrototype, ErrorConstructor, ErrorPrototype,
+        FinalizationRegistryConstructor, FinalizationRegistryPrototype, Function,
+        FunctionConstructor, FunctionPrototype, GeneratorFunctionConstructor,
+        GeneratorFunctionPrototype, GeneratorPrototype, GlobalObject, IteratorConstructor,
+        IteratorHelperPrototype, IteratorPrototype, MapConstructor, MapIteratorPrototype,
+        MapPrototype, NativeErrorConstructors, NativeErrorPrototypes, NumberConstructor,
+        NumberPrototype, Object, ObjectConstructor, ObjectPrototype, ObjectRecord, ObjectShape,
+        OrdinaryObject, PrimitiveObject, PrimitiveObjectRecord, PromiseConstructor,
+        PromisePrototype, ProxyConstructor, ReflectObject, StringConstructor,
+        StringIteratorPrototype, StringPrototype, SuppressedErrorConstructor,
+        SuppressedErrorPrototype, SymbolConstructor, SymbolPrototype,
+        WrapForValidIteratorPrototype,
     },
     engine::NoGcScope,
     heap::{
@@ -95,6 +103,10 @@ pub enum ProtoIntrinsics {
     /// ```
     ArrayIterator,
     /// ```javascript
+    /// AsyncDisposableStack.prototype
+    /// ```
+    AsyncDisposableStack,
+    /// ```javascript
     /// Object.getPrototypeOf(async () => {})
     /// ```
     AsyncFunction,
@@ -140,6 +152,10 @@ pub enum ProtoIntrinsics {
     /// ```
     Date,
     /// ```javascript
+    /// DisposableStack.prototype
+    /// ```
+    DisposableStack,
+    /// ```javascript
     /// Error.prototype
     /// ```
     Error,
@@ -198,6 +214,10 @@ pub enum ProtoIntrinsics {
     /// ```
     Iterator,
     /// ```javascript
+    /// Object.getPrototypeOf(Iterator.prototype.map.call([].values(), x => x))
+    /// ```
+    IteratorHelper,
+    /// ```javascript
     /// Map.prototype
     /// ```
     Map,
@@ -259,6 +279,10 @@ pub enum ProtoIntrinsics {
     /// ```
     RegExpStringIterator,
     /// ```javascript
+    /// SuppressedError.prototype
+    /// ```
+    SuppressedError,
+    /// ```javascript
     /// Symbol.prototype
     /// ```
     Symbol,
@@ -281,6 +305,31 @@ pub enum ProtoIntrinsics {
     /// TemporalPlainTime.prototype
     /// ```
     TemporalPlainTime,
+    #[cfg(feature = "temporal")]
+    /// ```javascript
+    /// TemporalPlainDate.prototype
+    /// ```
+    TemporalPlainDate,
+    #[cfg(feature = "temporal")]
+    /// ```javascript
+    /// TemporalPlainDateTime.prototype
+    /// ```
+    TemporalPlainDateTime,
+    #[cfg(feature = "temporal")]
+    /// ```javascript
+    /// TemporalZonedDateTime.prototype
+    /// ```
+    TemporalZonedDateTime,
+    #[cfg(feature = "temporal")]
+    /// ```javascript
+    /// TemporalPlainYearMonth.prototype
+    /// ```
+    TemporalPlainYearMonth,
+    #[cfg(feature = "temporal")]
+    /// ```javascript
+    /// TemporalPlainMonthDay.prototype
+    /// ```
+    TemporalPlainMonthDay,
     /// ```javascript
     /// TypeError.prototype
     /// ```
@@ -324,6 +373,10 @@ pub enum ProtoIntrinsics {
     /// WeakSet.prototype
     /// ```
     WeakSet,
+    /// ```javascript
+    /// Object.getPrototypeOf(Iterator.from({ next() {} }))
+    /// ```
+    WrapForValidIterator,
 }
 
 impl Intrinsics {
@@ -382,6 +435,8 @@ impl Intrinsics {
         NativeErrorConstructors::create_intrinsic(agent, realm);
         AggregateErrorPrototype::create_intrinsic(agent, realm);
         AggregateErrorConstructor::create_intrinsic(agent, realm);
+        SuppressedErrorPrototype::create_intrinsic(agent, realm);
+        SuppressedErrorConstructor::create_intrinsic(agent, realm);
         NumberPrototype::create_intrinsic(agent, realm);
         NumberConstructor::create_intrinsic(agent, realm, gc);
         BigIntPrototype::create_intrinsic(agent, realm);
@@ -391,12 +446,23 @@ impl Intrinsics {
         #[cfg(feature = "temporal")]
         {
             TemporalObject::create_intrinsic(agent, realm, gc);
+            TemporalNowObject::create_intrinsic(agent, realm, gc);
             TemporalInstantPrototype::create_intrinsic(agent, realm, gc);
             TemporalInstantConstructor::create_intrinsic(agent, realm, gc);
             TemporalDurationPrototype::create_intrinsic(agent, realm, gc);
             TemporalDurationConstructor::create_intrinsic(agent, realm, gc);
             TemporalPlainTimePrototype::create_intrinsic(agent, realm, gc);
             TemporalPlainTimeConstructor::create_intrinsic(agent, realm, gc);
+            TemporalPlainDatePrototype::create_intrinsic(agent, realm, gc);
+            TemporalPlainDateConstructor::create_intrinsic(agent, realm, gc);
+            TemporalPlainDateTimePrototype::create_intrinsic(agent, realm, gc);
+            TemporalPlainDateTimeConstructor::create_intrinsic(agent, realm, gc);
+            TemporalZonedDateTimePrototype::create_intrinsic(agent, realm, gc);
+            TemporalZonedDateTimeConstructor::create_intrinsic(agent, realm, gc);
+            TemporalPlainYearMonthPrototype::create_intrinsic(agent, realm, gc);
+            TemporalPlainYearMonthConstructor::create_intrinsic(agent, realm, gc);
+            TemporalPlainMonthDayPrototype::create_intrinsic(agent, realm, gc);
+            TemporalPlainMonthDayConstructor::create_intrinsic(agent, realm, gc);
         }
         #[cfg(feature = "date")]
         DatePrototype::create_intrinsic(agent, realm);
@@ -461,7 +527,13 @@ impl Intrinsics {
         WeakRefConstructor::create_intrinsic(agent, realm);
         FinalizationRegistryPrototype::create_intrinsic(agent, realm);
         FinalizationRegistryConstructor::create_intrinsic(agent, realm);
+        DisposableStackPrototype::create_intrinsic(agent, realm);
+        DisposableStackConstructor::create_intrinsic(agent, realm);
+        AsyncDisposableStackPrototype::create_intrinsic(agent, realm);
+        AsyncDisposableStackConstructor::create_intrinsic(agent, realm);
         IteratorPrototype::create_intrinsic(agent, realm);
+        IteratorHelperPrototype::create_intrinsic(agent, realm);
+        WrapForValidIteratorPrototype::create_intrinsic(agent, realm);
         AsyncIteratorPrototype::create_intrinsic(agent, realm);
         PromisePrototype::create_intrinsic(agent, realm);
         PromiseConstructor::create_intrinsic(agent, realm);
@@ -475,6 +547,8 @@ impl Intrinsics {
         AsyncFunctionConstructor::create_intrinsic(agent, realm);
         ReflectObject::create_intrinsic(agent, realm);
         ProxyConstructor::create_intrinsic(agent, realm);
+        AbstractModuleSourcePrototype::create_intrinsic(agent, realm);
+        AbstractModuleSourceConstructor::create_intrinsic(agent, realm);
         IteratorConstructor::create_intrinsic(agent, realm);
     }
 
@@ -513,6 +587,16 @@ impl Intrinsics {
             ProtoIntrinsics::TemporalDuration => self.temporal_duration().into(),
             #[cfg(feature = "temporal")]
             ProtoIntrinsics::TemporalPlainTime => self.temporal_plain_time().into(),
+            #[cfg(feature = "temporal")]
+            ProtoIntrinsics::TemporalPlainDate => self.temporal_plain_date().into(),
+            #[cfg(feature = "temporal")]
+            ProtoIntrinsics::TemporalPlainDateTime => self.temporal_plain_date_time().into(),
+            #[cfg(feature = "temporal")]
+            ProtoIntrinsics::TemporalZonedDateTime => self.temporal_zoned_date_time().into(),
+            #[cfg(feature = "temporal")]
+            ProtoIntrinsics::TemporalPlainYearMonth => self.temporal_plain_year_month().into(),
+            #[cfg(feature = "temporal")]
+            ProtoIntrinsics::TemporalPlainMonthDay => self.temporal_plain_month_day().into(),
             ProtoIntrinsics::TypeError => self.type_error().into(),
             ProtoIntrinsics::URIError => self.uri_error().into(),
             ProtoIntrinsics::AggregateError => self.aggregate_error().into(),
@@ -528,6 +612,9 @@ impl Intrinsics {
             #[cfg(feature = "shared-array-buffer")]
             ProtoIntrinsics::SharedDataView => self.data_view().into(),
             ProtoIntrinsics::FinalizationRegistry => self.finalization_registry().into(),
+            ProtoIntrinsics::DisposableStack => self.disposable_stack().into(),
+            ProtoIntrinsics::AsyncDisposableStack => self.async_disposable_stack().into(),
+            ProtoIntrinsics::SuppressedError => self.suppressed_error().into(),
             #[cfg(feature = "proposal-float16array")]
             ProtoIntrinsics::Float16Array => self.float16_array().into(),
             #[cfg(feature = "array-buffer")]
@@ -543,6 +630,7 @@ impl Intrinsics {
             #[cfg(feature = "array-buffer")]
             ProtoIntrinsics::Int8Array => self.int8_array().into(),
             ProtoIntrinsics::Iterator => self.iterator().into(),
+            ProtoIntrinsics::IteratorHelper => unreachable!(),
             ProtoIntrinsics::Map => self.map().into(),
             ProtoIntrinsics::MapIterator => unreachable!(),
             ProtoIntrinsics::Promise => self.promise().into(),
@@ -568,6 +656,7 @@ impl Intrinsics {
             ProtoIntrinsics::WeakRef => self.weak_ref().into(),
             #[cfg(feature = "weak-refs")]
             ProtoIntrinsics::WeakSet => self.weak_set().into(),
+            ProtoIntrinsics::WrapForValidIterator => unreachable!(),
         }
     }
 
@@ -608,6 +697,24 @@ impl Intrinsics {
             ProtoIntrinsics::TemporalDuration => self.temporal_duration_prototype().into(),
             #[cfg(feature = "temporal")]
             ProtoIntrinsics::TemporalPlainTime => self.temporal_plain_time_prototype().into(),
+            #[cfg(feature = "temporal")]
+            ProtoIntrinsics::TemporalPlainDate => self.temporal_plain_date_prototype().into(),
+            #[cfg(feature = "temporal")]
+            ProtoIntrinsics::TemporalPlainDateTime => {
+                self.temporal_plain_date_time_prototype().into()
+            }
+            #[cfg(feature = "temporal")]
+            ProtoIntrinsics::TemporalZonedDateTime => {
+                self.temporal_zoned_date_time_prototype().into()
+            }
+            #[cfg(feature = "temporal")]
+            ProtoIntrinsics::TemporalPlainYearMonth => {
+                self.temporal_plain_year_month_prototype().into()
+            }
+            #[cfg(feature = "temporal")]
+            ProtoIntrinsics::TemporalPlainMonthDay => {
+                self.temporal_plain_month_day_prototype().into()
+            }
             ProtoIntrinsics::TypeError => self.type_error_prototype().into(),
             ProtoIntrinsics::URIError => self.uri_error_prototype().into(),
             ProtoIntrinsics::AggregateError => self.aggregate_error_prototype().into(),
@@ -625,6 +732,9 @@ impl Intrinsics {
             #[cfg(feature = "shared-array-buffer")]
             ProtoIntrinsics::SharedDataView => self.data_view_prototype().into(),
             ProtoIntrinsics::FinalizationRegistry => self.finalization_registry_prototype().into(),
+            ProtoIntrinsics::DisposableStack => self.disposable_stack_prototype().into(),
+            ProtoIntrinsics::AsyncDisposableStack => self.async_disposable_stack_prototype().into(),
+            ProtoIntrinsics::SuppressedError => self.suppressed_error_prototype().into(),
             #[cfg(feature = "proposal-float16array")]
             ProtoIntrinsics::Float16Array => self.float16_array_prototype().into(),
             #[cfg(feature = "array-buffer")]
@@ -640,6 +750,7 @@ impl Intrinsics {
             #[cfg(feature = "array-buffer")]
             ProtoIntrinsics::Int8Array => self.int8_array_prototype().into(),
             ProtoIntrinsics::Iterator => self.iterator_prototype().into(),
+            ProtoIntrinsics::IteratorHelper => self.iterator_helper_prototype().into(),
             ProtoIntrinsics::Map => self.map_prototype().into(),
             ProtoIntrinsics::MapIterator => self.map_iterator_prototype().into(),
             ProtoIntrinsics::Promise => self.promise_prototype().into(),
@@ -665,6 +776,9 @@ impl Intrinsics {
             ProtoIntrinsics::WeakRef => self.weak_ref_prototype().into(),
             #[cfg(feature = "weak-refs")]
             ProtoIntrinsics::WeakSet => self.weak_set_prototype().into(),
+            ProtoIntrinsics::WrapForValidIterator => {
+                self.wrap_for_valid_iterator_prototype().into()
+            }
         }
     }
 
@@ -702,6 +816,18 @@ impl Intrinsics {
         index.get_backing_object(self.object_index_base)
     }
 
+    /// %AbstractModuleSource.prototype%
+    pub(crate) const fn abstract_module_source_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::AbstractModuleSourcePrototype
+            .get_backing_object(self.object_index_base)
+    }
+
+    /// %AbstractModuleSource%
+    pub(crate) const fn abstract_module_source(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::AbstractModuleSource
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
     /// %AggregateError.prototype%
     pub(crate) const fn aggregate_error_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::AggregateErrorPrototype.get_backing_object(self.object_index_base)
@@ -931,6 +1057,43 @@ impl Intrinsics {
         IntrinsicFunctionIndexes::Eval.get_builtin_function(self.builtin_function_index_base)
     }
 
+    /// %AsyncDisposableStack.prototype%
+    pub(crate) const fn async_disposable_stack_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::AsyncDisposableStackPrototype
+            .get_backing_object(self.object_index_base)
+    }
+
+    /// %AsyncDisposableStack.prototype.disposeAsync%
+    pub(crate) const fn async_disposable_stack_prototype_dispose_async(
+        &self,
+    ) -> BuiltinFunction<'static> {
+        IntrinsicFunctionIndexes::AsyncDisposableStackPrototypeDisposeAsync
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
+    /// %AsyncDisposableStack%
+    pub(crate) const fn async_disposable_stack(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::AsyncDisposableStack
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
+    /// %DisposableStack.prototype%
+    pub(crate) const fn disposable_stack_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::DisposableStackPrototype.get_backing_object(self.object_index_base)
+    }
+
+    /// %DisposableStack.prototype.dispose%
+    pub(crate) const fn disposable_stack_prototype_dispose(&self) -> BuiltinFunction<'static> {
+        IntrinsicFunctionIndexes::DisposableStackPrototypeDispose
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
+    /// %DisposableStack%
+    pub(crate) const fn disposable_stack(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::DisposableStack
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
     /// %EvalError.prototype%
     pub(crate) const fn eval_error_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::EvalErrorPrototype.get_backing_object(self.object_index_base)
@@ -1081,6 +1244,11 @@ impl Intrinsics {
         IntrinsicObjectIndexes::IteratorPrototype.get_backing_object(self.object_index_base)
     }
 
+    /// %IteratorHelperPrototype%
+    pub(crate) const fn iterator_helper_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::IteratorHelperPrototype.get_backing_object(self.object_index_base)
+    }
+
     #[cfg(feature = "json")]
     /// %JSON%
     pub(crate) const fn json(&self) -> OrdinaryObject<'static> {
@@ -1114,44 +1282,127 @@ impl Intrinsics {
         IntrinsicObjectIndexes::MathObject.get_backing_object(self.object_index_base)
     }
 
+    #[cfg(feature = "temporal")]
     /// %Temporal%
     pub(crate) const fn temporal(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::Temporal.get_backing_object(self.object_index_base)
     }
 
+    #[cfg(feature = "temporal")]
+    /// %Temporal.Now%
+    pub(crate) const fn temporal_now(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::TemporalNow.get_backing_object(self.object_index_base)
+    }
+
+    #[cfg(feature = "temporal")]
     /// %Temporal.Duration.Prototype%
     pub(crate) const fn temporal_duration_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::TemporalDurationPrototype.get_backing_object(self.object_index_base)
     }
 
+    #[cfg(feature = "temporal")]
     /// %Temporal.Duration%
     pub(crate) const fn temporal_duration(&self) -> BuiltinFunction<'static> {
         IntrinsicConstructorIndexes::TemporalDuration
             .get_builtin_function(self.builtin_function_index_base)
     }
 
+    #[cfg(feature = "temporal")]
     /// %Temporal.Instant.Prototype%
     pub(crate) const fn temporal_instant_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::TemporalInstantPrototype.get_backing_object(self.object_index_base)
     }
 
+    #[cfg(feature = "temporal")]
     /// %Temporal.Instant%
     pub(crate) const fn temporal_instant(&self) -> BuiltinFunction<'static> {
         IntrinsicConstructorIndexes::TemporalInstant
             .get_builtin_function(self.builtin_function_index_base)
     }
 
+    #[cfg(feature = "temporal")]
     /// %Temporal.PlainTime%
     pub(crate) const fn temporal_plain_time(&self) -> BuiltinFunction<'static> {
         IntrinsicConstructorIndexes::TemporalPlainTime
             .get_builtin_function(self.builtin_function_index_base)
     }
 
+    #[cfg(feature = "temporal")]
+    /// %Temporal.PlainDate%
+    pub(crate) const fn temporal_plain_date(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::TemporalPlainDate
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
+    #[cfg(feature = "temporal")]
+    /// %Temporal.PlainDateTime%
+    pub(crate) const fn temporal_plain_date_time(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::TemporalPlainDateTime
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
+    #[cfg(feature = "temporal")]
+    /// %Temporal.ZonedDateTime%
+    pub(crate) const fn temporal_zoned_date_time(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::TemporalZonedDateTime
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
+    #[cfg(feature = "temporal")]
+    /// %Temporal.PlainYearMonth%
+    pub(crate) const fn temporal_plain_year_month(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::TemporalPlainYearMonth
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
+    #[cfg(feature = "temporal")]
+    /// %Temporal.PlainMonthDay%
+    pub(crate) const fn temporal_plain_month_day(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::TemporalPlainMonthDay
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
+    #[cfg(feature = "temporal")]
     /// %Temporal.PlainTime.Prototype%
     pub(crate) const fn temporal_plain_time_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::TemporalPlainTimePrototype
             .get_backing_object(self.object_index_base)
     }
+
+    #[cfg(feature = "temporal")]
+    /// %Temporal.PlainDate.Prototype%
+    pub(crate) const fn temporal_plain_date_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::TemporalPlainDatePrototype
+            .get_backing_object(self.object_index_base)
+    }
+
+    #[cfg(feature = "temporal")]
+    /// %Temporal.PlainDateTime.Prototype%
+    pub(crate) const fn temporal_plain_date_time_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::TemporalPlainDateTimePrototype
+            .get_backing_object(self.object_index_base)
+    }
+
+    #[cfg(feature = "temporal")]
+    /// %Temporal.ZonedDateTime.Prototype%
+    pub(crate) const fn temporal_zoned_date_time_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::TemporalZonedDateTimePrototype
+            .get_backing_object(self.object_index_base)
+    }
+
+    #[cfg(feature = "temporal")]
+    /// %Temporal.PlainYearMonth.Prototype%
+    pub(crate) const fn temporal_plain_year_month_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::TemporalPlainYearMonthPrototype
+            .get_backing_object(self.object_index_base)
+    }
+
+    #[cfg(feature = "temporal")]
+    /// %Temporal.PlainMonthDay.Prototype%
+    pub(crate) const fn temporal_plain_month_day_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::TemporalPlainMonthDayPrototype
+            .get_backing_object(self.object_index_base)
+    }
     /// %Number.prototype%
     pub(crate) fn number_prototype(&self) -> PrimitiveObject<'static> {
         IntrinsicPrimitiveObjectIndexes::NumberPrototype
@@ -1347,6 +1598,17 @@ impl Intrinsics {
         IntrinsicObjectIndexes::StringIteratorPrototype.get_backing_object(self.object_index_base)
     }
 
+    /// %SuppressedError.prototype%
+    pub(crate) const fn suppressed_error_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::SuppressedErrorPrototype.get_backing_object(self.object_index_base)
+    }
+
+    /// %SuppressedError%
+    pub(crate) const fn suppressed_error(&self) -> BuiltinFunction<'static> {
+        IntrinsicConstructorIndexes::SuppressedError
+            .get_builtin_function(self.builtin_function_index_base)
+    }
+
     /// %Symbol.prototype%
     pub(crate) const fn symbol_prototype(&self) -> OrdinaryObject<'static> {
         IntrinsicObjectIndexes::SymbolPrototype.get_backing_object(self.object_index_base)
@@ -1505,10 +1767,18 @@ impl Intrinsics {
     pub(crate) const fn weak_set(&self) -> BuiltinFunction<'static> {
         IntrinsicConstructorIndexes::WeakSet.get_builtin_function(self.builtin_function_index_base)
     }
+
+    /// %WrapForValidIteratorPrototype%
+    pub(crate) const fn wrap_for_valid_iterator_prototype(&self) -> OrdinaryObject<'static> {
+        IntrinsicObjectIndexes::WrapForValidIteratorPrototype
+            .get_backing_object(self.object_index_base)
+    }
 }
 
 impl HeapMarkAndSweep for Intrinsics {
     fn mark_values(&self, queues: &mut WorkQueues) {
+        self.abstract_module_source_prototype().mark_values(queues);
+        self.abstract_module_source().mark_values(queues);
         self.aggregate_error_prototype().mark_values(queues);
         self.aggregate_error().mark_values(queues);
         self.array_prototype_sort().mark_values(queues);
@@ -1565,6 +1835,16 @@ impl HeapMarkAndSweep for Intrinsics {
         self.eval_error().mark_values(queues);
         self.finalization_registry_prototype().mark_values(queues);
         self.finalization_registry().mark_values(queues);
+        self.disposable_stack_prototype().mark_values(queues);
+        self.disposable_stack_prototype_dispose()
+            .mark_values(queues);
+        self.disposable_stack().mark_values(queues);
+        self.async_disposable_stack_prototype().mark_values(queues);
+        self.async_disposable_stack_prototype_dispose_async()
+            .mark_values(queues);
+        self.async_disposable_stack().mark_values(queues);
+        self.suppressed_error_prototype().mark_values(queues);
+        self.suppressed_error().mark_values(queues);
         #[cfg(feature = "array-buffer")]
         self.float32_array().mark_values(queues);
         #[cfg(feature = "array-buffer")]
@@ -1595,6 +1875,7 @@ impl HeapMarkAndSweep for Intrinsics {
         self.is_finite().mark_values(queues);
         self.is_nan().mark_values(queues);
         self.iterator_prototype().mark_values(queues);
+        self.iterator_helper_prototype().mark_values(queues);
         #[cfg(feature = "json")]
         self.json().mark_values(queues);
         self.map_prototype_entries().mark_values(queues);
@@ -1647,6 +1928,46 @@ impl HeapMarkAndSweep for Intrinsics {
         self.symbol().mark_values(queues);
         self.syntax_error_prototype().mark_values(queues);
         self.syntax_error().mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal().mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal_now().mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal_duration_prototype().mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal_duration().mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal_instant_prototype().mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal_instant().mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal_plain_date_prototype().mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal_plain_date().mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal_plain_date_time_prototype()
+            .mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal_plain_date_time().mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal_plain_month_day_prototype()
+            .mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal_plain_month_day().mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal_plain_time_prototype().mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal_plain_time().mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal_plain_year_month_prototype()
+            .mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal_plain_year_month().mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal_zoned_date_time_prototype()
+            .mark_values(queues);
+        #[cfg(feature = "temporal")]
+        self.temporal_zoned_date_time().mark_values(queues);
         self.throw_type_error().mark_values(queues);
         #[cfg(feature = "array-buffer")]
         self.typed_array_prototype_values().mark_values(queues);
@@ -1691,6 +2012,7 @@ impl HeapMarkAndSweep for Intrinsics {
         self.weak_set_prototype().mark_values(queues);
         #[cfg(feature = "weak-refs")]
         self.weak_set().mark_values(queues);
+        self.wrap_for_valid_iterator_prototype().mark_values(queues);
         IntrinsicObjectShapes::Object
             .get_object_shape_index(self.object_shape_base)
             .mark_values(queues);
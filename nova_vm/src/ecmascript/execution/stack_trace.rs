@@ -0,0 +1,282 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Stack traces
+//!
+//! Error objects capture the execution context stack at the time they are
+//! created, or for errors thrown by the engine, when the error leaves the
+//! builtin function or bytecode instruction that threw it. The captured frames
+//! are formatted into a V8-compatible `stack` string lazily, when the property
+//! is first read.
+
+use core::fmt::{self, Display};
+
+use crate::{
+    ecmascript::{
+        AbstractModuleSlots, Agent, Error, ErrorStack, Function, ScriptOrModule, SourceCode,
+    },
+    engine::{Bindable, NoGcScope, Vm, bindable_handle},
+    heap::{ArenaAccess, ArenaAccessMut, CompactionLists, HeapMarkAndSweep, WorkQueues},
+};
+
+/// Maximum number of frames captured into a stack trace.
+const STACK_TRACE_LIMIT: usize = 10;
+
+/// A single frame of a JavaScript stack trace.
+///
+/// Frames are created by [`Agent::current_stack_trace`] and
+/// [`Error::stack_trace`]. The [`Display`] implementation formats the frame
+/// the same way as a line of a V8 `stack` string, without the leading `at`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct StackFrame {
+    /// Name of the function being executed. This is `None` for Script and
+    /// Module code, and an empty string for anonymous functions.
+    pub function_name: Option<std::string::String>,
+    /// Name of the script or module source as given by
+    /// [`HostHooks::get_source_name`]. This is `None` for builtin functions
+    /// and for sources that the host did not name.
+    ///
+    /// [`HostHooks::get_source_name`]: crate::ecmascript::HostHooks::get_source_name
+    pub source_name: Option<std::string::String>,
+    /// One-based line number in the source text. This is `None` for builtin
+    /// functions.
+    pub line: Option<u32>,
+    /// One-based column number in the source text, counted in UTF-16 code
+    /// units. This is `None` for builtin functions.
+    pub column: Option<u32>,
+}
+
+impl StackFrame {
+    /// Returns true if the frame belongs to a builtin function.
+    pub fn is_native(&self) -> bool {
+        self.line.is_none()
+    }
+}
+
+impl Display for StackFrame {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let function_name = self.function_name.as_deref().unwrap_or_default();
+        let (Some(line), Some(column)) = (self.line, self.column) else {
+            let function_name = if function_name.is_empty() {
+                "<anonymous>"
+            } else {
+                function_name
+            };
+            return write!(f, "{function_name} (<anonymous>)");
+        };
+        let source_name = self.source_name.as_deref().unwrap_or("<anonymous>");
+        if function_name.is_empty() {
+            write!(f, "{source_name}:{line}:{column}")
+        } else {
+            write!(f, "{function_name} ({source_name}:{line}:{column})")
+        }
+    }
+}
+
+/// A stack frame as captured into an Error object's heap data.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct StackFrameRecord<'a> {
+    /// Function of the execution context.
+    function: Option<Function<'a>>,
+    /// ScriptOrModule of the execution context.
+    script_or_module: Option<ScriptOrModule<'a>>,
+    /// Source code being evaluated; `None` for builtin functions.
+    source_code: Option<SourceCode<'a>>,
+    /// Offset of the currently executing expression in the source text.
+    source_position: Option<u32>,
+    /// Length of the execution context stack up to and including this frame.
+    execution_context_depth: usize,
+}
+
+bindable_handle!(StackFrameRecord);
+
+impl StackFrameRecord<'_> {
+    pub(crate) fn to_stack_frame(self, agent: &Agent, gc: NoGcScope) -> StackFrame {
+        let function_name = self
+            .function
+            .map(|function| function.name(agent, gc).to_string_lossy(agent).into_owned());
+        let Some(source_code) = self.source_code else {
+            return StackFrame {
+                function_name,
+                source_name: None,
+                line: None,
+                column: None,
+            };
+        };
+        let source_name = self.script_or_module.and_then(|script_or_module| {
+            let host_defined = match script_or_module {
+                ScriptOrModule::Script(script) => script.host_defined(agent),
+                ScriptOrModule::SourceTextModule(module) => module.host_defined(agent),
+            };
+            agent.host_hooks.get_source_name(host_defined)
+        });
+        let source_text = source_code.get_source_text(agent);
+        let offset = (self.source_position.unwrap_or(0) as usize).min(source_text.len());
+        let preceding_text = source_text.get(..offset).unwrap_or_default();
+        let line_start = preceding_text.rfind('\n').map_or(0, |index| index + 1);
+        let line = preceding_text.matches('\n').count() + 1;
+        let column = preceding_text[line_start..].encode_utf16().count() + 1;
+        StackFrame {
+            function_name,
+            source_name,
+            line: Some(line as u32),
+            column: Some(column as u32),
+        }
+    }
+}
+
+impl Agent {
+    /// Capture the current execution context stack for an Error object,
+    /// skipping the given number of innermost execution contexts.
+    pub(crate) fn capture_stack_trace<'a>(
+        &self,
+        skip: usize,
+        gc: NoGcScope<'a, '_>,
+    ) -> Box<[StackFrameRecord<'a>]> {
+        self.capture_stack_frames(skip, STACK_TRACE_LIMIT, gc)
+    }
+
+    fn capture_stack_frames<'a>(
+        &self,
+        skip: usize,
+        limit: usize,
+        gc: NoGcScope<'a, '_>,
+    ) -> Box<[StackFrameRecord<'a>]> {
+        self.execution_context_stack
+            .iter()
+            .enumerate()
+            .rev()
+            .skip(skip)
+            // Host execution contexts are not shown in stack traces.
+            .filter(|(_, execution_context)| {
+                execution_context.function.is_some() || execution_context.ecmascript_code.is_some()
+            })
+            .take(limit)
+            .map(|(index, execution_context)| {
+                let source_code = execution_context
+                    .ecmascript_code
+                    .as_ref()
+                    .map(|code| code.source_code);
+                let source_position = if source_code.is_some() {
+                    Vm::get_source_position(self, index + 1)
+                } else {
+                    None
+                };
+                StackFrameRecord {
+                    function: execution_context.function,
+                    script_or_module: execution_context.script_or_module,
+                    source_code,
+                    source_position,
+                    execution_context_depth: index + 1,
+                }
+                .bind(gc)
+            })
+            .collect()
+    }
+
+    /// Returns the current JavaScript stack trace, innermost frame first.
+    pub fn current_stack_trace(&self, gc: NoGcScope) -> Vec<StackFrame> {
+        self.capture_stack_trace(0, gc)
+            .iter()
+            .map(|frame| frame.to_stack_frame(self, gc))
+            .collect()
+    }
+
+    /// Returns the entire current JavaScript stack trace, innermost frame
+    /// first, as seen by a VM running in the execution context at the given
+    /// depth and currently executing at the given source position.
+    pub(crate) fn sample_stack_trace(
+        &self,
+        execution_context_depth: usize,
+        source_position: Option<u32>,
+        gc: NoGcScope,
+    ) -> Vec<StackFrame> {
+        let mut frames = self.capture_stack_frames(0, usize::MAX, gc);
+        if let Some(frame) = frames.first_mut()
+            && frame.source_code.is_some()
+            && frame.execution_context_depth == execution_context_depth
+        {
+            // The running VM is not on the VM stack.
+            frame.source_position = source_position;
+        }
+        frames
+            .iter()
+            .map(|frame| frame.to_stack_frame(self, gc))
+            .collect()
+    }
+}
+
+impl<'a> Error<'a> {
+    /// Returns the stack trace captured when the Error object was created,
+    /// innermost frame first.
+    pub fn stack_trace(self, agent: &Agent, gc: NoGcScope) -> Option<Vec<StackFrame>> {
+        let frames = self.get(agent).stack.frames()?;
+        Some(
+            frames
+                .iter()
+                .map(|frame| frame.to_stack_frame(agent, gc))
+                .collect(),
+        )
+    }
+
+    /// Capture the pending stack trace of an Error thrown by the engine as it
+    /// leaves the builtin function or bytecode instruction that threw it.
+    ///
+    /// If the Error was thrown by the VM in the execution context at the given
+    /// depth without the VM being on the VM stack, the source position of the
+    /// innermost stack frame is set to the given position.
+    pub(crate) fn complete_stack_trace(
+        self,
+        agent: &mut Agent,
+        execution_context_depth: usize,
+        source_position: Option<u32>,
+        gc: NoGcScope,
+    ) {
+        if matches!(self.get(agent).stack, ErrorStack::Pending) {
+            self.capture_stack_trace(agent, 0, gc);
+        }
+        let Some(frame) = self
+            .get_mut(agent)
+            .stack
+            .frames_mut()
+            .and_then(|frames| frames.first_mut())
+        else {
+            return;
+        };
+        if frame.source_code.is_some()
+            && frame.source_position.is_none()
+            && frame.execution_context_depth == execution_context_depth
+        {
+            frame.source_position = source_position;
+        }
+    }
+}
+
+impl HeapMarkAndSweep for StackFrameRecord<'static> {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            function,
+            script_or_module,
+            source_code,
+            source_position: _,
+            execution_context_depth: _,
+        } = self;
+        function.mark_values(queues);
+        script_or_module.mark_values(queues);
+        source_code.mark_values(queues);
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            function,
+            script_or_module,
+            source_code,
+            source_position: _,
+            execution_context_depth: _,
+        } = self;
+        function.sweep_values(compactions);
+        script_or_module.sweep_values(compactions);
+        source_code.sweep_values(compactions);
+    }
+}
@@ -8,10 +8,6 @@
 use crate::ecmascript::DATE_DISCRIMINANT;
 #[cfg(feature = "date")]
 use crate::ecmascript::Date;
-#[cfg(feature = "temporal")]
-use crate::ecmascript::PLAIN_TIME_DISCRIMINANT;
-#[cfg(feature = "temporal")]
-use crate::ecmascript::TemporalPlainTime;
 use crate::ecmascript::UnmappedArguments;
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::{
@@ -29,6 +25,15 @@ use crate::ecmascript::{
 };
 #[cfg(feature = "proposal-float16array")]
 use crate::ecmascript::{FLOAT_16_ARRAY_DISCRIMINANT, Float16Array};
+#[cfg(feature = "temporal")]
+use crate::ecmascript::{
+    PLAIN_DATE_DISCRIMINANT, PLAIN_DATE_TIME_DISCRIMINANT, PLAIN_MONTH_DAY_DISCRIMINANT,
+    PLAIN_TIME_DISCRIMINANT, PLAIN_YEAR_MONTH_DISCRIMINANT, TemporalPlainDate,
+    TemporalPlainDateTime, TemporalPlainMonthDay, TemporalPlainTime, TemporalPlainYearMonth,
+    TemporalZonedDateTime, ZONED_DATE_TIME_DISCRIMINANT,
+};
+#[cfg(feature = "json")]
+use crate::ecmascript::{RAW_JSON_DISCRIMINANT, RawJSON};
 #[cfg(feature = "regexp")]
 use crate::ecmascript::{
     REGEXP_DISCRIMINANT, REGEXP_STRING_ITERATOR_DISCRIMINANT, RegExp, RegExpStringIterator,
@@ -65,12 +70,14 @@ use crate::{
         ASYNC_GENERATOR_DISCRIMINANT, Array, ArrayIterator, AsyncGenerator,
         BOUND_FUNCTION_DISCRIMINANT, BUILTIN_CONSTRUCTOR_FUNCTION_DISCRIMINANT,
         BUILTIN_FUNCTION_DISCRIMINANT, BUILTIN_PROMISE_FINALLY_FUNCTION_DISCRIMINANT,
-        BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT, BUILTIN_PROXY_REVOKER_FUNCTION,
-        BoundFunction, BuiltinConstructorFunction, BuiltinFunction, BuiltinPromiseFinallyFunction,
-        BuiltinPromiseResolvingFunction, ECMASCRIPT_FUNCTION_DISCRIMINANT, ECMAScriptFunction,
-        EMBEDDER_OBJECT_DISCRIMINANT, ERROR_DISCRIMINANT, EmbedderObject, Error,
-        FINALIZATION_REGISTRY_DISCRIMINANT, FinalizationRegistry, GENERATOR_DISCRIMINANT,
-        Generator, MAP_DISCRIMINANT, MAP_ITERATOR_DISCRIMINANT, MODULE_DISCRIMINANT, Map,
+        BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT,
+        BUILTIN_PROXY_REVOKER_FUNCTION_DISCRIMINANT, BoundFunction, BuiltinConstructorFunction,
+        BuiltinFunction, BuiltinPromiseFinallyFunction, BuiltinPromiseResolvingFunction,
+        BuiltinProxyRevokerFunction, DISPOSABLE_STACK_DISCRIMINANT, DisposableStack,
+        ECMASCRIPT_FUNCTION_DISCRIMINANT, ECMAScriptFunction, EMBEDDER_OBJECT_DISCRIMINANT,
+        ERROR_DISCRIMINANT, EmbedderObject, Error, FINALIZATION_REGISTRY_DISCRIMINANT,
+        FinalizationRegistry, GENERATOR_DISCRIMINANT, Generator, ITERATOR_HELPER_DISCRIMINANT,
+        IteratorHelper, MAP_DISCRIMINANT, MAP_ITERATOR_DISCRIMINANT, MODULE_DISCRIMINANT, Map,
         MapIterator, Module, OBJECT_DISCRIMINANT, Object, OrdinaryObject,
         PRIMITIVE_OBJECT_DISCRIMINANT, PROMISE_DISCRIMINANT, PROXY_DISCRIMINANT, PrimitiveObject,
         Promise, Proxy, STRING_ITERATOR_DISCRIMINANT, SYMBOL_DISCRIMINANT, StringIterator, Symbol,
@@ -97,7 +104,8 @@ pub(crate) enum WeakKey<'a> {
         BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT,
     BuiltinPromiseFinallyFunction(BuiltinPromiseFinallyFunction<'a>) =
         BUILTIN_PROMISE_FINALLY_FUNCTION_DISCRIMINANT,
-    BuiltinProxyRevokerFunction = BUILTIN_PROXY_REVOKER_FUNCTION,
+    BuiltinProxyRevokerFunction(BuiltinProxyRevokerFunction<'a>) =
+        BUILTIN_PROXY_REVOKER_FUNCTION_DISCRIMINANT,
     PrimitiveObject(PrimitiveObject<'a>) = PRIMITIVE_OBJECT_DISCRIMINANT,
     Arguments(UnmappedArguments<'a>) = ARGUMENTS_DISCRIMINANT,
     Array(Array<'a>) = ARRAY_DISCRIMINANT,
@@ -109,6 +117,17 @@ pub(crate) enum WeakKey<'a> {
     Duration(TemporalDuration<'a>) = DURATION_DISCRIMINANT,
     #[cfg(feature = "temporal")]
     PlainTime(TemporalPlainTime<'a>) = PLAIN_TIME_DISCRIMINANT,
+    #[cfg(feature = "temporal")]
+    PlainDate(TemporalPlainDate<'a>) = PLAIN_DATE_DISCRIMINANT,
+    #[cfg(feature = "temporal")]
+    PlainDateTime(TemporalPlainDateTime<'a>) = PLAIN_DATE_TIME_DISCRIMINANT,
+    #[cfg(feature = "temporal")]
+    ZonedDateTime(TemporalZonedDateTime<'a>) = ZONED_DATE_TIME_DISCRIMINANT,
+    #[cfg(feature = "temporal")]
+    PlainYearMonth(TemporalPlainYearMonth<'a>) = PLAIN_YEAR_MONTH_DISCRIMINANT,
+    #[cfg(feature = "temporal")]
+    PlainMonthDay(TemporalPlainMonthDay<'a>) = PLAIN_MONTH_DAY_DISCRIMINANT,
+    DisposableStack(DisposableStack<'a>) = DISPOSABLE_STACK_DISCRIMINANT,
     Error(Error<'a>) = ERROR_DISCRIMINANT,
     FinalizationRegistry(FinalizationRegistry<'a>) = FINALIZATION_REGISTRY_DISCRIMINANT,
     Map(Map<'a>) = MAP_DISCRIMINANT,
@@ -190,6 +209,9 @@ pub(crate) enum WeakKey<'a> {
     #[cfg(feature = "set")]
     MapIterator(MapIterator<'a>) = MAP_ITERATOR_DISCRIMINANT,
     StringIterator(StringIterator<'a>) = STRING_ITERATOR_DISCRIMINANT,
+    IteratorHelper(IteratorHelper<'a>) = ITERATOR_HELPER_DISCRIMINANT,
+    #[cfg(feature = "json")]
+    RawJSON(RawJSON<'a>) = RAW_JSON_DISCRIMINANT,
     #[cfg(feature = "regexp")]
     RegExpStringIterator(RegExpStringIterator<'a>) = REGEXP_STRING_ITERATOR_DISCRIMINANT,
     Generator(Generator<'a>) = GENERATOR_DISCRIMINANT,
@@ -216,7 +238,7 @@ impl<'a> From<WeakKey<'a>> for Value<'a> {
             WeakKey::BuiltinConstructorFunction(d) => Self::BuiltinConstructorFunction(d),
             WeakKey::BuiltinPromiseResolvingFunction(d) => Self::BuiltinPromiseResolvingFunction(d),
             WeakKey::BuiltinPromiseFinallyFunction(d) => Self::BuiltinPromiseFinallyFunction(d),
-            WeakKey::BuiltinProxyRevokerFunction => Self::BuiltinProxyRevokerFunction,
+            WeakKey::BuiltinProxyRevokerFunction(d) => Self::BuiltinProxyRevokerFunction(d),
             WeakKey::PrimitiveObject(d) => Self::PrimitiveObject(d),
             WeakKey::Arguments(d) => Self::Arguments(d),
             WeakKey::Array(d) => Self::Array(d),
@@ -228,6 +250,17 @@ impl<'a> From<WeakKey<'a>> for Value<'a> {
             WeakKey::Duration(d) => Self::Duration(d),
             #[cfg(feature = "temporal")]
             WeakKey::PlainTime(d) => Self::PlainTime(d),
+            #[cfg(feature = "temporal")]
+            WeakKey::PlainDate(d) => Self::PlainDate(d),
+            #[cfg(feature = "temporal")]
+            WeakKey::PlainDateTime(d) => Self::PlainDateTime(d),
+            #[cfg(feature = "temporal")]
+            WeakKey::ZonedDateTime(d) => Self::ZonedDateTime(d),
+            #[cfg(feature = "temporal")]
+            WeakKey::PlainYearMonth(d) => Self::PlainYearMonth(d),
+            #[cfg(feature = "temporal")]
+            WeakKey::PlainMonthDay(d) => Self::PlainMonthDay(d),
+            WeakKey::DisposableStack(d) => Self::DisposableStack(d),
             WeakKey::Error(d) => Self::Error(d),
             WeakKey::FinalizationRegistry(d) => Self::FinalizationRegistry(d),
             WeakKey::Map(d) => Self::Map(d),
@@ -308,6 +341,9 @@ impl<'a> From<WeakKey<'a>> for Value<'a> {
             WeakKey::SetIterator(d) => Self::SetIterator(d),
             WeakKey::MapIterator(d) => Self::MapIterator(d),
             WeakKey::StringIterator(d) => Self::StringIterator(d),
+            WeakKey::IteratorHelper(d) => Self::IteratorHelper(d),
+            #[cfg(feature = "json")]
+            WeakKey::RawJSON(d) => Self::RawJSON(d),
             #[cfg(feature = "regexp")]
             WeakKey::RegExpStringIterator(d) => Self::RegExpStringIterator(d),
             WeakKey::Generator(d) => Self::Generator(d),
@@ -328,7 +364,7 @@ impl<'a> From<Object<'a>> for WeakKey<'a> {
             Object::BuiltinConstructorFunction(d) => Self::BuiltinConstructorFunction(d),
             Object::BuiltinPromiseResolvingFunction(d) => Self::BuiltinPromiseResolvingFunction(d),
             Object::BuiltinPromiseFinallyFunction(d) => Self::BuiltinPromiseFinallyFunction(d),
-            Object::BuiltinProxyRevokerFunction => Self::BuiltinProxyRevokerFunction,
+            Object::BuiltinProxyRevokerFunction(d) => Self::BuiltinProxyRevokerFunction(d),
             Object::PrimitiveObject(d) => Self::PrimitiveObject(d),
             Object::Arguments(d) => Self::Arguments(d),
             Object::Array(d) => Self::Array(d),
@@ -340,6 +376,17 @@ impl<'a> From<Object<'a>> for WeakKey<'a> {
             Object::Duration(d) => Self::Duration(d),
             #[cfg(feature = "temporal")]
             Object::PlainTime(d) => Self::PlainTime(d),
+            #[cfg(feature = "temporal")]
+            Object::PlainDate(d) => Self::PlainDate(d),
+            #[cfg(feature = "temporal")]
+            Object::PlainDateTime(d) => Self::PlainDateTime(d),
+            #[cfg(feature = "temporal")]
+            Object::ZonedDateTime(d) => Self::ZonedDateTime(d),
+            #[cfg(feature = "temporal")]
+            Object::PlainYearMonth(d) => Self::PlainYearMonth(d),
+            #[cfg(feature = "temporal")]
+            Object::PlainMonthDay(d) => Self::PlainMonthDay(d),
+            Object::DisposableStack(d) => Self::DisposableStack(d),
             Object::Error(d) => Self::Error(d),
             Object::FinalizationRegistry(d) => Self::FinalizationRegistry(d),
             Object::Map(d) => Self::Map(d),
@@ -420,6 +467,9 @@ impl<'a> From<Object<'a>> for WeakKey<'a> {
             Object::SetIterator(d) => Self::SetIterator(d),
             Object::MapIterator(d) => Self::MapIterator(d),
             Object::StringIterator(d) => Self::StringIterator(d),
+            Object::IteratorHelper(d) => Self::IteratorHelper(d),
+            #[cfg(feature = "json")]
+            Object::RawJSON(d) => Self::RawJSON(d),
             #[cfg(feature = "regexp")]
             Object::RegExpStringIterator(d) => Self::RegExpStringIterator(d),
             Object::Generator(d) => Self::Generator(d),
@@ -444,7 +494,7 @@ impl<'a> TryFrom<WeakKey<'a>> for Object<'a> {
                 Ok(Self::BuiltinPromiseResolvingFunction(d))
             }
             WeakKey::BuiltinPromiseFinallyFunction(d) => Ok(Self::BuiltinPromiseFinallyFunction(d)),
-            WeakKey::BuiltinProxyRevokerFunction => Ok(Self::BuiltinProxyRevokerFunction),
+            WeakKey::BuiltinProxyRevokerFunction(d) => Ok(Self::BuiltinProxyRevokerFunction(d)),
             WeakKey::PrimitiveObject(d) => Ok(Self::PrimitiveObject(d)),
             WeakKey::Arguments(d) => Ok(Self::Arguments(d)),
             WeakKey::Array(d) => Ok(Self::Array(d)),
@@ -456,6 +506,17 @@ impl<'a> TryFrom<WeakKey<'a>> for Object<'a> {
             WeakKey::Duration(d) => Ok(Self::Duration(d)),
             #[cfg(feature = "temporal")]
             WeakKey::PlainTime(d) => Ok(Self::PlainTime(d)),
+            #[cfg(feature = "temporal")]
+            WeakKey::PlainDate(d) => Ok(Self::PlainDate(d)),
+            #[cfg(feature = "temporal")]
+            WeakKey::PlainDateTime(d) => Ok(Self::PlainDateTime(d)),
+            #[cfg(feature = "temporal")]
+            WeakKey::ZonedDateTime(d) => Ok(Self::ZonedDateTime(d)),
+            #[cfg(feature = "temporal")]
+            WeakKey::PlainYearMonth(d) => Ok(Self::PlainYearMonth(d)),
+            #[cfg(feature = "temporal")]
+            WeakKey::PlainMonthDay(d) => Ok(Self::PlainMonthDay(d)),
+            WeakKey::DisposableStack(d) => Ok(Self::DisposableStack(d)),
             WeakKey::Error(d) => Ok(Self::Error(d)),
             WeakKey::FinalizationRegistry(d) => Ok(Self::FinalizationRegistry(d)),
             WeakKey::Map(d) => Ok(Self::Map(d)),
@@ -536,6 +597,9 @@ impl<'a> TryFrom<WeakKey<'a>> for Object<'a> {
             WeakKey::SetIterator(d) => Ok(Self::SetIterator(d)),
             WeakKey::MapIterator(d) => Ok(Self::MapIterator(d)),
             WeakKey::StringIterator(d) => Ok(Self::StringIterator(d)),
+            WeakKey::IteratorHelper(d) => Ok(Self::IteratorHelper(d)),
+            #[cfg(feature = "json")]
+            WeakKey::RawJSON(d) => Ok(Self::RawJSON(d)),
             #[cfg(feature = "regexp")]
             WeakKey::RegExpStringIterator(d) => Ok(Self::RegExpStringIterator(d)),
             WeakKey::Generator(d) => Ok(Self::Generator(d)),
@@ -607,7 +671,7 @@ impl HeapMarkAndSweep for WeakKey<'static> {
             Self::BuiltinConstructorFunction(d) => d.mark_values(queues),
             Self::BuiltinPromiseResolvingFunction(d) => d.mark_values(queues),
             Self::BuiltinPromiseFinallyFunction(d) => d.mark_values(queues),
-            Self::BuiltinProxyRevokerFunction => {}
+            Self::BuiltinProxyRevokerFunction(d) => d.mark_values(queues),
             Self::PrimitiveObject(d) => d.mark_values(queues),
             Self::Arguments(d) => d.mark_values(queues),
             Self::Array(d) => d.mark_values(queues),
@@ -619,6 +683,17 @@ impl HeapMarkAndSweep for WeakKey<'static> {
             Self::Duration(d) => d.mark_values(queues),
             #[cfg(feature = "temporal")]
             Self::PlainTime(d) => d.mark_values(queues),
+            #[cfg(feature = "temporal")]
+            Self::PlainDate(d) => d.mark_values(queues),
+            #[cfg(feature = "temporal")]
+            Self::PlainDateTime(d) => d.mark_values(queues),
+            #[cfg(feature = "temporal")]
+            Self::ZonedDateTime(d) => d.mark_values(queues),
+            #[cfg(feature = "temporal")]
+            Self::PlainYearMonth(d) => d.mark_values(queues),
+            #[cfg(feature = "temporal")]
+            Self::PlainMonthDay(d) => d.mark_values(queues),
+            Self::DisposableStack(d) => d.mark_values(queues),
             Self::Error(d) => d.mark_values(queues),
             Self::FinalizationRegistry(d) => d.mark_values(queues),
             Self::Map(d) => d.mark_values(queues),
@@ -699,6 +774,9 @@ impl HeapMarkAndSweep for WeakKey<'static> {
             Self::SetIterator(d) => d.mark_values(queues),
             Self::MapIterator(d) => d.mark_values(queues),
             Self::StringIterator(d) => d.mark_values(queues),
+            Self::IteratorHelper(d) => d.mark_values(queues),
+            #[cfg(feature = "json")]
+            Self::RawJSON(d) => d.mark_values(queues),
             #[cfg(feature = "regexp")]
             Self::RegExpStringIterator(d) => d.mark_values(queues),
             Self::Generator(d) => d.mark_values(queues),
@@ -717,7 +795,7 @@ impl HeapMarkAndSweep for WeakKey<'static> {
             Self::BuiltinConstructorFunction(d) => d.sweep_values(compactions),
             Self::BuiltinPromiseResolvingFunction(d) => d.sweep_values(compactions),
             Self::BuiltinPromiseFinallyFunction(d) => d.sweep_values(compactions),
-            Self::BuiltinProxyRevokerFunction => {}
+            Self::BuiltinProxyRevokerFunction(d) => d.sweep_values(compactions),
             Self::PrimitiveObject(d) => d.sweep_values(compactions),
             Self::Arguments(d) => d.sweep_values(compactions),
             Self::Array(d) => d.sweep_values(compactions),
@@ -729,6 +807,17 @@ impl HeapMarkAndSweep for WeakKey<'static> {
             Self::Duration(d) => d.sweep_values(compactions),
             #[cfg(feature = "temporal")]
             Self::PlainTime(d) => d.sweep_values(compactions),
+            #[cfg(feature = "temporal")]
+            Self::PlainDate(d) => d.sweep_values(compactions),
+            #[cfg(feature = "temporal")]
+            Self::PlainDateTime(d) => d.sweep_values(compactions),
+            #[cfg(feature = "temporal")]
+            Self::ZonedDateTime(d) => d.sweep_values(compactions),
+            #[cfg(feature = "temporal")]
+            Self::PlainYearMonth(d) => d.sweep_values(compactions),
+            #[cfg(feature = "temporal")]
+            Self::PlainMonthDay(d) => d.sweep_values(compactions),
+            Self::DisposableStack(d) => d.sweep_values(compactions),
             Self::Error(d) => d.sweep_values(compactions),
             Self::FinalizationRegistry(d) => d.sweep_values(compactions),
             Self::Map(d) => d.sweep_values(compactions),
@@ -809,6 +898,9 @@ impl HeapMarkAndSweep for WeakKey<'static> {
             Self::SetIterator(d) => d.sweep_values(compactions),
             Self::MapIterator(d) => d.sweep_values(compactions),
             Self::StringIterator(d) => d.sweep_values(compactions),
+            Self::IteratorHelper(d) => d.sweep_values(compactions),
+            #[cfg(feature = "json")]
+            Self::RawJSON(d) => d.sweep_values(compactions),
             #[cfg(feature = "regexp")]
             Self::RegExpStringIterator(d) => d.sweep_values(compactions),
             Self::Generator(d) => d.sweep_values(compactions),
@@ -841,7 +933,9 @@ impl HeapSweepWeakReference for WeakKey<'static> {
             Self::BuiltinPromiseFinallyFunction(data) => data
                 .sweep_weak_reference(compactions)
                 .map(Self::BuiltinPromiseFinallyFunction),
-            Self::BuiltinProxyRevokerFunction => Some(Self::BuiltinProxyRevokerFunction),
+            Self::BuiltinProxyRevokerFunction(data) => data
+                .sweep_weak_reference(compactions)
+                .map(Self::BuiltinProxyRevokerFunction),
             Self::PrimitiveObject(data) => data
                 .sweep_weak_reference(compactions)
                 .map(Self::PrimitiveObject),
@@ -855,6 +949,27 @@ impl HeapSweepWeakReference for WeakKey<'static> {
             Self::Duration(data) => data.sweep_weak_reference(compactions).map(Self::Duration),
             #[cfg(feature = "temporal")]
             Self::PlainTime(data) => data.sweep_weak_reference(compactions).map(Self::PlainTime),
+            #[cfg(feature = "temporal")]
+            Self::PlainDate(data) => data.sweep_weak_reference(compactions).map(Self::PlainDate),
+            #[cfg(feature = "temporal")]
+            Self::PlainDateTime(data) => data
+                .sweep_weak_reference(compactions)
+                .map(Self::PlainDateTime),
+            #[cfg(feature = "temporal")]
+            Self::ZonedDateTime(data) => data
+                .sweep_weak_reference(compactions)
+                .map(Self::ZonedDateTime),
+            #[cfg(feature = "temporal")]
+            Self::PlainYearMonth(data) => data
+                .sweep_weak_reference(compactions)
+                .map(Self::PlainYearMonth),
+            #[cfg(feature = "temporal")]
+            Self::PlainMonthDay(data) => data
+                .sweep_weak_reference(compactions)
+                .map(Self::PlainMonthDay),
+            Self::DisposableStack(data) => data
+                .sweep_weak_reference(compactions)
+                .map(Self::DisposableStack),
             Self::Error(data) => data.sweep_weak_reference(compactions).map(Self::Error),
             Self::FinalizationRegistry(data) => data
                 .sweep_weak_reference(compactions)
@@ -981,6 +1096,11 @@ impl HeapSweepWeakReference for WeakKey<'static> {
             Self::StringIterator(data) => data
                 .sweep_weak_reference(compactions)
                 .map(Self::StringIterator),
+            Self::IteratorHelper(data) => data
+                .sweep_weak_reference(compactions)
+                .map(Self::IteratorHelper),
+            #[cfg(feature = "json")]
+            Self::RawJSON(data) => data.sweep_weak_reference(compactions).map(Self::RawJSON),
             #[cfg(feature = "regexp")]
             Self::RegExpStringIterator(data) => data
                 .sweep_weak_reference(compactions)
@@ -11,19 +11,22 @@ pub use module_semantics::*;
 use crate::{
     ecmascript::{
         Agent, Array, BUILTIN_STRING_MEMORY, EnumerateKeysAndValues, ExceptionType, JsResult,
-        Object, Promise, PromiseCapability, PromiseReactionHandler, String, Value,
-        enumerable_own_properties, get, get_active_script_or_module, if_abrupt_reject_promise_m,
-        inner_promise_then, to_string, unwrap_try,
+        Object, Promise, PromiseCapability, PromiseGroupRecord, PromiseGroupType,
+        PromiseReactionHandler, String, Value, array_create, enumerable_own_properties, get,
+        get_active_script_or_module, if_abrupt_reject_promise_m, inner_promise_then, to_string,
+        unwrap_try,
     },
     engine::{Bindable, GcScope, NoGcScope, Scopable, Scoped, typeof_operator},
+    heap::CreateHeapData,
 };
 
-/// ### [13.3.10.2 EvaluateImportCall ( specifierExpression \[ , optionsExpression \] )](https://tc39.es/ecma262/#sec-evaluate-import-call)
+/// ### [13.3.10.2 EvaluateImportCall ( specifierExpression \[ , optionsExpression \] , phase )](https://tc39.es/ecma262/#sec-evaluate-import-call)
 ///
 /// The abstract operation EvaluateImportCall takes argument
-/// specifierExpression (a Parse Node) and optional argument optionsExpression
-/// (a Parse Node) and returns either a normal completion containing a Promise
-/// or an abrupt completion.
+/// specifierExpression (a Parse Node), optional argument optionsExpression
+/// (a Parse Node), and argument phase (source, defer, or evaluation) and
+/// returns either a normal completion containing a Promise or an abrupt
+/// completion.
 ///
 /// > NOTE: This method performs steps 1, 2, and 7 onwards. Thus, the arguments
 /// > are already evaluated into Values (optional in the case of options).
@@ -31,6 +34,7 @@ pub(crate) fn evaluate_import_call<'gc>(
     agent: &mut Agent,
     specifier: Value,
     options: Option<Value>,
+    phase: ImportPhase,
     mut gc: GcScope<'gc, '_>,
 ) -> Promise<'gc> {
     let specifier = specifier.bind(gc.nogc());
@@ -189,9 +193,10 @@ pub(crate) fn evaluate_import_call<'gc>(
     };
     // 12. Let moduleRequest be a new ModuleRequest Record {
     let module_request = ModuleRequest::new_dynamic(
-        agent,     // [[Specifier]]: specifierString,
-        specifier, // [[Attributes]]: attributes
-        attributes, gc,
+        agent,      // [[Specifier]]: specifierString,
+        specifier,  // [[Attributes]]: attributes,
+        attributes, // [[Phase]]: phase
+        phase, gc,
     );
     // }.
     // 1. Let referrer be GetActiveScriptOrModule().
@@ -271,17 +276,18 @@ fn reject_unsupported_import_attributes<'gc>(
     promise_capability.promise
 }
 
-/// ### [13.3.10.3 ContinueDynamicImport ( promiseCapability, moduleCompletion )](https://tc39.es/ecma262/#sec-ContinueDynamicImport)
+/// ### [13.3.10.3 ContinueDynamicImport ( promiseCapability, phase, moduleCompletion )](https://tc39.es/ecma262/#sec-ContinueDynamicImport)
 ///
 /// The abstract operation ContinueDynamicImport takes arguments
-/// promiseCapability (a PromiseCapability Record) and moduleCompletion (either
-/// a normal completion containing a Module Record or a throw completion) and
-/// returns unused. It completes the process of a dynamic import originally
+/// promiseCapability (a PromiseCapability Record), phase (source, defer, or
+/// evaluation), and moduleCompletion (either a normal completion containing a
+/// Module Record or a throw completion) and returns unused. It completes the process of a dynamic import originally
 /// started by an import() call, resolving or rejecting the promise returned by
 /// that call as appropriate.
 pub(super) fn continue_dynamic_import<'a>(
     agent: &mut Agent,
     promise_capability: PromiseCapability<'a>,
+    phase: ImportPhase,
     module_completion: JsResult<'a, AbstractModule<'a>>,
     gc: NoGcScope<'a, '_>,
 ) {
@@ -297,7 +303,27 @@ pub(super) fn continue_dynamic_import<'a>(
         // 2. Let module be moduleCompletion.[[Value]].
         Ok(module) => module,
     };
-    // 3. Let loadPromise be module.LoadRequestedModules().
+    // 3. If phase is source, then
+    if phase == ImportPhase::Source {
+        // a. Let moduleSourceCompletion be Completion(module.GetModuleSource()).
+        match module.get_module_source(agent, gc) {
+            // b. If moduleSourceCompletion is an abrupt completion, then
+            Err(err) => {
+                // i. Perform ! Call(promiseCapability.[[Reject]], undefined,
+                //    « moduleSourceCompletion.[[Value]] »).
+                promise_capability.reject(agent, err.value(), gc);
+            }
+            // c. Else,
+            Ok(source) => {
+                // i. Perform ! Call(promiseCapability.[[Resolve]], undefined,
+                //    « moduleSourceCompletion.[[Value]] »).
+                unwrap_try(promise_capability.try_resolve(agent, source.into(), gc));
+            }
+        }
+        // d. Return unused.
+        return;
+    }
+    // 4. Let loadPromise be module.LoadRequestedModules().
     let load_promise = module.load_requested_modules(agent, None, gc);
     // 4. Let rejectedClosure be a new Abstract Closure with parameters
     //    (reason) that captures promiseCapability and performs the following
@@ -312,8 +338,16 @@ pub(super) fn continue_dynamic_import<'a>(
     inner_promise_then(
         agent,
         load_promise,
-        PromiseReactionHandler::DynamicImport { promise, module },
-        PromiseReactionHandler::DynamicImport { promise, module },
+        PromiseReactionHandler::DynamicImport {
+            promise,
+            module,
+            phase,
+        },
+        PromiseReactionHandler::DynamicImport {
+            promise,
+            module,
+            phase,
+        },
         None,
         gc,
     );
@@ -327,6 +361,7 @@ pub(crate) fn link_and_evaluate(
     agent: &mut Agent,
     promise: Promise,
     module: AbstractModule,
+    phase: ImportPhase,
     mut gc: GcScope,
 ) {
     let promise = promise.bind(gc.nogc());
@@ -340,9 +375,15 @@ pub(crate) fn link_and_evaluate(
         // ii. Return unused.
         return;
     }
+    // c. If phase is defer, then
+    if phase == ImportPhase::Defer {
+        link_and_evaluate_deferred(agent, promise.unbind(), module.unbind(), gc);
+        return;
+    }
     let scoped_module = module.scope(agent, gc.nogc());
     let promise = promise.scope(agent, gc.nogc());
-    // c. Let evaluatePromise be module.Evaluate().
+    // d. Else,
+    // i. Let evaluatePromise be module.Evaluate().
     let evaluate_promise = module
         .unbind()
         .evaluate(agent, gc.reborrow())
@@ -356,8 +397,8 @@ pub(crate) fn link_and_evaluate(
         // Synchronous evaluation finish.
         match result {
             Ok(_) => {
-                // i. Let namespace be GetModuleNamespace(module).
-                let namespace = get_module_namespace(agent, module, gc.nogc());
+                // i. Let namespace be GetModuleNamespace(module, phase).
+                let namespace = get_module_namespace(agent, module, phase, gc.nogc());
                 // ii. Perform ! Call(promiseCapability.[[Resolve]], undefined, « namespace »).
                 unwrap_try(PromiseCapability::from_promise(promise, true).try_resolve(
                     agent,
@@ -378,20 +419,115 @@ pub(crate) fn link_and_evaluate(
         }
         return;
     };
-    // d. Let fulfilledClosure be a new Abstract Closure with no parameters
-    //    that captures module and promiseCapability and performs the following
-    //    steps when called:
-    // e. Let onFulfilled be CreateBuiltinFunction(fulfilledClosure, 0, "", « »).
-    // f. Perform PerformPromiseThen(evaluatePromise, onFulfilled, onRejected).
+    // e. Let fulfilledClosure be a new Abstract Closure with no parameters
+    //    that captures module, phase, and promiseCapability and performs the
+    //    following steps when called:
+    // f. Let onFulfilled be CreateBuiltinFunction(fulfilledClosure, 0, "", « »).
+    // g. Perform PerformPromiseThen(evaluatePromise, onFulfilled, onRejected).
     inner_promise_then(
         agent,
         evaluate_promise,
-        PromiseReactionHandler::DynamicImportEvaluate { promise, module },
-        PromiseReactionHandler::DynamicImportEvaluate { promise, module },
+        PromiseReactionHandler::DynamicImportEvaluate {
+            promise,
+            module,
+            phase,
+        },
+        PromiseReactionHandler::DynamicImportEvaluate {
+            promise,
+            module,
+            phase,
+        },
         None,
         gc.nogc(),
     );
-    // g. Return unused.
+    // h. Return unused.
+}
+
+/// Steps c.i to c.vi of the linkAndEvaluateClosure for `import.defer()`:
+/// the asynchronous transitive dependencies of module are evaluated and the
+/// deferred namespace is resolved once they have all settled.
+fn link_and_evaluate_deferred(
+    agent: &mut Agent,
+    promise: Promise,
+    module: AbstractModule,
+    mut gc: GcScope,
+) {
+    let promise = promise.bind(gc.nogc());
+    let module = module.bind(gc.nogc());
+    // i. Let evaluationList be GatherAsynchronousTransitiveDependencies(module).
+    let mut evaluation_list = vec![];
+    gather_asynchronous_transitive_dependencies(
+        agent,
+        module,
+        &mut vec![],
+        &mut evaluation_list,
+        gc.nogc(),
+    );
+    // ii. If evaluationList is empty, then
+    if evaluation_list.is_empty() {
+        // 1. Let namespace be GetModuleNamespace(module, defer).
+        let namespace = get_module_namespace(agent, module, ImportPhase::Defer, gc.nogc());
+        // 2. Perform ! Call(promiseCapability.[[Resolve]], undefined, « namespace »).
+        unwrap_try(PromiseCapability::from_promise(promise, true).try_resolve(
+            agent,
+            namespace.into(),
+            gc.nogc(),
+        ));
+        // 3. Return unused.
+        return;
+    }
+    let promise = promise.scope(agent, gc.nogc());
+    let module = module.scope(agent, gc.nogc());
+    let evaluation_list = evaluation_list
+        .into_iter()
+        .map(|m| m.scope(agent, gc.nogc()))
+        .collect::<Vec<_>>();
+    // iii. Let asyncModuleEvaluationPromises be a new empty List.
+    // iv. For each Module Record m of evaluationList, do
+    let async_module_evaluation_promises = evaluation_list
+        .into_iter()
+        .map(|m| {
+            // 1. Append m.Evaluate() to asyncModuleEvaluationPromises.
+            // SAFETY: not shared.
+            let m = unsafe { m.take(agent) };
+            m.evaluate(agent, gc.reborrow())
+                .unbind()
+                .scope(agent, gc.nogc())
+        })
+        .collect::<Vec<_>>();
+    let gc = gc.into_nogc();
+    // SAFETY: not shared.
+    let promise = unsafe { promise.take(agent) }.bind(gc);
+    // SAFETY: not shared.
+    let module = unsafe { module.take(agent) }.bind(gc);
+    // v. Let evaluatePromise be ! PerformPromiseAll(asyncModuleEvaluationPromises).
+    let count = async_module_evaluation_promises.len();
+    let result_array = array_create(agent, count, count, None, gc)
+        .expect("Failed to allocate import.defer() result array");
+    let evaluate_capability = PromiseCapability::new(agent, gc);
+    let evaluate_promise = evaluate_capability.promise();
+    let promise_group = agent.heap.create(PromiseGroupRecord {
+        promise_group_type: PromiseGroupType::All,
+        remaining_elements_count: count as u32,
+        result_array: result_array.unbind(),
+        capability: evaluate_capability.unbind().into(),
+    });
+    for (index, m) in async_module_evaluation_promises.into_iter().enumerate() {
+        // SAFETY: not shared.
+        let m = unsafe { m.take(agent) }.bind(gc);
+        let reaction = PromiseReactionHandler::PromiseGroup {
+            index: index as u32,
+            promise_group,
+        };
+        inner_promise_then(agent, m, reaction, reaction, None, gc);
+    }
+    // vi. Perform PerformPromiseThen(evaluatePromise, onFulfilled, onRejected).
+    let reaction = PromiseReactionHandler::DynamicImportEvaluate {
+        promise,
+        module,
+        phase: ImportPhase::Defer,
+    };
+    inner_promise_then(agent, evaluate_promise, reaction, reaction, None, gc);
 }
 
 /// d. Let fulfilledClosure be a new Abstract Closure with no parameters
@@ -401,12 +537,13 @@ pub(crate) fn import_get_module_namespace(
     agent: &mut Agent,
     promise: Promise,
     module: AbstractModule,
+    phase: ImportPhase,
     gc: NoGcScope,
 ) {
     let promise = promise.bind(gc);
     let module = module.bind(gc);
-    // i. Let namespace be GetModuleNamespace(module).
-    let namespace = get_module_namespace(agent, module, gc);
+    // i. Let namespace be GetModuleNamespace(module, phase).
+    let namespace = get_module_namespace(agent, module, phase, gc);
     // ii. Perform ! Call(promiseCapability.[[Resolve]], undefined, « namespace »).
     unwrap_try(PromiseCapability::from_promise(promise, true).try_resolve(
         agent,
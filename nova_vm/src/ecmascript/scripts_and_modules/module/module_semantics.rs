@@ -51,10 +51,52 @@ pub(crate) struct ModuleRequestRecord<'a> {
     /// > NOTE: The attributes are sorted by key in a stable, deterministic,
     /// > but undetermined order.
     attributes: Option<Box<[ImportAttributeRecord<'a>]>>,
+    /// ### \[\[Phase]]
+    ///
+    /// The import phase of the request.
+    ///
+    /// > NOTE: The phase is not a part of the ModuleRequest's identity:
+    /// > requests differing only by phase load the same module.
+    phase: ImportPhase,
     /// Precomputed hash of the ModuleRequest specifier and attributes.
     hash: u64,
 }
 
+/// ### \[\[Phase]]
+///
+/// The phase of a module import: this determines how far the imported module
+/// is taken through its lifecycle by the import.
+///
+/// # Examples
+///
+/// ```javascript
+/// import source s from "a";
+/// import defer * as ns from "b";
+/// import { x } from "c";
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum ImportPhase {
+    /// The module is loaded but not linked or evaluated; the import produces
+    /// the module's source object.
+    Source,
+    /// The module is loaded and linked, and evaluated only when its deferred
+    /// namespace object is first accessed.
+    Defer,
+    /// The module is loaded, linked, and evaluated.
+    #[default]
+    Evaluation,
+}
+
+impl From<Option<ast::ImportPhase>> for ImportPhase {
+    fn from(value: Option<ast::ImportPhase>) -> Self {
+        match value {
+            Some(ast::ImportPhase::Source) => Self::Source,
+            Some(ast::ImportPhase::Defer) => Self::Defer,
+            None => Self::Evaluation,
+        }
+    }
+}
+
 impl<'a> ModuleRequestRecord<'a> {
     /// ### \[\[Attributes]]
     fn attributes(&self) -> &[ImportAttributeRecord<'a>] {
@@ -87,12 +129,13 @@ bindable_handle!(ModuleRequest);
 arena_vec_access!(ModuleRequest, 'a, ModuleRequestRecord, module_request_records);
 
 impl<'r> ModuleRequest<'r> {
-    /// Create a new ModuleRequest from a specifier string and a `with`
-    /// clause.
+    /// Create a new ModuleRequest from a specifier string, a `with` clause,
+    /// and an import phase.
     pub(super) fn new(
         agent: &mut Agent,
         specifier: &str,
         with_clause: Option<&ast::WithClause>,
+        phase: ImportPhase,
         gc: NoGcScope<'r, '_>,
     ) -> Self {
         let mut state = AHasher::default();
@@ -131,17 +174,19 @@ impl<'r> ModuleRequest<'r> {
         agent.heap.module_request_records.push(ModuleRequestRecord {
             specifier,
             attributes,
+            phase,
             hash,
         });
         Self::from_index_u32(index)
     }
 
-    /// Create a new ModuleRequest from a specifier String and a list of
-    /// import attribute records.
+    /// Create a new ModuleRequest from a specifier String, a list of import
+    /// attribute records, and an import phase.
     pub(super) fn new_dynamic(
         agent: &mut Agent,
         specifier: String,
         attributes: Box<[ImportAttributeRecord]>,
+        phase: ImportPhase,
         gc: NoGcScope<'r, '_>,
     ) -> Self {
         let mut state = AHasher::default();
@@ -156,6 +201,7 @@ impl<'r> ModuleRequest<'r> {
             ModuleRequestRecord {
                 specifier,
                 attributes: Some(attributes),
+                phase,
                 hash,
             }
             .unbind(),
@@ -172,6 +218,11 @@ impl<'r> ModuleRequest<'r> {
     pub fn attributes(self, agent: &Agent) -> &[ImportAttributeRecord<'r>] {
         self.get(agent).attributes()
     }
+
+    /// Get the ModuleRequest's \[\[Phase]].
+    pub fn phase(self, agent: &Agent) -> ImportPhase {
+        self.get(agent).phase
+    }
 }
 
 impl HeapIndexHandle for ModuleRequest<'_> {
@@ -529,8 +580,15 @@ pub fn finish_loading_imported_module<'a>(
         continue_module_loading(agent, payload, result, gc);
     } else {
         // 3. Else,
-        // a. Perform ContinueDynamicImport(payload, result).
-        continue_dynamic_import(agent, payload.promise_capability.clone(), result, gc);
+        // a. Perform ContinueDynamicImport(payload, moduleRequest.[[Phase]],
+        //    result).
+        continue_dynamic_import(
+            agent,
+            payload.promise_capability.clone(),
+            module_request.phase(agent),
+            result,
+            gc,
+        );
     }
     // 4. Return unused.
 }
@@ -557,13 +615,14 @@ pub(crate) fn all_import_attributes_supported(
     true
 }
 
-/// ### [16.2.1.13 GetModuleNamespace ( module )](https://tc39.es/ecma262/#sec-getmodulenamespace)
+/// ### [16.2.1.13 GetModuleNamespace ( module, phase )](https://tc39.es/ecma262/#sec-getmodulenamespace)
 ///
-/// The abstract operation GetModuleNamespace takes argument module (an
-/// instance of a concrete subclass of Module Record) and returns a Module
-/// Namespace Object. It retrieves the Module Namespace Object representing
-/// module's exports, lazily creating it the first time it was requested, and
-/// storing it in module.\[\[Namespace]] for future retrieval.
+/// The abstract operation GetModuleNamespace takes arguments module (an
+/// instance of a concrete subclass of Module Record) and phase (defer or
+/// evaluation) and returns a Module Namespace Object. It retrieves the Module
+/// Namespace Object representing module's exports, lazily creating it the
+/// first time it was requested, and storing it in module.\[\[Namespace]] or
+/// module.\[\[DeferredNamespace]] for future retrieval.
 ///
 /// > NOTE: GetModuleNamespace never throws. Instead, unresolvable names are
 /// > simply excluded from the namespace at this point. They will lead to a
@@ -572,9 +631,11 @@ pub(crate) fn all_import_attributes_supported(
 pub(crate) fn get_module_namespace<'a>(
     agent: &mut Agent,
     module: AbstractModule,
+    phase: ImportPhase,
     gc: NoGcScope<'a, '_>,
 ) -> Module<'a> {
     let module = module.bind(gc);
+    debug_assert_ne!(phase, ImportPhase::Source);
     if let Some(module) = module.as_source_text_module() {
         // 1. Assert: If module is a Cyclic Module Record, then module.[[Status]]
         //    is not new or unlinked.
@@ -583,8 +644,13 @@ pub(crate) fn get_module_namespace<'a>(
             CyclicModuleRecordStatus::New | CyclicModuleRecordStatus::Unlinked
         ));
     }
-    // 2. Let namespace be module.[[Namespace]].
-    let namespace = module.namespace(agent, gc);
+    // 2. If phase is defer, let namespace be module.[[DeferredNamespace]];
+    //    otherwise let namespace be module.[[Namespace]].
+    let namespace = if phase == ImportPhase::Defer {
+        module.deferred_namespace(agent, gc)
+    } else {
+        module.namespace(agent, gc)
+    };
     // 3. If namespace is empty, then
     let Some(namespace) = namespace else {
         // a. Let exportedNames be module.GetExportedNames().
@@ -601,8 +667,9 @@ pub(crate) fn get_module_namespace<'a>(
                 matches!(resolution, Some(ResolvedBinding::Resolved { .. }))
             })
             .collect::<Box<[String]>>();
-        // d. Set namespace to ModuleNamespaceCreate(module, unambiguousNames).
-        return module_namespace_create(agent, module, unambiguous_names, gc);
+        // d. Set namespace to ModuleNamespaceCreate(module, unambiguousNames,
+        //    phase).
+        return module_namespace_create(agent, module, unambiguous_names, phase, gc);
     };
     // 4. Return namespace.
     namespace
@@ -5,7 +5,9 @@
 //! ### [16.2.1.5 Abstract Module Records](https://tc39.es/ecma262/#sec-abstract-module-records)
 
 use crate::{
-    ecmascript::{Agent, HostDefined, JsResult, Module, ModuleEnvironment, Promise, Realm, String},
+    ecmascript::{
+        Agent, HostDefined, JsResult, Module, ModuleEnvironment, Object, Promise, Realm, String,
+    },
     engine::{Bindable, GcScope, HeapRootData, HeapRootRef, NoGcScope, Rootable, bindable_handle},
     heap::{CompactionLists, HeapMarkAndSweep, WorkQueues},
 };
@@ -29,6 +31,11 @@ pub(crate) struct AbstractModuleRecord<'a> {
     /// The Module Namespace Object (28.3) if one has been created for this
     /// module.
     namespace: Option<Module<'a>>,
+    /// ### \[\[DeferredNamespace]]
+    ///
+    /// The deferred Module Namespace Object if one has been created for this
+    /// module.
+    deferred_namespace: Option<Module<'a>>,
     /// ### \[\[HostDefined]]
     ///
     /// Field reserved for use by host environments that need to associate
@@ -44,6 +51,7 @@ impl<'m> AbstractModuleRecord<'m> {
             realm,
             environment: None,
             namespace: None,
+            deferred_namespace: None,
             host_defined,
         }
     }
@@ -74,6 +82,21 @@ impl<'m> AbstractModuleRecord<'m> {
         );
     }
 
+    /// ### \[\[DeferredNamespace]]
+    pub(super) fn deferred_namespace(&self) -> Option<Module<'m>> {
+        self.deferred_namespace
+    }
+
+    /// Set \[\[DeferredNamespace]] to namespace.
+    pub(super) fn set_deferred_namespace(&mut self, namespace: Module) {
+        assert!(
+            self.deferred_namespace
+                .replace(namespace.unbind())
+                .is_none(),
+            "Attempted to set deferred module namespace twice"
+        );
+    }
+
     /// ### \[\[Realm]]
     pub(super) fn realm(&self) -> Realm<'m> {
         self.realm
@@ -119,6 +142,19 @@ impl<'m> AbstractModule<'m> {
             InnerAbstractModule::SourceTextModule(m) => Some(m),
         }
     }
+
+    /// Returns true if the module has been evaluated, successfully or not.
+    /// Non-cyclic modules are always considered evaluated.
+    pub(crate) fn is_evaluated(self, agent: &Agent) -> bool {
+        self.as_source_text_module()
+            .is_none_or(|m| m.is_evaluated(agent))
+    }
+
+    /// Returns true if the module has been evaluated without error.
+    pub(crate) fn is_evaluated_without_error(self, agent: &Agent) -> bool {
+        self.as_source_text_module()
+            .is_none_or(|m| m.is_evaluated(agent) && !m.has_evaluation_error(agent))
+    }
 }
 
 impl<'a> From<SourceTextModule<'a>> for AbstractModule<'a> {
@@ -201,6 +237,12 @@ pub(crate) trait AbstractModuleSlots: Copy {
     /// Set \[\[Namespace]] to namespace.
     fn set_namespace(self, agent: &mut Agent, namespace: Module);
 
+    /// ### \[\[DeferredNamespace]]
+    fn deferred_namespace<'a>(self, agent: &Agent, gc: NoGcScope<'a, '_>) -> Option<Module<'a>>;
+
+    /// Set \[\[DeferredNamespace]] to namespace.
+    fn set_deferred_namespace(self, agent: &mut Agent, namespace: Module);
+
     /// ### \[\[Realm]]
     fn realm<'a>(self, agent: &Agent, gc: NoGcScope<'a, '_>) -> Realm<'a>;
 
@@ -285,6 +327,20 @@ pub(crate) trait AbstractModuleMethods: AbstractModuleSlots {
     ///
     /// Link must have completed successfully prior to invoking this method.
     fn evaluate<'gc>(self, agent: &mut Agent, gc: GcScope<'gc, '_>) -> Promise<'gc>;
+
+    /// ### GetModuleSource()
+    ///
+    /// Return the module source object for this module, or throw if this
+    /// module does not provide one. Module source objects inherit from
+    /// %AbstractModuleSource.prototype%.
+    ///
+    /// LoadRequestedModules must have completed successfully prior to invoking
+    /// this method.
+    fn get_module_source<'a>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'a, '_>,
+    ) -> JsResult<'a, Object<'a>>;
 }
 
 impl AbstractModuleSlots for AbstractModule<'_> {
@@ -316,6 +372,18 @@ impl AbstractModuleSlots for AbstractModule<'_> {
         }
     }
 
+    fn deferred_namespace<'a>(self, agent: &Agent, gc: NoGcScope<'a, '_>) -> Option<Module<'a>> {
+        match self.0 {
+            InnerAbstractModule::SourceTextModule(m) => m.deferred_namespace(agent, gc),
+        }
+    }
+
+    fn set_deferred_namespace(self, agent: &mut Agent, namespace: Module) {
+        match self.0 {
+            InnerAbstractModule::SourceTextModule(m) => m.set_deferred_namespace(agent, namespace),
+        }
+    }
+
     fn realm<'a>(self, agent: &Agent, gc: NoGcScope<'a, '_>) -> Realm<'a> {
         match self.0 {
             InnerAbstractModule::SourceTextModule(m) => m.realm(agent, gc),
@@ -381,6 +449,16 @@ impl AbstractModuleMethods for AbstractModule<'_> {
             InnerAbstractModule::SourceTextModule(m) => m.evaluate(agent, gc),
         }
     }
+
+    fn get_module_source<'a>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'a, '_>,
+    ) -> JsResult<'a, Object<'a>> {
+        match self.0 {
+            InnerAbstractModule::SourceTextModule(m) => m.get_module_source(agent, gc),
+        }
+    }
 }
 
 impl HeapMarkAndSweep for AbstractModule<'static> {
@@ -403,11 +481,13 @@ impl HeapMarkAndSweep for AbstractModuleRecord<'static> {
             realm,
             environment,
             namespace,
+            deferred_namespace,
             host_defined: _,
         } = self;
         realm.mark_values(queues);
         environment.mark_values(queues);
         namespace.mark_values(queues);
+        deferred_namespace.mark_values(queues);
     }
 
     fn sweep_values(&mut self, compactions: &CompactionLists) {
@@ -415,10 +495,12 @@ impl HeapMarkAndSweep for AbstractModuleRecord<'static> {
             realm,
             environment,
             namespace,
+            deferred_namespace,
             host_defined: _,
         } = self;
         realm.sweep_values(compactions);
         environment.sweep_values(compactions);
         namespace.sweep_values(compactions);
+        deferred_namespace.sweep_values(compactions);
     }
 }
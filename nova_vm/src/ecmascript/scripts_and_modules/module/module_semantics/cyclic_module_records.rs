@@ -6,7 +6,7 @@
 
 use crate::{
     ecmascript::{
-        AbstractModule, AbstractModuleMethods, Agent, HostDefined, JsError, JsResult,
+        AbstractModule, AbstractModuleMethods, Agent, HostDefined, ImportPhase, JsError, JsResult,
         LoadedModules, ModuleRequest, ModuleRequestRecord, Promise, PromiseCapability,
         PromiseReactionHandler, SourceTextModule, Value, get_imported_module, inner_promise_then,
         unwrap_try,
@@ -211,6 +211,11 @@ impl<'m> CyclicModuleRecord<'m> {
         }
     }
 
+    /// Returns true if \[\[EvaluationError]] is not empty.
+    pub(super) fn has_evaluation_error(&self) -> bool {
+        self.evaluation_error.is_some()
+    }
+
     /// Set \[\[EvaluationError]] to error and \[\[Status]] to evaluated.
     pub(super) fn set_evaluation_error(&mut self, error: JsError) {
         debug_assert!(
@@ -656,6 +661,11 @@ pub(crate) fn inner_module_linking<'a>(
     // 9. For each ModuleRequest Record request of module.[[RequestedModules]], do
     // SAFETY: module is currently rooted.
     for request in unsafe { module.get_requested_modules(agent) } {
+        // Note: source phase imports only expose the module source; the
+        // imported module is not linked.
+        if request.phase(agent) == ImportPhase::Source {
+            continue;
+        }
         // a. Let requiredModule be GetImportedModule(module, request).
         let required_module = get_imported_module(agent, module, *request, gc);
         // b. Set index to ? InnerModuleLinking(requiredModule, stack, index).
@@ -744,8 +754,7 @@ pub(crate) fn inner_module_evaluation<'a, 'b>(
     // 1. If module is not a Cyclic Module Record, then
     let Some(mut module) = module.as_source_text_module() else {
         // a. Perform ? EvaluateModuleSync(module).
-        // evaluate_module_sync(agent, module, gc)?;
-        module.unbind().evaluate(agent, gc.reborrow());
+        evaluate_module_sync(agent, module.unbind(), gc)?;
         // b. Return index.
         return Ok(index);
     };
@@ -783,14 +792,48 @@ pub(crate) fn inner_module_evaluation<'a, 'b>(
     index += 1;
     // 10. Append module to stack.
     stack.push(scoped_module.clone());
-    // 11. For each ModuleRequest Record request of module.[[RequestedModules]], do
+    // 11. Let evaluationList be a new empty List.
+    let mut evaluation_list: Vec<AbstractModule> = vec![];
+    // 12. For each ModuleRequest Record request of module.[[RequestedModules]], do
     // SAFETY: module is currently rooted.
     for request in scoped_module.get_requested_modules(agent) {
+        let phase = request.phase(agent);
+        // Note: source phase imports only expose the module source; the
+        // imported module is not evaluated.
+        if phase == ImportPhase::Source {
+            continue;
+        }
         // a. Let requiredModule be GetImportedModule(module, request).
         let required_module = get_imported_module(agent, module, request, gc.nogc());
-
-        let scoped_required_module = required_module.scope(agent, gc.nogc());
-        // b. Set index to ? InnerModuleEvaluation(requiredModule, stack, index).
+        // b. If request.[[Phase]] is defer, then
+        if phase == ImportPhase::Defer {
+            // i. Let additionalModules be
+            //    GatherAsynchronousTransitiveDependencies(requiredModule).
+            // ii. For each Module Record additionalModule of
+            //     additionalModules, do
+            //         1. If evaluationList does not contain additionalModule,
+            //            then
+            //             a. Append additionalModule to evaluationList.
+            gather_asynchronous_transitive_dependencies(
+                agent,
+                required_module,
+                &mut vec![],
+                &mut evaluation_list,
+                gc.nogc(),
+            );
+        } else if !evaluation_list.contains(&required_module) {
+            // c. Else if evaluationList does not contain requiredModule, then
+            // i. Append requiredModule to evaluationList.
+            evaluation_list.push(required_module);
+        }
+    }
+    let evaluation_list = evaluation_list
+        .into_iter()
+        .map(|m| m.scope(agent, gc.nogc()))
+        .collect::<Vec<_>>();
+    // 13. For each Module Record requiredModule of evaluationList, do
+    for scoped_required_module in evaluation_list {
+        // a. Set index to ? InnerModuleEvaluation(requiredModule, stack, index).
         index = inner_module_evaluation(
             agent,
             scoped_required_module.clone(),
@@ -801,7 +844,7 @@ pub(crate) fn inner_module_evaluation<'a, 'b>(
         .unbind()?;
         module = scoped_module.get(agent).bind(gc.nogc());
         let required_module = scoped_required_module.get(agent).bind(gc.nogc());
-        // c. If requiredModule is a Cyclic Module Record, then
+        // b. If requiredModule is a Cyclic Module Record, then
         if let Some(mut required_module) = required_module.as_source_text_module() {
             // i. Assert: requiredModule.[[Status]] is one of evaluating,
             //    evaluating-async, or evaluated.
@@ -846,7 +889,7 @@ pub(crate) fn inner_module_evaluation<'a, 'b>(
             }
         }
     }
-    // 12. If module.[[PendingAsyncDependencies]] > 0 or module.[[HasTLA]] is true, then
+    // 14. If module.[[PendingAsyncDependencies]] > 0 or module.[[HasTLA]] is true, then
     if module
         .pending_async_dependencies(agent)
         .is_some_and(|i| i > 0)
@@ -862,7 +905,7 @@ pub(crate) fn inner_module_evaluation<'a, 'b>(
             execute_async_module(agent, module.unbind(), gc.reborrow());
         }
     } else {
-        // 13. Else,
+        // 15. Else,
         // a. Perform ? module.ExecuteModule().
         module
             .unbind()
@@ -870,11 +913,11 @@ pub(crate) fn inner_module_evaluation<'a, 'b>(
             .unbind()?;
     }
     module = scoped_module.get(agent).bind(gc.nogc());
-    // 14. Assert: module occurs exactly once in stack.
+    // 16. Assert: module occurs exactly once in stack.
     debug_assert_eq!(stack.iter().filter(|m| m.get(agent) == module).count(), 1);
-    // 15. Assert: module.[[DFSAncestorIndex]] ≤ module.[[DFSIndex]].
+    // 17. Assert: module.[[DFSAncestorIndex]] ≤ module.[[DFSIndex]].
     debug_assert!(module.dfs_ancestor_index(agent) <= module.dfs_index(agent));
-    // 16. If module.[[DFSAncestorIndex]] = module.[[DFSIndex]], then
+    // 18. If module.[[DFSAncestorIndex]] = module.[[DFSIndex]], then
     if module.dfs_ancestor_index(agent) == module.dfs_index(agent) {
         // a. Let done be false.
         // b. Repeat, while done is false,
@@ -908,10 +951,168 @@ pub(crate) fn inner_module_evaluation<'a, 'b>(
             }
         }
     }
-    // 17. Return index.
+    // 19. Return index.
     Ok(index)
 }
 
+/// ### [GatherAsynchronousTransitiveDependencies ( module \[ , seen \] )](https://tc39.es/proposal-defer-import-eval/#sec-GatherAsynchronousTransitiveDependencies)
+///
+/// The abstract operation GatherAsynchronousTransitiveDependencies takes
+/// argument module (a Cyclic Module Record) and optional argument seen (a List
+/// of Module Records) and returns a List of Cyclic Module Records. It collects
+/// the direct post-order list of asynchronous unexecuted transitive
+/// dependencies, stopping the depth-first search for a branch when an
+/// asynchronous dependency is found.
+pub(crate) fn gather_asynchronous_transitive_dependencies<'a>(
+    agent: &Agent,
+    module: AbstractModule<'a>,
+    seen: &mut Vec<AbstractModule<'a>>,
+    result: &mut Vec<AbstractModule<'a>>,
+    gc: NoGcScope<'a, '_>,
+) {
+    // 1. If seen is not present, set seen to a new empty List.
+    // 2. Let result be a new empty List.
+    // 3. If seen contains module, return result.
+    if seen.contains(&module) {
+        return;
+    }
+    // 4. Append module to seen.
+    seen.push(module);
+    // 5. If module is not a Cyclic Module Record, return result.
+    let Some(cyclic_module) = module.as_source_text_module() else {
+        return;
+    };
+    // 6. If module.[[Status]] is either evaluating or evaluated, return
+    //    result.
+    if matches!(
+        cyclic_module.status(agent),
+        CyclicModuleRecordStatus::Evaluating | CyclicModuleRecordStatus::Evaluated
+    ) {
+        return;
+    }
+    // 7. If module.[[HasTLA]] is true, then
+    if cyclic_module.has_tla(agent) {
+        // a. Append module to result.
+        if !result.contains(&module) {
+            result.push(module);
+        }
+        // b. Return result.
+        return;
+    }
+    // 8. For each ModuleRequest Record required of
+    //    module.[[RequestedModules]], do
+    // SAFETY: No GC can happen while we hold the slice.
+    for request in unsafe { cyclic_module.get_requested_modules(agent) } {
+        if request.phase(agent) == ImportPhase::Source {
+            continue;
+        }
+        // a. Let requiredModule be GetImportedModule(module, required).
+        let required_module = get_imported_module(agent, cyclic_module, *request, gc);
+        // b. Let additionalModules be
+        //    GatherAsynchronousTransitiveDependencies(requiredModule, seen).
+        // c. For each Module Record m of additionalModules, do
+        //     i. If result does not contain m, append m to result.
+        gather_asynchronous_transitive_dependencies(agent, required_module, seen, result, gc);
+    }
+    // 9. Return result.
+}
+
+/// ### [ReadyForSyncExecution ( module \[ , seen \] )](https://tc39.es/proposal-defer-import-eval/#sec-ReadyForSyncExecution)
+///
+/// The abstract operation ReadyForSyncExecution takes argument module (a
+/// Module Record) and optional argument seen (a List of Module Records) and
+/// returns a Boolean. It checks whether module can be synchronously evaluated.
+pub(crate) fn ready_for_sync_execution<'a>(
+    agent: &Agent,
+    module: AbstractModule<'a>,
+    seen: &mut Vec<AbstractModule<'a>>,
+    gc: NoGcScope<'a, '_>,
+) -> bool {
+    // 1. If seen is not provided, let seen be a new empty List.
+    // 2. If seen contains module, return true.
+    if seen.contains(&module) {
+        return true;
+    }
+    // 3. Append module to seen.
+    seen.push(module);
+    // Note: non-cyclic modules are always synchronously evaluable.
+    let Some(cyclic_module) = module.as_source_text_module() else {
+        return true;
+    };
+    // 4. If module.[[Status]] is evaluated, return true.
+    // 5. If module.[[Status]] is evaluating or evaluating-async, return false.
+    match cyclic_module.status(agent) {
+        CyclicModuleRecordStatus::Evaluated => return true,
+        CyclicModuleRecordStatus::Evaluating | CyclicModuleRecordStatus::EvaluatingAsync => {
+            return false;
+        }
+        _ => {}
+    }
+    // 6. Assert: module.[[Status]] is linked.
+    debug_assert!(matches!(
+        cyclic_module.status(agent),
+        CyclicModuleRecordStatus::Linked
+    ));
+    // 7. If module.[[HasTLA]] is true, return false.
+    if cyclic_module.has_tla(agent) {
+        return false;
+    }
+    // 8. For each ModuleRequest Record required of
+    //    module.[[RequestedModules]], do
+    // SAFETY: No GC can happen while we hold the slice.
+    for request in unsafe { cyclic_module.get_requested_modules(agent) } {
+        if request.phase(agent) == ImportPhase::Source {
+            continue;
+        }
+        // a. Let requiredModule be GetImportedModule(module, required).
+        let required_module = get_imported_module(agent, cyclic_module, *request, gc);
+        // b. If ReadyForSyncExecution(requiredModule, seen) is false, then
+        if !ready_for_sync_execution(agent, required_module, seen, gc) {
+            // i. Return false.
+            return false;
+        }
+    }
+    // 9. Return true.
+    true
+}
+
+/// ### [EvaluateModuleSync ( module )](https://tc39.es/proposal-defer-import-eval/#sec-EvaluateModuleSync)
+///
+/// The abstract operation EvaluateModuleSync takes argument module (a Module
+/// Record) and returns either a normal completion containing unused or a
+/// throw completion. It synchronously evaluates module, provided that the
+/// caller guarantees that module's evaluation will not pause.
+pub(crate) fn evaluate_module_sync<'a>(
+    agent: &mut Agent,
+    module: AbstractModule,
+    mut gc: GcScope<'a, '_>,
+) -> JsResult<'a, ()> {
+    // 1. Assert: If module is a Cyclic Module Record,
+    //    ReadyForSyncExecution(module) is true.
+    debug_assert!(ready_for_sync_execution(
+        agent,
+        module,
+        &mut vec![],
+        gc.nogc()
+    ));
+    // 2. Let promise be module.Evaluate().
+    let promise = module.unbind().evaluate(agent, gc.reborrow()).unbind();
+    let gc = gc.into_nogc();
+    let promise = promise.bind(gc);
+    // 3. Assert: promise.[[PromiseState]] is either fulfilled or rejected.
+    // 4. If promise.[[PromiseState]] is rejected, then
+    if let Some(Err(err)) = promise.try_get_result(agent, gc) {
+        // a. If promise.[[PromiseIsHandled]] is false, perform
+        //    HostPromiseRejectionTracker(promise, "handle").
+        // b. Set promise.[[PromiseIsHandled]] to true.
+        promise.set_handled(agent);
+        // c. Return ThrowCompletion(promise.[[PromiseResult]]).
+        return Err(err);
+    }
+    // 5. Return unused.
+    Ok(())
+}
+
 /// 16.2.1.6.1.3.2 ExecuteAsyncModule ( module )
 ///
 /// The abstract operation ExecuteAsyncModule takes argument module (a Cyclic
@@ -21,11 +21,11 @@ use crate::{
         AsyncEvaluationOrder, AwaitReactionRecord, BUILTIN_STRING_MEMORY, Contains, ContainsSymbol,
         CyclicModuleMethods, CyclicModuleRecord, CyclicModuleRecordStatus, CyclicModuleSlots,
         ECMAScriptCodeEvaluationState, ExceptionType, ExecutionContext, GraphLoadingStateRecord,
-        HostDefined, JsError, JsResult, LexicallyScopedDeclaration, LexicallyScopedDeclarations,
-        Module, ModuleEnvironment, ModuleRequest, ModuleRequestRecord, OrdinaryObject, ParseResult,
-        Promise, PromiseCapability, PromiseReactionHandler, Realm, ResolveSetEntry,
-        ResolvedBinding, ScriptOrModule, SourceCode, SourceCodeType, String, Value,
-        VarScopedDeclaration, VarScopedDeclarations, create_import_binding,
+        HostDefined, ImportPhase, JsError, JsResult, LexicallyScopedDeclaration,
+        LexicallyScopedDeclarations, Module, ModuleEnvironment, ModuleRequest, ModuleRequestRecord,
+        Object, OrdinaryObject, ParseResult, Promise, PromiseCapability, PromiseReactionHandler,
+        Realm, ResolveSetEntry, ResolvedBinding, ScriptOrModule, SourceCode, SourceCodeType,
+        String, Value, VarScopedDeclaration, VarScopedDeclarations, create_import_binding,
         create_indirect_import_binding, get_imported_module, get_module_namespace,
         initialize_import_binding, inner_module_evaluation, inner_module_linking,
         inner_module_loading, inner_promise_then, instantiate_function_object,
@@ -35,7 +35,7 @@ use crate::{
         Bindable, Executable, ExecutionResult, GcScope, GcToken, HeapRootData, NoGcScope, Scopable,
         Scoped, Vm, bindable_handle,
     },
-    heap::{CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues},
+    heap::{Arena, CompactionLists, CreateHeapData, Heap, HeapMarkAndSweep, WorkQueues},
     ndt,
 };
 
@@ -341,6 +341,19 @@ impl<'m> SourceTextModule<'m> {
         self.get(agent).cyclic_fields.evaluation_error(gc)
     }
 
+    /// Returns true if \[\[Status]] is evaluated.
+    pub(super) fn is_evaluated(self, agent: &Agent) -> bool {
+        matches!(
+            self.get(agent).cyclic_fields.status(),
+            CyclicModuleRecordStatus::Evaluated
+        )
+    }
+
+    /// Returns true if \[\[EvaluationError]] is not empty.
+    pub(super) fn has_evaluation_error(self, agent: &Agent) -> bool {
+        self.get(agent).cyclic_fields.has_evaluation_error()
+    }
+
     /// Set \[\[EvaluationError]] to error and \[\[Status]] to evaluated.
     pub(super) fn set_evaluation_error(self, agent: &mut Agent, error: JsError) {
         self.get_mut(agent)
@@ -493,9 +506,12 @@ struct ImportEntryRecord<'a> {
     /// The name under which the desired binding is exported by the module
     /// identified by \[\[ModuleRequest]]. The value namespace-object indicates
     /// that the import request is for the target module's namespace object.
+    /// The value source indicates that the import request is for the target
+    /// module's source object.
     ///
-    /// Note: If the \[\[ImportName]] is NAMESPACE-OBJECT, then the value is
-    /// None.
+    /// Note: If the \[\[ImportName]] is NAMESPACE-OBJECT or SOURCE, then the
+    /// value is None. The two are told apart by the \[\[Phase]] of the
+    /// \[\[ModuleRequest]].
     import_name: Option<String<'a>>,
     /// ### \[\[LocalName]]
     ///
@@ -590,6 +606,19 @@ impl AbstractModuleSlots for SourceTextModule<'_> {
         self.get_mut(agent).abstract_fields.set_namespace(namespace);
     }
 
+    fn deferred_namespace<'a>(self, agent: &Agent, gc: NoGcScope<'a, '_>) -> Option<Module<'a>> {
+        self.get(agent)
+            .abstract_fields
+            .deferred_namespace()
+            .bind(gc)
+    }
+
+    fn set_deferred_namespace(self, agent: &mut Agent, namespace: Module) {
+        self.get_mut(agent)
+            .abstract_fields
+            .set_deferred_namespace(namespace);
+    }
+
     fn realm<'a>(self, agent: &Agent, gc: NoGcScope<'a, '_>) -> Realm<'a> {
         self.get(agent).abstract_fields.realm().bind(gc)
     }
@@ -967,12 +996,25 @@ impl AbstractModuleMethods for SourceTextModule<'_> {
                 | CyclicModuleRecordStatus::Evaluated
         ));
         // 3. If module.[[Status]] is either evaluating-async or evaluated,
+        //    then
         if matches!(
             module.status(agent),
             CyclicModuleRecordStatus::EvaluatingAsync | CyclicModuleRecordStatus::Evaluated
         ) {
-            // set module to module.[[CycleRoot]].
-            module = module.get_cycle_root(agent).unwrap();
+            // a. If module.[[CycleRoot]] is not empty, then
+            if let Some(cycle_root) = module.get_cycle_root(agent) {
+                // i. Set module to module.[[CycleRoot]].
+                module = cycle_root;
+            } else {
+                // b. Else,
+                // i. Assert: module.[[Status]] is evaluated and
+                //    module.[[EvaluationError]] is a throw completion.
+                debug_assert!(matches!(
+                    module.status(agent),
+                    CyclicModuleRecordStatus::Evaluated
+                ));
+                debug_assert!(module.evaluation_error(agent, gc.nogc()).is_err());
+            }
         }
         // 4. If module.[[TopLevelCapability]] is not empty, then
         if let Some(top_level_capability) = module.top_level_capability(agent) {
@@ -1054,6 +1096,26 @@ impl AbstractModuleMethods for SourceTextModule<'_> {
         // 11. Return capability.[[Promise]].
         capability.promise()
     }
+
+    /// ### [GetModuleSource ( )](https://tc39.es/proposal-source-phase-imports/#sec-source-text-module-record-getmodulesource)
+    ///
+    /// The GetModuleSource concrete method of a Source Text Module Record
+    /// module takes no arguments and returns a throw completion.
+    ///
+    /// > NOTE: Source Text Module Records do not yet provide a module source
+    /// > object representation.
+    fn get_module_source<'a>(
+        self,
+        agent: &mut Agent,
+        gc: NoGcScope<'a, '_>,
+    ) -> JsResult<'a, Object<'a>> {
+        // 1. Throw a SyntaxError exception.
+        Err(agent.throw_exception_with_static_message(
+            ExceptionType::SyntaxError,
+            "Source phase import is not available for this module",
+            gc,
+        ))
+    }
 }
 
 impl CyclicModuleSlots for SourceTextModule<'_> {
@@ -1171,14 +1233,22 @@ impl CyclicModuleMethods for SourceTextModule<'_> {
         for r#in in module.import_entries(agent) {
             // a. Let importedModule be GetImportedModule(module, in.[[ModuleRequest]]).
             let imported_module = get_imported_module(agent, module, r#in.module_request, gc);
-            // b. If in.[[ImportName]] is namespace-object, then
             let Some(import_name) = r#in.import_name else {
-                // i. Let namespace be GetModuleNamespace(importedModule).
-                let namespace = get_module_namespace(agent, imported_module, gc);
+                let phase = r#in.module_request.phase(agent);
+                // b. If in.[[ImportName]] is namespace-object, then
+                let value: Value = if phase != ImportPhase::Source {
+                    // i. Let namespace be GetModuleNamespace(importedModule,
+                    //    in.[[ModuleRequest]].[[Phase]]).
+                    get_module_namespace(agent, imported_module, phase, gc).into()
+                } else {
+                    // c. Else if in.[[ImportName]] is source, then
+                    // i. Let moduleSourceObject be ? importedModule.GetModuleSource().
+                    imported_module.get_module_source(agent, gc)?.into()
+                };
                 // ii. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
                 env.create_immutable_binding(agent, r#in.local_name);
                 // iii. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
-                env.initialize_binding(agent, r#in.local_name, namespace.into());
+                env.initialize_binding(agent, r#in.local_name, value);
                 continue;
             };
             // c. Else,
@@ -1198,8 +1268,10 @@ impl CyclicModuleMethods for SourceTextModule<'_> {
             };
             // iii. If resolution.[[BindingName]] is namespace, then
             let Some(resolution_binding_name) = resolution_binding_name else {
-                // 1. Let namespace be GetModuleNamespace(resolution.[[Module]]).
-                let namespace = get_module_namespace(agent, resolution_module, gc);
+                // 1. Let namespace be GetModuleNamespace(resolution.[[Module]],
+                //    evaluation).
+                let namespace =
+                    get_module_namespace(agent, resolution_module, ImportPhase::Evaluation, gc);
                 // 2. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
                 env.create_immutable_binding(agent, r#in.local_name);
                 // 3. Perform ! env.InitializeBinding(in.[[LocalName]], namespace).
@@ -1364,7 +1436,7 @@ impl CyclicModuleMethods for SourceTextModule<'_> {
             // we're doing here.
             // a. Let importedModule be GetImportedModule(module, in.[[ModuleRequest]]).
             let imported_modules = get_imported_module(agent, module, r#in.module_request, gc);
-            // b. If in.[[ImportName]] is namespace-object, then
+            // b. If in.[[ImportName]] is namespace-object or source, then
             let Some(import_name) = r#in.import_name else {
                 // i. Let namespace be GetModuleNamespace(importedModule).
                 // ii. Perform ! env.CreateImmutableBinding(in.[[LocalName]], true).
@@ -1680,8 +1752,13 @@ pub fn parse_module<'a>(
                 if ee.import_kind.is_type() {
                     continue;
                 }
-                let module_request =
-                    ModuleRequest::new(agent, &ee.source.value, ee.with_clause.as_deref(), gc);
+                let module_request = ModuleRequest::new(
+                    agent,
+                    &ee.source.value,
+                    ee.with_clause.as_deref(),
+                    ee.phase.into(),
+                    gc,
+                );
                 requested_modules.push(module_request);
                 let Some(specifiers) = &ee.specifiers else {
                     continue;
@@ -1712,9 +1789,16 @@ pub fn parse_module<'a>(
                             let local_name = specifier.local.name.as_str();
                             imported_bound_names.insert(local_name);
                             let local_name = String::from_str(agent, local_name, gc);
+                            // NOTE: `import source x from "..."` is parsed as a
+                            // default specifier; its ImportName is source.
+                            let import_name = if ee.phase == Some(ast::ImportPhase::Source) {
+                                None
+                            } else {
+                                Some(BUILTIN_STRING_MEMORY.default)
+                            };
                             import_entries.push(ImportEntryRecord {
                                 module_request,
-                                import_name: Some(BUILTIN_STRING_MEMORY.default),
+                                import_name,
                                 local_name,
                             })
                         }
@@ -1868,8 +1952,13 @@ pub fn parse_module<'a>(
                     // export { a, b as c } from "source";
                     //  ExportSpecifier : ModuleExportName
                     //  ExportSpecifier : ModuleExportName as ModuleExportName
-                    let module_request =
-                        ModuleRequest::new(agent, &source.value, ee.with_clause.as_deref(), gc);
+                    let module_request = ModuleRequest::new(
+                        agent,
+                        &source.value,
+                        ee.with_clause.as_deref(),
+                        ImportPhase::Evaluation,
+                        gc,
+                    );
                     requested_modules.push(module_request);
                     debug_assert!(ee.declaration.is_none());
                     for entry in ee.specifiers.iter() {
@@ -1957,8 +2046,13 @@ pub fn parse_module<'a>(
                     // c. Else,
                     // i. Append ee to indirectExportEntries.
                     // export * as ns from "foo";
-                    let module_request =
-                        ModuleRequest::new(agent, &ee.source.value, ee.with_clause.as_deref(), gc);
+                    let module_request = ModuleRequest::new(
+                        agent,
+                        &ee.source.value,
+                        ee.with_clause.as_deref(),
+                        ImportPhase::Evaluation,
+                        gc,
+                    );
                     requested_modules.push(module_request);
                     indirect_export_entries.push(IndirectExportEntryRecord {
                         export_name: String::from_str(agent, exported.name().as_str(), gc),
@@ -1969,8 +2063,13 @@ pub fn parse_module<'a>(
                     // b. Else if ee.[[ImportName]] is all-but-default, then
                     // i. Assert: ee.[[ExportName]] is null.
                     // ii. Append ee to starExportEntries.
-                    let module_request =
-                        ModuleRequest::new(agent, &ee.source.value, ee.with_clause.as_deref(), gc);
+                    let module_request = ModuleRequest::new(
+                        agent,
+                        &ee.source.value,
+                        ee.with_clause.as_deref(),
+                        ImportPhase::Evaluation,
+                        gc,
+                    );
                     requested_modules.push(module_request);
                     star_export_entries.push(module_request);
                 }
@@ -2191,6 +2290,7 @@ impl HeapMarkAndSweep for ModuleRequestRecord<'static> {
         let Self {
             specifier,
             attributes,
+            phase: _,
             hash: _,
         } = self;
         specifier.mark_values(queues);
@@ -2206,6 +2306,7 @@ impl HeapMarkAndSweep for ModuleRequestRecord<'static> {
         let Self {
             specifier,
             attributes,
+            phase: _,
             hash: _,
         } = self;
         specifier.sweep_values(compactions);
@@ -2219,7 +2320,7 @@ impl HeapMarkAndSweep for ModuleRequestRecord<'static> {
 }
 
 #[derive(Debug)]
-pub(crate) struct SourceTextModuleHeap(pub(crate) Vec<SourceTextModuleRecord<'static>>);
+pub(crate) struct SourceTextModuleHeap(pub(crate) Arena<Vec<SourceTextModuleRecord<'static>>>);
 
 impl Deref for SourceTextModuleHeap {
     type Target = Vec<SourceTextModuleRecord<'static>>;
@@ -653,6 +653,10 @@ impl Contains for ast::WithStatement<'_> {
 
 impl Contains for ast::VariableDeclaration<'_> {
     fn contains(&self, symbol: ContainsSymbol) -> bool {
+        // AwaitUsingDeclaration contains the await keyword.
+        if symbol == ContainsSymbol::Await && self.kind.is_await() {
+            return true;
+        }
         self.declarations.iter().any(|e| e.contains(symbol))
     }
 }
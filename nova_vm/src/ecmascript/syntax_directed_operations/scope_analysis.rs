@@ -58,7 +58,7 @@ impl LexicallyScopedDeclaration<'_> {
     #[inline]
     pub(crate) fn is_const(&self) -> bool {
         if let Self::Variable(v) = self {
-            v.kind.is_const()
+            v.kind.is_const() || v.kind.is_using()
         } else {
             false
         }
@@ -23,10 +23,11 @@ use crate::ecmascript::{
     ASYNC_GENERATOR_DISCRIMINANT, BOUND_FUNCTION_DISCRIMINANT,
     BUILTIN_CONSTRUCTOR_FUNCTION_DISCRIMINANT, BUILTIN_FUNCTION_DISCRIMINANT,
     BUILTIN_PROMISE_FINALLY_FUNCTION_DISCRIMINANT, BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT,
-    BUILTIN_PROXY_REVOKER_FUNCTION, ECMASCRIPT_FUNCTION_DISCRIMINANT, EMBEDDER_OBJECT_DISCRIMINANT,
-    ERROR_DISCRIMINANT, FINALIZATION_REGISTRY_DISCRIMINANT, Function, GENERATOR_DISCRIMINANT,
-    MAP_DISCRIMINANT, MAP_ITERATOR_DISCRIMINANT, MODULE_DISCRIMINANT, OBJECT_DISCRIMINANT,
-    PRIMITIVE_OBJECT_DISCRIMINANT, PROMISE_DISCRIMINANT, PROXY_DISCRIMINANT,
+    BUILTIN_PROXY_REVOKER_FUNCTION_DISCRIMINANT, DISPOSABLE_STACK_DISCRIMINANT,
+    ECMASCRIPT_FUNCTION_DISCRIMINANT, EMBEDDER_OBJECT_DISCRIMINANT, ERROR_DISCRIMINANT,
+    FINALIZATION_REGISTRY_DISCRIMINANT, Function, GENERATOR_DISCRIMINANT,
+    ITERATOR_HELPER_DISCRIMINANT, MAP_DISCRIMINANT, MAP_ITERATOR_DISCRIMINANT, MODULE_DISCRIMINANT,
+    OBJECT_DISCRIMINANT, PRIMITIVE_OBJECT_DISCRIMINANT, PROMISE_DISCRIMINANT, PROXY_DISCRIMINANT,
     STRING_ITERATOR_DISCRIMINANT, UnmappedArguments, Value,
 };
 #[cfg(feature = "array-buffer")]
@@ -43,11 +44,16 @@ use crate::ecmascript::{
 use crate::ecmascript::{DATE_DISCRIMINANT, Date};
 #[cfg(feature = "temporal")]
 use crate::ecmascript::{
-    DURATION_DISCRIMINANT, INSTANT_DISCRIMINANT, PLAIN_TIME_DISCRIMINANT, TemporalDuration,
-    TemporalInstant, TemporalPlainTime,
+    DURATION_DISCRIMINANT, INSTANT_DISCRIMINANT, PLAIN_DATE_DISCRIMINANT,
+    PLAIN_DATE_TIME_DISCRIMINANT, PLAIN_MONTH_DAY_DISCRIMINANT, PLAIN_TIME_DISCRIMINANT,
+    PLAIN_YEAR_MONTH_DISCRIMINANT, TemporalDuration, TemporalInstant, TemporalPlainDate,
+    TemporalPlainDateTime, TemporalPlainMonthDay, TemporalPlainTime, TemporalPlainYearMonth,
+    TemporalZonedDateTime, ZONED_DATE_TIME_DISCRIMINANT,
 };
 #[cfg(feature = "proposal-float16array")]
 use crate::ecmascript::{FLOAT_16_ARRAY_DISCRIMINANT, Float16Array};
+#[cfg(feature = "json")]
+use crate::ecmascript::{RAW_JSON_DISCRIMINANT, RawJSON};
 #[cfg(feature = "regexp")]
 use crate::ecmascript::{
     REGEXP_DISCRIMINANT, REGEXP_STRING_ITERATOR_DISCRIMINANT, RegExp, RegExpStringIterator,
@@ -77,18 +83,18 @@ use crate::{
     ecmascript::{
         Agent, ArgumentsList, Array, ArrayIterator, AsyncGenerator, BoundFunction,
         BuiltinConstructorFunction, BuiltinFunction, BuiltinPromiseFinallyFunction,
-        BuiltinPromiseResolvingFunction, ECMAScriptFunction, EmbedderObject, Error,
-        FinalizationRegistry, Generator, JsResult, Map, MapIterator, Module, ObjectShape,
-        ObjectShapeRecord, PrimitiveObject, Promise, PropertyDescriptor, PropertyLookupCache,
-        PropertyOffset, ProtoIntrinsics, Proxy, StringIterator, TryResult,
-        ordinary_object_create_with_intrinsics,
+        BuiltinPromiseResolvingFunction, BuiltinProxyRevokerFunction, DisposableStack,
+        ECMAScriptFunction, EmbedderObject, Error, FinalizationRegistry, Generator, IteratorHelper,
+        JsResult, Map, MapIterator, Module, ObjectShape, ObjectShapeRecord, PrimitiveObject,
+        Promise, PropertyDescriptor, PropertyLookupCache, PropertyOffset, ProtoIntrinsics, Proxy,
+        StringIterator, TryResult, ordinary_object_create_with_intrinsics,
     },
     engine::{Bindable, GcScope, HeapRootData, NoGcScope, bindable_handle},
     heap::{
-        ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData, DirectArenaAccess,
-        Heap, HeapMarkAndSweep, HeapSweepWeakReference, IntrinsicConstructorIndexes,
-        IntrinsicObjectIndexes, IntrinsicPrimitiveObjectIndexes, ObjectEntry, WorkQueues,
-        arena_vec_access,
+        AllocationError, ArenaAccess, ArenaAccessMut, BaseIndex, CompactionLists, CreateHeapData,
+        DirectArenaAccess, Heap, HeapMarkAndSweep, HeapSweepWeakReference,
+        IntrinsicConstructorIndexes, IntrinsicObjectIndexes, IntrinsicPrimitiveObjectIndexes,
+        ObjectEntry, WorkQueues, arena_vec_access,
         {
             ElementDescriptor, ElementStorageMut, ElementStorageRef, ElementStorageUninit,
             ElementsVector, PropertyStorageMut, PropertyStorageRef,
@@ -98,7 +104,6 @@ use crate::{
 
 use ahash::AHashMap;
 use core::hash::Hash;
-use std::collections::TryReserveError;
 
 /// ### [6.1.7 The Object Type](https://tc39.es/ecma262/#sec-object-type)
 ///
@@ -129,8 +134,9 @@ pub enum Object<'a> {
     /// Special functions created as part of `Promise.prototype.finally`.
     BuiltinPromiseFinallyFunction(BuiltinPromiseFinallyFunction<'a>) =
         BUILTIN_PROMISE_FINALLY_FUNCTION_DISCRIMINANT,
-    /// Placeholder.
-    BuiltinProxyRevokerFunction = BUILTIN_PROXY_REVOKER_FUNCTION,
+    /// Special functions created by `Proxy.revocable`.
+    BuiltinProxyRevokerFunction(BuiltinProxyRevokerFunction<'a>) =
+        BUILTIN_PROXY_REVOKER_FUNCTION_DISCRIMINANT,
     /// Primitive objects are special objects that hold a primitive value in their
     /// internal data.
     PrimitiveObject(PrimitiveObject<'a>) = PRIMITIVE_OBJECT_DISCRIMINANT,
@@ -153,6 +159,25 @@ pub enum Object<'a> {
     #[cfg(feature = "temporal")]
     /// # [4 Temporal.PlainTime Objects](https://tc39.es/proposal-temporal/#sec-temporal-plaintime-objects)
     PlainTime(TemporalPlainTime<'a>) = PLAIN_TIME_DISCRIMINANT,
+    #[cfg(feature = "temporal")]
+    /// # [3 Temporal.PlainDate Objects](https://tc39.es/proposal-temporal/#sec-temporal-plaindate-objects)
+    PlainDate(TemporalPlainDate<'a>) = PLAIN_DATE_DISCRIMINANT,
+    #[cfg(feature = "temporal")]
+    /// # [5 Temporal.PlainDateTime Objects](https://tc39.es/proposal-temporal/#sec-temporal-plaindatetime-objects)
+    PlainDateTime(TemporalPlainDateTime<'a>) = PLAIN_DATE_TIME_DISCRIMINANT,
+    #[cfg(feature = "temporal")]
+    /// # [6 Temporal.ZonedDateTime Objects](https://tc39.es/proposal-temporal/#sec-temporal-zoneddatetime-objects)
+    ZonedDateTime(TemporalZonedDateTime<'a>) = ZONED_DATE_TIME_DISCRIMINANT,
+    #[cfg(feature = "temporal")]
+    /// # [9 Temporal.PlainYearMonth Objects](https://tc39.es/proposal-temporal/#sec-temporal-plainyearmonth-objects)
+    PlainYearMonth(TemporalPlainYearMonth<'a>) = PLAIN_YEAR_MONTH_DISCRIMINANT,
+    #[cfg(feature = "temporal")]
+    /// # [10 Temporal.PlainMonthDay Objects](https://tc39.es/proposal-temporal/#sec-temporal-plainmonthday-objects)
+    PlainMonthDay(TemporalPlainMonthDay<'a>) = PLAIN_MONTH_DAY_DISCRIMINANT,
+    /// ## [27.3 DisposableStack Objects](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects)
+    ///
+    /// Both DisposableStack and AsyncDisposableStack objects.
+    DisposableStack(DisposableStack<'a>) = DISPOSABLE_STACK_DISCRIMINANT,
     /// ## [20.5 Error Objects](https://tc39.es/ecma262/#sec-error-objects)
     Error(Error<'a>) = ERROR_DISCRIMINANT,
     /// ## [26.2 FinalizationRegistry Objects](https://tc39.es/ecma262/#sec-finalization-registry-objects)
@@ -331,6 +356,13 @@ pub enum Object<'a> {
     MapIterator(MapIterator<'a>) = MAP_ITERATOR_DISCRIMINANT,
     /// ### [22.1.5 String Iterator Objects](https://tc39.es/ecma262/#sec-string-iterator-objects)
     StringIterator(StringIterator<'a>) = STRING_ITERATOR_DISCRIMINANT,
+    /// ### [27.1.2.1 Iterator Helper Objects](https://tc39.es/ecma262/#sec-iterator-helper-objects)
+    ///
+    /// Also used for %WrapForValidIteratorPrototype% objects.
+    IteratorHelper(IteratorHelper<'a>) = ITERATOR_HELPER_DISCRIMINANT,
+    #[cfg(feature = "json")]
+    /// ### [JSON.rawJSON ( text )](https://tc39.es/proposal-json-parse-with-source/#sec-json.rawjson)
+    RawJSON(RawJSON<'a>) = RAW_JSON_DISCRIMINANT,
     #[cfg(feature = "regexp")]
     /// ### [22.2.9 RegExp String Iterator Objects](https://tc39.es/ecma262/#sec-regexp-string-iterator-objects)
     RegExpStringIterator(RegExpStringIterator<'a>) = REGEXP_STRING_ITERATOR_DISCRIMINANT,
@@ -417,7 +449,7 @@ impl<'a> OrdinaryObject<'a> {
     /// Turn an OrdinaryObject's Object Shape into an intrinsic.
     ///
     /// For objects with an intrinsic shape, this is a no-op.
-    pub(crate) fn make_intrinsic(self, agent: &mut Agent) -> Result<(), TryReserveError> {
+    pub(crate) fn make_intrinsic(self, agent: &mut Agent) -> Result<(), AllocationError> {
         let shape = self.object_shape(agent);
         if shape.is_intrinsic(agent) {
             // Already an intrinsic shape, nothing to do.
@@ -566,7 +598,7 @@ impl<'a> OrdinaryObject<'a> {
         agent: &mut Agent,
         prototype: Option<Object<'a>>,
         entries: &[ObjectEntry<'a>],
-    ) -> Result<Self, TryReserveError> {
+    ) -> Result<Self, AllocationError> {
         let base_shape = ObjectShape::get_shape_for_prototype(agent, prototype);
         let mut shape = base_shape;
         for e in entries {
@@ -600,7 +632,7 @@ impl<'a> OrdinaryObject<'a> {
     pub(crate) fn create_object_with_shape(
         agent: &mut Agent,
         shape: ObjectShape<'a>,
-    ) -> Result<Self, TryReserveError> {
+    ) -> Result<Self, AllocationError> {
         let ElementsVector {
             elements_index: values,
             cap,
@@ -621,7 +653,7 @@ impl<'a> OrdinaryObject<'a> {
         agent: &mut Agent,
         prototype: Option<Object<'a>>,
         entries: &[ObjectEntry<'a>],
-    ) -> Result<Self, TryReserveError> {
+    ) -> Result<Self, AllocationError> {
         let properties_count = entries.len();
         let (cap, index) = agent
             .heap
@@ -783,7 +815,7 @@ impl<'a> From<Object<'a>> for Value<'a> {
             Object::BuiltinPromiseFinallyFunction(data) => {
                 Self::BuiltinPromiseFinallyFunction(data)
             }
-            Object::BuiltinProxyRevokerFunction => Self::BuiltinProxyRevokerFunction,
+            Object::BuiltinProxyRevokerFunction(d) => Self::from(d),
             Object::PrimitiveObject(data) => Self::PrimitiveObject(data),
             Object::Arguments(data) => Self::Arguments(data),
             Object::Array(data) => Self::Array(data),
@@ -795,6 +827,17 @@ impl<'a> From<Object<'a>> for Value<'a> {
             Object::Duration(data) => Value::Duration(data),
             #[cfg(feature = "temporal")]
             Object::PlainTime(data) => Value::PlainTime(data),
+            #[cfg(feature = "temporal")]
+            Object::PlainDate(data) => Value::PlainDate(data),
+            #[cfg(feature = "temporal")]
+            Object::PlainDateTime(data) => Value::PlainDateTime(data),
+            #[cfg(feature = "temporal")]
+            Object::ZonedDateTime(data) => Value::ZonedDateTime(data),
+            #[cfg(feature = "temporal")]
+            Object::PlainYearMonth(data) => Value::PlainYearMonth(data),
+            #[cfg(feature = "temporal")]
+            Object::PlainMonthDay(data) => Value::PlainMonthDay(data),
+            Object::DisposableStack(data) => Self::DisposableStack(data),
             Object::Error(data) => Self::Error(data),
             Object::FinalizationRegistry(data) => Self::FinalizationRegistry(data),
             Object::Map(data) => Self::Map(data),
@@ -875,6 +918,9 @@ impl<'a> From<Object<'a>> for Value<'a> {
             Object::SetIterator(data) => Self::SetIterator(data),
             Object::MapIterator(data) => Self::MapIterator(data),
             Object::StringIterator(data) => Self::StringIterator(data),
+            Object::IteratorHelper(data) => Self::IteratorHelper(data),
+            #[cfg(feature = "json")]
+            Object::RawJSON(data) => Self::RawJSON(data),
             #[cfg(feature = "regexp")]
             Object::RegExpStringIterator(data) => Self::RegExpStringIterator(data),
             Object::Generator(data) => Self::Generator(data),
@@ -925,6 +971,17 @@ macro_rules! object_delegate {
             Object::Duration(data) => data.$method($($arg),+),
             #[cfg(feature = "temporal")]
             Object::PlainTime(data) => data.$method($($arg),+),
+            #[cfg(feature = "temporal")]
+            Object::PlainDate(data) => data.$method($($arg),+),
+            #[cfg(feature = "temporal")]
+            Object::PlainDateTime(data) => data.$method($($arg),+),
+            #[cfg(feature = "temporal")]
+            Object::ZonedDateTime(data) => data.$method($($arg),+),
+            #[cfg(feature = "temporal")]
+            Object::PlainYearMonth(data) => data.$method($($arg),+),
+            #[cfg(feature = "temporal")]
+            Object::PlainMonthDay(data) => data.$method($($arg),+),
+            Self::DisposableStack(data) => data.$method($($arg),+),
             Self::Error(data) => data.$method($($arg),+),
             Self::BoundFunction(data) => data.$method($($arg),+),
             Self::BuiltinFunction(data) => data.$method($($arg),+),
@@ -932,7 +989,7 @@ macro_rules! object_delegate {
             Self::BuiltinConstructorFunction(data) => data.$method($($arg),+),
             Self::BuiltinPromiseResolvingFunction(data) => data.$method($($arg),+),
             Self::BuiltinPromiseFinallyFunction(data) => data.$method($($arg),+),
-            Self::BuiltinProxyRevokerFunction => todo!(),
+            Self::BuiltinProxyRevokerFunction(data) => data.$method($($arg),+),
             Self::PrimitiveObject(data) => data.$method($($arg),+),
             Self::Arguments(data) => data.$method($($arg),+),
             Self::FinalizationRegistry(data) => data.$method($($arg),+),
@@ -1014,6 +1071,9 @@ macro_rules! object_delegate {
             Self::SetIterator(data) => data.$method($($arg),+),
             Self::MapIterator(data) => data.$method($($arg),+),
             Self::StringIterator(data) => data.$method($($arg),+),
+            Self::IteratorHelper(data) => data.$method($($arg),+),
+            #[cfg(feature = "json")]
+            Self::RawJSON(data) => data.$method($($arg),+),
             #[cfg(feature = "regexp")]
             Self::RegExpStringIterator(data) => data.$method($($arg),+),
             Self::Generator(data) => data.$method($($arg),+),
@@ -1360,6 +1420,27 @@ impl HeapSweepWeakReference for Object<'static> {
             Self::Duration(data) => data.sweep_weak_reference(compactions).map(Self::Duration),
             #[cfg(feature = "temporal")]
             Self::PlainTime(data) => data.sweep_weak_reference(compactions).map(Self::PlainTime),
+            #[cfg(feature = "temporal")]
+            Self::PlainDate(data) => data.sweep_weak_reference(compactions).map(Self::PlainDate),
+            #[cfg(feature = "temporal")]
+            Self::PlainDateTime(data) => data
+                .sweep_weak_reference(compactions)
+                .map(Self::PlainDateTime),
+            #[cfg(feature = "temporal")]
+            Self::ZonedDateTime(data) => data
+                .sweep_weak_reference(compactions)
+                .map(Self::ZonedDateTime),
+            #[cfg(feature = "temporal")]
+            Self::PlainYearMonth(data) => data
+                .sweep_weak_reference(compactions)
+                .map(Self::PlainYearMonth),
+            #[cfg(feature = "temporal")]
+            Self::PlainMonthDay(data) => data
+                .sweep_weak_reference(compactions)
+                .map(Self::PlainMonthDay),
+            Self::DisposableStack(data) => data
+                .sweep_weak_reference(compactions)
+                .map(Self::DisposableStack),
             Self::Error(data) => data.sweep_weak_reference(compactions).map(Self::Error),
             Self::BoundFunction(data) => data
                 .sweep_weak_reference(compactions)
@@ -1379,7 +1460,9 @@ impl HeapSweepWeakReference for Object<'static> {
             Self::BuiltinPromiseFinallyFunction(data) => data
                 .sweep_weak_reference(compactions)
                 .map(Self::BuiltinPromiseFinallyFunction),
-            Self::BuiltinProxyRevokerFunction => todo!(),
+            Self::BuiltinProxyRevokerFunction(data) => data
+                .sweep_weak_reference(compactions)
+                .map(Self::BuiltinProxyRevokerFunction),
             Self::PrimitiveObject(data) => data
                 .sweep_weak_reference(compactions)
                 .map(Self::PrimitiveObject),
@@ -1506,6 +1589,11 @@ impl HeapSweepWeakReference for Object<'static> {
             Self::StringIterator(data) => data
                 .sweep_weak_reference(compactions)
                 .map(Self::StringIterator),
+            Self::IteratorHelper(data) => data
+                .sweep_weak_reference(compactions)
+                .map(Self::IteratorHelper),
+            #[cfg(feature = "json")]
+            Self::RawJSON(data) => data.sweep_weak_reference(compactions).map(Self::RawJSON),
             #[cfg(feature = "regexp")]
             Self::RegExpStringIterator(data) => data
                 .sweep_weak_reference(compactions)
@@ -1554,7 +1642,7 @@ impl From<Object<'_>> for HeapRootData {
             Object::BuiltinConstructorFunction(d) => Self::from(d),
             Object::BuiltinPromiseResolvingFunction(d) => Self::from(d),
             Object::BuiltinPromiseFinallyFunction(d) => Self::from(d),
-            Object::BuiltinProxyRevokerFunction => Self::BuiltinProxyRevokerFunction,
+            Object::BuiltinProxyRevokerFunction(d) => Self::from(d),
             Object::PrimitiveObject(d) => Self::from(d),
             Object::Arguments(d) => Self::from(d),
             Object::Array(d) => Self::from(d),
@@ -1566,6 +1654,17 @@ impl From<Object<'_>> for HeapRootData {
             Object::Instant(d) => Self::from(d),
             #[cfg(feature = "temporal")]
             Object::PlainTime(d) => Self::from(d),
+            #[cfg(feature = "temporal")]
+            Object::PlainDate(d) => Self::from(d),
+            #[cfg(feature = "temporal")]
+            Object::PlainDateTime(d) => Self::from(d),
+            #[cfg(feature = "temporal")]
+            Object::ZonedDateTime(d) => Self::from(d),
+            #[cfg(feature = "temporal")]
+            Object::PlainYearMonth(d) => Self::from(d),
+            #[cfg(feature = "temporal")]
+            Object::PlainMonthDay(d) => Self::from(d),
+            Object::DisposableStack(d) => Self::from(d),
             Object::Error(d) => Self::from(d),
             Object::FinalizationRegistry(d) => Self::from(d),
             Object::Map(d) => Self::from(d),
@@ -1646,6 +1745,9 @@ impl From<Object<'_>> for HeapRootData {
             Object::SetIterator(d) => Self::from(d),
             Object::MapIterator(d) => Self::from(d),
             Object::StringIterator(d) => Self::from(d),
+            Object::IteratorHelper(d) => Self::from(d),
+            #[cfg(feature = "json")]
+            Object::RawJSON(d) => Self::from(d),
             #[cfg(feature = "regexp")]
             Object::RegExpStringIterator(d) => Self::from(d),
             Object::Generator(d) => Self::from(d),
@@ -1672,7 +1774,9 @@ impl TryFrom<HeapRootData> for Object<'_> {
             HeapRootData::BuiltinConstructorFunction(f) => Ok(Self::BuiltinConstructorFunction(f)),
             HeapRootData::BuiltinPromiseResolvingFunction(f) => Ok(Self::from(f)),
             HeapRootData::BuiltinPromiseFinallyFunction(f) => Ok(Self::from(f)),
-            HeapRootData::BuiltinProxyRevokerFunction => Ok(Self::BuiltinProxyRevokerFunction),
+            HeapRootData::BuiltinProxyRevokerFunction(d) => {
+                Ok(Self::BuiltinProxyRevokerFunction(d))
+            }
             HeapRootData::PrimitiveObject(o) => Ok(Self::from(o)),
             HeapRootData::Arguments(o) => Ok(Self::from(o)),
             HeapRootData::Array(o) => Ok(Self::from(o)),
@@ -1684,6 +1788,17 @@ impl TryFrom<HeapRootData> for Object<'_> {
             HeapRootData::Duration(o) => Ok(Self::from(o)),
             #[cfg(feature = "temporal")]
             HeapRootData::PlainTime(o) => Ok(Self::from(o)),
+            #[cfg(feature = "temporal")]
+            HeapRootData::PlainDate(o) => Ok(Self::from(o)),
+            #[cfg(feature = "temporal")]
+            HeapRootData::PlainDateTime(o) => Ok(Self::from(o)),
+            #[cfg(feature = "temporal")]
+            HeapRootData::ZonedDateTime(o) => Ok(Self::from(o)),
+            #[cfg(feature = "temporal")]
+            HeapRootData::PlainYearMonth(o) => Ok(Self::from(o)),
+            #[cfg(feature = "temporal")]
+            HeapRootData::PlainMonthDay(o) => Ok(Self::from(o)),
+            HeapRootData::DisposableStack(o) => Ok(Self::from(o)),
             HeapRootData::Error(o) => Ok(Self::from(o)),
             HeapRootData::FinalizationRegistry(o) => Ok(Self::from(o)),
             HeapRootData::Map(o) => Ok(Self::from(o)),
@@ -1764,6 +1879,9 @@ impl TryFrom<HeapRootData> for Object<'_> {
             HeapRootData::SetIterator(o) => Ok(Self::from(o)),
             HeapRootData::MapIterator(o) => Ok(Self::from(o)),
             HeapRootData::StringIterator(o) => Ok(Self::from(o)),
+            HeapRootData::IteratorHelper(o) => Ok(Self::from(o)),
+            #[cfg(feature = "json")]
+            HeapRootData::RawJSON(o) => Ok(Self::from(o)),
             #[cfg(feature = "regexp")]
             HeapRootData::RegExpStringIterator(o) => Ok(Self::from(o)),
             HeapRootData::Generator(o) => Ok(Self::from(o)),
@@ -1772,6 +1890,7 @@ impl TryFrom<HeapRootData> for Object<'_> {
             HeapRootData::AwaitReaction(_)
             | HeapRootData::PromiseReaction(_)
             | HeapRootData::PromiseGroup(_)
+            | HeapRootData::ArrayFromAsync(_)
             | HeapRootData::Executable(_)
             | HeapRootData::Realm(_)
             | HeapRootData::Script(_)
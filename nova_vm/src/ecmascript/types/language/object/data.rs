@@ -110,6 +110,7 @@ impl ObjectRecord<'static> {
             ElementArrayKey::E16 => queues.e_2_16.push(*values),
             ElementArrayKey::E24 => queues.e_2_24.push(*values),
             ElementArrayKey::E32 => queues.e_2_32.push(*values),
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         }
     }
 
@@ -136,6 +137,7 @@ impl ObjectRecord<'static> {
             ElementArrayKey::E16 => compactions.e_2_16.shift_index(values),
             ElementArrayKey::E24 => compactions.e_2_24.shift_index(values),
             ElementArrayKey::E32 => compactions.e_2_32.shift_index(values),
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         };
     }
 }
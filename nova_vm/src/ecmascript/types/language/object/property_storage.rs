@@ -2,10 +2,7 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use std::{
-    collections::{TryReserveError, hash_map::Entry},
-    ptr::NonNull,
-};
+use std::{collections::hash_map::Entry, ptr::NonNull};
 
 use ahash::AHashMap;
 
@@ -16,7 +13,7 @@ use crate::{
     },
     engine::{Bindable, NoGcScope},
     heap::{
-        ArenaAccess, ArenaAccessMut, DirectArenaAccess, ElementIndex, Heap,
+        AllocationError, ArenaAccess, ArenaAccessMut, DirectArenaAccess, ElementIndex, Heap,
         {
             ElementDescriptor, ElementStorageMut, ElementStorageUninit, PropertyStorageMut,
             PropertyStorageRef,
@@ -57,7 +54,7 @@ impl<'a> PropertyStorage<'a> {
         self,
         agent: &mut Agent,
         private_name: PrivateName,
-    ) -> Result<(), TryReserveError> {
+    ) -> Result<(), AllocationError> {
         // SAFETY: Private fields are backed by on-stack data; mutating Agent
         // is totally okay.
         unsafe {
@@ -126,7 +123,7 @@ impl<'a> PropertyStorage<'a> {
         agent: &mut Agent,
         object: OrdinaryObject,
         private_fields: NonNull<[PrivateField]>,
-    ) -> Result<(), TryReserveError> {
+    ) -> Result<(), AllocationError> {
         let old_len = object.len(agent);
         let new_len = old_len.checked_add(private_fields.len() as u32).unwrap();
         let old_shape = object.object_shape(agent);
@@ -346,7 +343,7 @@ impl<'a> PropertyStorage<'a> {
         key: PropertyKey<'a>,
         descriptor: PropertyDescriptor<'a>,
         gc: NoGcScope,
-    ) -> Result<(), TryReserveError> {
+    ) -> Result<(), AllocationError> {
         let object = self.0;
 
         let value = descriptor.value;
@@ -395,7 +392,7 @@ impl<'a> PropertyStorage<'a> {
         value: Option<Value<'a>>,
         desc: Option<ElementDescriptor<'a>>,
         gc: NoGcScope,
-    ) -> Result<(), TryReserveError> {
+    ) -> Result<(), AllocationError> {
         let object = self.0;
 
         let old_len = object.len(agent);
@@ -465,7 +462,7 @@ impl<'a> PropertyStorage<'a> {
         agent: &mut Agent,
         o: Object,
         key: PropertyKey<'a>,
-    ) -> Result<(), TryReserveError> {
+    ) -> Result<(), AllocationError> {
         let object = self.0;
 
         let old_shape = object.object_shape(agent);
@@ -698,6 +698,7 @@ impl<'gc> String<'gc> {
         string_lookup_table: &mut HashTable<HeapString<'static>>,
         string_hasher: &ahash::RandomState,
         alloc_counter: &mut usize,
+        string_bytes: &mut usize,
         str: &str,
         gc: NoGcScope<'gc, '_>,
     ) -> Self {
@@ -712,7 +713,8 @@ impl<'gc> String<'gc> {
                     let data = StringRecord::from_str(str);
                     // SAFETY: checked that the value is not found.
                     String::String(unsafe {
-                        *alloc_counter += core::mem::size_of::<HeapString>();
+                        *alloc_counter += core::mem::size_of::<HeapString>() + str.len();
+                        *string_bytes += str.len();
                         Self::insert_string_with_hash(strings, string_lookup_table, data, hash)
                     })
                     .bind(gc)
@@ -815,6 +817,10 @@ impl Scoped<'_, String<'static>> {
 
 impl<'a> CreateHeapData<(StringRecord, u64), String<'a>> for Heap {
     fn create(&mut self, (data, hash): (StringRecord, u64)) -> String<'a> {
+        if let StringBuffer::Owned(buf) = &data.data {
+            self.alloc_counter += buf.len();
+            self.string_bytes += buf.len();
+        }
         self.strings.push(data);
         self.alloc_counter += core::mem::size_of::<StringRecord>();
         let index = BaseIndex::last(&self.strings);
@@ -6,6 +6,8 @@
 use crate::ecmascript::Date;
 #[cfg(feature = "proposal-float16array")]
 use crate::ecmascript::Float16Array;
+#[cfg(feature = "json")]
+use crate::ecmascript::RawJSON;
 #[cfg(all(feature = "proposal-float16array", feature = "shared-array-buffer"))]
 use crate::ecmascript::SharedFloat16Array;
 #[cfg(feature = "array-buffer")]
@@ -24,20 +26,24 @@ use crate::ecmascript::{
     SharedUint8Array, SharedUint8ClampedArray, SharedUint16Array, SharedUint32Array,
 };
 #[cfg(feature = "temporal")]
-use crate::ecmascript::{TemporalDuration, TemporalInstant, TemporalPlainTime};
+use crate::ecmascript::{
+    TemporalDuration, TemporalInstant, TemporalPlainDate, TemporalPlainDateTime,
+    TemporalPlainMonthDay, TemporalPlainTime, TemporalPlainYearMonth, TemporalZonedDateTime,
+};
 #[cfg(feature = "weak-refs")]
 use crate::ecmascript::{WeakMap, WeakRef, WeakSet};
 use crate::{
     ecmascript::{
         Agent, Array, ArrayIterator, AsyncGenerator, BUILTIN_STRING_MEMORY, BigInt, BoundFunction,
         BuiltinConstructorFunction, BuiltinFunction, BuiltinPromiseFinallyFunction,
-        BuiltinPromiseResolvingFunction, ECMAScriptFunction, EmbedderObject, Error,
-        FinalizationRegistry, Function, Generator, HeapBigInt, HeapNumber, HeapString, JsResult,
-        Map, MapIterator, Module, Number, Numeric, Object, OrdinaryObject, Primitive,
-        PrimitiveObject, Promise, Proxy, SmallBigInt, SmallF64, SmallInteger, SmallString, String,
-        StringIterator, Symbol, TryResult, UnmappedArguments, to_big_int, to_big_int64,
-        to_big_uint64, to_int8, to_int16, to_int32, to_number, to_numeric, to_string, to_uint8,
-        to_uint8_clamp, to_uint16, to_uint32, try_result_into_js, try_to_string,
+        BuiltinPromiseResolvingFunction, BuiltinProxyRevokerFunction, DisposableStack,
+        ECMAScriptFunction, EmbedderObject, Error, FinalizationRegistry, Function, Generator,
+        HeapBigInt, HeapNumber, HeapString, IteratorHelper, JsResult, Map, MapIterator, Module,
+        Number, Numeric, Object, OrdinaryObject, Primitive, PrimitiveObject, Promise, Proxy,
+        SmallBigInt, SmallF64, SmallInteger, SmallString, String, StringIterator, Symbol,
+        TryResult, UnmappedArguments, to_big_int, to_big_int64, to_big_uint64, to_int8, to_int16,
+        to_int32, to_number, to_numeric, to_string, to_uint8, to_uint8_clamp, to_uint16, to_uint32,
+        try_result_into_js, try_to_string,
     },
     engine::{
         Bindable, GcScope, HeapRootData, HeapRootRef, NoGcScope, Rootable, Scoped, bindable_handle,
@@ -169,7 +175,8 @@ pub enum Value<'a> {
     /// Special functions created as part of `Promise.prototype.finally`.
     BuiltinPromiseFinallyFunction(BuiltinPromiseFinallyFunction<'a>),
     /// Placeholder.
-    BuiltinProxyRevokerFunction,
+    /// Special functions created by `Proxy.revocable`.
+    BuiltinProxyRevokerFunction(BuiltinProxyRevokerFunction<'a>),
 
     /// Primitive objects are special objects that hold a primitive value in their
     /// internal data.
@@ -200,6 +207,23 @@ pub enum Value<'a> {
     #[cfg(feature = "temporal")]
     /// # [4 Temporal.PlainTime Objects](https://tc39.es/proposal-temporal/#sec-temporal-plaintime-objects)
     PlainTime(TemporalPlainTime<'a>),
+    #[cfg(feature = "temporal")]
+    /// # [3 Temporal.PlainDate Objects](https://tc39.es/proposal-temporal/#sec-temporal-plaindate-objects)
+    PlainDate(TemporalPlainDate<'a>),
+    #[cfg(feature = "temporal")]
+    /// # [5 Temporal.PlainDateTime Objects](https://tc39.es/proposal-temporal/#sec-temporal-plaindatetime-objects)
+    PlainDateTime(TemporalPlainDateTime<'a>),
+    #[cfg(feature = "temporal")]
+    /// # [6 Temporal.ZonedDateTime Objects](https://tc39.es/proposal-temporal/#sec-temporal-zoneddatetime-objects)
+    ZonedDateTime(TemporalZonedDateTime<'a>),
+    #[cfg(feature = "temporal")]
+    /// # [9 Temporal.PlainYearMonth Objects](https://tc39.es/proposal-temporal/#sec-temporal-plainyearmonth-objects)
+    PlainYearMonth(TemporalPlainYearMonth<'a>),
+    #[cfg(feature = "temporal")]
+    /// # [10 Temporal.PlainMonthDay Objects](https://tc39.es/proposal-temporal/#sec-temporal-plainmonthday-objects)
+    PlainMonthDay(TemporalPlainMonthDay<'a>),
+    /// ## [27.3 DisposableStack Objects](https://tc39.es/proposal-explicit-resource-management/#sec-disposablestack-objects)
+    DisposableStack(DisposableStack<'a>),
     /// ## [20.5 Error Objects](https://tc39.es/ecma262/#sec-error-objects)
     Error(Error<'a>),
     /// ## [26.2 FinalizationRegistry Objects](https://tc39.es/ecma262/#sec-finalization-registry-objects)
@@ -379,6 +403,11 @@ pub enum Value<'a> {
     MapIterator(MapIterator<'a>),
     /// ### [22.1.5 String Iterator Objects](https://tc39.es/ecma262/#sec-string-iterator-objects)
     StringIterator(StringIterator<'a>),
+    /// ### [27.1.2.1 Iterator Helper Objects](https://tc39.es/ecma262/#sec-iterator-helper-objects)
+    IteratorHelper(IteratorHelper<'a>),
+    #[cfg(feature = "json")]
+    /// ### [JSON.rawJSON ( text )](https://tc39.es/proposal-json-parse-with-source/#sec-json.rawjson)
+    RawJSON(RawJSON<'a>),
     #[cfg(feature = "regexp")]
     /// ### [22.2.9 RegExp String Iterator Objects](https://tc39.es/ecma262/#sec-regexp-string-iterator-objects)
     RegExpStringIterator(RegExpStringIterator<'a>),
@@ -434,6 +463,23 @@ pub(crate) const DURATION_DISCRIMINANT: u8 =
 #[cfg(feature = "temporal")]
 pub(crate) const PLAIN_TIME_DISCRIMINANT: u8 =
     value_discriminant(Value::PlainTime(TemporalPlainTime::_DEF));
+#[cfg(feature = "temporal")]
+pub(crate) const PLAIN_DATE_DISCRIMINANT: u8 =
+    value_discriminant(Value::PlainDate(TemporalPlainDate::_DEF));
+#[cfg(feature = "temporal")]
+pub(crate) const PLAIN_DATE_TIME_DISCRIMINANT: u8 =
+    value_discriminant(Value::PlainDateTime(TemporalPlainDateTime::_DEF));
+#[cfg(feature = "temporal")]
+pub(crate) const ZONED_DATE_TIME_DISCRIMINANT: u8 =
+    value_discriminant(Value::ZonedDateTime(TemporalZonedDateTime::_DEF));
+#[cfg(feature = "temporal")]
+pub(crate) const PLAIN_YEAR_MONTH_DISCRIMINANT: u8 =
+    value_discriminant(Value::PlainYearMonth(TemporalPlainYearMonth::_DEF));
+#[cfg(feature = "temporal")]
+pub(crate) const PLAIN_MONTH_DAY_DISCRIMINANT: u8 =
+    value_discriminant(Value::PlainMonthDay(TemporalPlainMonthDay::_DEF));
+pub(crate) const DISPOSABLE_STACK_DISCRIMINANT: u8 =
+    value_discriminant(Value::DisposableStack(DisposableStack::_DEF));
 pub(crate) const ERROR_DISCRIMINANT: u8 = value_discriminant(Value::Error(Error::_DEF));
 pub(crate) const BUILTIN_FUNCTION_DISCRIMINANT: u8 =
     value_discriminant(Value::BuiltinFunction(BuiltinFunction::_DEF));
@@ -453,8 +499,9 @@ pub(crate) const BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT: u8 = value_dis
 pub(crate) const BUILTIN_PROMISE_FINALLY_FUNCTION_DISCRIMINANT: u8 = value_discriminant(
     Value::BuiltinPromiseFinallyFunction(BuiltinPromiseFinallyFunction::_DEF),
 );
-pub(crate) const BUILTIN_PROXY_REVOKER_FUNCTION: u8 =
-    value_discriminant(Value::BuiltinProxyRevokerFunction);
+pub(crate) const BUILTIN_PROXY_REVOKER_FUNCTION_DISCRIMINANT: u8 = value_discriminant(
+    Value::BuiltinProxyRevokerFunction(BuiltinProxyRevokerFunction::_DEF),
+);
 pub(crate) const PRIMITIVE_OBJECT_DISCRIMINANT: u8 =
     value_discriminant(Value::PrimitiveObject(PrimitiveObject::_DEF));
 pub(crate) const ARGUMENTS_DISCRIMINANT: u8 =
@@ -571,6 +618,10 @@ pub(crate) const MAP_ITERATOR_DISCRIMINANT: u8 =
     value_discriminant(Value::MapIterator(MapIterator::_DEF));
 pub(crate) const STRING_ITERATOR_DISCRIMINANT: u8 =
     value_discriminant(Value::StringIterator(StringIterator::_DEF));
+pub(crate) const ITERATOR_HELPER_DISCRIMINANT: u8 =
+    value_discriminant(Value::IteratorHelper(IteratorHelper::_DEF));
+#[cfg(feature = "json")]
+pub(crate) const RAW_JSON_DISCRIMINANT: u8 = value_discriminant(Value::RawJSON(RawJSON::_DEF));
 #[cfg(feature = "regexp")]
 pub(crate) const REGEXP_STRING_ITERATOR_DISCRIMINANT: u8 =
     value_discriminant(Value::RegExpStringIterator(RegExpStringIterator::_DEF));
@@ -1113,7 +1164,7 @@ impl Rootable for Value<'_> {
             Self::BuiltinPromiseFinallyFunction(builtin_promise_finally_function) => {
                 Err(HeapRootData::from(builtin_promise_finally_function))
             }
-            Self::BuiltinProxyRevokerFunction => Err(HeapRootData::BuiltinProxyRevokerFunction),
+            Self::BuiltinProxyRevokerFunction(d) => Err(HeapRootData::from(d)),
             Self::PrimitiveObject(primitive_object) => Err(HeapRootData::from(primitive_object)),
             Self::Arguments(ordinary_object) => Err(HeapRootData::from(ordinary_object)),
             Self::Array(array) => Err(HeapRootData::from(array)),
@@ -1125,6 +1176,17 @@ impl Rootable for Value<'_> {
             Self::Duration(duration) => Err(HeapRootData::from(duration)),
             #[cfg(feature = "temporal")]
             Self::PlainTime(plain_time) => Err(HeapRootData::from(plain_time)),
+            #[cfg(feature = "temporal")]
+            Self::PlainDate(plain_date) => Err(HeapRootData::from(plain_date)),
+            #[cfg(feature = "temporal")]
+            Self::PlainDateTime(plain_date_time) => Err(HeapRootData::from(plain_date_time)),
+            #[cfg(feature = "temporal")]
+            Self::ZonedDateTime(zoned_date_time) => Err(HeapRootData::from(zoned_date_time)),
+            #[cfg(feature = "temporal")]
+            Self::PlainYearMonth(plain_year_month) => Err(HeapRootData::from(plain_year_month)),
+            #[cfg(feature = "temporal")]
+            Self::PlainMonthDay(plain_month_day) => Err(HeapRootData::from(plain_month_day)),
+            Self::DisposableStack(disposable_stack) => Err(HeapRootData::from(disposable_stack)),
             Self::Error(error) => Err(HeapRootData::from(error)),
             Self::FinalizationRegistry(finalization_registry) => {
                 Err(HeapRootData::from(finalization_registry))
@@ -1206,6 +1268,9 @@ impl Rootable for Value<'_> {
             Self::MapIterator(map_iterator) => Err(HeapRootData::from(map_iterator)),
             Self::Generator(generator) => Err(HeapRootData::from(generator)),
             Self::StringIterator(generator) => Err(HeapRootData::from(generator)),
+            Self::IteratorHelper(data) => Err(HeapRootData::from(data)),
+            #[cfg(feature = "json")]
+            Self::RawJSON(data) => Err(HeapRootData::from(data)),
             #[cfg(feature = "regexp")]
             Self::RegExpStringIterator(data) => Err(HeapRootData::from(data)),
             Self::Module(module) => Err(HeapRootData::from(module)),
@@ -1246,7 +1311,7 @@ impl Rootable for Value<'_> {
             HeapRootData::BuiltinConstructorFunction(f) => Some(Self::from(f)),
             HeapRootData::BuiltinPromiseResolvingFunction(f) => Some(Self::from(f)),
             HeapRootData::BuiltinPromiseFinallyFunction(f) => Some(Self::from(f)),
-            HeapRootData::BuiltinProxyRevokerFunction => Some(Self::BuiltinProxyRevokerFunction),
+            HeapRootData::BuiltinProxyRevokerFunction(d) => Some(Self::from(d)),
             HeapRootData::PrimitiveObject(o) => Some(Self::from(o)),
             HeapRootData::Arguments(o) => Some(Self::from(o)),
             HeapRootData::Array(o) => Some(Self::from(o)),
@@ -1258,6 +1323,17 @@ impl Rootable for Value<'_> {
             HeapRootData::Duration(o) => Some(Self::from(o)),
             #[cfg(feature = "temporal")]
             HeapRootData::PlainTime(o) => Some(Self::from(o)),
+            #[cfg(feature = "temporal")]
+            HeapRootData::PlainDate(o) => Some(Self::from(o)),
+            #[cfg(feature = "temporal")]
+            HeapRootData::PlainDateTime(o) => Some(Self::from(o)),
+            #[cfg(feature = "temporal")]
+            HeapRootData::ZonedDateTime(o) => Some(Self::from(o)),
+            #[cfg(feature = "temporal")]
+            HeapRootData::PlainYearMonth(o) => Some(Self::from(o)),
+            #[cfg(feature = "temporal")]
+            HeapRootData::PlainMonthDay(o) => Some(Self::from(o)),
+            HeapRootData::DisposableStack(o) => Some(Self::from(o)),
             HeapRootData::Error(o) => Some(Self::from(o)),
             HeapRootData::FinalizationRegistry(o) => Some(Self::from(o)),
             HeapRootData::Map(o) => Some(Self::from(o)),
@@ -1339,6 +1415,9 @@ impl Rootable for Value<'_> {
             HeapRootData::SetIterator(set_iterator) => Some(Self::from(set_iterator)),
             HeapRootData::MapIterator(map_iterator) => Some(Self::from(map_iterator)),
             HeapRootData::StringIterator(generator) => Some(Self::from(generator)),
+            HeapRootData::IteratorHelper(data) => Some(Self::from(data)),
+            #[cfg(feature = "json")]
+            HeapRootData::RawJSON(data) => Some(Self::from(data)),
             #[cfg(feature = "regexp")]
             HeapRootData::RegExpStringIterator(generator) => Some(Self::from(generator)),
             HeapRootData::Generator(generator) => Some(Self::from(generator)),
@@ -1352,6 +1431,7 @@ impl Rootable for Value<'_> {
             | HeapRootData::AwaitReaction(_)
             | HeapRootData::PromiseReaction(_)
             | HeapRootData::PromiseGroup(_)
+            | HeapRootData::ArrayFromAsync(_)
             | HeapRootData::DeclarativeEnvironment(_)
             | HeapRootData::FunctionEnvironment(_)
             | HeapRootData::GlobalEnvironment(_)
@@ -1405,6 +1485,17 @@ impl HeapMarkAndSweep for Value<'static> {
             Self::Duration(data) => data.mark_values(queues),
             #[cfg(feature = "temporal")]
             Self::PlainTime(data) => data.mark_values(queues),
+            #[cfg(feature = "temporal")]
+            Self::PlainDate(data) => data.mark_values(queues),
+            #[cfg(feature = "temporal")]
+            Self::PlainDateTime(data) => data.mark_values(queues),
+            #[cfg(feature = "temporal")]
+            Self::ZonedDateTime(data) => data.mark_values(queues),
+            #[cfg(feature = "temporal")]
+            Self::PlainYearMonth(data) => data.mark_values(queues),
+            #[cfg(feature = "temporal")]
+            Self::PlainMonthDay(data) => data.mark_values(queues),
+            Self::DisposableStack(data) => data.mark_values(queues),
             Self::Error(data) => data.mark_values(queues),
             Self::BoundFunction(data) => data.mark_values(queues),
             Self::BuiltinFunction(data) => data.mark_values(queues),
@@ -1484,13 +1575,16 @@ impl HeapMarkAndSweep for Value<'static> {
             Self::BuiltinConstructorFunction(data) => data.mark_values(queues),
             Self::BuiltinPromiseResolvingFunction(data) => data.mark_values(queues),
             Self::BuiltinPromiseFinallyFunction(data) => data.mark_values(queues),
-            Self::BuiltinProxyRevokerFunction => todo!(),
+            Self::BuiltinProxyRevokerFunction(data) => data.mark_values(queues),
             Self::AsyncGenerator(data) => data.mark_values(queues),
             Self::ArrayIterator(data) => data.mark_values(queues),
             #[cfg(feature = "set")]
             Self::SetIterator(data) => data.mark_values(queues),
             Self::MapIterator(data) => data.mark_values(queues),
             Self::StringIterator(data) => data.mark_values(queues),
+            Self::IteratorHelper(data) => data.mark_values(queues),
+            #[cfg(feature = "json")]
+            Self::RawJSON(data) => data.mark_values(queues),
             #[cfg(feature = "regexp")]
             Self::RegExpStringIterator(data) => data.mark_values(queues),
             Self::Generator(data) => data.mark_values(queues),
@@ -1524,6 +1618,17 @@ impl HeapMarkAndSweep for Value<'static> {
             Self::Duration(data) => data.sweep_values(compactions),
             #[cfg(feature = "temporal")]
             Self::PlainTime(data) => data.sweep_values(compactions),
+            #[cfg(feature = "temporal")]
+            Self::PlainDate(data) => data.sweep_values(compactions),
+            #[cfg(feature = "temporal")]
+            Self::PlainDateTime(data) => data.sweep_values(compactions),
+            #[cfg(feature = "temporal")]
+            Self::ZonedDateTime(data) => data.sweep_values(compactions),
+            #[cfg(feature = "temporal")]
+            Self::PlainYearMonth(data) => data.sweep_values(compactions),
+            #[cfg(feature = "temporal")]
+            Self::PlainMonthDay(data) => data.sweep_values(compactions),
+            Self::DisposableStack(data) => data.sweep_values(compactions),
             Self::Error(data) => data.sweep_values(compactions),
             Self::BoundFunction(data) => data.sweep_values(compactions),
             Self::BuiltinFunction(data) => data.sweep_values(compactions),
@@ -1606,13 +1711,16 @@ impl HeapMarkAndSweep for Value<'static> {
             Self::BuiltinConstructorFunction(data) => data.sweep_values(compactions),
             Self::BuiltinPromiseResolvingFunction(data) => data.sweep_values(compactions),
             Self::BuiltinPromiseFinallyFunction(data) => data.sweep_values(compactions),
-            Self::BuiltinProxyRevokerFunction => todo!(),
+            Self::BuiltinProxyRevokerFunction(data) => data.sweep_values(compactions),
             Self::AsyncGenerator(data) => data.sweep_values(compactions),
             Self::ArrayIterator(data) => data.sweep_values(compactions),
             #[cfg(feature = "set")]
             Self::SetIterator(data) => data.sweep_values(compactions),
             Self::MapIterator(data) => data.sweep_values(compactions),
             Self::StringIterator(data) => data.sweep_values(compactions),
+            Self::IteratorHelper(data) => data.sweep_values(compactions),
+            #[cfg(feature = "json")]
+            Self::RawJSON(data) => data.sweep_values(compactions),
             #[cfg(feature = "regexp")]
             Self::RegExpStringIterator(data) => data.sweep_values(compactions),
             Self::Generator(data) => data.sweep_values(compactions),
@@ -1630,7 +1738,7 @@ fn map_object_to_static_string_repr(value: Value) -> String<'static> {
         | Object::BuiltinConstructorFunction(_)
         | Object::BuiltinPromiseResolvingFunction(_)
         | Object::BuiltinPromiseFinallyFunction(_)
-        | Object::BuiltinProxyRevokerFunction => BUILTIN_STRING_MEMORY._object_Function_,
+        | Object::BuiltinProxyRevokerFunction(_) => BUILTIN_STRING_MEMORY._object_Function_,
         Object::Arguments(_) => BUILTIN_STRING_MEMORY._object_Arguments_,
         Object::Array(_) => BUILTIN_STRING_MEMORY._object_Array_,
         Object::Error(_) => BUILTIN_STRING_MEMORY._object_Error_,
@@ -1673,12 +1781,24 @@ fn map_object_to_static_string_repr(value: Value) -> String<'static> {
         Object::SharedFloat16Array(_) => BUILTIN_STRING_MEMORY._object_Object_,
         #[cfg(feature = "date")]
         Object::Date(_) => BUILTIN_STRING_MEMORY._object_Object_,
+        #[cfg(feature = "json")]
+        Object::RawJSON(_) => BUILTIN_STRING_MEMORY._object_Object_,
         #[cfg(feature = "temporal")]
         Object::Instant(_) => BUILTIN_STRING_MEMORY._object_Object_,
         #[cfg(feature = "temporal")]
         Object::Duration(_) => BUILTIN_STRING_MEMORY._object_Object_,
         #[cfg(feature = "temporal")]
         Object::PlainTime(_) => BUILTIN_STRING_MEMORY._object_Object_,
+        #[cfg(feature = "temporal")]
+        Object::PlainDate(_) => BUILTIN_STRING_MEMORY._object_Object_,
+        #[cfg(feature = "temporal")]
+        Object::PlainDateTime(_) => BUILTIN_STRING_MEMORY._object_Object_,
+        #[cfg(feature = "temporal")]
+        Object::ZonedDateTime(_) => BUILTIN_STRING_MEMORY._object_Object_,
+        #[cfg(feature = "temporal")]
+        Object::PlainYearMonth(_) => BUILTIN_STRING_MEMORY._object_Object_,
+        #[cfg(feature = "temporal")]
+        Object::PlainMonthDay(_) => BUILTIN_STRING_MEMORY._object_Object_,
         #[cfg(feature = "set")]
         Object::Set(_) | Object::SetIterator(_) => BUILTIN_STRING_MEMORY._object_Object_,
         #[cfg(feature = "weak-refs")]
@@ -1687,6 +1807,7 @@ fn map_object_to_static_string_repr(value: Value) -> String<'static> {
         }
         Object::Object(_)
         | Object::PrimitiveObject(_)
+        | Object::DisposableStack(_)
         | Object::FinalizationRegistry(_)
         | Object::Map(_)
         | Object::Promise(_)
@@ -1695,6 +1816,7 @@ fn map_object_to_static_string_repr(value: Value) -> String<'static> {
         | Object::ArrayIterator(_)
         | Object::MapIterator(_)
         | Object::StringIterator(_)
+        | Object::IteratorHelper(_)
         | Object::Generator(_)
         | Object::EmbedderObject(_) => BUILTIN_STRING_MEMORY._object_Object_,
     }
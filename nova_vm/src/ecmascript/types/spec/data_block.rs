@@ -318,6 +318,19 @@ impl DataBlock {
     }
 
     pub(crate) fn realloc(&mut self, new_byte_length: usize) {
+        if !self.try_realloc(new_byte_length) {
+            let layout = Layout::from_size_align(new_byte_length, 8).unwrap();
+            handle_alloc_error(layout);
+        }
+    }
+
+    /// Attempt to resize the DataBlock in place, or move it to a new
+    /// allocation of the given size.
+    ///
+    /// Returns false if the allocation failed, in which case the DataBlock is
+    /// left unchanged.
+    #[must_use]
+    pub(crate) fn try_realloc(&mut self, new_byte_length: usize) -> bool {
         // Max byte length should be within safe integer length.
         debug_assert!(new_byte_length < 2usize.pow(53));
         let ptr = if let Some(ptr) = self.ptr {
@@ -325,26 +338,27 @@ impl DataBlock {
                 // When resizing to zero, we can just reassign self to an empty
                 // data block; that drops the previous block which deallocs.
                 *self = Self::EMPTY_DATA_BLOCK;
-                return;
+                return true;
             } else {
+                let Ok(new_layout) = Layout::from_size_align(new_byte_length, 8) else {
+                    return false;
+                };
                 // SAFETY: `ptr` can currently only come from GlobalAllocator, it was
                 // allocated with `Layout::from_size_align(self.byte_length, 8)`, new
-                // size is non-zero, and cannot overflow isize (on a 64-bit machine).
+                // size is non-zero, and cannot overflow isize.
                 if self.byte_length > 0 {
                     let layout = Layout::from_size_align(self.byte_length, 8).unwrap();
-                    unsafe { realloc(ptr.as_ptr(), layout, new_byte_length) }
+                    unsafe { realloc(ptr.as_ptr(), layout, new_layout.size()) }
                 } else {
-                    let layout = Layout::from_size_align(new_byte_length, 8).unwrap();
-                    unsafe { alloc_zeroed(layout) }
+                    unsafe { alloc_zeroed(new_layout) }
                 }
             }
         } else {
             // Detached.
-            return;
+            return true;
         };
         let Some(ptr) = NonNull::new(ptr) else {
-            let layout = Layout::from_size_align(new_byte_length, 8).unwrap();
-            handle_alloc_error(layout);
+            return false;
         };
         self.ptr = Some(ptr);
         if new_byte_length > self.byte_length {
@@ -364,6 +378,7 @@ impl DataBlock {
             data_slice.fill(MaybeUninit::new(0));
         }
         self.byte_length = new_byte_length;
+        true
     }
 }
 
@@ -1178,6 +1193,8 @@ pub(crate) fn create_byte_data_block<'a>(
     size: u64,
     gc: NoGcScope<'a, '_>,
 ) -> JsResult<'a, DataBlock> {
+    // Note: the Data Block must also fit within the heap size limit.
+    agent.check_heap_allocation(usize::try_from(size).unwrap_or(usize::MAX), gc)?;
     // 1. If size > 2**53 - 1, throw a RangeError exception.
     if let Some(db) = usize::try_from(size)
         .ok()
@@ -1226,6 +1243,8 @@ pub(crate) unsafe fn create_shared_byte_data_block<'a>(
     unsafe {
         assert_unchecked(byte_length <= size);
     }
+    // Note: the Shared Data Block must also fit within the heap size limit.
+    agent.check_heap_allocation(usize::try_from(size).unwrap_or(usize::MAX), gc)?;
     // 1. Let db be a new Shared Data Block value consisting of size bytes. If
     //    it is impossible to create such a Shared Data Block, throw a
     //    RangeError exception.
@@ -36,6 +36,7 @@ use num_traits::Num;
 use oxc_ast::ast;
 use oxc_ecmascript::BoundNames;
 use oxc_semantic::{NodeId, ScopeFlags, SymbolFlags};
+use oxc_span::GetSpan;
 use oxc_syntax::operator::{BinaryOperator, UnaryOperator};
 use template_literals::get_template_object;
 use wtf8::{CodePoint, Wtf8Buf};
@@ -2142,7 +2143,13 @@ impl<'a, 's, 'gc, 'scope> CompileEvaluation<'a, 's, 'gc, 'scope> for ast::Import
     /// 1. Return ? EvaluateImportCall(the first AssignmentExpression, the second AssignmentExpression).
     /// ```
     ///
-    /// ### [13.3.10.2 EvaluateImportCall ( specifierExpression \[ , optionsExpression \] )](https://tc39.es/ecma262/#sec-evaluate-import-call)
+    /// ```text
+    /// ImportCall : import . PhaseKeyword ( AssignmentExpression , (opt) )
+    ///
+    /// 1. Return ? EvaluateImportCall(AssignmentExpression, empty, phase).
+    /// ```
+    ///
+    /// ### [13.3.10.2 EvaluateImportCall ( specifierExpression \[ , optionsExpression \] , phase )](https://tc39.es/ecma262/#sec-evaluate-import-call)
     fn compile(&'s self, ctx: &mut CompileContext<'a, 's, 'gc, 'scope>) -> Self::Output {
         // Note: referrer cannot change dynamically, so we don't need to get it
         // right here and now; we'll defer that to after all the other steps.
@@ -2167,7 +2174,11 @@ impl<'a, 's, 'gc, 'scope> CompileEvaluation<'a, 's, 'gc, 'scope> for ast::Import
         // a. Let options be undefined.
         // Note: we don't store an undefined constant; the ImportCall
         // instruction can take care of that.
-        ctx.add_instruction(Instruction::ImportCall);
+        ctx.add_instruction(match self.phase {
+            None => Instruction::ImportCall,
+            Some(ast::ImportPhase::Defer) => Instruction::ImportDeferCall,
+            Some(ast::ImportPhase::Source) => Instruction::ImportSourceCall,
+        });
         Ok(ValueOutput::Value)
     }
 }
@@ -2675,7 +2686,8 @@ fn compile_create_iterator_result_object(ctx: &mut CompileContext, done: bool) {
 impl<'a, 's, 'gc, 'scope> CompileEvaluation<'a, 's, 'gc, 'scope> for ast::Expression<'s> {
     type Output = Result<PlaceOrValue<'s, 'gc>, ExpressionError>;
     fn compile(&'s self, ctx: &mut CompileContext<'a, 's, 'gc, 'scope>) -> Self::Output {
-        match self {
+        let previous_position = ctx.set_source_position(Some(self.span().start));
+        let result = match self {
             ast::Expression::ArrayExpression(x) => x.compile(ctx).map(Into::into),
             ast::Expression::ArrowFunctionExpression(x) => {
                 x.compile(ctx);
@@ -2755,7 +2767,9 @@ impl<'a, 's, 'gc, 'scope> CompileEvaluation<'a, 's, 'gc, 'scope> for ast::Expres
             | ast::Expression::TSSatisfiesExpression(_) => {
                 unreachable!()
             }
-        }
+        };
+        ctx.set_source_position(previous_position);
+        result
     }
 }
 
@@ -3676,8 +3690,51 @@ impl<'a, 's, 'gc, 'scope> CompileEvaluation<'a, 's, 'gc, 'scope> for ast::Variab
                     // 6. Return empty.
                 }
             }
-            ast::VariableDeclarationKind::Using => todo!(),
-            ast::VariableDeclarationKind::AwaitUsing => todo!(),
+            // UsingDeclaration : using BindingList ;
+            // AwaitUsingDeclaration : CoverAwaitExpressionAndAwaitUsingDeclarationHead BindingList ;
+            ast::VariableDeclarationKind::Using | ast::VariableDeclarationKind::AwaitUsing => {
+                for decl in &self.declarations {
+                    // Note: using declarations only allow BindingIdentifiers
+                    // with an Initializer; the parser enforces this.
+                    let ast::BindingPattern::BindingIdentifier(identifier) = &decl.id else {
+                        unreachable!()
+                    };
+                    let init = decl.init.as_ref().unwrap();
+
+                    // LexicalBinding : BindingIdentifier Initializer
+                    // 1. Let bindingId be the StringValue of BindingIdentifier.
+                    // 2. Let lhs be ! ResolveBinding(bindingId).
+                    let lhs = identifier.compile(ctx);
+
+                    let do_push_reference = lhs.has_reference() && !init.is_literal();
+                    if do_push_reference {
+                        ctx.add_instruction(Instruction::PushReference);
+                    }
+                    // 3. If IsAnonymousFunctionDefinition(Initializer) is true, then
+                    if is_anonymous_function_definition(init) {
+                        // a. Let value be ? NamedEvaluation of Initializer with argument bindingId.
+                        ctx.add_instruction_with_constant(
+                            Instruction::StoreConstant,
+                            lhs.identifier().unwrap(),
+                        );
+                        ctx.name_identifier = Some(NamedEvaluationParameter::Result);
+                        // 4. Else,
+                        // a. Let rhs be ? Evaluation of Initializer.
+                    }
+                    // b. Let value be ? GetValue(rhs).
+                    let value = init.compile(ctx)?.get_value(ctx)?;
+                    if do_push_reference {
+                        ctx.add_instruction(Instruction::PopReference);
+                    }
+                    // 5. If hint is either sync-dispose or async-dispose, then
+                    // a. Perform ? AddDisposableResource(
+                    //    env.[[DisposeCapability]], value, hint).
+                    ctx.add_disposable_resource(self.kind.is_await());
+                    // 6. Perform ! InitializeReferencedBinding(lhs, value).
+                    lhs.initialise_referenced_binding(ctx, value);
+                    // 7. Return empty.
+                }
+            }
         }
         Ok(())
     }
@@ -3711,6 +3768,7 @@ impl<'a, 's, 'gc, 'scope> CompileLabelledEvaluation<'a, 's, 'gc, 'scope> for ast
     ) -> Self::Output {
         let mut per_iteration_env_lets: Vec<String<'_>> = vec![];
         let mut block_prep: Vec<BlockEnvPrep> = vec![];
+        let mut disposable_scope = None;
 
         let result = if let Some(init) = &self.init {
             match init {
@@ -3719,7 +3777,7 @@ impl<'a, 's, 'gc, 'scope> CompileLabelledEvaluation<'a, 's, 'gc, 'scope> for ast
                         // 1. Let oldEnv be the running execution context's LexicalEnvironment.
                         // 2. Let loopEnv be NewDeclarativeEnvironment(oldEnv).
                         // 3. Let isConst be IsConstantDeclaration of LexicalDeclaration.
-                        let is_const = init.kind.is_const();
+                        let is_const = init.kind.is_const() || init.kind.is_using();
                         // 4. Let boundNames be the BoundNames of LexicalDeclaration.
                         // 5. For each element dn of boundNames, do
                         // a. If isConst is true, then
@@ -3768,6 +3826,10 @@ impl<'a, 's, 'gc, 'scope> CompileLabelledEvaluation<'a, 's, 'gc, 'scope> for ast
                             });
                         }
                         // 6. Set the running execution context's LexicalEnvironment to loopEnv.
+                        if init.kind.is_using() {
+                            disposable_scope =
+                                Some(ctx.enter_disposable_scope(init.kind.is_await()));
+                        }
                     }
                     init.compile(ctx)
                 }
@@ -3781,6 +3843,9 @@ impl<'a, 's, 'gc, 'scope> CompileLabelledEvaluation<'a, 's, 'gc, 'scope> for ast
         };
 
         if let Err(err) = result {
+            if let Some(disposable_scope) = disposable_scope {
+                disposable_scope.exit(ctx);
+            }
             for block_prep in block_prep.into_iter().rev() {
                 block_prep.exit(ctx);
             }
@@ -3869,6 +3934,9 @@ impl<'a, 's, 'gc, 'scope> CompileLabelledEvaluation<'a, 's, 'gc, 'scope> for ast
         l.exit(ctx, continue_label);
         v.forget(ctx);
 
+        if let Some(disposable_scope) = disposable_scope {
+            disposable_scope.exit(ctx);
+        }
         for block_prep in block_prep.into_iter().rev() {
             block_prep.exit(ctx);
         }
@@ -34,16 +34,32 @@ pub(super) fn instantiation<'s, 'gc>(
     cb: impl FnOnce(&mut CompileContext<'_, 's, 'gc, '_>) -> StatementResult<'gc>,
 ) -> StatementResult<'gc> {
     let mut block_prep = Vec::new();
+    let mut has_using_declarations = false;
+    let mut has_await_using_declarations = false;
     // 1. Let declarations be the LexicallyScopedDeclarations of code.
     // 2. Let privateEnv be the running execution context's PrivateEnvironment.
     // 3. For each element d of declarations, do
     code.lexically_scoped_declarations(&mut |d| {
+        if let LexicallyScopedDeclaration::Variable(decl) = d
+            && decl.kind.is_using()
+        {
+            has_using_declarations = true;
+            has_await_using_declarations |= decl.kind.is_await();
+        }
         handle_block_lexically_scoped_declaration(ctx, &mut block_prep, d);
     });
+    // NOTE: Blocks containing using declarations get a DisposeCapability
+    // whose resources are disposed when the block is exited.
+    let disposable_scope =
+        has_using_declarations.then(|| ctx.enter_disposable_scope(has_await_using_declarations));
 
     // 4. Return unused.
     let result = cb(ctx);
 
+    if let Some(disposable_scope) = disposable_scope {
+        disposable_scope.exit(ctx);
+    }
+
     for prop in block_prep.into_iter().rev() {
         prop.exit(ctx);
     }
@@ -57,7 +73,9 @@ fn handle_block_lexically_scoped_declaration<'s>(
 ) {
     match d {
         // a. For each element dn of the BoundNames of d, do
-        LexicallyScopedDeclaration::Variable(decl) if decl.kind.is_const() => {
+        LexicallyScopedDeclaration::Variable(decl)
+            if decl.kind.is_const() || decl.kind.is_using() =>
+        {
             // i. If IsConstantDeclaration of d is true, then
             decl.id.bound_names(&mut |identifier| {
                 if handle_lexical_variable(ctx, identifier, block_prep, None) {
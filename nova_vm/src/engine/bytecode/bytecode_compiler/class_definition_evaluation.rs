@@ -1097,7 +1097,7 @@ impl<'a, 's, 'gc, 'scope> CompileEvaluation<'a, 's, 'gc, 'scope> for ast::Static
             let mut create_default_export = false;
             self.lexically_scoped_declarations(&mut |d| match d {
                 LexicallyScopedDeclaration::Variable(decl) => {
-                    is_constant_declaration.set(decl.kind.is_const());
+                    is_constant_declaration.set(decl.kind.is_const() || decl.kind.is_using());
                     decl.id.bound_names(cb);
                     is_constant_declaration.set(false);
                 }
@@ -1141,12 +1141,16 @@ impl<'a, 's, 'gc, 'scope> CompileEvaluation<'a, 's, 'gc, 'scope> for ast::Static
             f.put_value(ctx, ValueOutput::Value).unwrap();
         }
 
+        let disposable_scope = ctx.enter_disposable_scope_for_statements(&self.body);
         for statement in self.body.iter() {
             let result = statement.compile(ctx);
             if result.is_break() {
                 break;
             }
         }
+        if let Some(disposable_scope) = disposable_scope {
+            disposable_scope.exit(ctx);
+        }
         for block_prep in block_prep.into_iter().rev() {
             block_prep.exit(ctx);
         }
@@ -19,8 +19,9 @@ use crate::{
         Executable, FunctionExpression, Instruction, NoGcScope,
         bytecode::{
             bytecode_compiler::finaliser_stack::{
-                compile_array_destructuring_exit, compile_if_statement_exit, compile_loop_exit,
-                compile_stack_variable_exit, compile_sync_iterator_exit,
+                compile_array_destructuring_exit, compile_disposable_scope_exit,
+                compile_if_statement_exit, compile_loop_exit, compile_stack_variable_exit,
+                compile_sync_iterator_exit,
             },
             executable::ArrowFunctionExpression,
         },
@@ -170,6 +171,12 @@ impl<'agent, 'script, 'gc, 'scope> CompileContext<'agent, 'script, 'gc, 'scope>
         self.executable.create_property_lookup_cache(identifier)
     }
 
+    /// Set the source text offset that following instructions originate from.
+    /// Returns the previous source text offset.
+    pub(crate) fn set_source_position(&mut self, position: Option<u32>) -> Option<u32> {
+        self.executable.set_source_position(position)
+    }
+
     /// Create a new JavaScript BigInt from a bigint literal and radix.
     pub(crate) fn create_bigint(&mut self, literal: &str, radix: u32) -> BigInt<'gc> {
         self.executable.create_bigint(literal, radix)
@@ -886,6 +893,93 @@ impl<'agent, 'script, 'gc, 'scope> CompileContext<'agent, 'script, 'gc, 'scope>
         }
     }
 
+    /// Enter a scope containing `using` or `await using` declarations.
+    ///
+    /// A new DisposeCapability is pushed onto the stack, and a catch block is
+    /// set up to dispose its resources on thrown error.
+    #[must_use]
+    pub(super) fn enter_disposable_scope(&mut self, is_async: bool) -> DisposableScope {
+        self.add_instruction(Instruction::PushDisposeCapability);
+        let stack_slot = self.executable.push_stack();
+        self.control_flow_stack
+            .push(ControlFlowStackEntry::DisposableScope {
+                stack_slot,
+                is_async,
+            });
+        DisposableScope(self.add_instruction_with_jump_slot(Instruction::PushExceptionJumpTarget))
+    }
+
+    /// Enter a scope containing `using` or `await using` declarations if the
+    /// statement list directly contains any.
+    pub(crate) fn enter_disposable_scope_for_statements<'b>(
+        &mut self,
+        statements: impl IntoIterator<Item = &'b Statement<'b>>,
+    ) -> Option<DisposableScope> {
+        let mut has_using_declarations = false;
+        let mut is_async = false;
+        for statement in statements {
+            if let Statement::VariableDeclaration(decl) = statement
+                && decl.kind.is_using()
+            {
+                has_using_declarations = true;
+                is_async |= decl.kind.is_await();
+            }
+        }
+        has_using_declarations.then(|| self.enter_disposable_scope(is_async))
+    }
+
+    /// Exit a scope containing `using` or `await using` declarations.
+    fn exit_disposable_scope(&mut self, scope: DisposableScope) {
+        let jump_to_catch = scope.0.clone();
+        core::mem::forget(scope);
+        let Some(ControlFlowStackEntry::DisposableScope { is_async, .. }) =
+            self.control_flow_stack.pop()
+        else {
+            unreachable!()
+        };
+        let jump_over_catch = if !self.is_unreachable() {
+            compile_disposable_scope_exit(&mut self.executable, is_async);
+            Some(self.add_instruction_with_jump_slot(Instruction::Jump))
+        } else {
+            None
+        };
+        // A thrown error disposes the resources with the error as the
+        // completion, and then rethrows the resulting error.
+        self.set_jump_target_here(jump_to_catch);
+        self.reset_stack_depth();
+        if is_async {
+            self.add_instruction(Instruction::AsyncDisposeResourcesWithError);
+        } else {
+            self.add_instruction(Instruction::DisposeResourcesWithError);
+        }
+        self.executable.pop_stack();
+        if let Some(jump_over_catch) = jump_over_catch {
+            self.set_jump_target_here(jump_over_catch);
+        }
+    }
+
+    /// Perform AddDisposableResource with the value in the result register,
+    /// adding it to the DisposeCapability of the innermost disposable scope.
+    pub(super) fn add_disposable_resource(&mut self, is_await: bool) {
+        let stack_slot = self
+            .control_flow_stack
+            .iter()
+            .rev()
+            .find_map(|entry| match entry {
+                ControlFlowStackEntry::DisposableScope { stack_slot, .. } => Some(*stack_slot),
+                _ => None,
+            })
+            .expect("using declaration outside of a disposable scope");
+        self.add_instruction_with_immediate(
+            if is_await {
+                Instruction::AddAsyncDisposableResource
+            } else {
+                Instruction::AddDisposableResource
+            },
+            stack_slot as usize,
+        );
+    }
+
     /// Compile a break statement targeting optional label.
     ///
     /// This helper injects all necessary finalisers at the break statement
@@ -1040,7 +1134,11 @@ impl<'agent, 'script, 'gc, 'scope> CompileContext<'agent, 'script, 'gc, 'scope>
         let body: &[Statement] =
             unsafe { core::mem::transmute(data.ast.ecmascript_code().statements.as_slice()) };
 
+        let disposable_scope = self.enter_disposable_scope_for_statements(body);
         self.compile_statements(body);
+        if let Some(disposable_scope) = disposable_scope {
+            disposable_scope.exit(self);
+        }
 
         for stack_variable in stack_variables {
             stack_variable.exit(self);
@@ -1684,6 +1782,24 @@ impl Drop for ArrayDestructuring {
     }
 }
 
+pub(crate) struct DisposableScope(JumpIndex);
+#[cfg(debug_assertions)]
+impl Undroppable for DisposableScope {}
+
+impl DisposableScope {
+    #[inline(always)]
+    pub(crate) fn exit(self, ctx: &mut CompileContext) {
+        ctx.exit_disposable_scope(self);
+    }
+}
+
+#[cfg(debug_assertions)]
+impl Drop for DisposableScope {
+    fn drop(&mut self) {
+        Self::on_drop();
+    }
+}
+
 pub(crate) trait CompileEvaluation<'a, 's, 'gc, 'scope> {
     type Output;
 
@@ -46,6 +46,11 @@ pub(super) struct ExecutableContext<'agent, 'gc, 'scope> {
     /// Arrow function expressions being built
     arrow_function_expressions: Vec<ArrowFunctionExpression>,
     class_initializer_bytecodes: Vec<(Option<Executable<'gc>>, bool)>,
+    /// Source text offset that instructions are currently being emitted for
+    current_source_position: Option<u32>,
+    /// Source positions being built, as pairs of instruction pointer and
+    /// source text offset
+    source_positions: Vec<(u32, u32)>,
 }
 
 impl<'agent, 'gc, 'scope> ExecutableContext<'agent, 'gc, 'scope> {
@@ -62,6 +67,8 @@ impl<'agent, 'gc, 'scope> ExecutableContext<'agent, 'gc, 'scope> {
             function_expressions: Vec::new(),
             arrow_function_expressions: Vec::new(),
             class_initializer_bytecodes: Vec::new(),
+            current_source_position: None,
+            source_positions: Vec::new(),
         }
     }
 
@@ -157,9 +164,16 @@ impl<'agent, 'gc, 'scope> ExecutableContext<'agent, 'gc, 'scope> {
                 .into_iter()
                 .map(|(exe, b)| (exe.unbind(), b))
                 .collect(),
+            source_positions: self.source_positions.into_boxed_slice(),
         })
     }
 
+    /// Set the source text offset that following instructions originate from.
+    /// Returns the previous source text offset.
+    pub(super) fn set_source_position(&mut self, position: Option<u32>) -> Option<u32> {
+        core::mem::replace(&mut self.current_source_position, position)
+    }
+
     pub(super) fn add_instruction(&mut self, instruction: Instruction) {
         debug_assert_eq!(instruction.argument_count(), 0);
         debug_assert!(
@@ -489,6 +503,14 @@ impl<'agent, 'gc, 'scope> ExecutableContext<'agent, 'gc, 'scope> {
     }
 
     fn push_instruction(&mut self, instruction: Instruction) {
+        if let Some(position) = self.current_source_position {
+            let ip = self.instructions.len() as u32;
+            match self.source_positions.last_mut() {
+                Some((_, last_position)) if *last_position == position => {}
+                Some((last_ip, last_position)) if *last_ip == ip => *last_position = position,
+                _ => self.source_positions.push((ip, position)),
+            }
+        }
         self.instructions.push(instruction.as_u8());
         self.current_instruction_pointer_is_unreachable = instruction.is_terminal();
     }
@@ -12,6 +12,7 @@
 //! - Entering and exiting try-catch blocks.
 //! - Closing iterators on for-of loop exit.
 //! - Visiting finally blocks on try-finally block exit.
+//! - Disposing resources on exit from blocks containing `using` declarations.
 
 use oxc_ast::ast::LabelIdentifier;
 
@@ -92,6 +93,14 @@ pub(super) enum ControlFlowStackEntry<'a> {
         label_set: Option<Vec<&'a LabelIdentifier<'a>>>,
         incoming_control_flows: Option<Box<ControlFlowLoopEntry>>,
     },
+    /// A scope containing `using` or `await using` declarations was entered.
+    /// Requires disposing the resources of the DisposeCapability on exit.
+    DisposableScope {
+        /// Stack slot of the scope's DisposeCapability.
+        stack_slot: u32,
+        /// True if the scope contains `await using` declarations.
+        is_async: bool,
+    },
 }
 
 impl<'a> ControlFlowStackEntry<'a> {
@@ -236,7 +245,8 @@ impl<'a> ControlFlowStackEntry<'a> {
             | ControlFlowStackEntry::IfStatement
             | ControlFlowStackEntry::FinallyBlock
             | ControlFlowStackEntry::IteratorStackEntry { .. }
-            | ControlFlowStackEntry::ArrayDestructuring => false,
+            | ControlFlowStackEntry::ArrayDestructuring
+            | ControlFlowStackEntry::DisposableScope { .. } => false,
             // Finally-block needs to intercept every break and continue.
             ControlFlowStackEntry::TryFinallyBlock { .. } => true,
             ControlFlowStackEntry::Loop { label_set, .. }
@@ -273,7 +283,8 @@ impl<'a> ControlFlowStackEntry<'a> {
             | ControlFlowStackEntry::CatchBlock { .. }
             | ControlFlowStackEntry::Switch { .. }
             | ControlFlowStackEntry::IteratorStackEntry
-            | ControlFlowStackEntry::ArrayDestructuring => false,
+            | ControlFlowStackEntry::ArrayDestructuring
+            | ControlFlowStackEntry::DisposableScope { .. } => false,
             // Finally-block needs to intercept every break and continue.
             ControlFlowStackEntry::TryFinallyBlock { .. } => true,
             ControlFlowStackEntry::Loop { label_set, .. }
@@ -312,8 +323,8 @@ impl<'a> ControlFlowStackEntry<'a> {
             | ControlFlowStackEntry::Loop { .. }
             | ControlFlowStackEntry::Switch { .. } => false,
             // If-statements, finally-blocks results, user-controlled
-            // try-finally-blocks, and iterator closes must be called on
-            // return.
+            // try-finally-blocks, iterator closes, and resource disposal must
+            // be called on return.
             ControlFlowStackEntry::StackValue
             | ControlFlowStackEntry::StackResultValue
             | ControlFlowStackEntry::IfStatement
@@ -321,7 +332,8 @@ impl<'a> ControlFlowStackEntry<'a> {
             | ControlFlowStackEntry::ArrayDestructuring
             | ControlFlowStackEntry::Iterator { .. }
             | ControlFlowStackEntry::AsyncIterator { .. }
-            | ControlFlowStackEntry::TryFinallyBlock { .. } => true,
+            | ControlFlowStackEntry::TryFinallyBlock { .. }
+            | ControlFlowStackEntry::DisposableScope { .. } => true,
             // Catch blocks and the iterator stack don't require finalisation
             // on their own, but they do affect iterator closing and finally
             // block work.
@@ -412,6 +424,9 @@ impl<'a> ControlFlowStackEntry<'a> {
             ControlFlowStackEntry::AsyncIterator { .. } => {
                 compile_async_iterator_exit(executable);
             }
+            ControlFlowStackEntry::DisposableScope { is_async, .. } => {
+                compile_disposable_scope_exit(executable, *is_async);
+            }
         }
     }
 }
@@ -494,6 +509,24 @@ pub(super) fn compile_async_iterator_exit(executable: &mut ExecutableContext) {
     executable.add_instruction(Instruction::Store);
 }
 
+/// Helper method to compile disposable scope exit handling.
+///
+/// Disposable scopes have an exception handler for disposing resources on
+/// thrown error: that needs to be removed. Next, the resources of the
+/// DisposeCapability on the top of the stack are disposed, and the
+/// DisposeCapability is popped. For `await using` declarations, the disposal
+/// is awaited; the current result is kept on the stack during the Await and
+/// restored afterwards.
+pub(super) fn compile_disposable_scope_exit(executable: &mut ExecutableContext, is_async: bool) {
+    executable.add_instruction(Instruction::PopExceptionJumpTarget);
+    if is_async {
+        executable.add_instruction(Instruction::AsyncDisposeResources);
+        executable.add_instruction(Instruction::Store);
+    } else {
+        executable.add_instruction(Instruction::DisposeResources);
+    }
+}
+
 impl ControlFlowSwitchEntry {
     pub(super) fn compile(self, ctx: &mut ExecutableContext) {
         // Note: iterate breaks in reverse, in case the last one is our current
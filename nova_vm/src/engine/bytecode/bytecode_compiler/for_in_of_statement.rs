@@ -183,6 +183,7 @@ fn for_in_of_body_evaluation<'s, 'gc>(
     };
 
     let mut block_prep: Vec<BlockEnvPrep> = vec![];
+    let mut disposable_scope = None;
     // g. If lhsKind is either ASSIGNMENT or VAR-BINDING, then
     let status = match lhs_kind {
         LeftHandSideKind::Assignment | LeftHandSideKind::VarBinding => {
@@ -238,6 +239,13 @@ fn for_in_of_body_evaluation<'s, 'gc>(
                 unreachable!()
             };
             assert!(lhs.kind.is_lexical());
+            // Note: using declarations cannot be destructuring; the
+            // DisposeCapability of iterationEnv is created and nextValue added
+            // to it before the binding is initialised.
+            if lhs.kind.is_using() {
+                disposable_scope = Some(ctx.enter_disposable_scope(lhs.kind.is_await()));
+                ctx.add_disposable_resource(lhs.kind.is_await());
+            }
             // vi. If destructuring is true, then
             if destructuring {
                 // iii. Let iterationEnv be NewDeclarativeEnvironment(oldEnv).
@@ -252,7 +260,7 @@ fn for_in_of_body_evaluation<'s, 'gc>(
                         }
                         let identifier = ctx.create_string(binding_identifier.name.as_str());
                         ctx.add_instruction_with_identifier(
-                            if lhs.kind.is_const() {
+                            if lhs.kind.is_const() || lhs.kind.is_using() {
                                 Instruction::CreateImmutableBinding
                             } else {
                                 Instruction::CreateMutableBinding
@@ -294,7 +302,7 @@ fn for_in_of_body_evaluation<'s, 'gc>(
                         // 2. Let lhsName be the sole element of the BoundNames of lhs.
                         let lhs_name = ctx.create_string(binding_identifier.name.as_str());
                         ctx.add_instruction_with_identifier(
-                            if lhs.kind.is_const() {
+                            if lhs.kind.is_const() || lhs.kind.is_using() {
                                 Instruction::CreateImmutableBinding
                             } else {
                                 Instruction::CreateMutableBinding
@@ -334,6 +342,11 @@ fn for_in_of_body_evaluation<'s, 'gc>(
     for block_prep in block_prep.into_iter().rev() {
         block_prep.exit(ctx);
     }
+    // Note: Set result to Completion(DisposeResources(
+    // iterationEnv.[[DisposeCapability]], result)).
+    if let Some(disposable_scope) = disposable_scope {
+        disposable_scope.exit(ctx);
+    }
 
     let continue_target = ctx.get_jump_index_to_here();
 
@@ -429,7 +429,7 @@ pub(crate) fn instantiation<'s>(
             // b. For each element dn of the BoundNames of d, do
             match d {
                 LexicallyScopedDeclaration::Variable(decl) => {
-                    is_constant_declaration.set(decl.kind.is_const());
+                    is_constant_declaration.set(decl.kind.is_const() || decl.kind.is_using());
                     decl.id.bound_names(cb);
                     is_constant_declaration.set(false);
                 }
@@ -84,6 +84,7 @@ pub(super) fn get_template_object<'a>(
     let string_lookup_table = &mut agent.heap.string_lookup_table;
     let string_hasher = &mut agent.heap.string_hasher;
     let alloc_counter = &mut agent.heap.alloc_counter;
+    let string_bytes = &mut agent.heap.string_bytes;
 
     for (prop, quasi) in template_literal.quasis.iter().enumerate() {
         // a. Let prop be ! ToString(𝔽(index)).
@@ -94,6 +95,7 @@ pub(super) fn get_template_object<'a>(
                 string_lookup_table,
                 string_hasher,
                 alloc_counter,
+                string_bytes,
                 cooked_value.as_str(),
                 gc,
             )
@@ -105,6 +107,7 @@ pub(super) fn get_template_object<'a>(
             string_lookup_table,
             string_hasher,
             alloc_counter,
+            string_bytes,
             quasi.value.raw.as_str(),
             gc,
         );
@@ -124,6 +124,10 @@ pub(crate) struct ExecutableHeapData<'a> {
     pub(crate) function_expressions: Box<[FunctionExpression<'a>]>,
     pub(crate) arrow_function_expressions: Box<[ArrowFunctionExpression]>,
     pub(crate) class_initializer_bytecodes: Box<[(Option<Executable<'a>>, bool)]>,
+    /// Pairs of instruction pointer and source text offset, sorted by the
+    /// instruction pointer. Each source text offset applies until the next
+    /// pair's instruction pointer.
+    pub(crate) source_positions: Box<[(u32, u32)]>,
 }
 
 impl<'gc> Executable<'gc> {
@@ -160,7 +164,11 @@ impl<'gc> Executable<'gc> {
         let body = module.get_statements(agent, gc);
         let mut ctx = CompileContext::new(agent, source_code, gc);
 
+        let disposable_scope = ctx.enter_disposable_scope_for_statements(body);
         ctx.compile_statements(body);
+        if let Some(disposable_scope) = disposable_scope {
+            disposable_scope.exit(&mut ctx);
+        }
         ctx.do_implicit_return();
         ctx.finish()
     }
@@ -230,6 +238,19 @@ impl<'gc> Executable<'gc> {
         }
     }
 
+    /// Returns the source text offset that the instruction preceding the
+    /// given instruction pointer originates from.
+    ///
+    /// The VM advances the instruction pointer past an instruction before
+    /// executing it, so this gives the position of the currently executing
+    /// instruction.
+    pub(crate) fn get_source_position(self, agent: &Agent, ip: usize) -> Option<u32> {
+        let source_positions = &self.get(agent).source_positions;
+        let ip = ip.saturating_sub(1) as u32;
+        let index = source_positions.partition_point(|(start, _)| *start <= ip);
+        index.checked_sub(1).map(|index| source_positions[index].1)
+    }
+
     /// SAFETY: The returned reference is valid until the Executable is garbage
     /// collected.
     #[inline]
@@ -460,6 +481,7 @@ impl HeapMarkAndSweep for ExecutableHeapData<'static> {
             function_expressions,
             arrow_function_expressions: _,
             class_initializer_bytecodes,
+            source_positions: _,
         } = self;
         constants.mark_values(queues);
         caches.mark_values(queues);
@@ -477,6 +499,7 @@ impl HeapMarkAndSweep for ExecutableHeapData<'static> {
             function_expressions,
             arrow_function_expressions: _,
             class_initializer_bytecodes,
+            source_positions: _,
         } = self;
         constants.sweep_values(compactions);
         caches.sweep_values(compactions);
@@ -522,12 +522,56 @@ pub(crate) enum Instruction {
     /// options (optionally) in the result register. Pops the stack and places
     /// a dynamic import Promise into the result register.
     ImportCall,
+    /// Perform EvaluateImportCall with phase defer, otherwise identical to
+    /// ImportCall.
+    ImportDeferCall,
+    /// Perform EvaluateImportCall with phase source, otherwise identical to
+    /// ImportCall.
+    ImportSourceCall,
     /// Store `import.meta` object as the result value.
     ImportMeta,
     /// Throw a TypeError if the result register does not contain an Object.
     ///
     /// The error message is provided as an identifier.
     VerifyIsObject,
+    /// Create a new empty DisposeCapability and push it onto the stack.
+    PushDisposeCapability,
+    /// Perform AddDisposableResource with the value in the result register
+    /// and the sync-dispose hint. The DisposeCapability is found at the stack
+    /// index given as an immediate.
+    ///
+    /// The value is kept in the result register.
+    AddDisposableResource,
+    /// Perform AddDisposableResource with the value in the result register
+    /// and the async-dispose hint. The DisposeCapability is found at the stack
+    /// index given as an immediate.
+    ///
+    /// The value is kept in the result register.
+    AddAsyncDisposableResource,
+    /// Pop a DisposeCapability from the stack and perform DisposeResources
+    /// on it with a normal completion. The result register is not affected.
+    DisposeResources,
+    /// Pop a DisposeCapability from the stack and perform DisposeResources
+    /// on it with the result register as a thrown value, and then throw the
+    /// resulting error.
+    DisposeResourcesWithError,
+    /// Pop a DisposeCapability from the stack and perform the asynchronous
+    /// DisposeResources on it with a normal completion.
+    ///
+    /// The current result is stored onto the stack, and if the
+    /// DisposeCapability contains resources then an await is performed on the
+    /// disposal promise.
+    ///
+    /// This instruction should always be followed by a Store instruction to
+    /// restore the result.
+    AsyncDisposeResources,
+    /// Pop a DisposeCapability from the stack and perform the asynchronous
+    /// DisposeResources on it with the result register as a thrown value.
+    ///
+    /// If the DisposeCapability contains resources, then an await is
+    /// performed on the disposal promise which will always reject.
+    /// Otherwise, the thrown value is rethrown immediately.
+    AsyncDisposeResourcesWithError,
     Debug,
 }
 
@@ -537,7 +581,12 @@ impl Instruction {
     pub const fn is_terminal(self) -> bool {
         matches!(
             self,
-            Self::Jump | Self::Return | Self::Throw | Self::ThrowError
+            Self::Jump
+                | Self::Return
+                | Self::Throw
+                | Self::ThrowError
+                | Self::DisposeResourcesWithError
+                | Self::AsyncDisposeResourcesWithError
         )
     }
 
@@ -566,6 +615,8 @@ impl Instruction {
             | Self::ResolveBindingWithCache => 2,
             Self::PutValueToIndex
             | Self::GetValueFromIndex
+            | Self::AddAsyncDisposableResource
+            | Self::AddDisposableResource
             | Self::ArrayCreate
             | Self::BeginSimpleObjectBindingPattern
             | Self::BindingPatternBind
@@ -1327,8 +1378,18 @@ impl TryFrom<u8> for Instruction {
         const ITERATORPOP: u8 = Instruction::IteratorPop.as_u8();
         const GETNEWTARGET: u8 = Instruction::GetNewTarget.as_u8();
         const IMPORTCALL: u8 = Instruction::ImportCall.as_u8();
+        const IMPORTDEFERCALL: u8 = Instruction::ImportDeferCall.as_u8();
+        const IMPORTSOURCECALL: u8 = Instruction::ImportSourceCall.as_u8();
         const IMPORTMETA: u8 = Instruction::ImportMeta.as_u8();
         const VERIFYISOBJECT: u8 = Instruction::VerifyIsObject.as_u8();
+        const PUSHDISPOSECAPABILITY: u8 = Instruction::PushDisposeCapability.as_u8();
+        const ADDDISPOSABLERESOURCE: u8 = Instruction::AddDisposableResource.as_u8();
+        const ADDASYNCDISPOSABLERESOURCE: u8 = Instruction::AddAsyncDisposableResource.as_u8();
+        const DISPOSERESOURCES: u8 = Instruction::DisposeResources.as_u8();
+        const DISPOSERESOURCESWITHERROR: u8 = Instruction::DisposeResourcesWithError.as_u8();
+        const ASYNCDISPOSERESOURCES: u8 = Instruction::AsyncDisposeResources.as_u8();
+        const ASYNCDISPOSERESOURCESWITHERROR: u8 =
+            Instruction::AsyncDisposeResourcesWithError.as_u8();
         match value {
             APPLYADDITIONBINARYOPERATOR => Ok(Instruction::ApplyAdditionBinaryOperator),
             APPLYSUBTRACTIONBINARYOPERATOR => Ok(Instruction::ApplySubtractionBinaryOperator),
@@ -1490,8 +1551,17 @@ impl TryFrom<u8> for Instruction {
             ITERATORPOP => Ok(Instruction::IteratorPop),
             GETNEWTARGET => Ok(Instruction::GetNewTarget),
             IMPORTCALL => Ok(Instruction::ImportCall),
+            IMPORTDEFERCALL => Ok(Instruction::ImportDeferCall),
+            IMPORTSOURCECALL => Ok(Instruction::ImportSourceCall),
             IMPORTMETA => Ok(Instruction::ImportMeta),
             VERIFYISOBJECT => Ok(Instruction::VerifyIsObject),
+            PUSHDISPOSECAPABILITY => Ok(Instruction::PushDisposeCapability),
+            ADDDISPOSABLERESOURCE => Ok(Instruction::AddDisposableResource),
+            ADDASYNCDISPOSABLERESOURCE => Ok(Instruction::AddAsyncDisposableResource),
+            DISPOSERESOURCES => Ok(Instruction::DisposeResources),
+            DISPOSERESOURCESWITHERROR => Ok(Instruction::DisposeResourcesWithError),
+            ASYNCDISPOSERESOURCES => Ok(Instruction::AsyncDisposeResources),
+            ASYNCDISPOSERESOURCESWITHERROR => Ok(Instruction::AsyncDisposeResourcesWithError),
             _ => Err(()),
         }
     }
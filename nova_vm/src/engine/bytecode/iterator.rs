@@ -203,8 +203,8 @@ impl<'a> VmIteratorRecord<'a> {
             VmIteratorRecord::ArrayValues(iter) => {
                 Some(iter.array.len(agent).saturating_sub(iter.index) as usize)
             }
-            VmIteratorRecord::AsyncFromSyncGenericIterator(_) => todo!(),
-            VmIteratorRecord::GenericIterator(_) => None,
+            VmIteratorRecord::AsyncFromSyncGenericIterator(_)
+            | VmIteratorRecord::GenericIterator(_) => None,
             VmIteratorRecord::SliceIterator(slice) => Some(slice.len(agent)),
             VmIteratorRecord::EmptySliceIterator => Some(0),
         }
@@ -644,7 +644,7 @@ impl<'a> ArrayValuesIterator<'a> {
             // The iterator is exhausted.
             return Ok(None);
         };
-        if let Some(element_value) = array.as_slice(agent)[index as usize] {
+        if let Some(element_value) = array.get_data_value(agent, index) {
             // Fast path: If the element at this index has a Value, then it is
             // not an accessor nor a hole. Yield the result as-is.
             return Ok(Some(element_value.unbind()));
@@ -783,7 +783,7 @@ impl<'a> ArrayValuesIteratorRecord<'a> {
             // The iterator is exhausted.
             return TryResult::Continue(None);
         };
-        if let Some(element_value) = array.as_slice(agent)[index as usize] {
+        if let Some(element_value) = array.get_data_value(agent, index) {
             // Fast path: If the element at this index has a Value, then it is
             // not an accessor nor a hole. Yield the result as-is.
             return TryResult::Continue(Some(element_value.unbind()));
@@ -12,10 +12,11 @@ use wtf8::Wtf8Buf;
 
 use crate::{
     ecmascript::{
-        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, BigInt, Environment, ExceptionType, JsError,
-        JsResult, Number, Object, Primitive, Promise, Reference, ScopedArgumentsList, String,
-        Value, call_function, get_method, is_callable, ordinary_has_instance, to_boolean,
-        to_numeric, to_numeric_primitive, to_primitive, to_property_key, to_string_primitive,
+        Agent, ArgumentsList, BUILTIN_STRING_MEMORY, BigInt, DisposeHint, Environment,
+        ExceptionType, ImportPhase, JsError, JsResult, Number, Object, Primitive, Promise,
+        Reference, SafePointRequests, ScopedArgumentsList, String, Value, call_function,
+        get_method, is_callable, ordinary_has_instance, to_boolean, to_numeric,
+        to_numeric_primitive, to_primitive, to_property_key, to_string_primitive,
         try_get_object_method, try_result_into_option_js,
     },
     engine::{
@@ -92,6 +93,10 @@ pub(crate) struct Vm {
     exception_handler_stack: Vec<ExceptionHandler<'static>>,
     result: Option<Value<'static>>,
     reference: Option<Reference<'static>>,
+    /// Executable being executed; used for source positions in stack traces.
+    executable: Option<Executable<'static>>,
+    /// Length of the execution context stack when execution started.
+    execution_context_depth: usize,
 }
 
 #[derive(Debug)]
@@ -184,6 +189,8 @@ impl Vm {
             exception_handler_stack: Vec::new(),
             result: None,
             reference: None,
+            executable: None,
+            execution_context_depth: 0,
         }
     }
 
@@ -206,9 +213,60 @@ impl Vm {
             exception_handler_stack: suspended.exception_jump_target_stack.into_vec(),
             result: None,
             reference: None,
+            executable: None,
+            execution_context_depth: 0,
         }
     }
 
+    /// Returns the source text offset of the instruction currently executed
+    /// by the innermost VM on the VM stack running in the execution context at
+    /// the given depth.
+    pub(crate) fn get_source_position(
+        agent: &Agent,
+        execution_context_depth: usize,
+    ) -> Option<u32> {
+        let vm = agent.vm_stack.iter().rev().find_map(|vm| {
+            // SAFETY: VMs on the VM stack are alive and not being accessed
+            // while they are on the stack.
+            let vm = unsafe { vm.as_ref() };
+            (vm.execution_context_depth == execution_context_depth).then_some(vm)
+        })?;
+        vm.executable?.get_source_position(agent, vm.ip)
+    }
+
+    /// Records a CPU profile sample of the JavaScript call stack with this VM
+    /// as the innermost running VM.
+    #[cold]
+    #[inline(never)]
+    fn record_cpu_profile_sample(&self, agent: &mut Agent, gc: NoGcScope) {
+        let source_position = self
+            .executable
+            .and_then(|executable| executable.get_source_position(agent, self.ip));
+        agent.record_cpu_profile_sample(self.execution_context_depth, source_position, gc);
+    }
+
+    /// Consumes fuel, records a CPU profile sample if one was requested and
+    /// throws an uncatchable error if the Agent's JavaScript execution has
+    /// been interrupted.
+    #[inline(always)]
+    fn check_safe_point<'gc>(
+        &self,
+        agent: &mut Agent,
+        gc: NoGcScope<'gc, '_>,
+    ) -> JsResult<'gc, ()> {
+        let requests = agent.take_safe_point_requests();
+        if requests == 0 {
+            return Ok(());
+        }
+        if requests & SafePointRequests::CPU_PROFILE_SAMPLE != 0 {
+            self.record_cpu_profile_sample(agent, gc);
+        }
+        if requests & SafePointRequests::INTERRUPTS != 0 {
+            return Err(agent.throw_interrupt(requests, gc));
+        }
+        Ok(())
+    }
+
     /// Executes an executable using the virtual machine.
     pub(crate) fn execute<'gc>(
         agent: &mut Agent,
@@ -299,27 +357,47 @@ impl Vm {
         mut gc: GcScope<'gc, '_>,
     ) -> ExecutionResult<'gc> {
         let stack_depth = agent.stack_refs.borrow().len();
+        self.executable = Some(executable.get(agent));
+        self.execution_context_depth = agent.execution_context_stack.len();
         let instructions = executable.get_instructions(agent);
-        while let Some(instr) = Instr::consume_instruction(instructions, &mut self.ip) {
-            if agent.check_gc() {
-                self.trigger_gc(agent, gc.reborrow());
-            }
+        // Note: starting or resuming execution and backward jumps are safe
+        // points; function calls are safe points checked by the callee.
+        let mut previous_ip = usize::MAX;
+        loop {
+            let ip = self.ip;
+            let Some(instr) = Instr::consume_instruction(instructions, &mut self.ip) else {
+                break;
+            };
             if agent.options.print_internals {
                 Self::print_executing(instr.kind);
             }
-            let result = Self::execute_instruction(
-                agent,
-                &mut self,
-                executable.clone(),
-                instr,
-                gc.reborrow(),
-            );
+            let is_safe_point = ip <= previous_ip;
+            previous_ip = ip;
+            let result = if is_safe_point && let Err(err) = self.check_safe_point(agent, gc.nogc())
+            {
+                Err(err)
+            } else if agent.check_gc()
+                && let Err(err) = self.trigger_gc(agent, gc.reborrow())
+            {
+                Err(err)
+            } else {
+                Self::execute_instruction(
+                    agent,
+                    &mut self,
+                    executable.clone(),
+                    instr,
+                    gc.reborrow(),
+                )
+            };
             match result {
                 Ok(ContinuationKind::Normal) => {}
                 // SAFETY: result is not Ok(ContinuationKind::Normal).
                 _ => unsafe {
-                    if let Some(r) = self.handle_execute_instruction_abnormal_result(agent, result)
-                    {
+                    if let Some(r) = self.handle_execute_instruction_abnormal_result(
+                        agent,
+                        result.map_err(JsError::unbind),
+                        gc.nogc(),
+                    ) {
                         return r.unbind().bind(gc.into_nogc());
                     }
                 },
@@ -338,9 +416,10 @@ impl Vm {
     unsafe fn handle_execute_instruction_abnormal_result<'a>(
         &mut self,
         agent: &mut Agent,
-        result: JsResult<'a, ContinuationKind>,
+        result: JsResult<'static, ContinuationKind>,
+        gc: NoGcScope<'a, '_>,
     ) -> Option<ExecutionResult<'a>> {
-        match result {
+        match result.map_err(|err| err.bind(gc)) {
             // SAFETY: method only called if result is not normal.
             Ok(ContinuationKind::Normal) => unsafe { unreachable_unchecked() },
             Ok(ContinuationKind::Return) => {
@@ -373,6 +452,19 @@ impl Vm {
                 })
             }
             Err(err) => {
+                if let Value::Error(error) = err.value()
+                    && let Some(executable) = self.executable
+                {
+                    // Errors thrown directly by an instruction did not see
+                    // this VM on the VM stack.
+                    let source_position = executable.get_source_position(agent, self.ip);
+                    error.complete_stack_trace(
+                        agent,
+                        self.execution_context_depth,
+                        source_position,
+                        gc,
+                    );
+                }
                 if !self.handle_error(agent, err) {
                     if agent.options.print_internals {
                         Self::print_exiting_with_error();
@@ -387,8 +479,13 @@ impl Vm {
 
     #[inline(never)]
     #[cold]
-    fn trigger_gc(&mut self, agent: &mut Agent, gc: GcScope) {
-        with_vm_gc(agent, self, |agent, gc| agent.gc(gc), gc);
+    fn trigger_gc<'gc>(
+        &mut self,
+        agent: &mut Agent,
+        mut gc: GcScope<'gc, '_>,
+    ) -> JsResult<'gc, ()> {
+        with_vm_gc(agent, self, |agent, gc| agent.young_gc(gc), gc.reborrow());
+        agent.check_heap_limit(gc.into_nogc())
     }
 
     #[inline(never)]
@@ -425,6 +522,10 @@ impl Vm {
     #[cold]
     #[must_use]
     fn handle_error(&mut self, agent: &mut Agent, err: JsError) -> bool {
+        if agent.is_interrupted() {
+            // Interrupts cannot be caught.
+            return false;
+        }
         if let Some(handler) = self.exception_handler_stack.pop() {
             match handler {
                 ExceptionHandler::CatchBlock {
@@ -784,11 +885,37 @@ impl Vm {
                 let _ = vm.pop_iterator(gc.into_nogc());
             }
             Instruction::GetNewTarget => execute_get_new_target(agent, vm, gc.into_nogc()),
-            Instruction::ImportCall => execute_import_call(agent, vm, gc),
+            Instruction::ImportCall => execute_import_call(agent, vm, ImportPhase::Evaluation, gc),
+            Instruction::ImportDeferCall => execute_import_call(agent, vm, ImportPhase::Defer, gc),
+            Instruction::ImportSourceCall => {
+                execute_import_call(agent, vm, ImportPhase::Source, gc)
+            }
             Instruction::ImportMeta => execute_import_meta(agent, vm, gc.into_nogc()),
             Instruction::VerifyIsObject => {
                 execute_verify_is_object(agent, vm, executable, instr, gc.into_nogc())?
             }
+            Instruction::PushDisposeCapability => {
+                execute_push_dispose_capability(agent, vm, gc.into_nogc())
+            }
+            Instruction::AddDisposableResource => {
+                execute_add_disposable_resource(agent, vm, instr, DisposeHint::SyncDispose, gc)?
+            }
+            Instruction::AddAsyncDisposableResource => {
+                execute_add_disposable_resource(agent, vm, instr, DisposeHint::AsyncDispose, gc)?
+            }
+            Instruction::DisposeResources => execute_dispose_resources(agent, vm, gc)?,
+            Instruction::DisposeResourcesWithError => {
+                execute_dispose_resources_with_error(agent, vm, gc)?
+            }
+            Instruction::AsyncDisposeResources => {
+                if execute_async_dispose_resources(agent, vm, gc) {
+                    return Ok(ContinuationKind::Await);
+                }
+            }
+            Instruction::AsyncDisposeResourcesWithError => {
+                execute_async_dispose_resources_with_error(agent, vm, gc)?;
+                return Ok(ContinuationKind::Await);
+            }
             Instruction::Debug => execute_debug(agent, vm),
         };
         Ok(ContinuationKind::Normal)
@@ -1246,6 +1373,7 @@ pub(crate) fn typeof_operator(agent: &Agent, val: Value, gc: NoGcScope) -> Strin
         Value::Object(_)  |
         Value::Array(_)  |
         Value::Error(_)  |
+        Value::DisposableStack(_) |
         // 14. Return "object".
         Value::PrimitiveObject(_) |
         Value::Arguments(_) |
@@ -1256,9 +1384,12 @@ pub(crate) fn typeof_operator(agent: &Agent, val: Value, gc: NoGcScope) -> Strin
         Value::ArrayIterator(_) |
         Value::MapIterator(_) |
         Value::StringIterator(_) |
+        Value::IteratorHelper(_) |
         Value::Generator(_) |
         Value::Module(_) |
         Value::EmbedderObject(_) => BUILTIN_STRING_MEMORY.object,
+        #[cfg(feature = "json")]
+        Value::RawJSON(_) => BUILTIN_STRING_MEMORY.object,
         #[cfg(feature = "regexp")]
         Value::RegExp(_) | Value::RegExpStringIterator(_) => BUILTIN_STRING_MEMORY.object,
         #[cfg(feature = "weak-refs")]
@@ -1308,12 +1439,22 @@ pub(crate) fn typeof_operator(agent: &Agent, val: Value, gc: NoGcScope) -> Strin
         Value::Duration(_)  => BUILTIN_STRING_MEMORY.object,
         #[cfg(feature = "temporal")]
         Value::PlainTime(_)  => BUILTIN_STRING_MEMORY.object,
+        #[cfg(feature = "temporal")]
+        Value::PlainDate(_)  => BUILTIN_STRING_MEMORY.object,
+        #[cfg(feature = "temporal")]
+        Value::PlainDateTime(_)  => BUILTIN_STRING_MEMORY.object,
+        #[cfg(feature = "temporal")]
+        Value::ZonedDateTime(_)  => BUILTIN_STRING_MEMORY.object,
+        #[cfg(feature = "temporal")]
+        Value::PlainYearMonth(_)  => BUILTIN_STRING_MEMORY.object,
+        #[cfg(feature = "temporal")]
+        Value::PlainMonthDay(_)  => BUILTIN_STRING_MEMORY.object,
         // 13. If val has a [[Call]] internal slot, return "function".
         Value::BoundFunction(_) | Value::BuiltinFunction(_) | Value::ECMAScriptFunction(_) |
         Value::BuiltinConstructorFunction(_) |
         Value::BuiltinPromiseResolvingFunction(_) |
         Value::BuiltinPromiseFinallyFunction(_) |
-        Value::BuiltinProxyRevokerFunction => BUILTIN_STRING_MEMORY.function,
+        Value::BuiltinProxyRevokerFunction(_) => BUILTIN_STRING_MEMORY.function,
         Value::Proxy(proxy) => {
             if proxy.is_callable(agent, gc) {
                 BUILTIN_STRING_MEMORY.function
@@ -1473,8 +1614,11 @@ impl HeapMarkAndSweep for Vm {
             exception_handler_stack: exception_jump_target_stack,
             result,
             reference,
+            executable,
+            execution_context_depth: _,
         } = self;
         stack.as_slice().mark_values(queues);
+        executable.mark_values(queues);
         reference_stack.as_slice().mark_values(queues);
         iterator_stack.as_slice().mark_values(queues);
         exception_jump_target_stack.as_slice().mark_values(queues);
@@ -1491,8 +1635,11 @@ impl HeapMarkAndSweep for Vm {
             exception_handler_stack: exception_jump_target_stack,
             result,
             reference,
+            executable,
+            execution_context_depth: _,
         } = self;
         stack.as_mut_slice().sweep_values(compactions);
+        executable.sweep_values(compactions);
         reference_stack.as_mut_slice().sweep_values(compactions);
         iterator_stack.as_mut_slice().sweep_values(compactions);
         exception_jump_target_stack
@@ -8,14 +8,16 @@ use oxc_span::Span;
 use crate::{
     ecmascript::{
         Agent, ArgumentsList, Array, BUILTIN_STRING_MEMORY, BigInt, BuiltinConstructorArgs,
-        ConstructorStatus, Environment, ExceptionType, Function, FunctionAstRef, InternalMethods,
-        InternalSlots, JsResult, Number, Numeric, Object, OrdinaryFunctionCreateParams,
-        OrdinaryObject, Primitive, PrivateMethod, Promise, PropertyDescriptor, PropertyKey,
-        PropertyKeySet, PropertyLookupCache, ProtoIntrinsics, Reference, ScriptOrModule,
-        SetFunctionNamePrefix, SetResult, String, TryError, TryGetValueContinue, TryHasResult,
-        TryResult, Value, array_create, call, call_function, call_proxy_set, construct,
-        copy_data_properties, copy_data_properties_into_object, create_builtin_constructor,
-        create_data_property_or_throw, create_unmapped_arguments_object, define_property_or_throw,
+        ConstructorStatus, DisposableStack, DisposableStackHeapData, DisposeHint, Environment,
+        ExceptionType, Function, FunctionAstRef, ImportPhase, InternalMethods, InternalSlots,
+        JsError, JsResult, Number, Numeric, Object, OrdinaryFunctionCreateParams, OrdinaryObject,
+        Primitive, PrivateMethod, Promise, PropertyDescriptor, PropertyKey, PropertyKeySet,
+        PropertyLookupCache, ProtoIntrinsics, Reference, ScriptOrModule, SetFunctionNamePrefix,
+        SetResult, String, TryError, TryGetValueContinue, TryHasResult, TryResult, Value,
+        add_disposable_resource, array_create, async_dispose_resources, call, call_function,
+        call_proxy_set, construct, copy_data_properties, copy_data_properties_into_object,
+        create_builtin_constructor, create_data_property_or_throw,
+        create_unmapped_arguments_object, define_property_or_throw, dispose_resources,
         evaluate_import_call, get_this_environment, get_this_value, get_value, has_property,
         is_constructor, is_less_than, is_loosely_equal, is_private_reference,
         is_property_reference, is_strictly_equal, is_super_reference, is_unresolvable_reference,
@@ -42,7 +44,7 @@ use crate::{
         },
         throw_iterator_returned_non_object,
     },
-    heap::{ArenaAccessMut, ObjectEntry},
+    heap::{ArenaAccessMut, CreateHeapData, ObjectEntry},
 };
 
 use super::{
@@ -2955,6 +2957,141 @@ pub(super) fn execute_async_iterator_close_with_error(
     false
 }
 
+pub(super) fn execute_push_dispose_capability(agent: &mut Agent, vm: &mut Vm, gc: NoGcScope) {
+    // NewDisposeCapability()
+    let dispose_capability = agent
+        .heap
+        .create(DisposableStackHeapData::new(false))
+        .bind(gc);
+    vm.stack.push(dispose_capability.unbind().into());
+}
+
+/// Get the DisposeCapability at the given stack index.
+fn get_dispose_capability<'gc>(
+    vm: &Vm,
+    index: usize,
+    gc: NoGcScope<'gc, '_>,
+) -> DisposableStack<'gc> {
+    let Value::DisposableStack(dispose_capability) = vm.stack[index] else {
+        unreachable!()
+    };
+    dispose_capability.bind(gc)
+}
+
+/// Pop the DisposeCapability from the top of the stack.
+fn pop_dispose_capability<'gc>(vm: &mut Vm, gc: NoGcScope<'gc, '_>) -> DisposableStack<'gc> {
+    let Some(Value::DisposableStack(dispose_capability)) = vm.stack.pop() else {
+        unreachable!()
+    };
+    dispose_capability.bind(gc)
+}
+
+pub(super) fn execute_add_disposable_resource<'gc>(
+    agent: &mut Agent,
+    vm: &mut Vm,
+    instr: Instr,
+    hint: DisposeHint,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
+    let dispose_capability = get_dispose_capability(vm, instr.get_first_index(), gc.nogc());
+    // Note: the value is kept in the result register.
+    let value = vm.result.unwrap().bind(gc.nogc());
+    let dispose_capability = dispose_capability.unbind();
+    let value = value.unbind();
+    with_vm_gc(
+        agent,
+        vm,
+        |agent, gc| add_disposable_resource(agent, dispose_capability, value, hint, gc),
+        gc,
+    )
+}
+
+pub(super) fn execute_dispose_resources<'gc>(
+    agent: &mut Agent,
+    vm: &mut Vm,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
+    let dispose_capability = pop_dispose_capability(vm, gc.nogc());
+    if dispose_capability.is_empty(agent) {
+        return Ok(());
+    }
+    let dispose_capability = dispose_capability.unbind();
+    with_vm_gc(
+        agent,
+        vm,
+        |agent, gc| dispose_resources(agent, dispose_capability, None, gc),
+        gc,
+    )
+}
+
+pub(super) fn execute_dispose_resources_with_error<'gc>(
+    agent: &mut Agent,
+    vm: &mut Vm,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
+    let error = JsError::new(vm.result.take().unwrap()).bind(gc.nogc());
+    let dispose_capability = pop_dispose_capability(vm, gc.nogc());
+    if dispose_capability.is_empty(agent) {
+        return Err(error.unbind().bind(gc.into_nogc()));
+    }
+    let dispose_capability = dispose_capability.unbind();
+    let error = error.unbind();
+    with_vm_gc(
+        agent,
+        vm,
+        |agent, gc| dispose_resources(agent, dispose_capability, Some(error), gc),
+        gc,
+    )?;
+    // DisposeResources always returns the thrown completion it was given, or
+    // a new one if disposal threw errors.
+    unreachable!()
+}
+
+pub(super) fn execute_async_dispose_resources(agent: &mut Agent, vm: &mut Vm, gc: GcScope) -> bool {
+    let dispose_capability = pop_dispose_capability(vm, gc.nogc());
+    // Store our current result onto the stack to be restored by the following
+    // Store instruction.
+    let result = vm.result.take();
+    vm.stack.push(result.unwrap_or(Value::Undefined));
+    if dispose_capability.is_empty(agent) {
+        // No resources to dispose: nothing to await.
+        return false;
+    }
+    let dispose_capability = dispose_capability.unbind();
+    let promise = with_vm_gc(
+        agent,
+        vm,
+        |agent, gc| async_dispose_resources(agent, dispose_capability, None, gc),
+        gc,
+    );
+    // Await the disposal.
+    vm.result = Some(promise.unbind().into());
+    true
+}
+
+pub(super) fn execute_async_dispose_resources_with_error<'gc>(
+    agent: &mut Agent,
+    vm: &mut Vm,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, ()> {
+    let error = JsError::new(vm.result.take().unwrap()).bind(gc.nogc());
+    let dispose_capability = pop_dispose_capability(vm, gc.nogc());
+    if dispose_capability.is_empty(agent) {
+        return Err(error.unbind().bind(gc.into_nogc()));
+    }
+    let dispose_capability = dispose_capability.unbind();
+    let error = error.unbind();
+    let promise = with_vm_gc(
+        agent,
+        vm,
+        |agent, gc| async_dispose_resources(agent, dispose_capability, Some(error), gc),
+        gc,
+    );
+    // Note: the promise always rejects, so the Await will rethrow.
+    vm.result = Some(promise.unbind().into());
+    Ok(())
+}
+
 pub(super) fn execute_create_unmapped_arguments_object<'gc>(
     agent: &mut Agent,
     vm: &mut Vm,
@@ -2988,7 +3125,7 @@ pub(super) fn execute_get_new_target(agent: &mut Agent, vm: &mut Vm, gc: NoGcSco
     );
 }
 
-pub(super) fn execute_import_call(agent: &mut Agent, vm: &mut Vm, gc: GcScope) {
+pub(super) fn execute_import_call(agent: &mut Agent, vm: &mut Vm, phase: ImportPhase, gc: GcScope) {
     let specifier = vm.stack.pop().unwrap().bind(gc.nogc());
     let options = vm.result.take().bind(gc.nogc());
     vm.result = {
@@ -2998,7 +3135,7 @@ pub(super) fn execute_import_call(agent: &mut Agent, vm: &mut Vm, gc: GcScope) {
             with_vm_gc(
                 agent,
                 vm,
-                |agent, gc| evaluate_import_call(agent, specifier, options, gc),
+                |agent, gc| evaluate_import_call(agent, specifier, options, phase, gc),
                 gc,
             )
             .unbind()
@@ -14,10 +14,6 @@ pub(crate) use private::{HeapRootCollection, Rootable, RootableCollection};
 use crate::ecmascript::DATE_DISCRIMINANT;
 #[cfg(feature = "date")]
 use crate::ecmascript::Date;
-#[cfg(feature = "temporal")]
-use crate::ecmascript::PLAIN_TIME_DISCRIMINANT;
-#[cfg(feature = "temporal")]
-use crate::ecmascript::TemporalPlainTime;
 use crate::ecmascript::UnmappedArguments;
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::{
@@ -35,6 +31,15 @@ use crate::ecmascript::{DURATION_DISCRIMINANT, TemporalDuration};
 use crate::ecmascript::{FLOAT_16_ARRAY_DISCRIMINANT, Float16Array};
 #[cfg(feature = "temporal")]
 use crate::ecmascript::{INSTANT_DISCRIMINANT, TemporalInstant};
+#[cfg(feature = "temporal")]
+use crate::ecmascript::{
+    PLAIN_DATE_DISCRIMINANT, PLAIN_DATE_TIME_DISCRIMINANT, PLAIN_MONTH_DAY_DISCRIMINANT,
+    PLAIN_TIME_DISCRIMINANT, PLAIN_YEAR_MONTH_DISCRIMINANT, TemporalPlainDate,
+    TemporalPlainDateTime, TemporalPlainMonthDay, TemporalPlainTime, TemporalPlainYearMonth,
+    TemporalZonedDateTime, ZONED_DATE_TIME_DISCRIMINANT,
+};
+#[cfg(feature = "json")]
+use crate::ecmascript::{RAW_JSON_DISCRIMINANT, RawJSON};
 #[cfg(feature = "regexp")]
 use crate::ecmascript::{
     REGEXP_DISCRIMINANT, REGEXP_STRING_ITERATOR_DISCRIMINANT, RegExp, RegExpStringIterator,
@@ -65,22 +70,25 @@ use crate::heap::WorkQueues;
 use crate::{
     ecmascript::{
         ARGUMENTS_DISCRIMINANT, ARRAY_DISCRIMINANT, ARRAY_ITERATOR_DISCRIMINANT,
-        ASYNC_GENERATOR_DISCRIMINANT, Array, ArrayIterator, AsyncGenerator, AwaitReaction,
-        BIGINT_DISCRIMINANT, BOUND_FUNCTION_DISCRIMINANT,
+        ASYNC_GENERATOR_DISCRIMINANT, Array, ArrayFromAsync, ArrayIterator, AsyncGenerator,
+        AwaitReaction, BIGINT_DISCRIMINANT, BOUND_FUNCTION_DISCRIMINANT,
         BUILTIN_CONSTRUCTOR_FUNCTION_DISCRIMINANT, BUILTIN_FUNCTION_DISCRIMINANT,
         BUILTIN_PROMISE_FINALLY_FUNCTION_DISCRIMINANT,
-        BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT, BUILTIN_PROXY_REVOKER_FUNCTION,
-        BoundFunction, BuiltinConstructorFunction, BuiltinFunction, BuiltinPromiseFinallyFunction,
-        BuiltinPromiseResolvingFunction, DeclarativeEnvironment, ECMASCRIPT_FUNCTION_DISCRIMINANT,
-        ECMAScriptFunction, EMBEDDER_OBJECT_DISCRIMINANT, ERROR_DISCRIMINANT, EmbedderObject,
-        Error, FINALIZATION_REGISTRY_DISCRIMINANT, FinalizationRegistry, FunctionEnvironment,
+        BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT,
+        BUILTIN_PROXY_REVOKER_FUNCTION_DISCRIMINANT, BoundFunction, BuiltinConstructorFunction,
+        BuiltinFunction, BuiltinPromiseFinallyFunction, BuiltinPromiseResolvingFunction,
+        BuiltinProxyRevokerFunction, DISPOSABLE_STACK_DISCRIMINANT, DeclarativeEnvironment,
+        DisposableStack, ECMASCRIPT_FUNCTION_DISCRIMINANT, ECMAScriptFunction,
+        EMBEDDER_OBJECT_DISCRIMINANT, ERROR_DISCRIMINANT, EmbedderObject, Error,
+        FINALIZATION_REGISTRY_DISCRIMINANT, FinalizationRegistry, FunctionEnvironment,
         GENERATOR_DISCRIMINANT, Generator, GlobalEnvironment, HeapBigInt, HeapNumber, HeapString,
-        MAP_DISCRIMINANT, MAP_ITERATOR_DISCRIMINANT, MODULE_DISCRIMINANT, Map, MapIterator, Module,
-        ModuleEnvironment, NUMBER_DISCRIMINANT, OBJECT_DISCRIMINANT, ObjectEnvironment,
-        OrdinaryObject, PROMISE_DISCRIMINANT, PROXY_DISCRIMINANT, PrimitiveObject,
-        PrivateEnvironment, Promise, PromiseGroup, PromiseReaction, PropertyLookupCache, Proxy,
-        Realm, STRING_DISCRIMINANT, STRING_ITERATOR_DISCRIMINANT, SYMBOL_DISCRIMINANT, Script,
-        SourceCode, SourceTextModule, StringIterator, Symbol,
+        ITERATOR_HELPER_DISCRIMINANT, IteratorHelper, MAP_DISCRIMINANT, MAP_ITERATOR_DISCRIMINANT,
+        MODULE_DISCRIMINANT, Map, MapIterator, Module, ModuleEnvironment, NUMBER_DISCRIMINANT,
+        OBJECT_DISCRIMINANT, ObjectEnvironment, OrdinaryObject, PROMISE_DISCRIMINANT,
+        PROXY_DISCRIMINANT, PrimitiveObject, PrivateEnvironment, Promise, PromiseGroup,
+        PromiseReaction, PropertyLookupCache, Proxy, Realm, STRING_DISCRIMINANT,
+        STRING_ITERATOR_DISCRIMINANT, SYMBOL_DISCRIMINANT, Script, SourceCode, SourceTextModule,
+        StringIterator, Symbol,
     },
     heap::HeapMarkAndSweep,
 };
@@ -313,7 +321,8 @@ pub(crate) enum HeapRootData {
         BUILTIN_PROMISE_RESOLVING_FUNCTION_DISCRIMINANT,
     BuiltinPromiseFinallyFunction(BuiltinPromiseFinallyFunction<'static>) =
         BUILTIN_PROMISE_FINALLY_FUNCTION_DISCRIMINANT,
-    BuiltinProxyRevokerFunction = BUILTIN_PROXY_REVOKER_FUNCTION,
+    BuiltinProxyRevokerFunction(BuiltinProxyRevokerFunction<'static>) =
+        BUILTIN_PROXY_REVOKER_FUNCTION_DISCRIMINANT,
     PrimitiveObject(PrimitiveObject<'static>),
     Arguments(UnmappedArguments<'static>) = ARGUMENTS_DISCRIMINANT,
     Array(Array<'static>) = ARRAY_DISCRIMINANT,
@@ -325,6 +334,17 @@ pub(crate) enum HeapRootData {
     Duration(TemporalDuration<'static>) = DURATION_DISCRIMINANT,
     #[cfg(feature = "temporal")]
     PlainTime(TemporalPlainTime<'static>) = PLAIN_TIME_DISCRIMINANT,
+    #[cfg(feature = "temporal")]
+    PlainDate(TemporalPlainDate<'static>) = PLAIN_DATE_DISCRIMINANT,
+    #[cfg(feature = "temporal")]
+    PlainDateTime(TemporalPlainDateTime<'static>) = PLAIN_DATE_TIME_DISCRIMINANT,
+    #[cfg(feature = "temporal")]
+    ZonedDateTime(TemporalZonedDateTime<'static>) = ZONED_DATE_TIME_DISCRIMINANT,
+    #[cfg(feature = "temporal")]
+    PlainYearMonth(TemporalPlainYearMonth<'static>) = PLAIN_YEAR_MONTH_DISCRIMINANT,
+    #[cfg(feature = "temporal")]
+    PlainMonthDay(TemporalPlainMonthDay<'static>) = PLAIN_MONTH_DAY_DISCRIMINANT,
+    DisposableStack(DisposableStack<'static>) = DISPOSABLE_STACK_DISCRIMINANT,
     Error(Error<'static>) = ERROR_DISCRIMINANT,
     FinalizationRegistry(FinalizationRegistry<'static>) = FINALIZATION_REGISTRY_DISCRIMINANT,
     Map(Map<'static>) = MAP_DISCRIMINANT,
@@ -407,6 +427,9 @@ pub(crate) enum HeapRootData {
     MapIterator(MapIterator<'static>) = MAP_ITERATOR_DISCRIMINANT,
     Generator(Generator<'static>) = GENERATOR_DISCRIMINANT,
     StringIterator(StringIterator<'static>) = STRING_ITERATOR_DISCRIMINANT,
+    IteratorHelper(IteratorHelper<'static>) = ITERATOR_HELPER_DISCRIMINANT,
+    #[cfg(feature = "json")]
+    RawJSON(RawJSON<'static>) = RAW_JSON_DISCRIMINANT,
     #[cfg(feature = "regexp")]
     RegExpStringIterator(RegExpStringIterator<'static>) = REGEXP_STRING_ITERATOR_DISCRIMINANT,
     Module(Module<'static>) = MODULE_DISCRIMINANT,
@@ -422,6 +445,7 @@ pub(crate) enum HeapRootData {
     AwaitReaction(AwaitReaction<'static>),
     PromiseReaction(PromiseReaction<'static>),
     PromiseGroup(PromiseGroup<'static>),
+    ArrayFromAsync(ArrayFromAsync<'static>),
     Realm(Realm<'static>),
     Script(Script<'static>),
     SourceTextModule(SourceTextModule<'static>),
@@ -493,7 +517,9 @@ impl HeapMarkAndSweep for HeapRootData {
             Self::BuiltinPromiseFinallyFunction(builtin_promise_finally_function) => {
                 builtin_promise_finally_function.mark_values(queues)
             }
-            Self::BuiltinProxyRevokerFunction => todo!(),
+            Self::BuiltinProxyRevokerFunction(builtin_proxy_revoker_function) => {
+                builtin_proxy_revoker_function.mark_values(queues)
+            }
             Self::PrimitiveObject(primitive_object) => primitive_object.mark_values(queues),
             Self::Arguments(ordinary_object) => ordinary_object.mark_values(queues),
             Self::Array(array) => array.mark_values(queues),
@@ -505,6 +531,17 @@ impl HeapMarkAndSweep for HeapRootData {
             Self::Duration(duration) => duration.mark_values(queues),
             #[cfg(feature = "temporal")]
             Self::PlainTime(plaintime) => plaintime.mark_values(queues),
+            #[cfg(feature = "temporal")]
+            Self::PlainDate(plaindate) => plaindate.mark_values(queues),
+            #[cfg(feature = "temporal")]
+            Self::PlainDateTime(plaindatetime) => plaindatetime.mark_values(queues),
+            #[cfg(feature = "temporal")]
+            Self::ZonedDateTime(zoneddatetime) => zoneddatetime.mark_values(queues),
+            #[cfg(feature = "temporal")]
+            Self::PlainYearMonth(plainyearmonth) => plainyearmonth.mark_values(queues),
+            #[cfg(feature = "temporal")]
+            Self::PlainMonthDay(plainmonthday) => plainmonthday.mark_values(queues),
+            Self::DisposableStack(disposable_stack) => disposable_stack.mark_values(queues),
             Self::Error(error) => error.mark_values(queues),
             Self::FinalizationRegistry(finalization_registry) => {
                 finalization_registry.mark_values(queues)
@@ -587,6 +624,9 @@ impl HeapMarkAndSweep for HeapRootData {
             Self::SetIterator(set_iterator) => set_iterator.mark_values(queues),
             Self::MapIterator(map_iterator) => map_iterator.mark_values(queues),
             Self::StringIterator(generator) => generator.mark_values(queues),
+            Self::IteratorHelper(generator) => generator.mark_values(queues),
+            #[cfg(feature = "json")]
+            Self::RawJSON(data) => data.mark_values(queues),
             #[cfg(feature = "regexp")]
             Self::RegExpStringIterator(generator) => generator.mark_values(queues),
             Self::Generator(generator) => generator.mark_values(queues),
@@ -596,6 +636,7 @@ impl HeapMarkAndSweep for HeapRootData {
             Self::AwaitReaction(await_reaction) => await_reaction.mark_values(queues),
             Self::PromiseReaction(promise_reaction) => promise_reaction.mark_values(queues),
             Self::PromiseGroup(promise_group) => promise_group.mark_values(queues),
+            Self::ArrayFromAsync(array_from_async) => array_from_async.mark_values(queues),
             Self::Realm(realm) => realm.mark_values(queues),
             Self::Script(script) => script.mark_values(queues),
             Self::SourceCode(source_code) => source_code.mark_values(queues),
@@ -647,7 +688,9 @@ impl HeapMarkAndSweep for HeapRootData {
             Self::BuiltinPromiseFinallyFunction(builtin_promise_finally_function) => {
                 builtin_promise_finally_function.sweep_values(compactions);
             }
-            Self::BuiltinProxyRevokerFunction => todo!(),
+            Self::BuiltinProxyRevokerFunction(builtin_proxy_revoker_function) => {
+                builtin_proxy_revoker_function.sweep_values(compactions);
+            }
             Self::PrimitiveObject(primitive_object) => primitive_object.sweep_values(compactions),
             Self::Arguments(ordinary_object) => ordinary_object.sweep_values(compactions),
             Self::Array(array) => array.sweep_values(compactions),
@@ -659,6 +702,17 @@ impl HeapMarkAndSweep for HeapRootData {
             Self::Duration(duration) => duration.sweep_values(compactions),
             #[cfg(feature = "temporal")]
             Self::PlainTime(o) => o.sweep_values(compactions),
+            #[cfg(feature = "temporal")]
+            Self::PlainDate(o) => o.sweep_values(compactions),
+            #[cfg(feature = "temporal")]
+            Self::PlainDateTime(o) => o.sweep_values(compactions),
+            #[cfg(feature = "temporal")]
+            Self::ZonedDateTime(o) => o.sweep_values(compactions),
+            #[cfg(feature = "temporal")]
+            Self::PlainYearMonth(o) => o.sweep_values(compactions),
+            #[cfg(feature = "temporal")]
+            Self::PlainMonthDay(o) => o.sweep_values(compactions),
+            Self::DisposableStack(disposable_stack) => disposable_stack.sweep_values(compactions),
             Self::Error(error) => error.sweep_values(compactions),
             Self::FinalizationRegistry(finalization_registry) => {
                 finalization_registry.sweep_values(compactions)
@@ -743,6 +797,9 @@ impl HeapMarkAndSweep for HeapRootData {
             Self::SetIterator(set_iterator) => set_iterator.sweep_values(compactions),
             Self::MapIterator(map_iterator) => map_iterator.sweep_values(compactions),
             Self::StringIterator(generator) => generator.sweep_values(compactions),
+            Self::IteratorHelper(generator) => generator.sweep_values(compactions),
+            #[cfg(feature = "json")]
+            Self::RawJSON(data) => data.sweep_values(compactions),
             #[cfg(feature = "regexp")]
             Self::RegExpStringIterator(generator) => generator.sweep_values(compactions),
             Self::Generator(generator) => generator.sweep_values(compactions),
@@ -752,6 +809,7 @@ impl HeapMarkAndSweep for HeapRootData {
             Self::AwaitReaction(await_reaction) => await_reaction.sweep_values(compactions),
             Self::PromiseReaction(promise_reaction) => promise_reaction.sweep_values(compactions),
             Self::PromiseGroup(promise_group) => promise_group.sweep_values(compactions),
+            Self::ArrayFromAsync(array_from_async) => array_from_async.sweep_values(compactions),
             Self::Realm(realm) => realm.sweep_values(compactions),
             Self::Script(script) => script.sweep_values(compactions),
             Self::SourceCode(source_code) => source_code.sweep_values(compactions),
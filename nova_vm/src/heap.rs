@@ -7,17 +7,23 @@
 //! This module contains internal details of how the Nova engine lays out its
 //! heap. It is likely to go away in the future.
 
+mod arena;
 mod element_array;
 mod heap_bits;
 mod heap_constants;
 mod heap_gc;
+mod heap_snapshot;
+mod heap_statistics;
 mod indexes;
 mod object_entry;
 
+pub(crate) use arena::*;
 pub use element_array::*;
 pub(crate) use heap_bits::*;
 pub(crate) use heap_constants::*;
 pub(crate) use heap_gc::*;
+pub(crate) use heap_snapshot::*;
+pub use heap_statistics::*;
 pub(crate) use indexes::*;
 pub(crate) use object_entry::*;
 
@@ -26,13 +32,18 @@ use std::ops::Deref;
 
 #[cfg(feature = "date")]
 use crate::ecmascript::DateHeapData;
+#[cfg(feature = "json")]
+use crate::ecmascript::RawJSONHeapData;
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::{
     ArrayBuffer, ArrayBufferHeapData, DataView, DataViewRecord, DetachKey, TypedArrayRecord,
     VoidArray,
 };
 #[cfg(feature = "temporal")]
-use crate::ecmascript::{DurationRecord, InstantRecord, PlainTimeRecord};
+use crate::ecmascript::{
+    DurationRecord, InstantRecord, PlainDateRecord, PlainDateTimeRecord, PlainMonthDayRecord,
+    PlainTimeRecord, PlainYearMonthRecord, ZonedDateTimeRecord,
+};
 #[cfg(feature = "regexp")]
 use crate::ecmascript::{RegExpHeapData, RegExpStringIteratorRecord};
 #[cfg(feature = "set")]
@@ -46,21 +57,21 @@ use crate::ecmascript::{
 use crate::ecmascript::{WeakMapRecord, WeakRefHeapData, WeakSetHeapData};
 use crate::{
     ecmascript::{
-        Agent, ArrayHeapData, ArrayIteratorHeapData, AsyncGeneratorHeapData, AwaitReactionRecord,
-        BUILTIN_STRING_MEMORY, BUILTIN_STRINGS_LIST, BigIntHeapData, BoundFunctionHeapData,
-        BuiltinConstructorRecord, BuiltinFunctionHeapData, Caches, ECMAScriptFunctionHeapData,
+        Agent, ArrayFromAsyncRecord, ArrayHeapData, ArrayIteratorHeapData, AsyncGeneratorHeapData,
+        AwaitReactionRecord, BUILTIN_STRING_MEMORY, BUILTIN_STRINGS_LIST, BigIntHeapData,
+        BoundFunctionHeapData, BuiltinConstructorRecord, BuiltinFunction, BuiltinFunctionHeapData,
+        Caches, DisposableStackHeapData, ECMAScriptFunctionHeapData, EmbedderObject,
         EmbedderObjectHeapData, Environments, ErrorHeapData, FinalizationRegistryRecord,
-        GeneratorHeapData, HeapString, MapHeapData, MapIteratorHeapData, ModuleHeapData,
-        ModuleRequestRecord, NumberHeapData, ObjectRecord, ObjectShapeRecord,
+        GeneratorHeapData, HeapString, IteratorHelperHeapData, MapHeapData, MapIteratorHeapData,
+        ModuleHeapData, ModuleRequestRecord, NumberHeapData, ObjectRecord, ObjectShapeRecord,
         ObjectShapeTransitionMap, PrimitiveObjectRecord, PromiseFinallyFunctionHeapData,
         PromiseGroupRecord, PromiseHeapData, PromiseReactionRecord,
-        PromiseResolvingFunctionHeapData, PrototypeShapeTable, ProxyHeapData, RealmRecord,
-        ScriptRecord, SourceCodeHeapData, SourceTextModuleHeap, String, StringIteratorHeapData,
-        StringRecord, SymbolHeapData,
+        PromiseResolvingFunctionHeapData, PrototypeShapeTable, ProxyHeapData,
+        ProxyRevokerFunctionHeapData, RealmRecord, ScriptRecord, SourceCodeHeapData,
+        SourceTextModuleHeap, String, StringIteratorHeapData, StringRecord, SymbolHeapData,
     },
     engine::{ExecutableHeapData, HeapRootData},
 };
-#[cfg(feature = "array-buffer")]
 use ahash::AHashMap;
 use hashbrown::HashTable;
 use soavec::{SoAVec, SoAble};
@@ -68,67 +79,84 @@ use wtf8::{Wtf8, Wtf8Buf};
 
 pub(crate) struct Heap {
     #[cfg(feature = "array-buffer")]
-    pub(crate) array_buffers: Vec<ArrayBufferHeapData<'static>>,
+    pub(crate) array_buffers: Arena<Vec<ArrayBufferHeapData<'static>>>,
     #[cfg(feature = "array-buffer")]
     pub(crate) array_buffer_detach_keys: AHashMap<ArrayBuffer<'static>, DetachKey>,
-    pub(crate) arrays: SoAVec<ArrayHeapData<'static>>,
-    pub(crate) array_iterators: Vec<ArrayIteratorHeapData<'static>>,
-    pub(crate) async_generators: Vec<AsyncGeneratorHeapData<'static>>,
-    pub(crate) await_reactions: Vec<AwaitReactionRecord<'static>>,
-    pub(crate) bigints: Vec<BigIntHeapData>,
-    pub(crate) bound_functions: Vec<BoundFunctionHeapData<'static>>,
-    pub(crate) builtin_constructors: Vec<BuiltinConstructorRecord<'static>>,
-    pub(crate) builtin_functions: Vec<BuiltinFunctionHeapData<'static>>,
+    pub(crate) arrays: Arena<SoAVec<ArrayHeapData<'static>>>,
+    pub(crate) array_from_async_records: Arena<Vec<ArrayFromAsyncRecord<'static>>>,
+    pub(crate) array_iterators: Arena<Vec<ArrayIteratorHeapData<'static>>>,
+    pub(crate) async_generators: Arena<Vec<AsyncGeneratorHeapData<'static>>>,
+    pub(crate) await_reactions: Arena<Vec<AwaitReactionRecord<'static>>>,
+    pub(crate) bigints: Arena<Vec<BigIntHeapData>>,
+    pub(crate) bound_functions: Arena<Vec<BoundFunctionHeapData<'static>>>,
+    pub(crate) builtin_constructors: Arena<Vec<BuiltinConstructorRecord<'static>>>,
+    pub(crate) builtin_functions: Arena<Vec<BuiltinFunctionHeapData<'static>>>,
+    /// Embedder-defined data attached to builtin functions created by the
+    /// host; see [`BuiltinFunction::host_data`].
+    pub(crate) builtin_function_host_data:
+        AHashMap<BuiltinFunction<'static>, EmbedderObject<'static>>,
     pub(crate) caches: Caches<'static>,
     #[cfg(feature = "date")]
-    pub(crate) dates: Vec<DateHeapData<'static>>,
+    pub(crate) dates: Arena<Vec<DateHeapData<'static>>>,
+    #[cfg(feature = "temporal")]
+    pub(crate) instants: Arena<Vec<InstantRecord<'static>>>,
+    #[cfg(feature = "temporal")]
+    pub(crate) durations: Arena<Vec<DurationRecord<'static>>>,
+    #[cfg(feature = "temporal")]
+    pub(crate) plain_times: Arena<Vec<PlainTimeRecord<'static>>>,
     #[cfg(feature = "temporal")]
-    pub(crate) instants: Vec<InstantRecord<'static>>,
+    pub(crate) plain_dates: Arena<Vec<PlainDateRecord<'static>>>,
     #[cfg(feature = "temporal")]
-    pub(crate) durations: Vec<DurationRecord<'static>>,
+    pub(crate) plain_date_times: Arena<Vec<PlainDateTimeRecord<'static>>>,
     #[cfg(feature = "temporal")]
-    pub(crate) plain_times: Vec<PlainTimeRecord<'static>>,
-    pub(crate) ecmascript_functions: Vec<ECMAScriptFunctionHeapData<'static>>,
+    pub(crate) zoned_date_times: Arena<Vec<ZonedDateTimeRecord<'static>>>,
+    #[cfg(feature = "temporal")]
+    pub(crate) plain_year_months: Arena<Vec<PlainYearMonthRecord<'static>>>,
+    #[cfg(feature = "temporal")]
+    pub(crate) plain_month_days: Arena<Vec<PlainMonthDayRecord<'static>>>,
+    pub(crate) ecmascript_functions: Arena<Vec<ECMAScriptFunctionHeapData<'static>>>,
     /// ElementsArrays is where all keys and values arrays live;
     /// Element arrays are static arrays of Values plus
     /// a HashMap of possible property descriptors.
     pub(crate) elements: ElementArrays,
-    pub(crate) embedder_objects: Vec<EmbedderObjectHeapData<'static>>,
+    pub(crate) embedder_objects: Arena<Vec<EmbedderObjectHeapData<'static>>>,
     pub(crate) environments: Environments,
-    pub(crate) errors: Vec<ErrorHeapData<'static>>,
+    pub(crate) disposable_stacks: Arena<Vec<DisposableStackHeapData<'static>>>,
+    pub(crate) errors: Arena<Vec<ErrorHeapData<'static>>>,
     /// Stores compiled bytecodes
-    pub(crate) executables: Vec<ExecutableHeapData<'static>>,
-    pub(crate) finalization_registrys: SoAVec<FinalizationRegistryRecord<'static>>,
-    pub(crate) generators: Vec<GeneratorHeapData<'static>>,
+    pub(crate) executables: Arena<Vec<ExecutableHeapData<'static>>>,
+    pub(crate) finalization_registrys: Arena<SoAVec<FinalizationRegistryRecord<'static>>>,
+    pub(crate) generators: Arena<Vec<GeneratorHeapData<'static>>>,
     pub(crate) globals: RefCell<Vec<HeapRootData>>,
-    pub(crate) maps: SoAVec<MapHeapData<'static>>,
-    pub(crate) map_iterators: Vec<MapIteratorHeapData<'static>>,
-    pub(crate) numbers: Vec<NumberHeapData>,
-    pub(crate) object_shapes: Vec<ObjectShapeRecord<'static>>,
-    pub(crate) object_shape_transitions: Vec<ObjectShapeTransitionMap<'static>>,
+    pub(crate) maps: Arena<SoAVec<MapHeapData<'static>>>,
+    pub(crate) map_iterators: Arena<Vec<MapIteratorHeapData<'static>>>,
+    pub(crate) numbers: Arena<Vec<NumberHeapData>>,
+    pub(crate) object_shapes: Arena<Vec<ObjectShapeRecord<'static>>>,
+    pub(crate) object_shape_transitions: Arena<Vec<ObjectShapeTransitionMap<'static>>>,
     pub(crate) prototype_shapes: PrototypeShapeTable,
-    pub(crate) objects: Vec<ObjectRecord<'static>>,
-    pub(crate) primitive_objects: Vec<PrimitiveObjectRecord<'static>>,
-    pub(crate) promise_reaction_records: Vec<PromiseReactionRecord<'static>>,
-    pub(crate) promise_resolving_functions: Vec<PromiseResolvingFunctionHeapData<'static>>,
-    pub(crate) promise_finally_functions: Vec<PromiseFinallyFunctionHeapData<'static>>,
-    pub(crate) promises: Vec<PromiseHeapData<'static>>,
-    pub(crate) proxies: Vec<ProxyHeapData<'static>>,
-    pub(crate) realms: Vec<RealmRecord<'static>>,
-    pub(crate) promise_group_records: Vec<PromiseGroupRecord<'static>>,
+    pub(crate) objects: Arena<Vec<ObjectRecord<'static>>>,
+    pub(crate) primitive_objects: Arena<Vec<PrimitiveObjectRecord<'static>>>,
+    pub(crate) promise_reaction_records: Arena<Vec<PromiseReactionRecord<'static>>>,
+    pub(crate) promise_resolving_functions: Arena<Vec<PromiseResolvingFunctionHeapData<'static>>>,
+    pub(crate) promise_finally_functions: Arena<Vec<PromiseFinallyFunctionHeapData<'static>>>,
+    pub(crate) proxy_revoker_functions: Arena<Vec<ProxyRevokerFunctionHeapData<'static>>>,
+    pub(crate) promises: Arena<Vec<PromiseHeapData<'static>>>,
+    pub(crate) proxies: Arena<Vec<ProxyHeapData<'static>>>,
+    pub(crate) realms: Arena<Vec<RealmRecord<'static>>>,
+    pub(crate) promise_group_records: Arena<Vec<PromiseGroupRecord<'static>>>,
     #[cfg(feature = "regexp")]
-    pub(crate) regexps: Vec<RegExpHeapData<'static>>,
+    pub(crate) regexps: Arena<Vec<RegExpHeapData<'static>>>,
     #[cfg(feature = "regexp")]
-    pub(crate) regexp_string_iterators: Vec<RegExpStringIteratorRecord<'static>>,
+    pub(crate) regexp_string_iterators: Arena<Vec<RegExpStringIteratorRecord<'static>>>,
     #[cfg(feature = "set")]
-    pub(crate) sets: SoAVec<SetHeapData<'static>>,
+    pub(crate) sets: Arena<SoAVec<SetHeapData<'static>>>,
     #[cfg(feature = "set")]
-    pub(crate) set_iterators: Vec<SetIteratorHeapData<'static>>,
+    pub(crate) set_iterators: Arena<Vec<SetIteratorHeapData<'static>>>,
     #[cfg(feature = "shared-array-buffer")]
-    pub(crate) shared_array_buffers: Vec<SharedArrayBufferRecord<'static>>,
-    pub(crate) symbols: Vec<SymbolHeapData<'static>>,
+    pub(crate) shared_array_buffers: Arena<Vec<SharedArrayBufferRecord<'static>>>,
+    pub(crate) symbols: Arena<Vec<SymbolHeapData<'static>>>,
     #[cfg(feature = "array-buffer")]
-    pub(crate) typed_arrays: Vec<TypedArrayRecord<'static>>,
+    pub(crate) typed_arrays: Arena<Vec<TypedArrayRecord<'static>>>,
     #[cfg(feature = "array-buffer")]
     pub(crate) typed_array_byte_lengths: AHashMap<VoidArray<'static>, usize>,
     #[cfg(feature = "array-buffer")]
@@ -136,13 +164,13 @@ pub(crate) struct Heap {
     #[cfg(feature = "array-buffer")]
     pub(crate) typed_array_array_lengths: AHashMap<VoidArray<'static>, usize>,
     #[cfg(feature = "array-buffer")]
-    pub(crate) data_views: Vec<DataViewRecord<'static>>,
+    pub(crate) data_views: Arena<Vec<DataViewRecord<'static>>>,
     #[cfg(feature = "array-buffer")]
     pub(crate) data_view_byte_lengths: AHashMap<DataView<'static>, usize>,
     #[cfg(feature = "array-buffer")]
     pub(crate) data_view_byte_offsets: AHashMap<DataView<'static>, usize>,
     #[cfg(feature = "shared-array-buffer")]
-    pub(crate) shared_typed_arrays: Vec<SharedTypedArrayRecord<'static>>,
+    pub(crate) shared_typed_arrays: Arena<Vec<SharedTypedArrayRecord<'static>>>,
     #[cfg(feature = "shared-array-buffer")]
     pub(crate) shared_typed_array_byte_lengths: AHashMap<SharedVoidArray<'static>, usize>,
     #[cfg(feature = "shared-array-buffer")]
@@ -150,32 +178,52 @@ pub(crate) struct Heap {
     #[cfg(feature = "shared-array-buffer")]
     pub(crate) shared_typed_array_array_lengths: AHashMap<SharedVoidArray<'static>, usize>,
     #[cfg(feature = "shared-array-buffer")]
-    pub(crate) shared_data_views: Vec<SharedDataViewRecord<'static>>,
+    pub(crate) shared_data_views: Arena<Vec<SharedDataViewRecord<'static>>>,
     #[cfg(feature = "shared-array-buffer")]
     pub(crate) shared_data_view_byte_lengths: AHashMap<SharedDataView<'static>, usize>,
     #[cfg(feature = "shared-array-buffer")]
     pub(crate) shared_data_view_byte_offsets: AHashMap<SharedDataView<'static>, usize>,
     #[cfg(feature = "weak-refs")]
-    pub(crate) weak_maps: Vec<WeakMapRecord<'static>>,
+    pub(crate) weak_maps: Arena<Vec<WeakMapRecord<'static>>>,
     #[cfg(feature = "weak-refs")]
-    pub(crate) weak_refs: Vec<WeakRefHeapData<'static>>,
+    pub(crate) weak_refs: Arena<Vec<WeakRefHeapData<'static>>>,
     #[cfg(feature = "weak-refs")]
-    pub(crate) weak_sets: Vec<WeakSetHeapData<'static>>,
-    pub(crate) modules: Vec<ModuleHeapData<'static>>,
-    pub(crate) module_request_records: Vec<ModuleRequestRecord<'static>>,
+    pub(crate) weak_sets: Arena<Vec<WeakSetHeapData<'static>>>,
+    pub(crate) modules: Arena<Vec<ModuleHeapData<'static>>>,
+    pub(crate) module_request_records: Arena<Vec<ModuleRequestRecord<'static>>>,
     pub(crate) source_text_module_records: SourceTextModuleHeap,
-    pub(crate) scripts: Vec<ScriptRecord<'static>>,
-    pub(crate) string_iterators: Vec<StringIteratorHeapData<'static>>,
+    pub(crate) scripts: Arena<Vec<ScriptRecord<'static>>>,
+    pub(crate) string_iterators: Arena<Vec<StringIteratorHeapData<'static>>>,
+    pub(crate) iterator_helpers: Arena<Vec<IteratorHelperHeapData<'static>>>,
+    #[cfg(feature = "json")]
+    pub(crate) raw_json_objects: Arena<Vec<RawJSONHeapData<'static>>>,
     // Parsed ASTs referred by functions must be dropped after functions.
     // These are held in the SourceCodeHeapData structs.
-    pub(crate) source_codes: Vec<SourceCodeHeapData<'static>>,
+    pub(crate) source_codes: Arena<Vec<SourceCodeHeapData<'static>>>,
     // But: Source code string data is in the string heap. We need to thus drop
     // the strings only after the source ASTs drop.
-    pub(crate) strings: Vec<StringRecord>,
+    pub(crate) strings: Arena<Vec<StringRecord>>,
     pub(crate) string_lookup_table: HashTable<HeapString<'static>>,
     pub(crate) string_hasher: ahash::RandomState,
+    /// Number of bytes in the owned buffers of heap strings.
+    pub(crate) string_bytes: usize,
     /// Counts allocations for garbage collection triggering.
     pub(crate) alloc_counter: usize,
+    /// Maximum number of bytes the heap may use.
+    pub(crate) max_heap_bytes: Option<usize>,
+    /// Estimated number of bytes used by the heap after the last garbage
+    /// collection.
+    pub(crate) retained_bytes: usize,
+    /// Number of bytes the heap may temporarily go over its size limit. This
+    /// gives exception handlers room to run after an out of memory error.
+    pub(crate) heap_limit_grace_bytes: usize,
+    /// Estimated number of bytes used by the heap after the last full garbage
+    /// collection.
+    pub(crate) full_gc_retained_bytes: usize,
+    /// Number of garbage collections performed.
+    pub(crate) gc_count: usize,
+    /// Statistics of the last garbage collection.
+    pub(crate) last_gc: Option<GcStatistics>,
 }
 
 pub(crate) trait CreateHeapData<T, F> {
@@ -221,27 +269,39 @@ impl Heap {
     pub(crate) fn new() -> Heap {
         let mut heap = Heap {
             #[cfg(feature = "array-buffer")]
-            array_buffers: Vec::with_capacity(1024),
+            array_buffers: Arena::new(Vec::with_capacity(1024)),
             #[cfg(feature = "array-buffer")]
             array_buffer_detach_keys: AHashMap::with_capacity(0),
-            arrays: SoAVec::with_capacity(1024).expect("Failed to allocate Heap"),
-            array_iterators: Vec::with_capacity(256),
-            async_generators: Vec::with_capacity(0),
-            await_reactions: Vec::with_capacity(1024),
-            bigints: Vec::with_capacity(1024),
-            bound_functions: Vec::with_capacity(256),
-            builtin_constructors: Vec::with_capacity(256),
-            builtin_functions: Vec::with_capacity(1024),
+            arrays: Arena::new(SoAVec::with_capacity(1024).expect("Failed to allocate Heap")),
+            array_from_async_records: Arena::new(Vec::with_capacity(0)),
+            array_iterators: Arena::new(Vec::with_capacity(256)),
+            async_generators: Arena::new(Vec::with_capacity(0)),
+            await_reactions: Arena::new(Vec::with_capacity(1024)),
+            bigints: Arena::new(Vec::with_capacity(1024)),
+            bound_functions: Arena::new(Vec::with_capacity(256)),
+            builtin_constructors: Arena::new(Vec::with_capacity(256)),
+            builtin_functions: Arena::new(Vec::with_capacity(1024)),
+            builtin_function_host_data: AHashMap::with_capacity(0),
             caches: Caches::with_capacity(1024),
             #[cfg(feature = "date")]
-            dates: Vec::with_capacity(1024),
+            dates: Arena::new(Vec::with_capacity(1024)),
+            #[cfg(feature = "temporal")]
+            instants: Arena::new(Vec::with_capacity(0)),
+            #[cfg(feature = "temporal")]
+            durations: Arena::new(Vec::with_capacity(0)),
+            #[cfg(feature = "temporal")]
+            plain_times: Arena::new(Vec::with_capacity(0)),
+            #[cfg(feature = "temporal")]
+            plain_dates: Arena::new(Vec::with_capacity(0)),
             #[cfg(feature = "temporal")]
-            instants: Vec::with_capacity(0),
+            plain_date_times: Arena::new(Vec::with_capacity(0)),
             #[cfg(feature = "temporal")]
-            durations: Vec::with_capacity(0),
+            zoned_date_times: Arena::new(Vec::with_capacity(0)),
             #[cfg(feature = "temporal")]
-            plain_times: Vec::with_capacity(0),
-            ecmascript_functions: Vec::with_capacity(1024),
+            plain_year_months: Arena::new(Vec::with_capacity(0)),
+            #[cfg(feature = "temporal")]
+            plain_month_days: Arena::new(Vec::with_capacity(0)),
+            ecmascript_functions: Arena::new(Vec::with_capacity(1024)),
             elements: ElementArrays {
                 e2pow1: ElementArray2Pow1::with_capacity(1024),
                 e2pow2: ElementArray2Pow2::with_capacity(1024),
@@ -265,51 +325,62 @@ impl Heap {
                 k2pow16: PropertyKeyArray2Pow16::default(),
                 k2pow24: PropertyKeyArray2Pow24::default(),
                 k2pow32: PropertyKeyArray2Pow32::default(),
+                sparse: Arena::new(Vec::with_capacity(0)),
+                max_bytes: None,
+                alloc_counter: 0,
             },
-            embedder_objects: Vec::with_capacity(0),
+            embedder_objects: Arena::new(Vec::with_capacity(0)),
             environments: Default::default(),
-            errors: Vec::with_capacity(1024),
-            executables: Vec::with_capacity(1024),
-            source_codes: Vec::with_capacity(0),
-            finalization_registrys: SoAVec::with_capacity(0).expect("Failed to allocate Heap"),
-            generators: Vec::with_capacity(1024),
+            disposable_stacks: Arena::new(Vec::with_capacity(0)),
+            errors: Arena::new(Vec::with_capacity(1024)),
+            executables: Arena::new(Vec::with_capacity(1024)),
+            source_codes: Arena::new(Vec::with_capacity(0)),
+            finalization_registrys: Arena::new(
+                SoAVec::with_capacity(0).expect("Failed to allocate Heap"),
+            ),
+            generators: Arena::new(Vec::with_capacity(1024)),
             globals: RefCell::new(Vec::with_capacity(1024)),
-            maps: SoAVec::with_capacity(128).expect("Failed to allocate Heap"),
-            map_iterators: Vec::with_capacity(128),
-            modules: Vec::with_capacity(0),
-            module_request_records: Vec::with_capacity(0),
-            numbers: Vec::with_capacity(1024),
-            object_shapes: Vec::with_capacity(256),
-            object_shape_transitions: Vec::with_capacity(256),
+            maps: Arena::new(SoAVec::with_capacity(128).expect("Failed to allocate Heap")),
+            map_iterators: Arena::new(Vec::with_capacity(128)),
+            modules: Arena::new(Vec::with_capacity(0)),
+            module_request_records: Arena::new(Vec::with_capacity(0)),
+            numbers: Arena::new(Vec::with_capacity(1024)),
+            object_shapes: Arena::new(Vec::with_capacity(256)),
+            object_shape_transitions: Arena::new(Vec::with_capacity(256)),
             prototype_shapes: PrototypeShapeTable::with_capacity(64),
-            objects: Vec::with_capacity(1024),
-            primitive_objects: Vec::with_capacity(0),
-            promise_reaction_records: Vec::with_capacity(0),
-            promise_resolving_functions: Vec::with_capacity(0),
-            promise_finally_functions: Vec::with_capacity(0),
-            promises: Vec::with_capacity(0),
-            promise_group_records: Vec::with_capacity(0),
-            proxies: Vec::with_capacity(0),
-            realms: Vec::with_capacity(1),
+            objects: Arena::new(Vec::with_capacity(1024)),
+            primitive_objects: Arena::new(Vec::with_capacity(0)),
+            promise_reaction_records: Arena::new(Vec::with_capacity(0)),
+            promise_resolving_functions: Arena::new(Vec::with_capacity(0)),
+            promise_finally_functions: Arena::new(Vec::with_capacity(0)),
+            proxy_revoker_functions: Arena::new(Vec::with_capacity(0)),
+            promises: Arena::new(Vec::with_capacity(0)),
+            promise_group_records: Arena::new(Vec::with_capacity(0)),
+            proxies: Arena::new(Vec::with_capacity(0)),
+            realms: Arena::new(Vec::with_capacity(1)),
             #[cfg(feature = "regexp")]
-            regexps: Vec::with_capacity(1024),
+            regexps: Arena::new(Vec::with_capacity(1024)),
             #[cfg(feature = "regexp")]
-            regexp_string_iterators: Vec::with_capacity(0),
-            scripts: Vec::with_capacity(1),
+            regexp_string_iterators: Arena::new(Vec::with_capacity(0)),
+            scripts: Arena::new(Vec::with_capacity(1)),
             #[cfg(feature = "set")]
-            sets: SoAVec::with_capacity(128).expect("Failed to allocate Heap"),
+            sets: Arena::new(SoAVec::with_capacity(128).expect("Failed to allocate Heap")),
             #[cfg(feature = "set")]
-            set_iterators: Vec::with_capacity(128),
+            set_iterators: Arena::new(Vec::with_capacity(128)),
             #[cfg(feature = "shared-array-buffer")]
-            shared_array_buffers: Vec::with_capacity(0),
-            source_text_module_records: SourceTextModuleHeap(Vec::with_capacity(128)),
-            strings: Vec::with_capacity(1024),
-            string_iterators: Vec::with_capacity(0),
+            shared_array_buffers: Arena::new(Vec::with_capacity(0)),
+            source_text_module_records: SourceTextModuleHeap(Arena::new(Vec::with_capacity(128))),
+            strings: Arena::new(Vec::with_capacity(1024)),
+            string_iterators: Arena::new(Vec::with_capacity(0)),
+            iterator_helpers: Arena::new(Vec::with_capacity(0)),
+            #[cfg(feature = "json")]
+            raw_json_objects: Arena::new(Vec::with_capacity(0)),
             string_lookup_table: HashTable::with_capacity(1024),
             string_hasher: ahash::RandomState::new(),
-            symbols: Vec::with_capacity(1024),
+            string_bytes: 0,
+            symbols: Arena::new(Vec::with_capacity(1024)),
             #[cfg(feature = "array-buffer")]
-            typed_arrays: Vec::with_capacity(0),
+            typed_arrays: Arena::new(Vec::with_capacity(0)),
             #[cfg(feature = "array-buffer")]
             typed_array_byte_lengths: AHashMap::with_capacity(0),
             #[cfg(feature = "array-buffer")]
@@ -317,13 +388,13 @@ impl Heap {
             #[cfg(feature = "array-buffer")]
             typed_array_array_lengths: AHashMap::with_capacity(0),
             #[cfg(feature = "array-buffer")]
-            data_views: Vec::with_capacity(0),
+            data_views: Arena::new(Vec::with_capacity(0)),
             #[cfg(feature = "array-buffer")]
             data_view_byte_lengths: AHashMap::with_capacity(0),
             #[cfg(feature = "array-buffer")]
             data_view_byte_offsets: AHashMap::with_capacity(0),
             #[cfg(feature = "shared-array-buffer")]
-            shared_typed_arrays: Vec::with_capacity(0),
+            shared_typed_arrays: Arena::new(Vec::with_capacity(0)),
             #[cfg(feature = "shared-array-buffer")]
             shared_typed_array_byte_lengths: AHashMap::with_capacity(0),
             #[cfg(feature = "shared-array-buffer")]
@@ -331,18 +402,24 @@ impl Heap {
             #[cfg(feature = "shared-array-buffer")]
             shared_typed_array_array_lengths: AHashMap::with_capacity(0),
             #[cfg(feature = "shared-array-buffer")]
-            shared_data_views: Vec::with_capacity(0),
+            shared_data_views: Arena::new(Vec::with_capacity(0)),
             #[cfg(feature = "shared-array-buffer")]
             shared_data_view_byte_lengths: AHashMap::with_capacity(0),
             #[cfg(feature = "shared-array-buffer")]
             shared_data_view_byte_offsets: AHashMap::with_capacity(0),
             #[cfg(feature = "weak-refs")]
-            weak_maps: Vec::with_capacity(0),
+            weak_maps: Arena::new(Vec::with_capacity(0)),
             #[cfg(feature = "weak-refs")]
-            weak_refs: Vec::with_capacity(0),
+            weak_refs: Arena::new(Vec::with_capacity(0)),
             #[cfg(feature = "weak-refs")]
-            weak_sets: Vec::with_capacity(0),
+            weak_sets: Arena::new(Vec::with_capacity(0)),
             alloc_counter: 0,
+            max_heap_bytes: None,
+            retained_bytes: 0,
+            heap_limit_grace_bytes: 0,
+            full_gc_retained_bytes: 0,
+            gc_count: 0,
+            last_gc: None,
         };
 
         const {
@@ -359,7 +436,9 @@ impl Heap {
         }
 
         heap.symbols.extend_from_slice(&[
+            SymbolHeapData::new(BUILTIN_STRING_MEMORY.Symbol_asyncDispose),
             SymbolHeapData::new(BUILTIN_STRING_MEMORY.Symbol_asyncIterator),
+            SymbolHeapData::new(BUILTIN_STRING_MEMORY.Symbol_dispose),
             SymbolHeapData::new(BUILTIN_STRING_MEMORY.Symbol_hasInstance),
             SymbolHeapData::new(BUILTIN_STRING_MEMORY.Symbol_isConcatSpreadable),
             SymbolHeapData::new(BUILTIN_STRING_MEMORY.Symbol_iterator),
@@ -381,9 +460,605 @@ impl Heap {
         heap.object_shape_transitions
             .push(ObjectShapeTransitionMap::ROOT);
 
+        heap.retained_bytes = heap.byte_size();
         heap
     }
 
+    /// Returns an estimate of the number of bytes used by the heap.
+    ///
+    /// The estimate includes the data of all heap values, including garbage
+    /// values that have not yet been collected, but does not include the
+    /// spare capacity of the heap vectors. The bytes of owned string buffers are
+    /// counted as strings are allocated and swept, so that computing the
+    /// estimate does not iterate over the strings.
+    pub(crate) fn byte_size(&self) -> usize {
+        let mut bytes = 0;
+        self.for_each_arena(&mut |_, _, arena_bytes| bytes += arena_bytes);
+        bytes
+    }
+
+    /// Calls `f` with the name, number of items, and estimated number of bytes
+    /// of each arena in the heap.
+    ///
+    /// Like [`Heap::byte_size`], the counts include garbage values that have
+    /// not yet been collected.
+    pub(crate) fn for_each_arena(&self, f: &mut impl FnMut(&'static str, usize, usize)) {
+        let Self {
+            #[cfg(feature = "array-buffer")]
+            array_buffers,
+            #[cfg(feature = "array-buffer")]
+            array_buffer_detach_keys,
+            arrays,
+            array_from_async_records,
+            array_iterators,
+            async_generators,
+            await_reactions,
+            bigints,
+            bound_functions,
+            builtin_constructors,
+            builtin_functions,
+            builtin_function_host_data,
+            caches: _,
+            #[cfg(feature = "date")]
+            dates,
+            #[cfg(feature = "temporal")]
+            instants,
+            #[cfg(feature = "temporal")]
+            durations,
+            #[cfg(feature = "temporal")]
+            plain_times,
+            #[cfg(feature = "temporal")]
+            plain_dates,
+            #[cfg(feature = "temporal")]
+            plain_date_times,
+            #[cfg(feature = "temporal")]
+            zoned_date_times,
+            #[cfg(feature = "temporal")]
+            plain_year_months,
+            #[cfg(feature = "temporal")]
+            plain_month_days,
+            ecmascript_functions,
+            elements,
+            embedder_objects,
+            environments,
+            disposable_stacks,
+            errors,
+            executables,
+            finalization_registrys,
+            generators,
+            globals: _,
+            maps,
+            map_iterators,
+            numbers,
+            object_shapes,
+            object_shape_transitions,
+            prototype_shapes: _,
+            objects,
+            primitive_objects,
+            promise_reaction_records,
+            promise_resolving_functions,
+            promise_finally_functions,
+            proxy_revoker_functions,
+            promises,
+            proxies,
+            realms,
+            promise_group_records,
+            #[cfg(feature = "regexp")]
+            regexps,
+            #[cfg(feature = "regexp")]
+            regexp_string_iterators,
+            #[cfg(feature = "set")]
+            sets,
+            #[cfg(feature = "set")]
+            set_iterators,
+            #[cfg(feature = "shared-array-buffer")]
+            shared_array_buffers,
+            symbols,
+            #[cfg(feature = "array-buffer")]
+            typed_arrays,
+            #[cfg(feature = "array-buffer")]
+            typed_array_byte_lengths,
+            #[cfg(feature = "array-buffer")]
+            typed_array_byte_offsets,
+            #[cfg(feature = "array-buffer")]
+            typed_array_array_lengths,
+            #[cfg(feature = "array-buffer")]
+            data_views,
+            #[cfg(feature = "array-buffer")]
+            data_view_byte_lengths,
+            #[cfg(feature = "array-buffer")]
+            data_view_byte_offsets,
+            #[cfg(feature = "shared-array-buffer")]
+            shared_typed_arrays,
+            #[cfg(feature = "shared-array-buffer")]
+            shared_typed_array_byte_lengths,
+            #[cfg(feature = "shared-array-buffer")]
+            shared_typed_array_byte_offsets,
+            #[cfg(feature = "shared-array-buffer")]
+            shared_typed_array_array_lengths,
+            #[cfg(feature = "shared-array-buffer")]
+            shared_data_views,
+            #[cfg(feature = "shared-array-buffer")]
+            shared_data_view_byte_lengths,
+            #[cfg(feature = "shared-array-buffer")]
+            shared_data_view_byte_offsets,
+            #[cfg(feature = "weak-refs")]
+            weak_maps,
+            #[cfg(feature = "weak-refs")]
+            weak_refs,
+            #[cfg(feature = "weak-refs")]
+            weak_sets,
+            modules,
+            module_request_records,
+            source_text_module_records,
+            scripts,
+            string_iterators,
+            iterator_helpers,
+            #[cfg(feature = "json")]
+            raw_json_objects,
+            source_codes,
+            strings,
+            string_lookup_table: _,
+            string_hasher: _,
+            string_bytes,
+            alloc_counter: _,
+            max_heap_bytes: _,
+            retained_bytes: _,
+            heap_limit_grace_bytes: _,
+            full_gc_retained_bytes: _,
+            gc_count: _,
+            last_gc: _,
+        } = self;
+        #[cfg(feature = "array-buffer")]
+        {
+            f(
+                "array_buffers",
+                array_buffers.len(),
+                size_of_val(array_buffers.as_slice()),
+            );
+        }
+        #[cfg(feature = "array-buffer")]
+        {
+            f(
+                "array_buffer_detach_keys",
+                array_buffer_detach_keys.len(),
+                array_buffer_detach_keys.len() * size_of::<(ArrayBuffer<'static>, DetachKey)>(),
+            );
+        }
+        f(
+            "arrays",
+            arrays.len() as usize,
+            arrays.len() as usize * size_of::<ArrayHeapData<'static>>(),
+        );
+        f(
+            "array_from_async_records",
+            array_from_async_records.len(),
+            size_of_val(array_from_async_records.as_slice()),
+        );
+        f(
+            "array_iterators",
+            array_iterators.len(),
+            size_of_val(array_iterators.as_slice()),
+        );
+        f(
+            "async_generators",
+            async_generators.len(),
+            size_of_val(async_generators.as_slice()),
+        );
+        f(
+            "await_reactions",
+            await_reactions.len(),
+            size_of_val(await_reactions.as_slice()),
+        );
+        f("bigints", bigints.len(), size_of_val(bigints.as_slice()));
+        f(
+            "bound_functions",
+            bound_functions.len(),
+            size_of_val(bound_functions.as_slice()),
+        );
+        f(
+            "builtin_constructors",
+            builtin_constructors.len(),
+            size_of_val(builtin_constructors.as_slice()),
+        );
+        f(
+            "builtin_functions",
+            builtin_functions.len(),
+            size_of_val(builtin_functions.as_slice()),
+        );
+        f(
+            "builtin_function_host_data",
+            builtin_function_host_data.len(),
+            builtin_function_host_data.len()
+                * size_of::<(BuiltinFunction<'static>, EmbedderObject<'static>)>(),
+        );
+        #[cfg(feature = "date")]
+        {
+            f("dates", dates.len(), size_of_val(dates.as_slice()));
+        }
+        #[cfg(feature = "temporal")]
+        {
+            f("instants", instants.len(), size_of_val(instants.as_slice()));
+        }
+        #[cfg(feature = "temporal")]
+        {
+            f(
+                "durations",
+                durations.len(),
+                size_of_val(durations.as_slice()),
+            );
+        }
+        #[cfg(feature = "temporal")]
+        {
+            f(
+                "plain_times",
+                plain_times.len(),
+                size_of_val(plain_times.as_slice()),
+            );
+            f(
+                "plain_dates",
+                plain_dates.len(),
+                size_of_val(plain_dates.as_slice()),
+            );
+            f(
+                "plain_date_times",
+                plain_date_times.len(),
+                size_of_val(plain_date_times.as_slice()),
+            );
+            f(
+                "zoned_date_times",
+                zoned_date_times.len(),
+                size_of_val(zoned_date_times.as_slice()),
+            );
+            f(
+                "plain_year_months",
+                plain_year_months.len(),
+                size_of_val(plain_year_months.as_slice()),
+            );
+            f(
+                "plain_month_days",
+                plain_month_days.len(),
+                size_of_val(plain_month_days.as_slice()),
+            );
+        }
+        f(
+            "ecmascript_functions",
+            ecmascript_functions.len(),
+            size_of_val(ecmascript_functions.as_slice()),
+        );
+        elements.for_each_arena(f);
+        f(
+            "embedder_objects",
+            embedder_objects.len(),
+            size_of_val(embedder_objects.as_slice()),
+        );
+        environments.for_each_arena(f);
+        f(
+            "disposable_stacks",
+            disposable_stacks.len(),
+            size_of_val(disposable_stacks.as_slice()),
+        );
+        f("errors", errors.len(), size_of_val(errors.as_slice()));
+        f(
+            "executables",
+            executables.len(),
+            size_of_val(executables.as_slice()),
+        );
+        f(
+            "finalization_registrys",
+            finalization_registrys.len() as usize,
+            finalization_registrys.len() as usize
+                * size_of::<FinalizationRegistryRecord<'static>>(),
+        );
+        f(
+            "generators",
+            generators.len(),
+            size_of_val(generators.as_slice()),
+        );
+        f(
+            "maps",
+            maps.len() as usize,
+            maps.len() as usize * size_of::<MapHeapData<'static>>(),
+        );
+        f(
+            "map_iterators",
+            map_iterators.len(),
+            size_of_val(map_iterators.as_slice()),
+        );
+        f("numbers", numbers.len(), size_of_val(numbers.as_slice()));
+        f(
+            "object_shapes",
+            object_shapes.len(),
+            size_of_val(object_shapes.as_slice()),
+        );
+        f(
+            "object_shape_transitions",
+            object_shape_transitions.len(),
+            size_of_val(object_shape_transitions.as_slice()),
+        );
+        f("objects", objects.len(), size_of_val(objects.as_slice()));
+        f(
+            "primitive_objects",
+            primitive_objects.len(),
+            size_of_val(primitive_objects.as_slice()),
+        );
+        f(
+            "promise_reaction_records",
+            promise_reaction_records.len(),
+            size_of_val(promise_reaction_records.as_slice()),
+        );
+        f(
+            "promise_resolving_functions",
+            promise_resolving_functions.len(),
+            size_of_val(promise_resolving_functions.as_slice()),
+        );
+        f(
+            "promise_finally_functions",
+            promise_finally_functions.len(),
+            size_of_val(promise_finally_functions.as_slice()),
+        );
+        f(
+            "proxy_revoker_functions",
+            proxy_revoker_functions.len(),
+            size_of_val(proxy_revoker_functions.as_slice()),
+        );
+        f("promises", promises.len(), size_of_val(promises.as_slice()));
+        f("proxies", proxies.len(), size_of_val(proxies.as_slice()));
+        f("realms", realms.len(), size_of_val(realms.as_slice()));
+        f(
+            "promise_group_records",
+            promise_group_records.len(),
+            size_of_val(promise_group_records.as_slice()),
+        );
+        #[cfg(feature = "regexp")]
+        {
+            f("regexps", regexps.len(), size_of_val(regexps.as_slice()));
+        }
+        #[cfg(feature = "regexp")]
+        {
+            f(
+                "regexp_string_iterators",
+                regexp_string_iterators.len(),
+                size_of_val(regexp_string_iterators.as_slice()),
+            );
+        }
+        #[cfg(feature = "set")]
+        {
+            f(
+                "sets",
+                sets.len() as usize,
+                sets.len() as usize * size_of::<SetHeapData<'static>>(),
+            );
+        }
+        #[cfg(feature = "set")]
+        {
+            f(
+                "set_iterators",
+                set_iterators.len(),
+                size_of_val(set_iterators.as_slice()),
+            );
+        }
+        #[cfg(feature = "shared-array-buffer")]
+        {
+            f(
+                "shared_array_buffers",
+                shared_array_buffers.len(),
+                size_of_val(shared_array_buffers.as_slice()),
+            );
+        }
+        f("symbols", symbols.len(), size_of_val(symbols.as_slice()));
+        #[cfg(feature = "array-buffer")]
+        {
+            f(
+                "typed_arrays",
+                typed_arrays.len(),
+                size_of_val(typed_arrays.as_slice()),
+            );
+        }
+        #[cfg(feature = "array-buffer")]
+        {
+            f(
+                "typed_array_byte_lengths",
+                typed_array_byte_lengths.len(),
+                typed_array_byte_lengths.len() * size_of::<(VoidArray<'static>, usize)>(),
+            );
+        }
+        #[cfg(feature = "array-buffer")]
+        {
+            f(
+                "typed_array_byte_offsets",
+                typed_array_byte_offsets.len(),
+                typed_array_byte_offsets.len() * size_of::<(VoidArray<'static>, usize)>(),
+            );
+        }
+        #[cfg(feature = "array-buffer")]
+        {
+            f(
+                "typed_array_array_lengths",
+                typed_array_array_lengths.len(),
+                typed_array_array_lengths.len() * size_of::<(VoidArray<'static>, usize)>(),
+            );
+        }
+        #[cfg(feature = "array-buffer")]
+        {
+            f(
+                "data_views",
+                data_views.len(),
+                size_of_val(data_views.as_slice()),
+            );
+        }
+        #[cfg(feature = "array-buffer")]
+        {
+            f(
+                "data_view_byte_lengths",
+                data_view_byte_lengths.len(),
+                data_view_byte_lengths.len() * size_of::<(DataView<'static>, usize)>(),
+            );
+        }
+        #[cfg(feature = "array-buffer")]
+        {
+            f(
+                "data_view_byte_offsets",
+                data_view_byte_offsets.len(),
+                data_view_byte_offsets.len() * size_of::<(DataView<'static>, usize)>(),
+            );
+        }
+        #[cfg(feature = "shared-array-buffer")]
+        {
+            f(
+                "shared_typed_arrays",
+                shared_typed_arrays.len(),
+                size_of_val(shared_typed_arrays.as_slice()),
+            );
+        }
+        #[cfg(feature = "shared-array-buffer")]
+        {
+            f(
+                "shared_typed_array_byte_lengths",
+                shared_typed_array_byte_lengths.len(),
+                shared_typed_array_byte_lengths.len()
+                    * size_of::<(SharedVoidArray<'static>, usize)>(),
+            );
+        }
+        #[cfg(feature = "shared-array-buffer")]
+        {
+            f(
+                "shared_typed_array_byte_offsets",
+                shared_typed_array_byte_offsets.len(),
+                shared_typed_array_byte_offsets.len()
+                    * size_of::<(SharedVoidArray<'static>, usize)>(),
+            );
+        }
+        #[cfg(feature = "shared-array-buffer")]
+        {
+            f(
+                "shared_typed_array_array_lengths",
+                shared_typed_array_array_lengths.len(),
+                shared_typed_array_array_lengths.len()
+                    * size_of::<(SharedVoidArray<'static>, usize)>(),
+            );
+        }
+        #[cfg(feature = "shared-array-buffer")]
+        {
+            f(
+                "shared_data_views",
+                shared_data_views.len(),
+                size_of_val(shared_data_views.as_slice()),
+            );
+        }
+        #[cfg(feature = "shared-array-buffer")]
+        {
+            f(
+                "shared_data_view_byte_lengths",
+                shared_data_view_byte_lengths.len(),
+                shared_data_view_byte_lengths.len() * size_of::<(SharedDataView<'static>, usize)>(),
+            );
+        }
+        #[cfg(feature = "shared-array-buffer")]
+        {
+            f(
+                "shared_data_view_byte_offsets",
+                shared_data_view_byte_offsets.len(),
+                shared_data_view_byte_offsets.len() * size_of::<(SharedDataView<'static>, usize)>(),
+            );
+        }
+        #[cfg(feature = "weak-refs")]
+        {
+            f(
+                "weak_maps",
+                weak_maps.len(),
+                size_of_val(weak_maps.as_slice()),
+            );
+        }
+        #[cfg(feature = "weak-refs")]
+        {
+            f(
+                "weak_refs",
+                weak_refs.len(),
+                size_of_val(weak_refs.as_slice()),
+            );
+        }
+        #[cfg(feature = "weak-refs")]
+        {
+            f(
+                "weak_sets",
+                weak_sets.len(),
+                size_of_val(weak_sets.as_slice()),
+            );
+        }
+        f("modules", modules.len(), size_of_val(modules.as_slice()));
+        f(
+            "module_request_records",
+            module_request_records.len(),
+            size_of_val(module_request_records.as_slice()),
+        );
+        f(
+            "source_text_module_records",
+            source_text_module_records.len(),
+            size_of_val(source_text_module_records.as_slice()),
+        );
+        f("scripts", scripts.len(), size_of_val(scripts.as_slice()));
+        f(
+            "string_iterators",
+            string_iterators.len(),
+            size_of_val(string_iterators.as_slice()),
+        );
+        f(
+            "iterator_helpers",
+            iterator_helpers.len(),
+            size_of_val(iterator_helpers.as_slice()),
+        );
+        #[cfg(feature = "json")]
+        {
+            f(
+                "raw_json_objects",
+                raw_json_objects.len(),
+                size_of_val(raw_json_objects.as_slice()),
+            );
+        }
+        f(
+            "source_codes",
+            source_codes.len(),
+            size_of_val(source_codes.as_slice()),
+        );
+        f(
+            "strings",
+            strings.len(),
+            size_of_val(strings.as_slice()) + string_bytes,
+        );
+    }
+
+    /// Set the maximum number of bytes the heap may use.
+    pub(crate) fn set_max_heap_bytes(&mut self, max_heap_bytes: Option<usize>) {
+        self.max_heap_bytes = max_heap_bytes;
+        self.elements.max_bytes = max_heap_bytes;
+    }
+
+    /// Returns an estimate of the number of bytes currently used by the heap.
+    pub(crate) fn estimated_byte_size(&self) -> usize {
+        self.retained_bytes + self.alloc_counter + self.elements.alloc_counter
+    }
+
+    /// Returns true if the heap is estimated to be over its size limit.
+    #[inline(always)]
+    pub(crate) fn is_over_heap_limit(&self) -> bool {
+        self.max_heap_bytes
+            .is_some_and(|max| self.estimated_byte_size() > max + self.heap_limit_grace_bytes)
+    }
+
+    /// Returns true if the old generation has grown enough since the last full
+    /// garbage collection that the next garbage collection should be a full
+    /// one.
+    pub(crate) fn is_old_generation_grown(&self) -> bool {
+        // Perform a full garbage collection once the old generation has grown
+        // by half, or by 8 MiB, whichever is more.
+        const MIN_OLD_GENERATION_GROWTH: usize = 1024 * 1024 * 8;
+        let growth = self
+            .retained_bytes
+            .saturating_sub(self.full_gc_retained_bytes);
+        growth > (self.full_gc_retained_bytes / 2).max(MIN_OLD_GENERATION_GROWTH)
+    }
+
     /// Allocate a borrowed string onto the Agent heap
     ///
     /// This method will hash the input and look for a matching string on the
@@ -545,7 +1220,7 @@ where
 
 impl<K: DirectArenaAccessMut, T> ArenaAccessMut<T> for K
 where
-    T: AsMut<Vec<K::Data>>,
+    T: AsMut<Arena<Vec<K::Data>>>,
 {
     /// Access data belonging to a handle as mutable.
     #[inline]
@@ -553,11 +1228,13 @@ where
         self,
         agent: &'agent mut T,
     ) -> &'agent mut <Self as DirectArenaAccess>::Output {
+        let arena = agent.as_mut();
+        arena.remember(self.get_index());
         // SAFETY: HeapIndexHandle guarantees that the lifetime of the output is
-        // safe when agent is safe.
+        // safe when agent is safe. The accessed item was remembered above.
         unsafe {
             core::mem::transmute::<_, &'agent mut <Self as DirectArenaAccess>::Output>(
-                self.get_direct_mut(agent.as_mut()),
+                self.get_direct_mut(arena.as_mut_unremembered()),
             )
         }
     }
@@ -601,12 +1278,15 @@ where
 
 impl<K: DirectArenaAccessSoAMut, T> ArenaAccessSoAMut<T> for K
 where
-    T: AsMut<SoAVec<K::Data>>,
+    T: AsMut<Arena<SoAVec<K::Data>>>,
 {
     /// Access data belonging to a handle as mutable.
     #[inline]
     fn get_mut<'a>(self, agent: &'a mut T) -> <Self::Data as SoAble>::Mut<'a> {
-        self.get_direct_mut(agent.as_mut())
+        let arena = agent.as_mut();
+        arena.remember(self.get_index());
+        // SAFETY: The accessed item was remembered above.
+        self.get_direct_mut(unsafe { arena.as_mut_unremembered() })
     }
 }
 
@@ -703,6 +1383,7 @@ impl PropertyKeyHeapAccess for Agent {}
 pub(crate) trait StringHeapAccess: AsRef<Vec<StringRecord>> {}
 
 impl StringHeapAccess for Vec<StringRecord> {}
+impl StringHeapAccess for Arena<Vec<StringRecord>> {}
 impl StringHeapAccess for PrimitiveHeap<'_> {}
 impl StringHeapAccess for PropertyKeyHeap<'_> {}
 impl StringHeapAccess for Agent {}
@@ -771,9 +1452,11 @@ macro_rules! arena_vec_access {
         }
 
         #[doc(hidden)]
-        impl AsMut<soavec::SoAVec<$data<'static>>> for crate::ecmascript::Agent {
+        impl AsMut<crate::heap::Arena<soavec::SoAVec<$data<'static>>>>
+            for crate::ecmascript::Agent
+        {
             #[inline(always)]
-            fn as_mut(&mut self) -> &mut soavec::SoAVec<$data<'static>> {
+            fn as_mut(&mut self) -> &mut crate::heap::Arena<soavec::SoAVec<$data<'static>>> {
                 &mut self.heap.$member
             }
         }
@@ -816,9 +1499,9 @@ macro_rules! arena_vec_access {
         }
 
         #[doc(hidden)]
-        impl AsMut<Vec<$data<'static>>> for crate::ecmascript::Agent {
+        impl AsMut<crate::heap::Arena<Vec<$data<'static>>>> for crate::ecmascript::Agent {
             #[inline(always)]
-            fn as_mut(&mut self) -> &mut Vec<$data<'static>> {
+            fn as_mut(&mut self) -> &mut crate::heap::Arena<Vec<$data<'static>>> {
                 &mut self.heap.$member
             }
         }
@@ -856,9 +1539,9 @@ macro_rules! arena_vec_access {
         }
 
         #[doc(hidden)]
-        impl AsMut<Vec<$data>> for crate::ecmascript::Agent {
+        impl AsMut<crate::heap::Arena<Vec<$data>>> for crate::ecmascript::Agent {
             #[inline(always)]
-            fn as_mut(&mut self) -> &mut Vec<$data> {
+            fn as_mut(&mut self) -> &mut crate::heap::Arena<Vec<$data>> {
                 &mut self.heap.$member
             }
         }
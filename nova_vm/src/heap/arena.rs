@@ -0,0 +1,328 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Heap arenas
+//!
+//! Every kind of heap data lives in its own vector. The vectors are wrapped in
+//! an [`Arena`] which additionally keeps track of which part of the vector
+//! belongs to the old generation, and which old generation items may have been
+//! changed since the last garbage collection.
+//!
+//! Items that were alive after the last garbage collection form the old
+//! generation; items allocated after it form the young generation. A young
+//! generation garbage collection only traces and compacts the young part of
+//! each arena. This is only correct if all references from old items to young
+//! items are known, and for this purpose the arena acts as a write barrier:
+//! every mutable access to an old item is recorded in a remembered set.
+//!
+//! The write barrier is precise when data is accessed through the
+//! [`ArenaAccessMut`] and [`ArenaAccessSoAMut`] traits, as those know the index
+//! being accessed. Any other mutable access to the arena vector marks the
+//! whole arena as changed, making the next young generation garbage collection
+//! treat all of its old items as roots.
+//!
+//! [`ArenaAccessMut`]: super::ArenaAccessMut
+//! [`ArenaAccessSoAMut`]: super::ArenaAccessSoAMut
+
+use core::{
+    mem::MaybeUninit,
+    ops::{Deref, DerefMut, Range},
+};
+use std::collections::TryReserveError;
+
+use soavec::{SoAVec, SoAble};
+
+/// A heap data vector paired with its remembered set.
+///
+/// The arena dereferences to the vector it contains. Mutably dereferencing the
+/// arena conservatively marks all old items as changed; use
+/// [`Arena::get_mut`] or the arena access traits to only mark the
+/// accessed item.
+#[derive(Debug, Default)]
+pub(crate) struct Arena<V> {
+    data: V,
+    remembered_set: RememberedSet,
+}
+
+impl<V> Arena<V> {
+    pub(crate) fn new(data: V) -> Self {
+        Self {
+            data,
+            remembered_set: RememberedSet::new(),
+        }
+    }
+
+    /// Record a mutable access to the item at the given index.
+    #[inline(always)]
+    pub(crate) fn remember(&mut self, index: usize) {
+        self.remembered_set.remember(index);
+    }
+
+    /// Record all old generation items in the arena as changed.
+    #[inline]
+    pub(crate) fn remember_all(&mut self) {
+        self.remembered_set.remember_all();
+    }
+
+    /// Get the arena vector as mutable without recording the access in the
+    /// remembered set.
+    ///
+    /// ## Safety
+    ///
+    /// The caller must either record all accessed old items using
+    /// [`Arena::remember`], or must not store references to heap data into old
+    /// items.
+    #[inline(always)]
+    pub(crate) unsafe fn as_mut_unremembered(&mut self) -> &mut V {
+        &mut self.data
+    }
+
+    /// Returns the remembered set of the arena.
+    pub(crate) fn remembered_set(&self) -> &RememberedSet {
+        &self.remembered_set
+    }
+
+    /// Get the arena vector as mutable together with the remembered set,
+    /// without recording the access in the remembered set.
+    ///
+    /// ## Safety
+    ///
+    /// Same as [`Arena::as_mut_unremembered`].
+    #[inline(always)]
+    pub(crate) unsafe fn split_mut_unremembered(&mut self) -> (&mut V, &RememberedSet) {
+        (&mut self.data, &self.remembered_set)
+    }
+}
+
+impl<T> Arena<Vec<T>> {
+    /// Get an item in the arena as mutable, recording the access in the
+    /// remembered set.
+    #[inline]
+    pub(crate) fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.remembered_set.remember(index);
+        self.data.get_mut(index)
+    }
+
+    /// Appends a new item to the back of the arena.
+    ///
+    /// New items are always part of the young generation, so this does not
+    /// record anything in the remembered set.
+    #[inline]
+    pub(crate) fn push(&mut self, value: T) {
+        self.data.push(value);
+    }
+
+    /// Get the last item in the arena as mutable, recording the access in the
+    /// remembered set.
+    #[inline]
+    pub(crate) fn last_mut(&mut self) -> Option<&mut T> {
+        let index = self.data.len().checked_sub(1)?;
+        self.get_mut(index)
+    }
+
+    /// Returns the remaining spare capacity of the arena.
+    ///
+    /// The spare capacity is never part of the old generation, so this does
+    /// not record anything in the remembered set.
+    #[inline]
+    pub(crate) fn spare_capacity_mut(&mut self) -> &mut [MaybeUninit<T>] {
+        self.data.spare_capacity_mut()
+    }
+
+    /// Forces the length of the arena to `new_len`.
+    ///
+    /// ## Safety
+    ///
+    /// See [`Vec::set_len`].
+    #[inline]
+    pub(crate) unsafe fn set_len(&mut self, new_len: usize) {
+        if new_len < self.remembered_set.old_len() {
+            // Items in the old generation are being reused: we no longer know
+            // what they contain.
+            self.remembered_set.remember_all();
+        }
+        // SAFETY: Guaranteed by caller.
+        unsafe { self.data.set_len(new_len) };
+    }
+
+    /// Clones the items in `range` to the back of the arena.
+    #[inline]
+    pub(crate) fn extend_from_within(&mut self, range: Range<usize>)
+    where
+        T: Clone,
+    {
+        self.data.extend_from_within(range);
+    }
+
+    /// Reserves capacity for at least `additional` more items.
+    #[inline]
+    pub(crate) fn reserve(&mut self, additional: usize) {
+        self.data.reserve(additional);
+    }
+
+    /// Tries to reserve capacity for at least `additional` more items.
+    #[inline]
+    pub(crate) fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.data.try_reserve(additional)
+    }
+
+    /// Returns the range of indexes in the arena that belong to the old
+    /// generation.
+    pub(crate) fn old_range(&self) -> Range<u32> {
+        0..self.remembered_set.old_len().min(self.data.len()) as u32
+    }
+
+    /// Promote all items in the arena into the old generation and clear the
+    /// remembered set.
+    pub(crate) fn promote(&mut self) {
+        self.remembered_set.reset(self.data.len());
+    }
+}
+
+impl<T: SoAble> Arena<SoAVec<T>> {
+    /// Get an item in the arena as mutable, recording the access in the
+    /// remembered set.
+    #[inline]
+    pub(crate) fn get_mut(&mut self, index: u32) -> Option<T::Mut<'_>> {
+        self.remembered_set.remember(index as usize);
+        self.data.get_mut(index)
+    }
+
+    /// Appends a new item to the back of the arena.
+    ///
+    /// New items are always part of the young generation, so this does not
+    /// record anything in the remembered set.
+    #[inline]
+    pub(crate) fn push(&mut self, value: T) -> Result<(), impl core::fmt::Debug> {
+        self.data.push(value)
+    }
+
+    /// Returns the range of indexes in the arena that belong to the old
+    /// generation.
+    pub(crate) fn old_range(&self) -> Range<u32> {
+        0..(self.remembered_set.old_len() as u32).min(self.data.len())
+    }
+
+    /// Promote all items in the arena into the old generation and clear the
+    /// remembered set.
+    pub(crate) fn promote(&mut self) {
+        self.remembered_set.reset(self.data.len() as usize);
+    }
+}
+
+impl<V> Deref for Arena<V> {
+    type Target = V;
+
+    #[inline(always)]
+    fn deref(&self) -> &Self::Target {
+        &self.data
+    }
+}
+
+impl<V> AsRef<V> for Arena<V> {
+    #[inline(always)]
+    fn as_ref(&self) -> &V {
+        &self.data
+    }
+}
+
+impl<V> AsMut<Arena<V>> for Arena<V> {
+    #[inline(always)]
+    fn as_mut(&mut self) -> &mut Arena<V> {
+        self
+    }
+}
+
+impl<V> DerefMut for Arena<V> {
+    /// Get the arena vector as mutable. This conservatively records all old
+    /// items as changed.
+    #[inline(always)]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.remembered_set.remember_all();
+        &mut self.data
+    }
+}
+
+/// Set of old generation items in an arena that may have been changed since
+/// the last garbage collection.
+///
+/// Only items in the old generation are recorded: young items are always
+/// traced by a young generation garbage collection.
+#[derive(Debug, Default)]
+pub(crate) struct RememberedSet {
+    /// Number of items in the old generation.
+    old_len: usize,
+    /// One bit per old generation item; a set bit means that the item may
+    /// have been changed since the last garbage collection.
+    cards: Box<[u64]>,
+    /// If set, all old generation items may have been changed.
+    all: bool,
+}
+
+impl RememberedSet {
+    fn new() -> Self {
+        Self {
+            old_len: 0,
+            cards: Box::default(),
+            all: false,
+        }
+    }
+
+    /// Number of items in the old generation.
+    #[inline(always)]
+    pub(crate) fn old_len(&self) -> usize {
+        self.old_len
+    }
+
+    #[inline(always)]
+    fn remember(&mut self, index: usize) {
+        if index < self.old_len {
+            self.cards[index / 64] |= 1 << (index % 64);
+        }
+    }
+
+    #[inline(always)]
+    fn remember_all(&mut self) {
+        self.all = true;
+    }
+
+    /// Iterate over the indexes of all remembered old generation items in
+    /// ascending order.
+    pub(crate) fn iter(&self) -> impl Iterator<Item = usize> + '_ {
+        let all = self.all.then_some(0..self.old_len);
+        let cards = (!self.all).then(|| {
+            self.cards
+                .iter()
+                .enumerate()
+                .filter(|(_, card)| **card != 0)
+                .flat_map(|(i, &card)| {
+                    (0..64)
+                        .filter(move |bit| card & (1 << bit) != 0)
+                        .map(move |bit| i * 64 + bit)
+                })
+        });
+        all.into_iter().flatten().chain(cards.into_iter().flatten())
+    }
+
+    /// Returns true if the item at the given index must be traced and swept
+    /// by a young generation garbage collection: that is, if the item is
+    /// young or a remembered old item.
+    #[inline]
+    pub(crate) fn is_young_or_remembered(&self, index: usize) -> bool {
+        index >= self.old_len || self.all || self.cards[index / 64] & (1 << (index % 64)) != 0
+    }
+
+    /// Move the first `len` items into the old generation and forget all
+    /// remembered items.
+    fn reset(&mut self, len: usize) {
+        self.old_len = len;
+        self.all = false;
+        let card_count = len.div_ceil(64);
+        if self.cards.len() == card_count {
+            self.cards.fill(0);
+        } else {
+            self.cards = vec![0; card_count].into_boxed_slice();
+        }
+    }
+}
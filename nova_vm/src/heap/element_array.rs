@@ -11,10 +11,10 @@ use super::{
 use crate::{
     ecmascript::{Agent, Function, PropertyDescriptor, PropertyKey, Value},
     engine::{Bindable, NoGcScope, bindable_handle},
-    heap::HeapIndexHandle,
+    heap::{Arena, HeapIndexHandle},
 };
 use core::mem::MaybeUninit;
-use std::collections::{TryReserveError, hash_map::Entry};
+use std::collections::{BTreeMap, TryReserveError, hash_map::Entry};
 
 /// Shared access to an element storage.
 pub(crate) struct ElementStorageRef<'a, 'gc> {
@@ -117,6 +117,18 @@ pub(crate) enum ElementArrayKey {
     E24,
     /// up to 4294967296 elements
     E32,
+    /// Sparse storage of up to 4294967295 elements
+    ///
+    /// Only present elements are stored; see [`SparseElements`].
+    ///
+    /// Only the [`ElementsVector`] of an Array can be sparse: object property
+    /// storage and property keys always use dense storage, and
+    /// `ElementArrayKey::from` never returns this key. An Array's
+    /// ElementsVector checks [`ElementsVector::is_sparse`] before touching
+    /// dense storage, and its capacity is [`u32::MAX`] so it never needs to
+    /// grow. The dense storage operations of [`ElementArrays`] thus never see
+    /// this key; see [`ElementArrayKey::unreachable_sparse`].
+    Sparse,
 }
 
 impl ElementArrayKey {
@@ -134,10 +146,20 @@ impl ElementArrayKey {
             ElementArrayKey::E12 => 2u32.pow(12),
             ElementArrayKey::E16 => 2u32.pow(16),
             ElementArrayKey::E24 => 2u32.pow(24),
-            ElementArrayKey::E32 => u32::MAX,
+            ElementArrayKey::E32 | ElementArrayKey::Sparse => u32::MAX,
         }
     }
 
+    /// Called by operations on dense storage when they are given the
+    /// [`ElementArrayKey::Sparse`] key, which is only used by the elements
+    /// of Arrays and never passed to dense storage operations.
+    #[cold]
+    #[inline(never)]
+    #[track_caller]
+    pub(crate) fn unreachable_sparse() -> ! {
+        unreachable!("sparse elements passed to a dense storage operation")
+    }
+
     /// Turn the ElementArrayKey into an intrinsic one.
     ///
     /// This is needed for intrinsic object shapes.
@@ -267,8 +289,48 @@ impl<'gc> ElementsVector<'gc> {
         arena.as_mut().get_element_storage_mut(self)
     }
 
+    /// Returns true if the elements vector uses sparse storage.
+    ///
+    /// Sparse storage only holds present elements; it has no value slice and
+    /// must be accessed through [`ElementsVector::get_sparse`].
+    #[inline]
+    pub(crate) fn is_sparse(&self) -> bool {
+        self.cap == ElementArrayKey::Sparse
+    }
+
+    /// Get shared access to the sparse storage of an array.
+    ///
+    /// ## Panics
+    ///
+    /// If the elements vector is not sparse.
+    pub(crate) fn get_sparse<'a>(
+        &self,
+        arena: &'a impl AsRef<ElementArrays>,
+    ) -> &'a SparseElements {
+        debug_assert!(self.is_sparse());
+        &arena.as_ref().sparse[self.elements_index]
+    }
+
+    /// Get exclusive access to the sparse storage of an array.
+    ///
+    /// ## Panics
+    ///
+    /// If the elements vector is not sparse.
+    pub(crate) fn get_sparse_mut<'a>(
+        &self,
+        arena: &'a mut impl AsMut<ElementArrays>,
+    ) -> &'a mut SparseElements {
+        debug_assert!(self.is_sparse());
+        &mut arena.as_mut().sparse[self.elements_index]
+    }
+
     /// An elements vector is simple if it contains no accessor descriptors.
+    ///
+    /// Sparse elements vectors are never simple.
     pub(crate) fn is_simple(&self, arena: &impl AsRef<ElementArrays>) -> bool {
+        if self.is_sparse() {
+            return false;
+        }
         let storage = arena.as_ref().get_element_storage(self);
         storage.descriptors.is_none_or(|hashmap| {
             !hashmap
@@ -278,13 +340,23 @@ impl<'gc> ElementsVector<'gc> {
     }
 
     /// An elements vector is trivial if it contains no descriptors.
+    ///
+    /// Sparse elements vectors are never trivial.
     pub(crate) fn is_trivial(&self, arena: &impl AsRef<ElementArrays>) -> bool {
+        if self.is_sparse() {
+            return false;
+        }
         let ElementStorageRef { descriptors, .. } = arena.as_ref().get_element_storage(self);
         descriptors.is_none()
     }
 
     /// An elements vector is dense if it contains no holes or getters.
+    ///
+    /// Sparse elements vectors are never dense.
     pub(crate) fn is_dense(&self, arena: &impl AsRef<ElementArrays>) -> bool {
+        if self.is_sparse() {
+            return false;
+        }
         let ElementStorageRef {
             descriptors,
             values,
@@ -315,7 +387,7 @@ impl<'gc> ElementsVector<'gc> {
         &mut self,
         elements: &mut ElementArrays,
         new_len: u32,
-    ) -> Result<(), TryReserveError> {
+    ) -> Result<(), AllocationError> {
         if new_len <= self.cap() {
             // Enough capacity present already
             return Ok(());
@@ -324,12 +396,51 @@ impl<'gc> ElementsVector<'gc> {
         elements.reserve_elements(self, new_len)
     }
 
+    /// Reserve room for a length of `new_len` where the elements between the
+    /// current length and `new_len` are holes.
+    ///
+    /// If the resulting array would be mostly holes, the elements vector is
+    /// switched to sparse storage instead of allocating the dense storage.
+    pub(crate) fn reserve_holey(
+        &mut self,
+        elements: &mut ElementArrays,
+        new_len: u32,
+    ) -> Result<(), AllocationError> {
+        if self.is_sparse() || new_len <= self.cap() {
+            return Ok(());
+        }
+        if should_be_sparse(new_len, self.len) {
+            elements.make_sparse(self);
+            return Ok(());
+        }
+        elements.reserve_elements(self, new_len)
+    }
+
+    /// Switch a sparse elements vector back to dense storage if enough of its
+    /// elements are present.
+    ///
+    /// Failing to allocate the dense storage is not an error: the elements
+    /// vector simply stays sparse.
+    pub(crate) fn maybe_make_dense(&mut self, elements: &mut ElementArrays) {
+        if self.is_sparse() && should_be_dense(self.len, self.get_sparse(elements).count()) {
+            let _ = elements.make_dense(self);
+        }
+    }
+
     pub(crate) fn push(
         &mut self,
         elements: &mut ElementArrays,
         value: Option<Value>,
         descriptor: Option<ElementDescriptor>,
-    ) -> Result<(), TryReserveError> {
+    ) -> Result<(), AllocationError> {
+        if self.is_sparse() {
+            // Note: array indexes are below u32::MAX, so sparse elements are
+            // never full.
+            debug_assert!(!self.is_full());
+            elements.sparse[self.elements_index].set(self.len, value, descriptor);
+            self.len += 1;
+            return Ok(());
+        }
         if self.is_full() {
             self.reserve(elements, self.len() + 1)?;
         }
@@ -368,6 +479,7 @@ impl<'gc> ElementsVector<'gc> {
             ElementArrayKey::E32 => {
                 &mut elements.e2pow32.values[self.elements_index][self.len as usize]
             }
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         };
         *next_over_end = value.map(Value::unbind);
         if let Some(descriptor) = descriptor {
@@ -384,6 +496,7 @@ impl<'gc> ElementsVector<'gc> {
                 ElementArrayKey::E16 => &mut elements.e2pow16.descriptors,
                 ElementArrayKey::E24 => &mut elements.e2pow24.descriptors,
                 ElementArrayKey::E32 => &mut elements.e2pow32.descriptors,
+                ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
             };
             descriptors_map
                 .entry(self.elements_index.unbind())
@@ -418,6 +531,7 @@ impl HeapMarkAndSweep for ElementsVector<'static> {
             ElementArrayKey::E16 => queues.e_2_16.push(*elements_index),
             ElementArrayKey::E24 => queues.e_2_24.push(*elements_index),
             ElementArrayKey::E32 => queues.e_2_32.push(*elements_index),
+            ElementArrayKey::Sparse => queues.e_sparse.push(*elements_index),
         }
     }
 
@@ -441,6 +555,7 @@ impl HeapMarkAndSweep for ElementsVector<'static> {
             ElementArrayKey::E16 => compactions.e_2_16.shift_index(elements_index),
             ElementArrayKey::E24 => compactions.e_2_24.shift_index(elements_index),
             ElementArrayKey::E32 => compactions.e_2_32.shift_index(elements_index),
+            ElementArrayKey::Sparse => compactions.e_sparse.shift_index(elements_index),
         };
     }
 }
@@ -1219,7 +1334,7 @@ bindable_handle!(ElementDescriptor);
 
 #[derive(Debug, Default)]
 pub(crate) struct ElementArray<const N: usize> {
-    pub values: Vec<[Option<Value<'static>>; N]>,
+    pub values: Arena<Vec<[Option<Value<'static>>; N]>>,
     pub descriptors: AHashMap<ElementIndex<'static>, AHashMap<u32, ElementDescriptor<'static>>>,
 }
 
@@ -1227,11 +1342,16 @@ impl<const N: usize> ElementArray<N> {
     pub(crate) fn with_capacity(cap: usize) -> Self {
         const { assert!(N.is_power_of_two()) }
         Self {
-            values: Vec::with_capacity(cap),
+            values: Arena::new(Vec::with_capacity(cap)),
             descriptors: Default::default(),
         }
     }
 
+    /// Number of bytes used by the element arrays.
+    fn byte_size(&self) -> usize {
+        self.values.len() * core::mem::size_of::<[Option<Value>; N]>()
+    }
+
     fn get_values(&self, vector: &ElementsVector) -> &[Option<Value<'static>>] {
         self.get_values_raw(vector.elements_index, vector.len())
     }
@@ -1295,7 +1415,7 @@ impl<const N: usize> ElementArray<N> {
         &mut self,
         source: &[Option<Value>],
         descriptors: Option<AHashMap<u32, ElementDescriptor>>,
-    ) -> Result<ElementIndex<'static>, TryReserveError> {
+    ) -> Result<ElementIndex<'static>, AllocationError> {
         let length = source.len();
         self.values.try_reserve(1)?;
         if descriptors.is_some() {
@@ -1350,7 +1470,7 @@ impl<const N: usize> ElementArray<N> {
         &mut self,
         source: ElementStorageRef,
         removal_index: u32,
-    ) -> Result<ElementIndex<'static>, TryReserveError> {
+    ) -> Result<ElementIndex<'static>, AllocationError> {
         let source_length = source.values.len();
         let target_length = source_length - 1;
         self.values.try_reserve(1)?;
@@ -1423,21 +1543,160 @@ pub(crate) type ElementArray2Pow24 = ElementArray<16777216>;
 /// Element arrays of up to 4294967296 elements
 pub(crate) type ElementArray2Pow32 = ElementArray<4294967296>;
 
+/// Arrays that fit into [`ElementArrayKey::E16`] storage are always dense.
+const SPARSE_ELEMENTS_MIN_LENGTH: u32 = 2u32.pow(16) + 1;
+
+/// Returns true if an elements vector of length `len` with `count` present
+/// elements should use sparse storage.
+fn should_be_sparse(len: u32, count: u32) -> bool {
+    len >= SPARSE_ELEMENTS_MIN_LENGTH && count < len / 8
+}
+
+/// Returns true if a sparse elements vector of length `len` with `count`
+/// present elements should be switched back to dense storage.
+///
+/// The thresholds of sparse and dense storage are kept apart to avoid
+/// switching back and forth on every other write.
+fn should_be_dense(len: u32, count: u32) -> bool {
+    len < SPARSE_ELEMENTS_MIN_LENGTH || count >= len / 2
+}
+
+/// Sparse element storage.
+///
+/// Only present elements are stored, in ascending index order. Like in dense
+/// storage, a None value is an accessor property and has a descriptor.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct SparseElements {
+    pub(crate) values: BTreeMap<u32, Option<Value<'static>>>,
+    pub(crate) descriptors: AHashMap<u32, ElementDescriptor<'static>>,
+}
+
+impl SparseElements {
+    /// Number of present elements.
+    pub(crate) fn count(&self) -> u32 {
+        self.values.len() as u32
+    }
+
+    /// Get the value and descriptor of a present element.
+    pub(crate) fn get<'gc>(
+        &self,
+        index: u32,
+    ) -> Option<(Option<Value<'gc>>, Option<ElementDescriptor<'gc>>)> {
+        let value = self.values.get(&index)?;
+        Some((*value, self.descriptors.get(&index).copied()))
+    }
+
+    /// Index of the first present element at or after `index`.
+    pub(crate) fn next_index(&self, index: u32) -> Option<u32> {
+        self.values.range(index..).next().map(|(index, _)| *index)
+    }
+
+    /// Index of the last present element at or before `index`.
+    pub(crate) fn previous_index(&self, index: u32) -> Option<u32> {
+        self.values
+            .range(..=index)
+            .next_back()
+            .map(|(index, _)| *index)
+    }
+
+    /// Set the value and descriptor of an element, making it present.
+    pub(crate) fn set(
+        &mut self,
+        index: u32,
+        value: Option<Value>,
+        descriptor: Option<ElementDescriptor>,
+    ) {
+        self.values.insert(index, value.unbind());
+        if let Some(descriptor) = descriptor {
+            self.descriptors.insert(index, descriptor.unbind());
+        } else {
+            self.descriptors.remove(&index);
+        }
+    }
+
+    /// Remove an element, making it a hole.
+    pub(crate) fn remove(&mut self, index: u32) {
+        self.values.remove(&index);
+        self.descriptors.remove(&index);
+    }
+
+    /// Remove all elements at or above `new_len`.
+    ///
+    /// Non-configurable elements cannot be removed: if one is found, only the
+    /// elements above it are removed and its index is returned as an error.
+    pub(crate) fn truncate(&mut self, new_len: u32) -> Result<(), u32> {
+        let blocker = self
+            .descriptors
+            .iter()
+            .filter(|(index, descriptor)| **index >= new_len && !descriptor.is_configurable())
+            .map(|(index, _)| *index)
+            .max();
+        let split_at = blocker.map_or(new_len, |index| index + 1);
+        let removed = self.values.split_off(&split_at);
+        if !self.descriptors.is_empty() {
+            for index in removed.keys() {
+                self.descriptors.remove(index);
+            }
+        }
+        match blocker {
+            Some(index) => Err(index),
+            None => Ok(()),
+        }
+    }
+
+    /// Number of bytes used by the sparse storage, approximately.
+    pub(crate) fn byte_size(&self) -> usize {
+        self.values.len() * core::mem::size_of::<(u32, Option<Value>)>()
+            + self.descriptors.len() * core::mem::size_of::<(u32, ElementDescriptor)>()
+    }
+}
+
+impl HeapMarkAndSweep for SparseElements {
+    fn mark_values(&self, queues: &mut WorkQueues) {
+        let Self {
+            values,
+            descriptors,
+        } = self;
+        values.values().for_each(|value| value.mark_values(queues));
+        descriptors
+            .values()
+            .for_each(|descriptor| descriptor.mark_values(queues));
+    }
+
+    fn sweep_values(&mut self, compactions: &CompactionLists) {
+        let Self {
+            values,
+            descriptors,
+        } = self;
+        values
+            .values_mut()
+            .for_each(|value| value.sweep_values(compactions));
+        descriptors
+            .values_mut()
+            .for_each(|descriptor| descriptor.sweep_values(compactions));
+    }
+}
+
 /// Element arrays of up to 16 elements
 #[derive(Debug, Default)]
 #[repr(transparent)]
 pub(crate) struct PropertyKeyArray<const N: usize> {
-    pub keys: Vec<[Option<PropertyKey<'static>>; N]>,
+    pub keys: Arena<Vec<[Option<PropertyKey<'static>>; N]>>,
 }
 
 impl<const N: usize> PropertyKeyArray<N> {
     pub(crate) fn with_capacity(cap: usize) -> Self {
         const { assert!(N.is_power_of_two()) }
         Self {
-            keys: Vec::with_capacity(cap),
+            keys: Arena::new(Vec::with_capacity(cap)),
         }
     }
 
+    /// Number of bytes used by the property key arrays.
+    fn byte_size(&self) -> usize {
+        self.keys.len() * core::mem::size_of::<[Option<PropertyKey>; N]>()
+    }
+
     fn get_raw<'a>(&self, keys_index: PropertyKeyIndex<'a>, len: u32) -> &[PropertyKey<'a>] {
         let keys = &self.keys[keys_index.get_index()].as_slice()[0..len as usize];
         debug_assert!(keys.iter().all(|k| k.is_some()));
@@ -1448,13 +1707,16 @@ impl<const N: usize> PropertyKeyArray<N> {
     }
 
     fn get_uninit(&mut self, index: PropertyKeyIndex) -> &mut [Option<PropertyKey<'static>>] {
-        self.keys[index.get_index()].as_mut_slice()
+        self.keys
+            .get_mut(index.get_index())
+            .expect("Invalid PropertyKeyIndex")
+            .as_mut_slice()
     }
 
     fn push(
         &mut self,
         source: &[PropertyKey],
-    ) -> Result<PropertyKeyIndex<'static>, TryReserveError> {
+    ) -> Result<PropertyKeyIndex<'static>, AllocationError> {
         let length = source.len();
         self.keys.try_reserve(1)?;
         let remaining = self.keys.spare_capacity_mut();
@@ -1486,7 +1748,7 @@ impl<const N: usize> PropertyKeyArray<N> {
         &mut self,
         source: &[PropertyKey],
         removal_index: usize,
-    ) -> Result<PropertyKeyIndex<'static>, TryReserveError> {
+    ) -> Result<PropertyKeyIndex<'static>, AllocationError> {
         let source_length = source.len();
         let target_length = source_length - 1;
         self.keys.try_reserve(1)?;
@@ -1519,14 +1781,22 @@ impl<const N: usize> PropertyKeyArray<N> {
     }
 
     unsafe fn push_key(&mut self, index: PropertyKeyIndex, len: u32, key: PropertyKey) {
-        let keys = self.keys[index.get_index()].as_mut_slice();
+        let keys = self
+            .keys
+            .get_mut(index.get_index())
+            .expect("Invalid PropertyKeyIndex")
+            .as_mut_slice();
         let previous = keys[len as usize].replace(key.unbind());
         debug_assert!(previous.is_none());
     }
 
     unsafe fn remove(&mut self, index: PropertyKeyIndex, len: u32, removal_index: u32) {
         let len = usize::try_from(len).unwrap_or(usize::MAX);
-        let keys = &mut self.keys[index.get_index()].as_mut_slice()[..len];
+        let keys = &mut self
+            .keys
+            .get_mut(index.get_index())
+            .expect("Invalid PropertyKeyIndex")
+            .as_mut_slice()[..len];
         let Some(next_index) = usize::try_from(removal_index)
             .ok()
             .and_then(|i| i.checked_add(1))
@@ -1546,7 +1816,7 @@ impl<const N: usize> PropertyKeyArray<N> {
         &mut self,
         key_index: PropertyKeyIndex<'a>,
         len: u32,
-    ) -> Result<PropertyKeyIndex<'a>, TryReserveError> {
+    ) -> Result<PropertyKeyIndex<'a>, AllocationError> {
         self.keys.try_reserve(1)?;
         let start = key_index.get_index();
         let end = start.saturating_add(1);
@@ -1565,7 +1835,7 @@ impl<const N: usize> PropertyKeyArray<N> {
         key_index: PropertyKeyIndex<'a>,
         len: u32,
         removal_index: usize,
-    ) -> Result<PropertyKeyIndex<'a>, TryReserveError> {
+    ) -> Result<PropertyKeyIndex<'a>, AllocationError> {
         self.keys.try_reserve(1)?;
         let len = len as usize;
         let start = key_index.get_index();
@@ -1606,6 +1876,33 @@ pub(crate) type PropertyKeyArray2Pow24 = PropertyKeyArray<16777216>;
 /// Property key arrays of up to 4294967296 elements
 pub(crate) type PropertyKeyArray2Pow32 = PropertyKeyArray<4294967296>;
 
+/// Error returned when an allocation in the engine heap fails.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AllocationError {
+    /// The allocation would take the heap over its size limit, see
+    /// [`AgentOptions::max_heap_bytes`](crate::ecmascript::AgentOptions::max_heap_bytes).
+    HeapLimitExceeded,
+    /// The system allocator failed to allocate the memory.
+    TryReserve(TryReserveError),
+}
+
+impl From<TryReserveError> for AllocationError {
+    fn from(error: TryReserveError) -> Self {
+        Self::TryReserve(error)
+    }
+}
+
+impl core::fmt::Display for AllocationError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::HeapLimitExceeded => f.write_str("heap size limit exceeded"),
+            Self::TryReserve(error) => error.fmt(f),
+        }
+    }
+}
+
+impl core::error::Error for AllocationError {}
+
 #[derive(Debug)]
 pub(crate) struct ElementArrays {
     /// up to 2 elements
@@ -1641,19 +1938,192 @@ pub(crate) struct ElementArrays {
     /// up to 4294967296 elements
     pub(crate) k2pow32: PropertyKeyArray2Pow32,
     pub(crate) e2pow32: ElementArray2Pow32,
+    /// sparse elements of up to 4294967295 elements
+    pub(crate) sparse: Arena<Vec<SparseElements>>,
+    /// Maximum number of bytes the heap may use.
+    pub(crate) max_bytes: Option<usize>,
+    /// Counts bytes allocated for backing stores since last garbage
+    /// collection.
+    pub(crate) alloc_counter: usize,
 }
 
 impl ElementArrays {
+    /// Calls `f` with the name, number of arrays, and number of bytes used by
+    /// each element and property key array size class.
+    pub(crate) fn for_each_arena(&self, f: &mut impl FnMut(&'static str, usize, usize)) {
+        let Self {
+            k2pow1,
+            e2pow1,
+            k2pow2,
+            e2pow2,
+            k2pow3,
+            e2pow3,
+            k2pow4,
+            e2pow4,
+            k2pow6,
+            e2pow6,
+            k2pow8,
+            e2pow8,
+            k2pow10,
+            e2pow10,
+            k2pow12,
+            e2pow12,
+            k2pow16,
+            e2pow16,
+            k2pow24,
+            e2pow24,
+            k2pow32,
+            e2pow32,
+            sparse,
+            max_bytes: _,
+            alloc_counter: _,
+        } = self;
+        f(
+            "property_key_arrays_2pow1",
+            k2pow1.keys.len(),
+            k2pow1.byte_size(),
+        );
+        f(
+            "element_arrays_2pow1",
+            e2pow1.values.len(),
+            e2pow1.byte_size(),
+        );
+        f(
+            "property_key_arrays_2pow2",
+            k2pow2.keys.len(),
+            k2pow2.byte_size(),
+        );
+        f(
+            "element_arrays_2pow2",
+            e2pow2.values.len(),
+            e2pow2.byte_size(),
+        );
+        f(
+            "property_key_arrays_2pow3",
+            k2pow3.keys.len(),
+            k2pow3.byte_size(),
+        );
+        f(
+            "element_arrays_2pow3",
+            e2pow3.values.len(),
+            e2pow3.byte_size(),
+        );
+        f(
+            "property_key_arrays_2pow4",
+            k2pow4.keys.len(),
+            k2pow4.byte_size(),
+        );
+        f(
+            "element_arrays_2pow4",
+            e2pow4.values.len(),
+            e2pow4.byte_size(),
+        );
+        f(
+            "property_key_arrays_2pow6",
+            k2pow6.keys.len(),
+            k2pow6.byte_size(),
+        );
+        f(
+            "element_arrays_2pow6",
+            e2pow6.values.len(),
+            e2pow6.byte_size(),
+        );
+        f(
+            "property_key_arrays_2pow8",
+            k2pow8.keys.len(),
+            k2pow8.byte_size(),
+        );
+        f(
+            "element_arrays_2pow8",
+            e2pow8.values.len(),
+            e2pow8.byte_size(),
+        );
+        f(
+            "property_key_arrays_2pow10",
+            k2pow10.keys.len(),
+            k2pow10.byte_size(),
+        );
+        f(
+            "element_arrays_2pow10",
+            e2pow10.values.len(),
+            e2pow10.byte_size(),
+        );
+        f(
+            "property_key_arrays_2pow12",
+            k2pow12.keys.len(),
+            k2pow12.byte_size(),
+        );
+        f(
+            "element_arrays_2pow12",
+            e2pow12.values.len(),
+            e2pow12.byte_size(),
+        );
+        f(
+            "property_key_arrays_2pow16",
+            k2pow16.keys.len(),
+            k2pow16.byte_size(),
+        );
+        f(
+            "element_arrays_2pow16",
+            e2pow16.values.len(),
+            e2pow16.byte_size(),
+        );
+        f(
+            "property_key_arrays_2pow24",
+            k2pow24.keys.len(),
+            k2pow24.byte_size(),
+        );
+        f(
+            "element_arrays_2pow24",
+            e2pow24.values.len(),
+            e2pow24.byte_size(),
+        );
+        f(
+            "property_key_arrays_2pow32",
+            k2pow32.keys.len(),
+            k2pow32.byte_size(),
+        );
+        f(
+            "element_arrays_2pow32",
+            e2pow32.values.len(),
+            e2pow32.byte_size(),
+        );
+        f(
+            "element_arrays_sparse",
+            sparse.len(),
+            sparse.iter().map(SparseElements::byte_size).sum(),
+        );
+    }
+
+    /// Counts the allocation of a new backing store with the given capacity.
+    ///
+    /// A backing store larger than the heap size limit can never be allocated
+    /// and results in an error. Smaller allocations are checked against the
+    /// limit together with the rest of the heap at the next garbage
+    /// collection point.
+    fn check_heap_limit(&mut self, cap: ElementArrayKey) -> Result<(), AllocationError> {
+        let Some(max_bytes) = self.max_bytes else {
+            return Ok(());
+        };
+        let requested = cap.capacity() as usize * core::mem::size_of::<Option<Value>>();
+        if requested > max_bytes {
+            return Err(AllocationError::HeapLimitExceeded);
+        }
+        self.alloc_counter += requested;
+        Ok(())
+    }
+
     fn push_values(
         &mut self,
         key: ElementArrayKey,
         source: &[Option<Value>],
         descriptors: Option<AHashMap<u32, ElementDescriptor<'static>>>,
-    ) -> Result<ElementIndex<'static>, TryReserveError> {
+    ) -> Result<ElementIndex<'static>, AllocationError> {
         debug_assert_eq!(
             core::mem::size_of::<Option<[Option<Value>; 1]>>(),
             core::mem::size_of::<[Option<Value>; 1]>()
         );
+        self.check_heap_limit(key)?;
         let ElementArrays {
             e2pow1,
             e2pow2,
@@ -1684,14 +2154,64 @@ impl ElementArrays {
             ElementArrayKey::E16 => e2pow16.push(source, descriptors),
             ElementArrayKey::E24 => e2pow24.push(source, descriptors),
             ElementArrayKey::E32 => e2pow32.push(source, descriptors),
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
+        }
+    }
+
+    /// Move the elements of a dense elements vector into a new sparse
+    /// storage.
+    fn make_sparse(&mut self, elements_vector: &mut ElementsVector) {
+        debug_assert!(!elements_vector.is_sparse());
+        let mut sparse = SparseElements::default();
+        let ElementStorageRef {
+            values,
+            descriptors,
+        } = self.get_element_storage(elements_vector);
+        for (index, value) in values.iter().enumerate() {
+            let index = index as u32;
+            let descriptor = descriptors.and_then(|descriptors| descriptors.get(&index));
+            if value.is_none() && descriptor.is_none() {
+                // Hole.
+                continue;
+            }
+            sparse.set(index, *value, descriptor.copied());
         }
+        self.sparse.push(sparse);
+        elements_vector.elements_index = ElementIndex::from_index(self.sparse.len() - 1);
+        elements_vector.cap = ElementArrayKey::Sparse;
+    }
+
+    /// Move the elements of a sparse elements vector into a new dense
+    /// storage.
+    fn make_dense(&mut self, elements_vector: &mut ElementsVector) -> Result<(), AllocationError> {
+        debug_assert!(elements_vector.is_sparse());
+        let cap = ElementArrayKey::from(elements_vector.len);
+        let sparse = &self.sparse[elements_vector.elements_index];
+        let descriptors = if sparse.descriptors.is_empty() {
+            None
+        } else {
+            Some(sparse.descriptors.clone())
+        };
+        let index = self.push_values(cap, &[], descriptors)?;
+        let SparseElements { values, .. } =
+            core::mem::take(&mut self.sparse[elements_vector.elements_index]);
+        if !values.is_empty() {
+            let ElementStorageUninit { values: target, .. } =
+                self.get_element_storage_uninit_raw(index, cap);
+            for (index, value) in values {
+                target[index as usize] = value;
+            }
+        }
+        elements_vector.elements_index = index;
+        elements_vector.cap = cap;
+        Ok(())
     }
 
     fn reserve_elements(
         &mut self,
         elements_vector: &mut ElementsVector,
         new_len: u32,
-    ) -> Result<(), TryReserveError> {
+    ) -> Result<(), AllocationError> {
         if new_len <= elements_vector.cap.capacity() {
             // Already big enough, no need to grow
             return Ok(());
@@ -1710,9 +2230,10 @@ impl ElementArrays {
         cap: &mut ElementArrayKey,
         old_len: u32,
         new_len: u32,
-    ) -> Result<(), TryReserveError> {
+    ) -> Result<(), AllocationError> {
         let new_key = ElementArrayKey::from(new_len);
         assert_ne!(new_key, *cap);
+        self.check_heap_limit(new_key)?;
         let ElementArrays {
             e2pow1,
             e2pow2,
@@ -1753,6 +2274,7 @@ impl ElementArrays {
                     ElementArrayKey::E16 => e2pow16.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E24 => e2pow24.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E32 => e2pow32.get_descriptors_and_values_raw(*index, old_len),
+                    ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
                 };
                 e2pow1.push(source, descriptors.cloned())
             }
@@ -1777,6 +2299,7 @@ impl ElementArrays {
                     ElementArrayKey::E16 => e2pow16.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E24 => e2pow24.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E32 => e2pow32.get_descriptors_and_values_raw(*index, old_len),
+                    ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
                 };
                 e2pow2.push(source, descriptors.cloned())
             }
@@ -1801,6 +2324,7 @@ impl ElementArrays {
                     ElementArrayKey::E16 => e2pow16.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E24 => e2pow24.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E32 => e2pow32.get_descriptors_and_values_raw(*index, old_len),
+                    ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
                 };
                 e2pow3.push(source, descriptors.cloned())
             }
@@ -1823,6 +2347,7 @@ impl ElementArrays {
                     ElementArrayKey::E16 => e2pow16.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E24 => e2pow24.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E32 => e2pow32.get_descriptors_and_values_raw(*index, old_len),
+                    ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
                 };
                 e2pow4.push(source, descriptors.cloned())
             }
@@ -1845,6 +2370,7 @@ impl ElementArrays {
                     ElementArrayKey::E16 => e2pow16.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E24 => e2pow24.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E32 => e2pow32.get_descriptors_and_values_raw(*index, old_len),
+                    ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
                 };
                 e2pow6.push(source, descriptors.cloned())
             }
@@ -1867,6 +2393,7 @@ impl ElementArrays {
                     ElementArrayKey::E16 => e2pow16.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E24 => e2pow24.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E32 => e2pow32.get_descriptors_and_values_raw(*index, old_len),
+                    ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
                 };
                 e2pow8.push(source, descriptors.cloned())
             }
@@ -1889,6 +2416,7 @@ impl ElementArrays {
                     ElementArrayKey::E16 => e2pow16.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E24 => e2pow24.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E32 => e2pow32.get_descriptors_and_values_raw(*index, old_len),
+                    ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
                 };
                 e2pow10.push(source, descriptors.cloned())
             }
@@ -1911,6 +2439,7 @@ impl ElementArrays {
                     ElementArrayKey::E16 => e2pow16.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E24 => e2pow24.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E32 => e2pow32.get_descriptors_and_values_raw(*index, old_len),
+                    ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
                 };
                 e2pow12.push(source, descriptors.cloned())
             }
@@ -1933,6 +2462,7 @@ impl ElementArrays {
                     ElementArrayKey::E16 => unreachable!(),
                     ElementArrayKey::E24 => e2pow24.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E32 => e2pow32.get_descriptors_and_values_raw(*index, old_len),
+                    ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
                 };
                 e2pow16.push(source, descriptors.cloned())
             }
@@ -1955,6 +2485,7 @@ impl ElementArrays {
                     ElementArrayKey::E16 => e2pow16.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E24 => unreachable!(),
                     ElementArrayKey::E32 => e2pow32.get_descriptors_and_values_raw(*index, old_len),
+                    ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
                 };
                 e2pow24.push(source, descriptors.cloned())
             }
@@ -1977,9 +2508,11 @@ impl ElementArrays {
                     ElementArrayKey::E16 => e2pow16.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E24 => e2pow24.get_descriptors_and_values_raw(*index, old_len),
                     ElementArrayKey::E32 => unreachable!(),
+                    ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
                 };
                 e2pow32.push(source, descriptors.cloned())
             }
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         };
         *index = new_index?;
         *cap = new_key;
@@ -1992,7 +2525,7 @@ impl ElementArrays {
         cap: &mut ElementArrayKey,
         old_len: u32,
         additional: u32,
-    ) -> Result<(), TryReserveError> {
+    ) -> Result<(), AllocationError> {
         let new_len = old_len
             .checked_add(additional)
             .expect("Ridiculous amount of keys");
@@ -2009,7 +2542,7 @@ impl ElementArrays {
     pub(crate) fn allocate_elements_with_length(
         &mut self,
         length: usize,
-    ) -> Result<ElementsVector<'static>, TryReserveError> {
+    ) -> Result<ElementsVector<'static>, AllocationError> {
         let cap = ElementArrayKey::from(length);
         Self::allocate_elements_with_capacity(self, cap)
     }
@@ -2017,7 +2550,7 @@ impl ElementArrays {
     pub(crate) fn allocate_elements_with_capacity(
         &mut self,
         cap: ElementArrayKey,
-    ) -> Result<ElementsVector<'static>, TryReserveError> {
+    ) -> Result<ElementsVector<'static>, AllocationError> {
         Ok(ElementsVector {
             elements_index: self.push_values(cap, &[], None)?,
             cap,
@@ -2031,7 +2564,7 @@ impl ElementArrays {
         length: usize,
         values: &[Option<Value>],
         descriptors: Option<AHashMap<u32, ElementDescriptor<'static>>>,
-    ) -> Result<ElementsVector<'static>, TryReserveError> {
+    ) -> Result<ElementsVector<'static>, AllocationError> {
         let cap = ElementArrayKey::from(length);
         let len = length as u32;
         let elements_index = self.push_values(cap, values, descriptors)?;
@@ -2047,7 +2580,8 @@ impl ElementArrays {
     pub(crate) fn allocate_keys_with_capacity(
         &mut self,
         capacity: usize,
-    ) -> Result<(ElementArrayKey, PropertyKeyIndex<'static>), TryReserveError> {
+    ) -> Result<(ElementArrayKey, PropertyKeyIndex<'static>), AllocationError> {
+        self.check_heap_limit(ElementArrayKey::from(capacity))?;
         let Self {
             k2pow1,
             k2pow2,
@@ -2076,6 +2610,7 @@ impl ElementArrays {
             ElementArrayKey::E16 => k2pow16.push(&[])?,
             ElementArrayKey::E24 => k2pow24.push(&[])?,
             ElementArrayKey::E32 => k2pow32.push(&[])?,
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         };
         Ok((key, index))
     }
@@ -2087,7 +2622,7 @@ impl ElementArrays {
         index: PropertyKeyIndex<'a>,
         len: u32,
         key: PropertyKey<'a>,
-    ) -> Result<(ElementArrayKey, PropertyKeyIndex<'a>), TryReserveError> {
+    ) -> Result<(ElementArrayKey, PropertyKeyIndex<'a>), AllocationError> {
         let new_len = len.checked_add(1).expect("Ridiculous amount of keys");
         let (new_cap, new_key) = self.copy_keys_with_capacity(new_len as usize, cap, index, len)?;
         let Self {
@@ -2139,6 +2674,7 @@ impl ElementArrays {
             ElementArrayKey::E32 => {
                 k2pow32.get_uninit(new_key)[len as usize] = Some(key.unbind());
             }
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         }
         Ok((new_cap, new_key))
     }
@@ -2191,6 +2727,7 @@ impl ElementArrays {
             ElementArrayKey::E16 => unsafe { k2pow16.remove(index, *len, removal_index) },
             ElementArrayKey::E24 => unsafe { k2pow24.remove(index, *len, removal_index) },
             ElementArrayKey::E32 => unsafe { k2pow32.remove(index, *len, removal_index) },
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         }
         *len -= 1;
     }
@@ -2212,7 +2749,7 @@ impl ElementArrays {
         index: &mut PropertyKeyIndex,
         len: &mut u32,
         key: PropertyKey,
-    ) -> Result<(), TryReserveError> {
+    ) -> Result<(), AllocationError> {
         let Self {
             k2pow1,
             k2pow2,
@@ -2243,6 +2780,7 @@ impl ElementArrays {
                 ElementArrayKey::E16 => unsafe { k2pow16.push_key(*index, *len, key) },
                 ElementArrayKey::E24 => unsafe { k2pow24.push_key(*index, *len, key) },
                 ElementArrayKey::E32 => unsafe { k2pow32.push_key(*index, *len, key) },
+                ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
             }
         } else {
             // We need to grow our backing store.
@@ -2260,7 +2798,7 @@ impl ElementArrays {
         index: PropertyKeyIndex<'a>,
         len: u32,
         removal_index: usize,
-    ) -> Result<(ElementArrayKey, PropertyKeyIndex<'a>), TryReserveError> {
+    ) -> Result<(ElementArrayKey, PropertyKeyIndex<'a>), AllocationError> {
         if len <= 1 {
             // Removing the last key.
             debug_assert_eq!(removal_index, 0);
@@ -2296,6 +2834,7 @@ impl ElementArrays {
                 ElementArrayKey::E16 => k2pow16.push_within_with_removal(index, len, removal_index),
                 ElementArrayKey::E24 => k2pow24.push_within_with_removal(index, len, removal_index),
                 ElementArrayKey::E32 => k2pow32.push_within_with_removal(index, len, removal_index),
+                ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
             }
         } else {
             // Change in capacity.
@@ -2417,6 +2956,7 @@ impl ElementArrays {
                     k2pow24.push_with_removal(source, removal_index)
                 }
                 ElementArrayKey::E32 => unreachable!(),
+                ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
             }
         };
         Ok((new_cap, new_index?))
@@ -2431,7 +2971,7 @@ impl ElementArrays {
         cap: ElementArrayKey,
         index: PropertyKeyIndex<'a>,
         len: u32,
-    ) -> Result<(ElementArrayKey, PropertyKeyIndex<'a>), TryReserveError> {
+    ) -> Result<(ElementArrayKey, PropertyKeyIndex<'a>), AllocationError> {
         if capacity == 0 {
             return Ok((ElementArrayKey::Empty, PropertyKeyIndex::ZERO));
         }
@@ -2465,6 +3005,7 @@ impl ElementArrays {
                 ElementArrayKey::E16 => k2pow16.push_within(index, len),
                 ElementArrayKey::E24 => k2pow24.push_within(index, len),
                 ElementArrayKey::E32 => k2pow32.push_within(index, len),
+                ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
             }
         } else {
             // Change in capacity.
@@ -2480,7 +3021,7 @@ impl ElementArrays {
         dst_cap: ElementArrayKey,
         len: u32,
         removal_index: u32,
-    ) -> Result<ElementIndex<'a>, TryReserveError> {
+    ) -> Result<ElementIndex<'a>, AllocationError> {
         if dst_cap.capacity() == 0 {
             // Removing the last key.
             debug_assert_eq!(removal_index, 0);
@@ -2901,6 +3442,7 @@ impl ElementArrays {
                     };
                     e2pow32.push_with_removal(source, removal_index)
                 }
+                ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
             }
         }
     }
@@ -2912,7 +3454,8 @@ impl ElementArrays {
         index: PropertyKeyIndex,
         new_cap: ElementArrayKey,
         len: u32,
-    ) -> Result<PropertyKeyIndex<'a>, TryReserveError> {
+    ) -> Result<PropertyKeyIndex<'a>, AllocationError> {
+        self.check_heap_limit(new_cap)?;
         let Self {
             k2pow1,
             k2pow2,
@@ -3061,6 +3604,7 @@ impl ElementArrays {
                 };
                 k2pow32.push(source)
             }
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         }
     }
 
@@ -3071,7 +3615,7 @@ impl ElementArrays {
             Option<ElementDescriptor>,
             Option<Value<'a>>,
         )>,
-    ) -> Result<ElementsVector<'a>, TryReserveError> {
+    ) -> Result<ElementsVector<'a>, AllocationError> {
         let length = entries.len();
         let mut values: Vec<Option<Value>> = Vec::with_capacity(length);
         let mut descriptors: Option<AHashMap<u32, ElementDescriptor<'static>>> = None;
@@ -3095,7 +3639,7 @@ impl ElementArrays {
         &mut self,
         values: &[Option<Value<'a>>],
         descriptors: Option<AHashMap<u32, ElementDescriptor<'static>>>,
-    ) -> Result<ElementsVector<'a>, TryReserveError> {
+    ) -> Result<ElementsVector<'a>, AllocationError> {
         let length = values.len();
         self.allocate_object_property_storage(length, values, descriptors)
     }
@@ -3103,7 +3647,7 @@ impl ElementArrays {
     pub(crate) fn allocate_object_property_storage_from_entries_slice<'a>(
         &mut self,
         entries: &[ObjectEntry<'a>],
-    ) -> Result<ElementsVector<'a>, TryReserveError> {
+    ) -> Result<ElementsVector<'a>, AllocationError> {
         let length = entries.len();
         let mut keys: Vec<PropertyKey> = Vec::with_capacity(length);
         let mut values: Vec<Option<Value>> = Vec::with_capacity(length);
@@ -3160,6 +3704,7 @@ impl ElementArrays {
             ElementArrayKey::E16 => k2pow16.get_raw(index, len),
             ElementArrayKey::E24 => k2pow24.get_raw(index, len),
             ElementArrayKey::E32 => k2pow32.get_raw(index, len),
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         }
     }
 
@@ -3198,9 +3743,15 @@ impl ElementArrays {
             ElementArrayKey::E16 => k2pow16.get_uninit(keys_index),
             ElementArrayKey::E24 => k2pow24.get_uninit(keys_index),
             ElementArrayKey::E32 => k2pow32.get_uninit(keys_index),
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         }
     }
 
+    /// Get the values of a dense elements vector.
+    ///
+    /// ## Panics
+    ///
+    /// If the elements vector is sparse.
     pub(crate) fn get_values<'a>(&self, vector: &ElementsVector) -> &[Option<Value<'a>>] {
         let ElementArrays {
             e2pow1,
@@ -3229,9 +3780,15 @@ impl ElementArrays {
             ElementArrayKey::E16 => e2pow16.get_values(vector),
             ElementArrayKey::E24 => e2pow24.get_values(vector),
             ElementArrayKey::E32 => e2pow32.get_values(vector),
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         }
     }
 
+    /// Get exclusive access to the values of a dense elements vector.
+    ///
+    /// ## Panics
+    ///
+    /// If the elements vector is sparse.
     pub(crate) fn get_values_mut(
         &mut self,
         vector: &ElementsVector,
@@ -3263,6 +3820,7 @@ impl ElementArrays {
             ElementArrayKey::E16 => e2pow16.get_values_mut(vector),
             ElementArrayKey::E24 => e2pow24.get_values_mut(vector),
             ElementArrayKey::E32 => e2pow32.get_values_mut(vector),
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         }
     }
 
@@ -3315,6 +3873,7 @@ impl ElementArrays {
             ElementArrayKey::E16 => e2pow16.get_descriptors_and_values_raw(index, len),
             ElementArrayKey::E24 => e2pow24.get_descriptors_and_values_raw(index, len),
             ElementArrayKey::E32 => e2pow32.get_descriptors_and_values_raw(index, len),
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         }
     }
 
@@ -3356,6 +3915,7 @@ impl ElementArrays {
             ElementArrayKey::E16 => e2pow16.get_descriptors_and_values_mut_raw(index, len),
             ElementArrayKey::E24 => e2pow24.get_descriptors_and_values_mut_raw(index, len),
             ElementArrayKey::E32 => e2pow32.get_descriptors_and_values_mut_raw(index, len),
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         }
     }
 
@@ -3394,6 +3954,7 @@ impl ElementArrays {
             ElementArrayKey::E16 => e2pow16.get_descriptors_and_values_mut(vector),
             ElementArrayKey::E24 => e2pow24.get_descriptors_and_values_mut(vector),
             ElementArrayKey::E32 => e2pow32.get_descriptors_and_values_mut(vector),
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         }
     }
 
@@ -3436,6 +3997,7 @@ impl ElementArrays {
             ElementArrayKey::E16 => e2pow16.get_descriptors_and_values_uninit_raw(index),
             ElementArrayKey::E24 => e2pow24.get_descriptors_and_values_uninit_raw(index),
             ElementArrayKey::E32 => e2pow32.get_descriptors_and_values_uninit_raw(index),
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         }
     }
 
@@ -3479,6 +4041,7 @@ impl ElementArrays {
             ElementArrayKey::E16 => k2pow16.get_raw(keys_index, len),
             ElementArrayKey::E24 => k2pow24.get_raw(keys_index, len),
             ElementArrayKey::E32 => k2pow32.get_raw(keys_index, len),
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         };
         let elements = match values_cap {
             ElementArrayKey::Empty | ElementArrayKey::EmptyIntrinsic => return None,
@@ -3515,6 +4078,7 @@ impl ElementArrays {
             ElementArrayKey::E32 => self
                 .e2pow32
                 .get_descriptors_and_values_mut_raw(values_index.unbind(), len),
+            ElementArrayKey::Sparse => ElementArrayKey::unreachable_sparse(),
         };
         Some(PropertyStorageMut::from_keys_and_elements(keys, elements))
     }
@@ -3537,6 +4101,7 @@ impl ElementArrays {
             e2pow16,
             e2pow24,
             e2pow32,
+            sparse,
             ..
         } = self;
         let new_index = match elements_vector.cap {
@@ -3596,6 +4161,21 @@ impl ElementArrays {
                 elements.values.extend_from_within(index..index + 1);
                 ElementIndex::last_element_index(&elements.values)
             }
+            ElementArrayKey::Sparse => {
+                // Like above, descriptors are not cloned; accessor elements
+                // thus become holes.
+                let values = sparse[elements_vector.elements_index]
+                    .values
+                    .iter()
+                    .filter(|(_, value)| value.is_some())
+                    .map(|(index, value)| (*index, *value))
+                    .collect();
+                sparse.push(SparseElements {
+                    values,
+                    descriptors: Default::default(),
+                });
+                ElementIndex::from_index(sparse.len() - 1)
+            }
         };
 
         ElementsVector {
@@ -21,6 +21,8 @@ use soavec_derive::SoAble;
 
 #[cfg(feature = "date")]
 use crate::ecmascript::Date;
+#[cfg(feature = "json")]
+use crate::ecmascript::RawJSON;
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::{ArrayBuffer, DataView, VoidArray};
 #[cfg(feature = "regexp")]
@@ -30,24 +32,27 @@ use crate::ecmascript::{Set, SetIterator};
 #[cfg(feature = "shared-array-buffer")]
 use crate::ecmascript::{SharedArrayBuffer, SharedDataView, SharedVoidArray};
 #[cfg(feature = "temporal")]
-use crate::ecmascript::{TemporalDuration, TemporalInstant, TemporalPlainTime};
+use crate::ecmascript::{
+    TemporalDuration, TemporalInstant, TemporalPlainDate, TemporalPlainDateTime,
+    TemporalPlainMonthDay, TemporalPlainTime, TemporalPlainYearMonth, TemporalZonedDateTime,
+};
 #[cfg(feature = "weak-refs")]
 use crate::ecmascript::{WeakMap, WeakRef, WeakSet};
 use crate::{
     ecmascript::{
-        Array, ArrayIterator, AsyncGenerator, AwaitReaction, BUILTIN_STRINGS_LIST, BoundFunction,
-        BuiltinConstructorFunction, BuiltinFunction, BuiltinPromiseFinallyFunction,
-        BuiltinPromiseResolvingFunction, DeclarativeEnvironment, ECMAScriptFunction,
-        EmbedderObject, Error, FinalizationRegistry, FunctionEnvironment, Generator,
-        GlobalEnvironment, HeapBigInt, HeapNumber, HeapString, Map, MapIterator, Module,
-        ModuleEnvironment, ModuleRequest, ObjectEnvironment, ObjectShape, OrdinaryObject,
-        PrimitiveObject, PrivateEnvironment, Promise, PromiseGroup, PromiseReaction,
-        PropertyLookupCache, Proxy, Realm, Script, SourceCode, SourceTextModule, StringIterator,
-        Symbol, Value, WeakKey,
+        Array, ArrayFromAsync, ArrayIterator, AsyncGenerator, AwaitReaction, BUILTIN_STRINGS_LIST,
+        BoundFunction, BuiltinConstructorFunction, BuiltinFunction, BuiltinPromiseFinallyFunction,
+        BuiltinPromiseResolvingFunction, BuiltinProxyRevokerFunction, DeclarativeEnvironment,
+        DisposableStack, ECMAScriptFunction, EmbedderObject, Error, FinalizationRegistry,
+        FunctionEnvironment, Generator, GlobalEnvironment, HeapBigInt, HeapNumber, HeapString,
+        IteratorHelper, Map, MapIterator, Module, ModuleEnvironment, ModuleRequest,
+        ObjectEnvironment, ObjectShape, OrdinaryObject, PrimitiveObject, PrivateEnvironment,
+        Promise, PromiseGroup, PromiseReaction, PropertyLookupCache, Proxy, Realm, Script,
+        SourceCode, SourceTextModule, StringIterator, Symbol, Value, WeakKey,
     },
     engine::Executable,
     heap::{
-        BaseIndex, ElementIndex, Heap, HeapIndexHandle, PropertyKeyIndex,
+        Arena, BaseIndex, ElementIndex, Heap, HeapIndexHandle, PropertyKeyIndex,
         element_array::ElementDescriptor,
     },
 };
@@ -103,6 +108,9 @@ impl BitRange {
     }
 
     pub(crate) fn mark_range(&self, range_to_mark: Range<u32>, bits: &mut [AtomicBits]) {
+        if range_to_mark.is_empty() {
+            return;
+        }
         let start = self.0.start + range_to_mark.start as usize;
         let end = self.0.start + range_to_mark.end as usize;
 
@@ -380,6 +388,9 @@ impl AtomicBits {
 #[derive(Debug)]
 pub(crate) struct HeapBits {
     pub(super) bits: Box<[AtomicBits]>,
+    /// If true, the old generation is pre-marked and only the young
+    /// generation is traced and swept.
+    pub(super) young: bool,
     pub(super) e_2_1: BitRange,
     pub(super) e_2_2: BitRange,
     pub(super) e_2_3: BitRange,
@@ -391,6 +402,7 @@ pub(crate) struct HeapBits {
     pub(super) e_2_16: BitRange,
     pub(super) e_2_24: BitRange,
     pub(super) e_2_32: BitRange,
+    pub(super) e_sparse: BitRange,
     pub(super) k_2_1: BitRange,
     pub(super) k_2_2: BitRange,
     pub(super) k_2_3: BitRange,
@@ -405,6 +417,7 @@ pub(crate) struct HeapBits {
     #[cfg(feature = "array-buffer")]
     pub(super) array_buffers: BitRange,
     pub(super) arrays: BitRange,
+    pub(super) array_from_async_records: BitRange,
     pub(super) array_iterators: BitRange,
     pub(super) async_generators: BitRange,
     pub(super) await_reactions: BitRange,
@@ -423,9 +436,20 @@ pub(crate) struct HeapBits {
     pub(super) durations: BitRange,
     #[cfg(feature = "temporal")]
     pub(super) plain_times: BitRange,
+    #[cfg(feature = "temporal")]
+    pub(super) plain_dates: BitRange,
+    #[cfg(feature = "temporal")]
+    pub(super) plain_date_times: BitRange,
+    #[cfg(feature = "temporal")]
+    pub(super) zoned_date_times: BitRange,
+    #[cfg(feature = "temporal")]
+    pub(super) plain_year_months: BitRange,
+    #[cfg(feature = "temporal")]
+    pub(super) plain_month_days: BitRange,
     pub(super) declarative_environments: BitRange,
     pub(super) ecmascript_functions: BitRange,
     pub(super) embedder_objects: BitRange,
+    pub(super) disposable_stacks: BitRange,
     pub(super) errors: BitRange,
     pub(super) executables: BitRange,
     pub(super) source_codes: BitRange,
@@ -447,6 +471,7 @@ pub(crate) struct HeapBits {
     pub(super) promise_reaction_records: BitRange,
     pub(super) promise_resolving_functions: BitRange,
     pub(super) promise_finally_functions: BitRange,
+    pub(super) proxy_revoker_functions: BitRange,
     pub(super) promises: BitRange,
     pub(super) promise_group_records: BitRange,
     pub(super) proxies: BitRange,
@@ -468,6 +493,9 @@ pub(crate) struct HeapBits {
     pub(super) shared_typed_arrays: BitRange,
     pub(super) source_text_module_records: BitRange,
     pub(super) string_iterators: BitRange,
+    pub(super) iterator_helpers: BitRange,
+    #[cfg(feature = "json")]
+    pub(super) raw_json_objects: BitRange,
     pub(super) strings: BitRange,
     pub(super) symbols: BitRange,
     #[cfg(feature = "array-buffer")]
@@ -487,6 +515,7 @@ pub(crate) struct WorkQueues<'a> {
     #[cfg(feature = "array-buffer")]
     pub(crate) array_buffers: Vec<ArrayBuffer<'static>>,
     pub(crate) arrays: Vec<Array<'static>>,
+    pub(crate) array_from_async_records: Vec<ArrayFromAsync<'static>>,
     pub(crate) array_iterators: Vec<ArrayIterator<'static>>,
     pub(crate) async_generators: Vec<AsyncGenerator<'static>>,
     pub(crate) await_reactions: Vec<AwaitReaction<'static>>,
@@ -505,6 +534,16 @@ pub(crate) struct WorkQueues<'a> {
     pub(crate) durations: Vec<TemporalDuration<'static>>,
     #[cfg(feature = "temporal")]
     pub(crate) plain_times: Vec<TemporalPlainTime<'static>>,
+    #[cfg(feature = "temporal")]
+    pub(crate) plain_dates: Vec<TemporalPlainDate<'static>>,
+    #[cfg(feature = "temporal")]
+    pub(crate) plain_date_times: Vec<TemporalPlainDateTime<'static>>,
+    #[cfg(feature = "temporal")]
+    pub(crate) zoned_date_times: Vec<TemporalZonedDateTime<'static>>,
+    #[cfg(feature = "temporal")]
+    pub(crate) plain_year_months: Vec<TemporalPlainYearMonth<'static>>,
+    #[cfg(feature = "temporal")]
+    pub(crate) plain_month_days: Vec<TemporalPlainMonthDay<'static>>,
     pub(crate) declarative_environments: Vec<DeclarativeEnvironment<'static>>,
     pub(crate) e_2_1: Vec<ElementIndex<'static>>,
     pub(crate) e_2_2: Vec<ElementIndex<'static>>,
@@ -517,6 +556,7 @@ pub(crate) struct WorkQueues<'a> {
     pub(crate) e_2_16: Vec<ElementIndex<'static>>,
     pub(crate) e_2_24: Vec<ElementIndex<'static>>,
     pub(crate) e_2_32: Vec<ElementIndex<'static>>,
+    pub(crate) e_sparse: Vec<ElementIndex<'static>>,
     pub(crate) k_2_1: Vec<PropertyKeyIndex<'static>>,
     pub(crate) k_2_2: Vec<PropertyKeyIndex<'static>>,
     pub(crate) k_2_3: Vec<PropertyKeyIndex<'static>>,
@@ -531,6 +571,7 @@ pub(crate) struct WorkQueues<'a> {
     pub(crate) ecmascript_functions: Vec<ECMAScriptFunction<'static>>,
     pub(crate) embedder_objects: Vec<EmbedderObject<'static>>,
     pub(crate) source_codes: Vec<SourceCode<'static>>,
+    pub(crate) disposable_stacks: Vec<DisposableStack<'static>>,
     pub(crate) errors: Vec<Error<'static>>,
     pub(crate) executables: Vec<Executable<'static>>,
     pub(crate) finalization_registrys: Vec<FinalizationRegistry<'static>>,
@@ -552,6 +593,7 @@ pub(crate) struct WorkQueues<'a> {
     pub(crate) promise_reaction_records: Vec<PromiseReaction<'static>>,
     pub(crate) promise_resolving_functions: Vec<BuiltinPromiseResolvingFunction<'static>>,
     pub(crate) promise_finally_functions: Vec<BuiltinPromiseFinallyFunction<'static>>,
+    pub(crate) proxy_revoker_functions: Vec<BuiltinProxyRevokerFunction<'static>>,
     pub(crate) promise_group_records: Vec<PromiseGroup<'static>>,
     pub(crate) proxies: Vec<Proxy<'static>>,
     pub(crate) realms: Vec<Realm<'static>>,
@@ -572,6 +614,9 @@ pub(crate) struct WorkQueues<'a> {
     pub(crate) shared_typed_arrays: Vec<SharedVoidArray<'static>>,
     pub(crate) source_text_module_records: Vec<SourceTextModule<'static>>,
     pub(crate) string_iterators: Vec<StringIterator<'static>>,
+    pub(crate) iterator_helpers: Vec<IteratorHelper<'static>>,
+    #[cfg(feature = "json")]
+    pub(crate) raw_json_objects: Vec<RawJSON<'static>>,
     pub(crate) strings: Vec<HeapString<'static>>,
     pub(crate) symbols: Vec<Symbol<'static>>,
     #[cfg(feature = "array-buffer")]
@@ -628,6 +673,7 @@ impl HeapBits {
             BitRange::from_bit_count_and_len(&mut bit_count, heap.elements.e2pow24.values.len());
         let e_2_32 =
             BitRange::from_bit_count_and_len(&mut bit_count, heap.elements.e2pow32.values.len());
+        let e_sparse = BitRange::from_bit_count_and_len(&mut bit_count, heap.elements.sparse.len());
         let k_2_24 =
             BitRange::from_bit_count_and_len(&mut bit_count, heap.elements.k2pow24.keys.len());
         let k_2_32 =
@@ -636,6 +682,8 @@ impl HeapBits {
         let array_buffers =
             BitRange::from_bit_count_and_len(&mut bit_count, heap.array_buffers.len());
         let arrays = BitRange::from_bit_count_and_len(&mut bit_count, heap.arrays.len() as usize);
+        let array_from_async_records =
+            BitRange::from_bit_count_and_len(&mut bit_count, heap.array_from_async_records.len());
         let array_iterators =
             BitRange::from_bit_count_and_len(&mut bit_count, heap.array_iterators.len());
         let async_generators =
@@ -660,12 +708,28 @@ impl HeapBits {
         let durations = BitRange::from_bit_count_and_len(&mut bit_count, heap.durations.len());
         #[cfg(feature = "temporal")]
         let plain_times = BitRange::from_bit_count_and_len(&mut bit_count, heap.plain_times.len());
+        #[cfg(feature = "temporal")]
+        let plain_dates = BitRange::from_bit_count_and_len(&mut bit_count, heap.plain_dates.len());
+        #[cfg(feature = "temporal")]
+        let plain_date_times =
+            BitRange::from_bit_count_and_len(&mut bit_count, heap.plain_date_times.len());
+        #[cfg(feature = "temporal")]
+        let zoned_date_times =
+            BitRange::from_bit_count_and_len(&mut bit_count, heap.zoned_date_times.len());
+        #[cfg(feature = "temporal")]
+        let plain_year_months =
+            BitRange::from_bit_count_and_len(&mut bit_count, heap.plain_year_months.len());
+        #[cfg(feature = "temporal")]
+        let plain_month_days =
+            BitRange::from_bit_count_and_len(&mut bit_count, heap.plain_month_days.len());
         let declarative_environments =
             BitRange::from_bit_count_and_len(&mut bit_count, heap.environments.declarative.len());
         let ecmascript_functions =
             BitRange::from_bit_count_and_len(&mut bit_count, heap.ecmascript_functions.len());
         let embedder_objects =
             BitRange::from_bit_count_and_len(&mut bit_count, heap.embedder_objects.len());
+        let disposable_stacks =
+            BitRange::from_bit_count_and_len(&mut bit_count, heap.disposable_stacks.len());
         let errors = BitRange::from_bit_count_and_len(&mut bit_count, heap.errors.len());
         let executables = BitRange::from_bit_count_and_len(&mut bit_count, heap.executables.len());
         let source_codes =
@@ -703,6 +767,8 @@ impl HeapBits {
         );
         let promise_finally_functions =
             BitRange::from_bit_count_and_len(&mut bit_count, heap.promise_finally_functions.len());
+        let proxy_revoker_functions =
+            BitRange::from_bit_count_and_len(&mut bit_count, heap.proxy_revoker_functions.len());
         let private_environments =
             BitRange::from_bit_count_and_len(&mut bit_count, heap.environments.private.len());
         let promises = BitRange::from_bit_count_and_len(&mut bit_count, heap.promises.len());
@@ -734,6 +800,11 @@ impl HeapBits {
             BitRange::from_bit_count_and_len(&mut bit_count, heap.source_text_module_records.len());
         let string_iterators =
             BitRange::from_bit_count_and_len(&mut bit_count, heap.string_iterators.len());
+        let iterator_helpers =
+            BitRange::from_bit_count_and_len(&mut bit_count, heap.iterator_helpers.len());
+        #[cfg(feature = "json")]
+        let raw_json_objects =
+            BitRange::from_bit_count_and_len(&mut bit_count, heap.raw_json_objects.len());
         let strings = BitRange::from_bit_count_and_len(&mut bit_count, heap.strings.len());
         let symbols = BitRange::from_bit_count_and_len(&mut bit_count, heap.symbols.len());
         #[cfg(feature = "array-buffer")]
@@ -751,10 +822,12 @@ impl HeapBits {
         // SAFETY: filled in.
         let bits = unsafe { bits.assume_init() };
         Self {
+            young: false,
             bits,
             #[cfg(feature = "array-buffer")]
             array_buffers,
             arrays,
+            array_from_async_records,
             array_iterators,
             async_generators,
             await_reactions,
@@ -773,6 +846,16 @@ impl HeapBits {
             durations,
             #[cfg(feature = "temporal")]
             plain_times,
+            #[cfg(feature = "temporal")]
+            plain_dates,
+            #[cfg(feature = "temporal")]
+            plain_date_times,
+            #[cfg(feature = "temporal")]
+            zoned_date_times,
+            #[cfg(feature = "temporal")]
+            plain_year_months,
+            #[cfg(feature = "temporal")]
+            plain_month_days,
             declarative_environments,
             e_2_1,
             e_2_2,
@@ -785,6 +868,7 @@ impl HeapBits {
             e_2_16,
             e_2_24,
             e_2_32,
+            e_sparse,
             k_2_1,
             k_2_2,
             k_2_3,
@@ -798,6 +882,7 @@ impl HeapBits {
             k_2_32,
             ecmascript_functions,
             embedder_objects,
+            disposable_stacks,
             errors,
             executables,
             source_codes,
@@ -818,6 +903,7 @@ impl HeapBits {
             promise_reaction_records,
             promise_resolving_functions,
             promise_finally_functions,
+            proxy_revoker_functions,
             private_environments,
             promises,
             promise_group_records,
@@ -840,6 +926,9 @@ impl HeapBits {
             shared_typed_arrays,
             source_text_module_records,
             string_iterators,
+            iterator_helpers,
+            #[cfg(feature = "json")]
+            raw_json_objects,
             strings,
             symbols,
             #[cfg(feature = "array-buffer")]
@@ -853,6 +942,224 @@ impl HeapBits {
         }
     }
 
+    /// Mark all items in the old generation of the heap, preparing for a
+    /// young generation garbage collection.
+    ///
+    /// The old generation is not traced or swept by a young generation
+    /// garbage collection: only items in remembered sets are.
+    pub(crate) fn mark_old_generation(&mut self, heap: &Heap) {
+        self.young = true;
+        self.e_2_1
+            .mark_range(heap.elements.e2pow1.values.old_range(), &mut self.bits);
+        self.e_2_2
+            .mark_range(heap.elements.e2pow2.values.old_range(), &mut self.bits);
+        self.e_2_3
+            .mark_range(heap.elements.e2pow3.values.old_range(), &mut self.bits);
+        self.e_2_4
+            .mark_range(heap.elements.e2pow4.values.old_range(), &mut self.bits);
+        self.e_2_6
+            .mark_range(heap.elements.e2pow6.values.old_range(), &mut self.bits);
+        self.e_2_8
+            .mark_range(heap.elements.e2pow8.values.old_range(), &mut self.bits);
+        self.k_2_1
+            .mark_range(heap.elements.k2pow1.keys.old_range(), &mut self.bits);
+        self.k_2_2
+            .mark_range(heap.elements.k2pow2.keys.old_range(), &mut self.bits);
+        self.k_2_3
+            .mark_range(heap.elements.k2pow3.keys.old_range(), &mut self.bits);
+        self.k_2_4
+            .mark_range(heap.elements.k2pow4.keys.old_range(), &mut self.bits);
+        self.k_2_6
+            .mark_range(heap.elements.k2pow6.keys.old_range(), &mut self.bits);
+        self.k_2_8
+            .mark_range(heap.elements.k2pow8.keys.old_range(), &mut self.bits);
+        self.e_2_10
+            .mark_range(heap.elements.e2pow10.values.old_range(), &mut self.bits);
+        self.e_2_12
+            .mark_range(heap.elements.e2pow12.values.old_range(), &mut self.bits);
+        self.e_2_16
+            .mark_range(heap.elements.e2pow16.values.old_range(), &mut self.bits);
+        self.k_2_10
+            .mark_range(heap.elements.k2pow10.keys.old_range(), &mut self.bits);
+        self.k_2_12
+            .mark_range(heap.elements.k2pow12.keys.old_range(), &mut self.bits);
+        self.k_2_16
+            .mark_range(heap.elements.k2pow16.keys.old_range(), &mut self.bits);
+        self.e_2_24
+            .mark_range(heap.elements.e2pow24.values.old_range(), &mut self.bits);
+        self.e_2_32
+            .mark_range(heap.elements.e2pow32.values.old_range(), &mut self.bits);
+        self.e_sparse
+            .mark_range(heap.elements.sparse.old_range(), &mut self.bits);
+        self.k_2_24
+            .mark_range(heap.elements.k2pow24.keys.old_range(), &mut self.bits);
+        self.k_2_32
+            .mark_range(heap.elements.k2pow32.keys.old_range(), &mut self.bits);
+        #[cfg(feature = "array-buffer")]
+        self.array_buffers
+            .mark_range(heap.array_buffers.old_range(), &mut self.bits);
+        self.arrays
+            .mark_range(heap.arrays.old_range(), &mut self.bits);
+        self.array_from_async_records
+            .mark_range(heap.array_from_async_records.old_range(), &mut self.bits);
+        self.array_iterators
+            .mark_range(heap.array_iterators.old_range(), &mut self.bits);
+        self.async_generators
+            .mark_range(heap.async_generators.old_range(), &mut self.bits);
+        self.await_reactions
+            .mark_range(heap.await_reactions.old_range(), &mut self.bits);
+        self.bigints
+            .mark_range(heap.bigints.old_range(), &mut self.bits);
+        self.bound_functions
+            .mark_range(heap.bound_functions.old_range(), &mut self.bits);
+        self.builtin_constructors
+            .mark_range(heap.builtin_constructors.old_range(), &mut self.bits);
+        self.builtin_functions
+            .mark_range(heap.builtin_functions.old_range(), &mut self.bits);
+        #[cfg(feature = "array-buffer")]
+        self.data_views
+            .mark_range(heap.data_views.old_range(), &mut self.bits);
+        #[cfg(feature = "date")]
+        self.dates
+            .mark_range(heap.dates.old_range(), &mut self.bits);
+        #[cfg(feature = "temporal")]
+        self.instants
+            .mark_range(heap.instants.old_range(), &mut self.bits);
+        #[cfg(feature = "temporal")]
+        self.durations
+            .mark_range(heap.durations.old_range(), &mut self.bits);
+        #[cfg(feature = "temporal")]
+        self.plain_times
+            .mark_range(heap.plain_times.old_range(), &mut self.bits);
+        #[cfg(feature = "temporal")]
+        self.plain_dates
+            .mark_range(heap.plain_dates.old_range(), &mut self.bits);
+        #[cfg(feature = "temporal")]
+        self.plain_date_times
+            .mark_range(heap.plain_date_times.old_range(), &mut self.bits);
+        #[cfg(feature = "temporal")]
+        self.zoned_date_times
+            .mark_range(heap.zoned_date_times.old_range(), &mut self.bits);
+        #[cfg(feature = "temporal")]
+        self.plain_year_months
+            .mark_range(heap.plain_year_months.old_range(), &mut self.bits);
+        #[cfg(feature = "temporal")]
+        self.plain_month_days
+            .mark_range(heap.plain_month_days.old_range(), &mut self.bits);
+        self.declarative_environments
+            .mark_range(heap.environments.declarative.old_range(), &mut self.bits);
+        self.ecmascript_functions
+            .mark_range(heap.ecmascript_functions.old_range(), &mut self.bits);
+        self.embedder_objects
+            .mark_range(heap.embedder_objects.old_range(), &mut self.bits);
+        self.disposable_stacks
+            .mark_range(heap.disposable_stacks.old_range(), &mut self.bits);
+        self.errors
+            .mark_range(heap.errors.old_range(), &mut self.bits);
+        self.executables
+            .mark_range(heap.executables.old_range(), &mut self.bits);
+        self.source_codes
+            .mark_range(heap.source_codes.old_range(), &mut self.bits);
+        self.finalization_registrys
+            .mark_range(heap.finalization_registrys.old_range(), &mut self.bits);
+        self.function_environments
+            .mark_range(heap.environments.function.old_range(), &mut self.bits);
+        self.generators
+            .mark_range(heap.generators.old_range(), &mut self.bits);
+        self.global_environments
+            .mark_range(heap.environments.global.old_range(), &mut self.bits);
+        self.maps.mark_range(heap.maps.old_range(), &mut self.bits);
+        self.map_iterators
+            .mark_range(heap.map_iterators.old_range(), &mut self.bits);
+        self.module_environments
+            .mark_range(heap.environments.module.old_range(), &mut self.bits);
+        self.modules
+            .mark_range(heap.modules.old_range(), &mut self.bits);
+        self.module_request_records
+            .mark_range(heap.module_request_records.old_range(), &mut self.bits);
+        self.numbers
+            .mark_range(heap.numbers.old_range(), &mut self.bits);
+        self.object_environments
+            .mark_range(heap.environments.object.old_range(), &mut self.bits);
+        self.object_shapes
+            .mark_range(heap.object_shapes.old_range(), &mut self.bits);
+        self.objects
+            .mark_range(heap.objects.old_range(), &mut self.bits);
+        self.primitive_objects
+            .mark_range(heap.primitive_objects.old_range(), &mut self.bits);
+        self.promise_reaction_records
+            .mark_range(heap.promise_reaction_records.old_range(), &mut self.bits);
+        self.promise_resolving_functions
+            .mark_range(heap.promise_resolving_functions.old_range(), &mut self.bits);
+        self.promise_finally_functions
+            .mark_range(heap.promise_finally_functions.old_range(), &mut self.bits);
+        self.proxy_revoker_functions
+            .mark_range(heap.proxy_revoker_functions.old_range(), &mut self.bits);
+        self.private_environments
+            .mark_range(heap.environments.private.old_range(), &mut self.bits);
+        self.promises
+            .mark_range(heap.promises.old_range(), &mut self.bits);
+        self.promise_group_records
+            .mark_range(heap.promise_group_records.old_range(), &mut self.bits);
+        self.proxies
+            .mark_range(heap.proxies.old_range(), &mut self.bits);
+        self.realms
+            .mark_range(heap.realms.old_range(), &mut self.bits);
+        #[cfg(feature = "regexp")]
+        self.regexps
+            .mark_range(heap.regexps.old_range(), &mut self.bits);
+        #[cfg(feature = "regexp")]
+        self.regexp_string_iterators
+            .mark_range(heap.regexp_string_iterators.old_range(), &mut self.bits);
+        self.scripts
+            .mark_range(heap.scripts.old_range(), &mut self.bits);
+        #[cfg(feature = "set")]
+        self.sets.mark_range(heap.sets.old_range(), &mut self.bits);
+        #[cfg(feature = "set")]
+        self.set_iterators
+            .mark_range(heap.set_iterators.old_range(), &mut self.bits);
+        #[cfg(feature = "shared-array-buffer")]
+        self.shared_array_buffers
+            .mark_range(heap.shared_array_buffers.old_range(), &mut self.bits);
+        #[cfg(feature = "shared-array-buffer")]
+        self.shared_data_views
+            .mark_range(heap.shared_data_views.old_range(), &mut self.bits);
+        #[cfg(feature = "shared-array-buffer")]
+        self.shared_typed_arrays
+            .mark_range(heap.shared_typed_arrays.old_range(), &mut self.bits);
+        self.source_text_module_records.mark_range(
+            heap.source_text_module_records.0.old_range(),
+            &mut self.bits,
+        );
+        self.string_iterators
+            .mark_range(heap.string_iterators.old_range(), &mut self.bits);
+        self.iterator_helpers
+            .mark_range(heap.iterator_helpers.old_range(), &mut self.bits);
+        #[cfg(feature = "json")]
+        self.raw_json_objects
+            .mark_range(heap.raw_json_objects.old_range(), &mut self.bits);
+        self.strings
+            .mark_range(heap.strings.old_range(), &mut self.bits);
+        self.symbols
+            .mark_range(heap.symbols.old_range(), &mut self.bits);
+        #[cfg(feature = "array-buffer")]
+        self.typed_arrays
+            .mark_range(heap.typed_arrays.old_range(), &mut self.bits);
+        #[cfg(feature = "weak-refs")]
+        self.weak_maps
+            .mark_range(heap.weak_maps.old_range(), &mut self.bits);
+        #[cfg(feature = "weak-refs")]
+        self.weak_refs
+            .mark_range(heap.weak_refs.old_range(), &mut self.bits);
+        #[cfg(feature = "weak-refs")]
+        self.weak_sets
+            .mark_range(heap.weak_sets.old_range(), &mut self.bits);
+        // Property lookup caches are not behind a write barrier; they are all
+        // retained and traced by a young generation garbage collection.
+        self.caches
+            .mark_range(0..heap.caches.len() as u32, &mut self.bits);
+    }
+
     pub(crate) fn is_marked(&self, key: &WeakKey) -> bool {
         match key {
             WeakKey::Symbol(d) => self.symbols.get_bit(d.get_index(), &self.bits),
@@ -873,9 +1180,9 @@ impl HeapBits {
             WeakKey::BuiltinPromiseFinallyFunction(d) => self
                 .promise_finally_functions
                 .get_bit(d.get_index(), &self.bits),
-            WeakKey::BuiltinProxyRevokerFunction => {
-                unreachable!()
-            }
+            WeakKey::BuiltinProxyRevokerFunction(d) => self
+                .proxy_revoker_functions
+                .get_bit(d.get_index(), &self.bits),
             WeakKey::PrimitiveObject(d) => {
                 self.primitive_objects.get_bit(d.get_index(), &self.bits)
             }
@@ -889,6 +1196,19 @@ impl HeapBits {
             WeakKey::Duration(d) => self.durations.get_bit(d.get_index(), &self.bits),
             #[cfg(feature = "temporal")]
             WeakKey::PlainTime(d) => self.plain_times.get_bit(d.get_index(), &self.bits),
+            #[cfg(feature = "temporal")]
+            WeakKey::PlainDate(d) => self.plain_dates.get_bit(d.get_index(), &self.bits),
+            #[cfg(feature = "temporal")]
+            WeakKey::PlainDateTime(d) => self.plain_date_times.get_bit(d.get_index(), &self.bits),
+            #[cfg(feature = "temporal")]
+            WeakKey::ZonedDateTime(d) => self.zoned_date_times.get_bit(d.get_index(), &self.bits),
+            #[cfg(feature = "temporal")]
+            WeakKey::PlainYearMonth(d) => self.plain_year_months.get_bit(d.get_index(), &self.bits),
+            #[cfg(feature = "temporal")]
+            WeakKey::PlainMonthDay(d) => self.plain_month_days.get_bit(d.get_index(), &self.bits),
+            WeakKey::DisposableStack(d) => {
+                self.disposable_stacks.get_bit(d.get_index(), &self.bits)
+            }
             WeakKey::Error(d) => self.errors.get_bit(d.get_index(), &self.bits),
             WeakKey::FinalizationRegistry(d) => self
                 .finalization_registrys
@@ -995,6 +1315,9 @@ impl HeapBits {
             #[cfg(feature = "set")]
             WeakKey::MapIterator(d) => self.map_iterators.get_bit(d.get_index(), &self.bits),
             WeakKey::StringIterator(d) => self.string_iterators.get_bit(d.get_index(), &self.bits),
+            WeakKey::IteratorHelper(d) => self.iterator_helpers.get_bit(d.get_index(), &self.bits),
+            #[cfg(feature = "json")]
+            WeakKey::RawJSON(d) => self.raw_json_objects.get_bit(d.get_index(), &self.bits),
             #[cfg(feature = "regexp")]
             WeakKey::RegExpStringIterator(d) => self
                 .regexp_string_iterators
@@ -1014,6 +1337,7 @@ impl<'a> WorkQueues<'a> {
             #[cfg(feature = "array-buffer")]
             array_buffers: Vec::with_capacity(heap.array_buffers.len() / 4),
             arrays: Vec::with_capacity(heap.arrays.len() as usize / 4),
+            array_from_async_records: Vec::with_capacity(heap.array_from_async_records.len() / 4),
             array_iterators: Vec::with_capacity(heap.array_iterators.len() / 4),
             async_generators: Vec::with_capacity(heap.async_generators.len() / 4),
             await_reactions: Vec::with_capacity(heap.await_reactions.len() / 4),
@@ -1032,6 +1356,16 @@ impl<'a> WorkQueues<'a> {
             durations: Vec::with_capacity(heap.durations.len() / 4),
             #[cfg(feature = "temporal")]
             plain_times: Vec::with_capacity(heap.plain_times.len() / 4),
+            #[cfg(feature = "temporal")]
+            plain_dates: Vec::with_capacity(heap.plain_dates.len() / 4),
+            #[cfg(feature = "temporal")]
+            plain_date_times: Vec::with_capacity(heap.plain_date_times.len() / 4),
+            #[cfg(feature = "temporal")]
+            zoned_date_times: Vec::with_capacity(heap.zoned_date_times.len() / 4),
+            #[cfg(feature = "temporal")]
+            plain_year_months: Vec::with_capacity(heap.plain_year_months.len() / 4),
+            #[cfg(feature = "temporal")]
+            plain_month_days: Vec::with_capacity(heap.plain_month_days.len() / 4),
             declarative_environments: Vec::with_capacity(heap.environments.declarative.len() / 4),
             e_2_1: Vec::with_capacity(heap.elements.e2pow1.values.len() / 4),
             e_2_2: Vec::with_capacity(heap.elements.e2pow2.values.len() / 4),
@@ -1044,6 +1378,7 @@ impl<'a> WorkQueues<'a> {
             e_2_16: Vec::with_capacity(heap.elements.e2pow16.values.len() / 4),
             e_2_24: Vec::with_capacity(heap.elements.e2pow24.values.len() / 4),
             e_2_32: Vec::with_capacity(heap.elements.e2pow32.values.len() / 4),
+            e_sparse: Vec::with_capacity(heap.elements.sparse.len() / 4),
             k_2_1: Vec::with_capacity(heap.elements.k2pow1.keys.len() / 4),
             k_2_2: Vec::with_capacity(heap.elements.k2pow2.keys.len() / 4),
             k_2_3: Vec::with_capacity(heap.elements.k2pow3.keys.len() / 4),
@@ -1057,6 +1392,7 @@ impl<'a> WorkQueues<'a> {
             k_2_32: Vec::with_capacity(heap.elements.k2pow32.keys.len() / 4),
             ecmascript_functions: Vec::with_capacity(heap.ecmascript_functions.len() / 4),
             embedder_objects: Vec::with_capacity(heap.embedder_objects.len() / 4),
+            disposable_stacks: Vec::with_capacity(heap.disposable_stacks.len() / 4),
             errors: Vec::with_capacity(heap.errors.len() / 4),
             executables: Vec::with_capacity(heap.executables.len() / 4),
             source_codes: Vec::with_capacity(heap.source_codes.len() / 4),
@@ -1082,6 +1418,7 @@ impl<'a> WorkQueues<'a> {
                 heap.promise_resolving_functions.len() / 4,
             ),
             promise_finally_functions: Vec::with_capacity(heap.promise_finally_functions.len() / 4),
+            proxy_revoker_functions: Vec::with_capacity(heap.proxy_revoker_functions.len() / 4),
             promises: Vec::with_capacity(heap.promises.len() / 4),
             promise_group_records: Vec::with_capacity(heap.promise_group_records.len() / 4),
             proxies: Vec::with_capacity(heap.proxies.len() / 4),
@@ -1105,6 +1442,9 @@ impl<'a> WorkQueues<'a> {
                 heap.source_text_module_records.len() / 4,
             ),
             string_iterators: Vec::with_capacity(heap.string_iterators.len() / 4),
+            iterator_helpers: Vec::with_capacity(heap.iterator_helpers.len() / 4),
+            #[cfg(feature = "json")]
+            raw_json_objects: Vec::with_capacity(heap.raw_json_objects.len() / 4),
             strings: Vec::with_capacity((heap.strings.len() / 4).max(BUILTIN_STRINGS_LIST.len())),
             symbols: Vec::with_capacity((heap.symbols.len() / 4).max(13)),
             #[cfg(feature = "array-buffer")]
@@ -1126,6 +1466,7 @@ impl<'a> WorkQueues<'a> {
             #[cfg(feature = "array-buffer")]
             array_buffers,
             arrays,
+            array_from_async_records,
             array_iterators,
             async_generators,
             await_reactions,
@@ -1144,6 +1485,16 @@ impl<'a> WorkQueues<'a> {
             durations,
             #[cfg(feature = "temporal")]
             plain_times,
+            #[cfg(feature = "temporal")]
+            plain_dates,
+            #[cfg(feature = "temporal")]
+            plain_date_times,
+            #[cfg(feature = "temporal")]
+            zoned_date_times,
+            #[cfg(feature = "temporal")]
+            plain_year_months,
+            #[cfg(feature = "temporal")]
+            plain_month_days,
             declarative_environments,
             e_2_1,
             e_2_2,
@@ -1156,6 +1507,7 @@ impl<'a> WorkQueues<'a> {
             e_2_16,
             e_2_24,
             e_2_32,
+            e_sparse,
             k_2_1,
             k_2_2,
             k_2_3,
@@ -1170,6 +1522,7 @@ impl<'a> WorkQueues<'a> {
             ecmascript_functions,
             embedder_objects,
             source_codes,
+            disposable_stacks,
             errors,
             executables,
             finalization_registrys,
@@ -1191,6 +1544,7 @@ impl<'a> WorkQueues<'a> {
             promise_reaction_records,
             promise_resolving_functions,
             promise_finally_functions,
+            proxy_revoker_functions,
             promise_group_records,
             proxies,
             realms,
@@ -1211,6 +1565,9 @@ impl<'a> WorkQueues<'a> {
             shared_typed_arrays,
             source_text_module_records,
             string_iterators,
+            iterator_helpers,
+            #[cfg(feature = "json")]
+            raw_json_objects,
             strings,
             symbols,
             #[cfg(feature = "array-buffer")]
@@ -1229,6 +1586,16 @@ impl<'a> WorkQueues<'a> {
         let durations: &[bool; 0] = &[];
         #[cfg(not(feature = "temporal"))]
         let plain_times: &[bool; 0] = &[];
+        #[cfg(not(feature = "temporal"))]
+        let plain_dates: &[bool; 0] = &[];
+        #[cfg(not(feature = "temporal"))]
+        let plain_date_times: &[bool; 0] = &[];
+        #[cfg(not(feature = "temporal"))]
+        let zoned_date_times: &[bool; 0] = &[];
+        #[cfg(not(feature = "temporal"))]
+        let plain_year_months: &[bool; 0] = &[];
+        #[cfg(not(feature = "temporal"))]
+        let plain_month_days: &[bool; 0] = &[];
         #[cfg(not(feature = "date"))]
         let dates: &[bool; 0] = &[];
         #[cfg(not(feature = "array-buffer"))]
@@ -1243,6 +1610,8 @@ impl<'a> WorkQueues<'a> {
         let shared_data_views: &[bool; 0] = &[];
         #[cfg(not(feature = "shared-array-buffer"))]
         let shared_typed_arrays: &[bool; 0] = &[];
+        #[cfg(not(feature = "json"))]
+        let raw_json_objects: &[bool; 0] = &[];
         #[cfg(not(feature = "weak-refs"))]
         let weak_maps: &[bool; 0] = &[];
         #[cfg(not(feature = "weak-refs"))]
@@ -1259,6 +1628,7 @@ impl<'a> WorkQueues<'a> {
         let set_iterators: &[bool; 0] = &[];
         array_buffers.is_empty()
             && arrays.is_empty()
+            && array_from_async_records.is_empty()
             && array_iterators.is_empty()
             && async_generators.is_empty()
             && await_reactions.is_empty()
@@ -1272,6 +1642,11 @@ impl<'a> WorkQueues<'a> {
             && instants.is_empty()
             && durations.is_empty()
             && plain_times.is_empty()
+            && plain_dates.is_empty()
+            && plain_date_times.is_empty()
+            && zoned_date_times.is_empty()
+            && plain_year_months.is_empty()
+            && plain_month_days.is_empty()
             && declarative_environments.is_empty()
             && e_2_1.is_empty()
             && e_2_2.is_empty()
@@ -1284,6 +1659,7 @@ impl<'a> WorkQueues<'a> {
             && e_2_16.is_empty()
             && e_2_24.is_empty()
             && e_2_32.is_empty()
+            && e_sparse.is_empty()
             && k_2_1.is_empty()
             && k_2_2.is_empty()
             && k_2_3.is_empty()
@@ -1297,6 +1673,7 @@ impl<'a> WorkQueues<'a> {
             && k_2_32.is_empty()
             && ecmascript_functions.is_empty()
             && embedder_objects.is_empty()
+            && disposable_stacks.is_empty()
             && errors.is_empty()
             && executables.is_empty()
             && source_codes.is_empty()
@@ -1318,6 +1695,7 @@ impl<'a> WorkQueues<'a> {
             && promise_reaction_records.is_empty()
             && promise_resolving_functions.is_empty()
             && promise_finally_functions.is_empty()
+            && proxy_revoker_functions.is_empty()
             && promise_group_records.is_empty()
             && promises.is_empty()
             && proxies.is_empty()
@@ -1332,6 +1710,8 @@ impl<'a> WorkQueues<'a> {
             && shared_typed_arrays.is_empty()
             && source_text_module_records.is_empty()
             && string_iterators.is_empty()
+            && iterator_helpers.is_empty()
+            && raw_json_objects.is_empty()
             && strings.is_empty()
             && symbols.is_empty()
             && typed_arrays.is_empty()
@@ -1619,6 +1999,7 @@ pub(crate) struct CompactionLists {
     #[cfg(feature = "array-buffer")]
     pub(crate) array_buffers: CompactionList,
     pub(crate) arrays: CompactionList,
+    pub(crate) array_from_async_records: CompactionList,
     pub(crate) array_iterators: CompactionList,
     pub(crate) async_generators: CompactionList,
     pub(crate) await_reactions: CompactionList,
@@ -1637,6 +2018,16 @@ pub(crate) struct CompactionLists {
     pub(crate) durations: CompactionList,
     #[cfg(feature = "temporal")]
     pub(crate) plain_times: CompactionList,
+    #[cfg(feature = "temporal")]
+    pub(crate) plain_dates: CompactionList,
+    #[cfg(feature = "temporal")]
+    pub(crate) plain_date_times: CompactionList,
+    #[cfg(feature = "temporal")]
+    pub(crate) zoned_date_times: CompactionList,
+    #[cfg(feature = "temporal")]
+    pub(crate) plain_year_months: CompactionList,
+    #[cfg(feature = "temporal")]
+    pub(crate) plain_month_days: CompactionList,
     pub(crate) declarative_environments: CompactionList,
     pub(crate) e_2_1: CompactionList,
     pub(crate) e_2_2: CompactionList,
@@ -1649,6 +2040,7 @@ pub(crate) struct CompactionLists {
     pub(crate) e_2_16: CompactionList,
     pub(crate) e_2_24: CompactionList,
     pub(crate) e_2_32: CompactionList,
+    pub(crate) e_sparse: CompactionList,
     pub(crate) k_2_1: CompactionList,
     pub(crate) k_2_2: CompactionList,
     pub(crate) k_2_3: CompactionList,
@@ -1664,6 +2056,7 @@ pub(crate) struct CompactionLists {
     pub(crate) embedder_objects: CompactionList,
     pub(crate) source_codes: CompactionList,
     pub(crate) source_text_module_records: CompactionList,
+    pub(crate) disposable_stacks: CompactionList,
     pub(crate) errors: CompactionList,
     pub(crate) executables: CompactionList,
     pub(crate) finalization_registrys: CompactionList,
@@ -1684,6 +2077,7 @@ pub(crate) struct CompactionLists {
     pub(crate) promise_reaction_records: CompactionList,
     pub(crate) promise_resolving_functions: CompactionList,
     pub(crate) promise_finally_functions: CompactionList,
+    pub(crate) proxy_revoker_functions: CompactionList,
     pub(crate) promises: CompactionList,
     pub(crate) promise_group_records: CompactionList,
     pub(crate) proxies: CompactionList,
@@ -1704,6 +2098,9 @@ pub(crate) struct CompactionLists {
     #[cfg(feature = "shared-array-buffer")]
     pub(crate) shared_typed_arrays: CompactionList,
     pub(crate) string_iterators: CompactionList,
+    pub(crate) iterator_helpers: CompactionList,
+    #[cfg(feature = "json")]
+    pub(crate) raw_json_objects: CompactionList,
     pub(crate) strings: CompactionList,
     pub(crate) symbols: CompactionList,
     #[cfg(feature = "array-buffer")]
@@ -1758,6 +2155,7 @@ impl CompactionLists {
             e_2_16: CompactionList::from_mark_bits(&bits.e_2_16, &bits.bits),
             e_2_24: CompactionList::from_mark_bits(&bits.e_2_24, &bits.bits),
             e_2_32: CompactionList::from_mark_bits(&bits.e_2_32, &bits.bits),
+            e_sparse: CompactionList::from_mark_bits(&bits.e_sparse, &bits.bits),
             k_2_1: CompactionList::from_mark_bits(&bits.k_2_1, &bits.bits),
             k_2_2: CompactionList::from_mark_bits(&bits.k_2_2, &bits.bits),
             k_2_3: CompactionList::from_mark_bits(&bits.k_2_3, &bits.bits),
@@ -1772,6 +2170,10 @@ impl CompactionLists {
             arrays: CompactionList::from_mark_bits(&bits.arrays, &bits.bits),
             #[cfg(feature = "array-buffer")]
             array_buffers: CompactionList::from_mark_bits(&bits.array_buffers, &bits.bits),
+            array_from_async_records: CompactionList::from_mark_bits(
+                &bits.array_from_async_records,
+                &bits.bits,
+            ),
             array_iterators: CompactionList::from_mark_bits(&bits.array_iterators, &bits.bits),
             async_generators: CompactionList::from_mark_bits(&bits.async_generators, &bits.bits),
             await_reactions: CompactionList::from_mark_bits(&bits.await_reactions, &bits.bits),
@@ -1798,6 +2200,17 @@ impl CompactionLists {
             durations: CompactionList::from_mark_bits(&bits.durations, &bits.bits),
             #[cfg(feature = "temporal")]
             plain_times: CompactionList::from_mark_bits(&bits.plain_times, &bits.bits),
+            #[cfg(feature = "temporal")]
+            plain_dates: CompactionList::from_mark_bits(&bits.plain_dates, &bits.bits),
+            #[cfg(feature = "temporal")]
+            plain_date_times: CompactionList::from_mark_bits(&bits.plain_date_times, &bits.bits),
+            #[cfg(feature = "temporal")]
+            zoned_date_times: CompactionList::from_mark_bits(&bits.zoned_date_times, &bits.bits),
+            #[cfg(feature = "temporal")]
+            plain_year_months: CompactionList::from_mark_bits(&bits.plain_year_months, &bits.bits),
+            #[cfg(feature = "temporal")]
+            plain_month_days: CompactionList::from_mark_bits(&bits.plain_month_days, &bits.bits),
+            disposable_stacks: CompactionList::from_mark_bits(&bits.disposable_stacks, &bits.bits),
             errors: CompactionList::from_mark_bits(&bits.errors, &bits.bits),
             executables: CompactionList::from_mark_bits(&bits.executables, &bits.bits),
             maps: CompactionList::from_mark_bits(&bits.maps, &bits.bits),
@@ -1830,6 +2243,10 @@ impl CompactionLists {
                 &bits.promise_finally_functions,
                 &bits.bits,
             ),
+            proxy_revoker_functions: CompactionList::from_mark_bits(
+                &bits.proxy_revoker_functions,
+                &bits.bits,
+            ),
             promises: CompactionList::from_mark_bits(&bits.promises, &bits.bits),
             promise_group_records: CompactionList::from_mark_bits(
                 &bits.promise_group_records,
@@ -1847,6 +2264,9 @@ impl CompactionLists {
             #[cfg(feature = "set")]
             set_iterators: CompactionList::from_mark_bits(&bits.set_iterators, &bits.bits),
             string_iterators: CompactionList::from_mark_bits(&bits.string_iterators, &bits.bits),
+            iterator_helpers: CompactionList::from_mark_bits(&bits.iterator_helpers, &bits.bits),
+            #[cfg(feature = "json")]
+            raw_json_objects: CompactionList::from_mark_bits(&bits.raw_json_objects, &bits.bits),
             strings: CompactionList::from_mark_bits(&bits.strings, &bits.bits),
             #[cfg(feature = "shared-array-buffer")]
             shared_array_buffers: CompactionList::from_mark_bits(
@@ -2359,25 +2779,103 @@ pub(crate) fn sweep_heap_vector_values<T: HeapMarkAndSweep>(
     });
 }
 
-pub(crate) fn sweep_heap_soa_vector_values<T: SoAble>(
-    vec: &mut SoAVec<T>,
+/// Mark the values of all remembered old generation items in an arena.
+pub(crate) fn mark_remembered_values<T: HeapMarkAndSweep>(
+    arena: &Arena<Vec<T>>,
+    queues: &mut WorkQueues,
+) {
+    for index in arena.remembered_set().iter() {
+        if let Some(item) = arena.get(index) {
+            item.mark_values(queues);
+        }
+    }
+}
+
+/// Mark the values of all remembered old generation items in a SoA arena.
+pub(crate) fn mark_remembered_soa_values<T: SoAble>(
+    arena: &Arena<SoAVec<T>>,
+    queues: &mut WorkQueues,
+) where
+    for<'a> T::Ref<'a>: HeapMarkAndSweep,
+{
+    for index in arena.remembered_set().iter() {
+        if let Some(item) = arena.get(index as u32) {
+            item.mark_values(queues);
+        }
+    }
+}
+
+/// Sweep an arena and promote its surviving items into the old generation.
+///
+/// In a young generation garbage collection, old items that have not been
+/// remembered only refer to other old items and need not be swept.
+pub(crate) fn sweep_heap_arena_values<T: HeapMarkAndSweep>(
+    arena: &mut Arena<Vec<T>>,
     compactions: &CompactionLists,
     range: &BitRange,
-    bits: &[AtomicBits],
+    bits: &HeapBits,
+) {
+    sweep_heap_arena_values_with(arena, compactions, range, bits, |_| {});
+}
+
+/// Sweep an arena and promote its surviving items into the old generation,
+/// calling `on_drop` with each item that is about to be dropped.
+///
+/// See [`sweep_heap_arena_values`].
+pub(crate) fn sweep_heap_arena_values_with<T: HeapMarkAndSweep>(
+    arena: &mut Arena<Vec<T>>,
+    compactions: &CompactionLists,
+    range: &BitRange,
+    bits: &HeapBits,
+    mut on_drop: impl FnMut(&T),
+) {
+    {
+        // SAFETY: The arena is promoted after sweeping.
+        let (vec, remembered_set) = unsafe { arena.split_mut_unremembered() };
+        assert_eq!(vec.len(), range.len());
+        let mut iter = range.iter(&bits.bits);
+        let mut index = 0;
+        vec.retain_mut(|item| {
+            let do_retain = iter.next().unwrap();
+            if do_retain && (!bits.young || remembered_set.is_young_or_remembered(index)) {
+                item.sweep_values(compactions);
+            } else if !do_retain {
+                on_drop(item);
+            }
+            index += 1;
+            do_retain
+        });
+    }
+    arena.promote();
+}
+
+/// Sweep a SoA arena and promote its surviving items into the old generation.
+///
+/// See [`sweep_heap_arena_values`].
+pub(crate) fn sweep_heap_soa_arena_values<T: SoAble>(
+    arena: &mut Arena<SoAVec<T>>,
+    compactions: &CompactionLists,
+    range: &BitRange,
+    bits: &HeapBits,
 ) where
     for<'a> T::Mut<'a>: HeapMarkAndSweep,
 {
-    assert_eq!(vec.len() as usize, range.len());
-    let mut iter = range.iter(bits);
-    vec.retain_mut(|mut item| {
-        let do_retain = iter.next().unwrap();
-        if do_retain {
-            item.sweep_values(compactions);
-            true
-        } else {
-            false
-        }
-    });
+    {
+        // SAFETY: The arena is promoted after sweeping.
+        let (vec, remembered_set) = unsafe { arena.split_mut_unremembered() };
+        assert_eq!(vec.len() as usize, range.len());
+        let mut iter = range.iter(&bits.bits);
+        let mut index = 0;
+        vec.retain_mut(|mut item| {
+            let do_retain = iter.next().unwrap();
+            if do_retain && (!bits.young || remembered_set.is_young_or_remembered(index)) {
+                item.sweep_values(compactions);
+            }
+            index += 1;
+            do_retain
+        });
+    }
+    arena.promote();
 }
 
 pub(crate) fn sweep_heap_elements_vector_descriptors(
@@ -2441,7 +2939,6 @@ pub(crate) trait HeapSweepWeakReference: Sized + Copy {
     fn sweep_weak_reference(self, compactions: &CompactionLists) -> Option<Self>;
 }
 
-#[cfg(feature = "array-buffer")]
 pub(crate) fn sweep_side_table_values<K, V>(
     side_table: &mut AHashMap<K, V>,
     compactions: &CompactionLists,
@@ -36,11 +36,23 @@ pub(crate) enum IntrinsicObjectIndexes {
     #[cfg(feature = "temporal")]
     Temporal,
     #[cfg(feature = "temporal")]
+    TemporalNow,
+    #[cfg(feature = "temporal")]
     TemporalInstantPrototype,
     #[cfg(feature = "temporal")]
     TemporalDurationPrototype,
     #[cfg(feature = "temporal")]
     TemporalPlainTimePrototype,
+    #[cfg(feature = "temporal")]
+    TemporalPlainDatePrototype,
+    #[cfg(feature = "temporal")]
+    TemporalPlainDateTimePrototype,
+    #[cfg(feature = "temporal")]
+    TemporalZonedDateTimePrototype,
+    #[cfg(feature = "temporal")]
+    TemporalPlainYearMonthPrototype,
+    #[cfg(feature = "temporal")]
+    TemporalPlainMonthDayPrototype,
 
     // Text processing
     #[cfg(feature = "regexp")]
@@ -100,9 +112,13 @@ pub(crate) enum IntrinsicObjectIndexes {
     #[cfg(feature = "weak-refs")]
     WeakRefPrototype,
     FinalizationRegistryPrototype,
+    DisposableStackPrototype,
+    AsyncDisposableStackPrototype,
 
     // Control abstraction objects
     IteratorPrototype,
+    IteratorHelperPrototype,
+    WrapForValidIteratorPrototype,
     ArrayIteratorPrototype,
     // For-In Iterator objects are never directly accessible to ECMAScript code
     // ForInIteratorPrototype,
@@ -126,9 +142,11 @@ pub(crate) enum IntrinsicObjectIndexes {
 
     // Reflection
     ReflectObject,
+    AbstractModuleSourcePrototype,
 
     // Errors subtypes
     AggregateErrorPrototype,
+    SuppressedErrorPrototype,
     EvalErrorPrototype,
     RangeErrorPrototype,
     ReferenceErrorPrototype,
@@ -184,6 +202,16 @@ pub(crate) enum IntrinsicConstructorIndexes {
     TemporalDuration,
     #[cfg(feature = "temporal")]
     TemporalPlainTime,
+    #[cfg(feature = "temporal")]
+    TemporalPlainDate,
+    #[cfg(feature = "temporal")]
+    TemporalPlainDateTime,
+    #[cfg(feature = "temporal")]
+    TemporalZonedDateTime,
+    #[cfg(feature = "temporal")]
+    TemporalPlainYearMonth,
+    #[cfg(feature = "temporal")]
+    TemporalPlainMonthDay,
 
     // Text processing
     String,
@@ -240,6 +268,8 @@ pub(crate) enum IntrinsicConstructorIndexes {
     #[cfg(feature = "weak-refs")]
     WeakRef,
     FinalizationRegistry,
+    DisposableStack,
+    AsyncDisposableStack,
 
     // Control abstraction objects
     Iterator,
@@ -250,9 +280,11 @@ pub(crate) enum IntrinsicConstructorIndexes {
 
     // Reflection
     Proxy,
+    AbstractModuleSource,
 
     // Errors subtypes
     AggregateError,
+    SuppressedError,
     EvalError,
     RangeError,
     ReferenceError,
@@ -274,10 +306,12 @@ pub(crate) enum IntrinsicFunctionIndexes {
     ArrayPrototypeSort,
     ArrayPrototypeToString,
     ArrayPrototypeValues,
+    AsyncDisposableStackPrototypeDisposeAsync,
     #[cfg(feature = "date")]
     DatePrototypeToUTCString,
     DecodeURI,
     DecodeURIComponent,
+    DisposableStackPrototypeDispose,
     EncodeURI,
     EncodeURIComponent,
     #[cfg(feature = "annex-b-global")]
@@ -386,7 +420,9 @@ impl IntrinsicObjectShapes {
 #[non_exhaustive]
 #[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
 pub(crate) enum WellKnownSymbols {
+    AsyncDispose,
     AsyncIterator,
+    Dispose,
     HasInstance,
     IsConcatSpreadable,
     Iterator,
@@ -422,7 +458,9 @@ impl TryFrom<Symbol<'_>> for WellKnownSymbols {
     type Error = ();
 
     fn try_from(value: Symbol<'_>) -> Result<Self, Self::Error> {
+        const ASYNCDISPOSE: u32 = WellKnownSymbols::AsyncDispose as u32;
         const ASYNCITERATOR: u32 = WellKnownSymbols::AsyncIterator as u32;
+        const DISPOSE: u32 = WellKnownSymbols::Dispose as u32;
         const HASINSTANCE: u32 = WellKnownSymbols::HasInstance as u32;
         const ISCONCATSPREADABLE: u32 = WellKnownSymbols::IsConcatSpreadable as u32;
         const ITERATOR: u32 = WellKnownSymbols::Iterator as u32;
@@ -436,7 +474,9 @@ impl TryFrom<Symbol<'_>> for WellKnownSymbols {
         const TOSTRINGTAG: u32 = WellKnownSymbols::ToStringTag as u32;
         const UNSCOPABLES: u32 = WellKnownSymbols::Unscopables as u32;
         match value.get_index_u32() {
+            ASYNCDISPOSE => Ok(Self::AsyncDispose),
             ASYNCITERATOR => Ok(Self::AsyncIterator),
+            DISPOSE => Ok(Self::Dispose),
             HASINSTANCE => Ok(Self::HasInstance),
             ISCONCATSPREADABLE => Ok(Self::IsConcatSpreadable),
             ITERATOR => Ok(Self::Iterator),
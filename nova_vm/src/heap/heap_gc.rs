@@ -2,10 +2,12 @@
 // License, v. 2.0. If a copy of the MPL was not distributed with this
 // file, You can obtain one at https://mozilla.org/MPL/2.0/.
 
-use std::thread;
+use std::{thread, time::Instant};
 
 #[cfg(feature = "date")]
 use crate::ecmascript::Date;
+#[cfg(feature = "json")]
+use crate::ecmascript::RawJSON;
 #[cfg(feature = "array-buffer")]
 use crate::ecmascript::{ArrayBuffer, DataView, VoidArray};
 #[cfg(feature = "regexp")]
@@ -18,37 +20,58 @@ use crate::ecmascript::{SharedArrayBuffer, SharedDataView, SharedVoidArray};
 use crate::ecmascript::{TemporalDuration, TemporalInstant};
 #[cfg(feature = "weak-refs")]
 use crate::ecmascript::{WeakMap, WeakRef, WeakSet};
-#[cfg(feature = "array-buffer")]
-use crate::heap::heap_bits::sweep_side_table_values;
 use crate::{
     ecmascript::{
-        Agent, Array, ArrayIterator, AsyncGenerator, AwaitReaction, BUILTIN_STRINGS_LIST,
-        BoundFunction, BuiltinConstructorFunction, BuiltinFunction, BuiltinPromiseFinallyFunction,
-        BuiltinPromiseResolvingFunction, DeclarativeEnvironment, ECMAScriptFunction,
+        Agent, Array, ArrayFromAsync, ArrayIterator, AsyncGenerator, AwaitReaction,
+        BUILTIN_STRINGS_LIST, BoundFunction, BuiltinConstructorFunction, BuiltinFunction,
+        BuiltinPromiseFinallyFunction, BuiltinPromiseResolvingFunction,
+        BuiltinProxyRevokerFunction, DeclarativeEnvironment, DisposableStack, ECMAScriptFunction,
         EmbedderObject, Environments, Error, FinalizationRegistry, FunctionEnvironment, Generator,
-        GlobalEnvironment, HeapBigInt, HeapNumber, HeapString, Map, MapIterator, Module,
-        ModuleEnvironment, ModuleRequest, ObjectEnvironment, ObjectShape, OrdinaryObject,
+        GlobalEnvironment, HeapBigInt, HeapNumber, HeapString, IteratorHelper, Map, MapIterator,
+        Module, ModuleEnvironment, ModuleRequest, ObjectEnvironment, ObjectShape, OrdinaryObject,
         PrimitiveObject, PrivateEnvironment, Promise, PromiseGroup, PromiseReaction,
-        PropertyLookupCache, Proxy, Realm, Script, SourceCode, SourceTextModule, StringIterator,
-        Symbol,
+        PropertyLookupCache, Proxy, Realm, Script, SourceCode, SourceTextModule, StringBuffer,
+        StringIterator, Symbol,
     },
     engine::{Bindable, Executable, GcScope},
     heap::{
-        ElementIndex, Heap, HeapIndexHandle, PropertyKeyIndex, WellKnownSymbols,
-        element_array::ElementArrays,
+        ElementIndex, GcStatistics, Heap, HeapIndexHandle, PropertyKeyIndex, WellKnownSymbols,
+        element_array::{ElementArray, ElementArrays},
         heap_bits::{
             CompactionLists, HeapBits, HeapMarkAndSweep, WorkQueues, mark_descriptors,
-            sweep_heap_elements_vector_descriptors, sweep_heap_soa_vector_values,
-            sweep_heap_vector_values, sweep_lookup_table,
+            mark_remembered_soa_values, mark_remembered_values, sweep_heap_arena_values,
+            sweep_heap_arena_values_with, sweep_heap_elements_vector_descriptors,
+            sweep_heap_soa_arena_values, sweep_lookup_table, sweep_side_table_values,
         },
     },
     ndt,
 };
 
-pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static>>], gc: GcScope) {
+/// Perform a garbage collection.
+///
+/// If `young` is true, only the young generation is collected: all items in
+/// the old generation are retained and only remembered old items are traced.
+/// Otherwise the whole heap is collected. In both cases all surviving items are
+/// promoted into the old generation.
+pub(crate) fn heap_gc(
+    agent: &mut Agent,
+    root_realms: &mut [Option<Realm<'static>>],
+    young: bool,
+    gc: GcScope,
+) {
     ndt::gc_start!(|| ());
+    let start = Instant::now();
+    let bytes_before = agent.heap.byte_size();
 
+    if young {
+        // Embedder object payloads may hold JavaScript values behind interior
+        // mutability, which the write barrier cannot see.
+        agent.heap.embedder_objects.remember_all();
+    }
     let mut bits = HeapBits::new(&agent.heap);
+    if young {
+        bits.mark_old_generation(&agent.heap);
+    }
     bits.strings
         .mark_range(0..(BUILTIN_STRINGS_LIST.len() as u32), &mut bits.bits);
     bits.symbols
@@ -64,6 +87,12 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
     agent.heap.caches.mark_values(&mut queues);
     agent.mark_values(&mut queues);
     let mut has_finalization_registrys = false;
+    if young {
+        mark_remembered(&agent.heap, &mut queues);
+        // Finalization registries in the old generation are not traced, so we
+        // must assume that they exist.
+        has_finalization_registrys = !agent.heap.finalization_registrys.is_empty();
+    }
 
     while !queues.is_empty() {
         let Heap {
@@ -72,6 +101,7 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
             #[cfg(feature = "array-buffer")]
                 array_buffer_detach_keys: _,
             arrays,
+            array_from_async_records,
             array_iterators,
             async_generators,
             await_reactions,
@@ -79,6 +109,7 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
             bound_functions,
             builtin_constructors,
             builtin_functions,
+            builtin_function_host_data,
             caches,
             #[cfg(feature = "date")]
             dates,
@@ -88,10 +119,21 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
             durations,
             #[cfg(feature = "temporal")]
             plain_times,
+            #[cfg(feature = "temporal")]
+            plain_dates,
+            #[cfg(feature = "temporal")]
+            plain_date_times,
+            #[cfg(feature = "temporal")]
+            zoned_date_times,
+            #[cfg(feature = "temporal")]
+            plain_year_months,
+            #[cfg(feature = "temporal")]
+            plain_month_days,
             ecmascript_functions,
             elements,
             embedder_objects,
             environments,
+            disposable_stacks,
             errors,
             executables,
             source_codes,
@@ -111,6 +153,7 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
             promise_reaction_records,
             promise_resolving_functions,
             promise_finally_functions,
+            proxy_revoker_functions,
             promises,
             promise_group_records,
             proxies,
@@ -128,9 +171,13 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
             shared_array_buffers,
             source_text_module_records,
             string_iterators,
+            iterator_helpers,
+            #[cfg(feature = "json")]
+            raw_json_objects,
             strings,
             string_lookup_table: _,
             string_hasher: _,
+            string_bytes: _,
             symbols,
             #[cfg(feature = "array-buffer")]
             typed_arrays,
@@ -167,6 +214,12 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
             #[cfg(feature = "weak-refs")]
             weak_sets,
             alloc_counter: _,
+            max_heap_bytes: _,
+            retained_bytes: _,
+            heap_limit_grace_bytes: _,
+            full_gc_retained_bytes: _,
+            gc_count: _,
+            last_gc: _,
         } = &agent.heap;
         let Environments {
             declarative: declarative_environments,
@@ -188,6 +241,7 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
             e2pow16,
             e2pow24,
             e2pow32,
+            sparse,
             k2pow1,
             k2pow2,
             k2pow3,
@@ -199,6 +253,8 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
             k2pow16,
             k2pow24,
             k2pow32,
+            max_bytes: _,
+            alloc_counter: _,
         } = elements;
 
         if !queues.modules.is_empty() {
@@ -353,6 +409,19 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
             }
         }
 
+        if !queues.array_from_async_records.is_empty() {
+            let mut array_from_async_record_marks: Box<[ArrayFromAsync]> =
+                queues.array_from_async_records.drain(..).collect();
+            array_from_async_record_marks.sort();
+            array_from_async_record_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if bits.array_from_async_records.set_bit(index, &bits.bits) {
+                    // Did mark.
+                    array_from_async_records.get(index).mark_values(&mut queues);
+                }
+            });
+        }
+
         if !queues.array_iterators.is_empty() {
             let mut array_iterator_marks: Box<[ArrayIterator]> =
                 queues.array_iterators.drain(..).collect();
@@ -428,6 +497,18 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                 }
             });
         }
+        if !queues.disposable_stacks.is_empty() {
+            let mut disposable_stack_marks: Box<[DisposableStack]> =
+                queues.disposable_stacks.drain(..).collect();
+            disposable_stack_marks.sort();
+            disposable_stack_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if bits.disposable_stacks.set_bit(index, &bits.bits) {
+                    // Did mark.
+                    disposable_stacks.get(index).mark_values(&mut queues);
+                }
+            });
+        }
         if !queues.errors.is_empty() {
             let mut error_marks: Box<[Error]> = queues.errors.drain(..).collect();
             error_marks.sort();
@@ -482,6 +563,9 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                 if bits.builtin_functions.set_bit(index, &bits.bits) {
                     // Did mark.
                     builtin_functions.get(index).mark_values(&mut queues);
+                    builtin_function_host_data
+                        .get(&idx)
+                        .mark_values(&mut queues);
                 }
             });
         }
@@ -523,7 +607,10 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
         }
         #[cfg(feature = "temporal")]
         {
-            use crate::ecmascript::TemporalPlainTime;
+            use crate::ecmascript::{
+                TemporalPlainDate, TemporalPlainDateTime, TemporalPlainMonthDay, TemporalPlainTime,
+                TemporalPlainYearMonth, TemporalZonedDateTime,
+            };
 
             let mut instant_marks: Box<[TemporalInstant]> = queues.instants.drain(..).collect();
             instant_marks.sort();
@@ -553,6 +640,56 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                     plain_times.get(index).mark_values(&mut queues);
                 }
             });
+            let mut plain_date_marks: Box<[TemporalPlainDate]> =
+                queues.plain_dates.drain(..).collect();
+            plain_date_marks.sort();
+            plain_date_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if bits.plain_dates.set_bit(index, &bits.bits) {
+                    // Did mark.
+                    plain_dates.get(index).mark_values(&mut queues);
+                }
+            });
+            let mut plain_date_time_marks: Box<[TemporalPlainDateTime]> =
+                queues.plain_date_times.drain(..).collect();
+            plain_date_time_marks.sort();
+            plain_date_time_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if bits.plain_date_times.set_bit(index, &bits.bits) {
+                    // Did mark.
+                    plain_date_times.get(index).mark_values(&mut queues);
+                }
+            });
+            let mut zoned_date_time_marks: Box<[TemporalZonedDateTime]> =
+                queues.zoned_date_times.drain(..).collect();
+            zoned_date_time_marks.sort();
+            zoned_date_time_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if bits.zoned_date_times.set_bit(index, &bits.bits) {
+                    // Did mark.
+                    zoned_date_times.get(index).mark_values(&mut queues);
+                }
+            });
+            let mut plain_year_month_marks: Box<[TemporalPlainYearMonth]> =
+                queues.plain_year_months.drain(..).collect();
+            plain_year_month_marks.sort();
+            plain_year_month_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if bits.plain_year_months.set_bit(index, &bits.bits) {
+                    // Did mark.
+                    plain_year_months.get(index).mark_values(&mut queues);
+                }
+            });
+            let mut plain_month_day_marks: Box<[TemporalPlainMonthDay]> =
+                queues.plain_month_days.drain(..).collect();
+            plain_month_day_marks.sort();
+            plain_month_day_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if bits.plain_month_days.set_bit(index, &bits.bits) {
+                    // Did mark.
+                    plain_month_days.get(index).mark_values(&mut queues);
+                }
+            });
         }
 
         if !queues.embedder_objects.is_empty() {
@@ -684,6 +821,18 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                 }
             });
         }
+        if !queues.proxy_revoker_functions.is_empty() {
+            let mut proxy_revoker_function_marks: Box<[BuiltinProxyRevokerFunction]> =
+                queues.proxy_revoker_functions.drain(..).collect();
+            proxy_revoker_function_marks.sort();
+            proxy_revoker_function_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if bits.proxy_revoker_functions.set_bit(index, &bits.bits) {
+                    // Did mark.
+                    proxy_revoker_functions.get(index).mark_values(&mut queues);
+                }
+            });
+        }
         if !queues.proxies.is_empty() {
             let mut proxy_marks: Box<[Proxy]> = queues.proxies.drain(..).collect();
             proxy_marks.sort();
@@ -871,6 +1020,30 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                 }
             });
         }
+        if !queues.iterator_helpers.is_empty() {
+            let mut iterator_helper_marks: Box<[IteratorHelper]> =
+                queues.iterator_helpers.drain(..).collect();
+            iterator_helper_marks.sort();
+            iterator_helper_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if bits.iterator_helpers.set_bit(index, &bits.bits) {
+                    // Did mark.
+                    iterator_helpers.get(index).mark_values(&mut queues);
+                }
+            });
+        }
+        #[cfg(feature = "json")]
+        if !queues.raw_json_objects.is_empty() {
+            let mut raw_json_marks: Box<[RawJSON]> = queues.raw_json_objects.drain(..).collect();
+            raw_json_marks.sort();
+            raw_json_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if bits.raw_json_objects.set_bit(index, &bits.bits) {
+                    // Did mark.
+                    raw_json_objects.get(index).mark_values(&mut queues);
+                }
+            });
+        }
         if !queues.strings.is_empty() {
             let mut string_marks: Box<[HeapString]> = queues.strings.drain(..).collect();
             string_marks.sort();
@@ -952,7 +1125,9 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                         mark_descriptors(descriptors, &mut queues);
                     }
                     e2pow1.values.get(index).mark_values(&mut queues);
-                } else {
+                } else if !bits.young {
+                    // Note: in a young generation garbage collection the old
+                    // generation is pre-marked.
                     panic!("ElementsVector was not unique");
                 }
             });
@@ -967,7 +1142,9 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                         mark_descriptors(descriptors, &mut queues);
                     }
                     e2pow2.values.get(index).mark_values(&mut queues);
-                } else {
+                } else if !bits.young {
+                    // Note: in a young generation garbage collection the old
+                    // generation is pre-marked.
                     panic!("ElementsVector was not unique");
                 }
             });
@@ -982,7 +1159,9 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                         mark_descriptors(descriptors, &mut queues);
                     }
                     e2pow3.values.get(index).mark_values(&mut queues);
-                } else {
+                } else if !bits.young {
+                    // Note: in a young generation garbage collection the old
+                    // generation is pre-marked.
                     panic!("ElementsVector was not unique");
                 }
             });
@@ -997,7 +1176,9 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                         mark_descriptors(descriptors, &mut queues);
                     }
                     e2pow4.values.get(index).mark_values(&mut queues);
-                } else {
+                } else if !bits.young {
+                    // Note: in a young generation garbage collection the old
+                    // generation is pre-marked.
                     panic!("ElementsVector was not unique");
                 }
             });
@@ -1012,7 +1193,9 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                         mark_descriptors(descriptors, &mut queues);
                     }
                     e2pow6.values.get(index).mark_values(&mut queues);
-                } else {
+                } else if !bits.young {
+                    // Note: in a young generation garbage collection the old
+                    // generation is pre-marked.
                     panic!("ElementsVector was not unique");
                 }
             });
@@ -1027,7 +1210,9 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                         mark_descriptors(descriptors, &mut queues);
                     }
                     e2pow8.values.get(index).mark_values(&mut queues);
-                } else {
+                } else if !bits.young {
+                    // Note: in a young generation garbage collection the old
+                    // generation is pre-marked.
                     panic!("ElementsVector was not unique");
                 }
             });
@@ -1042,7 +1227,9 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                         mark_descriptors(descriptors, &mut queues);
                     }
                     e2pow10.values.get(index).mark_values(&mut queues);
-                } else {
+                } else if !bits.young {
+                    // Note: in a young generation garbage collection the old
+                    // generation is pre-marked.
                     panic!("ElementsVector was not unique");
                 }
             });
@@ -1057,7 +1244,9 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                         mark_descriptors(descriptors, &mut queues);
                     }
                     e2pow12.values.get(index).mark_values(&mut queues);
-                } else {
+                } else if !bits.young {
+                    // Note: in a young generation garbage collection the old
+                    // generation is pre-marked.
                     panic!("ElementsVector was not unique");
                 }
             });
@@ -1072,7 +1261,9 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                         mark_descriptors(descriptors, &mut queues);
                     }
                     e2pow16.values.get(index).mark_values(&mut queues);
-                } else {
+                } else if !bits.young {
+                    // Note: in a young generation garbage collection the old
+                    // generation is pre-marked.
                     panic!("ElementsVector was not unique");
                 }
             });
@@ -1087,7 +1278,9 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                         mark_descriptors(descriptors, &mut queues);
                     }
                     e2pow24.values.get(index).mark_values(&mut queues);
-                } else {
+                } else if !bits.young {
+                    // Note: in a young generation garbage collection the old
+                    // generation is pre-marked.
                     panic!("ElementsVector was not unique");
                 }
             });
@@ -1102,7 +1295,23 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
                         mark_descriptors(descriptors, &mut queues);
                     }
                     e2pow32.values.get(index).mark_values(&mut queues);
-                } else {
+                } else if !bits.young {
+                    // Note: in a young generation garbage collection the old
+                    // generation is pre-marked.
+                    panic!("ElementsVector was not unique");
+                }
+            });
+        }
+        if !queues.e_sparse.is_empty() {
+            let mut e_sparse_marks: Box<[ElementIndex]> = queues.e_sparse.drain(..).collect();
+            e_sparse_marks.sort();
+            e_sparse_marks.iter().for_each(|&idx| {
+                let index = idx.get_index();
+                if bits.e_sparse.set_bit(index, &bits.bits) {
+                    sparse.get(index).mark_values(&mut queues);
+                } else if !bits.young {
+                    // Note: in a young generation garbage collection the old
+                    // generation is pre-marked.
                     panic!("ElementsVector was not unique");
                 }
             });
@@ -1221,12 +1430,177 @@ pub(crate) fn heap_gc(agent: &mut Agent, root_realms: &mut [Option<Realm<'static
     }
 
     sweep(agent, &bits, root_realms, gc);
+    agent.heap.retained_bytes = agent.heap.byte_size();
+    agent.heap.heap_limit_grace_bytes = 0;
+    if !young {
+        agent.heap.full_gc_retained_bytes = agent.heap.retained_bytes;
+    }
+    agent.heap.gc_count += 1;
+    agent.heap.last_gc = Some(GcStatistics {
+        young,
+        duration: start.elapsed(),
+        freed_bytes: bytes_before.saturating_sub(agent.heap.retained_bytes),
+        retained_bytes: agent.heap.retained_bytes,
+    });
     if has_finalization_registrys {
         FinalizationRegistry::enqueue_cleanup_jobs(agent);
     }
     ndt::gc_done!(|| ());
 }
 
+/// Mark the values of all remembered old generation items in the heap. These
+/// are the roots of a young generation garbage collection in addition to the
+/// normal roots.
+fn mark_remembered(heap: &Heap, queues: &mut WorkQueues) {
+    mark_remembered_element_values(&heap.elements.e2pow1, queues);
+    mark_remembered_element_values(&heap.elements.e2pow2, queues);
+    mark_remembered_element_values(&heap.elements.e2pow3, queues);
+    mark_remembered_element_values(&heap.elements.e2pow4, queues);
+    mark_remembered_element_values(&heap.elements.e2pow6, queues);
+    mark_remembered_element_values(&heap.elements.e2pow8, queues);
+    mark_remembered_values(&heap.elements.k2pow1.keys, queues);
+    mark_remembered_values(&heap.elements.k2pow2.keys, queues);
+    mark_remembered_values(&heap.elements.k2pow3.keys, queues);
+    mark_remembered_values(&heap.elements.k2pow4.keys, queues);
+    mark_remembered_values(&heap.elements.k2pow6.keys, queues);
+    mark_remembered_values(&heap.elements.k2pow8.keys, queues);
+    mark_remembered_element_values(&heap.elements.e2pow10, queues);
+    mark_remembered_element_values(&heap.elements.e2pow12, queues);
+    mark_remembered_element_values(&heap.elements.e2pow16, queues);
+    mark_remembered_values(&heap.elements.k2pow10.keys, queues);
+    mark_remembered_values(&heap.elements.k2pow12.keys, queues);
+    mark_remembered_values(&heap.elements.k2pow16.keys, queues);
+    mark_remembered_element_values(&heap.elements.e2pow24, queues);
+    mark_remembered_element_values(&heap.elements.e2pow32, queues);
+    mark_remembered_values(&heap.elements.sparse, queues);
+    mark_remembered_values(&heap.elements.k2pow24.keys, queues);
+    mark_remembered_values(&heap.elements.k2pow32.keys, queues);
+    #[cfg(feature = "array-buffer")]
+    mark_remembered_values(&heap.array_buffers, queues);
+    mark_remembered_soa_values(&heap.arrays, queues);
+    mark_remembered_values(&heap.array_from_async_records, queues);
+    mark_remembered_values(&heap.array_iterators, queues);
+    mark_remembered_values(&heap.async_generators, queues);
+    mark_remembered_values(&heap.await_reactions, queues);
+    mark_remembered_values(&heap.bigints, queues);
+    mark_remembered_values(&heap.bound_functions, queues);
+    mark_remembered_values(&heap.builtin_constructors, queues);
+    mark_remembered_values(&heap.builtin_functions, queues);
+    #[cfg(feature = "array-buffer")]
+    mark_remembered_values(&heap.data_views, queues);
+    #[cfg(feature = "date")]
+    mark_remembered_values(&heap.dates, queues);
+    #[cfg(feature = "temporal")]
+    mark_remembered_values(&heap.instants, queues);
+    #[cfg(feature = "temporal")]
+    mark_remembered_values(&heap.durations, queues);
+    #[cfg(feature = "temporal")]
+    mark_remembered_values(&heap.plain_times, queues);
+    #[cfg(feature = "temporal")]
+    mark_remembered_values(&heap.plain_dates, queues);
+    #[cfg(feature = "temporal")]
+    mark_remembered_values(&heap.plain_date_times, queues);
+    #[cfg(feature = "temporal")]
+    mark_remembered_values(&heap.zoned_date_times, queues);
+    #[cfg(feature = "temporal")]
+    mark_remembered_values(&heap.plain_year_months, queues);
+    #[cfg(feature = "temporal")]
+    mark_remembered_values(&heap.plain_month_days, queues);
+    mark_remembered_values(&heap.environments.declarative, queues);
+    mark_remembered_values(&heap.ecmascript_functions, queues);
+    mark_remembered_values(&heap.embedder_objects, queues);
+    mark_remembered_values(&heap.disposable_stacks, queues);
+    mark_remembered_values(&heap.errors, queues);
+    mark_remembered_values(&heap.executables, queues);
+    mark_remembered_values(&heap.source_codes, queues);
+    mark_remembered_soa_values(&heap.finalization_registrys, queues);
+    mark_remembered_values(&heap.environments.function, queues);
+    mark_remembered_values(&heap.generators, queues);
+    mark_remembered_values(&heap.environments.global, queues);
+    mark_remembered_soa_values(&heap.maps, queues);
+    mark_remembered_values(&heap.map_iterators, queues);
+    mark_remembered_values(&heap.environments.module, queues);
+    mark_remembered_values(&heap.modules, queues);
+    mark_remembered_values(&heap.module_request_records, queues);
+    mark_remembered_values(&heap.numbers, queues);
+    mark_remembered_values(&heap.environments.object, queues);
+    mark_remembered_values(&heap.primitive_objects, queues);
+    mark_remembered_values(&heap.promise_reaction_records, queues);
+    mark_remembered_values(&heap.promise_resolving_functions, queues);
+    mark_remembered_values(&heap.promise_finally_functions, queues);
+    mark_remembered_values(&heap.proxy_revoker_functions, queues);
+    mark_remembered_values(&heap.environments.private, queues);
+    mark_remembered_values(&heap.promises, queues);
+    mark_remembered_values(&heap.promise_group_records, queues);
+    mark_remembered_values(&heap.proxies, queues);
+    mark_remembered_values(&heap.realms, queues);
+    #[cfg(feature = "regexp")]
+    mark_remembered_values(&heap.regexps, queues);
+    #[cfg(feature = "regexp")]
+    mark_remembered_values(&heap.regexp_string_iterators, queues);
+    mark_remembered_values(&heap.scripts, queues);
+    #[cfg(feature = "set")]
+    mark_remembered_soa_values(&heap.sets, queues);
+    #[cfg(feature = "set")]
+    mark_remembered_values(&heap.set_iterators, queues);
+    #[cfg(feature = "shared-array-buffer")]
+    mark_remembered_values(&heap.shared_array_buffers, queues);
+    #[cfg(feature = "shared-array-buffer")]
+    mark_remembered_values(&heap.shared_data_views, queues);
+    #[cfg(feature = "shared-array-buffer")]
+    mark_remembered_values(&heap.shared_typed_arrays, queues);
+    mark_remembered_values(&heap.source_text_module_records.0, queues);
+    mark_remembered_values(&heap.string_iterators, queues);
+    mark_remembered_values(&heap.iterator_helpers, queues);
+    #[cfg(feature = "json")]
+    mark_remembered_values(&heap.raw_json_objects, queues);
+    mark_remembered_values(&heap.strings, queues);
+    mark_remembered_values(&heap.symbols, queues);
+    #[cfg(feature = "array-buffer")]
+    mark_remembered_values(&heap.typed_arrays, queues);
+    #[cfg(feature = "weak-refs")]
+    mark_remembered_values(&heap.weak_maps, queues);
+    #[cfg(feature = "weak-refs")]
+    mark_remembered_values(&heap.weak_refs, queues);
+    #[cfg(feature = "weak-refs")]
+    mark_remembered_values(&heap.weak_sets, queues);
+    for index in heap
+        .object_shapes
+        .remembered_set()
+        .iter()
+        .chain(heap.object_shape_transitions.remembered_set().iter())
+    {
+        if let Some(shape) = heap.object_shapes.get(index) {
+            shape.mark_values(queues);
+            heap.object_shape_transitions.get(index).mark_values(queues);
+        }
+    }
+    for index in heap.objects.remembered_set().iter() {
+        if let Some(rec) = heap.objects.get(index) {
+            rec.mark_values(queues, &heap.object_shapes);
+        }
+    }
+    // Property lookup caches are not behind a write barrier: trace them all.
+    for index in 0..heap.caches.len() {
+        heap.caches.mark_cache(index, queues);
+    }
+}
+
+/// Mark the values of all remembered old generation element arrays, and the
+/// descriptors of all old generation element arrays.
+fn mark_remembered_element_values<const N: usize>(
+    elements: &ElementArray<N>,
+    queues: &mut WorkQueues,
+) {
+    let old_len = elements.values.remembered_set().old_len();
+    for (index, descriptors) in elements.descriptors.iter() {
+        if index.get_index() < old_len {
+            mark_descriptors(descriptors, queues);
+        }
+    }
+    mark_remembered_values(&elements.values, queues);
+}
+
 // NOTE: This is the one true use of the `GcScope` which is why we allow a lint
 // exception here. For future reference see [this comment](https://github.com/trynova/nova/pull/913#discussion_r2616482397).
 #[allow(unknown_lints, can_use_no_gc_scope)]
@@ -1250,6 +1624,7 @@ fn sweep(
         #[cfg(feature = "array-buffer")]
         array_buffer_detach_keys,
         arrays,
+        array_from_async_records,
         array_iterators,
         async_generators,
         await_reactions,
@@ -1257,6 +1632,7 @@ fn sweep(
         bound_functions,
         builtin_constructors,
         builtin_functions,
+        builtin_function_host_data,
         caches,
         #[cfg(feature = "date")]
         dates,
@@ -1266,10 +1642,21 @@ fn sweep(
         durations,
         #[cfg(feature = "temporal")]
         plain_times,
+        #[cfg(feature = "temporal")]
+        plain_dates,
+        #[cfg(feature = "temporal")]
+        plain_date_times,
+        #[cfg(feature = "temporal")]
+        zoned_date_times,
+        #[cfg(feature = "temporal")]
+        plain_year_months,
+        #[cfg(feature = "temporal")]
+        plain_month_days,
         ecmascript_functions,
         elements,
         embedder_objects,
         environments,
+        disposable_stacks,
         errors,
         executables,
         source_codes,
@@ -1289,6 +1676,7 @@ fn sweep(
         promise_reaction_records,
         promise_resolving_functions,
         promise_finally_functions,
+        proxy_revoker_functions,
         promises,
         promise_group_records,
         proxies,
@@ -1306,9 +1694,13 @@ fn sweep(
         shared_array_buffers,
         source_text_module_records,
         string_iterators,
+        iterator_helpers,
+        #[cfg(feature = "json")]
+        raw_json_objects,
         strings,
         string_lookup_table,
         string_hasher: _,
+        string_bytes,
         symbols,
         #[cfg(feature = "array-buffer")]
         typed_arrays,
@@ -1345,6 +1737,12 @@ fn sweep(
         #[cfg(feature = "weak-refs")]
         weak_sets,
         alloc_counter,
+        max_heap_bytes: _,
+        retained_bytes: _,
+        heap_limit_grace_bytes: _,
+        full_gc_retained_bytes: _,
+        gc_count: _,
+        last_gc: _,
     } = &mut agent.heap;
     // Reset the allocation counter.
     *alloc_counter = 0;
@@ -1368,6 +1766,7 @@ fn sweep(
         e2pow16,
         e2pow24,
         e2pow32,
+        sparse,
         k2pow1,
         k2pow2,
         k2pow3,
@@ -1379,7 +1778,10 @@ fn sweep(
         k2pow16,
         k2pow24,
         k2pow32,
+        max_bytes: _,
+        alloc_counter: elements_alloc_counter,
     } = elements;
+    *elements_alloc_counter = 0;
 
     let mut globals = globals.borrow_mut();
     let globals_iter = globals.iter_mut();
@@ -1398,6 +1800,16 @@ fn sweep(
                 value.sweep_values(&compactions);
             }
         });
+        if !embedder_objects.is_empty() {
+            s.spawn(|| {
+                sweep_heap_arena_values(
+                    embedder_objects,
+                    &compactions,
+                    &bits.embedder_objects,
+                    bits,
+                );
+            });
+        }
         if !e2pow1.values.is_empty() {
             s.spawn(|| {
                 sweep_heap_elements_vector_descriptors(
@@ -1407,7 +1819,7 @@ fn sweep(
                     &bits.e_2_1,
                     &bits.bits,
                 );
-                sweep_heap_vector_values(&mut e2pow1.values, &compactions, &bits.e_2_1, &bits.bits);
+                sweep_heap_arena_values(&mut e2pow1.values, &compactions, &bits.e_2_1, bits);
             });
         }
         if !e2pow2.values.is_empty() {
@@ -1419,7 +1831,7 @@ fn sweep(
                     &bits.e_2_2,
                     &bits.bits,
                 );
-                sweep_heap_vector_values(&mut e2pow2.values, &compactions, &bits.e_2_2, &bits.bits);
+                sweep_heap_arena_values(&mut e2pow2.values, &compactions, &bits.e_2_2, bits);
             });
         }
         if !e2pow3.values.is_empty() {
@@ -1431,7 +1843,7 @@ fn sweep(
                     &bits.e_2_3,
                     &bits.bits,
                 );
-                sweep_heap_vector_values(&mut e2pow3.values, &compactions, &bits.e_2_3, &bits.bits);
+                sweep_heap_arena_values(&mut e2pow3.values, &compactions, &bits.e_2_3, bits);
             });
         }
         if !e2pow4.values.is_empty() {
@@ -1443,7 +1855,7 @@ fn sweep(
                     &bits.e_2_4,
                     &bits.bits,
                 );
-                sweep_heap_vector_values(&mut e2pow4.values, &compactions, &bits.e_2_4, &bits.bits);
+                sweep_heap_arena_values(&mut e2pow4.values, &compactions, &bits.e_2_4, bits);
             });
         }
         if !e2pow6.values.is_empty() {
@@ -1455,7 +1867,7 @@ fn sweep(
                     &bits.e_2_6,
                     &bits.bits,
                 );
-                sweep_heap_vector_values(&mut e2pow6.values, &compactions, &bits.e_2_6, &bits.bits);
+                sweep_heap_arena_values(&mut e2pow6.values, &compactions, &bits.e_2_6, bits);
             });
         }
         if !e2pow8.values.is_empty() {
@@ -1467,7 +1879,7 @@ fn sweep(
                     &bits.e_2_8,
                     &bits.bits,
                 );
-                sweep_heap_vector_values(&mut e2pow8.values, &compactions, &bits.e_2_8, &bits.bits);
+                sweep_heap_arena_values(&mut e2pow8.values, &compactions, &bits.e_2_8, bits);
             });
         }
         if !e2pow10.values.is_empty() {
@@ -1479,12 +1891,7 @@ fn sweep(
                     &bits.e_2_10,
                     &bits.bits,
                 );
-                sweep_heap_vector_values(
-                    &mut e2pow10.values,
-                    &compactions,
-                    &bits.e_2_10,
-                    &bits.bits,
-                );
+                sweep_heap_arena_values(&mut e2pow10.values, &compactions, &bits.e_2_10, bits);
             });
         }
         if !e2pow12.values.is_empty() {
@@ -1496,12 +1903,7 @@ fn sweep(
                     &bits.e_2_12,
                     &bits.bits,
                 );
-                sweep_heap_vector_values(
-                    &mut e2pow12.values,
-                    &compactions,
-                    &bits.e_2_12,
-                    &bits.bits,
-                );
+                sweep_heap_arena_values(&mut e2pow12.values, &compactions, &bits.e_2_12, bits);
             });
         }
         if !e2pow16.values.is_empty() {
@@ -1513,12 +1915,7 @@ fn sweep(
                     &bits.e_2_16,
                     &bits.bits,
                 );
-                sweep_heap_vector_values(
-                    &mut e2pow16.values,
-                    &compactions,
-                    &bits.e_2_16,
-                    &bits.bits,
-                );
+                sweep_heap_arena_values(&mut e2pow16.values, &compactions, &bits.e_2_16, bits);
             });
         }
         if !e2pow24.values.is_empty() {
@@ -1530,12 +1927,7 @@ fn sweep(
                     &bits.e_2_24,
                     &bits.bits,
                 );
-                sweep_heap_vector_values(
-                    &mut e2pow24.values,
-                    &compactions,
-                    &bits.e_2_24,
-                    &bits.bits,
-                );
+                sweep_heap_arena_values(&mut e2pow24.values, &compactions, &bits.e_2_24, bits);
             });
         }
         if !e2pow32.values.is_empty() {
@@ -1547,155 +1939,149 @@ fn sweep(
                     &bits.e_2_32,
                     &bits.bits,
                 );
-                sweep_heap_vector_values(
-                    &mut e2pow32.values,
-                    &compactions,
-                    &bits.e_2_32,
-                    &bits.bits,
-                );
+                sweep_heap_arena_values(&mut e2pow32.values, &compactions, &bits.e_2_32, bits);
+            });
+        }
+        if !sparse.is_empty() {
+            s.spawn(|| {
+                sweep_heap_arena_values(sparse, &compactions, &bits.e_sparse, bits);
             });
         }
         if !k2pow1.keys.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(&mut k2pow1.keys, &compactions, &bits.k_2_1, &bits.bits);
+                sweep_heap_arena_values(&mut k2pow1.keys, &compactions, &bits.k_2_1, bits);
             });
         }
         if !k2pow2.keys.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(&mut k2pow2.keys, &compactions, &bits.k_2_2, &bits.bits);
+                sweep_heap_arena_values(&mut k2pow2.keys, &compactions, &bits.k_2_2, bits);
             });
         }
         if !k2pow3.keys.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(&mut k2pow3.keys, &compactions, &bits.k_2_3, &bits.bits);
+                sweep_heap_arena_values(&mut k2pow3.keys, &compactions, &bits.k_2_3, bits);
             });
         }
         if !k2pow4.keys.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(&mut k2pow4.keys, &compactions, &bits.k_2_4, &bits.bits);
+                sweep_heap_arena_values(&mut k2pow4.keys, &compactions, &bits.k_2_4, bits);
             });
         }
         if !k2pow6.keys.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(&mut k2pow6.keys, &compactions, &bits.k_2_6, &bits.bits);
+                sweep_heap_arena_values(&mut k2pow6.keys, &compactions, &bits.k_2_6, bits);
             });
         }
         if !k2pow8.keys.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(&mut k2pow8.keys, &compactions, &bits.k_2_8, &bits.bits);
+                sweep_heap_arena_values(&mut k2pow8.keys, &compactions, &bits.k_2_8, bits);
             });
         }
         if !k2pow10.keys.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(&mut k2pow10.keys, &compactions, &bits.k_2_10, &bits.bits);
+                sweep_heap_arena_values(&mut k2pow10.keys, &compactions, &bits.k_2_10, bits);
             });
         }
         if !k2pow12.keys.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(&mut k2pow12.keys, &compactions, &bits.k_2_12, &bits.bits);
+                sweep_heap_arena_values(&mut k2pow12.keys, &compactions, &bits.k_2_12, bits);
             });
         }
         if !k2pow16.keys.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(&mut k2pow16.keys, &compactions, &bits.k_2_16, &bits.bits);
+                sweep_heap_arena_values(&mut k2pow16.keys, &compactions, &bits.k_2_16, bits);
             });
         }
         if !k2pow24.keys.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(&mut k2pow24.keys, &compactions, &bits.k_2_24, &bits.bits);
+                sweep_heap_arena_values(&mut k2pow24.keys, &compactions, &bits.k_2_24, bits);
             });
         }
         if !k2pow32.keys.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(&mut k2pow32.keys, &compactions, &bits.k_2_32, &bits.bits);
+                sweep_heap_arena_values(&mut k2pow32.keys, &compactions, &bits.k_2_32, bits);
             });
         }
         #[cfg(feature = "array-buffer")]
         if !array_buffers.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
-                    array_buffers,
-                    &compactions,
-                    &bits.array_buffers,
-                    &bits.bits,
-                );
+                sweep_heap_arena_values(array_buffers, &compactions, &bits.array_buffers, bits);
                 sweep_side_table_values(array_buffer_detach_keys, &compactions);
             });
         }
         if !arrays.is_empty() {
             s.spawn(|| {
-                sweep_heap_soa_vector_values(arrays, &compactions, &bits.arrays, &bits.bits);
+                sweep_heap_soa_arena_values(arrays, &compactions, &bits.arrays, bits);
             });
         }
-        if !array_iterators.is_empty() {
+        if !array_from_async_records.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
-                    array_iterators,
+                sweep_heap_arena_values(
+                    array_from_async_records,
                     &compactions,
-                    &bits.array_iterators,
-                    &bits.bits,
+                    &bits.array_from_async_records,
+                    bits,
                 );
             });
         }
+        if !array_iterators.is_empty() {
+            s.spawn(|| {
+                sweep_heap_arena_values(array_iterators, &compactions, &bits.array_iterators, bits);
+            });
+        }
         if !async_generators.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
+                sweep_heap_arena_values(
                     async_generators,
                     &compactions,
                     &bits.async_generators,
-                    &bits.bits,
+                    bits,
                 );
             });
         }
         if !await_reactions.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
-                    await_reactions,
-                    &compactions,
-                    &bits.await_reactions,
-                    &bits.bits,
-                );
+                sweep_heap_arena_values(await_reactions, &compactions, &bits.await_reactions, bits);
             });
         }
         if !bigints.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(bigints, &compactions, &bits.bigints, &bits.bits);
+                sweep_heap_arena_values(bigints, &compactions, &bits.bigints, bits);
             });
         }
         if !bound_functions.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
-                    bound_functions,
-                    &compactions,
-                    &bits.bound_functions,
-                    &bits.bits,
-                );
+                sweep_heap_arena_values(bound_functions, &compactions, &bits.bound_functions, bits);
             });
         }
         if !builtin_constructors.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
+                sweep_heap_arena_values(
                     builtin_constructors,
                     &compactions,
                     &bits.builtin_constructors,
-                    &bits.bits,
+                    bits,
                 );
             });
         }
         if !builtin_functions.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
+                sweep_heap_arena_values(
                     builtin_functions,
                     &compactions,
                     &bits.builtin_functions,
-                    &bits.bits,
+                    bits,
                 );
+                sweep_side_table_values(builtin_function_host_data, &compactions);
+                builtin_function_host_data
+                    .values_mut()
+                    .for_each(|data| data.sweep_values(&compactions));
             });
         }
         #[cfg(feature = "array-buffer")]
         if !data_views.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(data_views, &compactions, &bits.data_views, &bits.bits);
+                sweep_heap_arena_values(data_views, &compactions, &bits.data_views, bits);
                 sweep_side_table_values(data_view_byte_lengths, &compactions);
                 sweep_side_table_values(data_view_byte_offsets, &compactions);
             });
@@ -1703,11 +2089,11 @@ fn sweep(
         #[cfg(feature = "shared-array-buffer")]
         if !shared_data_views.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
+                sweep_heap_arena_values(
                     shared_data_views,
                     &compactions,
                     &bits.shared_data_views,
-                    &bits.bits,
+                    bits,
                 );
                 sweep_side_table_values(shared_data_view_byte_lengths, &compactions);
                 sweep_side_table_values(shared_data_view_byte_offsets, &compactions);
@@ -1716,363 +2102,411 @@ fn sweep(
         #[cfg(feature = "date")]
         if !dates.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(dates, &compactions, &bits.dates, &bits.bits);
+                sweep_heap_arena_values(dates, &compactions, &bits.dates, bits);
             });
         }
         #[cfg(feature = "temporal")]
         if !instants.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(instants, &compactions, &bits.instants, &bits.bits);
+                sweep_heap_arena_values(instants, &compactions, &bits.instants, bits);
             });
         }
         #[cfg(feature = "temporal")]
         if !durations.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(durations, &compactions, &bits.durations, &bits.bits);
+                sweep_heap_arena_values(durations, &compactions, &bits.durations, bits);
             });
         }
         #[cfg(feature = "temporal")]
         if !plain_times.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(plain_times, &compactions, &bits.plain_times, &bits.bits);
+                sweep_heap_arena_values(plain_times, &compactions, &bits.plain_times, bits);
+            });
+        }
+        #[cfg(feature = "temporal")]
+        if !plain_dates.is_empty() {
+            s.spawn(|| {
+                sweep_heap_arena_values(plain_dates, &compactions, &bits.plain_dates, bits);
+            });
+        }
+        #[cfg(feature = "temporal")]
+        if !plain_date_times.is_empty() {
+            s.spawn(|| {
+                sweep_heap_arena_values(
+                    plain_date_times,
+                    &compactions,
+                    &bits.plain_date_times,
+                    bits,
+                );
+            });
+        }
+        #[cfg(feature = "temporal")]
+        if !zoned_date_times.is_empty() {
+            s.spawn(|| {
+                sweep_heap_arena_values(
+                    zoned_date_times,
+                    &compactions,
+                    &bits.zoned_date_times,
+                    bits,
+                );
+            });
+        }
+        #[cfg(feature = "temporal")]
+        if !plain_year_months.is_empty() {
+            s.spawn(|| {
+                sweep_heap_arena_values(
+                    plain_year_months,
+                    &compactions,
+                    &bits.plain_year_months,
+                    bits,
+                );
+            });
+        }
+        #[cfg(feature = "temporal")]
+        if !plain_month_days.is_empty() {
+            s.spawn(|| {
+                sweep_heap_arena_values(
+                    plain_month_days,
+                    &compactions,
+                    &bits.plain_month_days,
+                    bits,
+                );
             });
         }
         if !declarative.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
+                sweep_heap_arena_values(
                     declarative,
                     &compactions,
                     &bits.declarative_environments,
-                    &bits.bits,
+                    bits,
                 );
             });
         }
         if !ecmascript_functions.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
+                sweep_heap_arena_values(
                     ecmascript_functions,
                     &compactions,
                     &bits.ecmascript_functions,
-                    &bits.bits,
+                    bits,
                 );
             });
         }
-        if !embedder_objects.is_empty() {
+        if !disposable_stacks.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
-                    embedder_objects,
+                sweep_heap_arena_values(
+                    disposable_stacks,
                     &compactions,
-                    &bits.embedder_objects,
-                    &bits.bits,
+                    &bits.disposable_stacks,
+                    bits,
                 );
             });
         }
         if !errors.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(errors, &compactions, &bits.errors, &bits.bits);
+                sweep_heap_arena_values(errors, &compactions, &bits.errors, bits);
             });
         }
         if !executables.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(executables, &compactions, &bits.executables, &bits.bits);
+                sweep_heap_arena_values(executables, &compactions, &bits.executables, bits);
             });
         }
         if !finalization_registrys.is_empty() {
             s.spawn(|| {
-                sweep_heap_soa_vector_values(
+                sweep_heap_soa_arena_values(
                     finalization_registrys,
                     &compactions,
                     &bits.finalization_registrys,
-                    &bits.bits,
+                    bits,
                 );
             });
         }
         if !function.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
-                    function,
-                    &compactions,
-                    &bits.function_environments,
-                    &bits.bits,
-                );
+                sweep_heap_arena_values(function, &compactions, &bits.function_environments, bits);
             });
         }
         if !generators.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(generators, &compactions, &bits.generators, &bits.bits);
+                sweep_heap_arena_values(generators, &compactions, &bits.generators, bits);
             });
         }
         if !global.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
-                    global,
-                    &compactions,
-                    &bits.global_environments,
-                    &bits.bits,
-                );
+                sweep_heap_arena_values(global, &compactions, &bits.global_environments, bits);
             });
         }
         if !module.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
-                    module,
-                    &compactions,
-                    &bits.module_environments,
-                    &bits.bits,
-                );
+                sweep_heap_arena_values(module, &compactions, &bits.module_environments, bits);
             });
         }
         if !maps.is_empty() {
             s.spawn(|| {
-                sweep_heap_soa_vector_values(maps, &compactions, &bits.maps, &bits.bits);
+                sweep_heap_soa_arena_values(maps, &compactions, &bits.maps, bits);
             });
         }
         if !map_iterators.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
-                    map_iterators,
-                    &compactions,
-                    &bits.map_iterators,
-                    &bits.bits,
-                );
+                sweep_heap_arena_values(map_iterators, &compactions, &bits.map_iterators, bits);
             });
         }
         if !modules.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(modules, &compactions, &bits.modules, &bits.bits);
+                sweep_heap_arena_values(modules, &compactions, &bits.modules, bits);
             });
         }
         if !module_request_records.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
+                sweep_heap_arena_values(
                     module_request_records,
                     &compactions,
                     &bits.module_request_records,
-                    &bits.bits,
+                    bits,
                 );
             });
         }
         if !numbers.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(numbers, &compactions, &bits.numbers, &bits.bits);
+                sweep_heap_arena_values(numbers, &compactions, &bits.numbers, bits);
             });
         }
         if !object.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
-                    object,
-                    &compactions,
-                    &bits.object_environments,
-                    &bits.bits,
-                );
+                sweep_heap_arena_values(object, &compactions, &bits.object_environments, bits);
             });
         }
         if !private.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
-                    private,
-                    &compactions,
-                    &bits.private_environments,
-                    &bits.bits,
-                );
+                sweep_heap_arena_values(private, &compactions, &bits.private_environments, bits);
             });
         }
         if !object_shapes.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
+                sweep_heap_arena_values(
                     object_shape_transitions,
                     &compactions,
                     &bits.object_shapes,
-                    &bits.bits,
+                    bits,
                 );
             });
         }
         if !object_shapes.is_empty() || !objects.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
-                    object_shapes,
-                    &compactions,
-                    &bits.object_shapes,
-                    &bits.bits,
-                );
-                assert_eq!(objects.len(), bits.objects.len());
-                let mut iter = bits.objects.iter(&bits.bits);
-                objects.retain_mut(|item| {
-                    let do_retain = iter.next().unwrap();
-                    if do_retain {
-                        item.sweep_values(&compactions, object_shapes);
-                        true
-                    } else {
-                        false
-                    }
-                });
+                sweep_heap_arena_values(object_shapes, &compactions, &bits.object_shapes, bits);
+                {
+                    // SAFETY: The arena is promoted after sweeping.
+                    let (objects, remembered_set) = unsafe { objects.split_mut_unremembered() };
+                    assert_eq!(objects.len(), bits.objects.len());
+                    let mut iter = bits.objects.iter(&bits.bits);
+                    let mut index = 0;
+                    objects.retain_mut(|item| {
+                        let do_retain = iter.next().unwrap();
+                        if do_retain
+                            && (!bits.young || remembered_set.is_young_or_remembered(index))
+                        {
+                            item.sweep_values(&compactions, object_shapes);
+                        }
+                        index += 1;
+                        do_retain
+                    });
+                }
+                objects.promote();
             });
         }
         if !primitive_objects.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
+                sweep_heap_arena_values(
                     primitive_objects,
                     &compactions,
                     &bits.primitive_objects,
-                    &bits.bits,
+                    bits,
                 );
             });
         }
         if !promise_reaction_records.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
+                sweep_heap_arena_values(
                     promise_reaction_records,
                     &compactions,
                     &bits.promise_reaction_records,
-                    &bits.bits,
+                    bits,
                 );
             });
         }
         if !promise_resolving_functions.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
+                sweep_heap_arena_values(
                     promise_resolving_functions,
                     &compactions,
                     &bits.promise_resolving_functions,
-                    &bits.bits,
+                    bits,
                 );
             });
         }
         if !promise_finally_functions.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
+                sweep_heap_arena_values(
                     promise_finally_functions,
                     &compactions,
                     &bits.promise_finally_functions,
-                    &bits.bits,
+                    bits,
+                );
+            });
+        }
+        if !proxy_revoker_functions.is_empty() {
+            s.spawn(|| {
+                sweep_heap_arena_values(
+                    proxy_revoker_functions,
+                    &compactions,
+                    &bits.proxy_revoker_functions,
+                    bits,
                 );
             });
         }
         if !promises.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(promises, &compactions, &bits.promises, &bits.bits);
+                sweep_heap_arena_values(promises, &compactions, &bits.promises, bits);
             });
         }
         if !promise_group_records.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
+                sweep_heap_arena_values(
                     promise_group_records,
                     &compactions,
                     &bits.promise_group_records,
-                    &bits.bits,
+                    bits,
                 );
             });
         }
         if !proxies.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(proxies, &compactions, &bits.proxies, &bits.bits);
+                sweep_heap_arena_values(proxies, &compactions, &bits.proxies, bits);
             });
         }
         if !realms.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(realms, &compactions, &bits.realms, &bits.bits);
+                sweep_heap_arena_values(realms, &compactions, &bits.realms, bits);
             });
         }
         #[cfg(feature = "regexp")]
         if !regexps.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(regexps, &compactions, &bits.regexps, &bits.bits);
+                sweep_heap_arena_values(regexps, &compactions, &bits.regexps, bits);
             });
         }
         #[cfg(feature = "regexp")]
         if !regexp_string_iterators.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
+                sweep_heap_arena_values(
                     regexp_string_iterators,
                     &compactions,
                     &bits.regexp_string_iterators,
-                    &bits.bits,
+                    bits,
                 );
             });
         }
         if !scripts.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(scripts, &compactions, &bits.scripts, &bits.bits);
+                sweep_heap_arena_values(scripts, &compactions, &bits.scripts, bits);
             });
         }
         #[cfg(feature = "set")]
         if !sets.is_empty() {
             s.spawn(|| {
-                sweep_heap_soa_vector_values(sets, &compactions, &bits.sets, &bits.bits);
+                sweep_heap_soa_arena_values(sets, &compactions, &bits.sets, bits);
             });
         }
         #[cfg(feature = "set")]
         if !set_iterators.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
-                    set_iterators,
-                    &compactions,
-                    &bits.set_iterators,
-                    &bits.bits,
-                );
+                sweep_heap_arena_values(set_iterators, &compactions, &bits.set_iterators, bits);
             });
         }
         #[cfg(feature = "shared-array-buffer")]
         if !shared_array_buffers.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
+                sweep_heap_arena_values(
                     shared_array_buffers,
                     &compactions,
                     &bits.shared_array_buffers,
-                    &bits.bits,
+                    bits,
                 );
             });
         }
         if !source_text_module_records.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
-                    source_text_module_records,
+                sweep_heap_arena_values(
+                    &mut source_text_module_records.0,
                     &compactions,
                     &bits.source_text_module_records,
-                    &bits.bits,
+                    bits,
                 );
             });
         }
         if !source_codes.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
-                    source_codes,
-                    &compactions,
-                    &bits.source_codes,
-                    &bits.bits,
-                );
+                sweep_heap_arena_values(source_codes, &compactions, &bits.source_codes, bits);
             });
         }
         if !string_iterators.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
+                sweep_heap_arena_values(
                     string_iterators,
                     &compactions,
                     &bits.string_iterators,
-                    &bits.bits,
+                    bits,
+                );
+            });
+        }
+        if !iterator_helpers.is_empty() {
+            s.spawn(|| {
+                sweep_heap_arena_values(
+                    iterator_helpers,
+                    &compactions,
+                    &bits.iterator_helpers,
+                    bits,
+                );
+            });
+        }
+        #[cfg(feature = "json")]
+        if !raw_json_objects.is_empty() {
+            s.spawn(|| {
+                sweep_heap_arena_values(
+                    raw_json_objects,
+                    &compactions,
+                    &bits.raw_json_objects,
+                    bits,
                 );
             });
         }
         if !strings.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(strings, &compactions, &bits.strings, &bits.bits);
+                sweep_heap_arena_values_with(
+                    strings,
+                    &compactions,
+                    &bits.strings,
+                    bits,
+                    |string| {
+                        if let StringBuffer::Owned(buf) = &string.data {
+                            *string_bytes -= buf.len();
+                        }
+                    },
+                );
                 sweep_lookup_table(string_lookup_table, &compactions);
             });
         }
         if !symbols.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(symbols, &compactions, &bits.symbols, &bits.bits);
+                sweep_heap_arena_values(symbols, &compactions, &bits.symbols, bits);
             });
         }
         #[cfg(feature = "array-buffer")]
         if !typed_arrays.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
-                    typed_arrays,
-                    &compactions,
-                    &bits.typed_arrays,
-                    &bits.bits,
-                );
+                sweep_heap_arena_values(typed_arrays, &compactions, &bits.typed_arrays, bits);
                 sweep_side_table_values(typed_array_byte_lengths, &compactions);
                 sweep_side_table_values(typed_array_byte_offsets, &compactions);
                 sweep_side_table_values(typed_array_array_lengths, &compactions);
@@ -2081,11 +2515,11 @@ fn sweep(
         #[cfg(feature = "shared-array-buffer")]
         if !shared_typed_arrays.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(
+                sweep_heap_arena_values(
                     shared_typed_arrays,
                     &compactions,
                     &bits.shared_typed_arrays,
-                    &bits.bits,
+                    bits,
                 );
                 sweep_side_table_values(shared_typed_array_byte_lengths, &compactions);
                 sweep_side_table_values(shared_typed_array_byte_offsets, &compactions);
@@ -2095,19 +2529,19 @@ fn sweep(
         #[cfg(feature = "weak-refs")]
         if !weak_maps.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(weak_maps, &compactions, &bits.weak_maps, &bits.bits);
+                sweep_heap_arena_values(weak_maps, &compactions, &bits.weak_maps, bits);
             });
         }
         #[cfg(feature = "weak-refs")]
         if !weak_refs.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(weak_refs, &compactions, &bits.weak_refs, &bits.bits);
+                sweep_heap_arena_values(weak_refs, &compactions, &bits.weak_refs, bits);
             });
         }
         #[cfg(feature = "weak-refs")]
         if !weak_sets.is_empty() {
             s.spawn(|| {
-                sweep_heap_vector_values(weak_sets, &compactions, &bits.weak_sets, &bits.bits);
+                sweep_heap_arena_values(weak_sets, &compactions, &bits.weak_sets, bits);
             });
         }
     });
@@ -2130,7 +2564,7 @@ fn test_heap_gc() {
         OrdinaryObject::create_object(&mut agent, None, &[]).expect("Should perform GC here"),
     );
     agent.heap.globals.borrow_mut().push(obj);
-    heap_gc(&mut agent, &mut [], gc.reborrow());
+    heap_gc(&mut agent, &mut [], false, gc.reborrow());
 
     assert_eq!(agent.heap.objects.len(), 1);
     assert_eq!(agent.heap.elements.e2pow4.values.len(), 0);
@@ -0,0 +1,1107 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+//! ## Heap snapshots
+//!
+//! A heap snapshot is a graph of all heap data reachable from the roots of an
+//! Agent, written in the `.heapsnapshot` JSON format understood by the Memory
+//! tab of Chrome DevTools.
+//!
+//! The snapshot is built using the same [`HeapMarkAndSweep::mark_values`]
+//! implementations as the garbage collector: each heap item is marked into
+//! empty work queues, and whatever ends up in the queues are the item's
+//! outgoing edges. Ordinary objects and arrays additionally get property and
+//! element edges named after their keys.
+
+use std::{
+    borrow::Cow,
+    io::{self, BufWriter, Write},
+};
+
+use ahash::AHashMap;
+
+#[cfg(feature = "set")]
+use crate::ecmascript::SetHeapData;
+use crate::{
+    ecmascript::{
+        Agent, ArrayHeapData, Caches, FinalizationRegistryRecord, HeapString, MapHeapData,
+        ObjectShape, PropertyKey, Realm, StringBuffer, Symbol, Value,
+    },
+    engine::HeapRootData,
+    heap::{
+        ElementIndex, Heap, HeapBits, HeapIndexHandle, HeapMarkAndSweep, WorkQueues,
+        mark_descriptors,
+    },
+};
+
+/// Type of a node in the snapshot. The discriminants are indexes into the
+/// `node_types` list of the snapshot metadata.
+#[derive(Debug, Clone, Copy)]
+enum NodeType {
+    Hidden = 0,
+    Array = 1,
+    String = 2,
+    Object = 3,
+    Code = 4,
+    Closure = 5,
+    #[cfg(feature = "regexp")]
+    RegExp = 6,
+    Number = 7,
+    Native = 8,
+    Synthetic = 9,
+    Symbol = 12,
+    BigInt = 13,
+    ObjectShape = 14,
+}
+
+/// Type of an edge in the snapshot. The discriminants are indexes into the
+/// `edge_types` list of the snapshot metadata.
+#[derive(Debug, Clone, Copy)]
+enum EdgeType {
+    Context = 0,
+    Element = 1,
+    Property = 2,
+    Internal = 3,
+    Hidden = 4,
+}
+
+macro_rules! node_kinds {
+    ($($(#[$attr:meta])* $field:ident: $node_type:ident $label:literal,)*) => {
+        /// Kind of heap data a node refers to. Each kind corresponds to a work
+        /// queue in [`WorkQueues`].
+        #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+        #[allow(non_camel_case_types)]
+        enum NodeKind {
+            $($(#[$attr])* $field,)*
+        }
+
+        impl NodeKind {
+            fn node_type(self) -> NodeType {
+                match self {
+                    $($(#[$attr])* Self::$field => NodeType::$node_type,)*
+                }
+            }
+
+            fn label(self) -> &'static str {
+                match self {
+                    $($(#[$attr])* Self::$field => $label,)*
+                }
+            }
+        }
+
+        /// Drain all work queues, calling `f` with the kind and index of each
+        /// queued item.
+        fn drain_queues(queues: &mut WorkQueues, f: &mut impl FnMut(NodeKind, usize)) {
+            for (_, value) in core::mem::take(&mut queues.pending_ephemerons) {
+                // Ephemeron values are treated as strongly held by the weak
+                // collection.
+                value.mark_values(queues);
+            }
+            $($(#[$attr])* for item in queues.$field.drain(..) {
+                f(NodeKind::$field, item.get_index());
+            })*
+        }
+    };
+}
+
+node_kinds! {
+    #[cfg(feature = "array-buffer")]
+    array_buffers: Object "ArrayBuffer",
+    arrays: Object "Array",
+    array_from_async_records: Hidden "(Array.fromAsync record)",
+    array_iterators: Object "Array Iterator",
+    async_generators: Object "AsyncGenerator",
+    await_reactions: Hidden "(await reaction)",
+    bigints: BigInt "bigint",
+    bound_functions: Closure "BoundFunction",
+    builtin_constructors: Closure "(builtin constructor)",
+    builtin_functions: Closure "(builtin function)",
+    caches: Hidden "(property lookup cache)",
+    #[cfg(feature = "array-buffer")]
+    data_views: Object "DataView",
+    #[cfg(feature = "date")]
+    dates: Object "Date",
+    #[cfg(feature = "temporal")]
+    instants: Object "Temporal.Instant",
+    #[cfg(feature = "temporal")]
+    durations: Object "Temporal.Duration",
+    #[cfg(feature = "temporal")]
+    plain_times: Object "Temporal.PlainTime",
+    #[cfg(feature = "temporal")]
+    plain_dates: Object "Temporal.PlainDate",
+    #[cfg(feature = "temporal")]
+    plain_date_times: Object "Temporal.PlainDateTime",
+    #[cfg(feature = "temporal")]
+    zoned_date_times: Object "Temporal.ZonedDateTime",
+    #[cfg(feature = "temporal")]
+    plain_year_months: Object "Temporal.PlainYearMonth",
+    #[cfg(feature = "temporal")]
+    plain_month_days: Object "Temporal.PlainMonthDay",
+    declarative_environments: Hidden "(declarative environment)",
+    e_2_1: Array "(object elements)",
+    e_2_2: Array "(object elements)",
+    e_2_3: Array "(object elements)",
+    e_2_4: Array "(object elements)",
+    e_2_6: Array "(object elements)",
+    e_2_8: Array "(object elements)",
+    e_2_10: Array "(object elements)",
+    e_2_12: Array "(object elements)",
+    e_2_16: Array "(object elements)",
+    e_2_24: Array "(object elements)",
+    e_2_32: Array "(object elements)",
+    e_sparse: Array "(object elements)",
+    k_2_1: Array "(property keys)",
+    k_2_2: Array "(property keys)",
+    k_2_3: Array "(property keys)",
+    k_2_4: Array "(property keys)",
+    k_2_6: Array "(property keys)",
+    k_2_8: Array "(property keys)",
+    k_2_10: Array "(property keys)",
+    k_2_12: Array "(property keys)",
+    k_2_16: Array "(property keys)",
+    k_2_24: Array "(property keys)",
+    k_2_32: Array "(property keys)",
+    ecmascript_functions: Closure "Function",
+    embedder_objects: Native "EmbedderObject",
+    source_codes: Code "(source code)",
+    disposable_stacks: Object "DisposableStack",
+    errors: Object "Error",
+    executables: Code "(bytecode)",
+    finalization_registrys: Object "FinalizationRegistry",
+    function_environments: Hidden "(function environment)",
+    generators: Object "Generator",
+    global_environments: Hidden "(global environment)",
+    maps: Object "Map",
+    map_iterators: Object "Map Iterator",
+    module_environments: Hidden "(module environment)",
+    modules: Object "Module",
+    module_request_records: Hidden "(module request)",
+    numbers: Number "heap number",
+    object_environments: Hidden "(object environment)",
+    objects: Object "Object",
+    object_shapes: ObjectShape "(object shape)",
+    primitive_objects: Object "(primitive wrapper)",
+    private_environments: Hidden "(private environment)",
+    promises: Object "Promise",
+    promise_reaction_records: Hidden "(promise reaction)",
+    promise_resolving_functions: Closure "(promise resolving function)",
+    promise_finally_functions: Closure "(promise finally function)",
+    proxy_revoker_functions: Closure "(proxy revoker function)",
+    promise_group_records: Hidden "(promise group)",
+    proxies: Object "Proxy",
+    realms: Hidden "(realm)",
+    #[cfg(feature = "regexp")]
+    regexps: RegExp "RegExp",
+    #[cfg(feature = "regexp")]
+    regexp_string_iterators: Object "RegExp String Iterator",
+    scripts: Code "(script)",
+    #[cfg(feature = "set")]
+    sets: Object "Set",
+    #[cfg(feature = "set")]
+    set_iterators: Object "Set Iterator",
+    #[cfg(feature = "shared-array-buffer")]
+    shared_array_buffers: Object "SharedArrayBuffer",
+    #[cfg(feature = "shared-array-buffer")]
+    shared_data_views: Object "DataView",
+    #[cfg(feature = "shared-array-buffer")]
+    shared_typed_arrays: Object "TypedArray",
+    source_text_module_records: Code "(source text module)",
+    string_iterators: Object "String Iterator",
+    iterator_helpers: Object "Iterator Helper",
+    #[cfg(feature = "json")]
+    raw_json_objects: Object "RawJSON",
+    strings: String "string",
+    symbols: Symbol "symbol",
+    #[cfg(feature = "array-buffer")]
+    typed_arrays: Object "TypedArray",
+    #[cfg(feature = "weak-refs")]
+    weak_maps: Object "WeakMap",
+    #[cfg(feature = "weak-refs")]
+    weak_refs: Object "WeakRef",
+    #[cfg(feature = "weak-refs")]
+    weak_sets: Object "WeakSet",
+}
+
+impl NodeKind {
+    fn is_environment(self) -> bool {
+        matches!(
+            self,
+            Self::declarative_environments
+                | Self::function_environments
+                | Self::global_environments
+                | Self::module_environments
+                | Self::object_environments
+                | Self::private_environments
+        )
+    }
+
+    fn is_backing_store(self) -> bool {
+        matches!(self.node_type(), NodeType::Array)
+    }
+}
+
+fn item_size<T>(_: &[T]) -> usize {
+    size_of::<T>()
+}
+
+/// Push all children of a heap item into the work queues and return the
+/// number of bytes used by the item.
+fn trace_node(heap: &Heap, kind: NodeKind, index: usize, queues: &mut WorkQueues) -> usize {
+    match kind {
+        #[cfg(feature = "array-buffer")]
+        NodeKind::array_buffers => {
+            heap.array_buffers.get(index).mark_values(queues);
+            item_size(heap.array_buffers.as_slice())
+        }
+        NodeKind::arrays => {
+            heap.arrays.get(index as u32).mark_values(queues);
+            size_of::<ArrayHeapData<'static>>()
+        }
+        NodeKind::array_from_async_records => {
+            heap.array_from_async_records.get(index).mark_values(queues);
+            item_size(heap.array_from_async_records.as_slice())
+        }
+        NodeKind::array_iterators => {
+            heap.array_iterators.get(index).mark_values(queues);
+            item_size(heap.array_iterators.as_slice())
+        }
+        NodeKind::async_generators => {
+            heap.async_generators.get(index).mark_values(queues);
+            item_size(heap.async_generators.as_slice())
+        }
+        NodeKind::await_reactions => {
+            heap.await_reactions.get(index).mark_values(queues);
+            item_size(heap.await_reactions.as_slice())
+        }
+        NodeKind::bigints => {
+            heap.bigints.get(index).mark_values(queues);
+            item_size(heap.bigints.as_slice())
+        }
+        NodeKind::bound_functions => {
+            heap.bound_functions.get(index).mark_values(queues);
+            item_size(heap.bound_functions.as_slice())
+        }
+        NodeKind::builtin_constructors => {
+            heap.builtin_constructors.get(index).mark_values(queues);
+            item_size(heap.builtin_constructors.as_slice())
+        }
+        NodeKind::builtin_functions => {
+            heap.builtin_functions.get(index).mark_values(queues);
+            item_size(heap.builtin_functions.as_slice())
+        }
+        NodeKind::caches => {
+            heap.caches.mark_cache(index, queues);
+            Caches::cache_byte_size()
+        }
+        #[cfg(feature = "array-buffer")]
+        NodeKind::data_views => {
+            heap.data_views.get(index).mark_values(queues);
+            item_size(heap.data_views.as_slice())
+        }
+        #[cfg(feature = "date")]
+        NodeKind::dates => {
+            heap.dates.get(index).mark_values(queues);
+            item_size(heap.dates.as_slice())
+        }
+        #[cfg(feature = "temporal")]
+        NodeKind::instants => {
+            heap.instants.get(index).mark_values(queues);
+            item_size(heap.instants.as_slice())
+        }
+        #[cfg(feature = "temporal")]
+        NodeKind::durations => {
+            heap.durations.get(index).mark_values(queues);
+            item_size(heap.durations.as_slice())
+        }
+        #[cfg(feature = "temporal")]
+        NodeKind::plain_times => {
+            heap.plain_times.get(index).mark_values(queues);
+            item_size(heap.plain_times.as_slice())
+        }
+        #[cfg(feature = "temporal")]
+        NodeKind::plain_dates => {
+            heap.plain_dates.get(index).mark_values(queues);
+            item_size(heap.plain_dates.as_slice())
+        }
+        #[cfg(feature = "temporal")]
+        NodeKind::plain_date_times => {
+            heap.plain_date_times.get(index).mark_values(queues);
+            item_size(heap.plain_date_times.as_slice())
+        }
+        #[cfg(feature = "temporal")]
+        NodeKind::zoned_date_times => {
+            heap.zoned_date_times.get(index).mark_values(queues);
+            item_size(heap.zoned_date_times.as_slice())
+        }
+        #[cfg(feature = "temporal")]
+        NodeKind::plain_year_months => {
+            heap.plain_year_months.get(index).mark_values(queues);
+            item_size(heap.plain_year_months.as_slice())
+        }
+        #[cfg(feature = "temporal")]
+        NodeKind::plain_month_days => {
+            heap.plain_month_days.get(index).mark_values(queues);
+            item_size(heap.plain_month_days.as_slice())
+        }
+        NodeKind::declarative_environments => {
+            heap.environments.declarative.get(index).mark_values(queues);
+            item_size(heap.environments.declarative.as_slice())
+        }
+        NodeKind::e_2_1 => {
+            let elements = &heap.elements.e2pow1;
+            if let Some(descriptors) = elements.descriptors.get(&ElementIndex::from_index(index)) {
+                mark_descriptors(descriptors, queues);
+            }
+            elements.values.get(index).mark_values(queues);
+            item_size(elements.values.as_slice())
+        }
+        NodeKind::e_2_2 => {
+            let elements = &heap.elements.e2pow2;
+            if let Some(descriptors) = elements.descriptors.get(&ElementIndex::from_index(index)) {
+                mark_descriptors(descriptors, queues);
+            }
+            elements.values.get(index).mark_values(queues);
+            item_size(elements.values.as_slice())
+        }
+        NodeKind::e_2_3 => {
+            let elements = &heap.elements.e2pow3;
+            if let Some(descriptors) = elements.descriptors.get(&ElementIndex::from_index(index)) {
+                mark_descriptors(descriptors, queues);
+            }
+            elements.values.get(index).mark_values(queues);
+            item_size(elements.values.as_slice())
+        }
+        NodeKind::e_2_4 => {
+            let elements = &heap.elements.e2pow4;
+            if let Some(descriptors) = elements.descriptors.get(&ElementIndex::from_index(index)) {
+                mark_descriptors(descriptors, queues);
+            }
+            elements.values.get(index).mark_values(queues);
+            item_size(elements.values.as_slice())
+        }
+        NodeKind::e_2_6 => {
+            let elements = &heap.elements.e2pow6;
+            if let Some(descriptors) = elements.descriptors.get(&ElementIndex::from_index(index)) {
+                mark_descriptors(descriptors, queues);
+            }
+            elements.values.get(index).mark_values(queues);
+            item_size(elements.values.as_slice())
+        }
+        NodeKind::e_2_8 => {
+            let elements = &heap.elements.e2pow8;
+            if let Some(descriptors) = elements.descriptors.get(&ElementIndex::from_index(index)) {
+                mark_descriptors(descriptors, queues);
+            }
+            elements.values.get(index).mark_values(queues);
+            item_size(elements.values.as_slice())
+        }
+        NodeKind::e_2_10 => {
+            let elements = &heap.elements.e2pow10;
+            if let Some(descriptors) = elements.descriptors.get(&ElementIndex::from_index(index)) {
+                mark_descriptors(descriptors, queues);
+            }
+            elements.values.get(index).mark_values(queues);
+            item_size(elements.values.as_slice())
+        }
+        NodeKind::e_2_12 => {
+            let elements = &heap.elements.e2pow12;
+            if let Some(descriptors) = elements.descriptors.get(&ElementIndex::from_index(index)) {
+                mark_descriptors(descriptors, queues);
+            }
+            elements.values.get(index).mark_values(queues);
+            item_size(elements.values.as_slice())
+        }
+        NodeKind::e_2_16 => {
+            let elements = &heap.elements.e2pow16;
+            if let Some(descriptors) = elements.descriptors.get(&ElementIndex::from_index(index)) {
+                mark_descriptors(descriptors, queues);
+            }
+            elements.values.get(index).mark_values(queues);
+            item_size(elements.values.as_slice())
+        }
+        NodeKind::e_2_24 => {
+            let elements = &heap.elements.e2pow24;
+            if let Some(descriptors) = elements.descriptors.get(&ElementIndex::from_index(index)) {
+                mark_descriptors(descriptors, queues);
+            }
+            elements.values.get(index).mark_values(queues);
+            item_size(elements.values.as_slice())
+        }
+        NodeKind::e_2_32 => {
+            let elements = &heap.elements.e2pow32;
+            if let Some(descriptors) = elements.descriptors.get(&ElementIndex::from_index(index)) {
+                mark_descriptors(descriptors, queues);
+            }
+            elements.values.get(index).mark_values(queues);
+            item_size(elements.values.as_slice())
+        }
+        NodeKind::e_sparse => {
+            let sparse = &heap.elements.sparse[ElementIndex::from_index(index)];
+            sparse.mark_values(queues);
+            sparse.byte_size()
+        }
+        NodeKind::k_2_1 => {
+            let keys = &heap.elements.k2pow1.keys;
+            keys.get(index).mark_values(queues);
+            item_size(keys.as_slice())
+        }
+        NodeKind::k_2_2 => {
+            let keys = &heap.elements.k2pow2.keys;
+            keys.get(index).mark_values(queues);
+            item_size(keys.as_slice())
+        }
+        NodeKind::k_2_3 => {
+            let keys = &heap.elements.k2pow3.keys;
+            keys.get(index).mark_values(queues);
+            item_size(keys.as_slice())
+        }
+        NodeKind::k_2_4 => {
+            let keys = &heap.elements.k2pow4.keys;
+            keys.get(index).mark_values(queues);
+            item_size(keys.as_slice())
+        }
+        NodeKind::k_2_6 => {
+            let keys = &heap.elements.k2pow6.keys;
+            keys.get(index).mark_values(queues);
+            item_size(keys.as_slice())
+        }
+        NodeKind::k_2_8 => {
+            let keys = &heap.elements.k2pow8.keys;
+            keys.get(index).mark_values(queues);
+            item_size(keys.as_slice())
+        }
+        NodeKind::k_2_10 => {
+            let keys = &heap.elements.k2pow10.keys;
+            keys.get(index).mark_values(queues);
+            item_size(keys.as_slice())
+        }
+        NodeKind::k_2_12 => {
+            let keys = &heap.elements.k2pow12.keys;
+            keys.get(index).mark_values(queues);
+            item_size(keys.as_slice())
+        }
+        NodeKind::k_2_16 => {
+            let keys = &heap.elements.k2pow16.keys;
+            keys.get(index).mark_values(queues);
+            item_size(keys.as_slice())
+        }
+        NodeKind::k_2_24 => {
+            let keys = &heap.elements.k2pow24.keys;
+            keys.get(index).mark_values(queues);
+            item_size(keys.as_slice())
+        }
+        NodeKind::k_2_32 => {
+            let keys = &heap.elements.k2pow32.keys;
+            keys.get(index).mark_values(queues);
+            item_size(keys.as_slice())
+        }
+        NodeKind::ecmascript_functions => {
+            heap.ecmascript_functions.get(index).mark_values(queues);
+            item_size(heap.ecmascript_functions.as_slice())
+        }
+        NodeKind::embedder_objects => {
+            heap.embedder_objects.get(index).mark_values(queues);
+            item_size(heap.embedder_objects.as_slice())
+        }
+        NodeKind::source_codes => {
+            heap.source_codes.get(index).mark_values(queues);
+            item_size(heap.source_codes.as_slice())
+        }
+        NodeKind::disposable_stacks => {
+            heap.disposable_stacks.get(index).mark_values(queues);
+            item_size(heap.disposable_stacks.as_slice())
+        }
+        NodeKind::errors => {
+            heap.errors.get(index).mark_values(queues);
+            item_size(heap.errors.as_slice())
+        }
+        NodeKind::executables => {
+            heap.executables.get(index).mark_values(queues);
+            item_size(heap.executables.as_slice())
+        }
+        NodeKind::finalization_registrys => {
+            heap.finalization_registrys
+                .get(index as u32)
+                .mark_values(queues);
+            size_of::<FinalizationRegistryRecord<'static>>()
+        }
+        NodeKind::function_environments => {
+            heap.environments.function.get(index).mark_values(queues);
+            item_size(heap.environments.function.as_slice())
+        }
+        NodeKind::generators => {
+            heap.generators.get(index).mark_values(queues);
+            item_size(heap.generators.as_slice())
+        }
+        NodeKind::global_environments => {
+            heap.environments.global.get(index).mark_values(queues);
+            item_size(heap.environments.global.as_slice())
+        }
+        NodeKind::maps => {
+            heap.maps.get(index as u32).mark_values(queues);
+            size_of::<MapHeapData<'static>>()
+        }
+        NodeKind::map_iterators => {
+            heap.map_iterators.get(index).mark_values(queues);
+            item_size(heap.map_iterators.as_slice())
+        }
+        NodeKind::module_environments => {
+            heap.environments.module.get(index).mark_values(queues);
+            item_size(heap.environments.module.as_slice())
+        }
+        NodeKind::modules => {
+            heap.modules.get(index).mark_values(queues);
+            item_size(heap.modules.as_slice())
+        }
+        NodeKind::module_request_records => {
+            heap.module_request_records.get(index).mark_values(queues);
+            item_size(heap.module_request_records.as_slice())
+        }
+        NodeKind::numbers => {
+            heap.numbers.get(index).mark_values(queues);
+            item_size(heap.numbers.as_slice())
+        }
+        NodeKind::object_environments => {
+            heap.environments.object.get(index).mark_values(queues);
+            item_size(heap.environments.object.as_slice())
+        }
+        NodeKind::objects => {
+            if let Some(record) = heap.objects.get(index) {
+                record.mark_values(queues, &heap.object_shapes);
+            }
+            item_size(heap.objects.as_slice())
+        }
+        NodeKind::object_shapes => {
+            heap.object_shapes.get(index).mark_values(queues);
+            heap.object_shape_transitions.get(index).mark_values(queues);
+            item_size(heap.object_shapes.as_slice())
+                + item_size(heap.object_shape_transitions.as_slice())
+        }
+        NodeKind::primitive_objects => {
+            heap.primitive_objects.get(index).mark_values(queues);
+            item_size(heap.primitive_objects.as_slice())
+        }
+        NodeKind::private_environments => {
+            heap.environments.private.get(index).mark_values(queues);
+            item_size(heap.environments.private.as_slice())
+        }
+        NodeKind::promises => {
+            heap.promises.get(index).mark_values(queues);
+            item_size(heap.promises.as_slice())
+        }
+        NodeKind::promise_reaction_records => {
+            heap.promise_reaction_records.get(index).mark_values(queues);
+            item_size(heap.promise_reaction_records.as_slice())
+        }
+        NodeKind::promise_resolving_functions => {
+            heap.promise_resolving_functions
+                .get(index)
+                .mark_values(queues);
+            item_size(heap.promise_resolving_functions.as_slice())
+        }
+        NodeKind::promise_finally_functions => {
+            heap.promise_finally_functions
+                .get(index)
+                .mark_values(queues);
+            item_size(heap.promise_finally_functions.as_slice())
+        }
+        NodeKind::proxy_revoker_functions => {
+            heap.proxy_revoker_functions.get(index).mark_values(queues);
+            item_size(heap.proxy_revoker_functions.as_slice())
+        }
+        NodeKind::promise_group_records => {
+            heap.promise_group_records.get(index).mark_values(queues);
+            item_size(heap.promise_group_records.as_slice())
+        }
+        NodeKind::proxies => {
+            heap.proxies.get(index).mark_values(queues);
+            item_size(heap.proxies.as_slice())
+        }
+        NodeKind::realms => {
+            heap.realms.get(index).mark_values(queues);
+            item_size(heap.realms.as_slice())
+        }
+        #[cfg(feature = "regexp")]
+        NodeKind::regexps => {
+            heap.regexps.get(index).mark_values(queues);
+            item_size(heap.regexps.as_slice())
+        }
+        #[cfg(feature = "regexp")]
+        NodeKind::regexp_string_iterators => {
+            heap.regexp_string_iterators.get(index).mark_values(queues);
+            item_size(heap.regexp_string_iterators.as_slice())
+        }
+        NodeKind::scripts => {
+            heap.scripts.get(index).mark_values(queues);
+            item_size(heap.scripts.as_slice())
+        }
+        #[cfg(feature = "set")]
+        NodeKind::sets => {
+            heap.sets.get(index as u32).mark_values(queues);
+            size_of::<SetHeapData<'static>>()
+        }
+        #[cfg(feature = "set")]
+        NodeKind::set_iterators => {
+            heap.set_iterators.get(index).mark_values(queues);
+            item_size(heap.set_iterators.as_slice())
+        }
+        #[cfg(feature = "shared-array-buffer")]
+        NodeKind::shared_array_buffers => {
+            heap.shared_array_buffers.get(index).mark_values(queues);
+            item_size(heap.shared_array_buffers.as_slice())
+        }
+        #[cfg(feature = "shared-array-buffer")]
+        NodeKind::shared_data_views => {
+            heap.shared_data_views.get(index).mark_values(queues);
+            item_size(heap.shared_data_views.as_slice())
+        }
+        #[cfg(feature = "shared-array-buffer")]
+        NodeKind::shared_typed_arrays => {
+            heap.shared_typed_arrays.get(index).mark_values(queues);
+            item_size(heap.shared_typed_arrays.as_slice())
+        }
+        NodeKind::source_text_module_records => {
+            heap.source_text_module_records
+                .get(index)
+                .mark_values(queues);
+            item_size(heap.source_text_module_records.as_slice())
+        }
+        NodeKind::string_iterators => {
+            heap.string_iterators.get(index).mark_values(queues);
+            item_size(heap.string_iterators.as_slice())
+        }
+        NodeKind::iterator_helpers => {
+            heap.iterator_helpers.get(index).mark_values(queues);
+            item_size(heap.iterator_helpers.as_slice())
+        }
+        #[cfg(feature = "json")]
+        NodeKind::raw_json_objects => {
+            heap.raw_json_objects.get(index).mark_values(queues);
+            item_size(heap.raw_json_objects.as_slice())
+        }
+        NodeKind::strings => {
+            heap.strings.get(index).mark_values(queues);
+            let buffer_size = match &heap.strings[index].data {
+                StringBuffer::Owned(buf) => buf.len(),
+                StringBuffer::Static(_) => 0,
+            };
+            item_size(heap.strings.as_slice()) + buffer_size
+        }
+        NodeKind::symbols => {
+            heap.symbols.get(index).mark_values(queues);
+            item_size(heap.symbols.as_slice())
+        }
+        #[cfg(feature = "array-buffer")]
+        NodeKind::typed_arrays => {
+            heap.typed_arrays.get(index).mark_values(queues);
+            item_size(heap.typed_arrays.as_slice())
+        }
+        #[cfg(feature = "weak-refs")]
+        NodeKind::weak_maps => {
+            heap.weak_maps.get(index).mark_values(queues);
+            item_size(heap.weak_maps.as_slice())
+        }
+        #[cfg(feature = "weak-refs")]
+        NodeKind::weak_refs => {
+            heap.weak_refs.get(index).mark_values(queues);
+            item_size(heap.weak_refs.as_slice())
+        }
+        #[cfg(feature = "weak-refs")]
+        NodeKind::weak_sets => {
+            heap.weak_sets.get(index).mark_values(queues);
+            item_size(heap.weak_sets.as_slice())
+        }
+    }
+}
+
+/// Name of an edge that refers to a JavaScript property.
+enum PropertyEdgeName<'a> {
+    Key(PropertyKey<'a>),
+    Index(usize),
+}
+
+/// Call `f` with all properties of an ordinary object or array, or do nothing
+/// for other kinds of heap data.
+fn for_each_property(
+    heap: &Heap,
+    kind: NodeKind,
+    index: usize,
+    mut f: impl FnMut(PropertyEdgeName<'static>, Value<'static>),
+) {
+    match kind {
+        NodeKind::objects => {
+            let Some(record) = heap.objects.get(index) else {
+                return;
+            };
+            let shape = record.shape;
+            let keys = shape.keys(&heap.object_shapes, &heap.elements);
+            let storage = heap.elements.get_element_storage_raw(
+                record.values,
+                shape.values_capacity(&heap.object_shapes),
+                shape.len(&heap.object_shapes),
+            );
+            for (key, value) in keys.iter().zip(storage.values) {
+                if let Some(value) = value {
+                    f(PropertyEdgeName::Key(*key), *value);
+                }
+            }
+        }
+        NodeKind::arrays => {
+            let data = heap.arrays.get(index as u32).unwrap();
+            for (i, value) in heap.elements.get_values(data.elements).iter().enumerate() {
+                if let Some(value) = value {
+                    f(PropertyEdgeName::Index(i), *value);
+                }
+            }
+        }
+        _ => {}
+    }
+}
+
+struct Edge {
+    edge_type: EdgeType,
+    /// Index into the string table for named edges, or the element index for
+    /// indexed edges.
+    name_or_index: usize,
+    to_node: usize,
+}
+
+struct Node {
+    node_type: NodeType,
+    /// Index into the string table.
+    name: usize,
+    self_size: usize,
+    edges: Vec<Edge>,
+    /// The heap item this node refers to; `None` for synthetic nodes.
+    item: Option<(NodeKind, usize)>,
+}
+
+struct HeapSnapshotBuilder<'a> {
+    agent: &'a Agent,
+    nodes: Vec<Node>,
+    node_indexes: AHashMap<(NodeKind, usize), usize>,
+    strings: Vec<std::string::String>,
+    string_indexes: AHashMap<std::string::String, usize>,
+}
+
+impl<'a> HeapSnapshotBuilder<'a> {
+    fn new(agent: &'a Agent) -> Self {
+        Self {
+            agent,
+            nodes: Vec::new(),
+            node_indexes: AHashMap::default(),
+            strings: Vec::new(),
+            string_indexes: AHashMap::default(),
+        }
+    }
+
+    fn add_string(&mut self, string: Cow<str>) -> usize {
+        if let Some(&index) = self.string_indexes.get(string.as_ref()) {
+            return index;
+        }
+        let index = self.strings.len();
+        self.strings.push(string.clone().into_owned());
+        self.string_indexes.insert(string.into_owned(), index);
+        index
+    }
+
+    fn add_synthetic_node(&mut self, name: &'static str) -> usize {
+        let name = self.add_string(Cow::Borrowed(name));
+        self.nodes.push(Node {
+            node_type: NodeType::Synthetic,
+            name,
+            self_size: 0,
+            edges: Vec::new(),
+            item: None,
+        });
+        self.nodes.len() - 1
+    }
+
+    /// Get the node of a heap item, creating it if it does not exist yet.
+    fn get_or_add_node(&mut self, kind: NodeKind, index: usize) -> usize {
+        if let Some(&node) = self.node_indexes.get(&(kind, index)) {
+            return node;
+        }
+        let name = self.node_name(kind, index);
+        let name = self.add_string(name);
+        let node = self.nodes.len();
+        self.nodes.push(Node {
+            node_type: kind.node_type(),
+            name,
+            self_size: 0,
+            edges: Vec::new(),
+            item: Some((kind, index)),
+        });
+        self.node_indexes.insert((kind, index), node);
+        node
+    }
+
+    fn node_name(&self, kind: NodeKind, index: usize) -> Cow<'static, str> {
+        let agent = self.agent;
+        let name = match kind {
+            NodeKind::strings => Some(
+                HeapString::from_index(index)
+                    .to_string_lossy(agent)
+                    .into_owned(),
+            ),
+            NodeKind::symbols => Symbol::from_index(index)
+                .description(agent)
+                .map(|description| description.to_string_lossy(agent).into_owned()),
+            NodeKind::ecmascript_functions => agent
+                .heap
+                .ecmascript_functions
+                .get(index)
+                .and_then(|data| data.name)
+                .map(|name| name.to_string_lossy(agent).into_owned()),
+            NodeKind::builtin_functions => agent
+                .heap
+                .builtin_functions
+                .get(index)
+                .and_then(|data| data.initial_name)
+                .map(|name| name.to_string_lossy(agent).into_owned()),
+            _ => None,
+        };
+        match name {
+            Some(name) if !name.is_empty() || kind == NodeKind::strings => Cow::Owned(name),
+            _ => Cow::Borrowed(kind.label()),
+        }
+    }
+
+    /// Add edges from the given node to everything currently in the work
+    /// queues.
+    fn add_queued_edges(&mut self, from: usize, queues: &mut WorkQueues, edge_type: EdgeType) {
+        let mut children = Vec::new();
+        drain_queues(queues, &mut |kind, index| children.push((kind, index)));
+        for (ordinal, (kind, index)) in children.into_iter().enumerate() {
+            let to_node = self.get_or_add_node(kind, index);
+            let (edge_type, name_or_index) = match edge_type {
+                EdgeType::Internal if kind.is_environment() => (
+                    EdgeType::Context,
+                    self.add_string(Cow::Borrowed(kind.label())),
+                ),
+                EdgeType::Internal => (
+                    EdgeType::Internal,
+                    self.add_string(Cow::Borrowed(kind.label())),
+                ),
+                edge_type => (edge_type, ordinal),
+            };
+            self.nodes[from].edges.push(Edge {
+                edge_type,
+                name_or_index,
+                to_node,
+            });
+        }
+    }
+
+    /// Add a synthetic root node whose children are the items marked by
+    /// `mark`.
+    fn add_root(
+        &mut self,
+        gc_roots: usize,
+        name: &'static str,
+        queues: &mut WorkQueues,
+        mark: impl FnOnce(&mut WorkQueues),
+    ) {
+        let node = self.add_synthetic_node(name);
+        let name = self.nodes[node].name;
+        self.nodes[gc_roots].edges.push(Edge {
+            edge_type: EdgeType::Internal,
+            name_or_index: name,
+            to_node: node,
+        });
+        mark(queues);
+        self.add_queued_edges(node, queues, EdgeType::Element);
+    }
+
+    /// Trace the heap item of the given node, adding its size and edges.
+    fn trace(&mut self, node: usize, queues: &mut WorkQueues) {
+        let Some((kind, index)) = self.nodes[node].item else {
+            return;
+        };
+        let agent = self.agent;
+        let heap = &agent.heap;
+        for_each_property(heap, kind, index, |name, value| {
+            value.mark_values(queues);
+            let mut child = None;
+            drain_queues(queues, &mut |kind, index| child = Some((kind, index)));
+            let Some((kind, index)) = child else {
+                // Primitive values stored directly in the property.
+                return;
+            };
+            let to_node = self.get_or_add_node(kind, index);
+            let (edge_type, name_or_index) = match name {
+                PropertyEdgeName::Key(key) => (
+                    EdgeType::Property,
+                    self.add_string(Cow::Owned(key.as_display(agent).to_string())),
+                ),
+                PropertyEdgeName::Index(index) => (EdgeType::Element, index),
+            };
+            self.nodes[node].edges.push(Edge {
+                edge_type,
+                name_or_index,
+                to_node,
+            });
+        });
+        self.nodes[node].self_size = trace_node(heap, kind, index, queues);
+        let edge_type = if kind.is_backing_store() {
+            EdgeType::Hidden
+        } else {
+            EdgeType::Internal
+        };
+        self.add_queued_edges(node, queues, edge_type);
+    }
+
+    fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        let node_field_count = 7;
+        let edge_count: usize = self.nodes.iter().map(|node| node.edges.len()).sum();
+        write!(
+            writer,
+            concat!(
+                r#"{{"snapshot":{{"meta":{{"#,
+                r#""node_fields":["type","name","id","self_size","edge_count","trace_node_id","detachedness"],"#,
+                r#""node_types":[["hidden","array","string","object","code","closure","regexp","number","native","synthetic","concatenated string","sliced string","symbol","bigint","object shape"],"string","number","number","number","number","number"],"#,
+                r#""edge_fields":["type","name_or_index","to_node"],"#,
+                r#""edge_types":[["context","element","property","internal","hidden","shortcut","weak"],"string_or_number","node"],"#,
+                r#""trace_function_info_fields":["function_id","name","script_name","script_id","line","column"],"#,
+                r#""trace_node_fields":["id","function_info_index","count","size","children"],"#,
+                r#""sample_fields":["timestamp_us","last_assigned_id"],"#,
+                r#""location_fields":["object_index","script_id","line","column"]}},"#,
+                r#""node_count":{},"edge_count":{},"trace_function_count":0}},"#,
+                "\n",
+                r#""nodes":["#
+            ),
+            self.nodes.len(),
+            edge_count
+        )?;
+        for (i, node) in self.nodes.iter().enumerate() {
+            let separator = if i == 0 { "" } else { ",\n" };
+            write!(
+                writer,
+                "{separator}{},{},{},{},{},0,0",
+                node.node_type as u8,
+                node.name,
+                i * 2 + 1,
+                node.self_size,
+                node.edges.len()
+            )?;
+        }
+        writer.write_all(b"],\n\"edges\":[")?;
+        let mut separator = "";
+        for edge in self.nodes.iter().flat_map(|node| &node.edges) {
+            write!(
+                writer,
+                "{separator}{},{},{}",
+                edge.edge_type as u8,
+                edge.name_or_index,
+                edge.to_node * node_field_count
+            )?;
+            separator = ",\n";
+        }
+        writer.write_all(
+            b"],\n\"trace_function_infos\":[],\"trace_tree\":[],\"samples\":[],\"locations\":[],\n\"strings\":[",
+        )?;
+        for (i, string) in self.strings.iter().enumerate() {
+            if i != 0 {
+                writer.write_all(b",\n")?;
+            }
+            write_json_string(writer, string)?;
+        }
+        writer.write_all(b"]}\n")
+    }
+}
+
+pub(crate) fn write_json_string(writer: &mut impl Write, string: &str) -> io::Result<()> {
+    writer.write_all(b"\"")?;
+    for char in string.chars() {
+        match char {
+            '"' => writer.write_all(b"\\\"")?,
+            '\\' => writer.write_all(b"\\\\")?,
+            '\n' => writer.write_all(b"\\n")?,
+            '\r' => writer.write_all(b"\\r")?,
+            '\t' => writer.write_all(b"\\t")?,
+            char if (char as u32) < 0x20 => write!(writer, "\\u{:04x}", char as u32)?,
+            char => write!(writer, "{char}")?,
+        }
+    }
+    writer.write_all(b"\"")
+}
+
+/// Write a snapshot of all heap data reachable from the given realms and the
+/// Agent's roots in the Chrome DevTools `.heapsnapshot` format.
+pub(crate) fn write_heap_snapshot(
+    agent: &Agent,
+    root_realms: &[Option<Realm<'static>>],
+    writer: impl Write,
+) -> io::Result<()> {
+    let bits = HeapBits::new(&agent.heap);
+    let mut queues = WorkQueues::new(&agent.heap, &bits);
+    let mut builder = HeapSnapshotBuilder::new(agent);
+    let root = builder.add_synthetic_node("");
+    let gc_roots = builder.add_synthetic_node("(GC roots)");
+    let name = builder.nodes[gc_roots].name;
+    builder.nodes[root].edges.push(Edge {
+        edge_type: EdgeType::Internal,
+        name_or_index: name,
+        to_node: gc_roots,
+    });
+    builder.add_root(gc_roots, "(Realms)", &mut queues, |queues| {
+        queues.realms.extend(root_realms.iter().flatten().copied());
+    });
+    builder.add_root(gc_roots, "(Execution contexts)", &mut queues, |queues| {
+        agent
+            .execution_context_stack
+            .iter()
+            .for_each(|context| context.mark_values(queues));
+    });
+    builder.add_root(gc_roots, "(VM stacks)", &mut queues, |queues| {
+        agent.vm_stack.iter().for_each(|vm| {
+            // SAFETY: The VM stack only contains pointers to live VMs.
+            unsafe { vm.as_ref() }.mark_values(queues);
+        });
+    });
+    builder.add_root(gc_roots, "(Scoped values)", &mut queues, |queues| {
+        agent
+            .stack_refs
+            .borrow()
+            .iter()
+            .for_each(|value| value.mark_values(queues));
+        agent
+            .stack_ref_collections
+            .borrow()
+            .iter()
+            .for_each(|collection| collection.mark_values(queues));
+    });
+    builder.add_root(gc_roots, "(Global handles)", &mut queues, |queues| {
+        agent
+            .heap
+            .globals
+            .borrow()
+            .iter()
+            .filter(|value| **value != HeapRootData::Empty)
+            .for_each(|value| value.mark_values(queues));
+    });
+    builder.add_root(
+        gc_roots,
+        "(Global symbol registry)",
+        &mut queues,
+        |queues| {
+            agent.global_symbol_registry.mark_values(queues);
+        },
+    );
+    builder.add_root(gc_roots, "(Internal)", &mut queues, |queues| {
+        queues.object_shapes.push(ObjectShape::NULL);
+        agent.heap.prototype_shapes.mark_values(queues);
+        agent.heap.caches.mark_values(queues);
+    });
+
+    let mut node = 0;
+    while node < builder.nodes.len() {
+        builder.trace(node, &mut queues);
+        node += 1;
+    }
+
+    let mut writer = BufWriter::new(writer);
+    builder.write(&mut writer)?;
+    writer.flush()
+}
@@ -0,0 +1,81 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::time::Duration;
+
+use super::Heap;
+
+/// Statistics of an Agent's heap.
+///
+/// See [`GcAgent::heap_statistics`] and [`Agent::heap_statistics`].
+///
+/// [`GcAgent::heap_statistics`]: crate::ecmascript::GcAgent::heap_statistics
+/// [`Agent::heap_statistics`]: crate::ecmascript::Agent::heap_statistics
+#[derive(Debug, Clone)]
+pub struct HeapStatistics {
+    /// Statistics of each arena in the heap.
+    ///
+    /// The counts include garbage that has not yet been collected; directly
+    /// after a garbage collection they only contain live data.
+    pub arenas: Vec<ArenaStatistics>,
+    /// Estimated number of bytes currently used by the heap, including
+    /// allocations made since the last garbage collection.
+    pub estimated_bytes: usize,
+    /// Estimated number of bytes retained by the heap after the last garbage
+    /// collection.
+    pub retained_bytes: usize,
+    /// Maximum number of bytes the heap may use, if limited.
+    pub max_heap_bytes: Option<usize>,
+    /// Number of garbage collections performed so far.
+    pub gc_count: usize,
+    /// Statistics of the last garbage collection, if one has been performed.
+    pub last_gc: Option<GcStatistics>,
+}
+
+impl HeapStatistics {
+    pub(crate) fn new(heap: &Heap) -> Self {
+        let mut arenas = Vec::new();
+        heap.for_each_arena(&mut |name, count, bytes| {
+            arenas.push(ArenaStatistics { name, count, bytes })
+        });
+        Self {
+            arenas,
+            estimated_bytes: heap.estimated_byte_size(),
+            retained_bytes: heap.retained_bytes,
+            max_heap_bytes: heap.max_heap_bytes,
+            gc_count: heap.gc_count,
+            last_gc: heap.last_gc,
+        }
+    }
+
+    /// Returns the statistics of the arena with the given name.
+    pub fn arena(&self, name: &str) -> Option<&ArenaStatistics> {
+        self.arenas.iter().find(|arena| arena.name == name)
+    }
+}
+
+/// Statistics of a single heap arena.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArenaStatistics {
+    /// Name of the arena, eg. `"objects"` or `"element_arrays_2pow4"`.
+    pub name: &'static str,
+    /// Number of items in the arena.
+    pub count: usize,
+    /// Estimated number of bytes used by the items in the arena.
+    pub bytes: usize,
+}
+
+/// Statistics of a single garbage collection.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GcStatistics {
+    /// True if only the young generation was collected.
+    pub young: bool,
+    /// Time taken by the garbage collection.
+    pub duration: Duration,
+    /// Estimated number of bytes freed by the garbage collection.
+    pub freed_bytes: usize,
+    /// Estimated number of bytes retained by the heap after the garbage
+    /// collection.
+    pub retained_bytes: usize,
+}
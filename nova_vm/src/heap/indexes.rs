@@ -5,6 +5,7 @@
 use crate::{
     ecmascript::{PropertyKey, Value},
     engine::{Bindable, GcToken, NoGcScope},
+    heap::{Arena, SparseElements},
 };
 use core::fmt::Debug;
 use core::{
@@ -162,7 +163,7 @@ impl ElementIndex<'_> {
     }
 }
 
-impl<const N: usize> Index<ElementIndex<'_>> for Vec<[Option<Value<'static>>; N]> {
+impl<const N: usize> Index<ElementIndex<'_>> for Arena<Vec<[Option<Value<'static>>; N]>> {
     type Output = [Option<Value<'static>>; N];
 
     fn index(&self, index: ElementIndex) -> &Self::Output {
@@ -171,7 +172,23 @@ impl<const N: usize> Index<ElementIndex<'_>> for Vec<[Option<Value<'static>>; N]
     }
 }
 
-impl<const N: usize> IndexMut<ElementIndex<'_>> for Vec<[Option<Value<'static>>; N]> {
+impl<const N: usize> IndexMut<ElementIndex<'_>> for Arena<Vec<[Option<Value<'static>>; N]>> {
+    fn index_mut(&mut self, index: ElementIndex<'_>) -> &mut Self::Output {
+        self.get_mut(index.get_index())
+            .expect("Invalid ElementsVector: No item at index")
+    }
+}
+
+impl Index<ElementIndex<'_>> for Arena<Vec<SparseElements>> {
+    type Output = SparseElements;
+
+    fn index(&self, index: ElementIndex) -> &Self::Output {
+        self.get(index.get_index())
+            .expect("Invalid ElementsVector: No item at index")
+    }
+}
+
+impl IndexMut<ElementIndex<'_>> for Arena<Vec<SparseElements>> {
     fn index_mut(&mut self, index: ElementIndex<'_>) -> &mut Self::Output {
         self.get_mut(index.get_index())
             .expect("Invalid ElementsVector: No item at index")
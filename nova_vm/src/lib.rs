@@ -61,21 +61,20 @@
 //! Nova JavaScript engine is not perfect and has many shortcomings.
 //!
 //! 1. The engine performance is acceptable, but it is not fast by any means.
-//! 1. The [`Array`] implementation does not support sparse storage internally.
-//!    Calling `new Array(10 ** 9)` will request an allocation for 1 billion
-//!    JavaScript [`Value`]s.
+//! 1. Sparse [`Array`]s only store the elements present in them, but most
+//!    `Array.prototype` methods still visit every index up to `length` on them.
+//!    Calling `new Array(10 ** 9).fill()` will allocate 1 billion JavaScript
+//!    [`Value`]s.
 //! 1. The [`RegExp`] implementation does not support lookaheads, lookbehinds,
 //!    or backreferences. It is always in UTF-8 / Unicode sets mode, does not
 //!    support RegExp patterns containing unpaired surrogates, and its groups
 //!    are slightly different from what the ECMAScript specification defines. In
 //!    short: it is not compliant.
-//! 1. [`Promise`] subclassing is currently not supported.
 //! 1. The engine does not support [WebAssembly] execution.
 //!
 //! [`Agent`]: crate::ecmascript::Agent
 //! [`Array`]: crate::ecmascript::Array
 //! [`RegExp`]: crate::ecmascript::RegExp
-//! [`Promise`]: crate::ecmascript::Promise
 //! [`Object`]: crate::ecmascript::Object
 //! [`String`]: crate::ecmascript::String
 //! [`Value`]: crate::ecmascript::Value
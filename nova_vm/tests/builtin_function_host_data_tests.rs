@@ -0,0 +1,79 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::{
+    ecmascript::{
+        Agent, AgentOptions, ArgumentsList, Behaviour, BuiltinFunctionArgs, DefaultHostHooks,
+        EmbedderObject, EmbedderObjectHooks, Function, GcAgent, InternalMethods, JsResult,
+        PropertyDescriptor, PropertyKey, String, Value, create_builtin_function,
+    },
+    engine::{Bindable, GcScope},
+};
+
+struct Counter {
+    count: u32,
+}
+
+fn increment<'gc>(
+    agent: &mut Agent,
+    _this: Value,
+    _args: ArgumentsList,
+    gc: GcScope<'gc, '_>,
+) -> JsResult<'gc, Value<'gc>> {
+    let Function::BuiltinFunction(f) = agent.active_function_object(gc.nogc()) else {
+        unreachable!()
+    };
+    let data = f.host_data(agent).unwrap();
+    let counter = data.downcast_mut::<Counter>(agent).unwrap();
+    counter.count += 1;
+    Ok(Value::Integer(counter.count.into()))
+}
+
+#[test]
+fn builtin_function_host_data() {
+    let mut agent = GcAgent::new(AgentOptions::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let counter = EmbedderObject::create(
+            agent,
+            None,
+            Box::new(Counter { count: 0 }),
+            EmbedderObjectHooks::NONE,
+            gc.nogc(),
+        )
+        .unwrap();
+        let function = create_builtin_function(
+            agent,
+            Behaviour::Regular(increment),
+            BuiltinFunctionArgs {
+                host_data: Some(counter),
+                ..BuiltinFunctionArgs::new(0, "increment")
+            },
+            gc.nogc(),
+        );
+        let global = agent.current_global_object(gc.nogc());
+        let key = PropertyKey::from_static_str(agent, "increment", gc.nogc());
+        global
+            .unbind()
+            .internal_define_own_property(
+                agent,
+                key.unbind(),
+                PropertyDescriptor {
+                    value: Some(function.unbind().into()),
+                    ..Default::default()
+                },
+                gc.reborrow(),
+            )
+            .unwrap();
+    });
+    agent.gc();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let source_text =
+            String::from_static_str(agent, "increment(); increment(); increment()", gc.nogc());
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(result.unbind(), Value::Integer(3.into()));
+    });
+}
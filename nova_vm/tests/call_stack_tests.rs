@@ -0,0 +1,92 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::thread;
+
+use nova_vm::{
+    ecmascript::{AgentOptions, DefaultHostHooks, GcAgent, String, Value},
+    engine::Bindable,
+};
+
+/// Runs the source on a new thread with the given native stack size, and
+/// returns the integer result.
+fn run_on_thread(options: AgentOptions, stack_size: usize, source: &'static str) -> i32 {
+    thread::Builder::new()
+        .stack_size(stack_size)
+        .spawn(move || {
+            let mut agent = GcAgent::new(options, &DefaultHostHooks);
+            let realm = agent.create_default_realm();
+            agent.run_in_realm(&realm, |agent, mut gc| {
+                let source_text = String::from_static_str(agent, source, gc.nogc());
+                let result = agent
+                    .run_script(source_text.unbind(), gc.reborrow())
+                    .unwrap();
+                let Value::Integer(result) = result else {
+                    panic!("Expected an integer result");
+                };
+                result.into_i64() as i32
+            })
+        })
+        .unwrap()
+        .join()
+        .unwrap()
+}
+
+const RECURSE: &str = "let depth = 0; function f() { depth++; f(); } try { f(); } catch (err) { if (!(err instanceof RangeError)) throw err; } depth";
+
+#[test]
+fn max_call_depth() {
+    let depth = run_on_thread(
+        AgentOptions {
+            max_call_depth: Some(100),
+            ..Default::default()
+        },
+        16 * 1024 * 1024,
+        RECURSE,
+    );
+    assert!((90..=100).contains(&depth), "{depth}");
+}
+
+#[test]
+fn default_call_depth() {
+    let depth = run_on_thread(AgentOptions::default(), 256 * 1024 * 1024, RECURSE);
+    assert!((3490..=3500).contains(&depth), "{depth}");
+}
+
+fn native_stack_options() -> AgentOptions {
+    AgentOptions {
+        native_stack_reserve: Some(128 * 1024),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn native_stack_limit() {
+    let small = run_on_thread(native_stack_options(), 2 * 1024 * 1024, RECURSE);
+    let large = run_on_thread(native_stack_options(), 64 * 1024 * 1024, RECURSE);
+    assert!(small > 0);
+    assert!(large > small * 16, "{large} <= 16 * {small}");
+}
+
+#[test]
+fn native_stack_limit_on_small_stack() {
+    let depth = run_on_thread(native_stack_options(), 512 * 1024, RECURSE);
+    assert!(depth > 0);
+}
+
+#[test]
+fn native_stack_limit_in_builtins() {
+    let result = run_on_thread(
+        native_stack_options(),
+        512 * 1024,
+        "let a = []; for (let i = 0; i < 100000; i++) a = [a]; \
+         let p = {}; for (let i = 0; i < 100000; i++) p = new Proxy(p, {}); \
+         let caught = 0; \
+         for (const f of [() => JSON.stringify(a), () => a.flat(Infinity), () => p.x]) { \
+           try { f(); } catch (err) { if (!(err instanceof RangeError)) throw err; caught++; } \
+         } \
+         caught",
+    );
+    assert_eq!(result, 3);
+}
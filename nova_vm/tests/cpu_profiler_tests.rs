@@ -0,0 +1,105 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::time::Duration;
+
+use nova_vm::{
+    ecmascript::{AgentOptions, DefaultHostHooks, GcAgent, RealmRoot, String, Value},
+    engine::Bindable,
+};
+
+/// Runs the script and returns true if its result is `true`.
+fn run(agent: &mut GcAgent, realm: &RealmRoot, source: &str) -> bool {
+    agent.run_in_realm(realm, |agent, mut gc| {
+        let source_text = String::from_string(agent, source.to_owned(), gc.nogc());
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        result.unbind() == Value::Boolean(true)
+    })
+}
+
+/// Runs the script in a new Agent and returns true if its result is `true`.
+fn run_in_new_agent(source: &str) -> bool {
+    let mut agent = GcAgent::new(AgentOptions::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    run(&mut agent, &realm, source)
+}
+
+#[test]
+fn cpu_profiler_samples_call_stacks() {
+    let mut agent = GcAgent::new(AgentOptions::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    assert!(agent.stop_cpu_profiler().is_none());
+    agent.start_cpu_profiler(Duration::from_millis(1));
+    run(
+        &mut agent,
+        &realm,
+        "function hot(n) {
+            let sum = 0;
+            for (let i = 0; i < n; i++) sum += i;
+            return sum;
+        }
+        function outer() {
+            const end = Date.now() + 100;
+            while (Date.now() < end) hot(1000);
+        }
+        outer();",
+    );
+    let profile = agent.stop_cpu_profiler().unwrap();
+    assert!(agent.stop_cpu_profiler().is_none());
+    assert!(!profile.samples.is_empty());
+    assert!(
+        profile
+            .samples
+            .windows(2)
+            .all(|samples| samples[0].timestamp <= samples[1].timestamp)
+    );
+    let hot_sample = profile
+        .samples
+        .iter()
+        .find(|sample| profile.frames[sample.stack[0]].function_name.as_deref() == Some("hot"))
+        .unwrap();
+    let names = hot_sample
+        .stack
+        .iter()
+        .map(|&frame| profile.frames[frame].function_name.as_deref())
+        .collect::<Vec<_>>();
+    assert_eq!(names, [Some("hot"), Some("outer"), None]);
+    let hot = &profile.frames[hot_sample.stack[0]];
+    // Note: the sample can be taken on entry to the function, before its
+    // first statement.
+    assert!((1..=4).contains(&hot.line.unwrap()));
+
+    let mut folded = Vec::new();
+    profile.write_folded_stacks(&mut folded).unwrap();
+    let folded = std::string::String::from_utf8(folded).unwrap();
+    assert!(folded.lines().any(|line| line.starts_with(
+        "(root);(anonymous) (<anonymous>:10);outer (<anonymous>:8);hot (<anonymous>:"
+    )));
+    let folded_samples = folded
+        .lines()
+        .map(|line| line.rsplit_once(' ').unwrap().1.parse::<usize>().unwrap())
+        .sum::<usize>();
+    assert_eq!(folded_samples, profile.samples.len());
+
+    let mut cpuprofile = Vec::new();
+    profile.write_cpuprofile(&mut cpuprofile).unwrap();
+    let cpuprofile = std::string::String::from_utf8(cpuprofile).unwrap();
+    // Check the structure of the profile using JSON.parse in a fresh Agent.
+    assert!(run_in_new_agent(&format!(
+        "const profile = {cpuprofile};
+            const ids = new Set(profile.nodes.map(node => node.id));
+            const hot = profile.nodes.find(node => node.callFrame.functionName === 'hot');
+            profile.nodes[0].callFrame.functionName === '(root)'
+                && profile.samples.length === {}
+                && profile.timeDeltas.length === profile.samples.length
+                && profile.samples.every(id => ids.has(id))
+                && profile.nodes.every(node => (node.children ?? []).every(id => ids.has(id)))
+                && hot.hitCount > 0
+                && hot.positionTicks.length > 0
+                && hot.callFrame.lineNumber >= 0",
+        profile.samples.len()
+    )));
+}
@@ -0,0 +1,97 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::{
+    any::Any,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+use nova_vm::{
+    ecmascript::{
+        AgentOptions, DefaultHostHooks, EmbedderObject, EmbedderObjectHooks, GcAgent,
+        InternalMethods, PropertyDescriptor, PropertyKey, String, Value, unwrap_try,
+    },
+    engine::Bindable,
+};
+
+static FINALIZED: AtomicUsize = AtomicUsize::new(0);
+
+struct FileHandle {
+    fd: u32,
+}
+
+fn finalize_file_handle(data: Box<dyn Any + Send>) {
+    let handle = data.downcast::<FileHandle>().unwrap();
+    assert_eq!(handle.fd, 3);
+    FINALIZED.fetch_add(1, Ordering::Relaxed);
+}
+
+static FILE_HANDLE_HOOKS: EmbedderObjectHooks = EmbedderObjectHooks {
+    mark: None,
+    sweep: None,
+    finalize: Some(finalize_file_handle),
+};
+
+#[test]
+fn embedder_object_tests() {
+    let mut agent = GcAgent::new(AgentOptions::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let object_prototype = agent.current_realm(gc.nogc()).object_prototype(agent);
+        let handle = EmbedderObject::create(
+            agent,
+            Some(object_prototype),
+            Box::new(FileHandle { fd: 3 }),
+            &FILE_HANDLE_HOOKS,
+            gc.nogc(),
+        )
+        .unwrap();
+        assert_eq!(handle.downcast_ref::<FileHandle>(agent).unwrap().fd, 3);
+        assert!(handle.downcast_ref::<u32>(agent).is_none());
+
+        let bare = EmbedderObject::create(
+            agent,
+            None,
+            Box::new(()),
+            EmbedderObjectHooks::NONE,
+            gc.nogc(),
+        )
+        .unwrap();
+        assert_eq!(
+            unwrap_try(bare.try_get_prototype_of(agent, gc.nogc())),
+            None
+        );
+
+        let global = agent.current_global_object(gc.nogc());
+        let key = PropertyKey::from_static_str(agent, "handle", gc.nogc());
+        global
+            .unbind()
+            .internal_define_own_property(
+                agent,
+                key.unbind(),
+                PropertyDescriptor {
+                    value: Some(handle.unbind().into()),
+                    writable: Some(true),
+                    enumerable: Some(true),
+                    configurable: Some(true),
+                    ..Default::default()
+                },
+                gc.reborrow(),
+            )
+            .unwrap();
+
+        let source_text = String::from_static_str(
+            agent,
+            "handle.foo = 1; const result = Object.prototype.toString.call(handle) === '[object Object]' && handle.foo === 1 && Object.getPrototypeOf(handle) === Object.prototype; handle = undefined; result",
+            gc.nogc(),
+        );
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        assert_eq!(result.unbind(), Value::Boolean(true));
+    });
+    assert_eq!(FINALIZED.load(Ordering::Relaxed), 0);
+    agent.gc();
+    assert_eq!(FINALIZED.load(Ordering::Relaxed), 1);
+}
@@ -0,0 +1,77 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::{
+    ecmascript::{AgentOptions, DefaultHostHooks, GcAgent, String, Value},
+    engine::Bindable,
+};
+
+/// Runs the script in a new Agent with generational GC enabled, and returns
+/// true if its result is `true`.
+fn run_with_generational_gc(max_heap_bytes: Option<usize>, source: &str) -> bool {
+    let options = AgentOptions {
+        generational_gc: true,
+        max_heap_bytes,
+        ..Default::default()
+    };
+    let mut agent = GcAgent::new(options, &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let result = agent.run_in_realm(&realm, |agent, mut gc| {
+        let source_text = String::from_string(agent, source.to_owned(), gc.nogc());
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        result.unbind() == Value::Boolean(true)
+    });
+    // A full collection after young collections must not lose anything
+    // either.
+    agent.gc();
+    result
+}
+
+#[test]
+fn generational_gc_keeps_old_to_young_references() {
+    assert!(run_with_generational_gc(
+        None,
+        "const old = { list: [], map: new Map(), weak: new WeakMap(), slots: new Array(64).fill(null) };
+        const closures = [];
+        for (let round = 0; round < 400; round++) {
+            for (let i = 0; i < 100; i++) ({ garbage: [i, { i }], text: 'x' + i });
+            const young = { round, nested: { text: 'r' + round, pair: [round, round * 2] } };
+            old.list.push(young);
+            old.map.set('k' + round, young.nested);
+            old.weak.set(young, young.nested);
+            old.slots[round % 64] = young;
+            old['prop' + round] = [round];
+            closures.push(() => young.round);
+            if (round % 50 === 0) old.list = old.list.slice();
+        }
+        let ok = true;
+        for (let round = 0; round < 400; round++) {
+            const young = old.list[round];
+            ok &&= young.round === round
+                && young.nested.text === 'r' + round
+                && young.nested.pair[1] === round * 2
+                && old.map.get('k' + round) === young.nested
+                && old.weak.get(young) === young.nested
+                && old['prop' + round][0] === round
+                && closures[round]() === round;
+        }
+        for (let round = 336; round < 400; round++) ok &&= old.slots[round % 64].round === round;
+        ok"
+    ));
+}
+
+#[test]
+fn generational_gc_reclaims_young_garbage() {
+    assert!(run_with_generational_gc(
+        Some(16 * 1024 * 1024),
+        "let kept = [];
+        for (let i = 0; i < 200000; i++) {
+            const garbage = { i, list: [i, i + 1, i + 2] };
+            if (i % 1000 === 0) kept.push(garbage);
+        }
+        kept.length === 200 && kept[199].list[2] === 199002"
+    ));
+}
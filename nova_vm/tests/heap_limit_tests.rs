@@ -0,0 +1,97 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use nova_vm::{
+    ecmascript::{AgentOptions, DefaultHostHooks, GcAgent, HostHooks, Job, String, Value},
+    engine::Bindable,
+};
+
+const MAX_HEAP_BYTES: usize = 1024 * 1024;
+
+/// Runs the script in a new Agent with a heap limit, and returns true if its
+/// result is `true`.
+fn run_with_heap_limit(host_hooks: &'static dyn HostHooks, source: &str) -> bool {
+    let options = AgentOptions {
+        max_heap_bytes: Some(MAX_HEAP_BYTES),
+        ..Default::default()
+    };
+    let mut agent = GcAgent::new(options, host_hooks);
+    let realm = agent.create_default_realm();
+    agent.run_in_realm(&realm, |agent, mut gc| {
+        let source_text = String::from_string(agent, source.to_owned(), gc.nogc());
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        result.unbind() == Value::Boolean(true)
+    })
+}
+
+#[test]
+fn heap_limit_throws_range_error() {
+    assert!(run_with_heap_limit(
+        &DefaultHostHooks,
+        "try { Array(2 ** 30).fill(0); false } catch (err) { err instanceof RangeError }"
+    ));
+    assert!(run_with_heap_limit(
+        &DefaultHostHooks,
+        "let list = null; try { for (;;) list = { list }; } catch (err) { list = null; err instanceof RangeError }"
+    ));
+    assert!(run_with_heap_limit(
+        &DefaultHostHooks,
+        "const a = []; for (let i = 0; i < 10000; i++) a.push({}); a.length = 0; for (let i = 0; i < 10000; i++) a.push({}); true"
+    ));
+}
+
+#[test]
+fn heap_limit_checked_on_large_allocations() {
+    assert!(run_with_heap_limit(
+        &DefaultHostHooks,
+        "try { 'x'.repeat(2 ** 28); false } catch (err) { err instanceof RangeError && err.message.includes('heap size limit') }"
+    ));
+    assert!(run_with_heap_limit(
+        &DefaultHostHooks,
+        "try { 'x'.padEnd(2 ** 28, 'yz'); false } catch (err) { err instanceof RangeError && err.message.includes('heap size limit') }"
+    ));
+    assert!(run_with_heap_limit(
+        &DefaultHostHooks,
+        "try { new ArrayBuffer(2 ** 28); false } catch (err) { err instanceof RangeError && err.message.includes('heap size limit') }"
+    ));
+    assert!(run_with_heap_limit(
+        &DefaultHostHooks,
+        "new ArrayBuffer(1024).byteLength === 1024 && 'x'.repeat(1024).length === 1024"
+    ));
+}
+
+#[derive(Debug)]
+struct RaiseLimitHostHooks;
+
+static NEAR_HEAP_LIMIT_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+impl HostHooks for RaiseLimitHostHooks {
+    fn enqueue_generic_job(&self, _job: Job) {}
+
+    fn enqueue_promise_job(&self, _job: Job) {}
+
+    fn enqueue_timeout_job(&self, _timeout_job: Job, _milliseconds: u64) {}
+
+    fn near_heap_limit(&self, _heap_bytes: usize, max_heap_bytes: usize) -> Option<usize> {
+        // Allow the heap to grow once.
+        if NEAR_HEAP_LIMIT_CALLS.fetch_add(1, Ordering::Relaxed) == 0 {
+            Some(max_heap_bytes * 2)
+        } else {
+            None
+        }
+    }
+}
+
+#[test]
+fn near_heap_limit_hook() {
+    assert!(run_with_heap_limit(
+        &RaiseLimitHostHooks,
+        "let list = null; try { for (;;) list = { list }; } catch (err) { list = null; err instanceof RangeError }"
+    ));
+    assert_eq!(NEAR_HEAP_LIMIT_CALLS.load(Ordering::Relaxed), 2);
+}
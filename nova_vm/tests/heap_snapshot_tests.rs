@@ -0,0 +1,81 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::{
+    ecmascript::{AgentOptions, DefaultHostHooks, GcAgent, RealmRoot, String, Value},
+    engine::Bindable,
+};
+
+/// Runs the script and returns true if its result is `true`.
+fn run(agent: &mut GcAgent, realm: &RealmRoot, source: &str) -> bool {
+    agent.run_in_realm(realm, |agent, mut gc| {
+        let source_text = String::from_string(agent, source.to_owned(), gc.nogc());
+        let result = agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+        result.unbind() == Value::Boolean(true)
+    })
+}
+
+/// Runs the script in a new Agent and returns true if its result is `true`.
+fn run_in_new_agent(source: &str) -> bool {
+    let mut agent = GcAgent::new(AgentOptions::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    run(&mut agent, &realm, source)
+}
+
+/// Checks the structure of a heap snapshot using `JSON.parse` in a fresh
+/// Agent, and that `globalThis.leak.payload` can be found in it.
+const CHECK_SNAPSHOT: &str = "
+const { snapshot: { meta, node_count, edge_count }, nodes, edges, strings } = snapshot;
+const nodeFields = meta.node_fields.length;
+const edgeFields = meta.edge_fields.length;
+let ok = nodeFields === 7 && edgeFields === 3
+    && nodes.length === node_count * nodeFields
+    && edges.length === edge_count * edgeFields;
+let totalEdges = 0;
+const firstEdge = [];
+for (let i = 0; i < nodes.length; i += nodeFields) {
+    firstEdge.push(totalEdges);
+    totalEdges += nodes[i + 4];
+}
+ok &&= totalEdges === edge_count;
+for (let i = 0; i < edges.length; i += edgeFields) {
+    ok &&= edges[i + 2] % nodeFields === 0 && edges[i + 2] < nodes.length;
+}
+const property = meta.edge_types[0].indexOf('property');
+function findProperty(node, name) {
+    const first = firstEdge[node];
+    for (let e = first; e < first + nodes[node * nodeFields + 4]; e++) {
+        if (edges[e * edgeFields] === property && strings[edges[e * edgeFields + 1]] === name) {
+            return edges[e * edgeFields + 2] / nodeFields;
+        }
+    }
+}
+let payload;
+for (let node = 0; node < node_count && payload === undefined; node++) {
+    const leak = findProperty(node, 'leak');
+    if (leak !== undefined) payload = findProperty(leak, 'payload');
+}
+ok && payload !== undefined
+    && strings[nodes[payload * nodeFields + 1]] === 'Array'
+    && strings.includes('needle in the heap')";
+
+#[test]
+fn heap_snapshot_contains_reachable_objects() {
+    let mut agent = GcAgent::new(AgentOptions::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    run(
+        &mut agent,
+        &realm,
+        "globalThis.leak = { payload: [1, 'needle in the heap', { nested: true }] };",
+    );
+    let mut snapshot = Vec::new();
+    agent.write_heap_snapshot(&mut snapshot).unwrap();
+    let snapshot = std::string::String::from_utf8(snapshot).unwrap();
+
+    assert!(run_in_new_agent(&format!(
+        "const snapshot = {snapshot};\n{CHECK_SNAPSHOT}"
+    )));
+}
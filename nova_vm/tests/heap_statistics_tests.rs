@@ -0,0 +1,87 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use nova_vm::{
+    ecmascript::{AgentOptions, DefaultHostHooks, GcAgent, GcTrigger, RealmRoot, String},
+    engine::Bindable,
+};
+
+fn run(agent: &mut GcAgent, realm: &RealmRoot, source: &str) {
+    agent.run_in_realm(realm, |agent, mut gc| {
+        let source_text = String::from_string(agent, source.to_owned(), gc.nogc());
+        agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .unwrap();
+    });
+}
+
+#[test]
+fn heap_statistics_report_arenas_and_last_gc() {
+    let mut agent = GcAgent::new(AgentOptions::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let before = agent.heap_statistics();
+    assert!(before.last_gc.is_none());
+    let objects_before = before.arena("objects").unwrap().count;
+
+    run(
+        &mut agent,
+        &realm,
+        "globalThis.kept = [];
+        for (let i = 0; i < 1000; i++) kept.push({ i });
+        for (let i = 0; i < 1000; i++) ({ garbage: i });",
+    );
+    let allocated = agent.heap_statistics();
+    assert!(allocated.arena("objects").unwrap().count >= objects_before + 2000);
+
+    let gc_count = allocated.gc_count;
+    agent.gc();
+    let collected = agent.heap_statistics();
+    assert_eq!(collected.gc_count, gc_count + 1);
+    let last_gc = collected.last_gc.unwrap();
+    assert!(!last_gc.young);
+    assert!(last_gc.freed_bytes > 0);
+    assert_eq!(last_gc.retained_bytes, collected.retained_bytes);
+    let objects = collected.arena("objects").unwrap();
+    assert!(objects.count >= objects_before + 1000);
+    assert!(objects.count < objects_before + 2000);
+    assert!(objects.bytes > 0);
+    assert!(collected.arena("element_arrays_2pow10").is_some());
+    assert_eq!(
+        collected
+            .arenas
+            .iter()
+            .map(|arena| arena.bytes)
+            .sum::<usize>(),
+        collected.retained_bytes
+    );
+}
+
+#[test]
+fn gc_trigger_controls_collection_frequency() {
+    let count_gcs = |gc_trigger| {
+        let options = AgentOptions {
+            gc_trigger,
+            ..Default::default()
+        };
+        let mut agent = GcAgent::new(options, &DefaultHostHooks);
+        let realm = agent.create_default_realm();
+        run(
+            &mut agent,
+            &realm,
+            "for (let i = 0; i < 100000; i++) ({ garbage: [i] });",
+        );
+        agent.heap_statistics().gc_count
+    };
+    let frequent = count_gcs(GcTrigger::AllocatedBytes(256 * 1024));
+    let default = count_gcs(GcTrigger::default());
+    let never = count_gcs(GcTrigger::AllocatedBytes(usize::MAX));
+    let growth = count_gcs(GcTrigger::HeapGrowth {
+        factor: 2.0,
+        min_bytes: 256 * 1024,
+    });
+    assert!(frequent > default);
+    assert!(default > 0);
+    assert_eq!(never, 0);
+    assert!(growth > 0);
+}
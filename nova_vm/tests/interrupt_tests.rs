@@ -0,0 +1,132 @@
+// This Source Code Form is subject to the terms of the Mozilla Public
+// License, v. 2.0. If a copy of the MPL was not distributed with this
+// file, You can obtain one at https://mozilla.org/MPL/2.0/.
+
+use std::{thread, time::Duration};
+
+use nova_vm::{
+    ecmascript::{
+        AgentOptions, DefaultHostHooks, GcAgent, InternalMethods, Object, PropertyKey, RealmRoot,
+        String, Value, unwrap_try,
+    },
+    engine::Bindable,
+};
+
+/// Runs the script and returns true if its result is `true`, or None if it
+/// throws an error.
+fn run(agent: &mut GcAgent, realm: &RealmRoot, source: &str) -> Option<bool> {
+    agent.run_in_realm(realm, |agent, mut gc| {
+        let source_text = String::from_string(agent, source.to_owned(), gc.nogc());
+        agent
+            .run_script(source_text.unbind(), gc.reborrow())
+            .ok()
+            .map(|result| result.unbind() == Value::Boolean(true))
+    })
+}
+
+#[test]
+fn interrupt_handle() {
+    let mut agent = GcAgent::new(AgentOptions::default(), &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let handle = agent.interrupt_handle();
+    let watchdog = thread::spawn(move || {
+        thread::sleep(Duration::from_millis(50));
+        handle.interrupt();
+    });
+    let source = "while (true) { try { while (true) {} } catch { } finally { continue; } }";
+    assert_eq!(run(&mut agent, &realm, source), None);
+    watchdog.join().unwrap();
+    assert!(!agent.interrupt_handle().is_interrupted());
+    assert_eq!(run(&mut agent, &realm, "1 + 1 === 2"), Some(true));
+}
+
+#[test]
+fn fuel_limit() {
+    let options = AgentOptions {
+        fuel: Some(1000),
+        ..Default::default()
+    };
+    let mut agent = GcAgent::new(options, &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    let source = "function f() { try { for (;;) {} } catch { return 1; } } f()";
+    assert_eq!(run(&mut agent, &realm, source), None);
+    agent.run_in_realm(&realm, |agent, _| {
+        assert_eq!(agent.remaining_fuel(), Some(0));
+        agent.set_fuel(None);
+    });
+    let source = "let i = 0; for (; i < 10000; i++) {} i === 10000";
+    assert_eq!(run(&mut agent, &realm, source), Some(true));
+}
+
+#[test]
+fn fuel_is_consumed_at_safe_points() {
+    let options = AgentOptions {
+        fuel: Some(2),
+        ..Default::default()
+    };
+    let mut agent = GcAgent::new(options, &DefaultHostHooks);
+    let realm = agent.create_default_realm();
+    // Straight-line code does not consume fuel per instruction.
+    let source = "let x = 0; x++; x++; x++; x++; x++; x++; x === 6";
+    assert_eq!(run(&mut agent, &realm, source), Some(true));
+    assert_eq!(run(&mut agent, &realm, "for (;;) {}"), None);
+}
+
+#[test]
+fn interrupt_is_not_turned_into_rejection() {
+    for source in [
+        "new Promise(() => { while (true) {} }); globalThis.survived = true;",
+        "async function f() { while (true) {} } f().catch(() => {}); globalThis.survived = true;",
+        "async function f() { await null; } f(); new Promise(() => { for (;;) {} }).then(null, () => {}); globalThis.survived = true;",
+    ] {
+        let mut agent = GcAgent::new(AgentOptions::default(), &DefaultHostHooks);
+        let realm = agent.create_default_realm();
+        let handle = agent.interrupt_handle();
+        let watchdog = thread::spawn(move || {
+            thread::sleep(Duration::from_millis(50));
+            handle.interrupt();
+        });
+        assert_eq!(run(&mut agent, &realm, source), None);
+        watchdog.join().unwrap();
+        let source = "globalThis.survived === undefined";
+        assert_eq!(run(&mut agent, &realm, source), Some(true));
+    }
+}
+
+#[test]
+fn interrupt_error_message() {
+    let error_message = |fuel: Option<u64>, interrupt: bool| {
+        let options = AgentOptions {
+            fuel,
+            ..Default::default()
+        };
+        let mut agent = GcAgent::new(options, &DefaultHostHooks);
+        let realm = agent.create_default_realm();
+        if interrupt {
+            agent.interrupt_handle().interrupt();
+        }
+        agent.run_in_realm(&realm, |agent, mut gc| {
+            let source_text = String::from_static_str(agent, "for (;;) {}", gc.nogc());
+            let err = agent
+                .run_script(source_text.unbind(), gc.reborrow())
+                .unwrap_err()
+                .unbind();
+            let gc = gc.into_nogc();
+            // Note: the interrupt is still pending, so the message is read
+            // without calling into JavaScript.
+            let error = Object::try_from(err.bind(gc).value()).unwrap();
+            let key = PropertyKey::from_static_str(agent, "message", gc);
+            let message = unwrap_try(error.try_get_own_property(agent, key, None, gc))
+                .unwrap()
+                .value
+                .unwrap();
+            let message = String::try_from(message).unwrap();
+            message.to_string_lossy(agent).into_owned()
+        })
+    };
+    assert_eq!(error_message(None, true), "Execution interrupted");
+    assert_eq!(error_message(Some(10), false), "Execution fuel exhausted");
+    // Fuel that has run down to zero does not make an interrupt look like
+    // fuel exhaustion.
+    assert_eq!(error_message(Some(0), true), "Execution interrupted");
+}
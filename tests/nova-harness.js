@@ -31,6 +31,7 @@ const buildHarness = (global) => {
   global.$262.gc = novaObj.gc;
   global.$262.agent = novaObj.agent;
   global.$262.global = global;
+  global.$262.AbstractModuleSource = novaObj.AbstractModuleSource;
   return global.$262;
 };
 